@@ -0,0 +1,68 @@
+//! A `no_std` crate exercising `sbor` and `radix-engine-interface` with only `alloc`.
+//!
+//! This crate provides its own panic handler, so the build fails with a duplicate `panic_impl`
+//! lang item if any of its dependencies accidentally links `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::prelude::*;
+
+//=================
+// Exported checks
+//=================
+
+#[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+pub struct Sample {
+    pub number: u32,
+    pub text: String,
+    pub bytes: Vec<u8>,
+    pub map: BTreeMap<u8, Option<i64>>,
+}
+
+#[no_mangle]
+pub extern "C" fn sbor_round_trip() -> i32 {
+    let mut map = BTreeMap::new();
+    map.insert(1u8, Some(-5i64));
+    map.insert(2u8, None);
+    let sample = Sample {
+        number: 42,
+        text: String::from("no_std"),
+        bytes: alloc::vec![1, 2, 3],
+        map,
+    };
+
+    let decoded = basic_encode(&sample)
+        .ok()
+        .and_then(|payload| basic_decode::<Sample>(&payload).ok());
+    (decoded == Some(sample)) as i32
+}
+
+#[no_mangle]
+pub extern "C" fn interface_round_trip() -> i32 {
+    let amount = Decimal::from(3) / Decimal::from(2);
+    let badge = NonFungibleGlobalId::new(XRD, NonFungibleLocalId::integer(1));
+    let rule = rule!(require(badge.clone()));
+    let value = (amount, badge, rule);
+
+    let decoded = scrypto_encode(&value).ok().and_then(|payload| {
+        scrypto_decode::<(Decimal, NonFungibleGlobalId, AccessRule)>(&payload).ok()
+    });
+    (decoded == Some(value) && amount == dec!("1.5")) as i32
+}
+
+//=====================
+// Runtime scaffolding
+//=====================
+
+// Use WeeAlloc as our global heap allocator.
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+// Abort when panicking.
+#[panic_handler]
+pub fn panic(_: &core::panic::PanicInfo) -> ! {
+    core::arch::wasm32::unreachable()
+}
@@ -0,0 +1,111 @@
+//! Harness for checking that the interface crates keep working in constrained environments.
+//!
+//! The checks build small fixture crates (see `assets/`) with `no_std` + `alloc` for the
+//! `wasm32-unknown-unknown` target and run their exports in a minimal WASM runner, so that
+//! `cargo test -p compat-tests` catches accidental `std` dependencies locally.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use wasmi::core::Value;
+use wasmi::*;
+
+pub const WASM32_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Returns the path of a fixture crate under `assets/`.
+pub fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("assets")
+        .join(name)
+}
+
+/// Returns the directory used for the builds triggered by this harness, which is kept separate
+/// from the workspace target directory to avoid unifying features with the `std` builds.
+pub fn target_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("target")
+        .join("compat-tests")
+}
+
+/// Builds a workspace package with `std` replaced by `alloc`, optionally for a given target.
+pub fn build_without_std(package: &str, target: Option<&str>) {
+    let mut command = Command::new("cargo");
+    command
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .args(["build", "--package", package])
+        .args(["--no-default-features", "--features", "alloc"])
+        .arg("--target-dir")
+        .arg(target_dir());
+    if let Some(target) = target {
+        command.args(["--target", target]);
+    }
+    let status = command.status().expect("Failed to run cargo");
+    if !status.success() {
+        panic!(
+            "Failed to build {} without std for target {:?}",
+            package, target
+        );
+    }
+}
+
+/// Builds a fixture crate for `wasm32-unknown-unknown` and returns the WASM code.
+pub fn build_wasm_fixture(name: &str) -> Vec<u8> {
+    let fixture_dir = fixture_path(name);
+    let status = Command::new("cargo")
+        .current_dir(&fixture_dir)
+        .args(["build", "--target", WASM32_TARGET, "--release"])
+        .arg("--target-dir")
+        .arg(target_dir())
+        .status()
+        .expect("Failed to run cargo");
+    if !status.success() {
+        panic!("Failed to build fixture: {:?}", fixture_dir);
+    }
+
+    let mut path = target_dir();
+    path.push(WASM32_TARGET);
+    path.push("release");
+    path.push(name.replace("-", "_"));
+    path.set_extension("wasm");
+    std::fs::read(&path)
+        .unwrap_or_else(|err| panic!("Failed to read built WASM from path {:?} - {:?}", path, err))
+}
+
+/// A minimal runner for WASM modules which import nothing from the host.
+pub struct WasmRunner {
+    store: Store<()>,
+    instance: Instance,
+}
+
+impl WasmRunner {
+    pub fn new(code: &[u8]) -> Self {
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, code).expect("Failed to parse WASM module");
+        let linker = <Linker<()>>::new();
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .expect("Failed to instantiate WASM module")
+            .ensure_no_start(&mut store)
+            .expect("WASM module has a start function");
+
+        Self { store, instance }
+    }
+
+    /// Calls an exported function which takes no arguments and returns an `i32`.
+    pub fn call_i32(&mut self, func_name: &str) -> i32 {
+        let func = self
+            .instance
+            .get_export(&self.store, func_name)
+            .and_then(Extern::into_func)
+            .unwrap_or_else(|| panic!("Missing export: {}", func_name));
+
+        let mut ret = [Value::I32(0)];
+        func.call(&mut self.store, &[], &mut ret)
+            .unwrap_or_else(|err| panic!("Failed to call {}: {:?}", func_name, err));
+        match ret[0] {
+            Value::I32(value) => value,
+            ref other => panic!("Unexpected return value from {}: {:?}", func_name, other),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use compat_tests::*;
+
+#[test]
+fn sbor_builds_without_std() {
+    build_without_std("sbor", None);
+    build_without_std("sbor", Some(WASM32_TARGET));
+}
+
+#[test]
+fn radix_engine_interface_builds_without_std() {
+    build_without_std("radix-engine-interface", None);
+    build_without_std("radix-engine-interface", Some(WASM32_TARGET));
+}
+
+#[test]
+fn no_std_fixture_runs_on_wasm32() {
+    // Arrange
+    let code = build_wasm_fixture("no-std-interface");
+    let mut runner = WasmRunner::new(&code);
+
+    // Act
+    let sbor_result = runner.call_i32("sbor_round_trip");
+    let interface_result = runner.call_i32("interface_round_trip");
+
+    // Assert
+    assert_eq!(sbor_result, 1);
+    assert_eq!(interface_result, 1);
+}
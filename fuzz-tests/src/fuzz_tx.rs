@@ -7,6 +7,7 @@ use radix_engine_interface::api::node_modules::royalty::{
 };
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::component_config::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
 use radix_engine_interface::blueprints::package::*;
@@ -232,19 +233,32 @@ impl TxFuzzer {
                 }
                 // AssertWorktopContainsAny
                 2 => Some(InstructionV1::AssertWorktopContainsAny { resource_address }),
+                // AssertWorktopContainsExactly
+                3 => {
+                    let amount = Decimal::arbitrary(&mut unstructured).unwrap();
+
+                    Some(InstructionV1::AssertWorktopContainsExactly {
+                        amount,
+                        resource_address,
+                    })
+                }
                 // AssertWorktopContainsNonFungibles
-                3 => Some(InstructionV1::AssertWorktopContainsNonFungibles {
+                4 => Some(InstructionV1::AssertWorktopContainsNonFungibles {
                     resource_address,
                     ids: non_fungible_ids.clone(),
                 }),
+                // AssertWorktopResourcesOnly
+                5 => Some(InstructionV1::AssertWorktopResourcesOnly {
+                    resource_addresses: vec![resource_address],
+                }),
                 // BurnResource
-                4 => {
+                6 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::BurnResource { bucket_id })
                 }
                 // CallAccessRulesMethod
-                5 => {
+                7 => {
                     // TODO - fuzz more methods
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
@@ -259,23 +273,28 @@ impl TxFuzzer {
                         Err(_) => None,
                     }
                 }
+                // CallDirectVaultMethod
+                8 => {
+                    // TODO
+                    None
+                }
                 // CallFunction
-                6 => {
+                9 => {
                     // TODO
                     None
                 }
                 // CallMetadataMethod
-                7 => {
+                10 => {
                     // TODO
                     None
                 }
                 // CallMethod
-                8 => {
+                11 => {
                     // TODO
                     None
                 }
                 // CallRoyaltyMethod
-                9 =>
+                12 =>
                 // TODO - fuzz more methods
                 {
                     Some(InstructionV1::CallRoyaltyMethod {
@@ -285,13 +304,13 @@ impl TxFuzzer {
                     })
                 }
                 // ClaimComponentRoyalty
-                10 => Some(InstructionV1::CallRoyaltyMethod {
+                13 => Some(InstructionV1::CallRoyaltyMethod {
                     address: component_address.into(),
                     method_name: COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT.to_string(),
                     args: manifest_args!().into(),
                 }),
                 // ClaimPackageRoyalty
-                11 => {
+                14 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     Some(InstructionV1::CallMethod {
@@ -301,17 +320,17 @@ impl TxFuzzer {
                     })
                 }
                 // ClearAuthZone
-                12 => Some(InstructionV1::ClearAuthZone),
+                15 => Some(InstructionV1::ClearAuthZone),
                 // ClearSignatureProofs
-                13 => Some(InstructionV1::ClearSignatureProofs),
+                16 => Some(InstructionV1::ClearSignatureProofs),
                 // CloneProof
-                14 => {
+                17 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::CloneProof { proof_id })
                 }
                 // CreateAccessController
-                15 => {
+                18 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
@@ -327,7 +346,7 @@ impl TxFuzzer {
                     })
                 }
                 // CreateAccount
-                16 => {
+                19 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = AccountCreateInput::arbitrary(&mut unstructured).unwrap();
@@ -343,7 +362,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateAccountAdvanced
-                17 => {
+                20 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = AccountCreateAdvancedInput::arbitrary(&mut unstructured).unwrap();
@@ -359,7 +378,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateFungibleResource
-                18 => {
+                21 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -377,7 +396,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateFungibleResourceWithInitialSupply
-                19 => {
+                22 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -399,7 +418,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateIdentity
-                20 => {
+                23 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = IdentityCreateInput::arbitrary(&mut unstructured).unwrap();
@@ -415,7 +434,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateIdentityAdvanced
-                21 => {
+                24 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input = IdentityCreateAdvancedInput::arbitrary(&mut unstructured).unwrap();
@@ -431,7 +450,7 @@ impl TxFuzzer {
                     }
                 }
                 // CreateNonFungibleResource
-                22 => {
+                25 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -450,7 +469,7 @@ impl TxFuzzer {
                 }
 
                 // CreateNonFungibleResourceWithInitialSupply
-                23 => {
+                26 => {
                     package_addresses.push(PackageAddress::arbitrary(&mut unstructured).unwrap());
                     let package_address = *unstructured.choose(&package_addresses[..]).unwrap();
                     let input =
@@ -472,9 +491,9 @@ impl TxFuzzer {
                     }
                 }
                 // CreateProofFromAuthZoneofAll
-                24 => Some(InstructionV1::CreateProofFromAuthZoneOfAll { resource_address }),
+                27 => Some(InstructionV1::CreateProofFromAuthZoneOfAll { resource_address }),
                 // CreateProofFromAuthZoneOfAmount
-                25 => {
+                28 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CreateProofFromAuthZoneOfAmount {
@@ -483,32 +502,32 @@ impl TxFuzzer {
                     })
                 }
                 // CreateProofFromAuthZoneOfNonFungibles
-                26 => Some(InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                29 => Some(InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
                     ids: non_fungible_ids.clone(),
                     resource_address,
                 }),
                 // CreateProofFromBucketOfAll
-                27 => {
+                30 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfAll { bucket_id })
                 }
                 // CreateProofFromBucketOfAmount
-                28 => {
+                31 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount })
                 }
                 // CreateProofFromBucketOfNonFungibles
-                29 => {
+                32 => {
                     let ids = non_fungible_ids.clone();
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids })
                 }
                 // CreateValidator
-                30 => {
+                33 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     let input = ConsensusManagerCreateValidatorManifestInput {
@@ -526,16 +545,20 @@ impl TxFuzzer {
                         Err(_) => None,
                     }
                 }
+                // DropAllBucketsInto
+                34 => Some(InstructionV1::DropAllBucketsInto {
+                    account_address: component_address.into(),
+                }),
                 // DropAllProofs
-                31 => Some(InstructionV1::DropAllProofs),
+                35 => Some(InstructionV1::DropAllProofs),
                 // DropProof
-                32 => {
+                36 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::DropProof { proof_id })
                 }
                 // FreezeVault
-                33 => {
+                37 => {
                     let vault_id = {
                         let vaults = self
                             .runner
@@ -560,7 +583,7 @@ impl TxFuzzer {
                     }
                 }
                 // LockComponentRoyalty
-                34 => {
+                38 => {
                     let method = String::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CallRoyaltyMethod {
@@ -570,7 +593,7 @@ impl TxFuzzer {
                     })
                 }
                 // LockMetadata
-                35 => {
+                39 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -582,7 +605,7 @@ impl TxFuzzer {
                     })
                 }
                 // LockOwnerRole
-                36 => {
+                40 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input =
@@ -598,7 +621,7 @@ impl TxFuzzer {
                     }
                 }
                 // MintFungible
-                37 => {
+                41 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::CallMethod {
@@ -608,7 +631,7 @@ impl TxFuzzer {
                     })
                 }
                 // MintNonFungible
-                38 => {
+                42 => {
                     let input =
                         NonFungibleResourceManagerMintManifestInput::arbitrary(&mut unstructured)
                             .unwrap();
@@ -622,8 +645,14 @@ impl TxFuzzer {
                         Err(_) => None,
                     }
                 }
+                // MintNonFungibleFromBlobs
+                43 => {
+                    // Minting from blobs requires the blobs to be attached to the transaction.
+                    // TODO: attach arbitrary blobs to the fuzzed transaction
+                    None
+                }
                 // MintRuidNonFungible
-                39 => {
+                44 => {
                     let input = NonFungibleResourceManagerMintRuidManifestInput::arbitrary(
                         &mut unstructured,
                     )
@@ -638,10 +667,23 @@ impl TxFuzzer {
                         Err(_) => None,
                     }
                 }
+                // PauseComponent
+                45 => {
+                    let input = AccessRulesPauseInput::arbitrary(&mut unstructured).unwrap();
+
+                    match to_manifest_value(&input) {
+                        Ok(args) => Some(InstructionV1::CallAccessRulesMethod {
+                            address: component_address.into(),
+                            method_name: ACCESS_RULES_PAUSE_IDENT.to_string(),
+                            args,
+                        }),
+                        Err(_) => None,
+                    }
+                }
                 // PopFromAuthZone
-                40 => Some(InstructionV1::PopFromAuthZone {}),
+                46 => Some(InstructionV1::PopFromAuthZone {}),
                 // PublishPackage | PublishPackageAdvanced
-                41 | 42 => {
+                47 | 48 => {
                     // Publishing package involves a compilation by scrypto compiler.
                     // In case of AFL invoking external tool breaks fuzzing.
                     // For now we skip this step
@@ -650,13 +692,13 @@ impl TxFuzzer {
                     None
                 }
                 // PushToAuthZone
-                43 => {
+                49 => {
                     let proof_id = *unstructured.choose(&proof_ids[..]).unwrap();
 
                     Some(InstructionV1::PushToAuthZone { proof_id })
                 }
                 // RecallFromVault
-                44 => {
+                50 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
                     let vault_id = {
                         let vaults = self
@@ -678,7 +720,7 @@ impl TxFuzzer {
                     })
                 }
                 // RecallNonFungiblesFromVault
-                45 => {
+                51 => {
                     let input = NonFungibleVaultRecallNonFungiblesInput {
                         non_fungible_local_ids: BTreeSet::from_iter(
                             non_fungible_ids.clone().into_iter(),
@@ -695,7 +737,7 @@ impl TxFuzzer {
                     }
                 }
                 // RemoveMetadata
-                46 => {
+                52 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -706,14 +748,39 @@ impl TxFuzzer {
                         args: manifest_args!(key).into(),
                     })
                 }
+                // RenounceRole
+                53 => {
+                    global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
+                    let address = *unstructured.choose(&global_addresses[..]).unwrap();
+                    let input = AccessRulesRenounceRoleInput::arbitrary(&mut unstructured).unwrap();
+
+                    match to_manifest_value(&input) {
+                        Ok(args) => Some(InstructionV1::CallAccessRulesMethod {
+                            address: address.into(),
+                            method_name: ACCESS_RULES_RENOUNCE_ROLE_IDENT.to_string(),
+                            args,
+                        }),
+                        Err(_) => None,
+                    }
+                }
                 // ReturnToWorktop
-                47 => {
+                54 => {
                     let bucket_id = *unstructured.choose(&buckets[..]).unwrap();
 
                     Some(InstructionV1::ReturnToWorktop { bucket_id })
                 }
+                // SetComponentConfig
+                55 => {
+                    let config = Vec::<u8>::arbitrary(&mut unstructured).unwrap();
+
+                    Some(InstructionV1::CallMethod {
+                        address: component_address.into(),
+                        method_name: COMPONENT_CONFIG_SET_IDENT.to_string(),
+                        args: manifest_args!(config).into(),
+                    })
+                }
                 // SetComponentRoyalty
-                48 => {
+                56 => {
                     let method = String::arbitrary(&mut unstructured).unwrap();
                     let amount = RoyaltyAmount::arbitrary(&mut unstructured).unwrap();
 
@@ -724,7 +791,7 @@ impl TxFuzzer {
                     })
                 }
                 // SetMetadata
-                49 => {
+                57 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let key = String::arbitrary(&mut unstructured).unwrap();
@@ -737,7 +804,7 @@ impl TxFuzzer {
                     })
                 }
                 // SetOwnerRole
-                50 => {
+                58 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input = AccessRulesSetOwnerRoleInput::arbitrary(&mut unstructured).unwrap();
@@ -752,7 +819,7 @@ impl TxFuzzer {
                     }
                 }
                 // SetRole
-                51 => {
+                59 => {
                     global_addresses.push(GlobalAddress::arbitrary(&mut unstructured).unwrap());
                     let address = *unstructured.choose(&global_addresses[..]).unwrap();
                     let input = AccessRulesSetRoleInput::arbitrary(&mut unstructured).unwrap();
@@ -767,9 +834,9 @@ impl TxFuzzer {
                     }
                 }
                 // TakeAllFromWorktop
-                52 => Some(InstructionV1::TakeAllFromWorktop { resource_address }),
+                60 => Some(InstructionV1::TakeAllFromWorktop { resource_address }),
                 // TakeFromWorktop
-                53 => {
+                61 => {
                     let amount = Decimal::arbitrary(&mut unstructured).unwrap();
 
                     Some(InstructionV1::TakeFromWorktop {
@@ -778,12 +845,12 @@ impl TxFuzzer {
                     })
                 }
                 // TakeNonFungiblesFromWorktop
-                54 => Some(InstructionV1::TakeNonFungiblesFromWorktop {
+                62 => Some(InstructionV1::TakeNonFungiblesFromWorktop {
                     ids: non_fungible_ids.clone(),
                     resource_address,
                 }),
                 // UnfreezeVault
-                55 => {
+                63 => {
                     let vault_id = {
                         let vaults = self
                             .runner
@@ -808,6 +875,13 @@ impl TxFuzzer {
                     }
                 }
 
+                // UnpauseComponent
+                64 => Some(InstructionV1::CallAccessRulesMethod {
+                    address: component_address.into(),
+                    method_name: ACCESS_RULES_UNPAUSE_IDENT.to_string(),
+                    args: manifest_args!().into(),
+                }),
+
                 // If you encounter below error you can check what are the current instructions
                 // using below command:
                 //   cat transaction/src/manifest/ast.rs | awk '/pub enum Instruction/,/^}/ {print $0}' | grep -E "^[ ]*[A-Z][a-zA-Z]*" | sed -E "s/[ ,\{\}]//g" | sort | awk '{print NR-1"\t"$0}'
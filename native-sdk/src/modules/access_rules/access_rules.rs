@@ -1,7 +1,8 @@
 use radix_engine_interface::api::node_modules::auth::{
-    AccessRulesCreateInput, AccessRulesLockOwnerRoleInput, AccessRulesSetOwnerRoleInput,
-    AccessRulesSetRoleInput, ACCESS_RULES_BLUEPRINT, ACCESS_RULES_CREATE_IDENT,
-    ACCESS_RULES_SET_OWNER_ROLE_IDENT, ACCESS_RULES_SET_ROLE_IDENT,
+    AccessRulesCreateInput, AccessRulesLockOwnerRoleInput, AccessRulesRenounceRoleInput,
+    AccessRulesSetOwnerRoleInput, AccessRulesSetRoleInput, ACCESS_RULES_BLUEPRINT,
+    ACCESS_RULES_CREATE_IDENT, ACCESS_RULES_RENOUNCE_ROLE_IDENT, ACCESS_RULES_SET_OWNER_ROLE_IDENT,
+    ACCESS_RULES_SET_ROLE_IDENT,
 };
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::ClientApi;
@@ -121,4 +122,26 @@ pub trait AccessRulesObject {
 
         Ok(())
     }
+
+    fn renounce_role<Y: ClientApi<E>, E: Debug + ScryptoDecode, R: Into<RoleKey>>(
+        &self,
+        module: ObjectModuleId,
+        role_key: R,
+        api: &mut Y,
+    ) -> Result<(), E> {
+        let (node_id, module_id) = self.self_id();
+        let _rtn = api.call_method_advanced(
+            node_id,
+            false,
+            module_id,
+            ACCESS_RULES_RENOUNCE_ROLE_IDENT,
+            scrypto_encode(&AccessRulesRenounceRoleInput {
+                module,
+                role_key: role_key.into(),
+            })
+            .unwrap(),
+        )?;
+
+        Ok(())
+    }
 }
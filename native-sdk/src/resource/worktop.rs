@@ -167,6 +167,58 @@ impl Worktop {
         Ok(())
     }
 
+    pub fn assert_contains_exactly<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let _rtn = api.call_method(
+            self.0.as_node_id(),
+            WORKTOP_ASSERT_CONTAINS_EXACTLY_IDENT,
+            scrypto_encode(&WorktopAssertContainsExactlyInput {
+                resource_address,
+                amount,
+            })
+            .unwrap(),
+        )?;
+        Ok(())
+    }
+
+    pub fn assert_resources_only<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        resource_addresses: BTreeSet<ResourceAddress>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let _rtn = api.call_method(
+            self.0.as_node_id(),
+            WORKTOP_ASSERT_RESOURCES_ONLY_IDENT,
+            scrypto_encode(&WorktopAssertResourcesOnlyInput { resource_addresses }).unwrap(),
+        )?;
+        Ok(())
+    }
+
+    pub fn contents<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        api: &mut Y,
+    ) -> Result<WorktopContentsOutput, E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            WORKTOP_CONTENTS_IDENT,
+            scrypto_encode(&WorktopContentsInput {}).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn drain<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
         &self,
         api: &mut Y,
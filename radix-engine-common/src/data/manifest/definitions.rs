@@ -97,6 +97,12 @@ pub fn manifest_decode<T: ManifestDecode>(buf: &[u8]) -> Result<T, DecodeError>
         .decode_payload(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)
 }
 
+/// Decodes a payload with a strict decoder, rejecting non-canonical encodings.
+pub fn manifest_decode_strict<T: ManifestDecode>(buf: &[u8]) -> Result<T, DecodeError> {
+    ManifestDecoder::new_strict(buf, MANIFEST_SBOR_V1_MAX_DEPTH)
+        .decode_payload(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)
+}
+
 pub fn to_manifest_value<T: ManifestEncode + ?Sized>(
     value: &T,
 ) -> Result<ManifestValue, RustToManifestValueError> {
@@ -134,4 +134,26 @@ mod tests {
         assert_eq!(actual_rustlike, expected);
         assert_eq!(actual_nested, expected);
     }
+
+    #[test]
+    fn test_rustlike_string_format_of_named_custom_value() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let (type_index, schema) =
+            generate_full_schema_from_single_type::<ResourceAddress, ScryptoCustomSchema>();
+        let payload =
+            ScryptoRawPayload::new_from_valid_owned(scrypto_encode(&FUNGIBLE_RESOURCE).unwrap());
+
+        let actual = payload.to_string(ValueDisplayParameters::Annotated {
+            display_mode: DisplayMode::RustLike,
+            print_mode: PrintMode::SingleLine,
+            custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder)),
+            schema: &schema,
+            type_index,
+        });
+
+        assert_eq!(
+            actual,
+            format!("ResourceAddress(Reference(\"{FUNGIBLE_RESOURCE_SIM_ADDRESS}\"))")
+        );
+    }
 }
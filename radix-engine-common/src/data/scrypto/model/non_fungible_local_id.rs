@@ -381,6 +381,20 @@ pub enum ParseNonFungibleLocalIdError {
     ContentValidationError(ContentValidationError),
 }
 
+/// The part of a string which failed to parse as a non-fungible id, in Unicode code points
+/// from the start of the string, with an exclusive end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseNonFungibleLocalIdErrorSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl ParseNonFungibleLocalIdErrorSpan {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+}
+
 #[cfg(not(feature = "alloc"))]
 impl std::error::Error for ParseNonFungibleLocalIdError {}
 
@@ -507,47 +521,104 @@ impl FromStr for NonFungibleLocalId {
     type Err = ParseNonFungibleLocalIdError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let local_id = if s.starts_with("<") && s.ends_with(">") {
-            Self::string(s[1..s.len() - 1].to_string())
-                .map_err(ParseNonFungibleLocalIdError::ContentValidationError)?
+        Self::parse_with_error_span(s).map_err(|(error, _)| error)
+    }
+}
+
+impl NonFungibleLocalId {
+    /// Parses the canonical string form of an id of any kind, as produced by its `Display`.
+    ///
+    /// On failure, also returns the span of the string which is invalid, e.g. the first bad
+    /// character, so that tools can point at it.
+    pub fn parse_with_error_span(
+        s: &str,
+    ) -> Result<
+        Self,
+        (
+            ParseNonFungibleLocalIdError,
+            ParseNonFungibleLocalIdErrorSpan,
+        ),
+    > {
+        let length = s.chars().count();
+        if length < 2 {
+            return Err((
+                ParseNonFungibleLocalIdError::UnknownType,
+                ParseNonFungibleLocalIdErrorSpan::new(0, length),
+            ));
+        }
+        // The delimiters are all ASCII, so the content can be sliced by bytes
+        let content = &s[1..s.len() - 1];
+        let content_span = ParseNonFungibleLocalIdErrorSpan::new(1, length - 1);
+        let first_char_not_matching = |f: &dyn Fn(usize, char) -> bool| {
+            content
+                .chars()
+                .enumerate()
+                .find(|(i, c)| !f(*i, *c))
+                .map(|(i, _)| ParseNonFungibleLocalIdErrorSpan::new(1 + i, 2 + i))
+        };
+
+        if s.starts_with("<") && s.ends_with(">") {
+            Self::string(content).map_err(|error| {
+                let span = match &error {
+                    ContentValidationError::ContainsBadCharacter(bad_char) => {
+                        first_char_not_matching(&|_, c| c != *bad_char)
+                    }
+                    _ => None,
+                };
+                (
+                    ParseNonFungibleLocalIdError::ContentValidationError(error),
+                    span.unwrap_or(content_span),
+                )
+            })
         } else if s.starts_with("#") && s.ends_with("#") {
-            let digits = &s[1..s.len() - 1];
-            if !is_canonically_formatted_integer(digits) {
-                return Err(ParseNonFungibleLocalIdError::InvalidInteger);
+            if !is_canonically_formatted_integer(content) {
+                let span = first_char_not_matching(&|_, c| c.is_ascii_digit());
+                return Err((
+                    ParseNonFungibleLocalIdError::InvalidInteger,
+                    span.unwrap_or(content_span),
+                ));
             }
-            NonFungibleLocalId::integer(
-                u64::from_str_radix(&s[1..s.len() - 1], 10)
-                    .map_err(|_| ParseNonFungibleLocalIdError::InvalidInteger)?,
-            )
+            u64::from_str_radix(content, 10)
+                .map(NonFungibleLocalId::integer)
+                .map_err(|_| (ParseNonFungibleLocalIdError::InvalidInteger, content_span))
         } else if s.starts_with("[") && s.ends_with("]") {
-            NonFungibleLocalId::bytes(
-                hex::decode(&s[1..s.len() - 1])
-                    .map_err(|_| ParseNonFungibleLocalIdError::InvalidBytes)?,
-            )
-            .map_err(ParseNonFungibleLocalIdError::ContentValidationError)?
+            if let Some(span) = first_char_not_matching(&|_, c| c.is_ascii_hexdigit()) {
+                return Err((ParseNonFungibleLocalIdError::InvalidBytes, span));
+            }
+            let bytes = hex::decode(content)
+                .map_err(|_| (ParseNonFungibleLocalIdError::InvalidBytes, content_span))?;
+            NonFungibleLocalId::bytes(bytes).map_err(|error| {
+                (
+                    ParseNonFungibleLocalIdError::ContentValidationError(error),
+                    content_span,
+                )
+            })
         } else if s.starts_with("{") && s.ends_with("}") {
-            let chars: Vec<char> = s[1..s.len() - 1].chars().collect();
-            if chars.len() == 32 * 2 + 3 && chars[16] == '-' && chars[33] == '-' && chars[50] == '-'
-            {
-                let hyphen_stripped: String = chars.into_iter().filter(|c| *c != '-').collect();
-                if hyphen_stripped.len() == 64 {
-                    NonFungibleLocalId::RUID(RUIDNonFungibleLocalId(
-                        hex::decode(&hyphen_stripped)
-                            .map_err(|_| ParseNonFungibleLocalIdError::InvalidRUID)?
-                            .try_into()
-                            .unwrap(),
-                    ))
-                } else {
-                    return Err(ParseNonFungibleLocalIdError::InvalidRUID);
-                }
-            } else {
-                return Err(ParseNonFungibleLocalIdError::InvalidRUID);
+            const RUID_LENGTH: usize = 32 * 2 + 3;
+            let is_ruid_char = |i: usize, c: char| match i {
+                16 | 33 | 50 => c == '-',
+                i if i < RUID_LENGTH => c.is_ascii_hexdigit(),
+                _ => false,
+            };
+            if let Some(span) = first_char_not_matching(&is_ruid_char) {
+                return Err((ParseNonFungibleLocalIdError::InvalidRUID, span));
+            }
+            if content.len() != RUID_LENGTH {
+                return Err((ParseNonFungibleLocalIdError::InvalidRUID, content_span));
             }
+            let hyphen_stripped: String = content.chars().filter(|c| *c != '-').collect();
+            Ok(NonFungibleLocalId::RUID(RUIDNonFungibleLocalId(
+                hex::decode(&hyphen_stripped)
+                    .map_err(|_| (ParseNonFungibleLocalIdError::InvalidRUID, content_span))?
+                    .try_into()
+                    .unwrap(),
+            )))
         } else {
-            return Err(ParseNonFungibleLocalIdError::UnknownType);
-        };
-
-        Ok(local_id)
+            Err((
+                ParseNonFungibleLocalIdError::UnknownType,
+                ParseNonFungibleLocalIdErrorSpan::new(0, length),
+            ))
+        }
     }
 }
 
@@ -732,4 +803,135 @@ mod tests {
             "[010a]"
         );
     }
+
+    #[test]
+    fn test_from_str_error_spans() {
+        let span = |s: &str| {
+            NonFungibleLocalId::parse_with_error_span(s)
+                .map_err(|(error, span)| (error, span.start, span.end))
+        };
+
+        // Too short to have delimiters
+        assert_eq!(
+            span("#"),
+            Err((ParseNonFungibleLocalIdError::UnknownType, 0, 1))
+        );
+        assert_eq!(
+            span(""),
+            Err((ParseNonFungibleLocalIdError::UnknownType, 0, 0))
+        );
+        assert_eq!(
+            span("(1)"),
+            Err((ParseNonFungibleLocalIdError::UnknownType, 0, 3))
+        );
+        // The first offending character is pointed at
+        assert_eq!(
+            span("#12x4#"),
+            Err((ParseNonFungibleLocalIdError::InvalidInteger, 3, 4))
+        );
+        assert_eq!(
+            span("#18446744073709551616#"),
+            Err((ParseNonFungibleLocalIdError::InvalidInteger, 1, 21))
+        );
+        assert_eq!(
+            span("[01zz]"),
+            Err((ParseNonFungibleLocalIdError::InvalidBytes, 3, 4))
+        );
+        assert_eq!(
+            span("[012]"),
+            Err((ParseNonFungibleLocalIdError::InvalidBytes, 1, 4))
+        );
+        assert_eq!(
+            span("<\u{2764}ab\u{2764}>"),
+            Err((
+                ParseNonFungibleLocalIdError::ContentValidationError(
+                    ContentValidationError::ContainsBadCharacter('\u{2764}')
+                ),
+                1,
+                2
+            ))
+        );
+        assert_eq!(
+            span("<>"),
+            Err((
+                ParseNonFungibleLocalIdError::ContentValidationError(ContentValidationError::Empty),
+                1,
+                1
+            ))
+        );
+        assert_eq!(
+            span("{1111111111111111_1111111111111111-1111111111111111-1111111111111111}"),
+            Err((ParseNonFungibleLocalIdError::InvalidRUID, 17, 18))
+        );
+        assert_eq!(
+            span("{1111111111111111-1111111111111111-1111111111111111-11111111111111}"),
+            Err((ParseNonFungibleLocalIdError::InvalidRUID, 1, 66))
+        );
+        assert_eq!(
+            span("{1111111111111111-1111111111111111-1111111111111111-111111111111111111}"),
+            Err((ParseNonFungibleLocalIdError::InvalidRUID, 68, 69))
+        );
+    }
+
+    /// A small deterministic generator, so the round trip covers many ids without extra
+    /// dependencies.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn next_below(&mut self, bound: usize) -> usize {
+            (self.next() % bound as u64) as usize
+        }
+    }
+
+    fn assert_round_trips(id: NonFungibleLocalId) {
+        assert_eq!(NonFungibleLocalId::from_str(&id.to_string()), Ok(id));
+    }
+
+    #[test]
+    fn test_display_then_from_str_round_trips_for_all_id_kinds() {
+        const STRING_CHARS: &[u8] =
+            b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
+        let mut rng = XorShift(0x2545f4914f6cdd1d);
+
+        // Boundaries
+        assert_round_trips(NonFungibleLocalId::integer(0));
+        assert_round_trips(NonFungibleLocalId::integer(u64::MAX));
+        assert_round_trips(NonFungibleLocalId::bytes(vec![0]).unwrap());
+        assert_round_trips(
+            NonFungibleLocalId::bytes([0xff; NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH]).unwrap(),
+        );
+        assert_round_trips(NonFungibleLocalId::string("_").unwrap());
+        assert_round_trips(
+            NonFungibleLocalId::string(string_of_length(NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH)).unwrap(),
+        );
+        assert_round_trips(NonFungibleLocalId::ruid([0; 32]));
+        assert_round_trips(NonFungibleLocalId::ruid([0xff; 32]));
+
+        for _ in 0..1000 {
+            assert_round_trips(NonFungibleLocalId::integer(
+                rng.next() >> rng.next_below(64),
+            ));
+
+            let length = 1 + rng.next_below(NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH);
+            let bytes: Vec<u8> = (0..length).map(|_| rng.next() as u8).collect();
+            assert_round_trips(NonFungibleLocalId::bytes(bytes).unwrap());
+
+            let length = 1 + rng.next_below(NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH);
+            let string: String = (0..length)
+                .map(|_| STRING_CHARS[rng.next_below(STRING_CHARS.len())] as char)
+                .collect();
+            assert_round_trips(NonFungibleLocalId::string(string).unwrap());
+
+            let mut ruid = [0u8; 32];
+            ruid.iter_mut().for_each(|b| *b = rng.next() as u8);
+            assert_round_trips(NonFungibleLocalId::ruid(ruid));
+        }
+    }
 }
@@ -106,6 +106,24 @@ impl Decimal {
         self.round(0, RoundingMode::ToPositiveInfinity)
     }
 
+    /// Formats this number with exactly the specified decimal places (padding with trailing
+    /// zeros, truncating any extra digits), e.g. to render an amount of a fungible resource
+    /// according to its divisibility.
+    pub fn to_string_with_decimal_places(&self, decimal_places: u8) -> String {
+        let decimal_places = decimal_places.min(Self::SCALE as u8);
+        let rounded = self.round(decimal_places, RoundingMode::ToZero).to_string();
+        if decimal_places == 0 {
+            return rounded;
+        }
+        let (integral, fractional) = rounded.split_once('.').unwrap_or((&rounded, ""));
+        format!(
+            "{}.{:0<width$}",
+            integral,
+            fractional,
+            width = decimal_places as usize
+        )
+    }
+
     /// Rounds this number to the specified decimal places.
     ///
     /// # Panics
@@ -656,6 +674,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_decimal_with_decimal_places() {
+        assert_eq!(dec!("1.5").to_string_with_decimal_places(2), "1.50");
+        assert_eq!(dec!("1.5").to_string_with_decimal_places(0), "1");
+        assert_eq!(dec!("-0.125").to_string_with_decimal_places(2), "-0.12");
+        assert_eq!(dec!("-0.001").to_string_with_decimal_places(2), "0.00");
+        assert_eq!(dec!("42").to_string_with_decimal_places(3), "42.000");
+        assert_eq!(
+            Decimal(1i128.into()).to_string_with_decimal_places(18),
+            "0.000000000000000001"
+        );
+        assert_eq!(
+            Decimal::ONE.to_string_with_decimal_places(u8::MAX),
+            "1.000000000000000000"
+        );
+    }
+
     #[test]
     fn test_parse_decimal() {
         assert_eq!(
@@ -192,6 +192,36 @@ pub const TRANSACTION_TRACKER_PACKAGE: PackageAddress = PackageAddress::new_or_p
 ]);
 pub const TRANSACTION_TRACKER_BLUEPRINT: &str = "TransactionTracker";
 
+/// The native package for hook registries.
+pub const HOOK_REGISTRY_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 102, 243, 205, 140, 204, 99, 24, 198, 49, 140, 247, 170, 173,
+    202, 141, 153, 74, 38, 49, 140, 99, 24, 198,
+]);
+
+/// The native package for payment streams.
+pub const PAYMENT_STREAM_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 104, 44, 121, 238, 204, 99, 24, 198, 49, 140, 247, 169, 216,
+    249, 241, 165, 218, 230, 49, 140, 99, 24, 198,
+]);
+
+/// All the packages implemented natively by the engine.
+pub const NATIVE_PACKAGES: [PackageAddress; 14] = [
+    PACKAGE_PACKAGE,
+    RESOURCE_PACKAGE,
+    ACCOUNT_PACKAGE,
+    IDENTITY_PACKAGE,
+    CONSENSUS_MANAGER_PACKAGE,
+    ACCESS_CONTROLLER_PACKAGE,
+    POOL_PACKAGE,
+    TRANSACTION_PROCESSOR_PACKAGE,
+    METADATA_MODULE_PACKAGE,
+    ROYALTY_MODULE_PACKAGE,
+    ACCESS_RULES_MODULE_PACKAGE,
+    TRANSACTION_TRACKER_PACKAGE,
+    HOOK_REGISTRY_PACKAGE,
+    PAYMENT_STREAM_PACKAGE,
+];
+
 //=========================================================================
 // SYSTEM SINGLETON COMPONENTS - NATIVE
 //=========================================================================
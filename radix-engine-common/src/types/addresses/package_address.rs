@@ -72,6 +72,8 @@ impl PackageAddress {
             || self.0 == native_addresses::ROYALTY_MODULE_PACKAGE.0
             || self.0 == native_addresses::ACCESS_RULES_MODULE_PACKAGE.0
             || self.0 == native_addresses::TRANSACTION_TRACKER_PACKAGE.0
+            || self.0 == native_addresses::HOOK_REGISTRY_PACKAGE.0
+            || self.0 == native_addresses::PAYMENT_STREAM_PACKAGE.0
     }
 }
 
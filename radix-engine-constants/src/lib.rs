@@ -83,6 +83,9 @@ pub const DEFAULT_MAX_NUMBER_OF_SUBSTATES_IN_TRACK: usize = 512;
 /// The default max number of substates in heap.
 pub const DEFAULT_MAX_NUMBER_OF_SUBSTATES_IN_HEAP: usize = 512;
 
+/// The default max number of global entities (components, resources, packages) a transaction can create.
+pub const DEFAULT_MAX_NUMBER_OF_NEW_GLOBAL_ENTITIES: usize = 128;
+
 /// The default maximum substate read and write size.
 pub const DEFAULT_MAX_SUBSTATE_SIZE: usize = 2 * 1024 * 1024;
 
@@ -116,6 +119,12 @@ pub const DEFAULT_MAX_NUMBER_OF_EVENTS: usize = 256;
 /// The max number of logs
 pub const DEFAULT_MAX_NUMBER_OF_LOGS: usize = 256;
 
+/// The max total size of all events emitted by a transaction
+pub const DEFAULT_MAX_TOTAL_EVENT_SIZE: usize = 1024 * 1024;
+
+/// The max total size of all logs emitted by a transaction
+pub const DEFAULT_MAX_TOTAL_LOG_SIZE: usize = 1024 * 1024;
+
 /// The max SBOR size of metadata key
 pub const DEFAULT_MAX_METADATA_KEY_STRING_LEN: usize = 100;
 
@@ -137,3 +146,6 @@ pub const DEFAULT_USD_PRICE_IN_XRD: &str = "10";
 
 /// The default maximum that a package or component owner is allowed to set their method royalty to
 pub const DEFAULT_MAX_PER_FUNCTION_ROYALTY_IN_XRD: &str = "150.0";
+
+/// The default maximum of package and component royalties charged for a single call, in XRD
+pub const DEFAULT_MAX_PER_CALL_ROYALTY_IN_XRD: &str = "300.0";
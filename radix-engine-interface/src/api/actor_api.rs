@@ -1,4 +1,6 @@
+use crate::api::key_value_entry_api::KeyValueEntryHandle;
 use crate::api::{FieldIndex, ObjectModuleId};
+use crate::blueprints::resource::GlobalCaller;
 use crate::types::*;
 use radix_engine_interface::api::{LockFlags, ObjectHandle};
 use sbor::rust::fmt::Debug;
@@ -14,6 +16,23 @@ pub trait ClientActorApi<E: Debug> {
         flags: LockFlags,
     ) -> Result<LockHandle, E>;
 
+    /// Opens an entry of the transient store of the current object actor.
+    ///
+    /// Transient entries are visible for the rest of the transaction but are never committed.
+    /// If the entry doesn't exist, it uses the default "Option::None".
+    fn actor_open_transient_entry(
+        &mut self,
+        object_handle: ObjectHandle,
+        key: &Vec<u8>,
+        flags: LockFlags,
+    ) -> Result<KeyValueEntryHandle, E>;
+
+    fn actor_remove_transient_entry(
+        &mut self,
+        object_handle: ObjectHandle,
+        key: &Vec<u8>,
+    ) -> Result<Vec<u8>, E>;
+
     // TODO: do we need more granular interfaces for this?
     fn actor_get_info(&mut self) -> Result<ObjectInfo, E>;
 
@@ -21,6 +40,9 @@ pub trait ClientActorApi<E: Debug> {
 
     fn actor_get_global_address(&mut self) -> Result<GlobalAddress, E>;
 
+    /// Returns the global component or blueprint which called the current actor, if any
+    fn actor_get_global_caller(&mut self) -> Result<Option<GlobalCaller>, E>;
+
     fn actor_get_blueprint(&mut self) -> Result<BlueprintId, E>;
 
     fn actor_call_module_method(
@@ -0,0 +1,93 @@
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+/// The fixed-width integer types supported by the engine's big integer arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sbor)]
+pub enum BigIntType {
+    I256,
+    I384,
+    U256,
+    U384,
+}
+
+impl BigIntType {
+    /// The length of an operand or result of this type, in bytes.
+    pub fn byte_len(&self) -> usize {
+        match self {
+            BigIntType::I256 | BigIntType::U256 => 32,
+            BigIntType::I384 | BigIntType::U384 => 48,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, BigIntType::I256 | BigIntType::I384)
+    }
+}
+
+impl TryFrom<u32> for BigIntType {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BigIntType::I256),
+            1 => Ok(BigIntType::I384),
+            2 => Ok(BigIntType::U256),
+            3 => Ok(BigIntType::U384),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sbor)]
+pub enum BigIntOperation {
+    /// `a + b`
+    Add,
+    /// `a - b`
+    Sub,
+    /// `a * b`
+    Mul,
+    /// `a / b`, rounding towards zero
+    Div,
+    /// `base ^ exponent mod modulus`, with the result in `[0, modulus)` for a positive modulus
+    ModPow,
+}
+
+impl BigIntOperation {
+    pub fn operand_count(&self) -> usize {
+        match self {
+            BigIntOperation::Add
+            | BigIntOperation::Sub
+            | BigIntOperation::Mul
+            | BigIntOperation::Div => 2,
+            BigIntOperation::ModPow => 3,
+        }
+    }
+}
+
+impl TryFrom<u32> for BigIntOperation {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BigIntOperation::Add),
+            1 => Ok(BigIntOperation::Sub),
+            2 => Ok(BigIntOperation::Mul),
+            3 => Ok(BigIntOperation::Div),
+            4 => Ok(BigIntOperation::ModPow),
+            _ => Err(()),
+        }
+    }
+}
+
+pub trait ClientBigIntApi<E> {
+    /// Applies the operation to the concatenated operands, each of which is a little-endian
+    /// (two's complement, if signed) integer of `int_type.byte_len()` bytes.
+    ///
+    /// Returns the result in the same encoding, failing rather than wrapping on overflow.
+    fn big_int_op(
+        &mut self,
+        int_type: BigIntType,
+        operation: BigIntOperation,
+        operands: Vec<u8>,
+    ) -> Result<Vec<u8>, E>;
+}
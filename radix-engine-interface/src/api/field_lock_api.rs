@@ -18,6 +18,9 @@ bitflags! {
         /// Forces a write of a substate even on a transaction failure
         /// Currently used for vault fees.
         const FORCE_WRITE = 0b00000100;
+        /// Reads the substate without taking a lock, even if it is currently locked for writing.
+        /// This is only ever set by the system, for reads made within a static call.
+        const UNLOCKED_READ = 0b00001000;
     }
 }
 
@@ -2,6 +2,7 @@ pub mod actor_api;
 pub mod actor_index_api;
 pub mod actor_key_value_entry_api;
 pub mod actor_sorted_index_api;
+pub mod big_int_api;
 pub mod blueprint_api;
 pub mod field_lock_api;
 pub mod key_value_entry_api;
@@ -15,6 +16,7 @@ pub use actor_api::ClientActorApi;
 use actor_index_api::ClientActorIndexApi;
 pub use actor_key_value_entry_api::ClientActorKeyValueEntryApi;
 pub use actor_sorted_index_api::ClientActorSortedIndexApi;
+pub use big_int_api::*;
 pub use blueprint_api::ClientBlueprintApi;
 pub use field_lock_api::ClientFieldLockApi;
 pub use field_lock_api::LockFlags;
@@ -24,7 +26,9 @@ pub use object_api::*;
 pub use system_modules::auth_api::ClientAuthApi;
 pub use system_modules::costing_api::ClientCostingApi;
 pub use system_modules::execution_trace_api::ClientExecutionTraceApi;
-pub use system_modules::transaction_runtime_api::ClientTransactionRuntimeApi;
+pub use system_modules::transaction_runtime_api::{
+    blueprint_error_name, ClientTransactionRuntimeApi,
+};
 
 pub type ObjectHandle = u32;
 
@@ -51,5 +55,6 @@ pub trait ClientApi<E: sbor::rust::fmt::Debug>:
     + ClientTransactionRuntimeApi<E>
     + ClientExecutionTraceApi<E>
     + ClientAuthApi<E>
+    + ClientBigIntApi<E>
 {
 }
@@ -3,6 +3,8 @@ use crate::api::node_modules::metadata::METADATA_BLUEPRINT;
 use crate::constants::{
     ACCESS_RULES_MODULE_PACKAGE, METADATA_MODULE_PACKAGE, ROYALTY_MODULE_PACKAGE,
 };
+use crate::data::scrypto::model::NonFungibleLocalId;
+use crate::math::Decimal;
 use crate::types::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
@@ -156,6 +158,16 @@ pub trait ClientObjectApi<E> {
         self.call_method_advanced(receiver, true, ObjectModuleId::Main, method_name, args)
     }
 
+    /// Calls a method on an object in read-only mode. The callee, and anything it calls, can read
+    /// substates even if they are locked by a caller, but can't write any substates, move any
+    /// buckets or proofs, or make any non-static calls.
+    fn call_static_method(
+        &mut self,
+        receiver: &NodeId,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<u8>, E>;
+
     /// Calls a method on an object module
     fn call_method_advanced(
         &mut self,
@@ -165,4 +177,23 @@ pub trait ClientObjectApi<E> {
         method_name: &str,
         args: Vec<u8>,
     ) -> Result<Vec<u8>, E>;
+
+    /// Moves an amount of a resource from one vault to another, without creating an
+    /// intermediate bucket. Requires the withdraw authority of the source vault and the deposit
+    /// authority of the destination vault.
+    fn transfer_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        amount: Decimal,
+    ) -> Result<(), E>;
+
+    /// Moves the given non-fungibles from one non-fungible vault to another, without creating
+    /// an intermediate bucket. Requires the same authority as `transfer_between_vaults`.
+    fn transfer_non_fungibles_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    ) -> Result<(), E>;
 }
@@ -1,6 +1,7 @@
 use crate::sbor::rust::prelude::*;
 use crate::types::Level;
 use radix_engine_common::crypto::Hash;
+use radix_engine_common::data::scrypto::ScryptoDescribe;
 
 pub trait ClientTransactionRuntimeApi<E> {
     fn get_transaction_hash(&mut self) -> Result<Hash, E>;
@@ -12,4 +13,17 @@ pub trait ClientTransactionRuntimeApi<E> {
     fn emit_event(&mut self, event_name: String, event_data: Vec<u8>) -> Result<(), E>;
 
     fn panic(&mut self, message: String) -> Result<(), E>;
+
+    /// Fails the transaction with a typed error, given as the name of its type and its SBOR
+    /// encoding, which is kept in the receipt.
+    fn fail(&mut self, error_name: String, error: Vec<u8>) -> Result<(), E>;
+}
+
+/// The name a typed error of type `E` is failed with, as described by its schema.
+pub fn blueprint_error_name<E: ScryptoDescribe>() -> String {
+    E::type_data()
+        .metadata
+        .type_name
+        .map(|name| name.to_string())
+        .unwrap_or_default()
 }
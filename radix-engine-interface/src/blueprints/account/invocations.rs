@@ -215,6 +215,26 @@ pub struct AccountCreateProofOfNonFungiblesInput {
 
 pub type AccountCreateProofOfNonFungiblesOutput = Proof;
 
+//================================
+// Account Create Multiple Proofs
+//================================
+
+pub const ACCOUNT_CREATE_PROOFS_IDENT: &str = "create_proofs";
+
+/// A proof to create from the vault of an account.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum AccountProofSpecifier {
+    Amount(ResourceAddress, Decimal),
+    NonFungibles(ResourceAddress, BTreeSet<NonFungibleLocalId>),
+}
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountCreateProofsInput {
+    pub proofs: Vec<AccountProofSpecifier>,
+}
+
+pub type AccountCreateProofsOutput = Vec<Proof>;
+
 //=================================
 // Account Transition Deposit Mode
 //=================================
@@ -242,6 +262,32 @@ pub struct AccountConfigureResourceDepositRuleInput {
 
 pub type AccountConfigureResourceDepositRuleOutput = ();
 
+//============================
+// Add Authorized Depositor
+//============================
+
+pub const ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT: &str = "add_authorized_depositor";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountAddAuthorizedDepositorInput {
+    pub badge: ResourceOrNonFungible,
+}
+
+pub type AccountAddAuthorizedDepositorOutput = ();
+
+//============================
+// Remove Authorized Depositor
+//============================
+
+pub const ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT: &str = "remove_authorized_depositor";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountRemoveAuthorizedDepositorInput {
+    pub badge: ResourceOrNonFungible,
+}
+
+pub type AccountRemoveAuthorizedDepositorOutput = ();
+
 //===============================
 // Account Try Deposit Or Refund
 //===============================
@@ -0,0 +1,11 @@
+/// The public method returning the configuration of a component.
+pub const COMPONENT_CONFIG_GET_IDENT: &str = "get_config";
+
+/// The method replacing the configuration of a component, taking the new configuration.
+pub const COMPONENT_CONFIG_SET_IDENT: &str = "set_config";
+
+/// The role allowed to call `set_config`, falling back to the owner role if not set.
+pub const COMPONENT_CONFIG_ADMIN_ROLE: &str = "config_admin";
+
+/// The event emitted by `set_config`, with the new configuration as its `config` field.
+pub const COMPONENT_CONFIG_UPDATED_EVENT: &str = "ConfigUpdatedEvent";
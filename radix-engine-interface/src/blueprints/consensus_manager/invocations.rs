@@ -7,6 +7,7 @@ use radix_engine_common::time::{Instant, TimeComparisonOperator};
 use radix_engine_common::types::*;
 use radix_engine_interface::crypto::Secp256k1PublicKey;
 use radix_engine_interface::math::Decimal;
+use sbor::rust::collections::BTreeSet;
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
@@ -219,6 +220,54 @@ pub struct ConsensusManagerCompareCurrentTimeInput {
 
 pub type ConsensusManagerCompareCurrentTimeOutput = bool;
 
+/// The maximum number of past epochs (including the current one) whose start times are retained
+/// by the consensus manager and exposed via [`CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT`].
+pub const EPOCH_HISTORY_CAPACITY: usize = 128;
+
+pub const CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT: &str = "get_epoch_history";
+
+#[derive(Debug, Clone, Eq, PartialEq, Sbor)]
+pub struct ConsensusManagerGetEpochHistoryInput;
+
+/// The recorded epoch starts, ordered from the oldest to the most recent (i.e. current) epoch.
+pub type ConsensusManagerGetEpochHistoryOutput = Vec<EpochStartRecord>;
+
+pub const CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT: &str = "set_invocation_filter";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerSetInvocationFilterInput {
+    pub filter: InvocationFilter,
+}
+
+pub type ConsensusManagerSetInvocationFilterOutput = ();
+
+/// A governance-maintained list of packages and components, consulted by the engine before
+/// every invocation when the invocation filter system module is enabled.
+#[derive(Debug, Clone, Default, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub enum InvocationFilter {
+    /// Nothing is filtered.
+    #[default]
+    None,
+    /// Invocations of any listed package, or of any listed component, are rejected.
+    DenyList(BTreeSet<GlobalAddress>),
+    /// Only invocations of native packages, or of listed packages and components, are permitted.
+    AllowList(BTreeSet<GlobalAddress>),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ScryptoSbor)]
+pub struct EpochStartRecord {
+    pub epoch: Epoch,
+    /// The proposer timestamp (in "epoch millis") at which the epoch actually started.
+    pub start_milli: i64,
+}
+
+impl EpochStartRecord {
+    /// Returns the start instant of the epoch, rounded down to seconds.
+    pub fn start_instant(&self) -> Instant {
+        Instant::new(self.start_milli.div_euclid(1000))
+    }
+}
+
 pub const CONSENSUS_MANAGER_NEXT_ROUND_IDENT: &str = "next_round";
 
 #[derive(Debug, Clone, Eq, PartialEq, Sbor)]
@@ -0,0 +1,98 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+use sbor::rust::prelude::*;
+
+pub const HOOK_REGISTRY_BLUEPRINT: &str = "HookRegistry";
+
+/// The role which is allowed to invoke the hooks of a registry.
+pub const HOOK_REGISTRY_INVOKER_ROLE: &str = "hook_invoker";
+
+/// The maximum number of hooks which can be registered for a single event, which bounds the
+/// fan-out of a single `invoke_hooks` call.
+pub const MAX_HOOKS_PER_EVENT: usize = 16;
+
+/// The maximum length of an event name, in bytes.
+pub const MAX_HOOK_EVENT_NAME_LENGTH: usize = 64;
+
+/// The maximum length of the name of a hook method, in bytes.
+pub const MAX_HOOK_METHOD_NAME_LENGTH: usize = 64;
+
+/// A method of a global component which is called when a registry invokes the hooks of an event.
+///
+/// The method is called with the event name and the payload given to `invoke_hooks`, i.e. as
+/// `fn <method_name>(&mut self, event_name: String, payload: Vec<u8>)`, and must return `()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor, ManifestSbor)]
+pub struct RegisteredHook {
+    pub receiver: ComponentAddress,
+    pub method_name: String,
+}
+
+define_invocation! {
+    blueprint_name: HookRegistry,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        hook_invoker_rule: AccessRule
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        hook_invoker_rule: AccessRule
+    }
+}
+
+define_invocation! {
+    blueprint_name: HookRegistry,
+    function_name: register_hook,
+    input: struct {
+        event_name: String,
+        hook: RegisteredHook
+    },
+    output: type (),
+    manifest_input: struct {
+        event_name: String,
+        hook: RegisteredHook
+    }
+}
+
+define_invocation! {
+    blueprint_name: HookRegistry,
+    function_name: unregister_hook,
+    input: struct {
+        event_name: String,
+        hook: RegisteredHook
+    },
+    output: type (),
+    manifest_input: struct {
+        event_name: String,
+        hook: RegisteredHook
+    }
+}
+
+define_invocation! {
+    blueprint_name: HookRegistry,
+    function_name: invoke_hooks,
+    input: struct {
+        event_name: String,
+        payload: Vec<u8>
+    },
+    output: type Vec<RegisteredHook>,
+    manifest_input: struct {
+        event_name: String,
+        payload: Vec<u8>
+    }
+}
+
+define_invocation! {
+    blueprint_name: HookRegistry,
+    function_name: get_hooks,
+    input: struct {
+        event_name: String
+    },
+    output: type Vec<RegisteredHook>,
+    manifest_input: struct {
+        event_name: String
+    }
+}
@@ -0,0 +1,681 @@
+//! Exports the interface of a package as JSON Schema and TypeScript declarations, so that
+//! typed clients can be generated directly from a [`PackageDefinition`].
+//!
+//! The exported types describe a JSON-native representation of the SBOR values:
+//! * Structs with named fields become objects, other tuples become fixed-length arrays.
+//! * Enum values become `{ "variant": <name>, "fields": ... }` objects, with `fields` omitted
+//!   for variants without fields.
+//! * Integers which can't be represented safely as a JS number, decimals, addresses and
+//!   non-fungible local ids become strings. Byte arrays become hex strings.
+//! * Maps with string keys become objects, other maps become arrays of `{ key, value }` objects.
+
+use super::PackageDefinition;
+use crate::blueprints::resource::NonFungibleDataSchema;
+use crate::schema::{BlueprintSchemaInit, TypeRef};
+use radix_engine_common::data::scrypto::{ScryptoCustomTypeKind, ScryptoSchema};
+use sbor::rust::prelude::*;
+use sbor::{ChildNames, LocalTypeIndex, TypeKind, TypeMetadata};
+use serde_json::{json, Map, Value};
+
+/// A type, as seen by a client of a blueprint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientType {
+    Any,
+    Boolean,
+    /// An integer which fits into a JS number
+    Integer {
+        min: i64,
+        max: i64,
+    },
+    /// A value represented as a JSON string, with an optional format hint
+    String {
+        format: Option<&'static str>,
+    },
+    Array(Box<ClientType>),
+    Tuple(Vec<ClientType>),
+    Object(Vec<(String, ClientType)>),
+    StringKeyedMap(Box<ClientType>),
+    EntryList {
+        key: Box<ClientType>,
+        value: Box<ClientType>,
+    },
+    Enum(Vec<ClientEnumVariant>),
+    /// A reference to a named type of the same namespace
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientEnumVariant {
+    pub name: String,
+    pub fields: Option<ClientType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientFunction {
+    pub is_method: bool,
+    pub input: ClientType,
+    pub output: ClientType,
+}
+
+/// The client types of a single blueprint or non-fungible data schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientTypeNamespace {
+    pub name: String,
+    pub types: BTreeMap<String, ClientType>,
+    pub functions: BTreeMap<String, ClientFunction>,
+    pub events: BTreeMap<String, ClientType>,
+    pub non_fungible_data: Option<ClientType>,
+}
+
+impl ClientTypeNamespace {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            types: BTreeMap::new(),
+            functions: BTreeMap::new(),
+            events: BTreeMap::new(),
+            non_fungible_data: None,
+        }
+    }
+
+    pub fn from_blueprint_schema(blueprint_name: &str, schema: &BlueprintSchemaInit) -> Self {
+        let mut namespace = Self::new(blueprint_name);
+        let mut resolver = ClientTypeResolver::new(&schema.schema);
+        for (name, function) in &schema.functions.functions {
+            let client_function = ClientFunction {
+                is_method: function.receiver.is_some(),
+                input: resolver.resolve_type_ref(&function.input),
+                output: resolver.resolve_type_ref(&function.output),
+            };
+            namespace.functions.insert(name.clone(), client_function);
+        }
+        for (name, event) in &schema.events.event_schema {
+            let client_type = resolver.resolve_type_ref(event);
+            namespace.events.insert(name.clone(), client_type);
+        }
+        namespace.types = resolver.types;
+        namespace
+    }
+
+    pub fn from_non_fungible_data_schema(name: &str, schema: &NonFungibleDataSchema) -> Self {
+        let mut namespace = Self::new(name);
+        let mut resolver = ClientTypeResolver::new(&schema.schema);
+        namespace.non_fungible_data = Some(resolver.resolve(schema.non_fungible));
+        namespace.types = resolver.types;
+        namespace
+    }
+}
+
+pub fn package_client_types(definition: &PackageDefinition) -> Vec<ClientTypeNamespace> {
+    definition
+        .blueprints
+        .iter()
+        .map(|(name, blueprint)| {
+            ClientTypeNamespace::from_blueprint_schema(name, &blueprint.schema)
+        })
+        .collect()
+}
+
+pub fn package_definition_to_json_schema(definition: &PackageDefinition) -> Value {
+    to_json_schema(&package_client_types(definition))
+}
+
+pub fn package_definition_to_typescript(definition: &PackageDefinition) -> String {
+    to_typescript(&package_client_types(definition))
+}
+
+struct ClientTypeResolver<'s> {
+    schema: &'s ScryptoSchema,
+    type_names: BTreeMap<usize, String>,
+    types: BTreeMap<String, ClientType>,
+    visiting: BTreeSet<usize>,
+}
+
+impl<'s> ClientTypeResolver<'s> {
+    fn new(schema: &'s ScryptoSchema) -> Self {
+        let mut name_counts = BTreeMap::<String, usize>::new();
+        for metadata in &schema.type_metadata {
+            if let Some(name) = metadata.get_name() {
+                *name_counts.entry(sanitize_type_name(name)).or_default() += 1;
+            }
+        }
+
+        // Generic types (eg `Option<T>`) share their name between instantiations, so those are
+        // disambiguated by their index in the schema.
+        let mut type_names = BTreeMap::new();
+        for (index, metadata) in schema.type_metadata.iter().enumerate() {
+            if let Some(name) = metadata.get_name() {
+                let name = sanitize_type_name(name);
+                let unique_name = if name_counts[&name] > 1 {
+                    format!("{}_{}", name, index)
+                } else {
+                    name
+                };
+                type_names.insert(index, unique_name);
+            }
+        }
+
+        Self {
+            schema,
+            type_names,
+            types: BTreeMap::new(),
+            visiting: BTreeSet::new(),
+        }
+    }
+
+    fn resolve_type_ref(&mut self, type_ref: &TypeRef<LocalTypeIndex>) -> ClientType {
+        match type_ref {
+            TypeRef::Static(type_index) => self.resolve(*type_index),
+            // The concrete type is only known once the blueprint is instantiated
+            TypeRef::Generic(_) => ClientType::Any,
+        }
+    }
+
+    fn resolve(&mut self, type_index: LocalTypeIndex) -> ClientType {
+        let LocalTypeIndex::SchemaLocalIndex(index) = type_index else {
+            return self.resolve_kind(type_index);
+        };
+
+        if let Some(name) = self.type_names.get(&index).cloned() {
+            if !self.types.contains_key(&name) {
+                // Insert a placeholder first, so that recursive types terminate
+                self.types.insert(name.clone(), ClientType::Any);
+                let client_type = self.resolve_kind(type_index);
+                self.types.insert(name.clone(), client_type);
+            }
+            return ClientType::Named(name);
+        }
+
+        if !self.visiting.insert(index) {
+            return ClientType::Any;
+        }
+        let client_type = self.resolve_kind(type_index);
+        self.visiting.remove(&index);
+        client_type
+    }
+
+    fn resolve_kind(&mut self, type_index: LocalTypeIndex) -> ClientType {
+        let schema = self.schema;
+        let Some(type_kind) = schema.resolve_type_kind(type_index) else {
+            return ClientType::Any;
+        };
+        let metadata = schema.resolve_type_metadata(type_index);
+
+        match type_kind {
+            TypeKind::Any => ClientType::Any,
+            TypeKind::Bool => ClientType::Boolean,
+            TypeKind::I8 => integer(i8::MIN as i64, i8::MAX as i64),
+            TypeKind::I16 => integer(i16::MIN as i64, i16::MAX as i64),
+            TypeKind::I32 => integer(i32::MIN as i64, i32::MAX as i64),
+            TypeKind::U8 => integer(0, u8::MAX as i64),
+            TypeKind::U16 => integer(0, u16::MAX as i64),
+            TypeKind::U32 => integer(0, u32::MAX as i64),
+            TypeKind::I64 => string(Some("i64")),
+            TypeKind::I128 => string(Some("i128")),
+            TypeKind::U64 => string(Some("u64")),
+            TypeKind::U128 => string(Some("u128")),
+            TypeKind::String => string(None),
+            TypeKind::Array { element_type } => {
+                if let Some(TypeKind::U8) = schema.resolve_type_kind(*element_type) {
+                    string(Some("hex"))
+                } else {
+                    ClientType::Array(Box::new(self.resolve(*element_type)))
+                }
+            }
+            TypeKind::Tuple { field_types } => {
+                self.resolve_fields(field_types, metadata.and_then(|m| m.get_field_names()))
+            }
+            TypeKind::Enum { variants } => {
+                let variant_names = match metadata.map(|m| &m.child_names) {
+                    Some(Some(ChildNames::EnumVariants(variant_names))) => Some(variant_names),
+                    _ => None,
+                };
+                let mut client_variants = Vec::new();
+                for (variant_id, field_types) in variants {
+                    let variant_metadata: Option<&TypeMetadata> =
+                        variant_names.and_then(|names| names.get(variant_id));
+                    let name = variant_metadata
+                        .and_then(|m| m.get_name_string())
+                        .unwrap_or_else(|| variant_id.to_string());
+                    let fields = if field_types.is_empty() {
+                        None
+                    } else {
+                        Some(self.resolve_fields(
+                            field_types,
+                            variant_metadata.and_then(|m| m.get_field_names()),
+                        ))
+                    };
+                    client_variants.push(ClientEnumVariant { name, fields });
+                }
+                ClientType::Enum(client_variants)
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                if let Some(TypeKind::String) = schema.resolve_type_kind(*key_type) {
+                    ClientType::StringKeyedMap(Box::new(self.resolve(*value_type)))
+                } else {
+                    ClientType::EntryList {
+                        key: Box::new(self.resolve(*key_type)),
+                        value: Box::new(self.resolve(*value_type)),
+                    }
+                }
+            }
+            TypeKind::Custom(custom_type_kind) => match custom_type_kind {
+                ScryptoCustomTypeKind::Reference => string(Some("address")),
+                ScryptoCustomTypeKind::Own => string(Some("internal_address")),
+                ScryptoCustomTypeKind::Decimal => string(Some("decimal")),
+                ScryptoCustomTypeKind::PreciseDecimal => string(Some("precise_decimal")),
+                ScryptoCustomTypeKind::NonFungibleLocalId => string(Some("non_fungible_local_id")),
+            },
+        }
+    }
+
+    fn resolve_fields(
+        &mut self,
+        field_types: &[LocalTypeIndex],
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> ClientType {
+        match field_names {
+            Some(field_names) if field_names.len() == field_types.len() => ClientType::Object(
+                field_names
+                    .iter()
+                    .zip(field_types)
+                    .map(|(name, field_type)| (name.to_string(), self.resolve(*field_type)))
+                    .collect(),
+            ),
+            _ => ClientType::Tuple(
+                field_types
+                    .iter()
+                    .map(|field_type| self.resolve(*field_type))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn integer(min: i64, max: i64) -> ClientType {
+    ClientType::Integer { min, max }
+}
+
+fn string(format: Option<&'static str>) -> ClientType {
+    ClientType::String { format }
+}
+
+fn sanitize_type_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+//=============
+// JSON Schema
+//=============
+
+/// Renders the namespaces as a JSON Schema (draft-07) document. Each type is placed under
+/// `definitions`, keyed by `<namespace>.<type>`, and each function input/output, event and
+/// non-fungible data type gets its own definition, eg `<namespace>.functions.<function>.input`.
+pub fn to_json_schema(namespaces: &[ClientTypeNamespace]) -> Value {
+    let mut definitions = Map::new();
+    for namespace in namespaces {
+        let ns = namespace.name.as_str();
+        for (name, client_type) in &namespace.types {
+            definitions.insert(format!("{}.{}", ns, name), json_schema(ns, client_type));
+        }
+        for (name, function) in &namespace.functions {
+            definitions.insert(
+                format!("{}.functions.{}.input", ns, name),
+                json_schema(ns, &function.input),
+            );
+            definitions.insert(
+                format!("{}.functions.{}.output", ns, name),
+                json_schema(ns, &function.output),
+            );
+        }
+        for (name, client_type) in &namespace.events {
+            definitions.insert(
+                format!("{}.events.{}", ns, name),
+                json_schema(ns, client_type),
+            );
+        }
+        if let Some(client_type) = &namespace.non_fungible_data {
+            definitions.insert(
+                format!("{}.non_fungible_data", ns),
+                json_schema(ns, client_type),
+            );
+        }
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "definitions": definitions,
+    })
+}
+
+fn json_schema(ns: &str, client_type: &ClientType) -> Value {
+    match client_type {
+        ClientType::Any => json!({}),
+        ClientType::Boolean => json!({ "type": "boolean" }),
+        ClientType::Integer { min, max } => json!({
+            "type": "integer",
+            "minimum": min,
+            "maximum": max,
+        }),
+        ClientType::String { format } => {
+            let mut schema = Map::new();
+            schema.insert("type".to_string(), json!("string"));
+            if let Some(format) = format {
+                schema.insert("format".to_string(), json!(format));
+                if let Some(pattern) = string_pattern(format) {
+                    schema.insert("pattern".to_string(), json!(pattern));
+                }
+            }
+            Value::Object(schema)
+        }
+        ClientType::Array(element) => json!({
+            "type": "array",
+            "items": json_schema(ns, element),
+        }),
+        ClientType::Tuple(elements) => json!({
+            "type": "array",
+            "items": elements.iter().map(|e| json_schema(ns, e)).collect::<Vec<_>>(),
+            "minItems": elements.len(),
+            "maxItems": elements.len(),
+        }),
+        ClientType::Object(fields) => {
+            let mut properties = Map::new();
+            for (name, field) in fields {
+                properties.insert(name.clone(), json_schema(ns, field));
+            }
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": fields.iter().map(|(name, _)| name.clone()).collect::<Vec<_>>(),
+                "additionalProperties": false,
+            })
+        }
+        ClientType::StringKeyedMap(value) => json!({
+            "type": "object",
+            "additionalProperties": json_schema(ns, value),
+        }),
+        ClientType::EntryList { key, value } => json!({
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": {
+                    "key": json_schema(ns, key),
+                    "value": json_schema(ns, value),
+                },
+                "required": ["key", "value"],
+                "additionalProperties": false,
+            },
+        }),
+        ClientType::Enum(variants) => {
+            let variants: Vec<Value> = variants
+                .iter()
+                .map(|variant| match &variant.fields {
+                    Some(fields) => json!({
+                        "type": "object",
+                        "properties": {
+                            "variant": { "const": variant.name },
+                            "fields": json_schema(ns, fields),
+                        },
+                        "required": ["variant", "fields"],
+                        "additionalProperties": false,
+                    }),
+                    None => json!({
+                        "type": "object",
+                        "properties": {
+                            "variant": { "const": variant.name },
+                        },
+                        "required": ["variant"],
+                        "additionalProperties": false,
+                    }),
+                })
+                .collect();
+            json!({ "oneOf": variants })
+        }
+        ClientType::Named(name) => json!({ "$ref": format!("#/definitions/{}.{}", ns, name) }),
+    }
+}
+
+fn string_pattern(format: &str) -> Option<&'static str> {
+    match format {
+        "u64" | "u128" => Some("^[0-9]+$"),
+        "i64" | "i128" => Some("^-?[0-9]+$"),
+        "decimal" | "precise_decimal" => Some("^-?[0-9]+(\\.[0-9]+)?$"),
+        "hex" => Some("^([0-9a-f]{2})*$"),
+        _ => None,
+    }
+}
+
+//============
+// TypeScript
+//============
+
+/// Renders the namespaces as TypeScript declarations, with one `namespace` per blueprint.
+pub fn to_typescript(namespaces: &[ClientTypeNamespace]) -> String {
+    let mut buf = String::new();
+    for namespace in namespaces {
+        buf.push_str(&format!("export namespace {} {{\n", namespace.name));
+        for (name, client_type) in &namespace.types {
+            buf.push_str(&format!(
+                "    export type {} = {};\n",
+                name,
+                typescript(client_type)
+            ));
+        }
+        if !namespace.functions.is_empty() {
+            buf.push_str("    export interface Functions {\n");
+            for (name, function) in &namespace.functions {
+                buf.push_str(&format!(
+                    "        {}: {{ is_method: {}; input: {}; output: {} }};\n",
+                    typescript_property_name(name),
+                    function.is_method,
+                    typescript(&function.input),
+                    typescript(&function.output)
+                ));
+            }
+            buf.push_str("    }\n");
+        }
+        if !namespace.events.is_empty() {
+            buf.push_str("    export interface Events {\n");
+            for (name, client_type) in &namespace.events {
+                buf.push_str(&format!(
+                    "        {}: {};\n",
+                    typescript_property_name(name),
+                    typescript(client_type)
+                ));
+            }
+            buf.push_str("    }\n");
+        }
+        if let Some(client_type) = &namespace.non_fungible_data {
+            buf.push_str(&format!(
+                "    export type NonFungibleData = {};\n",
+                typescript(client_type)
+            ));
+        }
+        buf.push_str("}\n");
+    }
+    buf
+}
+
+fn typescript(client_type: &ClientType) -> String {
+    match client_type {
+        ClientType::Any => "unknown".to_string(),
+        ClientType::Boolean => "boolean".to_string(),
+        ClientType::Integer { .. } => "number".to_string(),
+        ClientType::String { .. } => "string".to_string(),
+        ClientType::Array(element) => format!("Array<{}>", typescript(element)),
+        ClientType::Tuple(elements) => format!(
+            "[{}]",
+            elements
+                .iter()
+                .map(typescript)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ClientType::Object(fields) => {
+            if fields.is_empty() {
+                return "{}".to_string();
+            }
+            format!(
+                "{{ {} }}",
+                fields
+                    .iter()
+                    .map(|(name, field)| format!(
+                        "{}: {};",
+                        typescript_property_name(name),
+                        typescript(field)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        }
+        ClientType::StringKeyedMap(value) => format!("Record<string, {}>", typescript(value)),
+        ClientType::EntryList { key, value } => format!(
+            "Array<{{ key: {}; value: {} }}>",
+            typescript(key),
+            typescript(value)
+        ),
+        ClientType::Enum(variants) => {
+            if variants.is_empty() {
+                return "never".to_string();
+            }
+            variants
+                .iter()
+                .map(|variant| match &variant.fields {
+                    Some(fields) => format!(
+                        "{{ variant: {}; fields: {} }}",
+                        typescript_string(&variant.name),
+                        typescript(fields)
+                    ),
+                    None => format!("{{ variant: {} }}", typescript_string(&variant.name)),
+                })
+                .collect::<Vec<_>>()
+                .join(" | ")
+        }
+        ClientType::Named(name) => name.clone(),
+    }
+}
+
+fn typescript_property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        typescript_string(name)
+    }
+}
+
+fn typescript_string(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blueprints::resource::NonFungibleResourceManagerCreateInput;
+    use crate::schema::FunctionSchemaInit;
+    use crate::types::NonFungibleData;
+    use crate::*;
+    use radix_engine_common::math::Decimal;
+    use sbor::{generate_full_schema, TypeAggregator};
+
+    #[derive(ScryptoSbor)]
+    struct Ticket {
+        seat: u16,
+        price: Decimal,
+        holder: Option<String>,
+    }
+
+    impl NonFungibleData for Ticket {
+        const MUTABLE_FIELDS: &'static [&'static str] = &["holder"];
+    }
+
+    #[test]
+    fn non_fungible_data_is_exported() {
+        let schema = NonFungibleDataSchema::new_schema::<Ticket>();
+        let namespace = ClientTypeNamespace::from_non_fungible_data_schema("Tickets", &schema);
+
+        assert_eq!(
+            namespace.types.get("Ticket"),
+            Some(&ClientType::Object(vec![
+                ("seat".to_string(), integer(0, u16::MAX as i64)),
+                ("price".to_string(), string(Some("decimal"))),
+                (
+                    "holder".to_string(),
+                    ClientType::Named("Option".to_string())
+                ),
+            ]))
+        );
+
+        let typescript = to_typescript(&[namespace.clone()]);
+        assert!(typescript.contains(
+            "    export type Ticket = { seat: number; price: string; holder: Option; };\n"
+        ));
+        assert!(typescript
+            .contains("    export type Option = { variant: \"None\" } | { variant: \"Some\"; fields: [string] };\n"));
+        assert!(typescript.contains("    export type NonFungibleData = Ticket;\n"));
+
+        let json_schema = to_json_schema(&[namespace]);
+        assert_eq!(
+            json_schema["definitions"]["Tickets.non_fungible_data"],
+            json!({ "$ref": "#/definitions/Tickets.Ticket" })
+        );
+        assert_eq!(
+            json_schema["definitions"]["Tickets.Ticket"]["properties"]["price"],
+            json!({
+                "type": "string",
+                "format": "decimal",
+                "pattern": "^-?[0-9]+(\\.[0-9]+)?$",
+            })
+        );
+    }
+
+    #[test]
+    fn blueprint_functions_are_exported() {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+        let input =
+            aggregator.add_child_type_and_descendents::<NonFungibleResourceManagerCreateInput>();
+        let output = aggregator.add_child_type_and_descendents::<Vec<u8>>();
+        let mut schema = BlueprintSchemaInit::default();
+        schema.schema = generate_full_schema(aggregator);
+        schema.functions.functions.insert(
+            "create".to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(input),
+                output: TypeRef::Static(output),
+                export: "create".to_string(),
+            },
+        );
+
+        let namespace = ClientTypeNamespace::from_blueprint_schema("Factory", &schema);
+        let function = namespace.functions.get("create").unwrap();
+        assert!(!function.is_method);
+        assert_eq!(
+            function.input,
+            ClientType::Named("NonFungibleResourceManagerCreateInput".to_string())
+        );
+        assert_eq!(function.output, string(Some("hex")));
+
+        let json_schema = to_json_schema(&[namespace]);
+        assert_eq!(
+            json_schema["definitions"]["Factory.functions.create.input"]["$ref"],
+            json!("#/definitions/Factory.NonFungibleResourceManagerCreateInput")
+        );
+        assert!(json_schema["definitions"]
+            .get("Factory.NonFungibleResourceManagerCreateInput")
+            .is_some());
+    }
+}
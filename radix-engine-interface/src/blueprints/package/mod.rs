@@ -1,5 +1,7 @@
+mod client_types;
 mod invocations;
 mod substates;
 
+pub use client_types::*;
 pub use invocations::*;
 pub use substates::*;
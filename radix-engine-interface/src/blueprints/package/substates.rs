@@ -21,6 +21,8 @@ pub const ROYALTY_CODE_ID: u64 = 11u64;
 pub const ACCESS_RULES_CODE_ID: u64 = 12u64;
 pub const POOL_CODE_ID: u64 = 13u64;
 pub const TRANSACTION_TRACKER_CODE_ID: u64 = 14u64;
+pub const HOOK_REGISTRY_CODE_ID: u64 = 15u64;
+pub const PAYMENT_STREAM_CODE_ID: u64 = 16u64;
 
 pub const PACKAGE_FIELDS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(0u8);
 pub const PACKAGE_BLUEPRINTS_PARTITION_OFFSET: PartitionOffset = PartitionOffset(1u8);
@@ -0,0 +1,80 @@
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const PAYMENT_STREAM_BLUEPRINT: &str = "PaymentStream";
+
+/// The role which funded the stream, and which may cancel it.
+pub const PAYMENT_STREAM_SENDER_ROLE: &str = "sender";
+
+/// The role which the stream pays, and which may withdraw the accrued amount.
+pub const PAYMENT_STREAM_RECIPIENT_ROLE: &str = "recipient";
+
+/// The state of a payment stream, as returned by `get_info`.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct PaymentStreamInfo {
+    pub resource_address: ResourceAddress,
+    /// The amount which accrues to the recipient at the start of each epoch after the start epoch.
+    pub rate_per_epoch: Decimal,
+    /// The epoch the stream was created in.
+    pub start_epoch: Epoch,
+    /// The amount locked by the sender when the stream was created.
+    pub total_amount: Decimal,
+    /// The amount withdrawn by the recipient so far.
+    pub withdrawn_amount: Decimal,
+    /// The epoch the sender cancelled the stream in, after which nothing more accrues.
+    pub cancelled_at_epoch: Option<Epoch>,
+}
+
+define_invocation! {
+    blueprint_name: PaymentStream,
+    function_name: instantiate,
+    input: struct {
+        bucket: Bucket,
+        rate_per_epoch: Decimal,
+        sender_rule: AccessRule,
+        recipient_rule: AccessRule
+    },
+    output: type ComponentAddress,
+    manifest_input: struct {
+        bucket: ManifestBucket,
+        rate_per_epoch: Decimal,
+        sender_rule: AccessRule,
+        recipient_rule: AccessRule
+    }
+}
+
+define_invocation! {
+    blueprint_name: PaymentStream,
+    function_name: withdraw,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: PaymentStream,
+    function_name: cancel,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: PaymentStream,
+    function_name: get_withdrawable_amount,
+    input: struct {},
+    output: type Decimal,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: PaymentStream,
+    function_name: get_info,
+    input: struct {},
+    output: type PaymentStreamInfo,
+    manifest_input: struct {}
+}
@@ -0,0 +1,116 @@
+use radix_engine_common::math::*;
+use radix_engine_common::ScryptoSbor;
+use sbor::rust::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum AtomicUnitsError {
+    InvalidDivisibility(u8),
+    NegativeAmount,
+    /// The amount has more decimal places than the divisibility allows.
+    PrecisionLoss,
+    Overflow,
+}
+
+/// Converts between a [`Decimal`] amount and the integer number of the smallest units of a
+/// fungible resource with the given divisibility, e.g. `1.5` is `1500000` units with divisibility `6`.
+pub trait AtomicUnits: Sized {
+    fn to_atomic_units(&self, divisibility: u8) -> Result<u128, AtomicUnitsError>;
+
+    fn from_atomic_units(units: u128, divisibility: u8) -> Result<Self, AtomicUnitsError>;
+}
+
+impl AtomicUnits for Decimal {
+    fn to_atomic_units(&self, divisibility: u8) -> Result<u128, AtomicUnitsError> {
+        let unit = atomic_unit(divisibility)?;
+        if self.is_negative() {
+            return Err(AtomicUnitsError::NegativeAmount);
+        }
+        if self.0 % unit != BnumI256::ZERO {
+            return Err(AtomicUnitsError::PrecisionLoss);
+        }
+        u128::try_from(self.0 / unit).map_err(|_| AtomicUnitsError::Overflow)
+    }
+
+    fn from_atomic_units(units: u128, divisibility: u8) -> Result<Self, AtomicUnitsError> {
+        let unit = atomic_unit(divisibility)?;
+        BnumI256::from(units)
+            .checked_mul(unit)
+            .map(Decimal)
+            .ok_or(AtomicUnitsError::Overflow)
+    }
+}
+
+/// The smallest amount representable with the given divisibility, in `Decimal` subunits.
+fn atomic_unit(divisibility: u8) -> Result<BnumI256, AtomicUnitsError> {
+    if u32::from(divisibility) > Decimal::SCALE {
+        return Err(AtomicUnitsError::InvalidDivisibility(divisibility));
+    }
+    Ok(BnumI256::from(10u8).pow(Decimal::SCALE - u32::from(divisibility)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_common::dec;
+
+    #[test]
+    fn decimal_to_atomic_units() {
+        assert_eq!(dec!("1.5").to_atomic_units(6), Ok(1_500_000));
+        assert_eq!(dec!("42").to_atomic_units(0), Ok(42));
+        assert_eq!(Decimal::ONE.to_atomic_units(18), Ok(10u128.pow(18)));
+        assert_eq!(Decimal::ZERO.to_atomic_units(18), Ok(0));
+    }
+
+    #[test]
+    fn decimal_to_atomic_units_fails_on_precision_loss() {
+        assert_eq!(
+            dec!("1.0000001").to_atomic_units(6),
+            Err(AtomicUnitsError::PrecisionLoss)
+        );
+        assert_eq!(
+            dec!("0.5").to_atomic_units(0),
+            Err(AtomicUnitsError::PrecisionLoss)
+        );
+    }
+
+    #[test]
+    fn decimal_to_atomic_units_fails_on_invalid_input() {
+        assert_eq!(
+            dec!("-1").to_atomic_units(6),
+            Err(AtomicUnitsError::NegativeAmount)
+        );
+        assert_eq!(
+            dec!("1").to_atomic_units(19),
+            Err(AtomicUnitsError::InvalidDivisibility(19))
+        );
+        assert_eq!(
+            Decimal::MAX
+                .round(0, RoundingMode::ToZero)
+                .to_atomic_units(0),
+            Err(AtomicUnitsError::Overflow)
+        );
+    }
+
+    #[test]
+    fn decimal_from_atomic_units() {
+        assert_eq!(Decimal::from_atomic_units(1_500_000, 6), Ok(dec!("1.5")));
+        assert_eq!(Decimal::from_atomic_units(1, 18), Ok(Decimal(1i128.into())));
+        assert_eq!(
+            Decimal::from_atomic_units(1, 19),
+            Err(AtomicUnitsError::InvalidDivisibility(19))
+        );
+        assert_eq!(
+            Decimal::from_atomic_units(u128::MAX, 0),
+            Ok(Decimal::from(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn atomic_units_round_trip() {
+        for divisibility in 0..=18 {
+            let units = 123_456_789u128;
+            let amount = Decimal::from_atomic_units(units, divisibility).unwrap();
+            assert_eq!(amount.to_atomic_units(divisibility), Ok(units));
+        }
+    }
+}
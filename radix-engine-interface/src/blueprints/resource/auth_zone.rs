@@ -104,6 +104,43 @@ pub struct AuthZoneDrainInput {}
 
 pub type AuthZoneDrainOutput = Vec<Proof>;
 
+pub const AUTH_ZONE_PUSH_SCOPE_IDENT: &str = "push_scope";
+
+pub const AUTH_ZONE_PUSH_SCOPE_EXPORT_NAME: &str = "AuthZone_push_scope";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AuthZonePushScopeInput {
+    pub proofs: Vec<Proof>,
+}
+
+impl Clone for AuthZonePushScopeInput {
+    fn clone(&self) -> Self {
+        Self {
+            proofs: self.proofs.iter().map(|proof| Proof(proof.0)).collect(),
+        }
+    }
+}
+
+pub type AuthZonePushScopeOutput = ();
+
+pub const AUTH_ZONE_POP_SCOPE_IDENT: &str = "pop_scope";
+
+pub const AUTH_ZONE_POP_SCOPE_EXPORT_NAME: &str = "AuthZone_pop_scope";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct AuthZonePopScopeInput {}
+
+pub type AuthZonePopScopeOutput = ();
+
+pub const AUTH_ZONE_SCOPE_DEPTH_IDENT: &str = "scope_depth";
+
+pub const AUTH_ZONE_SCOPE_DEPTH_EXPORT_NAME: &str = "AuthZone_scope_depth";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct AuthZoneScopeDepthInput {}
+
+pub type AuthZoneScopeDepthOutput = u32;
+
 pub const AUTH_ZONE_DROP_IDENT: &str = "drop";
 
 pub const AUTH_ZONE_DROP_EXPORT_NAME: &str = "AuthZone_drop";
@@ -157,3 +157,37 @@ pub struct FungibleResourceManagerMintInput {
 }
 
 pub type FungibleResourceManagerMintOutput = Bucket;
+
+pub const FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_IDENT: &str = "enable_reserve_tracking";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct FungibleResourceManagerEnableReserveTrackingInput {}
+
+pub type FungibleResourceManagerEnableReserveTrackingOutput = ();
+
+pub const FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_IDENT: &str = "proof_of_reserves";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct FungibleResourceManagerProofOfReservesInput {
+    /// The number of largest holdings to include in the breakdown
+    pub top_holders: u32,
+}
+
+pub type FungibleResourceManagerProofOfReservesOutput = ProofOfReserves;
+
+/// The amount of a resource held by a single vault.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ReserveHolding {
+    pub vault_id: NodeId,
+    pub amount: Decimal,
+}
+
+/// An attestation of the on-ledger reserves of a resource, aggregated over all vaults holding it.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ProofOfReserves {
+    pub total_amount: Decimal,
+    /// The number of vaults holding a non-zero amount of the resource
+    pub holder_count: u32,
+    /// The largest holdings, in descending order of amount
+    pub top_holders: Vec<ReserveHolding>,
+}
@@ -41,3 +41,27 @@ pub struct FungibleVaultCreateProofOfAmountInput {
 }
 
 pub type FungibleVaultCreateProofOfAmountOutput = Proof;
+
+/// Takes an amount out of the vault, as the first half of a vault-to-vault transfer.
+///
+/// Only callable by the system, see `ClientObjectApi::transfer_between_vaults`.
+pub const FUNGIBLE_VAULT_TRANSFER_OUT_IDENT: &str = "transfer_out";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultTransferOutInput {
+    pub amount: Decimal,
+}
+
+pub type FungibleVaultTransferOutOutput = Decimal;
+
+/// Puts an amount into the vault, as the second half of a vault-to-vault transfer.
+///
+/// Only callable by the system, see `ClientObjectApi::transfer_between_vaults`.
+pub const FUNGIBLE_VAULT_TRANSFER_IN_IDENT: &str = "transfer_in";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultTransferInInput {
+    pub amount: Decimal,
+}
+
+pub type FungibleVaultTransferInOutput = ();
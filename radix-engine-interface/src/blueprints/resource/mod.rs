@@ -1,4 +1,5 @@
 mod access_rules;
+mod atomic_units;
 mod auth_zone;
 mod bucket;
 mod fungible;
@@ -13,6 +14,7 @@ mod vault;
 mod worktop;
 
 pub use access_rules::*;
+pub use atomic_units::*;
 pub use auth_zone::*;
 pub use bucket::*;
 pub use fungible::*;
@@ -2,7 +2,7 @@ use crate::blueprints::resource::*;
 use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::{Arbitrary, Result, Unstructured};
-use radix_engine_common::data::manifest::model::ManifestAddressReservation;
+use radix_engine_common::data::manifest::model::{ManifestAddressReservation, ManifestBlobRef};
 use radix_engine_common::data::manifest::ManifestValue;
 use radix_engine_common::data::scrypto::{ScryptoCustomTypeKind, ScryptoSchema, ScryptoValue};
 use radix_engine_common::prelude::replace_self_package_address;
@@ -246,6 +246,22 @@ pub struct NonFungibleResourceManagerMintInput {
 
 pub type NonFungibleResourceManagerMintOutput = Bucket;
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT: &str = "mint_from_blobs";
+
+/// Mints non-fungibles whose data is provided as SBOR-encoded payloads in transaction blobs.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerMintFromBlobsManifestInput {
+    pub entries: BTreeMap<NonFungibleLocalId, ManifestBlobRef>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerMintFromBlobsInput {
+    pub entries: BTreeMap<NonFungibleLocalId, Vec<u8>>,
+}
+
+pub type NonFungibleResourceManagerMintFromBlobsOutput = Bucket;
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT: &str = "mint_ruid";
 
 #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
@@ -269,6 +285,40 @@ pub struct NonFungibleResourceManagerMintSingleRuidInput {
 }
 pub type NonFungibleResourceManagerMintSingleRuidOutput = (Bucket, NonFungibleLocalId);
 
+/// The name of the first non-fungible data field of time-boxed badges.
+pub const NON_FUNGIBLE_VALID_FROM_EPOCH_FIELD: &str = "valid_from_epoch";
+/// The name of the second non-fungible data field of time-boxed badges.
+pub const NON_FUNGIBLE_VALID_UNTIL_EPOCH_FIELD: &str = "valid_until_epoch";
+
+/// The epochs during which a time-boxed badge can be used in proofs for auth, read from the
+/// first two fields of its non-fungible data. The start epoch is inclusive, the end epoch is
+/// exclusive, and a missing bound means the window is open on that side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct ValidityWindow {
+    pub valid_from_epoch: Option<Epoch>,
+    pub valid_until_epoch: Option<Epoch>,
+}
+
+impl ValidityWindow {
+    pub fn from_non_fungible_data(data: &ScryptoValue) -> Option<Self> {
+        let ScryptoValue::Tuple { fields } = data else {
+            return None;
+        };
+        let [valid_from_epoch, valid_until_epoch, ..] = fields.as_slice() else {
+            return None;
+        };
+        Some(Self {
+            valid_from_epoch: scrypto_decode(&scrypto_encode(valid_from_epoch).ok()?).ok()?,
+            valid_until_epoch: scrypto_decode(&scrypto_encode(valid_until_epoch).ok()?).ok()?,
+        })
+    }
+
+    pub fn contains(&self, epoch: Epoch) -> bool {
+        self.valid_from_epoch.map_or(true, |from| epoch >= from)
+            && self.valid_until_epoch.map_or(true, |until| epoch < until)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
 pub struct NonFungibleDataSchema {
     pub schema: ScryptoSchema,
@@ -295,6 +345,22 @@ impl NonFungibleDataSchema {
     pub fn replace_self_package_address(&mut self, package_address: PackageAddress) {
         replace_self_package_address(&mut self.schema, package_address);
     }
+
+    /// Whether the non-fungible data starts with the `valid_from_epoch` and `valid_until_epoch`
+    /// fields of a [`ValidityWindow`], in which case the resource is created with the
+    /// [`TIME_BOXED_FEATURE`] and the auth module ignores badges outside of their window.
+    pub fn is_time_boxed(&self) -> bool {
+        let field_names = self
+            .schema
+            .resolve_type_metadata(self.non_fungible)
+            .and_then(|metadata| metadata.get_field_names());
+        matches!(
+            field_names,
+            Some([valid_from_epoch, valid_until_epoch, ..])
+                if valid_from_epoch == NON_FUNGIBLE_VALID_FROM_EPOCH_FIELD
+                    && valid_until_epoch == NON_FUNGIBLE_VALID_UNTIL_EPOCH_FIELD
+        )
+    }
 }
 
 #[cfg(feature = "radix_engine_fuzzing")]
@@ -1,4 +1,5 @@
 use crate::blueprints::resource::Proof;
+use crate::math::Decimal;
 use crate::*;
 use radix_engine_common::data::scrypto::model::NonFungibleLocalId;
 use radix_engine_interface::blueprints::resource::Bucket;
@@ -32,6 +33,17 @@ pub struct NonFungibleVaultRecallNonFungiblesInput {
 
 pub type NonFungibleVaultRecallNonFungiblesOutput = Bucket;
 
+pub const NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT: &str =
+    "recall_non_fungibles_with_reason";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleVaultRecallNonFungiblesWithReasonInput {
+    pub non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    pub reason: String,
+}
+
+pub type NonFungibleVaultRecallNonFungiblesWithReasonOutput = Bucket;
+
 pub const NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT: &str =
     "create_proof_of_non_fungibles";
 
@@ -68,3 +80,40 @@ pub struct NonFungibleVaultBurnNonFungiblesInput {
 }
 
 pub type NonFungibleVaultBurnNonFungiblesOutput = ();
+
+/// Takes an amount out of the vault, as the first half of a vault-to-vault transfer.
+///
+/// Only callable by the system, see `ClientObjectApi::transfer_between_vaults`.
+pub const NON_FUNGIBLE_VAULT_TRANSFER_OUT_IDENT: &str = "transfer_out";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleVaultTransferOutInput {
+    pub amount: Decimal,
+}
+
+pub type NonFungibleVaultTransferOutOutput = BTreeSet<NonFungibleLocalId>;
+
+/// Takes the given non-fungibles out of the vault, as the first half of a vault-to-vault
+/// transfer.
+///
+/// Only callable by the system, see `ClientObjectApi::transfer_non_fungibles_between_vaults`.
+pub const NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_IDENT: &str = "transfer_non_fungibles_out";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleVaultTransferNonFungiblesOutInput {
+    pub non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+}
+
+pub type NonFungibleVaultTransferNonFungiblesOutOutput = BTreeSet<NonFungibleLocalId>;
+
+/// Puts non-fungibles into the vault, as the second half of a vault-to-vault transfer.
+///
+/// Only callable by the system, see `ClientObjectApi::transfer_between_vaults`.
+pub const NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT: &str = "transfer_in";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleVaultTransferInInput {
+    pub non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+}
+
+pub type NonFungibleVaultTransferInOutput = ();
@@ -5,6 +5,7 @@ use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
 use radix_engine_common::types::*;
+use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
 
@@ -161,6 +162,52 @@ pub enum AccessRule {
     Protected(AccessRuleNode),
 }
 
+impl AccessRule {
+    /// Returns the non-fungibles the rule may require a proof of, such as the virtual signature
+    /// badges of the keys which can satisfy it.
+    pub fn non_fungible_global_ids(&self) -> BTreeSet<NonFungibleGlobalId> {
+        self.resources_and_non_fungibles()
+            .into_iter()
+            .filter_map(|resource| match resource {
+                ResourceOrNonFungible::NonFungible(id) => Some(id),
+                ResourceOrNonFungible::Resource(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the resources and non-fungibles the rule may require a proof of.
+    pub fn resources_and_non_fungibles(&self) -> BTreeSet<ResourceOrNonFungible> {
+        let mut resources = BTreeSet::new();
+        if let AccessRule::Protected(node) = self {
+            node.collect_resources_and_non_fungibles(&mut resources);
+        }
+        resources
+    }
+}
+
+impl AccessRuleNode {
+    fn collect_resources_and_non_fungibles(&self, resources: &mut BTreeSet<ResourceOrNonFungible>) {
+        match self {
+            AccessRuleNode::ProofRule(rule) => match rule {
+                ProofRule::Require(resource) => {
+                    resources.insert(resource.clone());
+                }
+                ProofRule::AmountOf(_, resource_address) => {
+                    resources.insert(ResourceOrNonFungible::Resource(*resource_address));
+                }
+                ProofRule::CountOf(_, list) | ProofRule::AllOf(list) | ProofRule::AnyOf(list) => {
+                    resources.extend(list.iter().cloned());
+                }
+            },
+            AccessRuleNode::AnyOf(nodes) | AccessRuleNode::AllOf(nodes) => {
+                for node in nodes {
+                    node.collect_resources_and_non_fungibles(resources);
+                }
+            }
+        }
+    }
+}
+
 impl From<AccessRuleNode> for AccessRule {
     fn from(value: AccessRuleNode) -> Self {
         AccessRule::Protected(value)
@@ -8,6 +8,9 @@ pub const VAULT_FREEZE_FEATURE: &str = "vault_freeze";
 pub const VAULT_RECALL_FEATURE: &str = "vault_recall";
 pub const MINT_FEATURE: &str = "mint";
 pub const BURN_FEATURE: &str = "burn";
+/// Enabled on non-fungible resources whose data starts with a validity window, see
+/// [`NonFungibleDataSchema::is_time_boxed`].
+pub const TIME_BOXED_FEATURE: &str = "time_boxed";
 
 // Main roles
 pub const MINTER_ROLE: &str = "minter";
@@ -62,6 +62,19 @@ pub struct VaultRecallInput {
 
 pub type VaultRecallOutput = Bucket;
 
+pub const VAULT_RECALL_WITH_REASON_IDENT: &str = "recall_with_reason";
+
+/// The maximum length, in bytes, of the reason given for a recall.
+pub const MAX_RECALL_REASON_LENGTH: usize = 256;
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VaultRecallWithReasonInput {
+    pub amount: Decimal,
+    pub reason: String,
+}
+
+pub type VaultRecallWithReasonOutput = Bucket;
+
 bitflags! {
     #[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
     #[derive(Sbor)]
@@ -69,6 +82,7 @@ bitflags! {
         const WITHDRAW = 0b00000001;
         const DEPOSIT = 0b00000010;
         const BURN = 0b00000100;
+        const RECALL = 0b00001000;
     }
 }
 
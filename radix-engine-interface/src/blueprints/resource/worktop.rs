@@ -116,6 +116,33 @@ pub struct WorktopAssertContainsNonFungiblesInput {
 
 pub type WorktopAssertContainsNonFungiblesOutput = ();
 
+pub const WORKTOP_ASSERT_CONTAINS_EXACTLY_IDENT: &str = "Worktop_assert_contains_exactly";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopAssertContainsExactlyInput {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+pub type WorktopAssertContainsExactlyOutput = ();
+
+pub const WORKTOP_ASSERT_RESOURCES_ONLY_IDENT: &str = "Worktop_assert_resources_only";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopAssertResourcesOnlyInput {
+    pub resource_addresses: BTreeSet<ResourceAddress>,
+}
+
+pub type WorktopAssertResourcesOnlyOutput = ();
+
+pub const WORKTOP_CONTENTS_IDENT: &str = "Worktop_contents";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopContentsInput {}
+
+/// The amount of each resource on the worktop.
+pub type WorktopContentsOutput = BTreeMap<ResourceAddress, Decimal>;
+
 pub const WORKTOP_DRAIN_IDENT: &str = "Worktop_drain";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
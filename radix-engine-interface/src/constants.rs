@@ -44,6 +44,8 @@ lazy_static! {
             FAUCET_PACKAGE.into(),
             POOL_PACKAGE.into(),
             TRANSACTION_TRACKER_PACKAGE.into(),
+            HOOK_REGISTRY_PACKAGE.into(),
+            PAYMENT_STREAM_PACKAGE.into(),
             // components
             CONSENSUS_MANAGER.into(),
         ]
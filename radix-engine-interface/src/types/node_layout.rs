@@ -24,6 +24,10 @@ pub const ACCESS_RULES_MUTABILITY_PARTITION_OFFSET: PartitionOffset = PartitionO
 pub const ACCESS_RULES_FIELDS_PARTITION: PartitionNumber = PartitionNumber(4u8);
 pub const ACCESS_RULES_ROLE_DEF_PARTITION: PartitionNumber = PartitionNumber(5u8);
 
+/// Scratch entries of an object which live for the duration of a transaction only.
+/// Substates in this partition are discarded by Track and never reach the substate database.
+pub const TRANSIENT_PARTITION: PartitionNumber = PartitionNumber(63u8);
+
 pub const MAIN_BASE_PARTITION: PartitionNumber = PartitionNumber(64u8);
 
 #[repr(u8)]
@@ -36,12 +40,15 @@ pub enum TypeInfoField {
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum RoyaltyField {
     RoyaltyAccumulator,
+    Rent,
 }
 
 #[repr(u8)]
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum AccessRulesField {
     OwnerRole,
+    Pause,
+    OwnerRoleHistory,
 }
 
 impl TryFrom<u8> for AccessRulesField {
@@ -64,11 +71,27 @@ pub enum PackageField {
     Royalty,
 }
 
+#[repr(u8)]
+#[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
+pub enum FungibleResourceManagerPartitionOffset {
+    ResourceManager,
+    Reserves,
+}
+
+impl TryFrom<u8> for FungibleResourceManagerPartitionOffset {
+    type Error = ();
+
+    fn try_from(offset: u8) -> Result<Self, Self::Error> {
+        Self::from_repr(offset).ok_or(())
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum FungibleResourceManagerField {
     Divisibility,
     TotalSupply,
+    ReserveTracking,
 }
 
 #[repr(u8)]
@@ -172,6 +195,9 @@ pub enum ConsensusManagerField {
     CurrentProposalStatistic,
     CurrentTimeRoundedToMinutes,
     CurrentTime,
+    EpochHistory,
+    InvocationFilter,
+    InstantiationDeposits,
 }
 
 #[repr(u8)]
@@ -277,6 +303,13 @@ pub enum MultiResourcePoolField {
 #[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum TransactionTrackerField {
     TransactionTracker,
+    IdempotencyKeys,
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
+pub enum PaymentStreamField {
+    PaymentStream,
 }
 
 macro_rules! substate_key {
@@ -327,6 +360,7 @@ substate_key!(OneResourcePoolField);
 substate_key!(TwoResourcePoolField);
 substate_key!(MultiResourcePoolField);
 substate_key!(TransactionTrackerField);
+substate_key!(PaymentStreamField);
 
 // Transient
 substate_key!(WorktopField);
@@ -0,0 +1,307 @@
+use radix_engine::system::node_modules::access_rules::OwnerRoleSubstate;
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::system::system::KeyValueEntrySubstate;
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::metadata::METADATA_BLUEPRINT;
+use radix_engine_interface::api::node_modules::royalty::COMPONENT_ROYALTY_BLUEPRINT;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
+use radix_engine_interface::blueprints::identity::IDENTITY_BLUEPRINT;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::types::{
+    ACCESS_RULES_BASE_PARTITION, ACCESS_RULES_FIELDS_PARTITION_OFFSET,
+    ACCESS_RULES_ROLE_DEF_PARTITION_OFFSET, MAIN_BASE_PARTITION, TYPE_INFO_FIELD_PARTITION,
+};
+use radix_engine_store_interface::{
+    db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::SubstateDatabase,
+};
+use sbor::rust::prelude::*;
+use transaction::prelude::{DynamicGlobalAddress, DynamicPackageAddress, InstructionV1};
+
+/// An access rule checked on a call made by an instruction of a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthCheck {
+    pub instruction_index: usize,
+    /// The rule the call must satisfy, which for a method is any of the rules of the roles
+    /// able to call it.
+    pub access_rule: AccessRule,
+}
+
+/// The access rules a manifest is checked against when executed on the current state, so that
+/// wallets can work out which keys must sign a transaction before submitting it.
+///
+/// Only calls made by the manifest itself to statically known addresses are analyzed, and
+/// calls which anyone may make are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuthRequirements {
+    pub checks: Vec<AuthCheck>,
+    /// The calls whose access rule couldn't be determined, e.g. of addresses allocated by the
+    /// manifest, or of access rules module methods.
+    pub unresolved_instructions: Vec<usize>,
+}
+
+impl AuthRequirements {
+    /// The virtual signature badges of the keys which may have to sign the transaction.
+    pub fn signatures(&self) -> BTreeSet<NonFungibleGlobalId> {
+        self.checks
+            .iter()
+            .flat_map(|check| check.access_rule.non_fungible_global_ids())
+            .filter(|id| is_signature_badge(&id.resource_address()))
+            .collect()
+    }
+
+    /// The resources and non-fungibles of which proofs may have to be put in the auth zone,
+    /// other than signatures and the badges the system adds by itself.
+    pub fn badges(&self) -> BTreeSet<ResourceOrNonFungible> {
+        self.checks
+            .iter()
+            .flat_map(|check| check.access_rule.resources_and_non_fungibles())
+            .filter(|resource| match resource {
+                ResourceOrNonFungible::NonFungible(id) => !is_virtual_badge(&id.resource_address()),
+                ResourceOrNonFungible::Resource(resource_address) => {
+                    !is_virtual_badge(resource_address)
+                }
+            })
+            .collect()
+    }
+}
+
+fn is_signature_badge(resource_address: &ResourceAddress) -> bool {
+    resource_address.eq(&SECP256K1_SIGNATURE_VIRTUAL_BADGE)
+        || resource_address.eq(&ED25519_SIGNATURE_VIRTUAL_BADGE)
+}
+
+fn is_virtual_badge(resource_address: &ResourceAddress) -> bool {
+    is_signature_badge(resource_address)
+        || resource_address.eq(&PACKAGE_OF_DIRECT_CALLER_VIRTUAL_BADGE)
+        || resource_address.eq(&GLOBAL_CALLER_VIRTUAL_BADGE)
+        || resource_address.eq(&SYSTEM_TRANSACTION_BADGE)
+}
+
+/// Extracts the [`AuthRequirements`] of manifests from a substate database.
+pub struct AuthRequirementsAnalyzer<'s, S: SubstateDatabase> {
+    substate_db: &'s S,
+}
+
+impl<'s, S: SubstateDatabase> AuthRequirementsAnalyzer<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        Self { substate_db }
+    }
+
+    pub fn analyze(&self, instructions: &[InstructionV1]) -> AuthRequirements {
+        let mut requirements = AuthRequirements::default();
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            let access_rule = match instruction {
+                InstructionV1::CallFunction {
+                    package_address,
+                    blueprint_name,
+                    function_name,
+                    ..
+                } => match package_address {
+                    DynamicPackageAddress::Static(package_address) => {
+                        self.function_access_rule(package_address, blueprint_name, function_name)
+                    }
+                    DynamicPackageAddress::Named(_) => None,
+                },
+                InstructionV1::CallMethod {
+                    address,
+                    method_name,
+                    ..
+                } => self.method_access_rule(address, ObjectModuleId::Main, method_name),
+                InstructionV1::CallMetadataMethod {
+                    address,
+                    method_name,
+                    ..
+                } => self.method_access_rule(address, ObjectModuleId::Metadata, method_name),
+                InstructionV1::CallRoyaltyMethod {
+                    address,
+                    method_name,
+                    ..
+                } => self.method_access_rule(address, ObjectModuleId::Royalty, method_name),
+                InstructionV1::CallAccessRulesMethod { .. }
+                | InstructionV1::CallDirectVaultMethod { .. } => None,
+                _ => continue,
+            };
+
+            match access_rule {
+                Some(AccessRule::AllowAll) => {}
+                Some(access_rule) => requirements.checks.push(AuthCheck {
+                    instruction_index,
+                    access_rule,
+                }),
+                None => requirements.unresolved_instructions.push(instruction_index),
+            }
+        }
+        requirements
+    }
+
+    fn function_access_rule(
+        &self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+    ) -> Option<AccessRule> {
+        match self
+            .auth_config(package_address, blueprint_name)?
+            .function_auth
+        {
+            FunctionAuth::AllowAll => Some(AccessRule::AllowAll),
+            FunctionAuth::AccessRules(rules) => rules.get(function_name).cloned(),
+            // The manifest is run by the transaction processor, rather than the root frame
+            FunctionAuth::RootOnly => Some(AccessRule::DenyAll),
+        }
+    }
+
+    fn method_access_rule(
+        &self,
+        address: &DynamicGlobalAddress,
+        module_id: ObjectModuleId,
+        method_name: &str,
+    ) -> Option<AccessRule> {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return None;
+        };
+        let blueprint_id = match module_id {
+            ObjectModuleId::Main => self.blueprint_id(address)?,
+            ObjectModuleId::Metadata => {
+                BlueprintId::new(&METADATA_MODULE_PACKAGE, METADATA_BLUEPRINT)
+            }
+            ObjectModuleId::Royalty => {
+                BlueprintId::new(&ROYALTY_MODULE_PACKAGE, COMPONENT_ROYALTY_BLUEPRINT)
+            }
+            ObjectModuleId::AccessRules => return None,
+        };
+
+        let static_roles = match self
+            .auth_config(&blueprint_id.package_address, &blueprint_id.blueprint_name)?
+            .method_auth
+        {
+            MethodAuthTemplate::AllowAll => return Some(AccessRule::AllowAll),
+            MethodAuthTemplate::StaticRoles(static_roles) => static_roles,
+        };
+        if let RoleSpecification::UseOuter = static_roles.roles {
+            return None;
+        }
+
+        match static_roles.methods.get(&MethodKey::new(method_name))? {
+            MethodAccessibility::Public => Some(AccessRule::AllowAll),
+            MethodAccessibility::OwnPackageOnly => Some(rule!(require(package_of_direct_caller(
+                blueprint_id.package_address
+            )))),
+            MethodAccessibility::OuterObjectOnly => None,
+            MethodAccessibility::RoleProtected(role_list) => {
+                let mut nodes = Vec::new();
+                for role_key in &role_list.list {
+                    match self.role_access_rule(address, module_id, role_key) {
+                        AccessRule::AllowAll => return Some(AccessRule::AllowAll),
+                        AccessRule::DenyAll => {}
+                        AccessRule::Protected(node) => nodes.push(node),
+                    }
+                }
+                Some(match nodes.len() {
+                    0 => AccessRule::DenyAll,
+                    1 => AccessRule::Protected(nodes.remove(0)),
+                    _ => AccessRule::Protected(AccessRuleNode::AnyOf(nodes)),
+                })
+            }
+        }
+    }
+
+    /// The rule of a role, which falls back to the owner role when the role isn't defined.
+    fn role_access_rule(
+        &self,
+        address: &GlobalAddress,
+        module_id: ObjectModuleId,
+        role_key: &RoleKey,
+    ) -> AccessRule {
+        if role_key.key.eq(SELF_ROLE) {
+            return rule!(require(global_caller(*address)));
+        }
+
+        let module_role_key = ModuleRoleKey::new(module_id, role_key.clone());
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<AccessRule>>(
+                address.as_node_id(),
+                ACCESS_RULES_BASE_PARTITION
+                    .at_offset(ACCESS_RULES_ROLE_DEF_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(&module_role_key).unwrap()),
+            )
+            .and_then(|entry| entry.value)
+            .unwrap_or_else(|| self.owner_access_rule(address))
+    }
+
+    fn owner_access_rule(&self, address: &GlobalAddress) -> AccessRule {
+        if let Some(owner_role) = self
+            .substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, OwnerRoleSubstate>(
+                address.as_node_id(),
+                ACCESS_RULES_BASE_PARTITION
+                    .at_offset(ACCESS_RULES_FIELDS_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Field(0u8),
+            )
+        {
+            return owner_role.owner_role_entry.rule;
+        }
+
+        // Virtual accounts and identities are owned by the key their address is derived from,
+        // until they are first called
+        let badge = match address.as_node_id().entity_type() {
+            Some(EntityType::GlobalVirtualSecp256k1Account)
+            | Some(EntityType::GlobalVirtualSecp256k1Identity) => SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+            Some(EntityType::GlobalVirtualEd25519Account)
+            | Some(EntityType::GlobalVirtualEd25519Identity) => ED25519_SIGNATURE_VIRTUAL_BADGE,
+            _ => return AccessRule::DenyAll,
+        };
+        let public_key_hash = address.as_node_id().as_bytes()[1..].to_vec();
+        rule!(require(NonFungibleGlobalId::new(
+            badge,
+            NonFungibleLocalId::bytes(public_key_hash).unwrap()
+        )))
+    }
+
+    fn blueprint_id(&self, address: &GlobalAddress) -> Option<BlueprintId> {
+        let type_info = self
+            .substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+                address.as_node_id(),
+                TYPE_INFO_FIELD_PARTITION,
+                &TypeInfoField::TypeInfo.into(),
+            );
+        match type_info {
+            Some(TypeInfoSubstate::Object(info)) => Some(info.blueprint_id),
+            Some(_) => None,
+            None => match address.as_node_id().entity_type()? {
+                EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account => {
+                    Some(BlueprintId::new(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT))
+                }
+                EntityType::GlobalVirtualSecp256k1Identity
+                | EntityType::GlobalVirtualEd25519Identity => {
+                    Some(BlueprintId::new(&IDENTITY_PACKAGE, IDENTITY_BLUEPRINT))
+                }
+                _ => None,
+            },
+        }
+    }
+
+    fn auth_config(
+        &self,
+        package_address: &PackageAddress,
+        blueprint_name: &str,
+    ) -> Option<AuthConfig> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, KeyValueEntrySubstate<AuthConfig>>(
+                package_address.as_node_id(),
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_AUTH_TEMPLATE_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(
+                    scrypto_encode(&BlueprintVersionKey::new_default(blueprint_name)).unwrap(),
+                ),
+            )
+            .and_then(|entry| entry.value)
+    }
+}
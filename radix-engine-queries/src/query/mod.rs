@@ -1,7 +1,15 @@
 mod accounter;
+mod auth_requirements;
+mod ownership_graph;
+mod state_query;
+mod storage_footprint;
 mod traverse;
 mod vault_finder;
 
 pub use accounter::*;
+pub use auth_requirements::*;
+pub use ownership_graph::*;
+pub use state_query::*;
+pub use storage_footprint::*;
 pub use traverse::*;
 pub use vault_finder::*;
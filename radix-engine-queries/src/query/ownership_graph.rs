@@ -0,0 +1,205 @@
+use super::{StateTreeTraverser, StateTreeVisitor};
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::types::SubstateKey;
+use radix_engine_interface::address::AddressBech32Encoder;
+use radix_engine_interface::types::{
+    NodeId, PartitionNumber, TypeInfoField, TYPE_INFO_FIELD_PARTITION,
+};
+use radix_engine_store_interface::{
+    db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::{ListableSubstateDatabase, SubstateDatabase},
+};
+use sbor::rust::fmt::Write;
+use sbor::rust::prelude::*;
+
+/// A node of the ownership graph, with a human readable type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipGraphNode {
+    pub node_id: NodeId,
+    /// The blueprint name for objects, or the kind of the node otherwise.
+    pub type_name: String,
+    /// The distance from the global entity owning this node, 0 for global entities.
+    pub depth: u32,
+}
+
+/// An ownership relation: the substate of `parent` under the given key owns `child`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnershipGraphEdge {
+    pub parent: NodeId,
+    pub partition_number: PartitionNumber,
+    pub substate_key: SubstateKey,
+    pub child: NodeId,
+}
+
+/// The tree of nodes owned by global entities, as persisted in a substate database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnershipGraph {
+    pub nodes: Vec<OwnershipGraphNode>,
+    pub edges: Vec<OwnershipGraphEdge>,
+}
+
+struct OwnershipGraphCollector {
+    nodes: Vec<(NodeId, u32)>,
+    edges: Vec<OwnershipGraphEdge>,
+}
+
+impl StateTreeVisitor for OwnershipGraphCollector {
+    fn visit_node_id(
+        &mut self,
+        parent_id: Option<&(NodeId, PartitionNumber, SubstateKey)>,
+        node_id: &NodeId,
+        depth: u32,
+    ) {
+        self.nodes.push((*node_id, depth));
+        if let Some((parent, partition_number, substate_key)) = parent_id {
+            self.edges.push(OwnershipGraphEdge {
+                parent: *parent,
+                partition_number: *partition_number,
+                substate_key: substate_key.clone(),
+                child: *node_id,
+            });
+        }
+    }
+}
+
+impl OwnershipGraph {
+    /// Builds the graph of all global entities in the database and the nodes they own.
+    pub fn from_all_global_entities<S: SubstateDatabase + ListableSubstateDatabase>(
+        substate_db: &S,
+    ) -> Self {
+        let mut roots = index_set_new();
+        for partition_key in substate_db.list_partition_keys() {
+            let (node_id, _) = SpreadPrefixKeyMapper::from_db_partition_key(&partition_key);
+            if node_id.is_global() {
+                roots.insert(node_id);
+            }
+        }
+        Self::from_roots(substate_db, roots)
+    }
+
+    /// Builds the graph of the given nodes and the nodes they own.
+    pub fn from_roots<S: SubstateDatabase, I: IntoIterator<Item = NodeId>>(
+        substate_db: &S,
+        roots: I,
+    ) -> Self {
+        let mut collector = OwnershipGraphCollector {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+        {
+            let mut traverser = StateTreeTraverser::new(substate_db, &mut collector, u32::MAX);
+            for root in roots {
+                traverser.traverse_all_descendents(None, root);
+            }
+        }
+
+        let nodes = collector
+            .nodes
+            .into_iter()
+            .map(|(node_id, depth)| OwnershipGraphNode {
+                node_id,
+                type_name: Self::type_name(substate_db, &node_id),
+                depth,
+            })
+            .collect();
+
+        Self {
+            nodes,
+            edges: collector.edges,
+        }
+    }
+
+    fn type_name<S: SubstateDatabase>(substate_db: &S, node_id: &NodeId) -> String {
+        match substate_db.get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+            node_id,
+            TYPE_INFO_FIELD_PARTITION,
+            &TypeInfoField::TypeInfo.into(),
+        ) {
+            Some(TypeInfoSubstate::Object(info)) => info.blueprint_id.blueprint_name,
+            Some(TypeInfoSubstate::KeyValueStore(_)) => "KeyValueStore".to_string(),
+            Some(TypeInfoSubstate::GlobalAddressReservation(_)) => {
+                "GlobalAddressReservation".to_string()
+            }
+            Some(TypeInfoSubstate::GlobalAddressPhantom(_)) => "GlobalAddressPhantom".to_string(),
+            None => "Unknown".to_string(),
+        }
+    }
+
+    /// Renders the graph in the Graphviz DOT format.
+    pub fn to_dot(&self, address_bech32_encoder: &AddressBech32Encoder) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph ownership {{").unwrap();
+        for node in &self.nodes {
+            let address = encode_node_id(address_bech32_encoder, &node.node_id);
+            writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\\n{}\"];",
+                address, node.type_name, address
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}: {}\"];",
+                encode_node_id(address_bech32_encoder, &edge.parent),
+                encode_node_id(address_bech32_encoder, &edge.child),
+                edge.partition_number.0,
+                format_substate_key(&edge.substate_key)
+            )
+            .unwrap();
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    /// Renders the graph as a JSON object with `nodes` and `edges` arrays.
+    pub fn to_json(&self, address_bech32_encoder: &AddressBech32Encoder) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    "{{\"id\":\"{}\",\"type\":\"{}\",\"depth\":{}}}",
+                    encode_node_id(address_bech32_encoder, &node.node_id),
+                    node.type_name,
+                    node.depth
+                )
+            })
+            .collect();
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                format!(
+                    "{{\"parent\":\"{}\",\"child\":\"{}\",\"partition\":{},\"key\":\"{}\"}}",
+                    encode_node_id(address_bech32_encoder, &edge.parent),
+                    encode_node_id(address_bech32_encoder, &edge.child),
+                    edge.partition_number.0,
+                    format_substate_key(&edge.substate_key)
+                )
+            })
+            .collect();
+        format!(
+            "{{\"nodes\":[{}],\"edges\":[{}]}}",
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+}
+
+fn encode_node_id(address_bech32_encoder: &AddressBech32Encoder, node_id: &NodeId) -> String {
+    address_bech32_encoder
+        .encode(node_id.as_ref())
+        .unwrap_or_else(|_| node_id.to_hex())
+}
+
+fn format_substate_key(substate_key: &SubstateKey) -> String {
+    match substate_key {
+        SubstateKey::Field(key) => format!("field {}", key),
+        SubstateKey::Map(key) => format!("map {}", hex::encode(key)),
+        SubstateKey::Sorted((sort_prefix, key)) => {
+            format!("sorted {} {}", sort_prefix, hex::encode(key))
+        }
+    }
+}
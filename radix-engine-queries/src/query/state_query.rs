@@ -0,0 +1,366 @@
+use super::{StateTreeTraverser, StateTreeVisitor};
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::types::{BlueprintId, Decimal, MapKey, ScryptoValue, SubstateKey};
+use radix_engine_interface::blueprints::resource::{
+    LiquidFungibleResource, LiquidNonFungibleVault,
+};
+use radix_engine_interface::data::scrypto::model::NonFungibleLocalId;
+use radix_engine_interface::types::{
+    EntityType, NodeId, PackageAddress, PartitionNumber, ResourceAddress, TypeInfoField,
+    MAIN_BASE_PARTITION, TYPE_INFO_FIELD_PARTITION,
+};
+use radix_engine_store_interface::{
+    db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::{ListableSubstateDatabase, SubstateDatabase},
+};
+use sbor::rust::prelude::*;
+
+/// Filters on the global entities matched by a [`StateQuery`]. Unset filters match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityFilter {
+    pub entity_types: Option<Vec<EntityType>>,
+    pub package_address: Option<PackageAddress>,
+    pub blueprint_name: Option<String>,
+    /// Only match entities owning a vault of this resource, directly or through owned objects.
+    pub holds_resource: Option<ResourceAddress>,
+}
+
+/// Which owned nodes to include in the result of each matched entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateQueryExpansion {
+    pub vaults: bool,
+    pub key_value_stores: bool,
+    /// The maximum ownership depth below the global entity to descend into.
+    pub max_depth: u32,
+}
+
+impl Default for StateQueryExpansion {
+    fn default() -> Self {
+        Self {
+            vaults: false,
+            key_value_stores: false,
+            max_depth: u32::MAX,
+        }
+    }
+}
+
+/// Entities are returned in ascending order of their node ids. A page starts after the
+/// `cursor`, which is the `next_cursor` of the previous page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    pub cursor: Option<NodeId>,
+    pub limit: usize,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            cursor: None,
+            limit: usize::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateQuery {
+    pub filter: EntityFilter,
+    pub expand: StateQueryExpansion,
+    pub pagination: Pagination,
+}
+
+impl StateQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn of_entity_types(mut self, entity_types: Vec<EntityType>) -> Self {
+        self.filter.entity_types = Some(entity_types);
+        self
+    }
+
+    pub fn of_blueprint(mut self, package_address: PackageAddress, blueprint_name: &str) -> Self {
+        self.filter.package_address = Some(package_address);
+        self.filter.blueprint_name = Some(blueprint_name.to_string());
+        self
+    }
+
+    pub fn of_package(mut self, package_address: PackageAddress) -> Self {
+        self.filter.package_address = Some(package_address);
+        self
+    }
+
+    pub fn holding_resource(mut self, resource_address: ResourceAddress) -> Self {
+        self.filter.holds_resource = Some(resource_address);
+        self
+    }
+
+    pub fn with_vaults(mut self) -> Self {
+        self.expand.vaults = true;
+        self
+    }
+
+    pub fn with_key_value_stores(mut self) -> Self {
+        self.expand.key_value_stores = true;
+        self
+    }
+
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.expand.max_depth = max_depth;
+        self
+    }
+
+    pub fn page(mut self, cursor: Option<NodeId>, limit: usize) -> Self {
+        self.pagination = Pagination { cursor, limit };
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VaultContents {
+    Fungible {
+        amount: Decimal,
+    },
+    NonFungible {
+        amount: Decimal,
+        ids: Vec<NonFungibleLocalId>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultResult {
+    pub vault_id: NodeId,
+    pub resource_address: ResourceAddress,
+    pub contents: VaultContents,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValueStoreResult {
+    pub node_id: NodeId,
+    /// The entries of the store, as pairs of the encoded key and the decoded value.
+    pub entries: Vec<(Vec<u8>, ScryptoValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityResult {
+    pub node_id: NodeId,
+    /// The blueprint of the entity, or `None` if the entity is not an object.
+    pub blueprint_id: Option<BlueprintId>,
+    pub vaults: Vec<VaultResult>,
+    pub key_value_stores: Vec<KeyValueStoreResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateQueryResult {
+    pub entities: Vec<EntityResult>,
+    /// The cursor of the next page, or `None` if this is the last page.
+    pub next_cursor: Option<NodeId>,
+}
+
+/// Executes [`StateQuery`]s against a substate database.
+pub struct StateQueryEngine<'s, S: SubstateDatabase + ListableSubstateDatabase> {
+    substate_db: &'s S,
+}
+
+impl<'s, S: SubstateDatabase + ListableSubstateDatabase> StateQueryEngine<'s, S> {
+    pub fn new(substate_db: &'s S) -> Self {
+        Self { substate_db }
+    }
+
+    pub fn execute(&self, query: &StateQuery) -> StateQueryResult {
+        let global_entities: BTreeSet<NodeId> = self
+            .substate_db
+            .list_partition_keys()
+            .map(|partition_key| SpreadPrefixKeyMapper::from_db_partition_key(&partition_key).0)
+            .filter(|node_id| node_id.is_global())
+            .collect();
+
+        let mut entities: Vec<EntityResult> = Vec::new();
+        let mut next_cursor = None;
+        for node_id in global_entities {
+            if let Some(cursor) = &query.pagination.cursor {
+                if node_id <= *cursor {
+                    continue;
+                }
+            }
+            let Some(entity) = self.query_entity(node_id, query) else {
+                continue;
+            };
+            if entities.len() == query.pagination.limit {
+                next_cursor = entities.last().map(|entity| entity.node_id);
+                break;
+            }
+            entities.push(entity);
+        }
+
+        StateQueryResult {
+            entities,
+            next_cursor,
+        }
+    }
+
+    fn query_entity(&self, node_id: NodeId, query: &StateQuery) -> Option<EntityResult> {
+        let filter = &query.filter;
+        if let Some(entity_types) = &filter.entity_types {
+            if !node_id
+                .entity_type()
+                .map_or(false, |entity_type| entity_types.contains(&entity_type))
+            {
+                return None;
+            }
+        }
+
+        let blueprint_id = match self.type_info(&node_id) {
+            Some(TypeInfoSubstate::Object(info)) => Some(info.blueprint_id),
+            _ => None,
+        };
+        if filter.package_address.is_some() || filter.blueprint_name.is_some() {
+            let blueprint_id = blueprint_id.as_ref()?;
+            if let Some(package_address) = &filter.package_address {
+                if blueprint_id.package_address.ne(package_address) {
+                    return None;
+                }
+            }
+            if let Some(blueprint_name) = &filter.blueprint_name {
+                if blueprint_id.blueprint_name.ne(blueprint_name) {
+                    return None;
+                }
+            }
+        }
+
+        let expand = &query.expand;
+        let mut vaults = Vec::new();
+        let mut key_value_stores = Vec::new();
+        if expand.vaults || expand.key_value_stores || filter.holds_resource.is_some() {
+            let mut collector = OwnedNodeCollector::default();
+            StateTreeTraverser::new(self.substate_db, &mut collector, expand.max_depth)
+                .traverse_all_descendents(None, node_id);
+
+            if let Some(resource_address) = &filter.holds_resource {
+                if !collector
+                    .vaults
+                    .iter()
+                    .any(|vault| vault.resource_address.eq(resource_address))
+                {
+                    return None;
+                }
+            }
+            if expand.vaults {
+                vaults = collector.vaults;
+            }
+            if expand.key_value_stores {
+                key_value_stores = collector
+                    .nodes
+                    .into_iter()
+                    .filter(|node_id| {
+                        matches!(
+                            self.type_info(node_id),
+                            Some(TypeInfoSubstate::KeyValueStore(_))
+                        )
+                    })
+                    .map(|node_id| self.key_value_store(node_id))
+                    .collect();
+            }
+        }
+
+        Some(EntityResult {
+            node_id,
+            blueprint_id,
+            vaults,
+            key_value_stores,
+        })
+    }
+
+    fn type_info(&self, node_id: &NodeId) -> Option<TypeInfoSubstate> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+                node_id,
+                TYPE_INFO_FIELD_PARTITION,
+                &TypeInfoField::TypeInfo.into(),
+            )
+    }
+
+    fn key_value_store(&self, node_id: NodeId) -> KeyValueStoreResult {
+        let entries = self
+            .substate_db
+            .list_mapped::<SpreadPrefixKeyMapper, ScryptoValue, MapKey>(
+                &node_id,
+                MAIN_BASE_PARTITION,
+            )
+            .filter_map(|(substate_key, value)| match substate_key {
+                SubstateKey::Map(key) => Some((key, value)),
+                _ => None,
+            })
+            .collect();
+        KeyValueStoreResult { node_id, entries }
+    }
+}
+
+#[derive(Default)]
+struct OwnedNodeCollector {
+    nodes: Vec<NodeId>,
+    vaults: Vec<VaultResult>,
+}
+
+impl StateTreeVisitor for OwnedNodeCollector {
+    fn visit_fungible_vault(
+        &mut self,
+        vault_id: NodeId,
+        address: &ResourceAddress,
+        resource: &LiquidFungibleResource,
+    ) {
+        self.vaults.push(VaultResult {
+            vault_id,
+            resource_address: *address,
+            contents: VaultContents::Fungible {
+                amount: resource.amount(),
+            },
+        });
+    }
+
+    fn visit_non_fungible_vault(
+        &mut self,
+        vault_id: NodeId,
+        address: &ResourceAddress,
+        resource: &LiquidNonFungibleVault,
+    ) {
+        self.vaults.push(VaultResult {
+            vault_id,
+            resource_address: *address,
+            contents: VaultContents::NonFungible {
+                amount: resource.amount,
+                ids: Vec::new(),
+            },
+        });
+    }
+
+    fn visit_non_fungible(
+        &mut self,
+        vault_id: NodeId,
+        _address: &ResourceAddress,
+        id: &NonFungibleLocalId,
+    ) {
+        if let Some(VaultResult {
+            contents: VaultContents::NonFungible { ids, .. },
+            ..
+        }) = self
+            .vaults
+            .iter_mut()
+            .rev()
+            .find(|vault| vault.vault_id == vault_id)
+        {
+            ids.push(id.clone());
+        }
+    }
+
+    fn visit_node_id(
+        &mut self,
+        parent_id: Option<&(NodeId, PartitionNumber, SubstateKey)>,
+        node_id: &NodeId,
+        _depth: u32,
+    ) {
+        // The root is the queried entity itself
+        if parent_id.is_some() {
+            self.nodes.push(*node_id);
+        }
+    }
+}
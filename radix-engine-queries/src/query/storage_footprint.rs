@@ -0,0 +1,105 @@
+use super::{StateTreeTraverser, StateTreeVisitor};
+use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
+use radix_engine::types::SubstateKey;
+use radix_engine_interface::types::{
+    NodeId, PartitionNumber, TypeInfoField, TYPE_INFO_FIELD_PARTITION,
+};
+use radix_engine_store_interface::{
+    db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::{DbSortKey, SubstateDatabase},
+};
+use sbor::rust::prelude::*;
+
+/// The number of largest substates reported by default.
+pub const DEFAULT_LARGEST_SUBSTATES: usize = 10;
+
+/// The size of a stored substate, sort key included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstateFootprint {
+    pub node_id: NodeId,
+    pub partition_number: PartitionNumber,
+    pub sort_key: DbSortKey,
+    pub size: usize,
+}
+
+/// The storage used by an entity, together with all the nodes it owns, e.g. its vaults and key
+/// value stores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityFootprint {
+    pub node_id: NodeId,
+    /// The entity followed by the nodes it owns, directly or transitively.
+    pub node_ids: Vec<NodeId>,
+    pub substate_count: usize,
+    /// The total size of the sort keys and values of all substates.
+    pub total_bytes: usize,
+    /// The largest substates, in descending order of size.
+    pub largest_substates: Vec<SubstateFootprint>,
+}
+
+impl EntityFootprint {
+    /// Measures the storage footprint of the given entity, or returns `None` if it doesn't exist.
+    pub fn measure<S: SubstateDatabase>(
+        substate_db: &S,
+        node_id: &NodeId,
+        max_largest_substates: usize,
+    ) -> Option<Self> {
+        substate_db.get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+            node_id,
+            TYPE_INFO_FIELD_PARTITION,
+            &TypeInfoField::TypeInfo.into(),
+        )?;
+
+        let mut collector = NodeCollector {
+            node_ids: Vec::new(),
+        };
+        StateTreeTraverser::new(substate_db, &mut collector, u32::MAX)
+            .traverse_all_descendents(None, *node_id);
+
+        let mut substate_count = 0;
+        let mut total_bytes = 0;
+        let mut substates = Vec::new();
+        for node_id in &collector.node_ids {
+            for partition_number in 0..=u8::MAX {
+                let partition_number = PartitionNumber(partition_number);
+                let partition_key =
+                    SpreadPrefixKeyMapper::to_db_partition_key(node_id, partition_number);
+                for (sort_key, value) in substate_db.list_entries(&partition_key) {
+                    let size = sort_key.0.len() + value.len();
+                    substate_count += 1;
+                    total_bytes += size;
+                    substates.push(SubstateFootprint {
+                        node_id: *node_id,
+                        partition_number,
+                        sort_key,
+                        size,
+                    });
+                }
+            }
+        }
+        substates.sort_by(|a, b| b.size.cmp(&a.size));
+        substates.truncate(max_largest_substates);
+
+        Some(Self {
+            node_id: *node_id,
+            node_ids: collector.node_ids,
+            substate_count,
+            total_bytes,
+            largest_substates: substates,
+        })
+    }
+}
+
+struct NodeCollector {
+    node_ids: Vec<NodeId>,
+}
+
+impl StateTreeVisitor for NodeCollector {
+    fn visit_node_id(
+        &mut self,
+        _parent_id: Option<&(NodeId, PartitionNumber, SubstateKey)>,
+        node_id: &NodeId,
+        _depth: u32,
+    ) {
+        self.node_ids.push(*node_id);
+    }
+}
@@ -127,6 +127,7 @@ pub enum TypedMainModuleSubstateKey {
     PackageOriginalCodeKey(Hash),
     PackageInstrumentedCodeKey(Hash),
     FungibleResourceField(FungibleResourceManagerField),
+    FungibleResourceReservesIndexKey(NodeId),
     NonFungibleResourceField(NonFungibleResourceManagerField),
     NonFungibleResourceData(NonFungibleLocalId),
     FungibleVaultField(FungibleVaultField),
@@ -315,9 +316,21 @@ fn to_typed_object_substate_key_internal(
             }
         }
         EntityType::GlobalFungibleResourceManager => {
-            TypedMainModuleSubstateKey::FungibleResourceField(
-                FungibleResourceManagerField::try_from(substate_key)?,
-            )
+            let partition_offset =
+                FungibleResourceManagerPartitionOffset::try_from(partition_offset)?;
+            match partition_offset {
+                FungibleResourceManagerPartitionOffset::ResourceManager => {
+                    TypedMainModuleSubstateKey::FungibleResourceField(
+                        FungibleResourceManagerField::try_from(substate_key)?,
+                    )
+                }
+                FungibleResourceManagerPartitionOffset::Reserves => {
+                    let key = substate_key.for_map().ok_or(())?;
+                    TypedMainModuleSubstateKey::FungibleResourceReservesIndexKey(
+                        scrypto_decode(&key).map_err(|_| ())?,
+                    )
+                }
+            }
         }
         EntityType::GlobalNonFungibleResourceManager => {
             let partition_offset =
@@ -455,12 +468,15 @@ pub enum TypedTypeInfoModuleSubstateValue {
 #[derive(Debug, Clone)]
 pub enum TypedAccessRulesModuleSubstateValue {
     OwnerRole(OwnerRoleSubstate),
+    Pause(PauseSubstate),
+    OwnerRoleHistory(OwnerRoleHistorySubstate),
     Rule(KeyValueEntrySubstate<AccessRule>),
 }
 
 #[derive(Debug, Clone)]
 pub enum TypedRoyaltyModuleSubstateValue {
     ComponentRoyalty(ComponentRoyaltySubstate),
+    ComponentRent(ComponentRentSubstate),
     ComponentMethodRoyalty(ComponentMethodRoyaltySubstate),
 }
 
@@ -483,6 +499,7 @@ pub enum TypedMainModuleSubstateValue {
     PackageOriginalCode(KeyValueEntrySubstate<PackageOriginalCodeSubstate>),
     PackageInstrumentedCode(KeyValueEntrySubstate<PackageInstrumentedCodeSubstate>),
     FungibleResource(TypedFungibleResourceManagerFieldValue),
+    FungibleResourceReservesIndexEntry(ReserveHolding),
     NonFungibleResource(TypedNonFungibleResourceManagerFieldValue),
     NonFungibleResourceData(KeyValueEntrySubstate<ScryptoOwnedRawValue>),
     FungibleVault(TypedFungibleVaultFieldValue),
@@ -514,6 +531,7 @@ pub enum TypedPackageFieldValue {
 pub enum TypedFungibleResourceManagerFieldValue {
     Divisibility(FungibleResourceManagerDivisibilitySubstate),
     TotalSupply(FungibleResourceManagerTotalSupplySubstate),
+    ReserveTracking(FungibleResourceManagerReserveTrackingSubstate),
 }
 
 #[derive(Debug, Clone)]
@@ -544,6 +562,9 @@ pub enum TypedConsensusManagerFieldValue {
     CurrentProposalStatistic(CurrentProposalStatisticSubstate),
     CurrentTimeRoundedToMinutes(ProposerMinuteTimestampSubstate),
     CurrentTime(ProposerMilliTimestampSubstate),
+    EpochHistory(EpochHistorySubstate),
+    InvocationFilter(InvocationFilterSubstate),
+    InstantiationDeposits(InstantiationDepositsSubstate),
 }
 
 #[derive(Debug, Clone)]
@@ -585,6 +606,7 @@ pub enum TypedMultiResourcePoolFieldValue {
 #[derive(Debug, Clone)]
 pub enum TypedTransactionTrackerFieldValue {
     TransactionTracker(TransactionTrackerSubstate),
+    IdempotencyKeys(IdempotencyKeysSubstate),
 }
 
 #[derive(Debug, Clone)]
@@ -622,6 +644,14 @@ fn to_typed_substate_value_internal(
                     AccessRulesField::OwnerRole => TypedSubstateValue::AccessRulesModule(
                         TypedAccessRulesModuleSubstateValue::OwnerRole(scrypto_decode(data)?),
                     ),
+                    AccessRulesField::Pause => TypedSubstateValue::AccessRulesModule(
+                        TypedAccessRulesModuleSubstateValue::Pause(scrypto_decode(data)?),
+                    ),
+                    AccessRulesField::OwnerRoleHistory => TypedSubstateValue::AccessRulesModule(
+                        TypedAccessRulesModuleSubstateValue::OwnerRoleHistory(scrypto_decode(
+                            data,
+                        )?),
+                    ),
                 }
             }
             TypedAccessRulesSubstateKey::Rule(_) => TypedSubstateValue::AccessRulesModule(
@@ -633,6 +663,9 @@ fn to_typed_substate_value_internal(
                 TypedRoyaltyModuleSubstateKey::RoyaltyField(RoyaltyField::RoyaltyAccumulator) => {
                     TypedRoyaltyModuleSubstateValue::ComponentRoyalty(scrypto_decode(data)?)
                 }
+                TypedRoyaltyModuleSubstateKey::RoyaltyField(RoyaltyField::Rent) => {
+                    TypedRoyaltyModuleSubstateValue::ComponentRent(scrypto_decode(data)?)
+                }
                 TypedRoyaltyModuleSubstateKey::RoyaltyMethodRoyaltyEntryKey(_) => {
                     TypedRoyaltyModuleSubstateValue::ComponentMethodRoyalty(scrypto_decode(data)?)
                 }
@@ -694,8 +727,14 @@ fn to_typed_object_substate_value(
                 FungibleResourceManagerField::TotalSupply => {
                     TypedFungibleResourceManagerFieldValue::TotalSupply(scrypto_decode(data)?)
                 }
+                FungibleResourceManagerField::ReserveTracking => {
+                    TypedFungibleResourceManagerFieldValue::ReserveTracking(scrypto_decode(data)?)
+                }
             })
         }
+        TypedMainModuleSubstateKey::FungibleResourceReservesIndexKey(_) => {
+            TypedMainModuleSubstateValue::FungibleResourceReservesIndexEntry(scrypto_decode(data)?)
+        }
         TypedMainModuleSubstateKey::NonFungibleResourceField(offset) => {
             TypedMainModuleSubstateValue::NonFungibleResource(match offset {
                 NonFungibleResourceManagerField::IdType => {
@@ -764,6 +803,15 @@ fn to_typed_object_substate_value(
                 ConsensusManagerField::CurrentTime => {
                     TypedConsensusManagerFieldValue::CurrentTime(scrypto_decode(data)?)
                 }
+                ConsensusManagerField::EpochHistory => {
+                    TypedConsensusManagerFieldValue::EpochHistory(scrypto_decode(data)?)
+                }
+                ConsensusManagerField::InvocationFilter => {
+                    TypedConsensusManagerFieldValue::InvocationFilter(scrypto_decode(data)?)
+                }
+                ConsensusManagerField::InstantiationDeposits => {
+                    TypedConsensusManagerFieldValue::InstantiationDeposits(scrypto_decode(data)?)
+                }
             })
         }
         TypedMainModuleSubstateKey::ConsensusManagerRegisteredValidatorsByStakeIndexKey(_) => {
@@ -838,6 +886,9 @@ fn to_typed_object_substate_value(
                 TransactionTrackerField::TransactionTracker => {
                     TypedTransactionTrackerFieldValue::TransactionTracker(scrypto_decode(data)?)
                 }
+                TransactionTrackerField::IdempotencyKeys => {
+                    TypedTransactionTrackerFieldValue::IdempotencyKeys(scrypto_decode(data)?)
+                }
             })
         }
         TypedMainModuleSubstateKey::TransactionTrackerCollectionEntry(_) => {
@@ -39,6 +39,34 @@ pub enum DatabaseUpdate {
     Delete,
 }
 
+/// How slow a lookup of a single substate is, compared to reading the next substate of a
+/// partition which is being iterated over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadLatency {
+    /// Lookups are about as fast as iteration, e.g. on an SSD or in memory.
+    Low,
+    /// Lookups are much slower than iteration, e.g. on an HDD.
+    High,
+}
+
+/// The performance characteristics advertised by a database, which Track uses to decide how to
+/// read from it. They never affect the outcome or the fees of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatabaseCharacteristics {
+    pub read_latency: ReadLatency,
+    /// Whether the database serves concurrent reads without serializing them.
+    pub supports_parallel_reads: bool,
+}
+
+impl Default for DatabaseCharacteristics {
+    fn default() -> Self {
+        Self {
+            read_latency: ReadLatency::Low,
+            supports_parallel_reads: false,
+        }
+    }
+}
+
 /// A read interface between Track and a database vendor.
 pub trait SubstateDatabase {
     /// Reads a substate value by its partition and sort key, or [`Option::None`] if missing.
@@ -54,6 +82,11 @@ pub trait SubstateDatabase {
         &self,
         partition_key: &DbPartitionKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// The performance characteristics of the database.
+    fn characteristics(&self) -> DatabaseCharacteristics {
+        DatabaseCharacteristics::default()
+    }
 }
 
 /// A write interface between Track and a database vendor.
@@ -0,0 +1,486 @@
+use radix_engine_store_interface::interface::*;
+use sbor::rust::prelude::*;
+
+/// How the commits made to a [`CachedSubstateStore`] reach the underlying store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheWriteMode {
+    /// Every commit is committed to the underlying store straight away.
+    WriteThrough,
+    /// Commits are buffered in memory, and only committed to the underlying store on
+    /// [`CachedSubstateStore::flush`].
+    WriteBack,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+    /// Reads served from memory, including reads of buffered writes.
+    pub hits: u64,
+    /// Reads served by the underlying store.
+    pub misses: u64,
+    /// Entries dropped from the cache to stay within its capacity.
+    pub evictions: u64,
+    /// Buffered writes committed to the underlying store.
+    pub flushes: u64,
+}
+
+impl CacheMetrics {
+    /// The ratio of reads served from memory, or zero if nothing was read yet.
+    pub fn hit_rate(&self) -> f64 {
+        let reads = self.hits + self.misses;
+        if reads == 0 {
+            0.0
+        } else {
+            self.hits as f64 / reads as f64
+        }
+    }
+}
+
+/// A least recently used cache of substate values, which also remembers missing substates.
+struct SubstateCache {
+    capacity: usize,
+    entries: IndexMap<DbSubstateKey, (Option<DbSubstateValue>, u64)>,
+    recency: BTreeMap<u64, DbSubstateKey>,
+    next_tick: u64,
+}
+
+impl SubstateCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: index_map_new(),
+            recency: BTreeMap::new(),
+            next_tick: 0,
+        }
+    }
+
+    fn tick(&mut self) -> u64 {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        tick
+    }
+
+    fn get(&mut self, key: &DbSubstateKey) -> Option<Option<DbSubstateValue>> {
+        let tick = self.tick();
+        let (value, last_used) = self.entries.get_mut(key)?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, key.clone());
+        *last_used = tick;
+        Some(value.clone())
+    }
+
+    /// Inserts a value, returning the number of entries evicted to make room for it.
+    fn insert(&mut self, key: DbSubstateKey, value: Option<DbSubstateValue>) -> u64 {
+        if self.capacity == 0 {
+            return 0;
+        }
+        let tick = self.tick();
+        if let Some((_, last_used)) = self.entries.insert(key.clone(), (value, tick)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(tick, key);
+
+        let mut evictions = 0;
+        while self.entries.len() > self.capacity {
+            let (_, evicted) = self
+                .recency
+                .pop_first()
+                .expect("Recency tracks every cached entry");
+            self.entries.swap_remove(&evicted);
+            evictions += 1;
+        }
+        evictions
+    }
+
+    fn update_if_present(&mut self, key: &DbSubstateKey, value: Option<DbSubstateValue>) {
+        if let Some((cached, _)) = self.entries.get_mut(key) {
+            *cached = value;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+}
+
+/// An in-memory read cache over a substate database, with optional write buffering.
+///
+/// In [`CacheWriteMode::WriteBack`] mode, buffered writes are visible to the reads of this store
+/// but not to the underlying store until [`CachedSubstateStore::flush`] is called, and are lost if
+/// the store is dropped without flushing.
+pub struct CachedSubstateStore<S> {
+    inner: S,
+    mode: CacheWriteMode,
+    cache: RefCell<SubstateCache>,
+    pending: DatabaseUpdates,
+    metrics: RefCell<CacheMetrics>,
+}
+
+impl<S> CachedSubstateStore<S> {
+    /// Wraps the given store, caching up to `capacity` substates.
+    pub fn new(inner: S, capacity: usize, mode: CacheWriteMode) -> Self {
+        Self {
+            inner,
+            mode,
+            cache: RefCell::new(SubstateCache::new(capacity)),
+            pending: index_map_new(),
+            metrics: RefCell::new(CacheMetrics::default()),
+        }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn mode(&self) -> CacheWriteMode {
+        self.mode
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cache.borrow().capacity
+    }
+
+    /// The number of substates currently cached.
+    pub fn cached_count(&self) -> usize {
+        self.cache.borrow().entries.len()
+    }
+
+    /// The number of substate writes waiting to be flushed.
+    pub fn pending_count(&self) -> usize {
+        self.pending
+            .values()
+            .map(|partition_updates| partition_updates.len())
+            .sum()
+    }
+
+    pub fn metrics(&self) -> CacheMetrics {
+        self.metrics.borrow().clone()
+    }
+
+    pub fn reset_metrics(&mut self) {
+        *self.metrics.get_mut() = CacheMetrics::default();
+    }
+
+    fn pending_update(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<&DatabaseUpdate> {
+        self.pending
+            .get(partition_key)
+            .and_then(|partition_updates| partition_updates.get(sort_key))
+    }
+}
+
+impl<S: CommittableSubstateDatabase> CachedSubstateStore<S> {
+    /// Commits the buffered writes to the underlying store.
+    pub fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let pending = core::mem::replace(&mut self.pending, index_map_new());
+        self.inner.commit(&pending);
+        self.metrics.get_mut().flushes += 1;
+    }
+
+    /// Flushes the buffered writes and returns the underlying store for direct modification.
+    ///
+    /// The cache is cleared, as writes made to the underlying store directly bypass it.
+    pub fn inner_mut(&mut self) -> &mut S {
+        self.flush();
+        self.cache.get_mut().clear();
+        &mut self.inner
+    }
+
+    /// Flushes the buffered writes and returns the underlying store.
+    pub fn into_inner(mut self) -> S {
+        self.flush();
+        self.inner
+    }
+}
+
+impl<S: SubstateDatabase> SubstateDatabase for CachedSubstateStore<S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        if let Some(update) = self.pending_update(partition_key, sort_key) {
+            self.metrics.borrow_mut().hits += 1;
+            return match update {
+                DatabaseUpdate::Set(value) => Some(value.clone()),
+                DatabaseUpdate::Delete => None,
+            };
+        }
+
+        let key = (partition_key.clone(), sort_key.clone());
+        if let Some(value) = self.cache.borrow_mut().get(&key) {
+            self.metrics.borrow_mut().hits += 1;
+            return value;
+        }
+
+        let value = self.inner.get_substate(partition_key, sort_key);
+        let evictions = self.cache.borrow_mut().insert(key, value.clone());
+        let mut metrics = self.metrics.borrow_mut();
+        metrics.misses += 1;
+        metrics.evictions += evictions;
+        value
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match self.pending.get(partition_key) {
+            None => self.inner.list_entries(partition_key),
+            Some(partition_updates) => {
+                let mut entries: BTreeMap<DbSortKey, DbSubstateValue> =
+                    self.inner.list_entries(partition_key).collect();
+                for (sort_key, update) in partition_updates {
+                    match update {
+                        DatabaseUpdate::Set(value) => {
+                            entries.insert(sort_key.clone(), value.clone());
+                        }
+                        DatabaseUpdate::Delete => {
+                            entries.remove(sort_key);
+                        }
+                    }
+                }
+                Box::new(entries.into_iter())
+            }
+        }
+    }
+
+    fn characteristics(&self) -> DatabaseCharacteristics {
+        self.inner.characteristics()
+    }
+}
+
+impl<S: CommittableSubstateDatabase> CommittableSubstateDatabase for CachedSubstateStore<S> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let cache = self.cache.get_mut();
+        for (partition_key, partition_updates) in database_updates {
+            for (sort_key, update) in partition_updates {
+                let value = match update {
+                    DatabaseUpdate::Set(value) => Some(value.clone()),
+                    DatabaseUpdate::Delete => None,
+                };
+                cache.update_if_present(&(partition_key.clone(), sort_key.clone()), value);
+            }
+        }
+
+        match self.mode {
+            CacheWriteMode::WriteThrough => self.inner.commit(database_updates),
+            CacheWriteMode::WriteBack => {
+                for (partition_key, partition_updates) in database_updates {
+                    let pending = self
+                        .pending
+                        .entry(partition_key.clone())
+                        .or_insert_with(|| index_map_new());
+                    for (sort_key, update) in partition_updates {
+                        pending.insert(sort_key.clone(), update.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<S: ListableSubstateDatabase> ListableSubstateDatabase for CachedSubstateStore<S> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        let mut partition_keys: IndexSet<DbPartitionKey> =
+            self.inner.list_partition_keys().collect();
+        for (partition_key, partition_updates) in &self.pending {
+            if partition_updates
+                .values()
+                .any(|update| matches!(update, DatabaseUpdate::Set(..)))
+            {
+                partition_keys.insert(partition_key.clone());
+            }
+        }
+        Box::new(partition_keys.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    fn partition(n: u8) -> DbPartitionKey {
+        DbPartitionKey(vec![n])
+    }
+
+    fn sort_key(n: u8) -> DbSortKey {
+        DbSortKey(vec![n])
+    }
+
+    fn set(partition_n: u8, sort_n: u8, value: u8) -> DatabaseUpdates {
+        indexmap!(partition(partition_n) => indexmap!(sort_key(sort_n) => DatabaseUpdate::Set(vec![value])))
+    }
+
+    fn delete(partition_n: u8, sort_n: u8) -> DatabaseUpdates {
+        indexmap!(partition(partition_n) => indexmap!(sort_key(sort_n) => DatabaseUpdate::Delete))
+    }
+
+    fn store_with(
+        updates: &DatabaseUpdates,
+        capacity: usize,
+        mode: CacheWriteMode,
+    ) -> CachedSubstateStore<InMemorySubstateDatabase> {
+        let mut inner = InMemorySubstateDatabase::standard();
+        inner.commit(updates);
+        CachedSubstateStore::new(inner, capacity, mode)
+    }
+
+    #[test]
+    fn test_repeated_reads_are_served_from_cache() {
+        let store = store_with(&set(1, 1, 10), 10, CacheWriteMode::WriteThrough);
+
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![10])
+        );
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![10])
+        );
+        assert_eq!(store.get_substate(&partition(1), &sort_key(2)), None);
+        assert_eq!(store.get_substate(&partition(1), &sort_key(2)), None);
+
+        let metrics = store.metrics();
+        assert_eq!(metrics.hits, 2);
+        assert_eq!(metrics.misses, 2);
+        assert_eq!(metrics.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_least_recently_used_entries_are_evicted() {
+        let mut updates = set(1, 1, 10);
+        updates[&partition(1)].insert(sort_key(2), DatabaseUpdate::Set(vec![20]));
+        updates[&partition(1)].insert(sort_key(3), DatabaseUpdate::Set(vec![30]));
+        let store = store_with(&updates, 2, CacheWriteMode::WriteThrough);
+
+        store.get_substate(&partition(1), &sort_key(1));
+        store.get_substate(&partition(1), &sort_key(2));
+        store.get_substate(&partition(1), &sort_key(1));
+        store.get_substate(&partition(1), &sort_key(3));
+        assert_eq!(store.cached_count(), 2);
+        assert_eq!(store.metrics().evictions, 1);
+
+        // Key 2 was the least recently used, so only it needs to be read again
+        store.get_substate(&partition(1), &sort_key(1));
+        store.get_substate(&partition(1), &sort_key(2));
+        let metrics = store.metrics();
+        assert_eq!(metrics.hits, 2);
+        assert_eq!(metrics.misses, 4);
+    }
+
+    #[test]
+    fn test_write_through_commit_is_visible_everywhere() {
+        let mut store = store_with(&set(1, 1, 10), 10, CacheWriteMode::WriteThrough);
+        store.get_substate(&partition(1), &sort_key(1));
+
+        store.commit(&set(1, 1, 11));
+
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        assert_eq!(
+            store.inner().get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        assert_eq!(store.pending_count(), 0);
+
+        store.commit(&delete(1, 1));
+
+        assert_eq!(store.get_substate(&partition(1), &sort_key(1)), None);
+        assert_eq!(
+            store.inner().get_substate(&partition(1), &sort_key(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_write_back_commit_is_visible_to_inner_store_after_flush() {
+        let mut store = store_with(&set(1, 1, 10), 10, CacheWriteMode::WriteBack);
+        store.get_substate(&partition(1), &sort_key(1));
+
+        store.commit(&set(1, 1, 11));
+        store.commit(&set(2, 1, 20));
+        store.commit(&delete(2, 1));
+        store.commit(&set(1, 2, 12));
+
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        assert_eq!(store.get_substate(&partition(2), &sort_key(1)), None);
+        assert_eq!(
+            store.list_entries(&partition(1)).collect::<Vec<_>>(),
+            vec![(sort_key(1), vec![11]), (sort_key(2), vec![12])]
+        );
+        assert_eq!(
+            store.inner().get_substate(&partition(1), &sort_key(1)),
+            Some(vec![10])
+        );
+        assert_eq!(store.pending_count(), 3);
+
+        store.flush();
+
+        assert_eq!(store.pending_count(), 0);
+        assert_eq!(store.metrics().flushes, 1);
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        let inner = store.into_inner();
+        assert_eq!(
+            inner.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        assert_eq!(
+            inner.get_substate(&partition(1), &sort_key(2)),
+            Some(vec![12])
+        );
+        assert_eq!(inner.get_substate(&partition(2), &sort_key(1)), None);
+    }
+
+    #[test]
+    fn test_direct_writes_to_inner_store_are_not_hidden_by_cache() {
+        let mut store = store_with(&set(1, 1, 10), 10, CacheWriteMode::WriteBack);
+        store.get_substate(&partition(1), &sort_key(1));
+        store.commit(&set(1, 2, 20));
+
+        store.inner_mut().commit(&set(1, 1, 11));
+
+        assert_eq!(store.pending_count(), 0);
+        assert_eq!(store.cached_count(), 0);
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![11])
+        );
+        assert_eq!(
+            store.get_substate(&partition(1), &sort_key(2)),
+            Some(vec![20])
+        );
+    }
+
+    #[test]
+    fn test_into_inner_flushes_pending_writes() {
+        let mut store = store_with(&index_map_new(), 10, CacheWriteMode::WriteBack);
+
+        store.commit(&set(1, 1, 10));
+
+        assert_eq!(
+            store.list_partition_keys().collect::<Vec<_>>(),
+            vec![partition(1)]
+        );
+        let inner = store.into_inner();
+        assert_eq!(
+            inner.get_substate(&partition(1), &sort_key(1)),
+            Some(vec![10])
+        );
+    }
+}
@@ -5,6 +5,7 @@ compile_error!("Either feature `std` or `alloc` must be enabled for this crate."
 #[cfg(all(feature = "std", feature = "alloc"))]
 compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
+pub mod cached_db;
 pub mod hash_tree;
 pub mod memory_db;
 #[cfg(feature = "rocksdb")]
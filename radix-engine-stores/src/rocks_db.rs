@@ -8,18 +8,31 @@ use utils::copy_u8_array;
 
 pub struct RocksdbSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
+    characteristics: DatabaseCharacteristics,
 }
 
 impl RocksdbSubstateStore {
     pub fn standard(root: PathBuf) -> Self {
         let db = DB::open_default(root.as_path()).expect("IO Error");
 
-        Self { db }
+        Self {
+            db,
+            characteristics: DatabaseCharacteristics::default(),
+        }
     }
     pub fn with_options(options: &Options, root: PathBuf) -> Self {
         let db = DB::open(options, root.as_path()).expect("IO Error");
 
-        Self { db }
+        Self {
+            db,
+            characteristics: DatabaseCharacteristics::default(),
+        }
+    }
+
+    /// Advertises the characteristics of the storage the database is on, e.g. an HDD.
+    pub fn with_characteristics(mut self, characteristics: DatabaseCharacteristics) -> Self {
+        self.characteristics = characteristics;
+        self
     }
 }
 
@@ -52,6 +65,10 @@ impl SubstateDatabase for RocksdbSubstateStore {
 
         Box::new(iter)
     }
+
+    fn characteristics(&self) -> DatabaseCharacteristics {
+        self.characteristics
+    }
 }
 
 impl CommittableSubstateDatabase for RocksdbSubstateStore {
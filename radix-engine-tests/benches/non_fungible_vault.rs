@@ -0,0 +1,86 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use radix_engine::types::*;
+use scrypto::NonFungibleData;
+use scrypto_unit::TestRunner;
+use transaction::prelude::*;
+
+#[derive(ManifestSbor, ScryptoSbor, NonFungibleData)]
+struct Collectible {}
+
+const MINT_BATCH_SIZE: u64 = 500;
+
+fn bench_non_fungible_vault(c: &mut Criterion) {
+    let mut group = c.benchmark_group("NonFungibleVault::withdraw_and_deposit");
+    for vault_size in [MINT_BATCH_SIZE, 20 * MINT_BATCH_SIZE] {
+        let mut test_runner = TestRunner::builder().without_trace().build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+
+        // Create a collection which anyone can mint, and fill the account vault in batches
+        let resource_address = test_runner
+            .execute_manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .create_non_fungible_resource::<Vec<(NonFungibleLocalId, Collectible)>, _>(
+                        OwnerRole::None,
+                        NonFungibleIdType::Integer,
+                        false,
+                        NonFungibleResourceRoles {
+                            mint_roles: mint_roles! {
+                                minter => rule!(allow_all);
+                                minter_updater => rule!(deny_all);
+                            },
+                            ..Default::default()
+                        },
+                        metadata!(),
+                        None,
+                    )
+                    .build(),
+                vec![],
+            )
+            .expect_commit_success()
+            .new_resource_addresses()[0];
+        for batch in 0..vault_size / MINT_BATCH_SIZE {
+            let entries = (1..=MINT_BATCH_SIZE).map(|n| {
+                (
+                    NonFungibleLocalId::integer(batch * MINT_BATCH_SIZE + n),
+                    Collectible {},
+                )
+            });
+            test_runner
+                .execute_manifest(
+                    ManifestBuilder::new()
+                        .lock_fee_from_faucet()
+                        .mint_non_fungible(resource_address, entries)
+                        .try_deposit_batch_or_abort(account)
+                        .build(),
+                    vec![],
+                )
+                .expect_commit_success();
+        }
+
+        // Withdraw a single non-fungible and deposit it back
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(
+                account,
+                resource_address,
+                &btreeset!(NonFungibleLocalId::integer(1)),
+            )
+            .try_deposit_batch_or_abort(account)
+            .build();
+        group.bench_function(BenchmarkId::from_parameter(vault_size), |b| {
+            b.iter(|| {
+                test_runner
+                    .execute_manifest(
+                        manifest.clone(),
+                        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+                    )
+                    .expect_commit_success();
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(non_fungible_vault, bench_non_fungible_vault);
+criterion_main!(non_fungible_vault);
@@ -147,6 +147,9 @@ fn bench_radiswap(c: &mut Criterion) {
             notary_public_key: pk3.clone().into(),
             notary_is_signatory: true,
             tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         })
         .manifest(manifest.clone())
         .notarize(&sk3)
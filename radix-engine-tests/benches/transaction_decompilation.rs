@@ -102,6 +102,9 @@ fn compiled_notarized_transaction() -> Vec<u8> {
         notary_public_key: public_key.into(),
         notary_is_signatory: true,
         tip_percentage: 0,
+        max_fee_in_xrd: None,
+        expires_at_timestamp: None,
+        idempotency_key: None,
     };
     TransactionBuilder::new()
         .header(header)
@@ -17,6 +17,7 @@ fn bench_transfer(c: &mut Criterion) {
     let mut scrypto_interpreter = ScryptoVm {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_validator_config: WasmValidatorConfigV1::new(),
+        wasm_instrumentation_cache: None,
     };
     let mut substate_db = InMemorySubstateDatabase::standard();
     Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false)
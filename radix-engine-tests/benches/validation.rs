@@ -56,6 +56,9 @@ fn bench_transaction_validation(c: &mut Criterion) {
             notary_public_key: signer.public_key().into(),
             notary_is_signatory: true,
             tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         })
         .manifest(
             ManifestBuilder::new()
@@ -0,0 +1,55 @@
+use scrypto_unit::*;
+use std::fs;
+use std::path::PathBuf;
+
+fn snapshot_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join("radix-engine-tests-abi-snapshots")
+        .join(name);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn missing_abi_snapshot_is_written_and_then_matches() {
+    // Arrange
+    let path = snapshot_path("logger_written.json");
+
+    // Act
+    assert_abi_snapshot_matches("./tests/blueprints/logger", &path);
+
+    // Assert
+    let snapshot = fs::read_to_string(&path).unwrap();
+    assert!(snapshot.contains("\"Logger\""));
+    assert_abi_snapshot_matches("./tests/blueprints/logger", &path);
+}
+
+#[test]
+fn abi_snapshot_matches_package_definition_json() {
+    // Arrange
+    let path = snapshot_path("logger_definition.json");
+    let (_, definition) = Compile::compile("./tests/blueprints/logger");
+
+    // Act
+    assert_abi_snapshot_matches("./tests/blueprints/logger", &path);
+
+    // Assert
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        package_definition_to_json(&definition)
+    );
+}
+
+#[test]
+#[should_panic(expected = "ABI snapshot mismatch")]
+fn changed_abi_fails_snapshot_assertion() {
+    // Arrange
+    let path = snapshot_path("logger_changed.json");
+    let (_, definition) = Compile::compile("./tests/blueprints/logger");
+    let snapshot = package_definition_to_json(&definition).replacen("\"Logger\"", "\"Renamed\"", 1);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, snapshot).unwrap();
+
+    // Act
+    assert_abi_snapshot_matches("./tests/blueprints/logger", &path);
+}
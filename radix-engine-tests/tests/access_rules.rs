@@ -2,7 +2,9 @@ use radix_engine::errors::{RuntimeError, SystemError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
-use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::api::node_modules::auth::{
+    AuthAddresses, OwnerRoleChange, MAX_OWNER_ROLE_HISTORY_LENGTH,
+};
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
@@ -327,6 +329,71 @@ fn change_lock_owner_role_rules() {
     })
 }
 
+#[test]
+fn owner_role_changes_are_recorded_in_history() {
+    // Arrange
+    let mut test_runner =
+        MutableAccessRulesTestRunner::new_with_owner_role(OwnerRole::Updatable(rule!(allow_all)));
+    let (public_key, _, _) = test_runner.test_runner.new_account(false);
+    let key_rule = rule!(require(NonFungibleGlobalId::from_public_key(&public_key)));
+    test_runner.test_runner.set_current_epoch(Epoch::of(5));
+    test_runner
+        .set_owner_role(key_rule.clone())
+        .expect_commit_success();
+    test_runner.test_runner.set_current_epoch(Epoch::of(7));
+    test_runner.add_initial_proof(NonFungibleGlobalId::from_public_key(&public_key));
+    test_runner
+        .set_owner_role(rule!(allow_all))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.get_owner_role_history();
+
+    // Assert
+    let history: Vec<OwnerRoleChange> = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        history,
+        vec![
+            OwnerRoleChange {
+                epoch: Epoch::of(5),
+                rule: key_rule.clone(),
+            },
+            OwnerRoleChange {
+                epoch: Epoch::of(7),
+                rule: rule!(allow_all),
+            },
+        ]
+    );
+    assert_eq!(
+        history[0].rule.non_fungible_global_ids(),
+        btreeset!(NonFungibleGlobalId::from_public_key(&public_key))
+    );
+}
+
+#[test]
+fn owner_role_history_is_bounded() {
+    // Arrange
+    let mut test_runner =
+        MutableAccessRulesTestRunner::new_with_owner_role(OwnerRole::Updatable(rule!(allow_all)));
+    for epoch in 1..=(MAX_OWNER_ROLE_HISTORY_LENGTH as u64 + 2) {
+        test_runner.test_runner.set_current_epoch(Epoch::of(epoch));
+        test_runner
+            .set_owner_role(rule!(allow_all))
+            .expect_commit_success();
+    }
+
+    // Act
+    let receipt = test_runner.get_owner_role_history();
+
+    // Assert
+    let history: Vec<OwnerRoleChange> = receipt.expect_commit_success().output(1);
+    let epochs: Vec<Epoch> = history.iter().map(|change| change.epoch).collect();
+    let expected: Vec<Epoch> = (3..=(MAX_OWNER_ROLE_HISTORY_LENGTH as u64 + 2))
+        .map(Epoch::of)
+        .collect();
+    assert_eq!(epochs, expected);
+}
+
 struct MutableAccessRulesTestRunner {
     test_runner: TestRunner,
     component_address: ComponentAddress,
@@ -447,6 +514,13 @@ impl MutableAccessRulesTestRunner {
         self.execute_manifest(manifest)
     }
 
+    pub fn get_owner_role_history(&mut self) -> TransactionReceipt {
+        let manifest = Self::manifest_builder()
+            .get_owner_role_history(self.component_address)
+            .build();
+        self.execute_manifest(manifest)
+    }
+
     pub fn borrow_funds(&mut self) -> TransactionReceipt {
         let manifest = Self::manifest_builder()
             .call_method(self.component_address, "borrow_funds", manifest_args!())
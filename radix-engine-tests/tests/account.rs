@@ -1,10 +1,14 @@
+use radix_engine::blueprints::account::AccountError;
 use radix_engine::blueprints::resource::NonFungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::transaction::BalanceChange;
 use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::auth::OwnerRoleChange;
 use radix_engine_interface::api::node_modules::metadata::MetadataValue;
-use radix_engine_interface::blueprints::account::{AccountSecurifyInput, ACCOUNT_SECURIFY_IDENT};
+use radix_engine_interface::blueprints::account::{
+    AccountProofSpecifier, AccountSecurifyInput, ACCOUNT_SECURIFY_IDENT,
+};
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -24,6 +28,36 @@ fn cannot_securify_allocated_account() {
     securify_account(false, true, false);
 }
 
+#[test]
+fn securifying_account_is_recorded_in_owner_role_history() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (key, _, account) = test_runner.new_account(true);
+    let (_, _, storing_account) = test_runner.new_account(true);
+    test_runner.set_current_epoch(Epoch::of(3));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(account, ACCOUNT_SECURIFY_IDENT, AccountSecurifyInput {})
+        .try_deposit_batch_or_refund(storing_account)
+        .get_owner_role_history(account)
+        .build();
+    let receipt =
+        test_runner.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&key)]);
+
+    // Assert
+    let history: Vec<OwnerRoleChange> = receipt.expect_commit_success().output(3);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].epoch, Epoch::of(3));
+    let owner_badges = history[0].rule.non_fungible_global_ids();
+    assert_eq!(owner_badges.len(), 1);
+    assert_eq!(
+        owner_badges.first().unwrap().resource_address(),
+        ACCOUNT_OWNER_BADGE
+    );
+}
+
 fn securify_account(is_virtual: bool, use_key: bool, expect_success: bool) {
     // Arrange
     let mut test_runner = TestRunner::builder().build();
@@ -313,6 +347,96 @@ fn account_created_with_create_advanced_has_an_empty_owner_badge() {
     assert!(is_metadata_empty(&metadata))
 }
 
+#[test]
+fn create_proofs_pushes_all_proofs_to_auth_zone() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let fungible = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let non_fungible = test_runner.create_non_fungible_resource(account);
+    let ids = btreeset!(
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(2)
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proofs_from_account(
+            account,
+            vec![
+                AccountProofSpecifier::Amount(fungible, dec!("10")),
+                AccountProofSpecifier::NonFungibles(non_fungible, ids.clone()),
+            ],
+        )
+        .create_proof_from_auth_zone_of_amount(fungible, dec!("10"), "fungible_proof")
+        .create_proof_from_auth_zone_of_non_fungibles(non_fungible, &ids, "non_fungible_proof")
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_create_proofs_from_other_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proofs_from_account(
+            other_account,
+            vec![AccountProofSpecifier::Amount(XRD, dec!("1"))],
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn create_proofs_fails_if_any_vault_does_not_exist() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, other_account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proofs_from_account(
+            account,
+            vec![
+                AccountProofSpecifier::Amount(XRD, dec!("1")),
+                AccountProofSpecifier::Amount(resource_address, dec!("1")),
+            ],
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::AccountError(
+            AccountError::VaultDoesNotExist { resource_address },
+        ))
+    });
+}
+
 fn is_metadata_empty(metadata_value: &Option<MetadataValue>) -> bool {
     if let None = metadata_value {
         true
@@ -439,6 +439,97 @@ fn disallow_all_permits_deposit_of_resource_in_allow_list() {
     }
 }
 
+#[test]
+fn authorized_depositor_badge_permits_deposit_of_disallowed_resource() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let depositor_badge = test_runner.new_depositor_badge();
+        test_runner
+            .transition_account_default_deposit_rule(AccountDefaultDepositRule::Reject, true)
+            .expect_commit_success();
+        test_runner
+            .add_authorized_depositor(depositor_badge.clone(), true)
+            .expect_commit_success();
+
+        // Act
+        let receipt = test_runner.free_tokens_from_faucet_to_account_as_depositor(
+            DepositMethod::TryDepositOrAbort,
+            depositor_badge,
+        );
+
+        // Assert
+        receipt.expect_commit_success();
+    }
+}
+
+#[test]
+fn deposit_of_disallowed_resource_is_rejected_without_authorized_depositor_badge() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let depositor_badge = test_runner.new_depositor_badge();
+        let other_badge = test_runner.new_depositor_badge();
+        test_runner
+            .transition_account_default_deposit_rule(AccountDefaultDepositRule::Reject, true)
+            .expect_commit_success();
+        test_runner
+            .add_authorized_depositor(depositor_badge, true)
+            .expect_commit_success();
+
+        // Act
+        let receipt = test_runner.free_tokens_from_faucet_to_account_as_depositor(
+            DepositMethod::TryDepositBatchOrAbort,
+            other_badge,
+        );
+
+        // Assert
+        receipt.expect_specific_failure(is_account_deposits_not_allowed_error);
+    }
+}
+
+#[test]
+fn removed_authorized_depositor_badge_no_longer_permits_deposit() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let depositor_badge = test_runner.new_depositor_badge();
+        test_runner
+            .transition_account_default_deposit_rule(AccountDefaultDepositRule::Reject, true)
+            .expect_commit_success();
+        test_runner
+            .add_authorized_depositor(depositor_badge.clone(), true)
+            .expect_commit_success();
+        test_runner
+            .remove_authorized_depositor(depositor_badge.clone(), true)
+            .expect_commit_success();
+
+        // Act
+        let receipt = test_runner.free_tokens_from_faucet_to_account_as_depositor(
+            DepositMethod::TryDepositOrAbort,
+            depositor_badge,
+        );
+
+        // Assert
+        receipt.expect_specific_failure(is_account_deposit_not_allowed_error);
+    }
+}
+
+#[test]
+fn add_authorized_depositor_is_not_callable_with_out_owner_signature() {
+    // Arrange
+    for is_virtual in [true, false] {
+        let mut test_runner = AccountDepositModesTestRunner::new(is_virtual);
+        let depositor_badge = test_runner.new_depositor_badge();
+
+        // Act
+        let receipt = test_runner.add_authorized_depositor(depositor_badge, false);
+
+        // Assert
+        receipt.expect_specific_failure(is_auth_unauthorized_error);
+    }
+}
+
 struct AccountDepositModesTestRunner {
     test_runner: TestRunner,
     public_key: PublicKey,
@@ -529,6 +620,39 @@ impl AccountDepositModesTestRunner {
         self.execute_manifest(manifest, sign)
     }
 
+    pub fn free_tokens_from_faucet_to_account_as_depositor(
+        &mut self,
+        deposit_method: DepositMethod,
+        depositor_badge: NonFungibleGlobalId,
+    ) -> TransactionReceipt {
+        let (method, is_vec) = match deposit_method {
+            DepositMethod::TryDepositOrAbort => (ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT, false),
+            DepositMethod::TryDepositBatchOrAbort => {
+                (ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT, true)
+            }
+            _ => panic!("Only the try deposit or abort methods are supported"),
+        };
+
+        let manifest = ManifestBuilder::new()
+            .get_free_xrd_from_faucet()
+            .take_all_from_worktop(XRD, "free_tokens")
+            .then(|builder| {
+                let bucket = builder.bucket("free_tokens");
+                builder.call_method(
+                    self.component_address,
+                    method,
+                    if is_vec {
+                        manifest_args!(vec![bucket])
+                    } else {
+                        manifest_args!(bucket)
+                    },
+                )
+            })
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![depositor_badge])
+    }
+
     pub fn transition_account_default_deposit_rule(
         &mut self,
         default_deposit_rule: AccountDefaultDepositRule,
@@ -601,6 +725,45 @@ impl AccountDepositModesTestRunner {
         self.configure_resource_deposit_rule(resource_address, ResourceDepositRule::Neither, sign)
     }
 
+    pub fn add_authorized_depositor(
+        &mut self,
+        depositor_badge: NonFungibleGlobalId,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.component_address,
+                ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT,
+                AccountAddAuthorizedDepositorInput {
+                    badge: ResourceOrNonFungible::NonFungible(depositor_badge),
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    pub fn remove_authorized_depositor(
+        &mut self,
+        depositor_badge: NonFungibleGlobalId,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                self.component_address,
+                ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT,
+                AccountRemoveAuthorizedDepositorInput {
+                    badge: ResourceOrNonFungible::NonFungible(depositor_badge),
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    pub fn new_depositor_badge(&mut self) -> NonFungibleGlobalId {
+        let (public_key, _) = self.test_runner.new_key_pair();
+        NonFungibleGlobalId::from_public_key(&public_key)
+    }
+
     pub fn virtual_signature_badge(&self) -> NonFungibleGlobalId {
         NonFungibleGlobalId::from_public_key(&self.public_key)
     }
@@ -673,3 +836,12 @@ fn is_account_deposit_not_allowed_error(runtime_error: &RuntimeError) -> bool {
         ))
     )
 }
+
+fn is_account_deposits_not_allowed_error(runtime_error: &RuntimeError) -> bool {
+    matches!(
+        runtime_error,
+        RuntimeError::ApplicationError(ApplicationError::AccountError(
+            AccountError::NotAllBucketsCouldBeDeposited
+        ))
+    )
+}
@@ -0,0 +1,111 @@
+use radix_engine::blueprints::access_controller::AccessControllerError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine_interface::blueprints::access_controller::Role;
+use scrypto_unit::*;
+
+#[test]
+fn securified_account_is_controlled_by_primary_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let securified_account = test_runner.new_securified_account(Some(10));
+    let (other_key, _) = test_runner.new_key_pair();
+
+    // Act
+    let primary_receipt = test_runner
+        .lock_fee_from_securified_account(&securified_account, &securified_account.primary_key);
+    let other_receipt =
+        test_runner.lock_fee_from_securified_account(&securified_account, &other_key);
+
+    // Assert
+    primary_receipt.expect_commit_success();
+    other_receipt.expect_rejection();
+}
+
+#[test]
+fn lost_primary_key_can_be_replaced_through_timed_recovery() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut securified_account = test_runner.new_securified_account(Some(10));
+    let lost_key = securified_account.primary_key;
+    let (new_key, _) = test_runner.new_key_pair();
+
+    // Act & Assert
+    test_runner
+        .initiate_securified_account_recovery(&securified_account, Role::Recovery, &new_key)
+        .expect_commit_success();
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &lost_key)
+        .expect_commit_success();
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &new_key)
+        .expect_rejection();
+
+    test_runner.wait_for_timed_recovery(&securified_account);
+    test_runner
+        .timed_confirm_securified_account_recovery(&mut securified_account, &new_key)
+        .expect_commit_success();
+
+    assert_eq!(securified_account.primary_key, new_key);
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &new_key)
+        .expect_commit_success();
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &lost_key)
+        .expect_rejection();
+}
+
+#[test]
+fn timed_recovery_of_securified_account_cannot_be_confirmed_before_delay() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut securified_account = test_runner.new_securified_account(Some(10));
+    let lost_key = securified_account.primary_key;
+    let (new_key, _) = test_runner.new_key_pair();
+    test_runner
+        .initiate_securified_account_recovery(&securified_account, Role::Recovery, &new_key)
+        .expect_commit_success();
+
+    // Act
+    let receipt =
+        test_runner.timed_confirm_securified_account_recovery(&mut securified_account, &new_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+                AccessControllerError::TimedRecoveryDelayHasNotElapsed
+            ))
+        )
+    });
+    assert_eq!(securified_account.primary_key, lost_key);
+}
+
+#[test]
+fn lost_primary_key_can_be_replaced_through_quick_confirmation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let mut securified_account = test_runner.new_securified_account(None);
+    let lost_key = securified_account.primary_key;
+    let (new_key, _) = test_runner.new_key_pair();
+    test_runner
+        .initiate_securified_account_recovery(&securified_account, Role::Recovery, &new_key)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.quick_confirm_securified_account_recovery(
+        &mut securified_account,
+        Role::Recovery,
+        Role::Confirmation,
+        &new_key,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &new_key)
+        .expect_commit_success();
+    test_runner
+        .lock_fee_from_securified_account(&securified_account, &lost_key)
+        .expect_rejection();
+}
@@ -1,10 +1,26 @@
 use radix_engine::{
-    errors::{CannotGlobalizeError, KernelError, RuntimeError, SystemError},
+    errors::{CannotGlobalizeError, RuntimeError, SystemError},
+    system::id_allocation::GlobalAddressReservationState,
+    transaction::CommitResult,
     types::*,
 };
 use scrypto_unit::*;
 use transaction::prelude::*;
 
+fn assert_reservations_released(commit_result: &CommitResult, count: usize) {
+    let reservations = &commit_result.execution_metrics.global_address_reservations;
+    assert_eq!(reservations.len(), count);
+    for (address, state) in reservations {
+        assert_eq!(*state, GlobalAddressReservationState::Released);
+        assert!(commit_result
+            .state_updates
+            .system_updates
+            .iter()
+            .all(|((node_id, _), updates)| node_id != address.as_node_id() || updates.is_empty()));
+    }
+    assert!(commit_result.new_component_addresses().is_empty());
+}
+
 #[test]
 fn test_create_and_return() {
     // Arrange
@@ -24,9 +40,8 @@ fn test_create_and_return() {
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
     // Assert
-    receipt.expect_specific_failure(|e| {
-        matches!(e, RuntimeError::KernelError(KernelError::NodeOrphaned(_)))
-    });
+    let commit_result = receipt.expect_commit_success();
+    assert_reservations_released(commit_result, 1);
 }
 
 #[test]
@@ -118,7 +133,15 @@ fn test_create_and_consume_within_frame() {
     let receipt = test_runner.execute_manifest(manifest, vec![]);
 
     // Assert
-    receipt.expect_commit_success();
+    let commit_result = receipt.expect_commit_success();
+    let component_address = commit_result.new_component_addresses()[0];
+    assert_eq!(
+        commit_result
+            .execution_metrics
+            .global_address_reservations
+            .get(&GlobalAddress::from(component_address)),
+        Some(&GlobalAddressReservationState::Globalized)
+    );
 }
 
 #[test]
@@ -215,3 +238,21 @@ fn test_create_and_store_in_metadata() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_unused_reservation_from_manifest_is_released() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package = test_runner.compile_and_publish("./tests/blueprints/allocated_address");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .allocate_global_address(package, "AllocatedAddressTest", "reservation", "address")
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_reservations_released(commit_result, 1);
+}
@@ -1,5 +1,7 @@
 extern crate core;
 
+use radix_engine::errors::{RuntimeError, SystemError};
+use radix_engine::system::node_modules::access_rules::SetAndLockRoleEvent;
 use radix_engine::types::*;
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::resource::{require, FromPublicKey};
@@ -188,3 +190,132 @@ pub fn assert_locked_auth_can_no_longer_be_updated(action: TestResourceAction) {
         )
         .expect_auth_failure();
 }
+
+fn create_mintable_fungible_resource_with_updatable_minter(
+    test_runner: &mut TestRunner,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18u8,
+            FungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => rule!(allow_all);
+                    minter_updater => rule!(allow_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None,
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+#[test]
+fn renounced_role_denies_all_and_emits_event() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        create_mintable_fungible_resource_with_updatable_minter(&mut test_runner, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .renounce_role(
+                resource_address,
+                ObjectModuleId::Main,
+                RoleKey::new(MINTER_ROLE),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert!(commit
+        .application_events
+        .iter()
+        .any(|(id, _)| test_runner.is_event_name_equal::<SetAndLockRoleEvent>(id)));
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .mint_fungible(resource_address, 1)
+                .try_deposit_batch_or_abort(account)
+                .build(),
+            vec![],
+        )
+        .expect_auth_failure();
+}
+
+#[test]
+fn renounced_role_can_no_longer_be_updated() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        create_mintable_fungible_resource_with_updatable_minter(&mut test_runner, account);
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .renounce_role(
+                    resource_address,
+                    ObjectModuleId::Main,
+                    RoleKey::new(MINTER_ROLE),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .update_role(
+                resource_address,
+                ObjectModuleId::Main,
+                RoleKey::new(MINTER_ROLE),
+                rule!(allow_all),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::MutatingImmutableSubstate)
+        )
+    });
+}
+
+#[test]
+fn role_cannot_be_renounced_without_its_updater_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_freely_mintable_fungible_resource(OwnerRole::None, None, 18u8, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .renounce_role(
+                resource_address,
+                ObjectModuleId::Main,
+                RoleKey::new(MINTER_ROLE),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+}
@@ -0,0 +1,88 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn auth_requirements_contain_signatures_of_accounts_withdrawn_from() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (allocated_key, _, allocated_account) = test_runner.new_allocated_account();
+    let (virtual_key, _, virtual_account) = test_runner.new_virtual_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(allocated_account, XRD, 1)
+        .withdraw_from_account(virtual_account, XRD, 1)
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+
+    // Act
+    let requirements = test_runner.auth_requirements(&manifest);
+
+    // Assert
+    assert_eq!(
+        requirements
+            .checks
+            .iter()
+            .map(|check| check.instruction_index)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!(requirements.unresolved_instructions.is_empty());
+    let signatures = requirements.signatures();
+    assert_eq!(
+        signatures,
+        btreeset!(
+            NonFungibleGlobalId::from_public_key(&allocated_key),
+            NonFungibleGlobalId::from_public_key(&virtual_key)
+        )
+    );
+    assert!(requirements.badges().is_empty());
+    test_runner
+        .execute_manifest(manifest, signatures)
+        .expect_commit_success();
+}
+
+#[test]
+fn auth_requirements_of_account_which_does_not_exist_yet_contain_its_key() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _) = test_runner.new_key_pair();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+    let manifest = ManifestBuilder::new().lock_fee(account, 10).build();
+
+    // Act
+    let requirements = test_runner.auth_requirements(&manifest);
+
+    // Assert
+    assert_eq!(
+        requirements.signatures(),
+        btreeset!(NonFungibleGlobalId::from_public_key(&public_key))
+    );
+}
+
+#[test]
+fn auth_requirements_contain_badges_of_protected_methods() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (admin_auth, resource_address) =
+        test_runner.create_mintable_burnable_fungible_resource(account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(resource_address, 1)
+        .try_deposit_batch_or_abort(account)
+        .build();
+
+    // Act
+    let requirements = test_runner.auth_requirements(&manifest);
+
+    // Assert
+    assert_eq!(requirements.checks.len(), 1);
+    assert_eq!(requirements.checks[0].instruction_index, 1);
+    assert!(requirements.signatures().is_empty());
+    assert_eq!(
+        requirements.badges(),
+        btreeset!(ResourceOrNonFungible::Resource(admin_auth))
+    );
+}
@@ -1,3 +1,7 @@
+use radix_engine::blueprints::resource::AuthZoneError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -53,3 +57,75 @@ fn test_auth_zone_create_proof_of_all_for_non_fungible() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn call_auth_scope_function(function_name: &str) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "AuthScope",
+            function_name,
+            manifest_args!(),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn test_proofs_pushed_in_auth_scope_are_usable_within_the_scope() {
+    // Act
+    let receipt = call_auth_scope_function("mint_in_scope");
+
+    // Assert
+    let (_, _, depth_in_scope, depth_after_scope): (Own, Own, u32, u32) =
+        receipt.expect_commit_success().output(1);
+    assert_eq!(depth_in_scope, 1);
+    assert_eq!(depth_after_scope, 0);
+}
+
+#[test]
+fn test_proofs_pushed_in_auth_scope_are_dropped_after_the_scope() {
+    // Act
+    let receipt = call_auth_scope_function("mint_after_scope");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_cannot_pop_proof_out_of_auth_scope() {
+    // Act
+    let receipt = call_auth_scope_function("pop_in_scope");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::AuthZoneError(AuthZoneError::ScopeOpen),
+        ))
+    });
+}
+
+#[test]
+fn test_cannot_pop_auth_scope_without_open_scope() {
+    // Act
+    let receipt = call_auth_scope_function("pop_scope_without_scope");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::AuthZoneError(AuthZoneError::NoOpenScope),
+        ))
+    });
+}
@@ -0,0 +1,87 @@
+use radix_engine::errors::{RuntimeError, SystemError};
+use radix_engine::system::big_int::BigIntError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn call_big_int_function(
+    function_name: &str,
+    args: impl ResolvableArguments,
+) -> TransactionReceipt {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/big_int");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "BigIntMath", function_name, args)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn test_u256_mul_div_beyond_u128() {
+    // Act
+    let receipt = call_big_int_function(
+        "mul_div_u256",
+        manifest_args!(
+            "340282366920938463463374607431768211455",
+            "340282366920938463463374607431768211455",
+            "3"
+        ),
+    );
+
+    // Assert
+    let result: String = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        result,
+        "38597363079105398474523661669562635950863139977266229037510278264349864405675"
+    );
+}
+
+#[test]
+fn test_i384_sub_below_zero() {
+    // Act
+    let receipt = call_big_int_function("sub_i384", manifest_args!("5", "12"));
+
+    // Assert
+    let result: String = receipt.expect_commit_success().output(1);
+    assert_eq!(result, "-7");
+}
+
+#[test]
+fn test_u384_mod_pow() {
+    // Act
+    let receipt = call_big_int_function("mod_pow_u384", manifest_args!("4", "13", "497"));
+
+    // Assert
+    let result: String = receipt.expect_commit_success().output(1);
+    assert_eq!(result, "445");
+}
+
+#[test]
+fn test_division_by_zero_fails() {
+    // Act
+    let receipt = call_big_int_function("mul_div_u256", manifest_args!("1", "1", "0"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::BigIntError(BigIntError::DivisionByZero))
+        )
+    });
+}
+
+#[test]
+fn test_overflow_fails() {
+    // Act
+    let receipt = call_big_int_function("add_max_i256", manifest_args!());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::BigIntError(BigIntError::Overflow))
+        )
+    });
+}
@@ -12,6 +12,13 @@ mod assert_access_rule {
                 .globalize()
         }
 
+        pub fn new_with_owner(owner_role: OwnerRole) -> Global<AssertAccessRule> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .globalize()
+        }
+
         pub fn assert_access_rule(&self, access_rule: AccessRule) {
             Runtime::assert_access_rule(access_rule);
         }
@@ -0,0 +1,69 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod unprotected_vault {
+    struct UnprotectedVault {
+        vault: Vault,
+    }
+
+    impl UnprotectedVault {
+        pub fn new(bucket: Bucket) -> Global<UnprotectedVault> {
+            Self {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            self.vault.put(bucket);
+        }
+
+        pub fn withdraw(&mut self, amount: Decimal) -> Bucket {
+            self.vault.take(amount)
+        }
+
+        pub fn balance(&self) -> Decimal {
+            self.vault.amount()
+        }
+    }
+}
+
+#[blueprint]
+mod protected_vault {
+    enable_method_auth! {
+        methods {
+            deposit => PUBLIC;
+            withdraw => restrict_to: [OWNER];
+            balance => PUBLIC;
+        }
+    }
+
+    struct ProtectedVault {
+        vault: Vault,
+    }
+
+    impl ProtectedVault {
+        pub fn new(bucket: Bucket, owner_badge: ResourceAddress) -> Global<ProtectedVault> {
+            Self {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(owner_badge))))
+            .globalize()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            self.vault.put(bucket);
+        }
+
+        pub fn withdraw(&mut self, amount: Decimal) -> Bucket {
+            self.vault.take(amount)
+        }
+
+        pub fn balance(&self) -> Decimal {
+            self.vault.amount()
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod big_int {
+    struct BigIntMath {}
+
+    impl BigIntMath {
+        pub fn mul_div_u256(a: String, b: String, c: String) -> String {
+            let a = EngineU256(a.parse().unwrap());
+            let b = EngineU256(b.parse().unwrap());
+            let c = EngineU256(c.parse().unwrap());
+            (a * b / c).to_string()
+        }
+
+        pub fn sub_i384(a: String, b: String) -> String {
+            let a = EngineI384(a.parse().unwrap());
+            let b = EngineI384(b.parse().unwrap());
+            (a - b).to_string()
+        }
+
+        pub fn mod_pow_u384(base: String, exponent: String, modulus: String) -> String {
+            let base = EngineU384(base.parse().unwrap());
+            let exponent = EngineU384(exponent.parse().unwrap());
+            let modulus = EngineU384(modulus.parse().unwrap());
+            base.mod_pow(exponent, modulus).to_string()
+        }
+
+        pub fn add_max_i256() -> String {
+            (EngineI256::MAX + EngineI256::ONE).to_string()
+        }
+    }
+}
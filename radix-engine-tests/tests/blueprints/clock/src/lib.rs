@@ -111,5 +111,13 @@ mod clock_test {
         pub fn get_current_time_rounded_to_minutes() -> i64 {
             Clock::current_time_rounded_to_minutes().seconds_since_unix_epoch
         }
+
+        pub fn get_epoch_history() -> Vec<EpochStartRecord> {
+            Clock::epoch_history()
+        }
+
+        pub fn get_epoch_start_time(epoch: Epoch) -> Option<Instant> {
+            Clock::epoch_start_time(epoch)
+        }
     }
 }
@@ -0,0 +1,36 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Settings {
+    pub fee: Decimal,
+    pub enabled: bool,
+}
+
+#[blueprint]
+#[component_config(settings)]
+mod configurable {
+    struct Configurable {
+        settings: Settings,
+    }
+
+    impl Configurable {
+        pub fn new(admin_badge: ResourceAddress) -> Global<Configurable> {
+            Self {
+                settings: Settings {
+                    fee: dec!("0.1"),
+                    enabled: true,
+                },
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .roles(roles! {
+                config_admin => rule!(require(admin_badge));
+            })
+            .globalize()
+        }
+
+        pub fn fee(&self) -> Decimal {
+            self.settings.fee
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+#[exportable_state]
+mod exportable_state {
+    struct ExportableState {
+        name: String,
+        tokens: Vault,
+        records: KeyValueStore<u32, String>,
+    }
+
+    impl ExportableState {
+        pub fn new(tokens: Bucket, owner_badge: ResourceAddress) -> Global<ExportableState> {
+            let records = KeyValueStore::new();
+            records.insert(1u32, "first".to_string());
+
+            Self {
+                name: "exportable".to_string(),
+                tokens: Vault::with_bucket(tokens),
+                records,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(owner_badge))))
+            .globalize()
+        }
+
+        pub fn deposit(&mut self, tokens: Bucket) {
+            self.tokens.put(tokens);
+        }
+    }
+}
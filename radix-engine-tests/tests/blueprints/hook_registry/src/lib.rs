@@ -0,0 +1,77 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod hook_listener {
+    struct HookListener {
+        received: Vec<(String, Vec<u8>)>,
+    }
+
+    impl HookListener {
+        pub fn instantiate() -> Global<HookListener> {
+            Self {
+                received: Vec::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn subscribe(
+            &mut self,
+            mut registry: Global<HookRegistry>,
+            event_name: String,
+            method_name: String,
+        ) {
+            registry.register_hook(
+                event_name,
+                RegisteredHook {
+                    receiver: Runtime::global_address(),
+                    method_name,
+                },
+            );
+        }
+
+        pub fn unsubscribe(
+            &mut self,
+            mut registry: Global<HookRegistry>,
+            event_name: String,
+            method_name: String,
+        ) {
+            registry.unregister_hook(
+                event_name,
+                RegisteredHook {
+                    receiver: Runtime::global_address(),
+                    method_name,
+                },
+            );
+        }
+
+        /// Attempts to register a hook on behalf of another component.
+        pub fn subscribe_other(
+            mut registry: Global<HookRegistry>,
+            event_name: String,
+            receiver: ComponentAddress,
+        ) {
+            registry.register_hook(
+                event_name,
+                RegisteredHook {
+                    receiver,
+                    method_name: "on_event".to_string(),
+                },
+            );
+        }
+
+        pub fn on_event(&mut self, event_name: String, payload: Vec<u8>) {
+            self.received.push((event_name, payload));
+        }
+
+        pub fn on_event_returning_value(&mut self, event_name: String, payload: Vec<u8>) -> u32 {
+            self.received.push((event_name, payload));
+            1
+        }
+
+        pub fn received(&self) -> Vec<(String, Vec<u8>)> {
+            self.received.clone()
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use scrypto::api::*;
+use scrypto::engine::scrypto_env::*;
+use scrypto::prelude::*;
+
+#[blueprint]
+mod entry_lock {
+    struct EntryLock {
+        map: KeyValueStore<String, u32>,
+    }
+
+    impl EntryLock {
+        pub fn new() -> Global<EntryLock> {
+            let map = KeyValueStore::new();
+            map.insert("counter".to_owned(), 0u32);
+            Self { map }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn increment(&self) {
+            let mut entry = self.map.get_mut(&"counter".to_owned()).unwrap();
+            *entry += 1;
+        }
+
+        pub fn get(&self) -> u32 {
+            *self.map.get(&"counter".to_owned()).unwrap()
+        }
+
+        pub fn increment_while_locked(&self, address: ComponentAddress) {
+            let _lock = self.map.lock_entry(&"counter".to_owned());
+            ScryptoEnv
+                .call_method(address.as_node_id(), "increment", scrypto_args!())
+                .unwrap();
+        }
+
+        pub fn increment_after_unlock(&self, address: ComponentAddress) {
+            let mut lock = self.map.lock_entry(&"counter".to_owned());
+            let value = *lock.get().unwrap();
+            lock.set(value + 10);
+            lock.unlock();
+            ScryptoEnv
+                .call_method(address.as_node_id(), "increment", scrypto_args!())
+                .unwrap();
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod basic;
 pub mod cyclic_map;
+pub mod entry_lock;
 pub mod kv_store;
 pub mod nested_kv_stores;
 pub mod precommitted;
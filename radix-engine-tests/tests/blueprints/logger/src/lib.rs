@@ -22,5 +22,14 @@ mod logger {
         pub fn assert_length_5(message: String) {
             assert_eq!(message.len(), 5);
         }
+
+        pub fn scrypto_fail(message: String) {
+            Runtime::fail(LoggerError::InvalidMessage { message })
+        }
     }
 }
+
+#[derive(ScryptoSbor)]
+pub enum LoggerError {
+    InvalidMessage { message: String },
+}
@@ -41,5 +41,36 @@ mod metadata_component {
         pub fn remove_metadata(global: Global<MetadataComponent>, key: String) {
             global.remove_metadata(key);
         }
+
+        pub fn lock_metadata(global: Global<MetadataComponent>, key: String) {
+            global.lock_metadata(key);
+        }
+
+        pub fn set_typed_metadata(global: Global<MetadataComponent>) {
+            let url = Url("https://www.radixdlt.com".to_string());
+            let addresses = vec![GlobalAddress::from(global.address())];
+            global.set_metadata("url", url.clone());
+            global.set_metadata("decimal", dec!("1.5"));
+            global.set_metadata("flag", true);
+            global.set_metadata("addresses", addresses.clone());
+
+            let url0: Url = global.get_metadata("url").unwrap();
+            let decimal0: Decimal = global.get_metadata("decimal").unwrap();
+            let flag0: bool = global.get_metadata("flag").unwrap();
+            let addresses0: Vec<GlobalAddress> = global.get_metadata("addresses").unwrap();
+            assert_eq!(url0, url);
+            assert_eq!(decimal0, dec!("1.5"));
+            assert!(flag0);
+            assert_eq!(addresses0, addresses);
+
+            assert!(matches!(
+                global.get_metadata::<_, String>("flag"),
+                Err(MetadataError::UnexpectedType { .. })
+            ));
+            assert!(matches!(
+                global.get_metadata::<_, bool>("missing"),
+                Err(MetadataError::NotFound)
+            ));
+        }
     }
 }
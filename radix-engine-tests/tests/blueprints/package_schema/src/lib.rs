@@ -18,6 +18,20 @@ pub extern "C" fn SchemaComponent2_invalid_output(_input: u64) -> Slice {
     ::scrypto::engine::wasm_api::forget_vec(::scrypto::data::scrypto::scrypto_encode(&()).unwrap())
 }
 
+#[derive(ScryptoSbor)]
+pub struct SchemaOutput {
+    pub amount: u8,
+    pub name: String,
+    pub enabled: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn invalid_struct_output_export(_input: u64) -> Slice {
+    ::scrypto::engine::wasm_api::forget_vec(
+        ::scrypto::data::scrypto::scrypto_encode(&(1000u16, "name".to_string(), 1u32)).unwrap(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn dummy_export(_input: u64) -> Slice {
     ::scrypto::engine::wasm_api::forget_vec(::scrypto::data::scrypto::scrypto_encode(&()).unwrap())
@@ -42,6 +56,15 @@ pub extern "C" fn SchemaComponent2_schema() -> Slice {
             export: "dummy_export".to_string(),
         },
     );
+    functions.insert(
+        "invalid_struct_output".to_string(),
+        FunctionSchemaInit {
+            receiver: None,
+            input: TypeRef::Static(aggregator.add_child_type_and_descendents::<()>()),
+            output: TypeRef::Static(aggregator.add_child_type_and_descendents::<SchemaOutput>()),
+            export: "invalid_struct_output_export".to_string(),
+        },
+    );
     functions.insert(
         "unit".to_string(),
         FunctionSchemaInit {
@@ -194,6 +217,7 @@ pub extern "C" fn SchemaComponent2_schema() -> Slice {
 
     let function_auth: BTreeMap<String, AccessRule> = btreemap!(
         "invalid_output".to_string() => AccessRule::AllowAll,
+        "invalid_struct_output".to_string() => AccessRule::AllowAll,
         "unit".to_string() => AccessRule::AllowAll,
         "bool".to_string() => AccessRule::AllowAll,
         "i8".to_string() => AccessRule::AllowAll,
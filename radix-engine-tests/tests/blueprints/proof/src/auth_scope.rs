@@ -0,0 +1,57 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod auth_scope {
+    struct AuthScope {}
+
+    impl AuthScope {
+        fn create_badge_and_resource() -> (Bucket, ResourceManager) {
+            let badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+            let resource_manager = ResourceBuilder::new_fungible(OwnerRole::None)
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(badge.resource_address()));
+                    minter_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            (badge, resource_manager)
+        }
+
+        pub fn mint_in_scope() -> (Bucket, Bucket, u32, u32) {
+            let (badge, resource_manager) = Self::create_badge_and_resource();
+
+            let (minted, depth_in_scope) =
+                Runtime::with_auth_scope(vec![badge.create_proof_of_all()], || {
+                    (resource_manager.mint(1), LocalAuthZone::scope_depth())
+                });
+
+            (badge, minted, depth_in_scope, LocalAuthZone::scope_depth())
+        }
+
+        pub fn mint_after_scope() -> (Bucket, Bucket) {
+            let (badge, resource_manager) = Self::create_badge_and_resource();
+
+            Runtime::with_auth_scope(vec![badge.create_proof_of_all()], || {});
+            let minted = resource_manager.mint(1);
+
+            (badge, minted)
+        }
+
+        pub fn pop_in_scope() -> Bucket {
+            let (badge, _) = Self::create_badge_and_resource();
+
+            let proof = Runtime::with_auth_scope(vec![badge.create_proof_of_all()], || {
+                LocalAuthZone::pop()
+            });
+            proof.drop();
+
+            badge
+        }
+
+        pub fn pop_scope_without_scope() {
+            LocalAuthZone::pop_scope();
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod auth_scope;
 pub mod bucket_lock_unlock_auth;
 pub mod bucket_proof;
 pub mod internal_proof_movement;
@@ -320,3 +320,32 @@ mod rounding {
         }
     }
 }
+
+#[blueprint]
+mod resource_presets {
+    struct ResourcePresets;
+
+    impl ResourcePresets {
+        pub fn create_badge() -> Bucket {
+            let badge = ResourceBuilder::new_badge(OwnerRole::None).mint_initial_supply(1);
+            assert_eq!(
+                badge.resource_manager().resource_type(),
+                ResourceType::Fungible { divisibility: 0 }
+            );
+            badge
+        }
+
+        pub fn create_soulbound_badge() -> Bucket {
+            ResourceBuilder::new_soulbound_badge(OwnerRole::None).mint_initial_supply(1)
+        }
+
+        pub fn create_share_token(pool: ComponentAddress) -> ResourceManager {
+            ResourceBuilder::new_share_token(OwnerRole::None, pool).create_with_no_initial_supply()
+        }
+
+        pub fn create_stablecoin(max_supply: Decimal, initial_supply: Decimal) -> Bucket {
+            ResourceBuilder::new_stablecoin(OwnerRole::None, max_supply)
+                .mint_initial_supply(initial_supply)
+        }
+    }
+}
@@ -7,8 +7,10 @@ mod royalty_test {
         paid_method_usd => Free;
         paid_method_panic => Xrd(2.into());
         free_method => Free;
+        lock_fee => Xrd(2.into());
         create_component_with_royalty_enabled => Free;
         create_component_with_royalty => Free;
+        create_component_with_royalty_and_call_paid_method => Free;
     }
 
     struct RoyaltyTest {}
@@ -30,6 +32,10 @@ mod royalty_test {
             1
         }
 
+        pub fn lock_fee(&self) -> u32 {
+            2
+        }
+
         pub fn create_component_with_royalty_enabled() -> Global<RoyaltyTest> {
             Self {}
                 .instantiate()
@@ -48,6 +54,7 @@ mod royalty_test {
                         paid_method => Xrd(1.into()), updatable;
                         paid_method_usd => Usd(1.into()), updatable;
                         paid_method_panic => Xrd(1.into()), updatable;
+                        lock_fee => Xrd(1.into()), updatable;
                     }
                 })
                 .globalize()
@@ -71,9 +78,16 @@ mod royalty_test {
                         paid_method => Xrd(amount), updatable;
                         paid_method_usd => Usd(1.into()), updatable;
                         paid_method_panic => Xrd(1.into()), updatable;
+                        lock_fee => Xrd(1.into()), updatable;
                     }
                 })
                 .globalize()
         }
+
+        pub fn create_component_with_royalty_and_call_paid_method() -> Global<RoyaltyTest> {
+            let component = Self::create_component_with_royalty_enabled();
+            component.paid_method();
+            component
+        }
     }
 }
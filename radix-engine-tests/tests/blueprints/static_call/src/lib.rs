@@ -0,0 +1,99 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod price_oracle {
+    struct PriceOracle {
+        price: Decimal,
+    }
+
+    impl PriceOracle {
+        pub fn new(price: Decimal) -> Global<PriceOracle> {
+            Self { price }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn get_price(&self) -> Decimal {
+            self.price
+        }
+
+        pub fn set_price(&mut self, price: Decimal) {
+            self.price = price;
+        }
+
+        pub fn get_price_in(&self, market: Global<AnyComponent>) -> Decimal {
+            let rate: Decimal = market.static_call("get_rate", &());
+            self.price * rate
+        }
+
+        pub fn get_price_in_with_call(&self, market: Global<AnyComponent>) -> Decimal {
+            let rate: Decimal = market.call("get_rate", &());
+            self.price * rate
+        }
+
+        pub fn burn(&self, bucket: Bucket) {
+            bucket.burn();
+        }
+    }
+}
+
+#[blueprint]
+mod market {
+    struct Market {
+        oracle: Global<AnyComponent>,
+        rate: Decimal,
+        last_price: Decimal,
+    }
+
+    impl Market {
+        pub fn new(oracle: Global<AnyComponent>, rate: Decimal) -> Global<Market> {
+            Self {
+                oracle,
+                rate,
+                last_price: Decimal::ZERO,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn get_rate(&self) -> Decimal {
+            self.rate
+        }
+
+        pub fn update_price(&mut self) -> Decimal {
+            self.last_price = self.oracle.static_call("get_price", &());
+            self.last_price
+        }
+
+        pub fn update_local_price(&mut self) -> Decimal {
+            let market: Global<AnyComponent> = Runtime::global_address().into();
+            self.last_price = self.oracle.static_call("get_price_in", &(market,));
+            self.last_price
+        }
+
+        pub fn update_local_price_with_call(&mut self) -> Decimal {
+            let market: Global<AnyComponent> = Runtime::global_address().into();
+            self.last_price = self.oracle.call("get_price_in_with_call", &(market,));
+            self.last_price
+        }
+
+        pub fn update_local_price_with_nested_call(&mut self) -> Decimal {
+            let market: Global<AnyComponent> = Runtime::global_address().into();
+            self.last_price = self
+                .oracle
+                .static_call("get_price_in_with_call", &(market,));
+            self.last_price
+        }
+
+        pub fn set_oracle_price(&self, price: Decimal) {
+            self.oracle.static_call::<_, ()>("set_price", &(price,));
+        }
+
+        pub fn send_bucket_to_oracle(&self) {
+            let bucket = ResourceBuilder::new_fungible(OwnerRole::None).mint_initial_supply(1);
+            self.oracle.static_call::<_, ()>("burn", &(bucket,));
+        }
+    }
+}
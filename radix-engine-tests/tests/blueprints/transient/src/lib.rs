@@ -0,0 +1,34 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod transient_counter {
+    struct TransientCounter {}
+
+    impl TransientCounter {
+        pub fn new() -> Global<TransientCounter> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn increment(&self) -> u32 {
+            let count = TransientState::get::<String, u32>(&"count".to_owned()).unwrap_or(0) + 1;
+            TransientState::insert("count".to_owned(), count);
+            count
+        }
+
+        pub fn get(&self) -> Option<u32> {
+            TransientState::get(&"count".to_owned())
+        }
+
+        pub fn take(&self) -> Option<u32> {
+            TransientState::remove(&"count".to_owned())
+        }
+
+        pub fn store_bucket(&self) {
+            let bucket = ResourceBuilder::new_fungible(OwnerRole::None).mint_initial_supply(1);
+            TransientState::insert("bucket".to_owned(), bucket);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod upgradeable {
+    enable_method_auth! {
+        roles {
+            migrator => updatable_by: [OWNER];
+        },
+        methods {
+            migrate => restrict_to: [migrator];
+            version => PUBLIC;
+        }
+    }
+
+    struct Upgradeable {
+        version: u32,
+        implementation: Option<PackageAddress>,
+    }
+
+    impl Upgradeable {
+        pub fn instantiate() -> Global<Upgradeable> {
+            Self {
+                version: 1,
+                implementation: None,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Updatable(rule!(allow_all)))
+            .roles(roles! {
+                migrator => rule!(allow_all);
+            })
+            .globalize()
+        }
+
+        pub fn migrate(&mut self, implementation: PackageAddress, version: u32) {
+            assert!(version > self.version, "Cannot migrate to an older version");
+            self.version = version;
+            self.implementation = Some(implementation);
+        }
+
+        pub fn version(&self) -> (u32, Option<PackageAddress>) {
+            (self.version, self.implementation)
+        }
+    }
+}
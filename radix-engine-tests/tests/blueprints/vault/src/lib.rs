@@ -1,4 +1,6 @@
 pub mod non_existent_vault;
 pub mod non_fungible_vault;
+pub mod non_fungible_vault_transfer;
 pub mod vault_burn;
 pub mod vault_in_structs;
+pub mod vault_transfer;
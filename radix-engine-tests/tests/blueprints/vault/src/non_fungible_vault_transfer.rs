@@ -0,0 +1,61 @@
+use scrypto::api::ClientObjectApi;
+use scrypto::engine::scrypto_env::ScryptoEnv;
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct Data {}
+
+#[blueprint]
+mod non_fungible_vault_transfer {
+    struct NonFungibleVaultTransfer {
+        source: Vault,
+        destination: Vault,
+    }
+
+    impl NonFungibleVaultTransfer {
+        pub fn new() -> Global<NonFungibleVaultTransfer> {
+            let bucket = ResourceBuilder::new_integer_non_fungible(OwnerRole::None)
+                .mint_initial_supply([
+                    (1u64.into(), Data {}),
+                    (2u64.into(), Data {}),
+                    (3u64.into(), Data {}),
+                ]);
+            Self {
+                destination: Vault::new(bucket.resource_address()),
+                source: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn vault_ids(&self) -> (NodeId, NodeId) {
+            (self.source.0 .0, self.destination.0 .0)
+        }
+
+        pub fn transfer(&mut self, amount: Decimal) {
+            self.source
+                .as_non_fungible()
+                .transfer_to(&mut self.destination.as_non_fungible(), amount)
+        }
+
+        pub fn transfer_non_fungibles(&mut self, ids: BTreeSet<NonFungibleLocalId>) {
+            self.source
+                .as_non_fungible()
+                .transfer_non_fungibles_to(&mut self.destination.as_non_fungible(), &ids)
+        }
+
+        pub fn transfer_in_directly(&mut self, ids: BTreeSet<NonFungibleLocalId>) {
+            ScryptoEnv
+                .call_method(
+                    self.destination.0.as_node_id(),
+                    NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT,
+                    scrypto_encode(&NonFungibleVaultTransferInInput {
+                        non_fungible_local_ids: ids,
+                    })
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use scrypto::api::ClientObjectApi;
+use scrypto::engine::scrypto_env::ScryptoEnv;
+use scrypto::prelude::*;
+
+#[blueprint]
+mod vault_transfer {
+    struct VaultTransfer {
+        source: Vault,
+        destination: Vault,
+    }
+
+    impl VaultTransfer {
+        pub fn new(source: Bucket, destination: Bucket) -> Global<VaultTransfer> {
+            Self {
+                source: Vault::with_bucket(source),
+                destination: Vault::with_bucket(destination),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn vault_ids(&self) -> (NodeId, NodeId) {
+            (self.source.0 .0, self.destination.0 .0)
+        }
+
+        pub fn transfer(&mut self, amount: Decimal) {
+            self.source
+                .as_fungible()
+                .transfer_to(&mut self.destination.as_fungible(), amount)
+        }
+
+        pub fn transfer_to_self(&mut self, amount: Decimal) {
+            self.source
+                .as_fungible()
+                .transfer_to(&mut self.source.as_fungible(), amount)
+        }
+
+        pub fn transfer_in_directly(&mut self, amount: Decimal) {
+            ScryptoEnv
+                .call_method(
+                    self.destination.0.as_node_id(),
+                    FUNGIBLE_VAULT_TRANSFER_IN_IDENT,
+                    scrypto_encode(&FungibleVaultTransferInInput { amount }).unwrap(),
+                )
+                .unwrap();
+        }
+    }
+}
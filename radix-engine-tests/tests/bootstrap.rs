@@ -71,6 +71,16 @@ fn test_bootstrap_receipt_should_match_constants() {
         .new_package_addresses()
         .contains(&TRANSACTION_TRACKER_PACKAGE));
 
+    assert!(system_bootstrap_receipt
+        .expect_commit_success()
+        .new_package_addresses()
+        .contains(&HOOK_REGISTRY_PACKAGE));
+
+    assert!(system_bootstrap_receipt
+        .expect_commit_success()
+        .new_package_addresses()
+        .contains(&PAYMENT_STREAM_PACKAGE));
+
     assert!(system_bootstrap_receipt
         .expect_commit_success()
         .new_component_addresses()
@@ -1,8 +1,10 @@
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
 use radix_engine_interface::blueprints::consensus_manager::{
-    ConsensusManagerNextRoundInput, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+    ConsensusManagerNextRoundInput, EpochStartRecord, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+    EPOCH_HISTORY_CAPACITY,
 };
+use radix_engine_interface::time::Instant;
 use scrypto_unit::*;
 use transaction::prelude::*;
 
@@ -121,3 +123,85 @@ fn test_date_time_conversions() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn sdk_clock_reads_epoch_history_recorded_on_epoch_changes() {
+    // Arrange
+    let genesis_epoch = Epoch::of(1);
+    let mut test_runner = TestRunner::builder()
+        .with_custom_genesis(CustomGenesis::default(
+            genesis_epoch,
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/clock");
+    let epoch_change_times_ms = [1669663688996i64, 1669663748996];
+    for time_ms in epoch_change_times_ms {
+        test_runner
+            .advance_to_round_at_timestamp(Round::of(1), time_ms)
+            .expect_commit_success();
+    }
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ClockTest",
+            "get_epoch_history",
+            manifest_args![],
+        )
+        .call_function(
+            package_address,
+            "ClockTest",
+            "get_epoch_start_time",
+            manifest_args![Epoch::of(3)],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let history: Vec<EpochStartRecord> = result.output(1);
+    let epochs: Vec<Epoch> = history.iter().map(|record| record.epoch).collect();
+    assert_eq!(
+        epochs,
+        vec![Epoch::of(1), Epoch::of(2), Epoch::of(3), Epoch::of(4)]
+    );
+    assert_eq!(history[2].start_milli, epoch_change_times_ms[0]);
+    assert_eq!(history[3].start_milli, epoch_change_times_ms[1]);
+    assert_eq!(history, test_runner.get_epoch_history());
+
+    let epoch_start_time: Option<Instant> = result.output(2);
+    assert_eq!(epoch_start_time, Some(Instant::new(1669663688)));
+}
+
+#[test]
+fn epoch_history_is_bounded_to_most_recent_epochs() {
+    // Arrange
+    let mut test_runner = TestRunner::builder()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+
+    // Act
+    let epoch_changes = EPOCH_HISTORY_CAPACITY as i64 + 5;
+    for i in 1..=epoch_changes {
+        test_runner
+            .advance_to_round_at_timestamp(Round::of(1), i * 1000)
+            .expect_commit_success();
+    }
+
+    // Assert
+    let history = test_runner.get_epoch_history();
+    let current_epoch = test_runner.get_current_epoch();
+    assert_eq!(history.len(), EPOCH_HISTORY_CAPACITY);
+    assert_eq!(history.last().unwrap().epoch, current_epoch);
+    assert_eq!(history.last().unwrap().start_milli, epoch_changes * 1000);
+    assert_eq!(
+        history.first().unwrap().epoch.number(),
+        current_epoch.number() - EPOCH_HISTORY_CAPACITY as u64 + 1
+    );
+}
@@ -0,0 +1,156 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::ScryptoEvent;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+struct Settings {
+    fee: Decimal,
+    enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, ScryptoEvent)]
+struct ConfigUpdatedEvent {
+    config: Settings,
+}
+
+struct Setup {
+    test_runner: TestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    admin_badge: ResourceAddress,
+    component_address: ComponentAddress,
+}
+
+fn setup() -> Setup {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let admin_badge = test_runner.create_fungible_resource(dec!(1), 0, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component_config");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Configurable",
+            "new",
+            manifest_args!(admin_badge),
+        )
+        .build();
+    let component_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    Setup {
+        test_runner,
+        public_key,
+        account,
+        admin_badge,
+        component_address,
+    }
+}
+
+#[test]
+fn config_admin_can_set_component_config() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        admin_badge,
+        component_address,
+    } = setup();
+    let new_settings = Settings {
+        fee: dec!("0.3"),
+        enabled: false,
+    };
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, admin_badge, dec!(1))
+        .set_component_config(component_address, new_settings.clone())
+        .call_method(component_address, "get_config", manifest_args!())
+        .call_method(component_address, "fee", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let config: Settings = commit.output(3);
+    assert_eq!(config, new_settings);
+    let fee: Decimal = commit.output(4);
+    assert_eq!(fee, dec!("0.3"));
+    assert_eq!(
+        test_runner.extract_events_of_type::<ConfigUpdatedEvent>(commit),
+        vec![ConfigUpdatedEvent {
+            config: new_settings
+        }]
+    );
+}
+
+#[test]
+fn anyone_can_get_component_config() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        component_address,
+        ..
+    } = setup();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "get_config", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let config: Settings = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        config,
+        Settings {
+            fee: dec!("0.1"),
+            enabled: true,
+        }
+    );
+}
+
+#[test]
+fn non_admin_cannot_set_component_config() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        component_address,
+        ..
+    } = setup();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_component_config(
+            component_address,
+            Settings {
+                fee: dec!("0.3"),
+                enabled: false,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
@@ -0,0 +1,247 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn setup() -> (TestRunner, ComponentAddress, NonFungibleGlobalId) {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _) = test_runner.new_key_pair();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "MutableAccessRulesComponent",
+            "new_with_owner",
+            manifest_args!(OwnerRole::Fixed(rule!(require(owner_badge.clone())))),
+        )
+        .build();
+    let component_address = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    (test_runner, component_address, owner_badge)
+}
+
+fn is_component_paused_error(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::ComponentPaused(
+            ..
+        )))
+    )
+}
+
+#[test]
+fn paused_component_rejects_all_but_exempt_methods() {
+    // Arrange
+    let (mut test_runner, component_address, owner_badge) = setup();
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .pause_component(component_address, btreeset!("deposit_funds".to_string()))
+                .build(),
+            vec![owner_badge.clone()],
+        )
+        .expect_commit_success();
+
+    // Act
+    let paused_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(
+                component_address,
+                "set_authority_rules",
+                manifest_args!("borrow_funds_auth", rule!(allow_all)),
+            )
+            .build(),
+        vec![],
+    );
+    let exempt_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "deposit_funds", manifest_args!())
+            .build(),
+        vec![owner_badge.clone()],
+    );
+    let module_receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .get_role(
+                component_address,
+                ObjectModuleId::Main,
+                RoleKey::new("borrow_funds_auth"),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    paused_receipt.expect_specific_failure(is_component_paused_error);
+    exempt_receipt.expect_commit_success();
+    module_receipt.expect_commit_success();
+}
+
+#[test]
+fn paused_component_without_method_auth_rejects_method_calls() {
+    // Arrange
+    let (mut test_runner, _, owner_badge) = setup();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let component_address = test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .call_function(
+                    package_address,
+                    "AssertAccessRule",
+                    "new_with_owner",
+                    manifest_args!(OwnerRole::Fixed(rule!(require(owner_badge.clone())))),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .pause_component(component_address, btreeset!())
+                .build(),
+            vec![owner_badge.clone()],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(
+                component_address,
+                "assert_access_rule",
+                manifest_args!(rule!(allow_all)),
+            )
+            .build(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_component_paused_error);
+}
+
+#[test]
+fn unpaused_component_accepts_method_calls_again() {
+    // Arrange
+    let (mut test_runner, component_address, owner_badge) = setup();
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .pause_component(component_address, btreeset!())
+                .build(),
+            vec![owner_badge.clone()],
+        )
+        .expect_commit_success();
+
+    // Act
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .unpause_component(component_address)
+                .build(),
+            vec![owner_badge.clone()],
+        )
+        .expect_commit_success();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "deposit_funds", manifest_args!())
+            .build(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn component_cannot_be_paused_without_owner_role() {
+    // Arrange
+    let (mut test_runner, component_address, _) = setup();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .pause_component(component_address, btreeset!())
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn method_call_on_never_paused_component_does_not_write_pause_state() {
+    // Arrange
+    let (mut test_runner, component_address, owner_badge) = setup();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "deposit_funds", manifest_args!())
+            .build(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let fields_partition = ACCESS_RULES_BASE_PARTITION
+        .at_offset(ACCESS_RULES_FIELDS_PARTITION_OFFSET)
+        .unwrap();
+    let pause_field: SubstateKey = AccessRulesField::Pause.into();
+    assert!(!commit_result
+        .state_updates
+        .system_updates
+        .get(&(component_address.as_node_id().clone(), fields_partition))
+        .map_or(false, |updates| updates.contains_key(&pause_field)));
+}
+
+#[test]
+fn pause_check_costs_the_same_after_component_is_unpaused() {
+    // Arrange
+    let (mut test_runner, component_address, owner_badge) = setup();
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, "deposit_funds", manifest_args!())
+        .build();
+    let cost_before_pause = test_runner
+        .execute_manifest_ignoring_fee(manifest.clone(), vec![owner_badge.clone()])
+        .expect_commit_success()
+        .fee_summary
+        .execution_cost_sum;
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .pause_component(component_address, btreeset!())
+                .unpause_component(component_address)
+                .build(),
+            vec![owner_badge.clone()],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![owner_badge]);
+
+    // Assert
+    assert_eq!(
+        receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_sum,
+        cost_before_pause
+    );
+}
@@ -0,0 +1,449 @@
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::node_modules::royalty::{
+    ComponentRoyaltyError, EnableRentEvent, TopUpRentEvent,
+};
+use radix_engine::system::system_modules::costing::CostingError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+/// An account, and the badge of its owner.
+type Payer = (ComponentAddress, NonFungibleGlobalId);
+
+fn new_payer(test_runner: &mut TestRunner) -> Payer {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    (account, NonFungibleGlobalId::from_public_key(&public_key))
+}
+
+fn setup() -> (TestRunner, ComponentAddress, Payer) {
+    let mut test_runner = TestRunner::builder().build();
+    let owner = new_payer(&mut test_runner);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/access_rules");
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "MutableAccessRulesComponent",
+            "new_with_owner",
+            manifest_args!(OwnerRole::Fixed(rule!(require(owner.1.clone())))),
+        )
+        .build();
+    let component_address = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    (test_runner, component_address, owner)
+}
+
+fn enable_rent(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    rent_per_epoch: Decimal,
+    amount: Decimal,
+    payer: &Payer,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .withdraw_from_account(payer.0, XRD, amount)
+            .take_all_from_worktop(XRD, "rent")
+            .enable_component_rent(component_address, rent_per_epoch, "rent")
+            .build(),
+        vec![payer.1.clone()],
+    )
+}
+
+fn top_up_rent(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    amount: Decimal,
+    payer: &Payer,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .withdraw_from_account(payer.0, XRD, amount)
+            .take_all_from_worktop(XRD, "rent")
+            .top_up_component_rent(component_address, "rent")
+            .build(),
+        vec![payer.1.clone()],
+    )
+}
+
+fn call_component(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    owner: &Payer,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "deposit_funds", manifest_args!())
+            .build(),
+        vec![owner.1.clone()],
+    )
+}
+
+fn advance_epochs(test_runner: &mut TestRunner, epochs: u64) {
+    let current_epoch = test_runner.get_current_epoch();
+    test_runner.set_current_epoch(current_epoch.after(epochs));
+}
+
+fn is_component_dormant_error(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+            CostingError::ComponentDormant { .. }
+        ))
+    )
+}
+
+#[test]
+fn owner_can_enable_rent() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    let current_epoch = test_runner.get_current_epoch();
+
+    // Act
+    let receipt = enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(1),
+        dec!(10),
+        &owner,
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert!(commit_result
+        .application_events
+        .iter()
+        .any(|(id, _)| test_runner.is_event_name_equal::<EnableRentEvent>(id)));
+    let rent = test_runner
+        .inspect_component_rent(component_address)
+        .unwrap();
+    assert_eq!(rent.rent_per_epoch, dec!(1));
+    assert_eq!(rent.balance, dec!(10));
+    assert_eq!(rent.charged_until, current_epoch);
+    assert_eq!(
+        test_runner.inspect_vault_balance(rent.rent_vault.0 .0),
+        Some(dec!(10))
+    );
+}
+
+#[test]
+fn rent_cannot_be_enabled_without_owner_role() {
+    // Arrange
+    let (mut test_runner, component_address, _) = setup();
+    let other = new_payer(&mut test_runner);
+
+    // Act
+    let receipt = enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(1),
+        dec!(10),
+        &other,
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+    assert!(test_runner
+        .inspect_component_rent(component_address)
+        .is_none());
+}
+
+#[test]
+fn rent_cannot_be_enabled_twice() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(1),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(2),
+        dec!(10),
+        &owner,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::RentIsAlreadyEnabled
+            ))
+        )
+    });
+}
+
+#[test]
+fn rent_per_epoch_must_be_positive() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+
+    // Act
+    let receipt = enable_rent(
+        &mut test_runner,
+        component_address,
+        Decimal::ZERO,
+        dec!(10),
+        &owner,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::InvalidRentPerEpoch(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn rent_cannot_be_topped_up_before_it_is_enabled() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+
+    // Act
+    let receipt = top_up_rent(&mut test_runner, component_address, dec!(10), &owner);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::RentIsDisabled
+            ))
+        )
+    });
+}
+
+#[test]
+fn rent_is_charged_for_every_elapsed_epoch() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(2),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 3);
+
+    // Act
+    let receipt = call_component(&mut test_runner, component_address, &owner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let rent = test_runner
+        .inspect_component_rent(component_address)
+        .unwrap();
+    assert_eq!(fee_summary.total_rent_xrd, dec!(6));
+    assert_eq!(
+        fee_summary.rent_payments.get(&component_address),
+        Some(&(rent.rent_vault.0 .0, dec!(6)))
+    );
+    assert_eq!(rent.balance, dec!(4));
+    assert_eq!(rent.charged_until, test_runner.get_current_epoch());
+    assert_eq!(
+        test_runner.inspect_vault_balance(rent.rent_vault.0 .0),
+        Some(dec!(4))
+    );
+}
+
+#[test]
+fn rent_is_charged_once_per_epoch() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(1),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 1);
+    call_component(&mut test_runner, component_address, &owner).expect_commit_success();
+
+    // Act
+    let receipt = call_component(&mut test_runner, component_address, &owner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.total_rent_xrd, Decimal::ZERO);
+    assert!(fee_summary.rent_payments.is_empty());
+    assert_eq!(
+        test_runner
+            .inspect_component_rent(component_address)
+            .unwrap()
+            .balance,
+        dec!(9)
+    );
+}
+
+#[test]
+fn rent_is_not_charged_by_failed_transactions() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(1),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 2);
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(component_address, "deposit_funds", manifest_args!())
+            .assert_worktop_contains(XRD, dec!(1))
+            .build(),
+        vec![owner.1.clone()],
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+    let rent = test_runner
+        .inspect_component_rent(component_address)
+        .unwrap();
+    assert_eq!(rent.balance, dec!(10));
+    assert_eq!(
+        test_runner.inspect_vault_balance(rent.rent_vault.0 .0),
+        Some(dec!(10))
+    );
+}
+
+#[test]
+fn component_becomes_dormant_when_rent_balance_runs_out() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(5),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 3);
+
+    // Act
+    let receipt = call_component(&mut test_runner, component_address, &owner);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemModuleError(
+            SystemModuleError::CostingError(CostingError::ComponentDormant {
+                component_address,
+                rent_owed: dec!(15),
+                rent_balance: dec!(10),
+            }),
+        ))
+    });
+}
+
+#[test]
+fn dormant_component_modules_remain_callable() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(5),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 3);
+
+    // Act
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .set_metadata(component_address, "name", "Dormant")
+            .get_role(
+                component_address,
+                ObjectModuleId::Main,
+                RoleKey::new("borrow_funds_auth"),
+            )
+            .build(),
+        vec![owner.1.clone()],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn dormant_component_is_revived_by_top_up() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    enable_rent(
+        &mut test_runner,
+        component_address,
+        dec!(5),
+        dec!(10),
+        &owner,
+    )
+    .expect_commit_success();
+    advance_epochs(&mut test_runner, 3);
+    call_component(&mut test_runner, component_address, &owner)
+        .expect_specific_failure(is_component_dormant_error);
+
+    // Act
+    let anyone = new_payer(&mut test_runner);
+    let top_up_receipt = top_up_rent(&mut test_runner, component_address, dec!(20), &anyone);
+    let receipt = call_component(&mut test_runner, component_address, &owner);
+
+    // Assert
+    assert!(top_up_receipt
+        .expect_commit_success()
+        .application_events
+        .iter()
+        .any(|(id, _)| test_runner.is_event_name_equal::<TopUpRentEvent>(id)));
+    assert_eq!(
+        receipt.expect_commit_success().fee_summary.total_rent_xrd,
+        dec!(15)
+    );
+    let rent = test_runner
+        .inspect_component_rent(component_address)
+        .unwrap();
+    assert_eq!(rent.balance, dec!(15));
+    assert_eq!(
+        test_runner.inspect_vault_balance(rent.rent_vault.0 .0),
+        Some(dec!(15))
+    );
+}
+
+#[test]
+fn components_without_rent_are_not_charged() {
+    // Arrange
+    let (mut test_runner, component_address, owner) = setup();
+    advance_epochs(&mut test_runner, 100);
+
+    // Act
+    let receipt = call_component(&mut test_runner, component_address, &owner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.total_rent_xrd, Decimal::ZERO);
+    assert!(fee_summary.rent_payments.is_empty());
+}
@@ -2790,3 +2790,129 @@ fn test_tips_and_fee_distribution_two_validators() {
             / (initial_stake_amount1 + initial_stake_amount2)
     );
 }
+
+#[test]
+fn emissions_and_stake_units_follow_stake_and_fee_factor_over_multiple_epochs() {
+    // Arrange
+    let genesis_epoch = Epoch::of(3);
+    let epoch_emissions_xrd = dec!("100");
+    let a_fee_factor = Decimal::zero();
+    let b_fee_factor = dec!("0.5");
+    let a_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let b_key = Secp256k1PrivateKey::from_u64(2).unwrap().public_key();
+    let validators = vec![
+        GenesisValidator {
+            fee_factor: a_fee_factor,
+            ..GenesisValidator::from(a_key)
+        },
+        GenesisValidator {
+            fee_factor: b_fee_factor,
+            ..GenesisValidator::from(b_key)
+        },
+    ];
+    let allocations = vec![
+        (
+            a_key,
+            vec![GenesisStakeAllocation {
+                account_index: 0,
+                xrd_amount: dec!("1000"),
+            }],
+        ),
+        (
+            b_key,
+            vec![GenesisStakeAllocation {
+                account_index: 1,
+                xrd_amount: dec!("3000"),
+            }],
+        ),
+    ];
+    let accounts = validators
+        .iter()
+        .map(|validator| validator.owner)
+        .collect::<Vec<_>>();
+    let genesis = CustomGenesis {
+        genesis_data_chunks: vec![
+            GenesisDataChunk::Validators(validators),
+            GenesisDataChunk::Stakes {
+                accounts,
+                allocations,
+            },
+        ],
+        genesis_epoch,
+        initial_config: CustomGenesis::default_consensus_manager_config()
+            .with_epoch_change_condition(EpochChangeCondition {
+                min_round_count: 1,
+                max_round_count: 1, // deliberate, to go through rounds/epoch without gaps
+                target_duration_millis: 0,
+            })
+            .with_total_emission_xrd_per_epoch(epoch_emissions_xrd),
+        initial_time_ms: 1,
+        initial_current_leader: Some(0),
+        faucet_supply: *DEFAULT_TESTING_FAUCET_SUPPLY,
+    };
+    let mut test_runner = TestRunner::builder().with_custom_genesis(genesis).build();
+    let a_address = test_runner.get_active_validator_with_key(&a_key);
+    let b_address = test_runner.get_active_validator_with_key(&b_key);
+    let a_genesis_stake_units = test_runner.get_validator_stake_unit_supply(a_address);
+    let b_genesis_stake_units = test_runner.get_validator_stake_unit_supply(b_address);
+    let mut a_total_emission_xrd = Decimal::zero();
+
+    for _ in 0..3 {
+        let a_starting_stake = test_runner.get_validator_stake_xrd(a_address);
+        let b_starting_stake = test_runner.get_validator_stake_xrd(b_address);
+        let a_starting_stake_units = test_runner.get_validator_stake_unit_supply(a_address);
+        let b_starting_stake_units = test_runner.get_validator_stake_unit_supply(b_address);
+
+        // Act
+        let receipt = test_runner.advance_epochs(1).pop().unwrap();
+
+        // Assert
+        let emission_per_staked_xrd = epoch_emissions_xrd / (a_starting_stake + b_starting_stake);
+        let emissions = test_runner.extract_validator_emissions(receipt.expect_commit_success());
+        assert_eq!(
+            emissions
+                .iter()
+                .map(|(address, _)| *address)
+                .collect::<Vec<_>>(),
+            vec![b_address, a_address] // ordered by stake desc
+        );
+        for (address, event) in emissions {
+            let (starting_stake, starting_stake_units, fee_factor) = if address == a_address {
+                (a_starting_stake, a_starting_stake_units, a_fee_factor)
+            } else {
+                (b_starting_stake, b_starting_stake_units, b_fee_factor)
+            };
+            let emission_xrd = starting_stake * emission_per_staked_xrd;
+            if address == a_address {
+                a_total_emission_xrd += emission_xrd;
+            }
+            assert_close_to!(event.starting_stake_pool_xrd, starting_stake);
+            assert_close_to!(event.validator_fee_xrd, emission_xrd * fee_factor);
+            assert_close_to!(
+                event.stake_pool_added_xrd,
+                emission_xrd - emission_xrd * fee_factor
+            );
+            assert_close_to!(event.total_stake_unit_supply, starting_stake_units);
+        }
+    }
+
+    // Assert: all newly minted stake units (from fees and rewards) are locked in the owner's vault
+    for (address, genesis_stake_units) in [
+        (a_address, a_genesis_stake_units),
+        (b_address, b_genesis_stake_units),
+    ] {
+        let validator_substate = test_runner.get_validator_info(address);
+        let owner_stake_units = test_runner
+            .inspect_vault_balance(validator_substate.locked_owner_stake_unit_vault_id.0)
+            .unwrap();
+        assert_close_to!(
+            test_runner.get_validator_stake_unit_supply(address),
+            genesis_stake_units + owner_stake_units
+        );
+    }
+    // ...and with a zero fee, the entire emission accrues to the existing stake units
+    assert_close_to!(
+        test_runner.get_validator_stake_unit_redemption_value(a_address, a_genesis_stake_units),
+        dec!("1000") + a_total_emission_xrd
+    );
+}
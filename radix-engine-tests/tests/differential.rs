@@ -0,0 +1,61 @@
+use radix_engine::transaction::TransactionResultKind;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn transaction_stream() -> Vec<DifferentialManifest> {
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    (0..3)
+        .map(|_| {
+            let manifest = ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .try_deposit_batch_or_abort(account)
+                .build();
+            (manifest, vec![])
+        })
+        .collect()
+}
+
+#[test]
+fn identical_engines_do_not_diverge() {
+    // Arrange
+    let mut harness =
+        DifferentialHarness::new(TestRunner::builder().build(), TestRunner::builder().build());
+
+    // Act
+    let report = harness.run(transaction_stream());
+
+    // Assert
+    assert_eq!(report.transaction_count, 3);
+    assert!(report.is_compatible());
+}
+
+#[test]
+fn diverging_engines_are_reported_per_transaction() {
+    // Arrange
+    let mut harness = DifferentialHarness::new(
+        TestRunner::builder().build(),
+        TestRunner::builder().with_max_number_of_events(0).build(),
+    );
+
+    // Act
+    let report = harness.run(transaction_stream());
+
+    // Assert
+    assert!(!report.is_compatible());
+    assert_eq!(report.divergences.len(), 3);
+    let divergences = &report.divergences[&0];
+    assert!(matches!(
+        divergences[0],
+        ReceiptDivergence::Result {
+            a: TransactionResultKind::CommitSuccess,
+            ..
+        }
+    ));
+    assert!(divergences
+        .iter()
+        .any(|divergence| matches!(divergence, ReceiptDivergence::StateUpdate { .. })));
+}
@@ -0,0 +1,218 @@
+use radix_engine::system::system_modules::transaction_runtime::EmissionOrigin;
+use radix_engine::transaction::CommitResult;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+/// Logs an entry, moves some XRD (emitting vault events), and logs another entry.
+fn build_manifest(logger_package: PackageAddress) -> TransactionManifestV1 {
+    let recipient = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(42).unwrap().public_key(),
+    );
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            logger_package,
+            "Logger",
+            "emit_log",
+            manifest_args!("first"),
+        )
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(recipient)
+        .call_function(
+            logger_package,
+            "Logger",
+            "emit_log",
+            manifest_args!("second"),
+        )
+        .build()
+}
+
+/// The parts of the events, logs and their origins which don't depend on allocated addresses.
+fn ordering_of(result: &CommitResult) -> Vec<(usize, u32, String, FnIdent, String)> {
+    let events = result
+        .application_events
+        .iter()
+        .zip(result.application_event_origins.iter())
+        .map(|((identifier, _), origin)| (origin, format!("{:?}", identifier.1)));
+    let logs = result
+        .application_logs
+        .iter()
+        .zip(result.application_log_origins.iter())
+        .map(|((level, message), origin)| (origin, format!("{:?} {}", level, message)));
+    let mut entries: Vec<_> = events
+        .chain(logs)
+        .map(|(origin, entry)| {
+            (
+                origin.instruction_index,
+                origin.emission_index,
+                origin.actor.blueprint_id.blueprint_name.clone(),
+                origin.actor.ident.clone(),
+                entry,
+            )
+        })
+        .collect();
+    entries.sort_by_key(|(instruction_index, emission_index, ..)| {
+        (*instruction_index, *emission_index)
+    });
+    entries
+}
+
+fn assert_totally_ordered(origins: &[EmissionOrigin]) {
+    for pair in origins.windows(2) {
+        assert!(pair[0].sort_key() < pair[1].sort_key(), "{:?}", pair);
+    }
+}
+
+fn run_on_test_runner() -> CommitResult {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+    let receipt = test_runner.execute_manifest(build_manifest(package_address), vec![]);
+    receipt.expect_commit_success().clone()
+}
+
+#[test]
+fn events_and_logs_are_ordered_by_instruction_then_emission() {
+    // Act
+    let result = run_on_test_runner();
+
+    // Assert
+    assert_eq!(
+        result.application_events.len(),
+        result.application_event_origins.len()
+    );
+    assert_eq!(
+        result.application_logs.len(),
+        result.application_log_origins.len()
+    );
+    assert_totally_ordered(&result.application_event_origins);
+    assert_totally_ordered(&result.application_log_origins);
+
+    // Events and logs share the emission counter of an instruction
+    let mut keys: Vec<(usize, u32)> = result
+        .application_event_origins
+        .iter()
+        .chain(result.application_log_origins.iter())
+        .map(EmissionOrigin::sort_key)
+        .collect();
+    let count = keys.len();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), count);
+
+    let log_origins: Vec<(usize, String, FnIdent)> = result
+        .application_log_origins
+        .iter()
+        .map(|origin| {
+            (
+                origin.instruction_index,
+                origin.actor.blueprint_id.blueprint_name.clone(),
+                origin.actor.ident.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        log_origins,
+        vec![
+            (
+                1,
+                "Logger".to_string(),
+                FnIdent::Application("emit_log".to_string())
+            ),
+            (
+                4,
+                "Logger".to_string(),
+                FnIdent::Application("emit_log".to_string())
+            ),
+        ]
+    );
+    assert_eq!(
+        result.application_logs,
+        vec![
+            (Level::Info, "first".to_string()),
+            (Level::Info, "second".to_string())
+        ]
+    );
+
+    // The deposit events fall between the two logs
+    assert!(result
+        .application_event_origins
+        .iter()
+        .any(|origin| origin.instruction_index == 3));
+}
+
+#[test]
+fn event_and_log_ordering_is_stable_across_runs() {
+    // Act
+    let first = run_on_test_runner();
+    let second = run_on_test_runner();
+
+    // Assert
+    assert_eq!(first.application_events, second.application_events);
+    assert_eq!(first.application_logs, second.application_logs);
+    assert_eq!(
+        first.application_event_origins,
+        second.application_event_origins
+    );
+    assert_eq!(
+        first.application_log_origins,
+        second.application_log_origins
+    );
+    assert_eq!(ordering_of(&first), ordering_of(&second));
+}
+
+#[test]
+fn failed_transaction_keeps_ordered_logs_and_drops_events() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Logger",
+            "emit_log",
+            manifest_args!("first"),
+        )
+        .call_function(
+            package_address,
+            "Logger",
+            "rust_panic",
+            manifest_args!("boom"),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_failure();
+    assert!(result.application_events.is_empty());
+    assert!(result.application_event_origins.is_empty());
+    assert_eq!(
+        result.application_logs,
+        vec![(Level::Info, "first".to_string())]
+    );
+    assert_eq!(result.application_log_origins.len(), 1);
+    assert_eq!(result.application_log_origins[0].instruction_index, 1);
+}
+
+#[cfg(feature = "rocksdb")]
+#[test]
+fn event_and_log_ordering_is_stable_across_store_backends() {
+    // Arrange
+    let path = std::env::temp_dir().join("radix-engine-tests-event-ordering");
+    let _ = std::fs::remove_dir_all(&path);
+    let mut rocksdb_test_runner = BasicRocksdbTestRunner::new(path.clone(), false);
+    let package_address = rocksdb_test_runner.compile_and_publish("./tests/blueprints/logger");
+
+    // Act
+    let receipt = rocksdb_test_runner.execute_manifest(build_manifest(package_address), vec![]);
+    let in_memory = run_on_test_runner();
+
+    // Assert
+    let on_rocksdb = receipt.expect_commit_success();
+    assert_eq!(ordering_of(on_rocksdb), ordering_of(&in_memory));
+    drop(rocksdb_test_runner);
+    let _ = std::fs::remove_dir_all(&path);
+}
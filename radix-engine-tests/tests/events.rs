@@ -18,6 +18,7 @@ use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerNextRoundInput, EpochChangeCondition, ValidatorUpdateAcceptDelegatedStakeInput,
     CONSENSUS_MANAGER_NEXT_ROUND_IDENT, VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
 };
+use radix_engine_interface::blueprints::transaction_processor::TRANSACTION_PROCESSOR_BLUEPRINT;
 use radix_engine_interface::{burn_roles, metadata, metadata_init, mint_roles, recall_roles};
 use scrypto::prelude::{AccessRule, FromPublicKey};
 use scrypto::NonFungibleData;
@@ -224,7 +225,7 @@ fn vault_fungible_recall_emits_correct_events() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault fungible withdraw, vault fungible recall, vault fungible deposit
+        assert_eq!(events.len(), 5); // Five events: vault lock fee, vault fungible withdraw, vault fungible recall, vault recall audit, vault fungible deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier
@@ -258,6 +259,28 @@ fn vault_fungible_recall_emits_correct_events() {
             _ => false,
         });
         assert!(match events.get(3) {
+            Some((
+                event_identifier
+                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<RecallAuditEvent>(event_identifier)
+                && is_decoded_equal(
+                    &RecallAuditEvent {
+                        vault_id: InternalAddress::new_or_panic(vault_id.into()),
+                        resource_address: recallable_resource_address,
+                        recalled: RecalledResource::Amount(1.into()),
+                        recaller: Some(GlobalCaller::PackageBlueprint(BlueprintId::new(
+                            &TRANSACTION_PROCESSOR_PACKAGE,
+                            TRANSACTION_PROCESSOR_BLUEPRINT
+                        ))),
+                        reason: None,
+                    },
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(4) {
             Some((
                 event_identifier
                 @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
@@ -313,7 +336,7 @@ fn vault_non_fungible_recall_emits_correct_events() {
     // Assert
     {
         let events = receipt.expect_commit(true).clone().application_events;
-        assert_eq!(events.len(), 4); // Four events: vault lock fee, vault non-fungible withdraw, vault non-fungible recall, vault non-fungible deposit
+        assert_eq!(events.len(), 5); // Five events: vault lock fee, vault non-fungible withdraw, vault non-fungible recall, vault recall audit, vault non-fungible deposit
         assert!(match events.get(0) {
             Some((
                 event_identifier
@@ -346,6 +369,28 @@ fn vault_non_fungible_recall_emits_correct_events() {
             _ => false,
         });
         assert!(match events.get(3) {
+            Some((
+                event_identifier
+                @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
+                ref event_data,
+            )) if test_runner.is_event_name_equal::<RecallAuditEvent>(event_identifier)
+                && is_decoded_equal(
+                    &RecallAuditEvent {
+                        vault_id: InternalAddress::new_or_panic(vault_id.into()),
+                        resource_address: recallable_resource_address,
+                        recalled: RecalledResource::Ids([non_fungible_local_id.clone()].into()),
+                        recaller: Some(GlobalCaller::PackageBlueprint(BlueprintId::new(
+                            &TRANSACTION_PROCESSOR_PACKAGE,
+                            TRANSACTION_PROCESSOR_BLUEPRINT
+                        ))),
+                        reason: None,
+                    },
+                    event_data
+                ) =>
+                true,
+            _ => false,
+        });
+        assert!(match events.get(4) {
             Some((
                 event_identifier
                 @ EventTypeIdentifier(Emitter::Method(_, ObjectModuleId::Main), ..),
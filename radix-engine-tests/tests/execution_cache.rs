@@ -0,0 +1,108 @@
+use radix_engine::transaction::{
+    execute_transaction_with_cache, ExecutionConfig, ExecutionResultCache, FeeReserveConfig,
+    TransactionReceipt,
+};
+use radix_engine::types::*;
+use radix_engine::vm::wasm::DefaultWasmEngine;
+use radix_engine::vm::ScryptoVm;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute(
+    test_runner: &TestRunner,
+    transaction: &PreparedTestTransaction,
+    initial_proofs: BTreeSet<NonFungibleGlobalId>,
+    cache: &mut ExecutionResultCache,
+    state_version: u64,
+) -> TransactionReceipt {
+    execute_transaction_with_cache(
+        test_runner.substate_db(),
+        &ScryptoVm::<DefaultWasmEngine>::default(),
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::for_test_transaction(),
+        &transaction.get_executable(initial_proofs),
+        cache,
+        state_version,
+    )
+}
+
+fn prepare_transfer(account: ComponentAddress) -> PreparedTestTransaction {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+    TestTransaction::new_from_nonce(manifest, 1)
+        .prepare()
+        .unwrap()
+}
+
+#[test]
+fn re_execution_at_same_state_version_returns_cached_receipt() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let transaction = prepare_transfer(account);
+    let proofs = btreeset![NonFungibleGlobalId::from_public_key(&public_key)];
+    let mut cache = ExecutionResultCache::default();
+
+    // Act
+    let proposed = execute(&test_runner, &transaction, proofs.clone(), &mut cache, 7);
+    let committed = execute(&test_runner, &transaction, proofs, &mut cache, 7);
+
+    // Assert
+    proposed.expect_commit_success();
+    assert_eq!(cache.metrics().misses, 1);
+    assert_eq!(cache.metrics().hits, 1);
+    assert_eq!(
+        scrypto_encode(&proposed).unwrap(),
+        scrypto_encode(&committed).unwrap()
+    );
+}
+
+#[test]
+fn re_execution_at_different_state_version_is_not_cached() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let transaction = prepare_transfer(account);
+    let proofs = btreeset![NonFungibleGlobalId::from_public_key(&public_key)];
+    let mut cache = ExecutionResultCache::default();
+
+    // Act
+    execute(&test_runner, &transaction, proofs.clone(), &mut cache, 7);
+    execute(&test_runner, &transaction, proofs, &mut cache, 8);
+
+    // Assert
+    assert_eq!(cache.metrics().misses, 2);
+    assert_eq!(cache.metrics().hits, 0);
+    assert_eq!(cache.len(), 1);
+
+    cache.evict_before(9);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn re_execution_with_different_signatures_is_not_cached() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let transaction = prepare_transfer(account);
+    let mut cache = ExecutionResultCache::default();
+
+    // Act
+    let unsigned = execute(&test_runner, &transaction, btreeset![], &mut cache, 7);
+    let signed = execute(
+        &test_runner,
+        &transaction,
+        btreeset![NonFungibleGlobalId::from_public_key(&public_key)],
+        &mut cache,
+        7,
+    );
+
+    // Assert
+    assert_eq!(cache.metrics().misses, 2);
+    assert_eq!(cache.metrics().hits, 0);
+    unsigned.expect_commit_success();
+    signed.expect_commit_success();
+}
@@ -0,0 +1,96 @@
+use radix_engine::errors::RejectionError;
+use radix_engine::system::system_modules::costing::ExecutionDeadline;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_with_deadline(
+    test_runner: &mut TestRunner,
+    account: ComponentAddress,
+    execution_deadline: ExecutionDeadline,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_preview().with_execution_deadline(execution_deadline),
+    )
+}
+
+#[test]
+fn exceeding_cost_unit_deadline_rejects_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = execute_with_deadline(
+        &mut test_runner,
+        account,
+        ExecutionDeadline::CostUnits(10_000),
+    );
+
+    // Assert
+    match receipt.expect_rejection() {
+        RejectionError::ExecutionDeadlineExceeded { deadline, metrics } => {
+            assert_eq!(*deadline, ExecutionDeadline::CostUnits(10_000));
+            assert!(metrics.cost_units_consumed > 10_000);
+            assert!(metrics.elapsed_millis.is_some());
+        }
+        e => panic!("Unexpected rejection: {:?}", e),
+    }
+}
+
+#[test]
+fn rejection_on_deadline_leaves_state_untouched() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.account_balance(account, XRD).unwrap();
+    execute_with_deadline(
+        &mut test_runner,
+        account,
+        ExecutionDeadline::CostUnits(10_000),
+    )
+    .expect_rejection();
+
+    // Act
+    let receipt = execute_with_deadline(
+        &mut test_runner,
+        account,
+        ExecutionDeadline::CostUnits(u32::MAX),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(account, XRD).unwrap(),
+        balance_before + dec!(10000)
+    );
+}
+
+#[test]
+fn transaction_within_wall_clock_deadline_is_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = execute_with_deadline(
+        &mut test_runner,
+        account,
+        ExecutionDeadline::WallClockMillis(60_000),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
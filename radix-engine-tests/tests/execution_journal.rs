@@ -0,0 +1,112 @@
+use radix_engine::system::system_modules::execution_journal::*;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_transfer(
+    test_runner: &mut TestRunner,
+    amount: Decimal,
+    with_execution_journal: bool,
+) -> TransactionReceipt {
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, amount, "xrd")
+        .try_deposit_or_abort(account, "xrd")
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_notarized_transaction().with_execution_journal(with_execution_journal),
+    )
+}
+
+#[test]
+fn execution_journal_is_disabled_by_default() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner, dec!("10"), false);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(receipt.execution_journal.is_none());
+}
+
+#[test]
+fn execution_journal_records_locks_reads_and_allocations() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner, dec!("10"), true);
+
+    // Assert
+    receipt.expect_commit_success();
+    let journal = receipt.execution_journal.unwrap();
+    assert!(journal
+        .entries
+        .iter()
+        .any(|entry| matches!(entry, JournalEntry::OpenSubstate { .. })));
+    assert!(journal
+        .entries
+        .iter()
+        .any(|entry| matches!(entry, JournalEntry::ReadSubstate { .. })));
+    assert!(journal
+        .entries
+        .iter()
+        .any(|entry| matches!(entry, JournalEntry::AllocateNodeId { .. })));
+    assert_eq!(
+        ExecutionJournal::from_bytes(&journal.to_bytes()).unwrap(),
+        journal
+    );
+}
+
+#[test]
+fn execution_journals_of_same_transaction_are_identical() {
+    // Arrange
+    let mut test_runner1 = TestRunner::builder().build();
+    let mut test_runner2 = TestRunner::builder().build();
+
+    // Act
+    let journal1 = execute_transfer(&mut test_runner1, dec!("10"), true)
+        .execution_journal
+        .unwrap();
+    let journal2 = execute_transfer(&mut test_runner2, dec!("10"), true)
+        .execution_journal
+        .unwrap();
+
+    // Assert
+    assert_eq!(journal1.compare(&journal2), None);
+}
+
+#[test]
+fn execution_journal_comparison_pinpoints_divergence() {
+    // Arrange
+    let mut test_runner1 = TestRunner::builder().build();
+    let mut test_runner2 = TestRunner::builder().build();
+
+    // Act
+    let journal1 = execute_transfer(&mut test_runner1, dec!("10"), true)
+        .execution_journal
+        .unwrap();
+    let journal2 = execute_transfer(&mut test_runner2, dec!("20"), true)
+        .execution_journal
+        .unwrap();
+
+    // Assert
+    let divergence = journal1.compare(&journal2).unwrap();
+    assert_eq!(
+        journal1.entries[..divergence.index],
+        journal2.entries[..divergence.index]
+    );
+    assert_ne!(divergence.left, divergence.right);
+}
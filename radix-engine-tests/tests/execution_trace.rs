@@ -593,6 +593,48 @@ fn test_worktop_changes() {
     }
 }
 
+#[test]
+fn test_chrome_trace_export() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest,
+        vec![public_key.clone().into()],
+        0,
+        PreviewFlags::default(),
+    );
+
+    // Assert
+    let execution_trace = &receipt.expect_commit_success().execution_trace;
+    for trace in &execution_trace.execution_traces {
+        assert_cost_units_nested(trace);
+    }
+    let json = execution_trace.to_chrome_trace_json();
+    assert!(json.starts_with("{\"traceEvents\":[{"));
+    assert!(json.ends_with("}]}"));
+    assert!(json.contains("\"name\":\"Account::withdraw\",\"cat\":\"method\",\"ph\":\"X\""));
+    assert!(json.contains("\"name\":\"Account::try_deposit_batch_or_abort\""));
+    assert!(json.contains("\"instruction_index\":1"));
+}
+
+fn assert_cost_units_nested(trace: &ExecutionTrace) {
+    assert!(trace.cost_units_start <= trace.cost_units_end);
+    for child in &trace.children {
+        assert!(trace.cost_units_start <= child.cost_units_start);
+        assert!(child.cost_units_end <= trace.cost_units_end);
+        assert_cost_units_nested(child);
+    }
+}
+
 fn traces_for_instruction(
     traces: &Vec<ExecutionTrace>,
     instruction_index: usize,
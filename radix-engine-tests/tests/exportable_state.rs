@@ -0,0 +1,174 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+struct VaultSummary {
+    resource_address: ResourceAddress,
+    amount: Decimal,
+}
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+struct ExportableStateExportedState {
+    name: String,
+    tokens: VaultSummary,
+    records: NodeId,
+}
+
+struct Setup {
+    test_runner: TestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    owner_badge: ResourceAddress,
+    token: ResourceAddress,
+    component_address: ComponentAddress,
+}
+
+fn setup() -> Setup {
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let owner_badge = test_runner.create_fungible_resource(dec!(1), 0, account);
+    let token = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/exportable_state");
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, token, dec!(40))
+        .take_all_from_worktop(token, "tokens")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                package_address,
+                "ExportableState",
+                "new",
+                manifest_args!(lookup.bucket("tokens"), owner_badge),
+            )
+        })
+        .build();
+    let component_address = test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    Setup {
+        test_runner,
+        public_key,
+        account,
+        owner_badge,
+        token,
+        component_address,
+    }
+}
+
+#[test]
+fn owner_can_export_state_with_vaults_summarized() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        owner_badge,
+        token,
+        component_address,
+    } = setup();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, owner_badge, dec!(1))
+        .call_method(component_address, "export_state", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let state: ExportableStateExportedState = receipt.expect_commit_success().output(2);
+    assert_eq!(state.name, "exportable");
+    assert_eq!(
+        state.tokens,
+        VaultSummary {
+            resource_address: token,
+            amount: dec!(40),
+        }
+    );
+    assert!(state.records.is_internal_kv_store());
+
+    let vaults = test_runner.get_component_vaults(component_address, token);
+    assert_eq!(vaults.len(), 1);
+    assert_eq!(
+        test_runner.inspect_vault_balance(vaults[0]).unwrap(),
+        dec!(40)
+    );
+}
+
+#[test]
+fn non_owner_cannot_export_state() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        component_address,
+        ..
+    } = setup();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "export_state", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn other_methods_remain_public() {
+    // Arrange
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        token,
+        component_address,
+        ..
+    } = setup();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, token, dec!(10))
+        .take_all_from_worktop(token, "tokens")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_method(
+                component_address,
+                "deposit",
+                manifest_args!(lookup.bucket("tokens")),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let vaults = test_runner.get_component_vaults(component_address, token);
+    assert_eq!(
+        test_runner.inspect_vault_balance(vaults[0]).unwrap(),
+        dec!(50)
+    );
+}
@@ -3,8 +3,9 @@ use radix_engine::errors::{ApplicationError, CallFrameError, KernelError};
 use radix_engine::errors::{RejectionError, RuntimeError};
 use radix_engine::kernel::call_frame::OpenSubstateError;
 use radix_engine::kernel::heap::HeapOpenSubstateError;
+use radix_engine::system::system_modules::costing::FeeStatementLine;
 use radix_engine::track::interface::AcquireLockError;
-use radix_engine::transaction::{FeeLocks, TransactionReceipt};
+use radix_engine::transaction::{CommitResult, FeeLocks, TransactionReceipt};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -562,3 +563,162 @@ fn regular_and_contingent_fee_locks_are_correct_in_execution_trace() {
         }
     )
 }
+
+#[test]
+fn test_fee_reconciliation_is_balanced() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key1, _, account1) = test_runner.new_allocated_account();
+    let (public_key2, _, account2) = test_runner.new_allocated_account();
+
+    // Act
+    let receipts: Vec<TransactionReceipt> = [true, false]
+        .into_iter()
+        .map(|succeed| {
+            let manifest = ManifestBuilder::new()
+                .lock_fee(account1, 500)
+                .lock_contingent_fee(account2, dec!("0.001"))
+                .then(|builder| {
+                    if succeed {
+                        builder
+                    } else {
+                        builder.assert_worktop_contains(XRD, 1)
+                    }
+                })
+                .build();
+            test_runner.execute_manifest(
+                manifest,
+                vec![
+                    NonFungibleGlobalId::from_public_key(&public_key1),
+                    NonFungibleGlobalId::from_public_key(&public_key2),
+                ],
+            )
+        })
+        .collect();
+
+    // Assert
+    for (receipt, is_success) in receipts.iter().zip([true, false]) {
+        let commit_result = receipt.expect_commit(is_success);
+        let summary = &commit_result.fee_summary;
+        let reconciliation = commit_result.fee_reconciliation();
+        assert_eq!(reconciliation.check(summary), Ok(()));
+        assert_eq!(reconciliation.balance(), Decimal::ZERO);
+        assert_eq!(reconciliation.total_locked(), dec!("500.001"));
+        assert_eq!(
+            reconciliation.total_locked() - reconciliation.total_refunded(),
+            summary.total_cost() + summary.total_instantiation_deposit_xrd
+        );
+    }
+    // The contingent fee is only charged on success
+    let contingent_fee_refunded = |commit_result: &CommitResult, amount: Decimal| {
+        commit_result
+            .fee_reconciliation()
+            .lines
+            .iter()
+            .any(|line| match line {
+                FeeStatementLine::Refunded {
+                    amount: refunded, ..
+                } => refunded.eq(&amount),
+                _ => false,
+            })
+    };
+    assert!(contingent_fee_refunded(
+        receipts[0].expect_commit_success(),
+        Decimal::ZERO
+    ));
+    assert!(contingent_fee_refunded(
+        receipts[1].expect_commit_failure(),
+        dec!("0.001")
+    ));
+}
+
+fn execute_transfer(test_runner: &mut TestRunner) -> TransactionReceipt {
+    let (public_key, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 500)
+        .withdraw_from_account(account1, XRD, 66)
+        .try_deposit_batch_or_abort(account2)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn test_fee_assertions_with_tolerances() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner);
+
+    // Assert
+    receipt.expect_commit_success();
+    receipt.expect_fee_between(dec!("0.01"), dec!("0.5"));
+    receipt.expect_execution_cost_units_between(1_000_000, 50_000_000);
+    receipt.expect_execution_cost_breakdown(|breakdown| {
+        breakdown.contains_key("RunNativeCode::lock_fee")
+            && breakdown.values().map(|x| *x as u64).sum::<u64>() > 0
+    });
+}
+
+#[test]
+fn test_instruction_cost_breakdown() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let breakdown = &fee_summary.instruction_cost_breakdown;
+    assert_eq!(breakdown.keys().cloned().collect::<Vec<_>>(), vec![0, 1, 2]);
+    for instruction_breakdown in breakdown.values() {
+        assert!(instruction_breakdown.substate_read_cost_units > 0);
+        assert_eq!(instruction_breakdown.royalty_cost_xrd, Decimal::ZERO);
+    }
+    assert!(
+        breakdown
+            .values()
+            .map(|b| b.execution_cost_units())
+            .sum::<u32>()
+            < fee_summary.execution_cost_sum
+    );
+    assert!(
+        breakdown
+            .values()
+            .map(|b| b.execution_cost_xrd)
+            .fold(Decimal::ZERO, |total, cost| total + cost)
+            < fee_summary.total_execution_cost_xrd
+    );
+    assert!(fee_summary.most_costly_instruction().is_some());
+}
+
+#[test]
+#[should_panic(expected = "Expected total cost between")]
+fn test_fee_assertion_fails_outside_of_bounds() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner);
+
+    // Assert
+    receipt.expect_fee_between(dec!("10"), dec!("100"));
+}
+
+#[test]
+#[should_panic(expected = "Unexpected execution cost breakdown")]
+fn test_execution_cost_breakdown_assertion_fails_on_unmet_predicate() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_transfer(&mut test_runner);
+
+    // Assert
+    receipt.expect_execution_cost_breakdown(|breakdown| breakdown.contains_key("NotACost"));
+}
@@ -0,0 +1,112 @@
+use radix_engine::system::system_modules::costing::*;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn transfer_manifest(from: ComponentAddress, to: ComponentAddress) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee(from, 50)
+        .withdraw_from_account(from, XRD, 10)
+        .try_deposit_batch_or_abort(to)
+        .build()
+}
+
+#[test]
+fn settlement_of_unchanged_fee_summary_matches_engine() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        transfer_manifest(account, other_account),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    let settlement = FeeCalculator::new(fee_summary, true).settle();
+    assert_eq!(settlement.payments, fee_summary.fee_payments);
+    assert_eq!(settlement.shortfall, Decimal::ZERO);
+    let (vault_id, _) = fee_summary.fee_payments.iter().next().unwrap();
+    assert_eq!(
+        settlement.payments[vault_id] + settlement.refunds[vault_id],
+        dec!("50")
+    );
+}
+
+#[test]
+fn settlement_with_raised_tip_matches_engine() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let preview = |test_runner: &mut TestRunner, tip_percentage: u16| {
+        test_runner
+            .preview_manifest(
+                transfer_manifest(account, other_account),
+                vec![public_key.into()],
+                tip_percentage,
+                PreviewFlags::default(),
+            )
+            .expect_commit_success()
+            .fee_summary
+            .clone()
+    };
+    let untipped = preview(&mut test_runner, 0);
+    let tipped = preview(&mut test_runner, 25);
+
+    // Act
+    let calculator = FeeCalculator::new(&untipped, true).with_tip_percentage(25);
+
+    // Assert
+    assert_eq!(untipped.execution_cost_sum, tipped.execution_cost_sum);
+    assert_eq!(
+        calculator.fee_summary().total_tipping_cost_xrd,
+        tipped.total_tipping_cost_xrd
+    );
+    assert_eq!(calculator.total_fee(), tipped.total_cost());
+    assert_eq!(calculator.settle().payments, tipped.fee_payments);
+}
+
+#[test]
+fn settlement_reports_shortfall_when_royalty_exceeds_locked_fees() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let receipt = test_runner.execute_manifest(
+        transfer_manifest(account, other_account),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let fee_summary = receipt.expect_commit_success().fee_summary.clone();
+    let (vault_id, _) = fee_summary.fee_payments.iter().next().unwrap();
+    let recipient = RoyaltyRecipient::Component(other_account);
+
+    // Act
+    let calculator = FeeCalculator::new(&fee_summary, true).with_royalty(
+        recipient.clone(),
+        *vault_id,
+        dec!("100"),
+    );
+    let settlement = calculator.settle();
+
+    // Assert
+    assert_eq!(
+        calculator.fee_summary().royalty_cost_of(&recipient),
+        dec!("100")
+    );
+    assert_eq!(settlement.payments[vault_id], dec!("50"));
+    assert_eq!(settlement.refunds[vault_id], Decimal::ZERO);
+    assert_eq!(
+        settlement.shortfall,
+        fee_summary.total_cost() + dec!("100") - dec!("50")
+    );
+
+    // Removing the royalty again restores the original settlement
+    let settlement = calculator
+        .with_royalty(recipient, *vault_id, Decimal::ZERO)
+        .settle();
+    assert_eq!(settlement.payments, fee_summary.fee_payments);
+}
@@ -0,0 +1,93 @@
+use radix_engine::errors::{RejectionError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::costing::{CostingError, FeeReserveError};
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+use transaction::validation::*;
+
+fn execute_with_max_fee(
+    test_runner: &mut TestRunner,
+    max_fee_in_xrd: Option<Decimal>,
+) -> TransactionReceipt {
+    let current_epoch = test_runner.get_current_epoch();
+    let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce: 5,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+            max_fee_in_xrd,
+            expires_at_timestamp: None,
+            idempotency_key: None,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .clear_auth_zone()
+                .build(),
+        )
+        .notarize(&sk_notary)
+        .build();
+    let validated = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+        .validate(transaction.prepare().unwrap())
+        .unwrap();
+    test_runner.execute_transaction(
+        validated.get_executable(),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_notarized_transaction(),
+    )
+}
+
+#[test]
+fn transaction_costing_more_than_its_declared_max_fee_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let max_fee = dec!("0.0001");
+
+    // Act
+    let receipt = execute_with_max_fee(&mut test_runner, Some(max_fee));
+
+    // Assert
+    receipt.expect_specific_rejection(|e| match e {
+        RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::SystemModuleError(
+            SystemModuleError::CostingError(CostingError::FeeReserveError(
+                FeeReserveError::FeeCeilingExceeded {
+                    max_fee: declared,
+                    required,
+                },
+            )),
+        )) => declared.eq(&max_fee) && *required > max_fee,
+        _ => false,
+    });
+}
+
+#[test]
+fn transaction_costing_less_than_its_declared_max_fee_is_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let max_fee = dec!(100);
+
+    // Act
+    let receipt = execute_with_max_fee(&mut test_runner, Some(max_fee));
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert!(fee_summary.total_cost() <= max_fee);
+}
+
+#[test]
+fn transaction_without_declared_max_fee_is_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_max_fee(&mut test_runner, None);
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -31,6 +31,7 @@ impl TransactionFuzzer {
         let scrypto_interpreter = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            wasm_instrumentation_cache: None,
         };
         let mut substate_db = InMemorySubstateDatabase::standard();
         Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false)
@@ -87,6 +88,9 @@ impl TransactionFuzzer {
             notary_public_key: private_key.public_key().into(),
             notary_is_signatory: false,
             tip_percentage: 0,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         };
 
         TransactionBuilder::new()
@@ -0,0 +1,75 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_notarized_transaction(test_runner: &mut TestRunner) -> NotarizedTransactionV1 {
+    let current_epoch = test_runner.get_current_epoch();
+    let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+    TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce: 1,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .clear_auth_zone()
+                .build(),
+        )
+        .notarize(&sk_notary)
+        .build()
+}
+
+#[test]
+fn transaction_in_gateway_format_can_be_replayed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let transaction = create_notarized_transaction(&mut test_runner);
+    let converted =
+        GatewayTransactionJson::from_notarized_transaction(&transaction, &network).unwrap();
+    let json = format!(
+        r#"{{
+            "state_version": 42,
+            "transaction_status": "CommittedSuccess",
+            "intent_hash": "{}",
+            "raw_hex": "{}",
+            "receipt": {{ "status": "CommittedSuccess" }}
+        }}"#,
+        converted.intent_hash.unwrap(),
+        converted.payload_hex
+    );
+    let gateway_transaction: GatewayTransactionJson = serde_json::from_str(&json).unwrap();
+
+    // Act
+    let receipt = test_runner.execute_gateway_transaction(&network, &gateway_transaction);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn converted_transaction_survives_json_round_trip() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let network = NetworkDefinition::simulator();
+    let transaction = create_notarized_transaction(&mut test_runner);
+
+    // Act
+    let converted =
+        GatewayTransactionJson::from_notarized_transaction(&transaction, &network).unwrap();
+    let json = serde_json::to_string(&converted).unwrap();
+    let parsed: GatewayTransactionJson = serde_json::from_str(&json).unwrap();
+
+    // Assert
+    assert_eq!(parsed, converted);
+    assert_eq!(parsed.to_notarized_transaction(&network), Ok(transaction));
+}
@@ -0,0 +1,94 @@
+use radix_engine::errors::{RejectionError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::limits::TransactionLimitsError;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_with_config(
+    test_runner: &mut TestRunner,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .deposit_batch(account)
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn heap_size_peaks_are_reported_per_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_config(&mut test_runner, ExecutionConfig::for_test_transaction());
+
+    // Assert
+    let heap_size_peaks = &receipt
+        .expect_commit_success()
+        .execution_metrics
+        .heap_size_peaks;
+    let faucet_peak = heap_size_peaks
+        .get(&BlueprintId::new(&FAUCET_PACKAGE, FAUCET_BLUEPRINT))
+        .expect("Faucet peak should be reported");
+    let account_peak = heap_size_peaks
+        .get(&BlueprintId::new(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT))
+        .expect("Account peak should be reported");
+    assert!(*faucet_peak > 0);
+    assert!(*account_peak > 0);
+}
+
+#[test]
+fn heap_size_peaks_are_not_reported_unless_tracking_is_enabled() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ExecutionConfig::for_notarized_transaction(),
+    );
+
+    // Assert
+    assert!(receipt
+        .expect_commit_success()
+        .execution_metrics
+        .heap_size_peaks
+        .is_empty());
+}
+
+#[test]
+fn exceeding_max_heap_size_per_frame_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction().with_max_heap_size_per_frame(1),
+    );
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::MaxHeapSizePerFrameExceeded { max: 1, .. }
+                )
+            ))
+        )
+    });
+}
@@ -0,0 +1,328 @@
+use radix_engine::blueprints::hook_registry::HookRegistryError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::hook_registry::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+struct HookRegistryTest {
+    test_runner: TestRunner,
+    package_address: PackageAddress,
+    registry: ComponentAddress,
+}
+
+impl HookRegistryTest {
+    fn new(hook_invoker_rule: AccessRule) -> Self {
+        let mut test_runner = TestRunner::builder().build();
+        let package_address = test_runner.compile_and_publish("./tests/blueprints/hook_registry");
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    HOOK_REGISTRY_PACKAGE,
+                    HOOK_REGISTRY_BLUEPRINT,
+                    HOOK_REGISTRY_INSTANTIATE_IDENT,
+                    manifest_args!(OwnerRole::None, hook_invoker_rule),
+                )
+                .build(),
+            vec![],
+        );
+        let registry = receipt.expect_commit_success().new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            package_address,
+            registry,
+        }
+    }
+
+    fn new_listener(&mut self) -> ComponentAddress {
+        let receipt = self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    self.package_address,
+                    "HookListener",
+                    "instantiate",
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit_success().new_component_addresses()[0]
+    }
+
+    fn subscribe(
+        &mut self,
+        listener: ComponentAddress,
+        event_name: &str,
+        method_name: &str,
+    ) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    listener,
+                    "subscribe",
+                    manifest_args!(self.registry, event_name, method_name),
+                )
+                .build(),
+            vec![],
+        )
+    }
+
+    fn invoke(&mut self, event_name: &str, payload: Vec<u8>) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.registry,
+                    HOOK_REGISTRY_INVOKE_HOOKS_IDENT,
+                    manifest_args!(event_name, payload),
+                )
+                .build(),
+            vec![],
+        )
+    }
+
+    fn received(&mut self, listener: ComponentAddress) -> Vec<(String, Vec<u8>)> {
+        let receipt = self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(listener, "received", manifest_args!())
+                .build(),
+            vec![],
+        );
+        receipt.expect_commit_success().output(1)
+    }
+}
+
+fn hook(receiver: ComponentAddress, method_name: &str) -> RegisteredHook {
+    RegisteredHook {
+        receiver,
+        method_name: method_name.to_string(),
+    }
+}
+
+#[test]
+fn invoking_hooks_calls_every_listener_in_registration_order() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let first = test.new_listener();
+    let second = test.new_listener();
+    test.subscribe(second, "deposit", "on_event")
+        .expect_commit_success();
+    test.subscribe(first, "deposit", "on_event")
+        .expect_commit_success();
+    test.subscribe(first, "withdraw", "on_event")
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.invoke("deposit", vec![1, 2, 3]);
+
+    // Assert
+    let called: Vec<RegisteredHook> = receipt.expect_commit_success().output(1);
+    assert_eq!(
+        called,
+        vec![hook(second, "on_event"), hook(first, "on_event")]
+    );
+    let expected = vec![("deposit".to_string(), vec![1u8, 2, 3])];
+    assert_eq!(test.received(first), expected);
+    assert_eq!(test.received(second), expected);
+}
+
+#[test]
+fn cannot_register_hook_on_behalf_of_another_component() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    let registry = test.registry;
+
+    // Act
+    let receipt = test.test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                registry,
+                HOOK_REGISTRY_REGISTER_HOOK_IDENT,
+                manifest_args!("deposit", hook(listener, "on_event")),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+}
+
+#[test]
+fn cannot_register_hook_from_another_component() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    let (package_address, registry) = (test.package_address, test.registry);
+
+    // Act
+    let receipt = test.test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "HookListener",
+                "subscribe_other",
+                manifest_args!(registry, "deposit", listener),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+}
+
+#[test]
+fn registering_beyond_the_hook_limit_fails() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    for _ in 0..MAX_HOOKS_PER_EVENT {
+        let listener = test.new_listener();
+        test.subscribe(listener, "deposit", "on_event")
+            .expect_commit_success();
+    }
+    let listener = test.new_listener();
+
+    // Act
+    let receipt = test.subscribe(listener, "deposit", "on_event");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::HookRegistryError(
+                HookRegistryError::TooManyHooks { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn registering_the_same_hook_twice_fails() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    test.subscribe(listener, "deposit", "on_event")
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.subscribe(listener, "deposit", "on_event");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::HookRegistryError(
+                HookRegistryError::HookAlreadyRegistered(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn unregistered_hook_is_no_longer_called() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    let registry = test.registry;
+    test.subscribe(listener, "deposit", "on_event")
+        .expect_commit_success();
+    test.test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    listener,
+                    "unsubscribe",
+                    manifest_args!(registry, "deposit", "on_event"),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.invoke("deposit", vec![]);
+
+    // Assert
+    let called: Vec<RegisteredHook> = receipt.expect_commit_success().output(1);
+    assert!(called.is_empty());
+    assert!(test.received(listener).is_empty());
+}
+
+#[test]
+fn invoking_hooks_requires_the_invoker_role() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(deny_all));
+    let listener = test.new_listener();
+    test.subscribe(listener, "deposit", "on_event")
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.invoke("deposit", vec![]);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn hook_returning_a_value_fails_the_invocation() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    test.subscribe(listener, "deposit", "on_event_returning_value")
+        .expect_commit_success();
+
+    // Act
+    let receipt = test.invoke("deposit", vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::HookRegistryError(
+                HookRegistryError::HookReturnedValue(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn event_name_longer_than_limit_is_rejected() {
+    // Arrange
+    let mut test = HookRegistryTest::new(rule!(allow_all));
+    let listener = test.new_listener();
+    let event_name = "e".repeat(MAX_HOOK_EVENT_NAME_LENGTH + 1);
+
+    // Act
+    let receipt = test.subscribe(listener, &event_name, "on_event");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::HookRegistryError(
+                HookRegistryError::EventNameTooLong { .. }
+            ))
+        )
+    });
+}
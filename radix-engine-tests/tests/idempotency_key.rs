@@ -0,0 +1,136 @@
+use radix_engine::blueprints::transaction_tracker::MAX_TRACKED_IDEMPOTENCY_KEYS;
+use radix_engine::errors::RejectionError;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+use transaction::validation::*;
+
+fn execute_with_idempotency_key(
+    test_runner: &mut TestRunner,
+    idempotency_key: Option<Hash>,
+    nonce: u32,
+) -> TransactionReceipt {
+    let current_epoch = test_runner.get_current_epoch();
+    let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .clear_auth_zone()
+                .build(),
+        )
+        .notarize(&sk_notary)
+        .build();
+    let validated = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+        .validate(transaction.prepare().unwrap())
+        .unwrap();
+    test_runner.execute_transaction(
+        validated.get_executable(),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_notarized_transaction(),
+    )
+}
+
+#[test]
+fn transaction_with_previously_committed_idempotency_key_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let idempotency_key = hash("withdrawal-1");
+    let expiry_epoch = test_runner.get_current_epoch().after(10);
+    execute_with_idempotency_key(&mut test_runner, Some(idempotency_key), 1)
+        .expect_commit_success();
+
+    // Act
+    let receipt = execute_with_idempotency_key(&mut test_runner, Some(idempotency_key), 2);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        e.eq(&RejectionError::IdempotencyKeyPreviouslyCommitted {
+            idempotency_key,
+            expiry_epoch,
+        })
+    });
+}
+
+#[test]
+fn transactions_with_distinct_or_no_idempotency_keys_are_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let first = execute_with_idempotency_key(&mut test_runner, Some(hash("withdrawal-1")), 1);
+    let second = execute_with_idempotency_key(&mut test_runner, Some(hash("withdrawal-2")), 2);
+    let without_key = execute_with_idempotency_key(&mut test_runner, None, 3);
+    let without_key_again = execute_with_idempotency_key(&mut test_runner, None, 4);
+
+    // Assert
+    first.expect_commit_success();
+    second.expect_commit_success();
+    without_key.expect_commit_success();
+    without_key_again.expect_commit_success();
+}
+
+#[test]
+fn idempotency_key_can_be_reused_once_its_epoch_range_has_passed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let idempotency_key = hash("withdrawal-1");
+    execute_with_idempotency_key(&mut test_runner, Some(idempotency_key), 1)
+        .expect_commit_success();
+    let current_epoch = test_runner.get_current_epoch();
+    test_runner.set_current_epoch(current_epoch.after(10));
+
+    // Act
+    let receipt = execute_with_idempotency_key(&mut test_runner, Some(idempotency_key), 2);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn transaction_with_new_idempotency_key_is_rejected_while_all_keys_are_unexpired() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    for i in 0..MAX_TRACKED_IDEMPOTENCY_KEYS {
+        execute_with_idempotency_key(&mut test_runner, Some(hash(i.to_le_bytes())), i)
+            .expect_commit_success();
+    }
+
+    // Act
+    let keyed_receipt = execute_with_idempotency_key(
+        &mut test_runner,
+        Some(hash("withdrawal-1")),
+        MAX_TRACKED_IDEMPOTENCY_KEYS,
+    );
+    let unkeyed_receipt =
+        execute_with_idempotency_key(&mut test_runner, None, MAX_TRACKED_IDEMPOTENCY_KEYS + 1);
+    let current_epoch = test_runner.get_current_epoch();
+    test_runner.set_current_epoch(current_epoch.after(10));
+    let receipt_after_expiry = execute_with_idempotency_key(
+        &mut test_runner,
+        Some(hash("withdrawal-1")),
+        MAX_TRACKED_IDEMPOTENCY_KEYS + 2,
+    );
+
+    // Assert
+    keyed_receipt.expect_specific_rejection(|e| {
+        e.eq(&RejectionError::IdempotencyKeysExhausted {
+            capacity: MAX_TRACKED_IDEMPOTENCY_KEYS,
+        })
+    });
+    unkeyed_receipt.expect_commit_success();
+    receipt_after_expiry.expect_commit_success();
+}
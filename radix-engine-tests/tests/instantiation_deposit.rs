@@ -0,0 +1,100 @@
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn instantiate_component(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+    instantiation_deposit: Option<Decimal>,
+    fail_afterwards: bool,
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet().call_function(
+        package_address,
+        "TransientCounter",
+        "new",
+        manifest_args!(),
+    );
+    if fail_afterwards {
+        builder = builder.assert_worktop_contains(XRD, dec!(1));
+    }
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(builder.build(), nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig {
+            instantiation_deposit,
+            ..FeeReserveConfig::default()
+        },
+        ExecutionConfig::for_test_transaction(),
+    )
+}
+
+#[test]
+fn globalizing_a_component_holds_the_instantiation_deposit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transient");
+
+    // Act
+    let receipt = instantiate_component(&mut test_runner, package_address, Some(dec!(50)), false);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let component_address = commit_result.new_component_addresses()[0];
+    let fee_summary = &commit_result.fee_summary;
+    assert_eq!(fee_summary.total_instantiation_deposit_xrd, dec!(50));
+    assert_eq!(
+        fee_summary
+            .instantiation_deposits
+            .get(&GlobalAddress::from(component_address)),
+        Some(&dec!(50))
+    );
+    let deposits = test_runner.get_instantiation_deposits();
+    assert_eq!(
+        deposits
+            .deposits
+            .get(&GlobalAddress::from(component_address)),
+        Some(&dec!(50))
+    );
+    assert_eq!(
+        test_runner.inspect_vault_balance(deposits.deposit_vault.0 .0),
+        Some(dec!(50))
+    );
+}
+
+#[test]
+fn no_deposit_is_held_by_default() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transient");
+
+    // Act
+    let receipt = instantiate_component(&mut test_runner, package_address, None, false);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(fee_summary.total_instantiation_deposit_xrd, Decimal::ZERO);
+    assert!(fee_summary.instantiation_deposits.is_empty());
+    assert!(test_runner.get_instantiation_deposits().deposits.is_empty());
+}
+
+#[test]
+fn instantiation_deposit_is_not_held_for_failed_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transient");
+
+    // Act
+    let receipt = instantiate_component(&mut test_runner, package_address, Some(dec!(50)), true);
+
+    // Assert
+    let commit_result = receipt.expect_commit_failure();
+    assert_eq!(
+        commit_result.fee_summary.total_instantiation_deposit_xrd,
+        Decimal::ZERO
+    );
+    assert!(test_runner.get_instantiation_deposits().deposits.is_empty());
+}
@@ -0,0 +1,213 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::system::system_modules::invocation_filter::InvocationFilterError;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn publish_and_instantiate(
+    test_runner: &mut TestRunner,
+) -> (PackageAddress, ComponentAddress, ComponentAddress) {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/static_call");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "PriceOracle",
+                "new",
+                manifest_args!(dec!(10)),
+            )
+            .build(),
+        vec![],
+    );
+    let oracle = receipt.expect_commit_success().new_component_addresses()[0];
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "Market",
+                "new",
+                manifest_args!(oracle, dec!(2)),
+            )
+            .build(),
+        vec![],
+    );
+    let market = receipt.expect_commit_success().new_component_addresses()[0];
+
+    (package_address, oracle, market)
+}
+
+fn set_invocation_filter(
+    test_runner: &mut TestRunner,
+    filter: InvocationFilter,
+    initial_proofs: Vec<NonFungibleGlobalId>,
+) -> TransactionReceipt {
+    test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                CONSENSUS_MANAGER,
+                CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT,
+                ConsensusManagerSetInvocationFilterInput { filter },
+            )
+            .build(),
+        initial_proofs,
+    )
+}
+
+fn call_method(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+) -> TransactionReceipt {
+    test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, method_name, manifest_args!())
+            .build(),
+        vec![],
+    )
+}
+
+fn is_invocation_filter_error(e: &RuntimeError, error: InvocationFilterError) -> bool {
+    matches!(
+        e,
+        RuntimeError::SystemModuleError(SystemModuleError::InvocationFilterError(e)) if *e == error
+    )
+}
+
+#[test]
+fn setting_invocation_filter_requires_system_role() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_invocation_filter().build();
+
+    // Act
+    let receipt = set_invocation_filter(
+        &mut test_runner,
+        InvocationFilter::AllowList(btreeset!()),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn invocation_filter_is_not_enforced_unless_module_is_enabled() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (package_address, oracle, _) = publish_and_instantiate(&mut test_runner);
+    set_invocation_filter(
+        &mut test_runner,
+        InvocationFilter::DenyList(btreeset!(package_address.into(), oracle.into())),
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = call_method(&mut test_runner, oracle, "get_price");
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn deny_listed_component_cannot_be_invoked() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_invocation_filter().build();
+    let (_, oracle, market) = publish_and_instantiate(&mut test_runner);
+    set_invocation_filter(
+        &mut test_runner,
+        InvocationFilter::DenyList(btreeset!(oracle.into())),
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let direct_receipt = call_method(&mut test_runner, oracle, "get_price");
+    let indirect_receipt = call_method(&mut test_runner, market, "update_price");
+    let unrelated_receipt = call_method(&mut test_runner, market, "get_rate");
+
+    // Assert
+    direct_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::DenyListed(oracle.into()))
+    });
+    indirect_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::DenyListed(oracle.into()))
+    });
+    unrelated_receipt.expect_commit_success();
+}
+
+#[test]
+fn deny_listed_package_cannot_be_invoked() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_invocation_filter().build();
+    let (package_address, _, market) = publish_and_instantiate(&mut test_runner);
+    set_invocation_filter(
+        &mut test_runner,
+        InvocationFilter::DenyList(btreeset!(package_address.into())),
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let function_receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "PriceOracle",
+                "new",
+                manifest_args!(dec!(10)),
+            )
+            .build(),
+        vec![],
+    );
+    let method_receipt = call_method(&mut test_runner, market, "get_rate");
+
+    // Assert
+    function_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::DenyListed(package_address.into()))
+    });
+    method_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::DenyListed(package_address.into()))
+    });
+}
+
+#[test]
+fn only_native_packages_and_allow_listed_entities_can_be_invoked() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_invocation_filter().build();
+    let (_, oracle, market) = publish_and_instantiate(&mut test_runner);
+    set_invocation_filter(
+        &mut test_runner,
+        InvocationFilter::AllowList(btreeset!(market.into())),
+        vec![AuthAddresses::system_role()],
+    )
+    .expect_commit_success();
+
+    // Act
+    let allowed_receipt = call_method(&mut test_runner, market, "get_rate");
+    let indirect_receipt = call_method(&mut test_runner, market, "update_price");
+    let direct_receipt = call_method(&mut test_runner, oracle, "get_price");
+
+    // Assert
+    allowed_receipt.expect_commit_success();
+    indirect_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::NotAllowListed(oracle.into()))
+    });
+    direct_receipt.expect_specific_failure(|e| {
+        is_invocation_filter_error(e, InvocationFilterError::NotAllowListed(oracle.into()))
+    });
+}
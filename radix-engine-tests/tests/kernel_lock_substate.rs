@@ -43,6 +43,7 @@ pub fn test_open_substate_of_invisible_package_address() {
         schema_cache: NonIterMap::new(),
         callback_obj: Vm {
             scrypto_vm: &scrypto_vm,
+            package_stubs: None,
         },
         modules: SystemModuleMixer::new(
             execution_config.enabled_modules,
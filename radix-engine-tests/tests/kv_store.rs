@@ -523,3 +523,56 @@ fn nested_kv_stores_works() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn locked_entry_cannot_be_opened_by_nested_invocation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let component_address = test_runner.construct_new(package_address, "EntryLock", "new", ());
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "increment_while_locked",
+            manifest_args!(component_address),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::CallFrameError(
+                CallFrameError::OpenSubstateError(OpenSubstateError::TrackError(_))
+            ))
+        )
+    });
+}
+
+#[test]
+fn unlocked_entry_can_be_opened_by_nested_invocation() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let component_address = test_runner.construct_new(package_address, "EntryLock", "new", ());
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "increment_after_unlock",
+            manifest_args!(component_address),
+        )
+        .call_method(component_address, "get", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let value: u32 = receipt.expect_commit_success().output(2);
+    assert_eq!(value, 11u32);
+}
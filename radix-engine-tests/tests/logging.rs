@@ -108,3 +108,34 @@ fn test_assert_length_5() {
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+enum LoggerError {
+    InvalidMessage { message: String },
+}
+
+#[test]
+fn test_scrypto_fail() {
+    // Arrange
+    let function_name = "scrypto_fail";
+    let message = "Oops";
+
+    // Act
+    let receipt = call(function_name, message);
+
+    // Assert
+    {
+        let (fn_identifier, error) = receipt.expect_blueprint_error::<LoggerError, _>(|_| true);
+        assert_eq!(
+            error,
+            LoggerError::InvalidMessage {
+                message: message.to_owned()
+            }
+        );
+        assert_eq!(fn_identifier.blueprint_id.blueprint_name, "Logger");
+        assert_eq!(
+            fn_identifier.ident,
+            FnIdent::Application(function_name.to_owned())
+        );
+    }
+}
@@ -93,7 +93,36 @@ fn test_emit_log(message_size: usize, iterations: usize, expected_err: Option<Ru
 
 #[test]
 fn test_emit_some_logs() {
-    test_emit_log(DEFAULT_MAX_LOG_SIZE, DEFAULT_MAX_NUMBER_OF_LOGS - 1, None);
+    test_emit_log(
+        DEFAULT_MAX_TOTAL_LOG_SIZE / DEFAULT_MAX_NUMBER_OF_LOGS,
+        DEFAULT_MAX_NUMBER_OF_LOGS - 1,
+        None,
+    );
+}
+
+#[test]
+fn test_emit_large_logs_up_to_total_size() {
+    test_emit_log(
+        DEFAULT_MAX_LOG_SIZE,
+        DEFAULT_MAX_TOTAL_LOG_SIZE / DEFAULT_MAX_LOG_SIZE,
+        None,
+    );
+}
+
+#[test]
+fn test_emit_large_logs_exceeding_total_size() {
+    test_emit_log(
+        DEFAULT_MAX_LOG_SIZE,
+        DEFAULT_MAX_TOTAL_LOG_SIZE / DEFAULT_MAX_LOG_SIZE + 1,
+        Some(RuntimeError::SystemModuleError(
+            SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TotalLogSizeTooLarge {
+                    actual: DEFAULT_MAX_TOTAL_LOG_SIZE + DEFAULT_MAX_LOG_SIZE,
+                    max: DEFAULT_MAX_TOTAL_LOG_SIZE,
+                },
+            ),
+        )),
+    );
 }
 
 #[test]
@@ -112,7 +141,7 @@ fn test_emit_large_logs() {
 #[test]
 fn test_emit_lots_of_logs() {
     test_emit_log(
-        DEFAULT_MAX_LOG_SIZE,
+        DEFAULT_MAX_TOTAL_LOG_SIZE / DEFAULT_MAX_NUMBER_OF_LOGS,
         1_000_000,
         Some(RuntimeError::SystemModuleError(
             SystemModuleError::TransactionLimitsError(TransactionLimitsError::TooManyLogs),
@@ -91,6 +91,118 @@ fn can_remove_metadata() {
     assert_eq!(value, None);
 }
 
+fn new_metadata_component(test_runner: &mut TestRunner) -> (PackageAddress, ComponentAddress) {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/metadata_component");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "new2",
+            manifest_args!("key".to_string(), "value".to_string()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+    (package_address, component_address)
+}
+
+#[test]
+fn can_remove_metadata_through_manifest() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, component_address) = new_metadata_component(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .remove_metadata(component_address, "key")
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let value = test_runner.get_metadata(component_address.into(), "key");
+    assert_eq!(value, None);
+}
+
+#[test]
+fn can_set_and_get_typed_metadata_from_scrypto() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (package_address, component_address) = new_metadata_component(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "set_typed_metadata",
+            manifest_args!(component_address),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_metadata(component_address.into(), "url"),
+        Some(MetadataValue::Url(Url::of("https://www.radixdlt.com")))
+    );
+    assert_eq!(
+        test_runner.get_metadata(component_address.into(), "addresses"),
+        Some(MetadataValue::GlobalAddressArray(vec![
+            component_address.into()
+        ]))
+    );
+}
+
+#[test]
+fn cannot_set_or_remove_metadata_locked_from_scrypto() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (package_address, component_address) = new_metadata_component(&mut test_runner);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataComponent",
+            "lock_metadata",
+            manifest_args!(component_address, "key".to_string()),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    for manifest in [
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .set_metadata(component_address, "key", "other_value")
+            .build(),
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .remove_metadata(component_address, "key")
+            .build(),
+    ] {
+        // Act
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::SystemError(SystemError::MutatingImmutableSubstate)
+            )
+        });
+    }
+    assert_eq!(
+        test_runner.get_metadata(component_address.into(), "key"),
+        Some(MetadataValue::String("value".to_string()))
+    );
+}
+
 fn can_set_metadata_through_manifest(entry: MetadataValue) {
     // Arrange
     let mut test_runner = TestRunner::builder().build();
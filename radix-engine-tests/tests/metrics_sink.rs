@@ -0,0 +1,145 @@
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::transaction::*;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::ScryptoVm;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use transaction::prelude::*;
+
+fn execute_with_metrics(
+    substate_db: &InMemorySubstateDatabase,
+    scrypto_interpreter: &ScryptoVm<DefaultWasmEngine>,
+    manifest: TransactionManifestV1,
+    nonce: u32,
+    metrics_sink: &mut dyn MetricsSink,
+) -> TransactionReceipt {
+    execute_transaction_with_metrics(
+        substate_db,
+        scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::for_test_transaction(),
+        &TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        metrics_sink,
+    )
+}
+
+#[test]
+fn aggregating_metrics_sink_sums_up_transactions() {
+    // Arrange
+    let scrypto_interpreter = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+        wasm_instrumentation_cache: None,
+    };
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false)
+        .bootstrap_test_default()
+        .unwrap();
+    let mut metrics_sink = AggregatingMetricsSink::new();
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+
+    // Act
+    let receipt = execute_with_metrics(
+        &substate_db,
+        &scrypto_interpreter,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .try_deposit_batch_or_abort(account)
+            .build(),
+        0,
+        &mut metrics_sink,
+    );
+    execute_with_metrics(
+        &substate_db,
+        &scrypto_interpreter,
+        ManifestBuilder::new().clear_auth_zone().build(),
+        1,
+        &mut metrics_sink,
+    )
+    .expect_rejection();
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(metrics_sink.transaction_count(), 2);
+    assert_eq!(
+        metrics_sink.transaction_count_of(TransactionResultKind::CommitSuccess),
+        1
+    );
+    assert_eq!(
+        metrics_sink.transaction_count_of(TransactionResultKind::Reject),
+        1
+    );
+    assert_eq!(
+        metrics_sink.total_execution_cost_units,
+        u64::from(commit.fee_summary.execution_cost_sum)
+    );
+    let io = &metrics_sink.substate_io;
+    assert!(io.reads.db_reads > 0);
+    assert!(io.reads.db_read_bytes > 0);
+    assert!(io.reads.track_hits > 0);
+    assert_eq!(
+        io.writes,
+        commit
+            .state_updates
+            .database_updates
+            .values()
+            .map(|partition_updates| partition_updates.len())
+            .sum::<usize>()
+    );
+    assert!(metrics_sink.max_duration <= metrics_sink.total_duration);
+}
+
+#[test]
+fn metrics_sink_receives_typed_callbacks_in_order() {
+    // Arrange
+    #[derive(Default)]
+    struct RecordingSink {
+        calls: Vec<&'static str>,
+    }
+    impl MetricsSink for RecordingSink {
+        fn on_duration(&mut self, _duration: core::time::Duration) {
+            self.calls.push("duration");
+        }
+        fn on_execution_cost_units(&mut self, _cost_units: u32) {
+            self.calls.push("cost_units");
+        }
+        fn on_substate_io(&mut self, _metrics: &SubstateIoMetrics) {
+            self.calls.push("substate_io");
+        }
+        fn on_transaction_executed(&mut self, _result: TransactionResultKind) {
+            self.calls.push("executed");
+        }
+    }
+    let scrypto_interpreter = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+        wasm_instrumentation_cache: None,
+    };
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    Bootstrapper::new(&mut substate_db, &scrypto_interpreter, false)
+        .bootstrap_test_default()
+        .unwrap();
+    let mut metrics_sink = RecordingSink::default();
+
+    // Act
+    execute_with_metrics(
+        &substate_db,
+        &scrypto_interpreter,
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        0,
+        &mut metrics_sink,
+    )
+    .expect_commit_success();
+
+    // Assert
+    assert_eq!(
+        metrics_sink.calls,
+        vec!["duration", "cost_units", "substate_io", "executed"]
+    );
+}
@@ -800,3 +800,193 @@ fn cant_burn_non_fungible_with_wrong_non_fungible_local_id_type() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+fn create_mintable_sandwich_resource(test_runner: &mut TestRunner) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            false,
+            NonFungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => rule!(allow_all);
+                    minter_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None::<BTreeMap<NonFungibleLocalId, Sandwich>>,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit(true).new_resource_addresses()[0]
+}
+
+#[test]
+fn can_mint_non_fungible_from_blobs() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_mintable_sandwich_resource(&mut test_runner);
+    let sandwich = Sandwich {
+        name: "test".to_string(),
+        available: false,
+        tastes_great: true,
+        reference: None,
+        own: None,
+    };
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible_from_blobs(
+            resource_address,
+            [(
+                NonFungibleLocalId::integer(1),
+                scrypto_encode(&sandwich).unwrap(),
+            )],
+        )
+        .assert_worktop_contains(resource_address, 1)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+    let (amount, first_id) = test_runner.inspect_non_fungible_vault(vault_id).unwrap();
+    assert_eq!(amount, dec!(1));
+    assert_eq!(first_id, Some(NonFungibleLocalId::integer(1)));
+}
+
+#[test]
+fn cannot_mint_non_fungible_from_blob_not_matching_schema() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_mintable_sandwich_resource(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible_from_blobs(
+            resource_address,
+            [(
+                NonFungibleLocalId::integer(1),
+                scrypto_encode(&("not a sandwich",)).unwrap(),
+            )],
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::PayloadValidationAgainstSchemaError(..))
+        )
+    });
+}
+
+#[test]
+fn cannot_mint_non_fungible_from_blob_which_is_not_sbor() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_mintable_sandwich_resource(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible_from_blobs(
+            resource_address,
+            [(NonFungibleLocalId::integer(1), vec![0xde, 0xad, 0xbe, 0xef])],
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::InvalidNonFungibleDataPayload(..)
+            ))
+        )
+    });
+}
+
+#[derive(ManifestSbor, ScryptoSbor, NonFungibleData)]
+pub struct Collectible {}
+
+fn create_account_with_non_fungibles(
+    test_runner: &mut TestRunner,
+    count: u64,
+) -> (Secp256k1PublicKey, ComponentAddress, ResourceAddress) {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let entries: BTreeMap<NonFungibleLocalId, Collectible> = (1..=count)
+        .map(|n| (NonFungibleLocalId::integer(n), Collectible {}))
+        .collect();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            false,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some(entries),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let resource_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    (public_key, account, resource_address)
+}
+
+fn transfer_one_non_fungible_cost_units(test_runner: &mut TestRunner, count: u64) -> u32 {
+    let (public_key, account, resource_address) =
+        create_account_with_non_fungibles(test_runner, count);
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            account,
+            resource_address,
+            &btreeset!(NonFungibleLocalId::integer(1)),
+        )
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success()
+        .fee_summary
+        .execution_cost_sum
+}
+
+#[test]
+fn transferring_a_non_fungible_costs_the_same_regardless_of_vault_size() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let small_vault_cost_units = transfer_one_non_fungible_cost_units(&mut test_runner, 10);
+    let large_vault_cost_units = transfer_one_non_fungible_cost_units(&mut test_runner, 500);
+
+    // Assert
+    // The ids held by a vault are stored as separate substates, so only those taken are read
+    assert!(
+        large_vault_cost_units <= small_vault_cost_units * 11 / 10,
+        "{} cost units from a large vault vs {} from a small vault",
+        large_vault_cost_units,
+        small_vault_cost_units
+    );
+}
@@ -0,0 +1,69 @@
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FUNGIBLE_VAULT_BLUEPRINT;
+use scrypto_unit::*;
+
+#[test]
+fn ownership_graph_of_account_contains_its_vaults() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let graph = test_runner.ownership_graph_of(account);
+
+    // Assert
+    let root = &graph.nodes[0];
+    assert_eq!(root.node_id, *account.as_node_id());
+    assert_eq!(root.depth, 0);
+    let vault = graph
+        .nodes
+        .iter()
+        .find(|node| node.type_name == FUNGIBLE_VAULT_BLUEPRINT)
+        .expect("Account should own an XRD vault");
+    assert!(vault.depth > 0);
+    assert!(graph
+        .edges
+        .iter()
+        .any(|edge| edge.child == vault.node_id && edge.parent == *account.as_node_id()));
+}
+
+#[test]
+fn ownership_graph_can_be_rendered_as_dot_and_json() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let encoder = AddressBech32Encoder::for_simulator();
+    let graph = test_runner.ownership_graph_of(account);
+
+    // Act
+    let dot = graph.to_dot(&encoder);
+    let json = graph.to_json(&encoder);
+
+    // Assert
+    let account_address = encoder.encode(account.as_node_id().as_ref()).unwrap();
+    assert!(dot.starts_with("digraph ownership {"));
+    assert!(dot.contains(&account_address));
+    assert!(json.starts_with("{\"nodes\":["));
+    assert!(json.contains(&format!("\"id\":\"{}\"", account_address)));
+}
+
+#[test]
+fn ownership_graph_of_all_global_entities_contains_every_component() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let graph = test_runner.ownership_graph();
+
+    // Assert
+    assert!(graph
+        .nodes
+        .iter()
+        .any(|node| node.node_id == *account.as_node_id() && node.depth == 0));
+    assert!(graph
+        .nodes
+        .iter()
+        .filter(|node| node.depth > 0)
+        .all(|node| !node.node_id.is_global()));
+}
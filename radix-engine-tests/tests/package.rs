@@ -1,4 +1,6 @@
-use radix_engine::blueprints::package::PackageError;
+use radix_engine::blueprints::package::{
+    AuthCoverageFinding, PackageAuthCoverageReportEvent, PackageError, UnprotectedMethod,
+};
 use radix_engine::errors::{
     ApplicationError, RuntimeError, SystemError, SystemModuleError, VmError,
 };
@@ -360,3 +362,72 @@ fn should_not_be_able_to_publish_native_packages_in_scrypto() {
         )
     });
 }
+
+#[test]
+fn publishing_package_reports_methods_without_auth_which_mutate_state_or_move_resources() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (code, definition) = Compile::compile("./tests/blueprints/auth_coverage");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(None, code, definition, BTreeMap::new(), OwnerRole::None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let reports = test_runner.extract_events_of_type::<PackageAuthCoverageReportEvent>(result);
+    assert_eq!(
+        reports,
+        vec![PackageAuthCoverageReportEvent {
+            unprotected_methods: vec![
+                UnprotectedMethod {
+                    blueprint: "ProtectedVault".to_string(),
+                    method: "deposit".to_string(),
+                    findings: vec![
+                        AuthCoverageFinding::MutatesState,
+                        AuthCoverageFinding::AcceptsResources
+                    ],
+                },
+                UnprotectedMethod {
+                    blueprint: "UnprotectedVault".to_string(),
+                    method: "deposit".to_string(),
+                    findings: vec![
+                        AuthCoverageFinding::MutatesState,
+                        AuthCoverageFinding::AcceptsResources
+                    ],
+                },
+                UnprotectedMethod {
+                    blueprint: "UnprotectedVault".to_string(),
+                    method: "withdraw".to_string(),
+                    findings: vec![
+                        AuthCoverageFinding::MutatesState,
+                        AuthCoverageFinding::ReturnsResources
+                    ],
+                },
+            ]
+        }]
+    );
+}
+
+#[test]
+fn publishing_package_with_all_mutating_methods_protected_reports_full_coverage() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (code, definition) = Compile::compile("./tests/blueprints/package_token");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(None, code, definition, BTreeMap::new(), OwnerRole::None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let reports = test_runner.extract_events_of_type::<PackageAuthCoverageReportEvent>(result);
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].is_fully_covered());
+}
@@ -1,4 +1,6 @@
-use radix_engine::errors::{RuntimeError, SystemError};
+use radix_engine::errors::{
+    InvalidFnOutputError, PayloadValidationAgainstSchemaError, RuntimeError, SystemError,
+};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -55,6 +57,83 @@ fn test_invalid_output_fails() {
     )
 }
 
+#[test]
+fn test_invalid_struct_output_reports_every_mismatching_field() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/package_schema");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "SchemaComponent2",
+            "invalid_struct_output",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::SystemError(SystemError::PayloadValidationAgainstSchemaError(
+            PayloadValidationAgainstSchemaError::InvalidFnOutput(InvalidFnOutputError {
+                fn_ident,
+                expected_type,
+                mismatches,
+            }),
+        )) => {
+            fn_ident == "invalid_struct_output"
+                && expected_type == "SchemaOutput"
+                && mismatches.len() == 2
+                && mismatches[0].starts_with("$.amount: ")
+                && mismatches[1].starts_with("$.enabled: ")
+        }
+        _ => false,
+    });
+}
+
+#[test]
+fn test_expect_output_decodes_call_return() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/package_schema");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Simple", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let component_address = commit_result.expect_output::<ComponentAddress>(1);
+    assert_eq!(
+        component_address,
+        commit_result.new_component_addresses()[0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expected output [1] to be alloc::string::String")]
+fn test_expect_output_panics_on_type_mismatch() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/package_schema");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Simple", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success().expect_output::<String>(1);
+}
+
 #[test]
 fn test_input_arg_unit_succeeds() {
     test_arg("unit", to_manifest_value_and_unwrap!(&()), Success)
@@ -0,0 +1,231 @@
+use radix_engine::errors::{
+    ApplicationError, PayloadValidationAgainstSchemaError, RuntimeError, SystemError,
+};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const TARGET_PACKAGE_ADDRESS: [u8; NodeId::LENGTH] = [
+    13, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1,
+];
+
+#[derive(ScryptoSbor)]
+struct ExtraStruct {
+    field_one: String,
+}
+
+#[derive(ScryptoSbor)]
+enum ExtraEnum {
+    EntryOne,
+}
+
+struct PackageStubsTest {
+    test_runner: TestRunner,
+    target_package_address: PackageAddress,
+    target_component_address: ComponentAddress,
+    caller_component_address: ComponentAddress,
+}
+
+impl PackageStubsTest {
+    /// Publishes the external blueprint target and caller, and instantiates both before any
+    /// package is stubbed.
+    fn new() -> Self {
+        let mut test_runner = TestRunner::builder().build();
+        let target_package_address = PackageAddress::new_or_panic(TARGET_PACKAGE_ADDRESS);
+        test_runner
+            .compile_and_publish_at_address("./tests/blueprints/component", target_package_address);
+        let caller_package_address =
+            test_runner.compile_and_publish("./tests/blueprints/external_blueprint_caller");
+
+        let receipt = test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    target_package_address,
+                    "ExternalBlueprintTarget",
+                    "create",
+                    manifest_args!(),
+                )
+                .call_function(
+                    caller_package_address,
+                    "ExternalBlueprintCaller",
+                    "create",
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        );
+        let commit = receipt.expect_commit_success();
+        let target_component_address = commit.new_component_addresses()[0];
+        let caller_component_address = commit.new_component_addresses()[1];
+
+        Self {
+            test_runner,
+            target_package_address,
+            target_component_address,
+            caller_component_address,
+        }
+    }
+
+    fn call_target_function(&mut self) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    self.target_package_address,
+                    "ExternalBlueprintTarget",
+                    "get_value_via_package_call",
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        )
+    }
+
+    fn call_target_via_caller(&mut self) -> TransactionReceipt {
+        self.test_runner.execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    self.caller_component_address,
+                    "run_tests_with_external_component",
+                    manifest_args!(self.target_component_address),
+                )
+                .build(),
+            vec![],
+        )
+    }
+}
+
+#[test]
+fn stubbed_function_returns_canned_output() {
+    // Arrange
+    let mut test = PackageStubsTest::new();
+    test.test_runner
+        .stub_package(test.target_package_address, |_| {
+            Ok(IndexedScryptoValue::from_typed(&"STUBBED".to_string()))
+        });
+
+    // Act
+    let receipt = test.call_target_function();
+
+    // Assert
+    let output: String = receipt.expect_commit_success().output(1);
+    assert_eq!(output, "STUBBED");
+}
+
+#[test]
+fn calls_to_stubbed_package_are_recorded() {
+    // Arrange
+    let mut test = PackageStubsTest::new();
+    test.test_runner
+        .stub_package(test.target_package_address, |call| {
+            match call.function_name.as_str() {
+                "get_value_via_ref" => Ok(IndexedScryptoValue::from_typed(&ExtraStruct {
+                    field_one: "test_1".to_string(),
+                })),
+                "get_value_via_mut_ref" => {
+                    Ok(IndexedScryptoValue::from_typed(&ExtraEnum::EntryOne))
+                }
+                _ => Err(format!("Unexpected call to {}", call.function_name)),
+            }
+        });
+
+    // Act
+    let receipt = test.call_target_via_caller();
+
+    // Assert
+    receipt.expect_commit_success();
+    let calls = test.test_runner.stubbed_calls();
+    assert_eq!(
+        calls
+            .iter()
+            .map(|call| (
+                call.package_address,
+                call.blueprint_name.as_str(),
+                call.function_name.as_str(),
+                call.receiver
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                test.target_package_address,
+                "ExternalBlueprintTarget",
+                "get_value_via_ref",
+                Some(*test.target_component_address.as_node_id())
+            ),
+            (
+                test.target_package_address,
+                "ExternalBlueprintTarget",
+                "get_value_via_mut_ref",
+                Some(*test.target_component_address.as_node_id())
+            ),
+        ]
+    );
+    assert_eq!(calls[0].input, scrypto_decode(&scrypto_args!()).unwrap());
+}
+
+#[test]
+fn stub_error_fails_the_transaction_with_a_panic() {
+    // Arrange
+    let mut test = PackageStubsTest::new();
+    test.test_runner
+        .stub_package(test.target_package_address, |_| {
+            Err("Oracle unavailable".to_string())
+        });
+
+    // Act
+    let receipt = test.call_target_function();
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::Panic(
+            "Oracle unavailable".to_string(),
+        ))
+    });
+}
+
+#[test]
+fn stub_output_is_validated_against_the_package_schema() {
+    // Arrange
+    let mut test = PackageStubsTest::new();
+    test.test_runner
+        .stub_package(test.target_package_address, |_| {
+            Ok(IndexedScryptoValue::from_typed(&1u32))
+        });
+
+    // Act
+    let receipt = test.call_target_function();
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::PayloadValidationAgainstSchemaError(
+                PayloadValidationAgainstSchemaError::InvalidFnOutput(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn unstubbed_package_executes_its_code_again() {
+    // Arrange
+    let mut test = PackageStubsTest::new();
+    test.test_runner
+        .stub_package(test.target_package_address, |_| {
+            Ok(IndexedScryptoValue::from_typed(&"STUBBED".to_string()))
+        });
+    test.call_target_function().expect_commit_success();
+    test.test_runner.clear_stubbed_calls();
+
+    // Act
+    test.test_runner
+        .unstub_package(&test.target_package_address);
+    let receipt = test.call_target_function();
+
+    // Assert
+    let output: String = receipt.expect_commit_success().output(1);
+    assert_eq!(output, "SUCCESS");
+    assert!(test.test_runner.stubbed_calls().is_empty());
+}
@@ -0,0 +1,475 @@
+use radix_engine::blueprints::payment_stream::{CancelEvent, PaymentStreamError, WithdrawEvent};
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::payment_stream::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+struct PaymentStreamTest {
+    test_runner: TestRunner,
+    sender_key: Secp256k1PublicKey,
+    sender: ComponentAddress,
+    recipient_key: Secp256k1PublicKey,
+    recipient: ComponentAddress,
+    resource_address: ResourceAddress,
+}
+
+impl PaymentStreamTest {
+    fn new(divisibility: u8) -> Self {
+        let mut test_runner = TestRunner::builder().build();
+        let (sender_key, _, sender) = test_runner.new_allocated_account();
+        let (recipient_key, _, recipient) = test_runner.new_allocated_account();
+        let resource_address =
+            test_runner.create_fungible_resource(dec!("1000"), divisibility, sender);
+
+        Self {
+            test_runner,
+            sender_key,
+            sender,
+            recipient_key,
+            recipient,
+            resource_address,
+        }
+    }
+
+    fn create_stream(&mut self, amount: Decimal, rate_per_epoch: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.sender, self.resource_address, amount)
+            .take_all_from_worktop(self.resource_address, "bucket")
+            .create_payment_stream(
+                "bucket",
+                rate_per_epoch,
+                rule!(require(NonFungibleGlobalId::from_public_key(
+                    &self.sender_key
+                ))),
+                rule!(require(NonFungibleGlobalId::from_public_key(
+                    &self.recipient_key
+                ))),
+            )
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.sender_key)],
+        )
+    }
+
+    fn new_stream(&mut self, amount: Decimal, rate_per_epoch: Decimal) -> ComponentAddress {
+        self.create_stream(amount, rate_per_epoch)
+            .expect_commit_success()
+            .new_component_addresses()[0]
+    }
+
+    fn withdraw(
+        &mut self,
+        stream: ComponentAddress,
+        signer: Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_payment_stream(stream)
+            .try_deposit_batch_or_abort(self.recipient)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&signer)],
+        )
+    }
+
+    fn cancel(
+        &mut self,
+        stream: ComponentAddress,
+        signer: Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .cancel_payment_stream(stream)
+            .try_deposit_batch_or_abort(self.sender)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&signer)],
+        )
+    }
+
+    fn withdrawable_amount(&mut self, stream: ComponentAddress) -> Decimal {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                stream,
+                PAYMENT_STREAM_GET_WITHDRAWABLE_AMOUNT_IDENT,
+                manifest_args!(),
+            )
+            .build();
+        let receipt = self.test_runner.execute_manifest(manifest, vec![]);
+        receipt.expect_commit_success().output(1)
+    }
+
+    fn info(&mut self, stream: ComponentAddress) -> PaymentStreamInfo {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(stream, PAYMENT_STREAM_GET_INFO_IDENT, manifest_args!())
+            .build();
+        let receipt = self.test_runner.execute_manifest(manifest, vec![]);
+        receipt.expect_commit_success().output(1)
+    }
+
+    fn set_epochs_since_start(&mut self, stream: ComponentAddress, epochs: u64) {
+        let start_epoch = self.info(stream).start_epoch;
+        self.test_runner
+            .set_current_epoch(start_epoch.after(epochs));
+    }
+
+    fn balance_of(&mut self, account: ComponentAddress) -> Decimal {
+        self.test_runner
+            .account_balance(account, self.resource_address)
+            .unwrap()
+    }
+
+    fn events_of<T: ScryptoDescribe + ScryptoDecode>(
+        &self,
+        stream: ComponentAddress,
+        receipt: &TransactionReceipt,
+    ) -> Vec<T> {
+        receipt
+            .expect_commit_success()
+            .application_events
+            .iter()
+            .filter(|(id, _)| match &id.0 {
+                Emitter::Method(node_id, ObjectModuleId::Main) => node_id.eq(stream.as_node_id()),
+                _ => false,
+            })
+            .filter(|(id, _)| self.test_runner.is_event_name_equal::<T>(id))
+            .map(|(_, data)| scrypto_decode::<T>(data).unwrap())
+            .collect()
+    }
+}
+
+fn is_payment_stream_error(error: &RuntimeError, expected: PaymentStreamError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::PaymentStreamError(e)) if e.eq(&expected)
+    )
+}
+
+fn is_auth_unauthorized_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(..)))
+    )
+}
+
+#[test]
+fn rate_per_epoch_accrues_at_each_epoch_boundary_until_total_is_reached() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+
+    for (epochs, expected) in [
+        (0, dec!("0")),
+        (1, dec!("30")),
+        (2, dec!("60")),
+        (3, dec!("90")),
+        (4, dec!("100")),
+        (5, dec!("100")),
+        (1000, dec!("100")),
+    ] {
+        test.set_epochs_since_start(stream, epochs);
+
+        // Act
+        let withdrawable_amount = test.withdrawable_amount(stream);
+
+        // Assert
+        assert_eq!(withdrawable_amount, expected, "after {} epochs", epochs);
+    }
+}
+
+#[test]
+fn stream_which_exactly_divides_total_completes_on_the_last_epoch_boundary() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("25"));
+
+    for (epochs, expected) in [(3, dec!("75")), (4, dec!("100")), (5, dec!("100"))] {
+        test.set_epochs_since_start(stream, epochs);
+
+        // Act
+        let withdrawable_amount = test.withdrawable_amount(stream);
+
+        // Assert
+        assert_eq!(withdrawable_amount, expected, "after {} epochs", epochs);
+    }
+}
+
+#[test]
+fn accrued_amount_is_rounded_down_to_the_divisibility_of_the_resource() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(0);
+    let stream = test.new_stream(dec!("10"), dec!("2.5"));
+
+    for (epochs, expected) in [
+        (1, dec!("2")),
+        (2, dec!("5")),
+        (3, dec!("7")),
+        (4, dec!("10")),
+    ] {
+        test.set_epochs_since_start(stream, epochs);
+
+        // Act
+        let withdrawable_amount = test.withdrawable_amount(stream);
+
+        // Assert
+        assert_eq!(withdrawable_amount, expected, "after {} epochs", epochs);
+    }
+}
+
+#[test]
+fn recipient_can_withdraw_accrued_amount_at_any_time() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let recipient_key = test.recipient_key;
+
+    for (epochs, expected_amount, expected_balance) in [
+        (0, dec!("0"), dec!("0")),
+        (1, dec!("30"), dec!("30")),
+        (1, dec!("0"), dec!("30")),
+        (3, dec!("60"), dec!("90")),
+        (7, dec!("10"), dec!("100")),
+        (8, dec!("0"), dec!("100")),
+    ] {
+        test.set_epochs_since_start(stream, epochs);
+
+        // Act
+        let receipt = test.withdraw(stream, recipient_key);
+
+        // Assert
+        assert_eq!(
+            test.events_of::<WithdrawEvent>(stream, &receipt),
+            vec![WithdrawEvent {
+                amount: expected_amount
+            }]
+        );
+        if expected_balance.is_positive() {
+            assert_eq!(test.balance_of(test.recipient), expected_balance);
+        }
+        assert_eq!(test.withdrawable_amount(stream), dec!("0"));
+    }
+    let info = test.info(stream);
+    assert_eq!(info.withdrawn_amount, dec!("100"));
+    assert_eq!(info.cancelled_at_epoch, None);
+}
+
+#[test]
+fn cancelling_refunds_unaccrued_amount_and_keeps_accrued_amount_withdrawable() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let (sender_key, recipient_key) = (test.sender_key, test.recipient_key);
+    test.set_epochs_since_start(stream, 1);
+    test.withdraw(stream, recipient_key).expect_commit_success();
+    test.set_epochs_since_start(stream, 2);
+
+    // Act
+    let receipt = test.cancel(stream, sender_key);
+
+    // Assert
+    assert_eq!(
+        test.events_of::<CancelEvent>(stream, &receipt),
+        vec![CancelEvent {
+            accrued_amount: dec!("60"),
+            refunded_amount: dec!("40"),
+        }]
+    );
+    assert_eq!(test.balance_of(test.sender), dec!("940"));
+    assert_eq!(test.withdrawable_amount(stream), dec!("30"));
+    let info = test.info(stream);
+    assert_eq!(info.cancelled_at_epoch, Some(info.start_epoch.after(2)));
+
+    // Nothing accrues after the cancellation
+    test.set_epochs_since_start(stream, 10);
+    assert_eq!(test.withdrawable_amount(stream), dec!("30"));
+    test.withdraw(stream, recipient_key).expect_commit_success();
+    assert_eq!(test.balance_of(test.recipient), dec!("60"));
+    assert_eq!(test.withdrawable_amount(stream), dec!("0"));
+}
+
+#[test]
+fn cancelling_in_the_start_epoch_refunds_everything() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let sender_key = test.sender_key;
+
+    // Act
+    let receipt = test.cancel(stream, sender_key);
+
+    // Assert
+    assert_eq!(
+        test.events_of::<CancelEvent>(stream, &receipt),
+        vec![CancelEvent {
+            accrued_amount: dec!("0"),
+            refunded_amount: dec!("100"),
+        }]
+    );
+    assert_eq!(test.balance_of(test.sender), dec!("1000"));
+    test.set_epochs_since_start(stream, 10);
+    assert_eq!(test.withdrawable_amount(stream), dec!("0"));
+}
+
+#[test]
+fn cancelling_after_the_total_has_accrued_refunds_nothing() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let sender_key = test.sender_key;
+    test.set_epochs_since_start(stream, 4);
+
+    // Act
+    let receipt = test.cancel(stream, sender_key);
+
+    // Assert
+    assert_eq!(
+        test.events_of::<CancelEvent>(stream, &receipt),
+        vec![CancelEvent {
+            accrued_amount: dec!("100"),
+            refunded_amount: dec!("0"),
+        }]
+    );
+    assert_eq!(test.balance_of(test.sender), dec!("900"));
+    assert_eq!(test.withdrawable_amount(stream), dec!("100"));
+}
+
+#[test]
+fn stream_cannot_be_cancelled_twice() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let sender_key = test.sender_key;
+    test.set_epochs_since_start(stream, 1);
+    test.cancel(stream, sender_key).expect_commit_success();
+    let cancelled_at_epoch = test.info(stream).cancelled_at_epoch.unwrap();
+    test.set_epochs_since_start(stream, 2);
+
+    // Act
+    let receipt = test.cancel(stream, sender_key);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_payment_stream_error(
+            e,
+            PaymentStreamError::StreamAlreadyCancelled { cancelled_at_epoch },
+        )
+    });
+}
+
+#[test]
+fn only_recipient_can_withdraw() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let sender_key = test.sender_key;
+    test.set_epochs_since_start(stream, 1);
+
+    // Act
+    let receipt = test.withdraw(stream, sender_key);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+fn only_sender_can_cancel() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+    let recipient_key = test.recipient_key;
+
+    // Act
+    let receipt = test.cancel(stream, recipient_key);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+fn stream_info_describes_the_stream() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+    let current_epoch = test.test_runner.get_current_epoch();
+
+    // Act
+    let stream = test.new_stream(dec!("100"), dec!("30"));
+
+    // Assert
+    assert_eq!(
+        test.info(stream),
+        PaymentStreamInfo {
+            resource_address: test.resource_address,
+            rate_per_epoch: dec!("30"),
+            start_epoch: current_epoch,
+            total_amount: dec!("100"),
+            withdrawn_amount: dec!("0"),
+            cancelled_at_epoch: None,
+        }
+    );
+}
+
+#[test]
+fn cannot_create_stream_with_non_positive_rate() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+
+    for rate_per_epoch in [dec!("0"), dec!("-1")] {
+        // Act
+        let receipt = test.create_stream(dec!("100"), rate_per_epoch);
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            is_payment_stream_error(e, PaymentStreamError::InvalidRatePerEpoch(rate_per_epoch))
+        });
+    }
+}
+
+#[test]
+fn cannot_create_empty_stream() {
+    // Arrange
+    let mut test = PaymentStreamTest::new(18);
+
+    // Act
+    let receipt = test.create_stream(dec!("0"), dec!("1"));
+
+    // Assert
+    receipt
+        .expect_specific_failure(|e| is_payment_stream_error(e, PaymentStreamError::EmptyStream));
+}
+
+#[test]
+fn cannot_create_stream_of_non_fungible_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, dec!("1"))
+        .take_all_from_worktop(resource_address, "bucket")
+        .create_payment_stream("bucket", dec!("1"), rule!(allow_all), rule!(allow_all))
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_payment_stream_error(
+            e,
+            PaymentStreamError::NonFungibleResourcesAreNotAccepted { resource_address },
+        )
+    });
+}
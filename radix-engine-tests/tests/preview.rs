@@ -20,6 +20,7 @@ fn test_transaction_preview_cost_estimate() {
         use_free_credit: true,
         assume_all_signature_proofs: false,
         skip_epoch_check: false,
+        assume_all_access_rule_checks_pass: false,
     };
     let (notarized_transaction, preview_intent) = prepare_matching_test_tx_and_preview_intent(
         &mut test_runner,
@@ -62,6 +63,7 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
         use_free_credit: true,
         assume_all_signature_proofs: true,
         skip_epoch_check: false,
+        assume_all_access_rule_checks_pass: false,
     };
 
     // Check method authorization (withdrawal) without a proof in the auth zone
@@ -85,6 +87,39 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+#[test]
+fn test_assume_all_access_rule_checks_pass_flag_method_authorization() {
+    // Arrange
+    // Create an account component that requires a badge for withdrawal
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, badge_holder) = test_runner.new_allocated_account();
+    let badge = test_runner.create_fungible_resource(dec!(1), 0, badge_holder);
+    let account = test_runner.new_account_advanced(OwnerRole::Fixed(rule!(require(badge))));
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Check method authorization (withdrawal) without the badge in the auth zone
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let preview_flags = |assume_all_access_rule_checks_pass| PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: false,
+        skip_epoch_check: false,
+        assume_all_access_rule_checks_pass,
+    };
+
+    // Act
+    let assumed_receipt =
+        test_runner.preview_manifest(manifest.clone(), vec![], 0, preview_flags(true));
+    let receipt = test_runner.preview_manifest(manifest, vec![], 0, preview_flags(false));
+
+    // Assert
+    assumed_receipt.expect_commit_success();
+    receipt.expect_auth_failure();
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut TestRunner,
     network: &NetworkDefinition,
@@ -103,6 +138,9 @@ fn prepare_matching_test_tx_and_preview_intent(
             notary_public_key: notary_priv_key.public_key().into(),
             notary_is_signatory: false,
             tip_percentage: 0,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         })
         .manifest(manifest)
         .sign(&tx_signer_priv_key)
@@ -776,3 +776,98 @@ fn can_not_call_bucket_unlock_non_fungibles_directly() {
         _ => false,
     })
 }
+
+#[test]
+fn can_create_named_proofs_from_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let fungible_resource_address =
+        test_runner.create_fungible_resource(100.into(), DIVISIBILITY_MAXIMUM, account);
+    let non_fungible_resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_named_proof_from_account_of_amount(
+            account,
+            fungible_resource_address,
+            dec!(3),
+            "fungible_proof",
+        )
+        .create_named_proof_from_account_of_non_fungibles(
+            account,
+            non_fungible_resource_address,
+            &BTreeSet::from([NonFungibleLocalId::integer(1)]),
+            "non_fungible_proof",
+        )
+        .with_name_lookup(|builder, lookup| {
+            builder
+                .call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_amount",
+                    manifest_args!(
+                        lookup.proof("fungible_proof"),
+                        dec!(3),
+                        fungible_resource_address
+                    ),
+                )
+                .call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_ids",
+                    manifest_args!(
+                        lookup.proof("non_fungible_proof"),
+                        BTreeSet::from([NonFungibleLocalId::integer(1)]),
+                        non_fungible_resource_address
+                    ),
+                )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_create_proof_of_single_non_fungible_from_account() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let non_fungible_global_id =
+        NonFungibleGlobalId::new(resource_address, NonFungibleLocalId::integer(2));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungible(account, &non_fungible_global_id)
+        .create_proof_from_auth_zone_of_all(resource_address, "proof")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                package_address,
+                "Receiver",
+                "assert_ids",
+                manifest_args!(
+                    lookup.proof("proof"),
+                    BTreeSet::from([NonFungibleLocalId::integer(2)]),
+                    resource_address
+                ),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
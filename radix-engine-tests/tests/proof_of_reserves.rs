@@ -0,0 +1,213 @@
+use radix_engine::blueprints::resource::FungibleResourceManagerError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn enable_reserve_tracking(
+    test_runner: &mut TestRunner,
+    resource_address: ResourceAddress,
+    initial_proofs: Vec<NonFungibleGlobalId>,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(
+                resource_address,
+                FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_IDENT,
+                manifest_args!(),
+            )
+            .build(),
+        initial_proofs,
+    )
+}
+
+fn proof_of_reserves(
+    test_runner: &mut TestRunner,
+    resource_address: ResourceAddress,
+    top_holders: u32,
+) -> TransactionReceipt {
+    test_runner.execute_manifest_ignoring_fee(
+        ManifestBuilder::new()
+            .call_method(
+                resource_address,
+                FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_IDENT,
+                manifest_args!(top_holders),
+            )
+            .build(),
+        vec![],
+    )
+}
+
+#[test]
+fn proof_of_reserves_aggregates_vault_balances() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::Fixed(rule!(allow_all)),
+        None,
+        18,
+        account,
+    );
+    enable_reserve_tracking(&mut test_runner, resource_address, vec![]).expect_commit_success();
+
+    // Act
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .mint_fungible(resource_address, dec!(100))
+                .try_deposit_batch_or_abort(account)
+                .mint_fungible(resource_address, dec!(30))
+                .try_deposit_batch_or_abort(other_account)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .withdraw_from_account(account, resource_address, dec!(10))
+                .try_deposit_batch_or_abort(other_account)
+                .withdraw_from_account(account, resource_address, dec!(5))
+                .burn_all_from_worktop(resource_address)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+    let receipt = proof_of_reserves(&mut test_runner, resource_address, 1);
+
+    // Assert
+    let reserves: ProofOfReserves = receipt.expect_commit_success().expect_output(0);
+    assert_eq!(reserves.total_amount, dec!(125));
+    assert_eq!(reserves.holder_count, 2);
+    assert_eq!(reserves.top_holders.len(), 1);
+    assert_eq!(reserves.top_holders[0].amount, dec!(85));
+}
+
+#[test]
+fn emptied_vaults_are_removed_from_reserves() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::Fixed(rule!(allow_all)),
+        None,
+        18,
+        account,
+    );
+    enable_reserve_tracking(&mut test_runner, resource_address, vec![]).expect_commit_success();
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .mint_fungible(resource_address, dec!(50))
+                .try_deposit_batch_or_abort(account)
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    test_runner
+        .execute_manifest_ignoring_fee(
+            ManifestBuilder::new()
+                .withdraw_from_account(account, resource_address, dec!(50))
+                .burn_all_from_worktop(resource_address)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+    let receipt = proof_of_reserves(&mut test_runner, resource_address, 10);
+
+    // Assert
+    let reserves: ProofOfReserves = receipt.expect_commit_success().expect_output(0);
+    assert_eq!(reserves.total_amount, Decimal::zero());
+    assert_eq!(reserves.holder_count, 0);
+    assert!(reserves.top_holders.is_empty());
+}
+
+#[test]
+fn reserve_tracking_cannot_be_enabled_with_existing_supply() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::Fixed(rule!(allow_all)),
+        Some(dec!(100)),
+        18,
+        account,
+    );
+
+    // Act
+    let receipt = enable_reserve_tracking(&mut test_runner, resource_address, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::ReserveTrackingRequiresZeroTrackedSupply
+            ))
+        )
+    });
+}
+
+#[test]
+fn reserve_tracking_can_only_be_enabled_by_owner() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::Fixed(rule!(require(owner_badge.clone()))),
+        None,
+        18,
+        account,
+    );
+
+    // Act
+    let unauthorized_receipt = enable_reserve_tracking(&mut test_runner, resource_address, vec![]);
+    let owner_receipt =
+        enable_reserve_tracking(&mut test_runner, resource_address, vec![owner_badge]);
+
+    // Assert
+    unauthorized_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+    owner_receipt.expect_commit_success();
+}
+
+#[test]
+fn proof_of_reserves_requires_reserve_tracking() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_fungible_resource(
+        OwnerRole::Fixed(rule!(allow_all)),
+        Some(dec!(100)),
+        18,
+        account,
+    );
+
+    // Act
+    let receipt = proof_of_reserves(&mut test_runner, resource_address, 10);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::ReserveTrackingNotEnabled
+            ))
+        )
+    });
+}
@@ -1,9 +1,16 @@
-use radix_engine::errors::{KernelError, RejectionError, RuntimeError, SystemModuleError};
+use radix_engine::blueprints::resource::{RecallAuditEvent, RecalledResource, VaultError};
+use radix_engine::errors::{
+    ApplicationError, KernelError, RejectionError, RuntimeError, SystemModuleError,
+    SystemUpstreamError,
+};
 use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::system::system_modules::execution_trace::{ResourceRecall, ResourceSpecifier};
 use radix_engine::types::*;
+use radix_engine_interface::blueprints::transaction_processor::TRANSACTION_PROCESSOR_BLUEPRINT;
 use scrypto::prelude::FromPublicKey;
 use scrypto_unit::*;
 use std::ops::Sub;
+use transaction::model::PreviewFlags;
 use transaction::prelude::*;
 
 #[test]
@@ -173,3 +180,203 @@ fn test_recall_on_received_direct_access_reference() {
     );
     receipt.expect_commit_success();
 }
+
+#[test]
+fn can_recall_with_direct_vault_method_call() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_direct_vault_method(
+            InternalAddress::new_or_panic(vault_id.into()),
+            VAULT_RECALL_IDENT,
+            manifest_args!(Decimal::one()),
+        )
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.account_balance(other_account, recallable_token),
+        Some(Decimal::one())
+    );
+}
+
+#[test]
+fn cannot_call_non_direct_access_vault_method_with_direct_vault_method_call() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_direct_vault_method(
+            InternalAddress::new_or_panic(vault_id.into()),
+            VAULT_TAKE_IDENT,
+            manifest_args!(Decimal::one()),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::ReceiverNotMatch(method))
+                if method.eq(VAULT_TAKE_IDENT)
+        )
+    });
+}
+
+#[test]
+fn recall_with_reason_emits_audit_event() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_with_reason(
+            InternalAddress::new_or_panic(vault_id.into()),
+            2,
+            "Court order 42",
+        )
+        .try_deposit_batch_or_abort(other_account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let audit_events = test_runner.extract_events_of_type::<RecallAuditEvent>(result);
+    assert_eq!(
+        audit_events,
+        vec![RecallAuditEvent {
+            vault_id: InternalAddress::new_or_panic(vault_id.into()),
+            resource_address: recallable_token,
+            recalled: RecalledResource::Amount(dec!("2")),
+            recaller: Some(GlobalCaller::PackageBlueprint(BlueprintId::new(
+                &TRANSACTION_PROCESSOR_PACKAGE,
+                TRANSACTION_PROCESSOR_BLUEPRINT
+            ))),
+            reason: Some("Court order 42".to_string()),
+        }]
+    );
+    assert_eq!(
+        test_runner.account_balance(other_account, recallable_token),
+        Some(dec!("2"))
+    );
+}
+
+#[test]
+fn recall_non_fungibles_with_reason_emits_audit_event() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource_with_access_rules(
+        NonFungibleResourceRoles {
+            recall_roles: recall_roles! {
+                recaller => rule!(allow_all);
+                recaller_updater => rule!(deny_all);
+            },
+            ..Default::default()
+        },
+        account,
+    );
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+    let ids = btreeset!(NonFungibleLocalId::integer(1));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_non_fungibles_with_reason(
+            InternalAddress::new_or_panic(vault_id.into()),
+            &ids,
+            "Stolen",
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let audit_events = test_runner.extract_events_of_type::<RecallAuditEvent>(result);
+    assert_eq!(audit_events.len(), 1);
+    assert_eq!(audit_events[0].recalled, RecalledResource::Ids(ids));
+    assert_eq!(audit_events[0].reason, Some("Stolen".to_string()));
+}
+
+#[test]
+fn recall_with_too_long_reason_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_with_reason(
+            InternalAddress::new_or_panic(vault_id.into()),
+            1,
+            "a".repeat(MAX_RECALL_REASON_LENGTH + 1),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::RecallReasonTooLong { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn recall_is_recorded_in_execution_trace() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let recallable_token = test_runner.create_recallable_token(account);
+    let vault_id = test_runner.get_component_vaults(account, recallable_token)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall(InternalAddress::new_or_panic(vault_id.into()), 3)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.preview_manifest(manifest, vec![], 0, PreviewFlags::default());
+
+    // Assert
+    let recalls = &receipt.expect_commit_success().execution_trace.recalls;
+    assert_eq!(recalls.len(), 1);
+    assert_eq!(
+        recalls.get(&1),
+        Some(&vec![ResourceRecall {
+            vault_id,
+            recaller: None,
+            resource: ResourceSpecifier::Amount(recallable_token, dec!("3")),
+        }])
+    );
+}
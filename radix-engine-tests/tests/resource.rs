@@ -2,6 +2,7 @@ use radix_engine::blueprints::resource::FungibleResourceManagerError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::metadata::MetadataValue;
 use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_interface::{metadata, metadata_init};
@@ -451,3 +452,147 @@ fn test_non_fungible_resource_take_advanced() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_badge_preset_is_not_divisible() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ResourcePresets",
+            "create_badge",
+            manifest_args!(),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_soulbound_badge_preset_cannot_be_withdrawn() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ResourcePresets",
+            "create_soulbound_badge",
+            manifest_args!(),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let badge = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, badge, 1)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn test_share_token_preset_can_only_be_minted_by_its_pool() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ResourcePresets",
+            "create_share_token",
+            manifest_args!(FAUCET),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let share_token = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(share_token, 1)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_eq!(
+        test_runner.get_metadata(share_token.into(), "pool"),
+        Some(MetadataValue::GlobalAddress(FAUCET.into()))
+    );
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn test_stablecoin_preset_initial_supply_is_capped_by_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let mut create_stablecoin = |initial_supply: Decimal| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "ResourcePresets",
+                "create_stablecoin",
+                manifest_args!(dec!(1000), initial_supply),
+            )
+            .try_deposit_batch_or_abort(account)
+            .build();
+        test_runner.execute_manifest(manifest, vec![])
+    };
+
+    // Act
+    let at_max_supply = create_stablecoin(dec!(1000));
+    let over_max_supply = create_stablecoin(dec!("1000.1"));
+
+    // Assert
+    let stablecoin = at_max_supply
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    assert_eq!(
+        test_runner.get_metadata(stablecoin.into(), "max_supply"),
+        Some(MetadataValue::Decimal(dec!(1000)))
+    );
+    over_max_supply.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::Panic(..))
+        )
+    });
+}
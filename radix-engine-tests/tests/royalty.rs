@@ -1,6 +1,7 @@
 use radix_engine::blueprints::package::PackageError;
-use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::system::node_modules::royalty::ComponentRoyaltyError;
+use radix_engine::system::system_modules::costing::{CostingError, RoyaltyRecipient};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -57,6 +58,43 @@ fn test_component_royalty() {
     );
 }
 
+#[test]
+fn test_royalty_is_attributed_to_the_instruction_charged() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/royalty");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_enabled",
+                manifest_args!(),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let component_address: ComponentAddress = receipt.expect_commit(true).output(1);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let fee_summary = &receipt.expect_commit(true).fee_summary;
+    let breakdown = &fee_summary.instruction_cost_breakdown;
+    assert_eq!(breakdown[&0].royalty_cost_xrd, Decimal::ZERO);
+    assert_eq!(breakdown[&1].royalty_cost_xrd, dec!("3"));
+    assert_eq!(fee_summary.most_costly_instruction().unwrap().0, 1);
+}
+
 #[test]
 fn test_component_royalty_in_usd() {
     // Basic setup
@@ -146,6 +184,54 @@ fn test_package_royalty() {
     assert_eq!(component_royalty, dec!(1));
 }
 
+#[test]
+fn test_royalty_breakdown_per_recipient() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        component_address,
+        _owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    let fee_summary = &receipt.expect_commit(true).fee_summary;
+    assert_eq!(fee_summary.package_royalty_cost(package_address), dec!("2"));
+    assert_eq!(
+        fee_summary.component_royalty_cost(component_address),
+        dec!(1)
+    );
+    assert_eq!(fee_summary.royalty_cost_breakdown.len(), 2);
+    assert_eq!(
+        fee_summary
+            .royalty_cost_breakdown
+            .values()
+            .map(|(_, amount)| *amount)
+            .sum::<Decimal>(),
+        fee_summary.total_royalty_cost_xrd
+    );
+    assert_eq!(
+        fee_summary.package_royalty_cost(FAUCET_PACKAGE),
+        Decimal::zero()
+    );
+    assert_eq!(
+        test_runner.inspect_royalty(&RoyaltyRecipient::Package(package_address)),
+        dec!("2")
+    );
+    assert_eq!(
+        test_runner.inspect_royalty(&RoyaltyRecipient::Component(component_address)),
+        dec!(1)
+    );
+}
+
 #[test]
 fn test_royalty_accumulation_when_success() {
     let (
@@ -458,6 +544,55 @@ fn cannot_set_royalty_after_locking() {
     });
 }
 
+#[test]
+fn cannot_call_method_if_royalty_exceeds_per_call_maximum() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+    test_runner.set_protocol_parameters(
+        ProtocolParameters::default().with_max_per_call_royalty_in_xrd(dec!("2.5")),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemModuleError(
+            SystemModuleError::CostingError(CostingError::RoyaltyExceedsPerCallMaximum {
+                max: dec!("2.5"),
+                actual: dec!("3"),
+            }),
+        ))
+    });
+}
+
+#[test]
+fn user_methods_named_lock_fee_are_charged_royalties() {
+    // Arrange
+    let (mut test_runner, account, public_key, _, component_address, _) =
+        set_up_package_and_component();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "lock_fee", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit(true);
+    assert_eq!(commit_result.fee_summary.total_royalty_cost_xrd, dec!("3"));
+}
+
 fn set_up_package_and_component() -> (
     TestRunner,
     ComponentAddress,
@@ -515,3 +650,81 @@ fn set_up_package_and_component() -> (
         owner_badge_resource,
     )
 }
+
+#[test]
+fn test_royalty_of_component_created_in_same_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/royalty");
+    let account_pre_balance = test_runner.account_balance(account, XRD).unwrap();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_and_call_paid_method",
+                manifest_args!(),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let component_address: ComponentAddress = commit_result.output(1);
+    assert_eq!(commit_result.fee_summary.total_royalty_cost_xrd, dec!("3"));
+    assert_eq!(
+        test_runner.inspect_component_royalty(component_address),
+        dec!("1")
+    );
+    assert_eq!(
+        test_runner.inspect_package_royalty(package_address),
+        Some(dec!("2"))
+    );
+    let account_post_balance = test_runner.account_balance(account, XRD).unwrap();
+    assert_eq!(
+        account_pre_balance - account_post_balance,
+        commit_result.fee_summary.total_cost()
+    );
+}
+
+#[test]
+fn test_royalty_of_component_created_in_failed_transaction_is_not_settled() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/royalty");
+    let account_pre_balance = test_runner.account_balance(account, XRD).unwrap();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_and_call_paid_method",
+                manifest_args!(),
+            )
+            .assert_worktop_contains(XRD, 1)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_failure();
+    assert_eq!(commit_result.fee_summary.total_royalty_cost_xrd, dec!("0"));
+    assert_eq!(
+        test_runner.inspect_package_royalty(package_address),
+        Some(dec!("0"))
+    );
+    let account_post_balance = test_runner.account_balance(account, XRD).unwrap();
+    assert_eq!(
+        account_pre_balance - account_post_balance,
+        commit_result.fee_summary.total_cost()
+    );
+}
@@ -0,0 +1,128 @@
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::{BlueprintDefinition, TypePointer};
+use radix_engine_store_interface::interface::DatabaseUpdate;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn blueprint_definition(
+    test_runner: &TestRunner,
+    package_address: PackageAddress,
+    blueprint_name: &str,
+) -> BlueprintDefinition {
+    test_runner
+        .get_package_blueprint_definitions(&package_address)
+        .into_iter()
+        .find(|(key, _)| key.blueprint == blueprint_name)
+        .unwrap()
+        .1
+}
+
+fn schema_hash_of(type_pointer: &TypePointer) -> Hash {
+    match type_pointer {
+        TypePointer::Package(schema_hash, _) => *schema_hash,
+        TypePointer::Instance(..) => panic!("Not a package type pointer"),
+    }
+}
+
+#[test]
+fn receipt_contains_schema_hash_of_each_event() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/events");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ScryptoEvents",
+            "emit_registered_event",
+            manifest_args!(12u64),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(
+        commit.application_event_schema_hashes.len(),
+        commit.application_events.len()
+    );
+    for ((EventTypeIdentifier(_, type_pointer), _), schema_hash) in commit
+        .application_events
+        .iter()
+        .zip(&commit.application_event_schema_hashes)
+    {
+        assert_eq!(schema_hash_of(type_pointer), *schema_hash);
+    }
+
+    let definition = blueprint_definition(&test_runner, package_address, "ScryptoEvents");
+    let expected_schema_hash =
+        schema_hash_of(definition.interface.events.get("RegisteredEvent").unwrap());
+    assert_eq!(
+        commit.application_event_schema_hashes.last(),
+        Some(&expected_schema_hash)
+    );
+}
+
+#[test]
+fn receipt_contains_schema_hash_of_component_state_at_its_global_address() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "ExternalBlueprintTarget",
+            "create",
+            manifest_args!(),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let component_address = commit.new_component_addresses()[0];
+    let definition = blueprint_definition(&test_runner, package_address, "ExternalBlueprintTarget");
+    let (fields_offset, fields) = definition.interface.state.fields.unwrap();
+    let state_location = (
+        *component_address.as_node_id(),
+        MAIN_BASE_PARTITION.at_offset(fields_offset).unwrap(),
+        SubstateKey::Field(0),
+    );
+    assert_eq!(
+        commit.state_write_schema_hashes.get(&state_location),
+        Some(&schema_hash_of(&fields[0].field))
+    );
+}
+
+#[test]
+fn receipt_contains_schema_hashes_of_committed_substates_only() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert!(!commit.state_write_schema_hashes.is_empty());
+    // The buckets moved through the worktop were written to, but dropped by the end of it
+    for (node_id, partition_num, substate_key) in commit.state_write_schema_hashes.keys() {
+        let update = commit
+            .state_updates
+            .system_updates
+            .get(&(*node_id, *partition_num))
+            .and_then(|updates| updates.get(substate_key));
+        assert!(matches!(update, Some(DatabaseUpdate::Set(..))));
+    }
+}
@@ -0,0 +1,97 @@
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
+use radix_engine_queries::query::*;
+use scrypto_unit::*;
+
+#[test]
+fn query_of_accounts_holding_xrd_returns_expanded_vaults() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let query = StateQuery::new()
+        .of_blueprint(ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT)
+        .holding_resource(XRD)
+        .with_vaults()
+        .with_key_value_stores();
+
+    // Act
+    let result = test_runner.query_state(&query);
+
+    // Assert
+    let entity = result
+        .entities
+        .iter()
+        .find(|entity| entity.node_id == *account.as_node_id())
+        .expect("Account should be matched");
+    assert_eq!(
+        entity.blueprint_id,
+        Some(BlueprintId::new(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT))
+    );
+    let balance = test_runner.account_balance(account, XRD).unwrap();
+    assert!(entity
+        .vaults
+        .iter()
+        .any(|vault| vault.resource_address == XRD
+            && vault.contents == VaultContents::Fungible { amount: balance }));
+    assert!(!entity.key_value_stores.is_empty());
+    assert!(result.entities.iter().all(|entity| entity
+        .vaults
+        .iter()
+        .any(|vault| vault.resource_address == XRD)));
+}
+
+#[test]
+fn query_can_be_paginated() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    for _ in 0..3 {
+        test_runner.new_allocated_account();
+    }
+    let query = StateQuery::new().of_blueprint(ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT);
+    let all_accounts = test_runner.query_state(&query).entities;
+
+    // Act
+    let first_page = test_runner.query_state(&query.clone().page(None, 2));
+    let second_page = test_runner.query_state(&query.page(first_page.next_cursor, 2));
+
+    // Assert
+    assert_eq!(first_page.entities.len(), 2);
+    assert_eq!(first_page.next_cursor, Some(first_page.entities[1].node_id));
+    let paged_accounts: Vec<NodeId> = first_page
+        .entities
+        .iter()
+        .chain(second_page.entities.iter())
+        .map(|entity| entity.node_id)
+        .collect();
+    assert_eq!(
+        paged_accounts,
+        all_accounts
+            .iter()
+            .take(paged_accounts.len())
+            .map(|entity| entity.node_id)
+            .collect::<Vec<_>>()
+    );
+    assert!(paged_accounts.windows(2).all(|ids| ids[0] < ids[1]));
+}
+
+#[test]
+fn query_can_filter_on_entity_types() {
+    // Arrange
+    let test_runner = TestRunner::builder().build();
+    let query = StateQuery::new().of_entity_types(vec![EntityType::GlobalFungibleResourceManager]);
+
+    // Act
+    let result = test_runner.query_state(&query);
+
+    // Assert
+    assert!(result
+        .entities
+        .iter()
+        .any(|entity| entity.node_id == *XRD.as_node_id()));
+    assert!(result
+        .entities
+        .iter()
+        .all(|entity| entity.node_id.entity_type()
+            == Some(EntityType::GlobalFungibleResourceManager)));
+    assert!(result.next_cursor.is_none());
+}
@@ -0,0 +1,178 @@
+use radix_engine::errors::{
+    CallFrameError, KernelError, RuntimeError, StaticCallError, SystemError,
+};
+use radix_engine::kernel::call_frame::OpenSubstateError;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn publish_and_instantiate(test_runner: &mut TestRunner) -> (ComponentAddress, ComponentAddress) {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/static_call");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "PriceOracle",
+                "new",
+                manifest_args!(dec!(10)),
+            )
+            .build(),
+        vec![],
+    );
+    let oracle = receipt.expect_commit_success().new_component_addresses()[0];
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "Market",
+                "new",
+                manifest_args!(oracle, dec!(2)),
+            )
+            .build(),
+        vec![],
+    );
+    let market = receipt.expect_commit_success().new_component_addresses()[0];
+
+    (oracle, market)
+}
+
+fn call_market(
+    test_runner: &mut TestRunner,
+    market: ComponentAddress,
+    method_name: &str,
+    args: ManifestArgs,
+) -> TransactionReceipt {
+    test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(market, method_name, args)
+            .build(),
+        vec![],
+    )
+}
+
+fn is_static_call_error(e: &RuntimeError, error: StaticCallError) -> bool {
+    matches!(
+        e,
+        RuntimeError::SystemError(SystemError::StaticCallError(e)) if *e == error
+    )
+}
+
+#[test]
+fn static_call_can_read_state_of_another_component() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(&mut test_runner, market, "update_price", manifest_args!());
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(commit_result.output::<Decimal>(1), dec!(10));
+}
+
+#[test]
+fn static_call_can_query_back_a_caller_which_holds_a_write_lock() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(
+        &mut test_runner,
+        market,
+        "update_local_price",
+        manifest_args!(),
+    );
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(commit_result.output::<Decimal>(1), dec!(20));
+}
+
+#[test]
+fn non_static_call_cannot_query_back_a_caller_which_holds_a_write_lock() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(
+        &mut test_runner,
+        market,
+        "update_local_price_with_call",
+        manifest_args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::CallFrameError(
+                CallFrameError::OpenSubstateError(OpenSubstateError::TrackError(_))
+            ))
+        )
+    });
+}
+
+#[test]
+fn static_call_cannot_make_a_non_static_call() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(
+        &mut test_runner,
+        market,
+        "update_local_price_with_nested_call",
+        manifest_args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_static_call_error(e, StaticCallError::NonStaticCallNotAllowed)
+    });
+}
+
+#[test]
+fn static_call_cannot_write_state() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(
+        &mut test_runner,
+        market,
+        "set_oracle_price",
+        manifest_args!(dec!(5)),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| is_static_call_error(e, StaticCallError::WriteNotAllowed));
+}
+
+#[test]
+fn static_call_cannot_move_buckets() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, market) = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let receipt = call_market(
+        &mut test_runner,
+        market,
+        "send_bucket_to_oracle",
+        manifest_args!(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_static_call_error(e, StaticCallError::OwnedNodeMoveNotAllowed)
+    });
+}
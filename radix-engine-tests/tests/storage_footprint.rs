@@ -0,0 +1,95 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn instantiate_kv_store_component(test_runner: &mut TestRunner) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Basic", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn insert(test_runner: &mut TestRunner, component: ComponentAddress, key: &str, value: String) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component, "insert", manifest_args!(key.to_string(), value))
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+}
+
+#[test]
+fn footprint_of_account_includes_its_vaults() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let footprint = test_runner.entity_footprint(account);
+
+    // Assert
+    assert_eq!(footprint.node_id, *account.as_node_id());
+    assert_eq!(footprint.node_ids[0], *account.as_node_id());
+    assert!(footprint.node_ids.len() > 1);
+    assert!(footprint
+        .largest_substates
+        .iter()
+        .any(|substate| substate.node_id != *account.as_node_id()));
+    assert!(footprint.substate_count >= footprint.largest_substates.len());
+}
+
+#[test]
+fn footprint_grows_with_key_value_store_entries() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component = instantiate_kv_store_component(&mut test_runner);
+    let before = test_runner.entity_footprint(component);
+
+    // Act
+    insert(&mut test_runner, component, "small", "x".to_string());
+    insert(&mut test_runner, component, "large", "x".repeat(10_000));
+    let after = test_runner.entity_footprint(component);
+
+    // Assert
+    assert!(after.substate_count >= before.substate_count + 2);
+    assert!(after.total_bytes > before.total_bytes + 10_000);
+    let largest = &after.largest_substates[0];
+    assert!(largest.size > 10_000);
+    assert_ne!(largest.node_id, *component.as_node_id());
+    assert!(after
+        .largest_substates
+        .windows(2)
+        .all(|pair| pair[0].size >= pair[1].size));
+}
+
+#[test]
+fn footprint_assertion_passes_within_limits() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component = instantiate_kv_store_component(&mut test_runner);
+    insert(&mut test_runner, component, "key", "value".to_string());
+    let footprint = test_runner.entity_footprint(component);
+
+    // Act & Assert
+    test_runner.assert_footprint_at_most(
+        component,
+        footprint.substate_count,
+        footprint.total_bytes,
+    );
+}
+
+#[test]
+#[should_panic]
+fn footprint_assertion_fails_beyond_limits() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component = instantiate_kv_store_component(&mut test_runner);
+    insert(&mut test_runner, component, "key", "x".repeat(10_000));
+
+    // Act & Assert
+    test_runner.assert_footprint_at_most(component, usize::MAX, 10_000);
+}
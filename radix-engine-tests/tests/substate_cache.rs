@@ -0,0 +1,79 @@
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn deposit_from_faucet(
+    test_runner: &mut TestRunner,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn repeated_transactions_read_substates_from_cache() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_substate_cache(1000).build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    deposit_from_faucet(&mut test_runner, account).expect_commit_success();
+    let metrics_before = test_runner.substate_cache_metrics().unwrap();
+
+    // Act
+    deposit_from_faucet(&mut test_runner, account).expect_commit_success();
+
+    // Assert
+    let metrics_after = test_runner.substate_cache_metrics().unwrap();
+    assert!(metrics_after.hits > metrics_before.hits);
+}
+
+#[test]
+fn substate_cache_does_not_change_fees() {
+    // Arrange
+    let mut cached_test_runner = TestRunner::builder().with_substate_cache(1000).build();
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, cached_account) = cached_test_runner.new_allocated_account();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    for _ in 0..3 {
+        // Act
+        let cached_receipt = deposit_from_faucet(&mut cached_test_runner, cached_account);
+        let receipt = deposit_from_faucet(&mut test_runner, account);
+
+        // Assert
+        let cached_fee_summary = &cached_receipt.expect_commit_success().fee_summary;
+        let fee_summary = &receipt.expect_commit_success().fee_summary;
+        assert_eq!(
+            cached_fee_summary.execution_cost_breakdown,
+            fee_summary.execution_cost_breakdown
+        );
+        assert_eq!(
+            cached_fee_summary.total_execution_cost_xrd,
+            fee_summary.total_execution_cost_xrd
+        );
+    }
+}
+
+#[test]
+fn committed_substates_are_not_read_from_cache() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_substate_cache(10).build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let mut balances = vec![test_runner.account_balance(account, XRD).unwrap()];
+
+    // Act
+    for _ in 0..3 {
+        deposit_from_faucet(&mut test_runner, account).expect_commit_success();
+        balances.push(test_runner.account_balance(account, XRD).unwrap());
+    }
+
+    // Assert
+    for window in balances.windows(2) {
+        assert!(window[1] > window[0]);
+    }
+    let metrics = test_runner.substate_cache_metrics().unwrap();
+    assert!(metrics.evictions > 0);
+}
@@ -0,0 +1,61 @@
+use radix_engine::track::PrefetchPolicy;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn deposit_from_faucet(
+    test_runner: &mut TestRunner,
+    account: ComponentAddress,
+    prefetch_policy: PrefetchPolicy,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction().with_prefetch_policy(prefetch_policy),
+    )
+}
+
+#[test]
+fn prefetching_does_not_change_outcome_or_fees() {
+    // Arrange
+    let mut prefetching_test_runner = TestRunner::builder().build();
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, prefetching_account) = prefetching_test_runner.new_allocated_account();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    for _ in 0..2 {
+        // Act
+        let prefetching_receipt = deposit_from_faucet(
+            &mut prefetching_test_runner,
+            prefetching_account,
+            PrefetchPolicy::UpTo(64),
+        );
+        let receipt = deposit_from_faucet(&mut test_runner, account, PrefetchPolicy::Disabled);
+
+        // Assert
+        let prefetching_commit = prefetching_receipt.expect_commit_success();
+        let commit = receipt.expect_commit_success();
+        assert_eq!(
+            prefetching_commit.fee_summary.execution_cost_breakdown,
+            commit.fee_summary.execution_cost_breakdown
+        );
+        assert_eq!(
+            prefetching_commit.fee_summary.total_execution_cost_xrd,
+            commit.fee_summary.total_execution_cost_xrd
+        );
+    }
+    assert_eq!(
+        prefetching_test_runner.account_balance(prefetching_account, XRD),
+        test_runner.account_balance(account, XRD)
+    );
+}
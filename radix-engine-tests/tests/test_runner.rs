@@ -0,0 +1,255 @@
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::EpochChangeCondition;
+use scrypto_unit::*;
+use transaction::prelude::*;
+use utils::ContextualDisplay;
+
+#[test]
+fn test_runners_with_the_same_seed_create_the_same_accounts() {
+    // Arrange
+    let mut test_runner1 = TestRunner::builder().with_seed(42).build();
+    let mut test_runner2 = TestRunner::builder().with_seed(42).build();
+
+    // Act
+    let (public_key1, _, account1) = test_runner1.new_allocated_account();
+    let (public_key2, _, account2) = test_runner2.new_allocated_account();
+
+    // Assert
+    assert_eq!(public_key1, public_key2);
+    assert_eq!(account1, account2);
+    assert_eq!(
+        test_runner1.next_transaction_nonce(),
+        test_runner2.next_transaction_nonce()
+    );
+    assert_eq!(test_runner1.seed(), Some(42));
+}
+
+#[test]
+fn test_runners_with_different_seeds_create_different_key_pairs() {
+    // Arrange
+    let mut test_runner1 = TestRunner::builder().with_seed(1).build();
+    let mut test_runner2 = TestRunner::builder().with_seed(2).build();
+
+    // Act
+    let (public_key1, _) = test_runner1.new_key_pair();
+    let (public_key2, _) = test_runner2.new_key_pair();
+
+    // Assert
+    assert_ne!(public_key1, public_key2);
+}
+
+#[test]
+fn restoring_a_snapshot_replays_seeded_choices() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().with_seed(7).build();
+    let snapshot = test_runner.create_snapshot();
+    let (public_key1, _, account1) = test_runner.new_virtual_account();
+
+    // Act
+    test_runner.restore_snapshot(snapshot);
+    let (public_key2, _, account2) = test_runner.new_virtual_account();
+
+    // Assert
+    assert_eq!(public_key1, public_key2);
+    assert_eq!(account1, account2);
+}
+
+#[test]
+fn unseeded_test_runner_has_no_seed() {
+    // Arrange
+    let test_runner = TestRunner::builder().build();
+
+    // Assert
+    assert_eq!(test_runner.seed(), None);
+}
+
+#[test]
+fn displayed_receipt_renders_balance_changes_with_resource_divisibility() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 2, account);
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, resource_address, dec!("1.5"))
+            .try_deposit_batch_or_abort(other_account)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    // Act
+    let displayed = test_runner.display_receipt(&receipt);
+
+    // Assert
+    let encoder = AddressBech32Encoder::for_simulator();
+    assert_eq!(
+        test_runner.get_resource_divisibility(&resource_address),
+        Some(2)
+    );
+    assert!(displayed.contains(&format!(
+        "Entity: {}\n   ResAddr: {}\n   Change: -1.50",
+        account.display(&encoder),
+        resource_address.display(&encoder)
+    )));
+    assert!(displayed.contains(&format!(
+        "Entity: {}\n   ResAddr: {}\n   Change: 1.50",
+        other_account.display(&encoder),
+        resource_address.display(&encoder)
+    )));
+}
+
+fn transfer_xrd_manifest(from: ComponentAddress, to: ComponentAddress) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee(from, 50)
+        .withdraw_from_account(from, XRD, 1)
+        .try_deposit_batch_or_abort(to)
+        .build()
+}
+
+#[test]
+fn changing_cost_unit_price_applies_to_subsequent_transactions() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+    let receipt = test_runner.execute_manifest(
+        transfer_xrd_manifest(account, other_account),
+        proofs.clone(),
+    );
+    let default_fee_summary = receipt.expect_commit_success().fee_summary.clone();
+
+    // Act
+    test_runner.set_protocol_parameters(
+        ProtocolParameters::default()
+            .with_cost_unit_price(default_fee_summary.cost_unit_price * dec!(2)),
+    );
+    let receipt =
+        test_runner.execute_manifest(transfer_xrd_manifest(account, other_account), proofs);
+
+    // Assert
+    let fee_summary = &receipt.expect_commit_success().fee_summary;
+    assert_eq!(
+        fee_summary.cost_unit_price,
+        default_fee_summary.cost_unit_price * dec!(2)
+    );
+    assert!(fee_summary.total_execution_cost_xrd > default_fee_summary.total_execution_cost_xrd);
+}
+
+#[test]
+fn lowering_cost_unit_limit_rejects_expensive_transactions() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    test_runner.set_protocol_parameters(ProtocolParameters::default().with_cost_unit_limit(1_000));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        transfer_xrd_manifest(account, other_account),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_rejection();
+    assert_eq!(
+        test_runner.protocol_parameters().cost_unit_limit,
+        Some(1_000)
+    );
+}
+
+#[test]
+fn protocol_parameters_apply_from_genesis_when_built_with_them() {
+    // Arrange
+    let mut test_runner = TestRunner::builder()
+        .with_protocol_parameters(
+            ProtocolParameters::default().with_cost_unit_price(dec!("0.000001")),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        vec![],
+    );
+
+    // Assert
+    assert_eq!(
+        receipt.expect_commit_success().fee_summary.cost_unit_price,
+        dec!("0.000001")
+    );
+}
+
+#[test]
+fn restoring_a_snapshot_restores_protocol_parameters() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let snapshot = test_runner.create_snapshot();
+    test_runner.set_protocol_parameters(ProtocolParameters::default().with_max_call_depth(2));
+
+    // Act
+    test_runner.restore_snapshot(snapshot);
+
+    // Assert
+    assert_eq!(
+        test_runner.protocol_parameters(),
+        &ProtocolParameters::default()
+    );
+}
+
+#[test]
+fn changing_epoch_change_condition_changes_epoch_length() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let rounds_per_epoch = 5;
+    test_runner.set_epoch_change_condition(EpochChangeCondition {
+        min_round_count: rounds_per_epoch,
+        max_round_count: rounds_per_epoch,
+        target_duration_millis: 1000,
+    });
+
+    // Act
+    let receipt = test_runner.advance_to_round(Round::of(rounds_per_epoch - 1));
+    let before_end_of_epoch = receipt.expect_commit_success().next_epoch();
+    let receipt = test_runner.advance_to_round(Round::of(rounds_per_epoch));
+
+    // Assert
+    assert!(before_end_of_epoch.is_none());
+    assert!(receipt.expect_commit_success().next_epoch().is_some());
+}
+
+#[test]
+fn instruction_outputs_can_be_decoded_by_instruction_index() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "ComponentTest",
+                "create_component",
+                manifest_args!(),
+            )
+            .build(),
+        vec![],
+    );
+    let component_address: ComponentAddress = receipt.output_of(1);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "get_component_state", manifest_args!())
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest.clone(), vec![]);
+
+    // Assert
+    assert_eq!(receipt.output_of::<String>(1), "Secret");
+    let outputs = test_runner.decoded_outputs(&manifest, &receipt);
+    assert_eq!(outputs.len(), 2);
+    assert!(outputs[1].as_ref().unwrap().contains("\"Secret\""));
+}
@@ -0,0 +1,37 @@
+use scrypto_unit::*;
+
+#[test]
+fn scaffold_has_one_test_per_function_and_method() {
+    // Act
+    let scaffold = scaffold_integration_tests("./tests/blueprints/upgrade");
+
+    // Assert
+    assert_eq!(scaffold.matches("#[test]").count(), 3);
+    assert!(scaffold.contains("fn test_upgradeable_instantiate() {"));
+    assert!(scaffold.contains("fn test_upgradeable_migrate() {"));
+    assert!(scaffold.contains("fn test_upgradeable_version() {"));
+    assert!(scaffold.contains("test_runner.compile_and_publish(\"./tests/blueprints/upgrade\")"));
+    assert_eq!(
+        scaffold.matches("receipt.expect_commit_success();").count(),
+        3
+    );
+}
+
+#[test]
+fn scaffold_derives_argument_placeholders_from_schema() {
+    // Act
+    let scaffold = scaffold_integration_tests("./tests/blueprints/upgrade");
+
+    // Assert
+    assert!(scaffold.contains(
+        ".call_function(package_address, \"Upgradeable\", \"instantiate\", manifest_args!())"
+    ));
+    assert!(scaffold.contains("let implementation = package_address;"));
+    assert!(scaffold.contains("let version = 0u32;"));
+    assert!(scaffold.contains(
+        ".call_method(component_address, \"migrate\", manifest_args!(implementation, version))"
+    ));
+    assert!(scaffold.contains(
+        "let component_address: ComponentAddress = todo!(\"Instantiate a Upgradeable component to call the method on\");"
+    ));
+}
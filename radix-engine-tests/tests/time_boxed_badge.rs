@@ -0,0 +1,178 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::metadata;
+use scrypto::NonFungibleData;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[derive(NonFungibleData, ScryptoSbor, ManifestSbor)]
+struct TimeBoxedBadge {
+    valid_from_epoch: Option<Epoch>,
+    valid_until_epoch: Option<Epoch>,
+    name: String,
+}
+
+#[derive(NonFungibleData, ScryptoSbor, ManifestSbor)]
+struct UntimedBadge {
+    name: String,
+    valid_from_epoch: Option<Epoch>,
+    valid_until_epoch: Option<Epoch>,
+}
+
+fn create_badge<V: ManifestEncode + NonFungibleData>(
+    test_runner: &mut TestRunner,
+    account: ComponentAddress,
+    data: V,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            false,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some([(NonFungibleLocalId::integer(1), data)]),
+        )
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+fn create_resource_mintable_by(test_runner: &mut TestRunner, rule: AccessRule) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            false,
+            18,
+            FungibleResourceRoles {
+                mint_roles: mint_roles! {
+                    minter => rule;
+                    minter_updater => rule!(deny_all);
+                },
+                ..Default::default()
+            },
+            metadata!(),
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_resource_addresses()[0]
+}
+
+fn mint_with_badge(
+    test_runner: &mut TestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    badge: ResourceAddress,
+    resource_address: ResourceAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            badge,
+            &btreeset!(NonFungibleLocalId::integer(1)),
+        )
+        .mint_fungible(resource_address, dec!("1"))
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+fn is_auth_unauthorized_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(..)))
+    )
+}
+
+#[test]
+fn time_boxed_badge_only_passes_auth_during_its_validity_window() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge = create_badge(
+        &mut test_runner,
+        account,
+        TimeBoxedBadge {
+            valid_from_epoch: Some(Epoch::of(10)),
+            valid_until_epoch: Some(Epoch::of(20)),
+            name: "Member".to_string(),
+        },
+    );
+    let by_resource = create_resource_mintable_by(&mut test_runner, rule!(require(badge)));
+    let by_non_fungible = create_resource_mintable_by(
+        &mut test_runner,
+        rule!(require(NonFungibleGlobalId::new(
+            badge,
+            NonFungibleLocalId::integer(1)
+        ))),
+    );
+    let by_amount =
+        create_resource_mintable_by(&mut test_runner, rule!(require_amount(dec!("1"), badge)));
+
+    for (epoch, is_valid) in [(9, false), (10, true), (19, true), (20, false)] {
+        test_runner.set_current_epoch(Epoch::of(epoch));
+        for resource_address in [by_resource, by_non_fungible, by_amount] {
+            // Act
+            let receipt = mint_with_badge(
+                &mut test_runner,
+                public_key,
+                account,
+                badge,
+                resource_address,
+            );
+
+            // Assert
+            if is_valid {
+                receipt.expect_commit_success();
+            } else {
+                receipt.expect_specific_failure(is_auth_unauthorized_error);
+            }
+        }
+    }
+}
+
+#[test]
+fn badge_with_validity_fields_elsewhere_is_not_time_boxed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge = create_badge(
+        &mut test_runner,
+        account,
+        UntimedBadge {
+            name: "Member".to_string(),
+            valid_from_epoch: Some(Epoch::of(10)),
+            valid_until_epoch: Some(Epoch::of(20)),
+        },
+    );
+    let resource_address = create_resource_mintable_by(&mut test_runner, rule!(require(badge)));
+    test_runner.set_current_epoch(Epoch::of(30));
+
+    // Act
+    let receipt = mint_with_badge(
+        &mut test_runner,
+        public_key,
+        account,
+        badge,
+        resource_address,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn non_fungible_data_schema_detects_time_boxed_badges() {
+    assert!(NonFungibleDataSchema::new_schema::<TimeBoxedBadge>().is_time_boxed());
+    assert!(!NonFungibleDataSchema::new_schema::<UntimedBadge>().is_time_boxed());
+    assert!(!NonFungibleDataSchema::new_schema::<()>().is_time_boxed());
+}
@@ -201,3 +201,31 @@ fn test_faucet_drain_attempt_should_fail() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+#[test]
+fn test_rejection_error_renders_node_ids_as_bech32_addresses() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let non_existent_resource = resource_address(EntityType::GlobalFungibleResourceManager, 222);
+    let manifest = ManifestBuilder::new()
+        .lock_standard_test_fee(account)
+        .take_all_from_worktop(non_existent_resource, "non_existent")
+        .try_deposit_or_abort(account, "non_existent")
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let encoder = AddressBech32Encoder::for_simulator();
+
+    // Act
+    let rendered = receipt.expect_rejection().display(&encoder).to_string();
+
+    // Assert
+    let address = non_existent_resource.display(&encoder).to_string();
+    assert!(rendered.starts_with("RejectionError::"));
+    assert!(rendered.contains(&address));
+    assert!(!rendered.contains("NodeId("));
+    assert!(receipt.display(&encoder).to_string().contains(&address));
+}
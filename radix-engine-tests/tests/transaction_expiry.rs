@@ -0,0 +1,123 @@
+use radix_engine::errors::RejectionError;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::errors::{HeaderValidationError, TransactionValidationError};
+use transaction::prelude::*;
+use transaction::validation::*;
+
+fn create_notarized_transaction(
+    current_epoch: Epoch,
+    expires_at_timestamp: Option<Instant>,
+    nonce: u32,
+) -> NotarizedTransactionV1 {
+    let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+
+    TransactionBuilder::new()
+        .header(TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: current_epoch.after(10),
+            nonce,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp,
+            idempotency_key: None,
+        })
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .clear_auth_zone()
+                .build(),
+        )
+        .notarize(&sk_notary)
+        .build()
+}
+
+fn validate(
+    transaction: &NotarizedTransactionV1,
+) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
+    NotarizedTransactionValidator::new(ValidationConfig::simulator())
+        .validate(transaction.prepare().unwrap())
+}
+
+fn execute_with_expiry(
+    test_runner: &mut TestRunner,
+    expires_at_timestamp: Option<Instant>,
+    nonce: u32,
+) -> TransactionReceipt {
+    let current_epoch = test_runner.get_current_epoch();
+    let transaction = create_notarized_transaction(current_epoch, expires_at_timestamp, nonce);
+    let validated = validate(&transaction).unwrap();
+    test_runner.execute_transaction(
+        validated.get_executable(),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_notarized_transaction(),
+    )
+}
+
+#[test]
+fn transaction_executed_before_expiry_timestamp_is_committed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    test_runner.advance_to_round_at_timestamp(Round::of(1), 60_000);
+
+    // Act
+    let receipt = execute_with_expiry(&mut test_runner, Some(Instant::new(61)), 1);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn transaction_executed_at_or_after_expiry_timestamp_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    test_runner.advance_to_round_at_timestamp(Round::of(1), 60_000);
+
+    // Act
+    let at_expiry_receipt = execute_with_expiry(&mut test_runner, Some(Instant::new(60)), 1);
+    let after_expiry_receipt = execute_with_expiry(&mut test_runner, Some(Instant::new(59)), 2);
+
+    // Assert
+    at_expiry_receipt.expect_specific_rejection(|e| {
+        e.eq(&RejectionError::TransactionExpired {
+            expires_at: Instant::new(60),
+            current_time_milli: 60_000,
+        })
+    });
+    after_expiry_receipt
+        .expect_specific_rejection(|e| matches!(e, RejectionError::TransactionExpired { .. }));
+}
+
+#[test]
+fn transaction_without_expiry_timestamp_is_bounded_by_epochs_only() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    test_runner.advance_to_round_at_timestamp(Round::of(1), 60_000);
+
+    // Act
+    let receipt = execute_with_expiry(&mut test_runner, None, 1);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn non_positive_expiry_timestamp_fails_header_validation() {
+    // Arrange
+    let transaction = create_notarized_transaction(Epoch::of(1), Some(Instant::new(0)), 1);
+
+    // Act
+    let result = validate(&transaction);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(TransactionValidationError::HeaderValidationError(
+            HeaderValidationError::InvalidExpiryTimestamp
+        ))
+    ));
+}
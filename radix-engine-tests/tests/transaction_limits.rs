@@ -1,7 +1,7 @@
 use radix_engine::{
     errors::{RuntimeError, SystemModuleError},
     system::system_modules::limits::TransactionLimitsError,
-    transaction::{ExecutionConfig, FeeReserveConfig},
+    transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt},
     types::*,
 };
 use scrypto_unit::*;
@@ -283,6 +283,90 @@ fn verify_event_size_limit() {
     })
 }
 
+#[test]
+fn verify_total_event_size_limit() {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transaction_limits");
+
+    // Each event is within the size limit, but together they exceed the total
+    let mut builder = ManifestBuilder::new();
+    for _ in 0..=DEFAULT_MAX_TOTAL_EVENT_SIZE / DEFAULT_MAX_EVENT_SIZE {
+        builder = builder.call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_event_of_size",
+            manifest_args!(DEFAULT_MAX_EVENT_SIZE - 16),
+        );
+    }
+    let receipt = test_runner.execute_manifest_ignoring_fee(builder.build(), vec![]);
+
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TotalEventSizeTooLarge { .. }
+            ),)
+        )
+    })
+}
+
+#[test]
+fn verify_total_log_size_limit() {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transaction_limits");
+
+    // Each log is within the size limit, but together they exceed the total
+    let mut builder = ManifestBuilder::new();
+    for _ in 0..=DEFAULT_MAX_TOTAL_LOG_SIZE / DEFAULT_MAX_LOG_SIZE {
+        builder = builder.call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_log_of_size",
+            manifest_args!(DEFAULT_MAX_LOG_SIZE),
+        );
+    }
+    let receipt = test_runner.execute_manifest_ignoring_fee(builder.build(), vec![]);
+
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TotalLogSizeTooLarge { .. }
+            ),)
+        )
+    })
+}
+
+#[test]
+fn emitting_many_events_costs_more_per_event() {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transaction_limits");
+    let mut emit_events = |n: usize| {
+        let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+        for _ in 0..n {
+            builder = builder.call_function(
+                package_address,
+                "TransactionLimitTest",
+                "emit_event_of_size",
+                manifest_args!(1usize),
+            );
+        }
+        let receipt = test_runner.execute_manifest(builder.build(), vec![]);
+        receipt
+            .expect_commit_success()
+            .fee_summary
+            .execution_cost_sum
+    };
+
+    let cost_of_one = emit_events(1);
+    let cost_of_two = emit_events(2);
+    let cost_of_sixty_four = emit_events(64);
+
+    // Calls after the first are identical apart from the surcharge on events beyond the first 32
+    let marginal_cost = cost_of_two - cost_of_one;
+    assert!(cost_of_sixty_four - cost_of_one > marginal_cost * 63);
+}
+
 #[test]
 fn verify_panic_size_limit() {
     let mut test_runner = TestRunner::builder().build();
@@ -307,3 +391,57 @@ fn verify_panic_size_limit() {
         )
     })
 }
+
+fn execute_creating_accounts(
+    test_runner: &mut TestRunner,
+    count: usize,
+    max_number_of_new_global_entities: usize,
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
+    for _ in 0..count {
+        builder = builder.new_account_advanced(OwnerRole::None);
+    }
+    let manifest = builder.build();
+
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, 10)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        FeeReserveConfig::default(),
+        ExecutionConfig::for_test_transaction()
+            .with_max_number_of_new_global_entities(max_number_of_new_global_entities),
+    )
+}
+
+#[test]
+fn creating_global_entities_up_to_the_limit_should_succeed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_creating_accounts(&mut test_runner, 3, 3);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.new_component_addresses().len(), 3);
+}
+
+#[test]
+fn creating_more_global_entities_than_the_limit_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+
+    // Act
+    let receipt = execute_creating_accounts(&mut test_runner, 4, 3);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TooManyNewGlobalEntities { max: 3 }
+            ))
+        )
+    });
+}
@@ -0,0 +1,155 @@
+use radix_engine::transaction::*;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn review_of_transfer_reports_guaranteed_and_predicted_balance_changes() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, sender) = test_runner.new_allocated_account();
+    let (_, _, receiver) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(sender, 10)
+        .withdraw_from_account(sender, XRD, 100)
+        .deposit_batch(receiver)
+        .build();
+
+    // Act
+    let review =
+        test_runner.review_manifest(manifest, vec![public_key.into()], PreviewFlags::default());
+
+    // Assert
+    review.preview_receipt.expect_commit_success();
+    assert!(review.summary.lints.is_empty());
+    assert_eq!(review.summary.instruction_count, 3);
+    assert!(review
+        .summary
+        .accounts_withdrawn_from
+        .contains(&GlobalAddress::from(sender)));
+    assert!(review
+        .summary
+        .accounts_deposited_into
+        .contains(&GlobalAddress::from(receiver)));
+
+    assert_eq!(
+        review.guaranteed_balance_changes[&GlobalAddress::from(sender)][&XRD],
+        BalanceChange::Fungible(dec!("-100"))
+    );
+    assert_eq!(
+        review.guaranteed_balance_changes[&GlobalAddress::from(receiver)][&XRD],
+        BalanceChange::Fungible(dec!("100"))
+    );
+    assert_eq!(
+        review.predicted_balance_changes[&GlobalAddress::from(receiver)][&XRD],
+        BalanceChange::Fungible(dec!("100"))
+    );
+
+    let fee_estimate = review.fee_estimate.unwrap();
+    assert!(fee_estimate.execution_cost_units > 0);
+    assert_eq!(
+        review.predicted_balance_changes[&GlobalAddress::from(sender)][&XRD],
+        BalanceChange::Fungible(dec!("-100") - fee_estimate.estimated_fee_xrd)
+    );
+    assert_eq!(
+        fee_estimate.fee_ceiling_xrd,
+        fee_estimate.estimated_fee_xrd * dec!("1.2")
+    );
+
+    assert!(review
+        .entities_touched
+        .contains(&GlobalAddress::from(sender)));
+    assert!(review
+        .entities_touched
+        .contains(&GlobalAddress::from(receiver)));
+}
+
+#[test]
+fn review_flags_deposit_of_unknown_amount_without_assertion() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10)
+        .get_free_xrd_from_faucet()
+        .deposit_batch(account)
+        .build();
+
+    // Act
+    let review =
+        test_runner.review_manifest(manifest, vec![public_key.into()], PreviewFlags::default());
+
+    // Assert
+    review.preview_receipt.expect_commit_success();
+    assert_eq!(
+        review.summary.lints,
+        vec![ReviewLint::UnguardedDeposit {
+            instruction_index: 2,
+            account: account.into(),
+            resource_address: None,
+        }]
+    );
+    assert!(!review
+        .guaranteed_balance_changes
+        .contains_key(&GlobalAddress::from(account)));
+    assert!(review
+        .entities_touched
+        .contains(&GlobalAddress::from(FAUCET)));
+}
+
+#[test]
+fn review_guarantees_asserted_amount_of_deposit() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10)
+        .get_free_xrd_from_faucet()
+        .assert_worktop_contains(XRD, 5)
+        .deposit_batch(account)
+        .build();
+
+    // Act
+    let review =
+        test_runner.review_manifest(manifest, vec![public_key.into()], PreviewFlags::default());
+
+    // Assert
+    review.preview_receipt.expect_commit_success();
+    assert!(review.summary.lints.is_empty());
+    assert_eq!(
+        review.guaranteed_balance_changes[&GlobalAddress::from(account)][&XRD],
+        BalanceChange::Fungible(dec!("5"))
+    );
+}
+
+#[test]
+fn review_flags_missing_fee_lock_and_burns() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, resource_address, 10)
+        .burn_all_from_worktop(resource_address)
+        .build();
+
+    // Act
+    let review =
+        test_runner.review_manifest(manifest, vec![public_key.into()], PreviewFlags::default());
+
+    // Assert
+    assert_eq!(
+        review.summary.lints,
+        vec![
+            ReviewLint::NoFeeLock,
+            ReviewLint::ResourceBurned {
+                instruction_index: 2,
+                resource_address: Some(resource_address),
+            }
+        ]
+    );
+    assert_eq!(
+        review.guaranteed_balance_changes[&GlobalAddress::from(account)][&resource_address],
+        BalanceChange::Fungible(dec!("-10"))
+    );
+}
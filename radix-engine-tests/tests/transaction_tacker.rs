@@ -102,6 +102,9 @@ fn create_notarized_transaction(params: TransactionParams) -> NotarizedTransacti
             notary_public_key: sk_notary.public_key().into(),
             notary_is_signatory: false,
             tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         })
         .manifest(
             ManifestBuilder::new()
@@ -0,0 +1,135 @@
+use radix_engine::errors::{RuntimeError, SystemError};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn publish_and_instantiate(test_runner: &mut TestRunner) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/transient");
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "TransientCounter", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+#[test]
+fn transient_state_is_shared_across_calls_within_a_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "increment", manifest_args!())
+        .call_method(component_address, "increment", manifest_args!())
+        .call_method(component_address, "get", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(commit_result.output::<u32>(1), 1);
+    assert_eq!(commit_result.output::<u32>(2), 2);
+    assert_eq!(commit_result.output::<Option<u32>>(3), Some(2));
+}
+
+#[test]
+fn transient_state_is_discarded_at_the_end_of_a_transaction() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate(&mut test_runner);
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(component_address, "increment", manifest_args!())
+                .build(),
+            vec![],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "get", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_eq!(
+        receipt.expect_commit_success().output::<Option<u32>>(1),
+        None
+    );
+}
+
+#[test]
+fn transient_state_is_not_part_of_state_updates() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "increment", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    let component_partitions = commit_result
+        .state_updates
+        .system_updates
+        .keys()
+        .filter(|(node_id, _)| node_id.eq(component_address.as_node_id()))
+        .map(|(_, partition_num)| *partition_num)
+        .collect::<Vec<_>>();
+    assert!(!component_partitions.contains(&TRANSIENT_PARTITION));
+}
+
+#[test]
+fn transient_entry_can_be_removed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "increment", manifest_args!())
+        .call_method(component_address, "take", manifest_args!())
+        .call_method(component_address, "get", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit_result = receipt.expect_commit_success();
+    assert_eq!(commit_result.output::<Option<u32>>(2), Some(1));
+    assert_eq!(commit_result.output::<Option<u32>>(3), None);
+}
+
+#[test]
+fn storing_owned_object_in_transient_state_fails() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = publish_and_instantiate(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "store_bucket", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::CannotStoreOwnedInTransientEntry)
+        )
+    });
+}
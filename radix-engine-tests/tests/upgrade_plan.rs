@@ -0,0 +1,104 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::metadata::MetadataInit;
+use radix_engine_interface::api::ObjectModuleId;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn instantiate_upgradeable(test_runner: &mut TestRunner) -> ComponentAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/upgrade");
+    let receipt = test_runner.call_function(package_address, "Upgradeable", "instantiate", ());
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn version(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+) -> (u32, Option<PackageAddress>) {
+    let receipt = test_runner.call_method(component_address, "version", ());
+    receipt.expect_commit_success().output(1)
+}
+
+fn upgrade_plan(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    new_version: u32,
+) -> UpgradePlan {
+    let (code, definition) = test_runner.compile("./tests/blueprints/upgrade");
+    UpgradePlan::new()
+        .publish_package(code, definition, MetadataInit::default(), OwnerRole::None)
+        .migrate_with_new_package_address(component_address, "migrate", manifest_args!(new_version))
+        .update_role(
+            component_address,
+            ObjectModuleId::Main,
+            "migrator",
+            AccessRule::DenyAll,
+        )
+}
+
+#[test]
+fn upgrade_plan_publishes_migrates_and_updates_roles() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate_upgradeable(&mut test_runner);
+    let plan = upgrade_plan(&mut test_runner, component_address, 2);
+
+    // Act
+    let receipt = test_runner.execute_upgrade_plan(&plan, vec![]);
+
+    // Assert
+    let new_package_address = receipt.expect_commit_success().new_package_addresses()[0];
+    assert_eq!(
+        version(&mut test_runner, component_address),
+        (2, Some(new_package_address))
+    );
+    let receipt =
+        test_runner.call_method(component_address, "migrate", (new_package_address, 3u32));
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn failed_migration_rolls_back_whole_upgrade_plan() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate_upgradeable(&mut test_runner);
+    let plan = upgrade_plan(&mut test_runner, component_address, 1);
+
+    // Act
+    let receipt = test_runner.execute_upgrade_plan(&plan, vec![]);
+
+    // Assert
+    receipt.expect_commit_failure();
+    assert_eq!(version(&mut test_runner, component_address), (1, None));
+    let plan = upgrade_plan(&mut test_runner, component_address, 2);
+    test_runner
+        .execute_upgrade_plan(&plan, vec![])
+        .expect_commit_success();
+}
+
+#[test]
+fn preview_of_upgrade_plan_commits_nothing() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let component_address = instantiate_upgradeable(&mut test_runner);
+    let plan = upgrade_plan(&mut test_runner, component_address, 2);
+
+    // Act
+    let receipt = test_runner.preview_upgrade_plan(&plan, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.new_package_addresses().len(), 1);
+    assert_eq!(version(&mut test_runner, component_address), (1, None));
+    test_runner
+        .execute_upgrade_plan(&plan, vec![])
+        .expect_commit_success();
+}
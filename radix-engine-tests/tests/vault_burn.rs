@@ -1027,6 +1027,58 @@ fn can_burn_by_ids_from_non_fungible_account_vault() {
     )
 }
 
+#[test]
+fn can_burn_by_ids_from_non_fungible_account_vault_with_manifest_builder() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let virtual_signature_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let virtual_signature_rule = rule!(require(virtual_signature_badge.clone()));
+    let resource_address = {
+        let manifest = ManifestBuilder::new()
+            .create_non_fungible_resource(
+                OwnerRole::None,
+                NonFungibleIdType::Integer,
+                true,
+                NonFungibleResourceRoles::single_locked_rule(virtual_signature_rule),
+                metadata!(),
+                Some(btreemap!(
+                    NonFungibleLocalId::integer(1) => EmptyStruct {},
+                    NonFungibleLocalId::integer(2) => EmptyStruct {},
+                )),
+            )
+            .try_deposit_batch_or_abort(account)
+            .build();
+        test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![virtual_signature_badge.clone()])
+            .expect_commit_success()
+            .new_resource_addresses()
+            .get(0)
+            .unwrap()
+            .clone()
+    };
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .burn_non_fungibles_in_account(
+            account,
+            resource_address,
+            &btreeset!(NonFungibleLocalId::integer(1)),
+        )
+        .build();
+    let receipt =
+        test_runner.execute_manifest_ignoring_fee(manifest, vec![virtual_signature_badge.clone()]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .account_balance(account, resource_address)
+            .unwrap(),
+        dec!(1)
+    )
+}
+
 fn get_vault_id(test_runner: &mut TestRunner, component_address: ComponentAddress) -> NodeId {
     let manifest = ManifestBuilder::new()
         .call_method(component_address, "vault_id", manifest_args!())
@@ -220,3 +220,81 @@ fn can_freezy_recall_unfreezy_non_fungible_vault() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn cannot_recall_from_frozen_recall_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(true);
+    let token_address = test_runner.create_freezeable_token(account);
+    let vaults = test_runner.get_component_vaults(account, token_address);
+    let vault_address = InternalAddress::new_or_panic(vaults[0].into());
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .freeze_recall(vault_address)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall(vault_address, 1)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+}
+
+#[test]
+fn can_recall_non_fungibles_from_unfrozen_recall_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (_, _, account) = test_runner.new_account(true);
+    let resource_address = test_runner.create_freezeable_non_fungible(account);
+    let vaults = test_runner.get_component_vaults(account, resource_address);
+    let vault_address = InternalAddress::new_or_panic(vaults[0].into());
+    let ids = btreeset!(NonFungibleLocalId::integer(1));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .freeze_recall(vault_address)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+    let recall_manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .recall_non_fungibles(vault_address, &ids)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    test_runner
+        .execute_manifest(recall_manifest.clone(), vec![])
+        .expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::VaultError(
+                    VaultError::VaultIsFrozen
+                ))
+            )
+        });
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .unfreeze_recall(vault_address)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(recall_manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -0,0 +1,426 @@
+use radix_engine::blueprints::resource::{NonFungibleVaultError, VaultError};
+use radix_engine::errors::{
+    ApplicationError, RuntimeError, SystemError, SystemModuleError, VaultTransferError,
+};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::{metadata, mint_roles, withdraw_roles};
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_resource(test_runner: &mut TestRunner, roles: FungibleResourceRoles) -> ResourceAddress {
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(OwnerRole::None, true, 18, roles, metadata!(), None)
+        .build();
+    test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .new_resource_addresses()[0]
+}
+
+fn create_component(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+    source_resource: ResourceAddress,
+    destination_resource: ResourceAddress,
+) -> (ComponentAddress, NodeId, NodeId) {
+    let manifest = ManifestBuilder::new()
+        .mint_fungible(source_resource, 100)
+        .take_all_from_worktop(source_resource, "source")
+        .mint_fungible(destination_resource, 10)
+        .take_all_from_worktop(destination_resource, "destination")
+        .with_name_lookup(|builder, lookup| {
+            builder.call_function(
+                package_address,
+                "VaultTransfer",
+                "new",
+                manifest_args!(lookup.bucket("source"), lookup.bucket("destination")),
+            )
+        })
+        .build();
+    let component_address = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, "vault_ids", manifest_args!())
+        .build();
+    let (source_vault, destination_vault): (NodeId, NodeId) = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+
+    (component_address, source_vault, destination_vault)
+}
+
+fn create_non_fungible_component(
+    test_runner: &mut TestRunner,
+    package_address: PackageAddress,
+) -> (ComponentAddress, NodeId, NodeId) {
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "NonFungibleVaultTransfer",
+            "new",
+            manifest_args!(),
+        )
+        .build();
+    let component_address = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, "vault_ids", manifest_args!())
+        .build();
+    let (source_vault, destination_vault): (NodeId, NodeId) = test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![])
+        .expect_commit_success()
+        .output(1);
+
+    (component_address, source_vault, destination_vault)
+}
+
+fn call_method_with_ids(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    ids: BTreeSet<NonFungibleLocalId>,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, method_name, manifest_args!(ids))
+        .build();
+    test_runner.execute_manifest_ignoring_fee(manifest, vec![])
+}
+
+fn call_method(
+    test_runner: &mut TestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .call_method(component_address, method_name, manifest_args!(amount))
+        .build();
+    test_runner.execute_manifest_ignoring_fee(manifest, vec![])
+}
+
+#[test]
+fn can_transfer_between_vaults_of_same_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let resource_address = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let (component_address, source_vault, destination_vault) = create_component(
+        &mut test_runner,
+        package_address,
+        resource_address,
+        resource_address,
+    );
+
+    // Act
+    let receipt = call_method(&mut test_runner, component_address, "transfer", dec!("25"));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.inspect_fungible_vault(source_vault).unwrap(),
+        dec!("75")
+    );
+    assert_eq!(
+        test_runner
+            .inspect_fungible_vault(destination_vault)
+            .unwrap(),
+        dec!("35")
+    );
+}
+
+#[test]
+fn cannot_transfer_more_than_vault_balance() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let resource_address = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let (component_address, _, _) = create_component(
+        &mut test_runner,
+        package_address,
+        resource_address,
+        resource_address,
+    );
+
+    // Act
+    let receipt = call_method(&mut test_runner, component_address, "transfer", dec!("101"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::ResourceError(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_transfer_between_vaults_of_different_resources() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let source_resource = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let destination_resource = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let (component_address, _, _) = create_component(
+        &mut test_runner,
+        package_address,
+        source_resource,
+        destination_resource,
+    );
+
+    // Act
+    let receipt = call_method(&mut test_runner, component_address, "transfer", dec!("1"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemError(SystemError::VaultTransferError(
+            VaultTransferError::ResourceMismatch,
+        )))
+    });
+}
+
+#[test]
+fn cannot_transfer_to_same_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let resource_address = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let (component_address, _, _) = create_component(
+        &mut test_runner,
+        package_address,
+        resource_address,
+        resource_address,
+    );
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        component_address,
+        "transfer_to_self",
+        dec!("1"),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemError(SystemError::VaultTransferError(
+            VaultTransferError::SameVault,
+        )))
+    });
+}
+
+#[test]
+fn transfer_requires_withdraw_auth_of_source_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let resource_address = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles {
+            mint_roles: mint_roles! {
+                minter => rule!(allow_all);
+                minter_updater => rule!(deny_all);
+            },
+            withdraw_roles: withdraw_roles! {
+                withdrawer => rule!(deny_all);
+                withdrawer_updater => rule!(deny_all);
+            },
+            ..Default::default()
+        },
+    );
+    let (component_address, _, _) = create_component(
+        &mut test_runner,
+        package_address,
+        resource_address,
+        resource_address,
+    );
+
+    // Act
+    let receipt = call_method(&mut test_runner, component_address, "transfer", dec!("1"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_call_transfer_in_directly() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let resource_address = create_resource(
+        &mut test_runner,
+        FungibleResourceRoles::single_locked_rule(rule!(allow_all)),
+    );
+    let (component_address, _, destination_vault) = create_component(
+        &mut test_runner,
+        package_address,
+        resource_address,
+        resource_address,
+    );
+
+    // Act
+    let receipt = call_method(
+        &mut test_runner,
+        component_address,
+        "transfer_in_directly",
+        dec!("1000"),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemError(SystemError::VaultTransferError(
+            VaultTransferError::MethodNotCallable(FUNGIBLE_VAULT_TRANSFER_IN_IDENT.to_string()),
+        )))
+    });
+    assert_eq!(
+        test_runner
+            .inspect_fungible_vault(destination_vault)
+            .unwrap(),
+        dec!("10")
+    );
+}
+
+#[test]
+fn can_transfer_amount_between_non_fungible_vaults() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let (component_address, source_vault, destination_vault) =
+        create_non_fungible_component(&mut test_runner, package_address);
+
+    // Act
+    let receipt = call_method(&mut test_runner, component_address, "transfer", dec!("2"));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .inspect_non_fungible_vault(source_vault)
+            .unwrap()
+            .0,
+        dec!("1")
+    );
+    assert_eq!(
+        test_runner
+            .inspect_non_fungible_vault(destination_vault)
+            .unwrap()
+            .0,
+        dec!("2")
+    );
+}
+
+#[test]
+fn can_transfer_non_fungibles_by_id_between_non_fungible_vaults() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let (component_address, source_vault, destination_vault) =
+        create_non_fungible_component(&mut test_runner, package_address);
+
+    // Act
+    let receipt = call_method_with_ids(
+        &mut test_runner,
+        component_address,
+        "transfer_non_fungibles",
+        btreeset![NonFungibleLocalId::integer(2)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .inspect_non_fungible_vault(source_vault)
+            .unwrap()
+            .0,
+        dec!("2")
+    );
+    assert_eq!(
+        test_runner.inspect_non_fungible_vault(destination_vault),
+        Some((dec!("1"), Some(NonFungibleLocalId::integer(2))))
+    );
+}
+
+#[test]
+fn cannot_transfer_non_fungibles_missing_from_source_vault() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let (component_address, _, _) =
+        create_non_fungible_component(&mut test_runner, package_address);
+
+    // Act
+    let receipt = call_method_with_ids(
+        &mut test_runner,
+        component_address,
+        "transfer_non_fungibles",
+        btreeset![NonFungibleLocalId::integer(4)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::NonFungibleVaultError(NonFungibleVaultError::MissingId(
+                NonFungibleLocalId::integer(4),
+            )),
+        ))
+    });
+}
+
+#[test]
+fn cannot_call_non_fungible_transfer_in_directly() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/vault");
+    let (component_address, _, destination_vault) =
+        create_non_fungible_component(&mut test_runner, package_address);
+
+    // Act
+    let receipt = call_method_with_ids(
+        &mut test_runner,
+        component_address,
+        "transfer_in_directly",
+        btreeset![NonFungibleLocalId::integer(4)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::SystemError(SystemError::VaultTransferError(
+            VaultTransferError::MethodNotCallable(NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT.to_string()),
+        )))
+    });
+    assert_eq!(
+        test_runner.inspect_non_fungible_vault(destination_vault),
+        Some((dec!("0"), None))
+    );
+}
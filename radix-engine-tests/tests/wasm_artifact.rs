@@ -0,0 +1,103 @@
+use radix_engine::types::*;
+use radix_engine::vm::wasm::*;
+use radix_engine::vm::ScryptoVm;
+use scrypto_unit::*;
+
+fn publish_and_build_artifact() -> (
+    TestRunner,
+    PackageAddress,
+    PrecompiledWasmArtifact,
+    IndexMap<Hash, Hash>,
+) {
+    let mut test_runner = TestRunner::builder().build();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+
+    let mut precompiler = WasmPrecompiler::default();
+    let code_hashes = precompiler.add_package(test_runner.substate_db(), &package_address);
+    assert_eq!(code_hashes.len(), 1);
+    let artifact = precompiler.build();
+    let expected = read_instrumented_code_hashes(test_runner.substate_db(), [&package_address]);
+
+    (test_runner, package_address, artifact, expected)
+}
+
+#[test]
+fn precompiled_artifact_matches_published_package() {
+    // Arrange
+    let (code, _) = Compile::compile("./tests/blueprints/logger");
+    let (_, _, artifact, expected) = publish_and_build_artifact();
+
+    // Act
+    let mut precompiler = WasmPrecompiler::default();
+    let code_hash = precompiler.add_code(&code).unwrap();
+    let offline_artifact = precompiler.build();
+
+    // Assert
+    assert_eq!(code_hash, hash(&code));
+    assert_eq!(offline_artifact, artifact);
+    assert_eq!(offline_artifact.to_bytes(), artifact.to_bytes());
+    let loaded = PrecompiledWasmArtifact::load(&artifact.to_bytes(), &expected).unwrap();
+    assert_eq!(loaded, artifact);
+    ScryptoVm::<DefaultWasmEngine>::default().preload_artifact(&loaded);
+}
+
+#[test]
+fn precompiled_artifact_with_tampered_code_is_rejected() {
+    // Arrange
+    let (_, _, mut artifact, expected) = publish_and_build_artifact();
+    let code_hash = artifact.modules[0].code_hash;
+    artifact.modules[0].instrumented_code.push(0u8);
+
+    // Act
+    let result = PrecompiledWasmArtifact::load(&artifact.to_bytes(), &expected);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(PrecompiledWasmArtifactError::InstrumentedCodeHashMismatch { code_hash })
+    );
+
+    // Rehashing the tampered code doesn't help, as it no longer matches the package
+    artifact.modules[0].instrumented_code_hash = hash(&artifact.modules[0].instrumented_code);
+    let result = PrecompiledWasmArtifact::load(&artifact.to_bytes(), &expected);
+    assert_eq!(
+        result,
+        Err(PrecompiledWasmArtifactError::ExpectedInstrumentedCodeMismatch { code_hash })
+    );
+}
+
+#[test]
+fn precompiled_artifact_for_unknown_package_is_rejected() {
+    // Arrange
+    let (_, _, artifact, _) = publish_and_build_artifact();
+    let code_hash = artifact.modules[0].code_hash;
+
+    // Act
+    let result = PrecompiledWasmArtifact::load(&artifact.to_bytes(), &index_map_new());
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(PrecompiledWasmArtifactError::UnknownCodeHash { code_hash })
+    );
+}
+
+#[test]
+fn precompiled_artifact_with_other_instrumenter_version_is_rejected() {
+    // Arrange
+    let (_, _, mut artifact, expected) = publish_and_build_artifact();
+    let instrumenter_version = artifact.instrumenter_version;
+    artifact.instrumenter_version += 1;
+
+    // Act
+    let result = PrecompiledWasmArtifact::load(&artifact.to_bytes(), &expected);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(PrecompiledWasmArtifactError::InstrumenterVersionMismatch {
+            expected: instrumenter_version,
+            actual: instrumenter_version + 1,
+        })
+    );
+}
@@ -0,0 +1,115 @@
+use radix_engine::types::*;
+use radix_engine::vm::wasm::*;
+use scrypto_unit::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+#[derive(Default)]
+struct InMemoryCache {
+    entries: Mutex<IndexMap<Hash, Vec<u8>>>,
+    puts: AtomicUsize,
+}
+
+impl WasmInstrumentationCache for InMemoryCache {
+    fn get(&self, key: &Hash) -> Option<Vec<u8>> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn put(&self, key: &Hash, value: &[u8]) {
+        self.puts.fetch_add(1, Ordering::SeqCst);
+        self.entries.lock().unwrap().insert(*key, value.to_vec());
+    }
+}
+
+fn cache_entries(dir: &std::path::Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter(|entry| {
+                    entry
+                        .as_ref()
+                        .unwrap()
+                        .path()
+                        .extension()
+                        .map_or(false, |extension| extension == "bin")
+                })
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn validation_is_reused_from_cache() {
+    // Arrange
+    let (code, definition) = Compile::compile("./tests/blueprints/logger");
+    let validator = WasmValidator::default();
+    let cache = InMemoryCache::default();
+
+    // Act
+    let first = validator
+        .validate_with_cache(&code, definition.blueprints.values(), &cache)
+        .unwrap();
+    let second = validator
+        .validate_with_cache(&code, definition.blueprints.values(), &cache)
+        .unwrap();
+
+    // Assert
+    assert_eq!(cache.puts.load(Ordering::SeqCst), 1);
+    assert_eq!(first, second);
+    assert_eq!(
+        first,
+        validator
+            .validate(&code, definition.blueprints.values())
+            .unwrap()
+    );
+}
+
+#[test]
+fn cache_key_depends_on_blueprints_and_metering_rules() {
+    // Arrange
+    let (code, definition) = Compile::compile("./tests/blueprints/logger");
+    let validator = WasmValidator::default();
+    let key = validator.instrumentation_cache_key(&code, definition.blueprints.values());
+
+    // Act
+    let mut other_definition = definition.clone();
+    other_definition.blueprints.clear();
+    let other_blueprints_key =
+        validator.instrumentation_cache_key(&code, other_definition.blueprints.values());
+    let mut other_validator = WasmValidator::default();
+    other_validator.max_number_of_functions += 1;
+    let other_limits_key =
+        other_validator.instrumentation_cache_key(&code, definition.blueprints.values());
+
+    // Assert
+    assert_ne!(key, other_blueprints_key);
+    assert_ne!(key, other_limits_key);
+}
+
+#[test]
+fn test_runners_share_instrumentation_through_cache_directory() {
+    // Arrange
+    let dir =
+        std::env::temp_dir().join(format!("wasm-instrumentation-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut first_runner = TestRunner::builder()
+        .with_wasm_instrumentation_cache(&dir)
+        .build();
+    let mut second_runner = TestRunner::builder()
+        .with_wasm_instrumentation_cache(&dir)
+        .build();
+
+    // Act
+    let first_package = first_runner.compile_and_publish("./tests/blueprints/logger");
+    let entries_after_first_publish = cache_entries(&dir);
+    let second_package = second_runner.compile_and_publish("./tests/blueprints/logger");
+
+    // Assert
+    assert_eq!(entries_after_first_publish, 1);
+    assert_eq!(cache_entries(&dir), 1);
+    assert_eq!(
+        read_instrumented_code_hashes(first_runner.substate_db(), [&first_package]),
+        read_instrumented_code_hashes(second_runner.substate_db(), [&second_package])
+    );
+    let _ = std::fs::remove_dir_all(&dir);
+}
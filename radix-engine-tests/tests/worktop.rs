@@ -1,4 +1,5 @@
 use radix_engine::blueprints::resource::FungibleResourceManagerError;
+use radix_engine::blueprints::resource::WorktopError;
 use radix_engine::errors::ApplicationError;
 use radix_engine::errors::RuntimeError;
 use radix_engine::errors::SystemModuleError;
@@ -97,3 +98,128 @@ fn test_many_worktop_call() {
         )
     });
 }
+
+#[test]
+fn test_drop_all_buckets_into_deposits_entire_worktop() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, dec!(40))
+        .take_from_worktop(resource_address, dec!(15), "bucket")
+        .return_to_worktop("bucket")
+        .drop_all_buckets_into(other_account)
+        .drop_all_proofs()
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .get_component_resources(other_account)
+            .get(&resource_address)
+            .cloned(),
+        Some(dec!(40))
+    );
+    assert_eq!(
+        test_runner
+            .get_component_resources(account)
+            .get(&resource_address)
+            .cloned(),
+        Some(dec!(60))
+    );
+}
+
+#[test]
+fn test_assert_worktop_contains_exactly_and_resources_only_succeed() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 0, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 5)
+        .assert_worktop_contains_exactly(XRD, 5)
+        .assert_worktop_contains_exactly(resource_address, 0)
+        .assert_worktop_resources_only([XRD])
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_assert_worktop_contains_exactly_fails_on_larger_amount() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 5)
+        .assert_worktop_contains_exactly(XRD, 4)
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::AssertionFailed
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_assert_worktop_resources_only_fails_on_other_resource() {
+    // Arrange
+    let mut test_runner = TestRunner::builder().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 0, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 5)
+        .withdraw_from_account(account, resource_address, 1)
+        .assert_worktop_resources_only([XRD])
+        .try_deposit_batch_or_abort(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::AssertionFailed
+            ))
+        )
+    });
+}
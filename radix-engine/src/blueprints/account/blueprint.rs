@@ -1,6 +1,7 @@
 use crate::blueprints::util::{PresecurifiedAccessRules, SecurifiedAccessRules};
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
+use crate::errors::SystemError;
 use crate::types::*;
 use native_sdk::modules::access_rules::AccessRules;
 use native_sdk::modules::metadata::Metadata;
@@ -23,6 +24,7 @@ use radix_engine_interface::metadata_init;
 #[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
 pub struct AccountSubstate {
     pub default_deposit_rule: AccountDefaultDepositRule,
+    pub authorized_depositors: BTreeSet<ResourceOrNonFungible>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -237,6 +239,7 @@ impl AccountBlueprint {
             None,
             vec![scrypto_encode(&AccountSubstate {
                 default_deposit_rule: AccountDefaultDepositRule::Accept,
+                authorized_depositors: BTreeSet::new(),
             })
             .unwrap()],
             btreemap!(),
@@ -538,6 +541,31 @@ impl AccountBlueprint {
         Ok(proof)
     }
 
+    /// Creates the proofs in the given order, as separate calls to `create_proof_of_amount` and
+    /// `create_proof_of_non_fungibles` would.
+    pub fn create_proofs<Y>(
+        proofs: Vec<AccountProofSpecifier>,
+        api: &mut Y,
+    ) -> Result<Vec<Proof>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut created = Vec::with_capacity(proofs.len());
+        for proof in proofs {
+            let proof = match proof {
+                AccountProofSpecifier::Amount(resource_address, amount) => {
+                    Self::create_proof_of_amount(resource_address, amount, api)?
+                }
+                AccountProofSpecifier::NonFungibles(resource_address, ids) => {
+                    Self::create_proof_of_non_fungibles(resource_address, ids, api)?
+                }
+            };
+            created.push(proof);
+        }
+
+        Ok(created)
+    }
+
     pub fn change_account_default_deposit_rule<Y>(
         default_deposit_rule: AccountDefaultDepositRule,
         api: &mut Y,
@@ -594,9 +622,45 @@ impl AccountBlueprint {
         Ok(())
     }
 
-    fn get_account_default_deposit_rule<Y>(
+    pub fn add_authorized_depositor<Y>(
+        badge: ResourceOrNonFungible,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate_key = AccountField::Account.into();
+        let handle = api.actor_open_field(OBJECT_HANDLE_SELF, substate_key, LockFlags::MUTABLE)?;
+        let mut account = api.field_lock_read_typed::<AccountSubstate>(handle)?;
+
+        account.authorized_depositors.insert(badge);
+
+        api.field_lock_write_typed(handle, account)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
+    pub fn remove_authorized_depositor<Y>(
+        badge: ResourceOrNonFungible,
         api: &mut Y,
-    ) -> Result<AccountDefaultDepositRule, RuntimeError>
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate_key = AccountField::Account.into();
+        let handle = api.actor_open_field(OBJECT_HANDLE_SELF, substate_key, LockFlags::MUTABLE)?;
+        let mut account = api.field_lock_read_typed::<AccountSubstate>(handle)?;
+
+        account.authorized_depositors.remove(&badge);
+
+        api.field_lock_write_typed(handle, account)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
+    fn get_account_substate<Y>(api: &mut Y) -> Result<AccountSubstate, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -604,10 +668,35 @@ impl AccountBlueprint {
         let handle =
             api.actor_open_field(OBJECT_HANDLE_SELF, substate_key, LockFlags::read_only())?;
         let account = api.field_lock_read_typed::<AccountSubstate>(handle)?;
-        let default_deposit_rule = account.default_deposit_rule;
         api.field_lock_release(handle)?;
 
-        Ok(default_deposit_rule)
+        Ok(account)
+    }
+
+    /// Checks whether the caller is presenting a proof of any of the authorized depositor badges.
+    fn is_authorized_depositor_present<Y>(
+        authorized_depositors: BTreeSet<ResourceOrNonFungible>,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if authorized_depositors.is_empty() {
+            return Ok(false);
+        }
+
+        let rule = AccessRule::Protected(AccessRuleNode::AnyOf(
+            authorized_depositors
+                .into_iter()
+                .map(|badge| AccessRuleNode::ProofRule(ProofRule::Require(badge)))
+                .collect(),
+        ));
+
+        match api.assert_access_rule(rule) {
+            Ok(()) => Ok(true),
+            Err(RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)) => Ok(false),
+            Err(error) => Err(error),
+        }
     }
 
     fn get_vault<F, Y, R>(
@@ -677,23 +766,29 @@ impl AccountBlueprint {
     {
         let resource_deposit_rule =
             Self::get_resource_deposit_configuration(resource_address, api)?;
+        let AccountSubstate {
+            default_deposit_rule,
+            authorized_depositors,
+        } = Self::get_account_substate(api)?;
 
         let is_deposit_allowed = match resource_deposit_rule {
             ResourceDepositRule::Allowed => true,
             ResourceDepositRule::Disallowed => false,
-            ResourceDepositRule::Neither => {
-                let default_deposit_rule = Self::get_account_default_deposit_rule(api)?;
-                match default_deposit_rule {
-                    AccountDefaultDepositRule::Accept => true,
-                    AccountDefaultDepositRule::Reject => false,
-                    AccountDefaultDepositRule::AllowExisting => {
-                        *resource_address == XRD || Self::does_vault_exist(resource_address, api)?
-                    }
+            ResourceDepositRule::Neither => match default_deposit_rule {
+                AccountDefaultDepositRule::Accept => true,
+                AccountDefaultDepositRule::Reject => false,
+                AccountDefaultDepositRule::AllowExisting => {
+                    *resource_address == XRD || Self::does_vault_exist(resource_address, api)?
                 }
-            }
+            },
         };
 
-        Ok(is_deposit_allowed)
+        // Deposits which the rules would reject are still accepted from authorized depositors.
+        if is_deposit_allowed {
+            Ok(true)
+        } else {
+            Self::is_authorized_depositor_present(authorized_depositors, api)
+        }
     }
 
     fn does_vault_exist<Y>(
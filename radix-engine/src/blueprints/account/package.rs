@@ -268,6 +268,20 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_CREATE_PROOFS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountCreateProofsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountCreateProofsOutput>(),
+                ),
+                export: ACCOUNT_CREATE_PROOFS_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_CHANGE_DEFAULT_DEPOSIT_RULE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -296,6 +310,38 @@ impl AccountNativePackage {
             },
         );
 
+        functions.insert(
+            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountAddAuthorizedDepositorInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountAddAuthorizedDepositorOutput>(),
+                ),
+                export: ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountRemoveAuthorizedDepositorInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountRemoveAuthorizedDepositorOutput>(),
+                ),
+                export: ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT.to_string(),
             FunctionSchemaInit {
@@ -399,6 +445,8 @@ impl AccountNativePackage {
 
                             ACCOUNT_CHANGE_DEFAULT_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
                             ACCOUNT_CONFIGURE_RESOURCE_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
+                            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT => [OWNER_ROLE];
+                            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT => [OWNER_ROLE];
                             ACCOUNT_WITHDRAW_IDENT => [OWNER_ROLE];
                             ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                             ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
@@ -407,6 +455,7 @@ impl AccountNativePackage {
                             ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                             ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => [OWNER_ROLE];
                             ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                            ACCOUNT_CREATE_PROOFS_IDENT => [OWNER_ROLE];
                             ACCOUNT_DEPOSIT_IDENT => [OWNER_ROLE];
                             ACCOUNT_DEPOSIT_BATCH_IDENT => [OWNER_ROLE];
                             ACCOUNT_BURN_IDENT => [OWNER_ROLE];
@@ -624,6 +673,13 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_CREATE_PROOFS_IDENT => {
+                let input: AccountCreateProofsInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::create_proofs(input.proofs, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_CHANGE_DEFAULT_DEPOSIT_RULE_IDENT => {
                 let AccountChangeDefaultDepositRuleInput {
                     default_deposit_rule,
@@ -650,6 +706,22 @@ impl AccountNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT => {
+                let AccountAddAuthorizedDepositorInput { badge } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::add_authorized_depositor(badge, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT => {
+                let AccountRemoveAuthorizedDepositorInput { badge } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::remove_authorized_depositor(badge, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
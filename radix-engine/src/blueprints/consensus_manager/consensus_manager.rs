@@ -1,5 +1,5 @@
 use super::{EpochChangeEvent, RoundChangeEvent, ValidatorCreator, ValidatorOwnerBadgeData};
-use crate::blueprints::consensus_manager::VALIDATOR_ROLE;
+use crate::blueprints::consensus_manager::{GOVERNANCE_ROLE, VALIDATOR_ROLE};
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
 use crate::kernel::kernel_api::KernelNodeApi;
@@ -73,6 +73,22 @@ impl Clone for ValidatorRewardsSubstate {
     }
 }
 
+/// The XRD deposits held for globalized components, refundable once a component is destroyed.
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct InstantiationDepositsSubstate {
+    pub deposits: IndexMap<GlobalAddress, Decimal>,
+    pub deposit_vault: Vault,
+}
+
+impl Clone for InstantiationDepositsSubstate {
+    fn clone(&self) -> Self {
+        Self {
+            deposits: self.deposits.clone(),
+            deposit_vault: Vault(self.deposit_vault.0.clone()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct CurrentValidatorSetSubstate {
     pub validator_set: ActiveValidatorSet,
@@ -135,6 +151,32 @@ pub struct ProposerMinuteTimestampSubstate {
     pub epoch_minute: i32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+#[sbor(transparent)]
+pub struct EpochHistorySubstate {
+    /// The start records of the most recent epochs, ordered from the oldest to the current one.
+    /// Bounded by [`EPOCH_HISTORY_CAPACITY`] - the oldest record is evicted when a new epoch
+    /// starts on a full history.
+    pub records: Vec<EpochStartRecord>,
+}
+
+impl EpochHistorySubstate {
+    pub fn record_epoch_start(&mut self, epoch: Epoch, start_milli: i64) {
+        if self.records.len() >= EPOCH_HISTORY_CAPACITY {
+            let excess = self.records.len() + 1 - EPOCH_HISTORY_CAPACITY;
+            self.records.drain(0..excess);
+        }
+        self.records.push(EpochStartRecord { epoch, start_milli });
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+#[sbor(transparent)]
+pub struct InvocationFilterSubstate {
+    /// The filter enforced by the invocation filter system module (if enabled).
+    pub filter: InvocationFilter,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct CurrentProposalStatisticSubstate {
     /// A proposal statistic of each validator from the current validator set, in the iteration
@@ -287,6 +329,15 @@ impl ConsensusManagerBlueprint {
             let milli_timestamp = ProposerMilliTimestampSubstate {
                 epoch_milli: initial_time_milli,
             };
+            let mut epoch_history = EpochHistorySubstate {
+                records: Vec::new(),
+            };
+            epoch_history.record_epoch_start(genesis_epoch, initial_time_milli);
+            let invocation_filter = InvocationFilterSubstate::default();
+            let instantiation_deposits = InstantiationDepositsSubstate {
+                deposits: index_map_new(),
+                deposit_vault: Vault::create(XRD, api)?,
+            };
 
             api.new_simple_object(
                 CONSENSUS_MANAGER_BLUEPRINT,
@@ -298,12 +349,16 @@ impl ConsensusManagerBlueprint {
                     scrypto_encode(&current_proposal_statistic).unwrap(),
                     scrypto_encode(&minute_timestamp).unwrap(),
                     scrypto_encode(&milli_timestamp).unwrap(),
+                    scrypto_encode(&epoch_history).unwrap(),
+                    scrypto_encode(&invocation_filter).unwrap(),
+                    scrypto_encode(&instantiation_deposits).unwrap(),
                 ],
             )?
         };
 
         let role_definitions = roles2! {
             VALIDATOR_ROLE => rule!(require(AuthAddresses::validator_role()));
+            GOVERNANCE_ROLE => rule!(require(AuthAddresses::system_role()));
         };
 
         let roles = btreemap!(ObjectModuleId::Main => role_definitions);
@@ -377,6 +432,11 @@ impl ConsensusManagerBlueprint {
         let post_genesis_epoch = manager_substate.epoch.next();
 
         Self::epoch_change(post_genesis_epoch, &config_substate.config, api)?;
+        Self::record_epoch_start(
+            post_genesis_epoch,
+            manager_substate.actual_epoch_start_milli,
+            api,
+        )?;
         manager_substate.started = true;
         manager_substate.epoch = post_genesis_epoch;
         manager_substate.round = Round::zero();
@@ -446,6 +506,60 @@ impl ConsensusManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_epoch_history<Y>(api: &mut Y) -> Result<Vec<EpochStartRecord>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::EpochHistory.into(),
+            LockFlags::read_only(),
+        )?;
+        let epoch_history: EpochHistorySubstate = api.field_lock_read_typed(handle)?;
+        api.field_lock_release(handle)?;
+
+        Ok(epoch_history.records)
+    }
+
+    pub(crate) fn set_invocation_filter<Y>(
+        filter: InvocationFilter,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::InvocationFilter.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_lock_write_typed(handle, &InvocationFilterSubstate { filter })?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
+    fn record_epoch_start<Y>(
+        epoch: Epoch,
+        start_milli: i64,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            ConsensusManagerField::EpochHistory.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut epoch_history: EpochHistorySubstate = api.field_lock_read_typed(handle)?;
+        epoch_history.record_epoch_start(epoch, start_milli);
+        api.field_lock_write_typed(handle, &epoch_history)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
     fn epoch_minute_to_instant(epoch_minute: i32) -> Instant {
         Instant::new(epoch_minute as i64 * SECONDS_IN_MINUTE)
     }
@@ -511,6 +625,7 @@ impl ConsensusManagerBlueprint {
             } => {
                 let next_epoch = manager_substate.epoch.next();
                 Self::epoch_change(next_epoch, config, api)?;
+                Self::record_epoch_start(next_epoch, proposer_timestamp_milli, api)?;
                 manager_substate.epoch = next_epoch;
                 manager_substate.round = Round::zero();
                 manager_substate.actual_epoch_start_milli = proposer_timestamp_milli;
@@ -19,6 +19,7 @@ use radix_engine_interface::schema::{
 use super::*;
 
 pub const VALIDATOR_ROLE: &str = "validator";
+pub const GOVERNANCE_ROLE: &str = "governance";
 
 pub struct ConsensusManagerNativePackage;
 
@@ -50,6 +51,15 @@ impl ConsensusManagerNativePackage {
             fields.push(FieldSchema::static_field(
                 aggregator.add_child_type_and_descendents::<ProposerMilliTimestampSubstate>(),
             ));
+            fields.push(FieldSchema::static_field(
+                aggregator.add_child_type_and_descendents::<EpochHistorySubstate>(),
+            ));
+            fields.push(FieldSchema::static_field(
+                aggregator.add_child_type_and_descendents::<InvocationFilterSubstate>(),
+            ));
+            fields.push(FieldSchema::static_field(
+                aggregator.add_child_type_and_descendents::<InstantiationDepositsSubstate>(),
+            ));
 
             let mut collections = Vec::new();
             collections.push(BlueprintCollectionSchema::SortedIndex(
@@ -124,6 +134,30 @@ impl ConsensusManagerNativePackage {
                     export: CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerGetEpochHistoryInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerGetEpochHistoryOutput>()),
+                    export: CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerSetInvocationFilterInput>(
+                        )),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerSetInvocationFilterOutput>(
+                        )),
+                    export: CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -192,14 +226,17 @@ impl ConsensusManagerNativePackage {
                     method_auth: MethodAuthTemplate::StaticRoles(roles_template!(
                         roles {
                             VALIDATOR_ROLE;
+                            GOVERNANCE_ROLE;
                         },
                         methods {
                             CONSENSUS_MANAGER_START_IDENT => []; // Genesis is able to call this by skipping auth
                             CONSENSUS_MANAGER_NEXT_ROUND_IDENT => [VALIDATOR_ROLE];
+                            CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT => [GOVERNANCE_ROLE];
 
                             CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT => MethodAccessibility::Public;
                             CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => MethodAccessibility::Public;
                             CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT => MethodAccessibility::Public;
+                            CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT => MethodAccessibility::Public;
                             CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT => MethodAccessibility::Public;
                         }
                     )),
@@ -560,6 +597,24 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT => {
+                let _input: ConsensusManagerGetEpochHistoryInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::get_epoch_history(api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            CONSENSUS_MANAGER_SET_INVOCATION_FILTER_IDENT => {
+                let input: ConsensusManagerSetInvocationFilterInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = ConsensusManagerBlueprint::set_invocation_filter(input.filter, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             CONSENSUS_MANAGER_NEXT_ROUND_IDENT => {
                 let input: ConsensusManagerNextRoundInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
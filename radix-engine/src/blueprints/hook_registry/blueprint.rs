@@ -0,0 +1,221 @@
+use crate::blueprints::hook_registry::*;
+use crate::errors::*;
+use crate::types::*;
+use native_sdk::modules::access_rules::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::key_value_entry_api::KeyValueEntryHandle;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::hook_registry::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::*;
+
+/// The collection mapping an event name to the hooks registered for it, in registration order.
+pub const HOOK_REGISTRY_HOOKS_INDEX: CollectionIndex = 0u8;
+
+/// A registry of (event name -> component method) hooks, implementing the observer pattern.
+///
+/// Components register their own methods as hooks, and holders of the invoker role call all hooks
+/// of an event at once. The hooks of an event are isolated from one another: each is called in its
+/// own call frame with its own copy of the payload, must return `()`, and is authorized only by
+/// the registry's own global caller badge rather than by the invoker's auth zone.
+///
+/// The engine can't roll back a failed call frame, so a hook which fails still fails the
+/// transaction invoking it.
+pub struct HookRegistryBlueprint;
+
+impl HookRegistryBlueprint {
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        hook_invoker_rule: AccessRule,
+        api: &mut Y,
+    ) -> Result<HookRegistryInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let access_rules = AccessRules::create(
+            owner_role,
+            btreemap! {
+                ObjectModuleId::Main => roles_init! {
+                    RoleKey { key: HOOK_REGISTRY_INVOKER_ROLE.to_owned() } => hook_invoker_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = api.new_simple_object(HOOK_REGISTRY_BLUEPRINT, vec![])?;
+
+        let address = api.globalize(
+            btreemap!(
+                ObjectModuleId::Main => object_id,
+                ObjectModuleId::AccessRules => access_rules.0,
+                ObjectModuleId::Metadata => metadata.0,
+                ObjectModuleId::Royalty => royalty.0,
+            ),
+            None,
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    /// Registers a hook, which only the receiving component itself may do.
+    pub fn register_hook<Y>(
+        event_name: String,
+        hook: RegisteredHook,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_names(&event_name, &hook)?;
+        Self::assert_called_by_receiver(&hook, api)?;
+
+        let handle = Self::open_hooks(&event_name, LockFlags::MUTABLE, api)?;
+        let mut hooks: Vec<RegisteredHook> = api
+            .key_value_entry_get_typed::<Vec<RegisteredHook>>(handle)?
+            .unwrap_or_default();
+        if hooks.contains(&hook) {
+            return Err(HookRegistryError::HookAlreadyRegistered(hook).into());
+        }
+        if hooks.len() >= MAX_HOOKS_PER_EVENT {
+            return Err(HookRegistryError::TooManyHooks {
+                event_name,
+                max: MAX_HOOKS_PER_EVENT,
+            }
+            .into());
+        }
+        hooks.push(hook);
+        api.key_value_entry_set_typed(handle, &hooks)?;
+        api.key_value_entry_release(handle)?;
+
+        Ok(())
+    }
+
+    /// Unregisters a hook, which only the receiving component itself may do.
+    pub fn unregister_hook<Y>(
+        event_name: String,
+        hook: RegisteredHook,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_called_by_receiver(&hook, api)?;
+
+        let handle = Self::open_hooks(&event_name, LockFlags::MUTABLE, api)?;
+        let mut hooks: Vec<RegisteredHook> = api
+            .key_value_entry_get_typed::<Vec<RegisteredHook>>(handle)?
+            .unwrap_or_default();
+        let index = hooks
+            .iter()
+            .position(|registered| registered.eq(&hook))
+            .ok_or_else(|| HookRegistryError::HookNotRegistered(hook))?;
+        hooks.remove(index);
+
+        if hooks.is_empty() {
+            api.key_value_entry_release(handle)?;
+            api.actor_remove_key_value_entry(
+                OBJECT_HANDLE_SELF,
+                HOOK_REGISTRY_HOOKS_INDEX,
+                &scrypto_encode(&event_name).unwrap(),
+            )?;
+        } else {
+            api.key_value_entry_set_typed(handle, &hooks)?;
+            api.key_value_entry_release(handle)?;
+        }
+
+        Ok(())
+    }
+
+    /// Calls every hook registered for the event, in registration order, returning the hooks
+    /// which were called.
+    ///
+    /// The hooks are read before any of them is called, so hooks (un)registered by a hook only
+    /// take effect from the next invocation.
+    pub fn invoke_hooks<Y>(
+        event_name: String,
+        payload: Vec<u8>,
+        api: &mut Y,
+    ) -> Result<HookRegistryInvokeHooksOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let hooks = Self::get_hooks(event_name.clone(), api)?;
+
+        for hook in hooks.iter() {
+            let rtn = api.call_method(
+                hook.receiver.as_node_id(),
+                hook.method_name.as_str(),
+                scrypto_args!(event_name.clone(), payload.clone()),
+            )?;
+            if scrypto_decode::<()>(&rtn).is_err() {
+                return Err(HookRegistryError::HookReturnedValue(hook.clone()).into());
+            }
+        }
+
+        Ok(hooks)
+    }
+
+    pub fn get_hooks<Y>(
+        event_name: String,
+        api: &mut Y,
+    ) -> Result<HookRegistryGetHooksOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = Self::open_hooks(&event_name, LockFlags::read_only(), api)?;
+        let hooks = api
+            .key_value_entry_get_typed::<Vec<RegisteredHook>>(handle)?
+            .unwrap_or_default();
+        api.key_value_entry_release(handle)?;
+
+        Ok(hooks)
+    }
+
+    fn open_hooks<Y>(
+        event_name: &String,
+        flags: LockFlags,
+        api: &mut Y,
+    ) -> Result<KeyValueEntryHandle, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        api.actor_open_key_value_entry(
+            OBJECT_HANDLE_SELF,
+            HOOK_REGISTRY_HOOKS_INDEX,
+            &scrypto_encode(event_name).unwrap(),
+            flags,
+        )
+    }
+
+    fn validate_names(event_name: &str, hook: &RegisteredHook) -> Result<(), RuntimeError> {
+        if event_name.len() > MAX_HOOK_EVENT_NAME_LENGTH {
+            return Err(HookRegistryError::EventNameTooLong {
+                length: event_name.len(),
+                max: MAX_HOOK_EVENT_NAME_LENGTH,
+            }
+            .into());
+        }
+        if hook.method_name.len() > MAX_HOOK_METHOD_NAME_LENGTH {
+            return Err(HookRegistryError::MethodNameTooLong {
+                length: hook.method_name.len(),
+                max: MAX_HOOK_METHOD_NAME_LENGTH,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn assert_called_by_receiver<Y>(hook: &RegisteredHook, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(
+            rule!(require(global_caller(GlobalAddress::from(hook.receiver)))),
+            api,
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::types::*;
+use radix_engine_interface::blueprints::hook_registry::RegisteredHook;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum HookRegistryError {
+    EventNameTooLong {
+        length: usize,
+        max: usize,
+    },
+    MethodNameTooLong {
+        length: usize,
+        max: usize,
+    },
+    TooManyHooks {
+        event_name: String,
+        max: usize,
+    },
+    HookAlreadyRegistered(RegisteredHook),
+    HookNotRegistered(RegisteredHook),
+    /// Hooks must return `()`, so that no value, and in particular no resource, can be handed
+    /// from one hook to the invoker or to another hook.
+    HookReturnedValue(RegisteredHook),
+}
+
+impl From<HookRegistryError> for RuntimeError {
+    fn from(error: HookRegistryError) -> Self {
+        Self::ApplicationError(ApplicationError::HookRegistryError(error))
+    }
+}
@@ -0,0 +1,203 @@
+use crate::blueprints::hook_registry::*;
+use crate::errors::{ApplicationError, RuntimeError};
+use crate::roles_template;
+use crate::types::*;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::hook_registry::*;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+    PackageDefinition,
+};
+use radix_engine_interface::blueprints::resource::MethodAccessibility;
+use radix_engine_interface::schema::{
+    BlueprintCollectionSchema, BlueprintEventSchemaInit, BlueprintFunctionsSchemaInit,
+    FunctionSchemaInit, ReceiverInfo, TypeRef,
+};
+use radix_engine_interface::schema::{BlueprintSchemaInit, BlueprintStateSchemaInit};
+
+pub struct HookRegistryNativePackage;
+
+impl HookRegistryNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+        let fields = Vec::new();
+
+        let mut collections = Vec::new();
+        collections.push(BlueprintCollectionSchema::KeyValueStore(
+            BlueprintKeyValueStoreSchema {
+                key: TypeRef::Static(aggregator.add_child_type_and_descendents::<String>()),
+                value: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<Vec<RegisteredHook>>(),
+                ),
+                can_own: false,
+            },
+        ));
+
+        let mut functions = BTreeMap::new();
+        functions.insert(
+            HOOK_REGISTRY_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryInstantiateOutput>(),
+                ),
+                export: HOOK_REGISTRY_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            HOOK_REGISTRY_REGISTER_HOOK_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryRegisterHookInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryRegisterHookOutput>(),
+                ),
+                export: HOOK_REGISTRY_REGISTER_HOOK_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            HOOK_REGISTRY_UNREGISTER_HOOK_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryUnregisterHookInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryUnregisterHookOutput>(),
+                ),
+                export: HOOK_REGISTRY_UNREGISTER_HOOK_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            HOOK_REGISTRY_INVOKE_HOOKS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryInvokeHooksInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryInvokeHooksOutput>(),
+                ),
+                export: HOOK_REGISTRY_INVOKE_HOOKS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            HOOK_REGISTRY_GET_HOOKS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryGetHooksInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<HookRegistryGetHooksOutput>(),
+                ),
+                export: HOOK_REGISTRY_GET_HOOKS_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let schema = generate_full_schema(aggregator);
+        let blueprints = btreemap!(
+            HOOK_REGISTRY_BLUEPRINT.to_string() => BlueprintDefinitionInit {
+                blueprint_type: BlueprintType::default(),
+                dependencies: btreeset!(),
+                feature_set: btreeset!(),
+                schema: BlueprintSchemaInit {
+                    generics: vec![],
+                    schema,
+                    state: BlueprintStateSchemaInit {
+                        fields,
+                        collections,
+                    },
+                    events: BlueprintEventSchemaInit::default(),
+                    functions: BlueprintFunctionsSchemaInit {
+                        virtual_lazy_load_functions: btreemap!(),
+                        functions,
+                    },
+                },
+
+                royalty_config: PackageRoyaltyConfig::default(),
+                auth_config: AuthConfig {
+                    function_auth: FunctionAuth::AllowAll,
+                    method_auth: MethodAuthTemplate::StaticRoles(roles_template! {
+                        roles {
+                            HOOK_REGISTRY_INVOKER_ROLE;
+                        },
+                        methods {
+                            // Registration is authorized by the blueprint against the hook receiver
+                            HOOK_REGISTRY_REGISTER_HOOK_IDENT => MethodAccessibility::Public;
+                            HOOK_REGISTRY_UNREGISTER_HOOK_IDENT => MethodAccessibility::Public;
+                            HOOK_REGISTRY_GET_HOOKS_IDENT => MethodAccessibility::Public;
+                            HOOK_REGISTRY_INVOKE_HOOKS_IDENT => [HOOK_REGISTRY_INVOKER_ROLE];
+                        }
+                    }),
+                },
+            }
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match export_name {
+            HOOK_REGISTRY_INSTANTIATE_EXPORT_NAME => {
+                let HookRegistryInstantiateInput {
+                    owner_role,
+                    hook_invoker_rule,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = HookRegistryBlueprint::instantiate(owner_role, hook_invoker_rule, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            HOOK_REGISTRY_REGISTER_HOOK_EXPORT_NAME => {
+                let HookRegistryRegisterHookInput { event_name, hook } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = HookRegistryBlueprint::register_hook(event_name, hook, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            HOOK_REGISTRY_UNREGISTER_HOOK_EXPORT_NAME => {
+                let HookRegistryUnregisterHookInput { event_name, hook } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = HookRegistryBlueprint::unregister_hook(event_name, hook, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            HOOK_REGISTRY_INVOKE_HOOKS_EXPORT_NAME => {
+                let HookRegistryInvokeHooksInput {
+                    event_name,
+                    payload,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = HookRegistryBlueprint::invoke_hooks(event_name, payload, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            HOOK_REGISTRY_GET_HOOKS_EXPORT_NAME => {
+                let HookRegistryGetHooksInput { event_name } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = HookRegistryBlueprint::get_hooks(event_name, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
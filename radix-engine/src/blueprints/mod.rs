@@ -1,9 +1,11 @@
 pub mod access_controller;
 pub mod account;
 pub mod consensus_manager;
+pub mod hook_registry;
 pub mod identity;
 pub mod native_schema;
 pub mod package;
+pub mod payment_stream;
 pub mod pool;
 pub mod resource;
 pub mod transaction_processor;
@@ -4,8 +4,10 @@ use radix_engine_interface::blueprints::package::*;
 use crate::blueprints::access_controller::*;
 use crate::blueprints::account::*;
 use crate::blueprints::consensus_manager::*;
+use crate::blueprints::hook_registry::HookRegistryNativePackage;
 use crate::blueprints::identity::*;
 use crate::blueprints::package::*;
+use crate::blueprints::payment_stream::PaymentStreamNativePackage;
 use crate::blueprints::pool::*;
 use crate::blueprints::resource::*;
 use crate::blueprints::transaction_processor::*;
@@ -26,6 +28,10 @@ lazy_static! {
     pub static ref POOL_PACKAGE_DEFINITION: PackageDefinition = PoolNativePackage::definition();
     pub static ref TRANSACTION_TRACKER_PACKAGE_DEFINITION: PackageDefinition =
         TransactionTrackerNativePackage::definition();
+    pub static ref HOOK_REGISTRY_PACKAGE_DEFINITION: PackageDefinition =
+        HookRegistryNativePackage::definition();
+    pub static ref PAYMENT_STREAM_PACKAGE_DEFINITION: PackageDefinition =
+        PaymentStreamNativePackage::definition();
     pub static ref RESOURCE_PACKAGE_DEFINITION: PackageDefinition =
         ResourceNativePackage::definition();
     pub static ref PACKAGE_PACKAGE_DEFINITION: PackageDefinition =
@@ -0,0 +1,137 @@
+use crate::types::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::schema::{FunctionSchemaInit, Receiver, TypeRef};
+use sbor::LocalTypeIndex;
+
+/// A reason for a method to be flagged by the auth coverage analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum AuthCoverageFinding {
+    /// The method takes `&mut self`, so anyone can mutate the component state.
+    MutatesState,
+    /// The method returns buckets, so anyone can take resources out of the component.
+    ReturnsResources,
+    /// The method accepts buckets, so anyone can move resources into the component.
+    AcceptsResources,
+}
+
+/// A method which can be called without any auth and which was flagged by the analysis.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct UnprotectedMethod {
+    pub blueprint: String,
+    pub method: String,
+    pub findings: Vec<AuthCoverageFinding>,
+}
+
+/// The result of statically analysing the declared method auth of a package.
+///
+/// Only what is known at publish time is considered: methods mapped to roles are treated as
+/// protected, even though the roles themselves may later be set to `AllowAll` by an instance.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor, ScryptoEvent)]
+pub struct PackageAuthCoverageReportEvent {
+    pub unprotected_methods: Vec<UnprotectedMethod>,
+}
+
+impl PackageAuthCoverageReportEvent {
+    pub fn is_fully_covered(&self) -> bool {
+        self.unprotected_methods.is_empty()
+    }
+}
+
+pub struct AuthCoverageAnalyzer;
+
+impl AuthCoverageAnalyzer {
+    pub fn analyze(definition: &PackageDefinition) -> PackageAuthCoverageReportEvent {
+        let mut unprotected_methods = Vec::new();
+
+        for (blueprint, blueprint_init) in &definition.blueprints {
+            for (method, function_schema) in &blueprint_init.schema.functions.functions {
+                let Some(receiver_info) = &function_schema.receiver else {
+                    continue;
+                };
+                if !Self::is_public(&blueprint_init.auth_config.method_auth, method) {
+                    continue;
+                }
+
+                let findings = Self::findings(
+                    &blueprint_init.schema.schema,
+                    function_schema,
+                    receiver_info.receiver.clone(),
+                );
+                if !findings.is_empty() {
+                    unprotected_methods.push(UnprotectedMethod {
+                        blueprint: blueprint.clone(),
+                        method: method.clone(),
+                        findings,
+                    });
+                }
+            }
+        }
+
+        PackageAuthCoverageReportEvent {
+            unprotected_methods,
+        }
+    }
+
+    fn is_public(method_auth: &MethodAuthTemplate, method: &str) -> bool {
+        match method_auth {
+            MethodAuthTemplate::AllowAll => true,
+            MethodAuthTemplate::StaticRoles(static_roles) => matches!(
+                static_roles.methods.get(&MethodKey::new(method)),
+                Some(MethodAccessibility::Public)
+            ),
+        }
+    }
+
+    fn findings(
+        schema: &ScryptoSchema,
+        function_schema: &FunctionSchemaInit,
+        receiver: Receiver,
+    ) -> Vec<AuthCoverageFinding> {
+        let mut findings = Vec::new();
+        if receiver == Receiver::SelfRefMut {
+            findings.push(AuthCoverageFinding::MutatesState);
+        }
+        if let TypeRef::Static(output) = function_schema.output {
+            if Self::contains_bucket(schema, output, &mut index_set_new()) {
+                findings.push(AuthCoverageFinding::ReturnsResources);
+            }
+        }
+        if let TypeRef::Static(input) = function_schema.input {
+            if Self::contains_bucket(schema, input, &mut index_set_new()) {
+                findings.push(AuthCoverageFinding::AcceptsResources);
+            }
+        }
+        findings
+    }
+
+    fn contains_bucket(
+        schema: &ScryptoSchema,
+        type_index: LocalTypeIndex,
+        visited: &mut IndexSet<LocalTypeIndex>,
+    ) -> bool {
+        if !visited.insert(type_index) {
+            return false;
+        }
+
+        if let Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+            OwnValidation::IsBucket,
+        ))) = schema.resolve_type_validation(type_index)
+        {
+            return true;
+        }
+
+        let children = match schema.resolve_type_kind(type_index) {
+            Some(TypeKind::Array { element_type }) => vec![*element_type],
+            Some(TypeKind::Tuple { field_types }) => field_types.clone(),
+            Some(TypeKind::Enum { variants }) => variants.values().flatten().cloned().collect(),
+            Some(TypeKind::Map {
+                key_type,
+                value_type,
+            }) => vec![*key_type, *value_type],
+            _ => vec![],
+        };
+        children
+            .into_iter()
+            .any(|child| Self::contains_bucket(schema, child, visited))
+    }
+}
@@ -1,3 +1,5 @@
+mod auth_coverage;
 mod package;
 
+pub use auth_coverage::*;
 pub use package::*;
@@ -7,12 +7,13 @@ use crate::system::node_modules::type_info::TypeInfoSubstate;
 use crate::system::system_modules::costing::{apply_royalty_cost, RoyaltyRecipient};
 use crate::track::interface::NodeSubstates;
 use crate::types::*;
-use crate::vm::wasm::PrepareError;
+use crate::vm::wasm::{PrepareError, WasmInstrumentationCache};
 use native_sdk::modules::access_rules::AccessRules;
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::royalty::ComponentRoyalty;
 use native_sdk::resource::NativeVault;
 use native_sdk::resource::ResourceManager;
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::{
     ClientApi, ClientObjectApi, KVEntry, LockFlags, ObjectModuleId, OBJECT_HANDLE_SELF,
@@ -20,14 +21,12 @@ use radix_engine_interface::api::{
 pub use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::{require, Bucket};
 use radix_engine_interface::schema::{
-    BlueprintCollectionSchema, BlueprintEventSchemaInit, BlueprintFunctionsSchemaInit,
-    BlueprintKeyValueStoreSchema, BlueprintSchemaInit, BlueprintStateSchemaInit, FieldSchema,
-    FunctionSchemaInit, TypeRef,
+    BlueprintCollectionSchema, BlueprintFunctionsSchemaInit, BlueprintKeyValueStoreSchema,
+    BlueprintSchemaInit, BlueprintStateSchemaInit, FieldSchema, FunctionSchemaInit, TypeRef,
 };
 use sbor::LocalTypeIndex;
 
 // Import and re-export substate types
-use crate::roles_template;
 use crate::system::node_modules::access_rules::AccessRulesNativePackage;
 use crate::system::node_modules::royalty::RoyaltyUtil;
 use crate::system::system::{KeyValueEntrySubstate, SubstateMutability, SystemService};
@@ -35,6 +34,7 @@ use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_modules::auth::{AuthError, ResolvedPermission};
 use crate::vm::VmPackageValidation;
+use crate::{event_schema, roles_template};
 pub use radix_engine_interface::blueprints::package::{
     PackageInstrumentedCodeSubstate, PackageOriginalCodeSubstate, PackageRoyaltyAccumulatorSubstate,
 };
@@ -921,6 +921,11 @@ impl PackageNativePackage {
             },
         );
 
+        let event_schema = event_schema! {
+            aggregator,
+            [PackageAuthCoverageReportEvent]
+        };
+
         let schema = generate_full_schema(aggregator);
         let blueprints = btreemap!(
             PACKAGE_BLUEPRINT.to_string() => BlueprintDefinitionInit {
@@ -940,7 +945,7 @@ impl PackageNativePackage {
                         fields,
                         collections,
                     },
-                    events: BlueprintEventSchemaInit::default(),
+                    events: event_schema,
                     functions: BlueprintFunctionsSchemaInit {
                         virtual_lazy_load_functions: btreemap!(),
                         functions,
@@ -976,6 +981,7 @@ impl PackageNativePackage {
     pub fn invoke_export<Y>(
         export_name: &str,
         input: &IndexedScryptoValue,
+        wasm_instrumentation_cache: Option<&dyn WasmInstrumentationCache>,
         api: &mut Y,
     ) -> Result<IndexedScryptoValue, RuntimeError>
     where
@@ -1002,7 +1008,13 @@ impl PackageNativePackage {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
 
-                let rtn = Self::publish_wasm(input.code, input.definition, input.metadata, api)?;
+                let rtn = Self::publish_wasm(
+                    input.code,
+                    input.definition,
+                    input.metadata,
+                    wasm_instrumentation_cache,
+                    api,
+                )?;
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
@@ -1017,6 +1029,7 @@ impl PackageNativePackage {
                     input.definition,
                     input.metadata,
                     input.owner_role,
+                    wasm_instrumentation_cache,
                     api,
                 )?;
 
@@ -1039,6 +1052,7 @@ impl PackageNativePackage {
         definition: PackageDefinition,
         vm_type: VmType,
         original_code: Vec<u8>,
+        wasm_instrumentation_cache: Option<&dyn WasmInstrumentationCache>,
     ) -> Result<PackageStructure, RuntimeError> {
         // Validate schema
         validate_package_schema(definition.blueprints.values().map(|s| &s.schema))
@@ -1049,8 +1063,12 @@ impl PackageNativePackage {
             .map_err(|e| RuntimeError::ApplicationError(ApplicationError::PackageError(e)))?;
 
         // Validate VM specific properties
-        let instrumented_code =
-            VmPackageValidation::validate(&definition, vm_type, &original_code)?;
+        let instrumented_code = VmPackageValidation::validate(
+            &definition,
+            vm_type,
+            &original_code,
+            wasm_instrumentation_cache,
+        )?;
 
         // Build Package structure
         let mut definitions = BTreeMap::new();
@@ -1199,6 +1217,7 @@ impl PackageNativePackage {
             definition,
             VmType::Native,
             native_package_code_id.to_be_bytes().to_vec(),
+            None,
         )?;
         let access_rules = AccessRules::create(OwnerRole::None, btreemap!(), api)?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
@@ -1216,14 +1235,20 @@ impl PackageNativePackage {
         code: Vec<u8>,
         definition: PackageDefinition,
         metadata_init: MetadataInit,
+        wasm_instrumentation_cache: Option<&dyn WasmInstrumentationCache>,
         api: &mut Y,
     ) -> Result<(PackageAddress, Bucket), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
         validate_royalties(&definition, api)?;
-        let package_structure =
-            Self::validate_and_build_package_structure(definition, VmType::ScryptoV1, code)?;
+        let auth_coverage_report = AuthCoverageAnalyzer::analyze(&definition);
+        let package_structure = Self::validate_and_build_package_structure(
+            definition,
+            VmType::ScryptoV1,
+            code,
+            wasm_instrumentation_cache,
+        )?;
 
         let (address_reservation, address) = api.allocate_global_address(BlueprintId {
             package_address: PACKAGE_PACKAGE,
@@ -1248,6 +1273,8 @@ impl PackageNativePackage {
             api,
         )?;
 
+        Runtime::emit_event(api, auth_coverage_report)?;
+
         Ok((address, bucket))
     }
 
@@ -1257,24 +1284,34 @@ impl PackageNativePackage {
         definition: PackageDefinition,
         metadata_init: MetadataInit,
         owner_role: OwnerRole,
+        wasm_instrumentation_cache: Option<&dyn WasmInstrumentationCache>,
         api: &mut Y,
     ) -> Result<PackageAddress, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
         validate_royalties(&definition, api)?;
-        let package_structure =
-            Self::validate_and_build_package_structure(definition, VmType::ScryptoV1, code)?;
+        let auth_coverage_report = AuthCoverageAnalyzer::analyze(&definition);
+        let package_structure = Self::validate_and_build_package_structure(
+            definition,
+            VmType::ScryptoV1,
+            code,
+            wasm_instrumentation_cache,
+        )?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
         let access_rules = SecurifiedPackage::create_advanced(owner_role, api)?;
 
-        globalize_package(
+        let address = globalize_package(
             package_address,
             package_structure,
             metadata,
             access_rules,
             api,
-        )
+        )?;
+
+        Runtime::emit_event(api, auth_coverage_report)?;
+
+        Ok(address)
     }
 }
 
@@ -0,0 +1,223 @@
+use crate::blueprints::payment_stream::*;
+use crate::errors::*;
+use native_sdk::modules::access_rules::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::payment_stream::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::types::*;
+use radix_engine_interface::*;
+
+/// A stream of a fungible resource from a sender to a recipient.
+///
+/// The sender locks the streamed amount in the stream when creating it, and at the start of each
+/// following epoch another `rate_per_epoch` of it accrues to the recipient, until all of it has.
+/// The recipient can withdraw whatever has accrued at any time. The sender can cancel the stream
+/// at any time, which settles it pro-rata: the part which hasn't accrued yet is returned to the
+/// sender, while the part which has stays withdrawable by the recipient.
+pub struct PaymentStreamBlueprint;
+
+impl PaymentStreamBlueprint {
+    pub fn instantiate<Y>(
+        bucket: Bucket,
+        rate_per_epoch: Decimal,
+        sender_rule: AccessRule,
+        recipient_rule: AccessRule,
+        api: &mut Y,
+    ) -> Result<PaymentStreamInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resource_address = bucket.resource_address(api)?;
+        let divisibility = match ResourceManager(resource_address).resource_type(api)? {
+            ResourceType::Fungible { divisibility } => divisibility,
+            ResourceType::NonFungible { .. } => {
+                return Err(PaymentStreamError::NonFungibleResourcesAreNotAccepted {
+                    resource_address,
+                }
+                .into())
+            }
+        };
+        if !rate_per_epoch.is_positive() {
+            return Err(PaymentStreamError::InvalidRatePerEpoch(rate_per_epoch).into());
+        }
+        let total_amount = bucket.amount(api)?;
+        if total_amount.is_zero() {
+            return Err(PaymentStreamError::EmptyStream.into());
+        }
+
+        let access_rules = AccessRules::create(
+            OwnerRole::None,
+            btreemap! {
+                ObjectModuleId::Main => roles_init! {
+                    RoleKey { key: PAYMENT_STREAM_SENDER_ROLE.to_owned() } => sender_rule;
+                    RoleKey { key: PAYMENT_STREAM_RECIPIENT_ROLE.to_owned() } => recipient_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let mut vault = Vault::create(resource_address, api)?;
+            vault.put(bucket, api)?;
+            let substate = PaymentStreamSubstate {
+                vault,
+                divisibility,
+                rate_per_epoch,
+                start_epoch: Runtime::current_epoch(api)?,
+                total_amount,
+                withdrawn_amount: Decimal::ZERO,
+                cancelled_at_epoch: None,
+            };
+            api.new_simple_object(
+                PAYMENT_STREAM_BLUEPRINT,
+                vec![scrypto_encode(&substate).unwrap()],
+            )?
+        };
+
+        let address = api.globalize(
+            btreemap!(
+                ObjectModuleId::Main => object_id,
+                ObjectModuleId::AccessRules => access_rules.0,
+                ObjectModuleId::Metadata => metadata.0,
+                ObjectModuleId::Royalty => royalty.0,
+            ),
+            None,
+        )?;
+
+        Ok(ComponentAddress::new_or_panic(address.as_node_id().0))
+    }
+
+    /// Withdraws everything which has accrued to the recipient and hasn't been withdrawn yet.
+    pub fn withdraw<Y>(api: &mut Y) -> Result<PaymentStreamWithdrawOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+
+        let current_epoch = Runtime::current_epoch(api)?;
+        let amount = Self::accrued_amount(&substate, current_epoch) - substate.withdrawn_amount;
+        let bucket = substate.vault.take(amount, api)?;
+        substate.withdrawn_amount += amount;
+
+        api.field_lock_write_typed(handle, &substate)?;
+        api.field_lock_release(handle)?;
+
+        Runtime::emit_event(api, WithdrawEvent { amount })?;
+
+        Ok(bucket)
+    }
+
+    /// Stops the stream, returning the amount which hasn't accrued to the recipient yet.
+    pub fn cancel<Y>(api: &mut Y) -> Result<PaymentStreamCancelOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (mut substate, handle) = Self::lock_and_read(api, LockFlags::MUTABLE)?;
+        if let Some(cancelled_at_epoch) = substate.cancelled_at_epoch {
+            return Err(PaymentStreamError::StreamAlreadyCancelled { cancelled_at_epoch }.into());
+        }
+
+        let current_epoch = Runtime::current_epoch(api)?;
+        let accrued_amount = Self::accrued_amount(&substate, current_epoch);
+        let refunded_amount = substate.total_amount - accrued_amount;
+        let bucket = substate.vault.take(refunded_amount, api)?;
+        substate.cancelled_at_epoch = Some(current_epoch);
+
+        api.field_lock_write_typed(handle, &substate)?;
+        api.field_lock_release(handle)?;
+
+        Runtime::emit_event(
+            api,
+            CancelEvent {
+                accrued_amount,
+                refunded_amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_withdrawable_amount<Y>(
+        api: &mut Y,
+    ) -> Result<PaymentStreamGetWithdrawableAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+        api.field_lock_release(handle)?;
+
+        let current_epoch = Runtime::current_epoch(api)?;
+        Ok(Self::accrued_amount(&substate, current_epoch) - substate.withdrawn_amount)
+    }
+
+    pub fn get_info<Y>(api: &mut Y) -> Result<PaymentStreamGetInfoOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (substate, handle) = Self::lock_and_read(api, LockFlags::read_only())?;
+        let resource_address = substate.vault.resource_address(api)?;
+        api.field_lock_release(handle)?;
+
+        Ok(PaymentStreamInfo {
+            resource_address,
+            rate_per_epoch: substate.rate_per_epoch,
+            start_epoch: substate.start_epoch,
+            total_amount: substate.total_amount,
+            withdrawn_amount: substate.withdrawn_amount,
+            cancelled_at_epoch: substate.cancelled_at_epoch,
+        })
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    /// The amount which has accrued to the recipient by the given epoch, including any amount
+    /// already withdrawn, rounded down to the divisibility of the resource.
+    fn accrued_amount(substate: &PaymentStreamSubstate, current_epoch: Epoch) -> Decimal {
+        let end_epoch = substate.cancelled_at_epoch.unwrap_or(current_epoch);
+        let elapsed_epochs = Decimal::from(
+            end_epoch
+                .number()
+                .saturating_sub(substate.start_epoch.number()),
+        );
+
+        // A rate of at least one can only be multiplied by the elapsed epochs without overflowing
+        // while the total amount hasn't fully accrued, so that's checked first.
+        if substate.rate_per_epoch >= Decimal::ONE
+            && elapsed_epochs > substate.total_amount / substate.rate_per_epoch
+        {
+            return substate.total_amount;
+        }
+
+        let accrued_amount = (substate.rate_per_epoch * elapsed_epochs)
+            .round(substate.divisibility, RoundingMode::ToNegativeInfinity);
+        if accrued_amount > substate.total_amount {
+            substate.total_amount
+        } else {
+            accrued_amount
+        }
+    }
+
+    fn lock_and_read<Y>(
+        api: &mut Y,
+        lock_flags: LockFlags,
+    ) -> Result<(PaymentStreamSubstate, LockHandle), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let substate_key = PaymentStreamField::PaymentStream.into();
+        let handle = api.actor_open_field(OBJECT_HANDLE_SELF, substate_key, lock_flags)?;
+        let substate = api.field_lock_read_typed::<PaymentStreamSubstate>(handle)?;
+
+        Ok((substate, handle))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum PaymentStreamError {
+    NonFungibleResourcesAreNotAccepted { resource_address: ResourceAddress },
+    EmptyStream,
+    InvalidRatePerEpoch(Decimal),
+    StreamAlreadyCancelled { cancelled_at_epoch: Epoch },
+}
+
+impl From<PaymentStreamError> for RuntimeError {
+    fn from(error: PaymentStreamError) -> Self {
+        Self::ApplicationError(ApplicationError::PaymentStreamError(error))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::types::*;
+use radix_engine_common::math::Decimal;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, ScryptoEvent)]
+pub struct WithdrawEvent {
+    pub amount: Decimal,
+}
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, ScryptoEvent)]
+pub struct CancelEvent {
+    /// The amount which had accrued to the recipient by the time of the cancellation, including
+    /// any amount already withdrawn.
+    pub accrued_amount: Decimal,
+    /// The amount which had not accrued yet, and was returned to the sender.
+    pub refunded_amount: Decimal,
+}
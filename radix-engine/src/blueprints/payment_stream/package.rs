@@ -0,0 +1,212 @@
+use crate::blueprints::payment_stream::*;
+use crate::errors::{ApplicationError, RuntimeError};
+use crate::event_schema;
+use crate::roles_template;
+use crate::types::*;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+    PackageDefinition,
+};
+use radix_engine_interface::blueprints::payment_stream::*;
+use radix_engine_interface::blueprints::resource::MethodAccessibility;
+use radix_engine_interface::schema::{
+    BlueprintFunctionsSchemaInit, FieldSchema, FunctionSchemaInit, ReceiverInfo, TypeRef,
+};
+use radix_engine_interface::schema::{BlueprintSchemaInit, BlueprintStateSchemaInit};
+
+pub struct PaymentStreamNativePackage;
+
+impl PaymentStreamNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+        let mut fields = Vec::new();
+        fields.push(FieldSchema::static_field(
+            aggregator.add_child_type_and_descendents::<PaymentStreamSubstate>(),
+        ));
+
+        let collections = Vec::new();
+
+        let mut functions = BTreeMap::new();
+        functions.insert(
+            PAYMENT_STREAM_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamInstantiateOutput>(),
+                ),
+                export: PAYMENT_STREAM_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            PAYMENT_STREAM_WITHDRAW_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamWithdrawInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamWithdrawOutput>(),
+                ),
+                export: PAYMENT_STREAM_WITHDRAW_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            PAYMENT_STREAM_CANCEL_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamCancelInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamCancelOutput>(),
+                ),
+                export: PAYMENT_STREAM_CANCEL_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            PAYMENT_STREAM_GET_WITHDRAWABLE_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentStreamGetWithdrawableAmountInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PaymentStreamGetWithdrawableAmountOutput>(
+                        ),
+                ),
+                export: PAYMENT_STREAM_GET_WITHDRAWABLE_AMOUNT_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            PAYMENT_STREAM_GET_INFO_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamGetInfoInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PaymentStreamGetInfoOutput>(),
+                ),
+                export: PAYMENT_STREAM_GET_INFO_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                WithdrawEvent,
+                CancelEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+        let blueprints = btreemap!(
+            PAYMENT_STREAM_BLUEPRINT.to_string() => BlueprintDefinitionInit {
+                blueprint_type: BlueprintType::default(),
+                dependencies: btreeset!(),
+                feature_set: btreeset!(),
+                schema: BlueprintSchemaInit {
+                    generics: vec![],
+                    schema,
+                    state: BlueprintStateSchemaInit {
+                        fields,
+                        collections,
+                    },
+                    events: event_schema,
+                    functions: BlueprintFunctionsSchemaInit {
+                        virtual_lazy_load_functions: btreemap!(),
+                        functions,
+                    },
+                },
+
+                royalty_config: PackageRoyaltyConfig::default(),
+                auth_config: AuthConfig {
+                    function_auth: FunctionAuth::AllowAll,
+                    method_auth: MethodAuthTemplate::StaticRoles(roles_template! {
+                        roles {
+                            PAYMENT_STREAM_SENDER_ROLE;
+                            PAYMENT_STREAM_RECIPIENT_ROLE;
+                        },
+                        methods {
+                            PAYMENT_STREAM_GET_WITHDRAWABLE_AMOUNT_IDENT => MethodAccessibility::Public;
+                            PAYMENT_STREAM_GET_INFO_IDENT => MethodAccessibility::Public;
+                            PAYMENT_STREAM_WITHDRAW_IDENT => [PAYMENT_STREAM_RECIPIENT_ROLE];
+                            PAYMENT_STREAM_CANCEL_IDENT => [PAYMENT_STREAM_SENDER_ROLE];
+                        }
+                    }),
+                },
+            }
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match export_name {
+            PAYMENT_STREAM_INSTANTIATE_EXPORT_NAME => {
+                let PaymentStreamInstantiateInput {
+                    bucket,
+                    rate_per_epoch,
+                    sender_rule,
+                    recipient_rule,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentStreamBlueprint::instantiate(
+                    bucket,
+                    rate_per_epoch,
+                    sender_rule,
+                    recipient_rule,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PAYMENT_STREAM_WITHDRAW_EXPORT_NAME => {
+                let PaymentStreamWithdrawInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentStreamBlueprint::withdraw(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PAYMENT_STREAM_CANCEL_EXPORT_NAME => {
+                let PaymentStreamCancelInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentStreamBlueprint::cancel(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PAYMENT_STREAM_GET_WITHDRAWABLE_AMOUNT_EXPORT_NAME => {
+                let PaymentStreamGetWithdrawableAmountInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentStreamBlueprint::get_withdrawable_amount(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PAYMENT_STREAM_GET_INFO_EXPORT_NAME => {
+                let PaymentStreamGetInfoInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PaymentStreamBlueprint::get_info(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use native_sdk::resource::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct PaymentStreamSubstate {
+    /// The vault of the amount which hasn't been withdrawn by the recipient or refunded to the
+    /// sender yet.
+    pub vault: Vault,
+
+    /// The divisibility of the streamed resource, which accrued amounts are rounded down to.
+    pub divisibility: u8,
+
+    pub rate_per_epoch: Decimal,
+
+    pub start_epoch: Epoch,
+
+    pub total_amount: Decimal,
+
+    pub withdrawn_amount: Decimal,
+
+    pub cancelled_at_epoch: Option<Epoch>,
+}
+
+impl Clone for PaymentStreamSubstate {
+    fn clone(&self) -> Self {
+        Self {
+            vault: Vault(self.vault.0.clone()),
+            divisibility: self.divisibility,
+            rate_per_epoch: self.rate_per_epoch,
+            start_epoch: self.start_epoch,
+            total_amount: self.total_amount,
+            withdrawn_amount: self.withdrawn_amount,
+            cancelled_at_epoch: self.cancelled_at_epoch,
+        }
+    }
+}
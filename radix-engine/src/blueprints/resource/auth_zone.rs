@@ -16,6 +16,10 @@ use super::{compose_proof_by_amount, compose_proof_by_ids, AuthZone, ComposeProo
 pub enum AuthZoneError {
     EmptyAuthZone,
     ComposeProofError(ComposeProofError),
+    /// Proofs can't be taken out of, or composed from, an auth zone with an open scope, as they
+    /// would outlive the scope.
+    ScopeOpen,
+    NoOpenScope,
 }
 
 pub struct AuthZoneBlueprint;
@@ -32,6 +36,7 @@ impl AuthZoneBlueprint {
         )?;
 
         let mut auth_zone: AuthZone = api.field_lock_read_typed(auth_zone_handle)?;
+        Self::assert_no_open_scope(&auth_zone)?;
         let proof = auth_zone.pop().ok_or(RuntimeError::ApplicationError(
             ApplicationError::AuthZoneError(AuthZoneError::EmptyAuthZone),
         ))?;
@@ -76,6 +81,7 @@ impl AuthZoneBlueprint {
 
         let composed_proof = {
             let auth_zone: AuthZone = api.field_lock_read_typed(auth_zone_handle)?;
+            Self::assert_no_open_scope(&auth_zone)?;
             let proofs: Vec<Proof> = auth_zone.proofs.iter().map(|p| Proof(p.0)).collect();
             compose_proof_by_amount(&proofs, resource_address, Some(amount), api)?
         };
@@ -142,6 +148,7 @@ impl AuthZoneBlueprint {
 
         let composed_proof = {
             let auth_zone: AuthZone = api.field_lock_read_typed(auth_zone_handle)?;
+            Self::assert_no_open_scope(&auth_zone)?;
             let proofs: Vec<Proof> = auth_zone.proofs.iter().map(|p| Proof(p.0)).collect();
             compose_proof_by_ids(&proofs, resource_address, Some(ids), api)?
         };
@@ -183,6 +190,7 @@ impl AuthZoneBlueprint {
         )?;
 
         let auth_zone: AuthZone = api.field_lock_read_typed(auth_zone_handle)?;
+        Self::assert_no_open_scope(&auth_zone)?;
         let proofs: Vec<Proof> = auth_zone.proofs.iter().map(|p| Proof(p.0)).collect();
         let composed_proof = compose_proof_by_amount(&proofs, resource_address, None, api)?;
 
@@ -265,6 +273,7 @@ impl AuthZoneBlueprint {
         )?;
 
         let mut auth_zone: AuthZone = api.field_lock_read_typed(auth_zone_handle)?;
+        Self::assert_no_open_scope(&auth_zone)?;
         let proofs = auth_zone.drain();
 
         api.field_lock_write_typed(auth_zone_handle, &auth_zone)?;
@@ -272,6 +281,71 @@ impl AuthZoneBlueprint {
         Ok(proofs)
     }
 
+    pub(crate) fn push_scope<Y>(proofs: Vec<Proof>, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            AuthZoneField::AuthZone.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut auth_zone: AuthZone = api.field_lock_read_typed(handle)?;
+        auth_zone.push_scope(proofs);
+        api.field_lock_write_typed(handle, &auth_zone)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
+    /// Closes the innermost scope, dropping all the proofs pushed since it was opened.
+    pub(crate) fn pop_scope<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            AuthZoneField::AuthZone.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut auth_zone: AuthZone = api.field_lock_read_typed(handle)?;
+        let proofs = auth_zone.pop_scope().ok_or(RuntimeError::ApplicationError(
+            ApplicationError::AuthZoneError(AuthZoneError::NoOpenScope),
+        ))?;
+        api.field_lock_write_typed(handle, &auth_zone)?;
+        api.field_lock_release(handle)?;
+
+        for proof in proofs {
+            proof.drop(api)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn scope_depth<Y>(api: &mut Y) -> Result<u32, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            AuthZoneField::AuthZone.into(),
+            LockFlags::read_only(),
+        )?;
+        let auth_zone: AuthZone = api.field_lock_read_typed(handle)?;
+        api.field_lock_release(handle)?;
+
+        Ok(auth_zone.scope_depth())
+    }
+
+    fn assert_no_open_scope(auth_zone: &AuthZone) -> Result<(), RuntimeError> {
+        if auth_zone.has_open_scope() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::AuthZoneError(AuthZoneError::ScopeOpen),
+            ));
+        }
+        Ok(())
+    }
+
     pub(crate) fn drop<Y>(
         input: &IndexedScryptoValue,
         api: &mut Y,
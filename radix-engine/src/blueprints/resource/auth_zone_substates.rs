@@ -13,6 +13,9 @@ pub struct AuthZone {
 
     pub is_barrier: bool,
     pub parent: Option<Reference>,
+
+    /// The number of proofs below each open scope, innermost last
+    pub scopes: Vec<u32>,
 }
 
 impl Clone for AuthZone {
@@ -27,6 +30,7 @@ impl Clone for AuthZone {
                 .clone(),
             is_barrier: self.is_barrier,
             parent: self.parent.clone(),
+            scopes: self.scopes.clone(),
         }
     }
 }
@@ -49,6 +53,7 @@ impl AuthZone {
             virtual_non_fungibles_non_extending_barrier,
             is_barrier,
             parent,
+            scopes: Vec::new(),
         }
     }
 
@@ -84,6 +89,25 @@ impl AuthZone {
         self.proofs.drain(0..).collect()
     }
 
+    pub fn has_open_scope(&self) -> bool {
+        !self.scopes.is_empty()
+    }
+
+    pub fn scope_depth(&self) -> u32 {
+        self.scopes.len() as u32
+    }
+
+    pub fn push_scope(&mut self, proofs: Vec<Proof>) {
+        self.scopes.push(self.proofs.len() as u32);
+        self.proofs.extend(proofs);
+    }
+
+    /// Closes the innermost scope, returning the proofs pushed since it was opened.
+    pub fn pop_scope(&mut self) -> Option<Vec<Proof>> {
+        let start = self.scopes.pop()? as usize;
+        Some(self.proofs.split_off(start.min(self.proofs.len())))
+    }
+
     pub fn clear_signature_proofs(&mut self) {
         self.virtual_resources.retain(|x| {
             x != &SECP256K1_SIGNATURE_VIRTUAL_BADGE && x != &ED25519_SIGNATURE_VIRTUAL_BADGE
@@ -22,3 +22,21 @@ pub enum RecallResourceEvent {
     Amount(Decimal),
     Ids(BTreeSet<NonFungibleLocalId>),
 }
+
+#[derive(ScryptoSbor, PartialEq, Eq, Debug)]
+pub enum RecalledResource {
+    Amount(Decimal),
+    Ids(BTreeSet<NonFungibleLocalId>),
+}
+
+/// Emitted alongside [`RecallResourceEvent`], recording who recalled what from a vault and why.
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+pub struct RecallAuditEvent {
+    pub vault_id: InternalAddress,
+    pub resource_address: ResourceAddress,
+    pub recalled: RecalledResource,
+    /// The global component or blueprint which called the vault, if any
+    pub recaller: Option<GlobalCaller>,
+    /// The reason given by the recall instruction, if any
+    pub reason: Option<String>,
+}
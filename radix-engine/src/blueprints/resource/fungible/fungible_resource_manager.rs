@@ -9,7 +9,7 @@ use num_traits::pow::Pow;
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::node_modules::ModuleConfig;
-use radix_engine_interface::api::{ClientApi, OBJECT_HANDLE_SELF};
+use radix_engine_interface::api::{ClientApi, CollectionIndex, ObjectHandle, OBJECT_HANDLE_SELF};
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::types::FungibleResourceManagerField;
@@ -31,10 +31,15 @@ pub enum FungibleResourceManagerError {
     DropNonEmptyBucket,
     NotMintable,
     NotBurnable,
+    ReserveTrackingRequiresZeroTrackedSupply,
+    ReserveTrackingNotEnabled,
 }
 
 pub type FungibleResourceManagerDivisibilitySubstate = u8;
 pub type FungibleResourceManagerTotalSupplySubstate = Decimal;
+pub type FungibleResourceManagerReserveTrackingSubstate = bool;
+
+pub const FUNGIBLE_RESOURCE_MANAGER_RESERVES_INDEX: CollectionIndex = 0u8;
 
 pub fn verify_divisibility(divisibility: u8) -> Result<(), RuntimeError> {
     if divisibility > DIVISIBILITY_MAXIMUM {
@@ -108,6 +113,7 @@ impl FungibleResourceManagerBlueprint {
             vec![
                 scrypto_encode(&divisibility).unwrap(),
                 scrypto_encode(&Decimal::zero()).unwrap(),
+                scrypto_encode(&false).unwrap(),
             ],
             btreemap!(),
         )?;
@@ -162,6 +168,7 @@ impl FungibleResourceManagerBlueprint {
             vec![
                 scrypto_encode(&divisibility).unwrap(),
                 scrypto_encode(&initial_supply).unwrap(),
+                scrypto_encode(&false).unwrap(),
             ],
             btreemap!(),
         )?;
@@ -360,6 +367,89 @@ impl FungibleResourceManagerBlueprint {
         }
     }
 
+    /// Starts tracking the vaults holding this resource. As the set of vaults can only be
+    /// reconstructed from the moment tracking is enabled, this requires a tracked total supply
+    /// of zero.
+    pub(crate) fn enable_reserve_tracking<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if Self::get_total_supply(api)? != Some(Decimal::zero()) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::FungibleResourceManagerError(
+                    FungibleResourceManagerError::ReserveTrackingRequiresZeroTrackedSupply,
+                ),
+            ));
+        }
+
+        let handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            FungibleResourceManagerField::ReserveTracking.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_lock_write_typed(handle, &true)?;
+        api.field_lock_release(handle)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn is_reserve_tracking_enabled<Y>(
+        object_handle: ObjectHandle,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            object_handle,
+            FungibleResourceManagerField::ReserveTracking.into(),
+            LockFlags::read_only(),
+        )?;
+        let enabled: FungibleResourceManagerReserveTrackingSubstate =
+            api.field_lock_read_typed(handle)?;
+        api.field_lock_release(handle)?;
+        Ok(enabled)
+    }
+
+    pub(crate) fn proof_of_reserves<Y>(
+        top_holders: u32,
+        api: &mut Y,
+    ) -> Result<ProofOfReserves, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !Self::is_reserve_tracking_enabled(OBJECT_HANDLE_SELF, api)? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::FungibleResourceManagerError(
+                    FungibleResourceManagerError::ReserveTrackingNotEnabled,
+                ),
+            ));
+        }
+
+        let mut holdings: Vec<ReserveHolding> = api.actor_index_scan_typed(
+            OBJECT_HANDLE_SELF,
+            FUNGIBLE_RESOURCE_MANAGER_RESERVES_INDEX,
+            u32::MAX,
+        )?;
+        holdings.sort_by(|a, b| {
+            b.amount
+                .cmp(&a.amount)
+                .then_with(|| a.vault_id.cmp(&b.vault_id))
+        });
+
+        let total_amount = holdings
+            .iter()
+            .fold(Decimal::zero(), |total, holding| total + holding.amount);
+        let holder_count = holdings.len() as u32;
+        holdings.truncate(top_holders as usize);
+
+        Ok(ProofOfReserves {
+            total_amount,
+            holder_count,
+            top_holders: holdings,
+        })
+    }
+
     pub(crate) fn amount_for_withdrawal<Y>(
         api: &mut Y,
         amount: Decimal,
@@ -59,6 +59,7 @@ impl FungibleVaultBlueprint {
 
         // Take
         let taken = Self::internal_take(amount, api)?;
+        Self::update_reserves(-taken.amount(), api)?;
 
         // Create node
         FungibleResourceManagerBlueprint::create_bucket(taken.amount(), api)
@@ -74,7 +75,51 @@ impl FungibleVaultBlueprint {
         let other_bucket = drop_fungible_bucket(bucket.0.as_node_id(), api)?;
 
         // Put
+        let amount = other_bucket.liquid.amount();
         Self::internal_put(other_bucket.liquid, api)?;
+        Self::update_reserves(amount, api)?;
+
+        Ok(())
+    }
+
+    pub fn transfer_out<Y>(amount: Decimal, api: &mut Y) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+
+        // Check amount
+        let divisibility = Self::get_divisibility(api)?;
+        if !check_fungible_amount(&amount, divisibility) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidAmount),
+            ));
+        }
+
+        // Take
+        let taken = Self::internal_take(amount, api)?;
+        Self::update_reserves(-taken.amount(), api)?;
+
+        Ok(taken.amount())
+    }
+
+    pub fn transfer_in<Y>(amount: Decimal, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::DEPOSIT, api)?;
+
+        // Check amount
+        let divisibility = Self::get_divisibility(api)?;
+        if !check_fungible_amount(&amount, divisibility) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::InvalidAmount),
+            ));
+        }
+
+        // Put
+        Self::internal_put(LiquidFungibleResource::new(amount), api)?;
+        Self::update_reserves(amount, api)?;
 
         Ok(())
     }
@@ -149,10 +194,34 @@ impl FungibleVaultBlueprint {
     }
 
     pub fn recall<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_internal(amount, None, api)
+    }
+
+    pub fn recall_with_reason<Y>(
+        amount: Decimal,
+        reason: String,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_internal(amount, Some(reason), api)
+    }
+
+    fn recall_internal<Y>(
+        amount: Decimal,
+        reason: Option<String>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
         Self::assert_recallable(api)?;
+        Self::assert_not_frozen(VaultFreezeFlags::RECALL, api)?;
+        VaultUtil::check_recall_reason(&reason)?;
 
         let divisibility = Self::get_divisibility(api)?;
         if !check_fungible_amount(&amount, divisibility) {
@@ -162,10 +231,12 @@ impl FungibleVaultBlueprint {
         }
 
         let taken = Self::internal_take(amount, api)?;
+        Self::update_reserves(-taken.amount(), api)?;
 
         let bucket = FungibleResourceManagerBlueprint::create_bucket(taken.amount(), api)?;
 
         Runtime::emit_event(api, RecallResourceEvent::Amount(amount))?;
+        VaultUtil::emit_recall_audit_event(RecalledResource::Amount(taken.amount()), reason, api)?;
 
         Ok(bucket)
     }
@@ -369,6 +440,43 @@ impl FungibleVaultBlueprint {
         Ok(())
     }
 
+    /// Records the change of this vault's balance in the reserves of its resource, if the
+    /// resource tracks them. Fees can only be locked in XRD, which doesn't track reserves.
+    fn update_reserves<Y>(delta: Decimal, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if delta.is_zero()
+            || !FungibleResourceManagerBlueprint::is_reserve_tracking_enabled(
+                OBJECT_HANDLE_OUTER_OBJECT,
+                api,
+            )?
+        {
+            return Ok(());
+        }
+
+        let vault_id = api.actor_get_node_id()?;
+        let key = scrypto_encode(&vault_id).unwrap();
+        let amount = api
+            .actor_index_remove_typed::<ReserveHolding>(
+                OBJECT_HANDLE_OUTER_OBJECT,
+                FUNGIBLE_RESOURCE_MANAGER_RESERVES_INDEX,
+                key.clone(),
+            )?
+            .map_or(Decimal::zero(), |holding| holding.amount)
+            + delta;
+        if amount.is_positive() {
+            api.actor_index_insert_typed(
+                OBJECT_HANDLE_OUTER_OBJECT,
+                FUNGIBLE_RESOURCE_MANAGER_RESERVES_INDEX,
+                key,
+                ReserveHolding { vault_id, amount },
+            )?;
+        }
+
+        Ok(())
+    }
+
     fn liquid_amount<Y>(api: &mut Y) -> Result<Decimal, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
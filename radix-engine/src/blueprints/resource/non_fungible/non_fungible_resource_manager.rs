@@ -27,6 +27,7 @@ pub enum NonFungibleResourceManagerError {
     DropNonEmptyBucket,
     NotMintable,
     NotBurnable,
+    InvalidNonFungibleDataPayload(Box<NonFungibleLocalId>, DecodeError),
 }
 
 pub type NonFungibleResourceManagerIdTypeSubstate = NonFungibleIdType;
@@ -120,6 +121,7 @@ impl NonFungibleResourceManagerBlueprint {
             }
         };
 
+        let time_boxed = non_fungible_schema.is_time_boxed();
         let mutable_fields = NonFungibleResourceManagerMutableFieldsSubstate {
             mutable_fields: non_fungible_schema.mutable_fields,
         };
@@ -133,6 +135,9 @@ impl NonFungibleResourceManagerBlueprint {
         if track_total_supply {
             features.push(TRACK_TOTAL_SUPPLY_FEATURE);
         }
+        if time_boxed {
+            features.push(TIME_BOXED_FEATURE);
+        }
 
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -190,6 +195,7 @@ impl NonFungibleResourceManagerBlueprint {
             ));
         }
 
+        let time_boxed = non_fungible_schema.is_time_boxed();
         let mutable_fields = NonFungibleResourceManagerMutableFieldsSubstate {
             mutable_fields: non_fungible_schema.mutable_fields,
         };
@@ -228,6 +234,9 @@ impl NonFungibleResourceManagerBlueprint {
         if track_total_supply {
             features.push(TRACK_TOTAL_SUPPLY_FEATURE);
         }
+        if time_boxed {
+            features.push(TIME_BOXED_FEATURE);
+        }
 
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -291,6 +300,7 @@ impl NonFungibleResourceManagerBlueprint {
             non_fungibles.insert(scrypto_encode(&id).unwrap(), kv_entry);
         }
 
+        let time_boxed = non_fungible_schema.is_time_boxed();
         let mutable_fields = NonFungibleResourceManagerMutableFieldsSubstate {
             mutable_fields: non_fungible_schema.mutable_fields,
         };
@@ -304,6 +314,9 @@ impl NonFungibleResourceManagerBlueprint {
         if track_total_supply {
             features.push(TRACK_TOTAL_SUPPLY_FEATURE);
         }
+        if time_boxed {
+            features.push(TIME_BOXED_FEATURE);
+        }
 
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -386,6 +399,30 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(bucket)
     }
 
+    pub(crate) fn mint_non_fungible_from_blobs<Y>(
+        entries: BTreeMap<NonFungibleLocalId, Vec<u8>>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut decoded_entries = BTreeMap::new();
+        for (id, payload) in entries {
+            // The decoded data is validated against the non-fungible schema on write
+            let value: ScryptoValue = scrypto_decode(&payload).map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::InvalidNonFungibleDataPayload(
+                        Box::new(id.clone()),
+                        e,
+                    ),
+                ))
+            })?;
+            decoded_entries.insert(id, (value,));
+        }
+
+        Self::mint_non_fungible(decoded_entries, api)
+    }
+
     pub(crate) fn mint_single_ruid_non_fungible<Y>(
         value: ScryptoValue,
         api: &mut Y,
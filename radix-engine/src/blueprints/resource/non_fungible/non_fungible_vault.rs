@@ -88,6 +88,56 @@ impl NonFungibleVaultBlueprint {
         Ok(())
     }
 
+    pub fn transfer_out<Y>(
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<BTreeSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+
+        // Check amount
+        check_non_fungible_amount(&amount).map_err(|_| {
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::InvalidAmount))
+        })?;
+
+        // Take
+        let taken = Self::internal_take(amount, api)?;
+
+        Ok(taken.into_ids())
+    }
+
+    pub fn transfer_non_fungibles_out<Y>(
+        non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<BTreeSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::WITHDRAW, api)?;
+
+        // Take
+        let taken = Self::internal_take_non_fungibles(non_fungible_local_ids, api)?;
+
+        Ok(taken.into_ids())
+    }
+
+    pub fn transfer_in<Y>(
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_not_frozen(VaultFreezeFlags::DEPOSIT, api)?;
+
+        // Put
+        Self::internal_put(LiquidNonFungibleResource::new(non_fungible_local_ids), api)?;
+
+        Ok(())
+    }
+
     pub fn get_amount<Y>(api: &mut Y) -> Result<Decimal, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
@@ -109,20 +159,46 @@ impl NonFungibleVaultBlueprint {
     }
 
     pub fn recall<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_internal(amount, None, api)
+    }
+
+    pub fn recall_with_reason<Y>(
+        amount: Decimal,
+        reason: String,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_internal(amount, Some(reason), api)
+    }
+
+    fn recall_internal<Y>(
+        amount: Decimal,
+        reason: Option<String>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
         Self::assert_recallable(api)?;
+        Self::assert_not_frozen(VaultFreezeFlags::RECALL, api)?;
+        VaultUtil::check_recall_reason(&reason)?;
 
         check_non_fungible_amount(&amount).map_err(|_| {
             RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::InvalidAmount))
         })?;
 
         let taken = Self::internal_take(amount, api)?;
+        let ids = taken.into_ids();
 
-        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(taken.into_ids(), api)?;
+        let bucket = NonFungibleResourceManagerBlueprint::create_bucket(ids.clone(), api)?;
 
         Runtime::emit_event(api, RecallResourceEvent::Amount(amount))?;
+        VaultUtil::emit_recall_audit_event(RecalledResource::Ids(ids), reason, api)?;
 
         Ok(bucket)
     }
@@ -168,16 +244,48 @@ impl NonFungibleVaultBlueprint {
         non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_non_fungibles_internal(non_fungible_local_ids, None, api)
+    }
+
+    pub fn recall_non_fungibles_with_reason<Y>(
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        reason: String,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        Self::recall_non_fungibles_internal(non_fungible_local_ids, Some(reason), api)
+    }
+
+    fn recall_non_fungibles_internal<Y>(
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+        reason: Option<String>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
     where
         Y: KernelNodeApi + ClientApi<RuntimeError>,
     {
         Self::assert_recallable(api)?;
+        Self::assert_not_frozen(VaultFreezeFlags::RECALL, api)?;
+        VaultUtil::check_recall_reason(&reason)?;
 
         let taken = Self::internal_take_non_fungibles(&non_fungible_local_ids, api)?;
 
         let bucket = NonFungibleResourceManagerBlueprint::create_bucket(taken.into_ids(), api)?;
 
-        Runtime::emit_event(api, RecallResourceEvent::Ids(non_fungible_local_ids))?;
+        Runtime::emit_event(
+            api,
+            RecallResourceEvent::Ids(non_fungible_local_ids.clone()),
+        )?;
+        VaultUtil::emit_recall_audit_event(
+            RecalledResource::Ids(non_fungible_local_ids),
+            reason,
+            api,
+        )?;
 
         Ok(bucket)
     }
@@ -44,6 +44,10 @@ const FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME: &str =
     "amount_for_withdrawal_FungibleResourceManager";
 const FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
     "drop_empty_bucket_FungibleResourceManager";
+const FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_EXPORT_NAME: &str =
+    "enable_reserve_tracking_FungibleResourceManager";
+const FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_EXPORT_NAME: &str =
+    "proof_of_reserves_FungibleResourceManager";
 
 const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str = "create_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME: &str =
@@ -52,6 +56,8 @@ const NON_FUNGIBLE_RESOURCE_MANAGER_BURN_EXPORT_NAME: &str = "burn_NonFungibleRe
 const NON_FUNGIBLE_RESOURCE_MANAGER_PACKAGE_BURN_EXPORT_NAME: &str =
     "package_burn_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME: &str = "mint_NonFungibleResourceManager";
+const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_EXPORT_NAME: &str =
+    "mint_from_blobs_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME: &str =
     "mint_ruid_NonFungibleResourceManager";
 const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME: &str =
@@ -72,6 +78,7 @@ const FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleVa
 const FUNGIBLE_VAULT_PUT_EXPORT_NAME: &str = "put_FungibleVault";
 const FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME: &str = "get_amount_FungibleVault";
 const FUNGIBLE_VAULT_RECALL_EXPORT_NAME: &str = "recall_FungibleVault";
+const FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME: &str = "recall_with_reason_FungibleVault";
 const FUNGIBLE_VAULT_FREEZE_EXPORT_NAME: &str = "freeze_FungibleVault";
 const FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME: &str = "unfreeze_FungibleVault";
 const FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
@@ -79,18 +86,26 @@ const FUNGIBLE_VAULT_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
 const FUNGIBLE_VAULT_LOCK_AMOUNT_EXPORT_NAME: &str = "lock_amount_FungibleVault";
 const FUNGIBLE_VAULT_UNLOCK_AMOUNT_EXPORT_NAME: &str = "unlock_amount_FungibleVault";
 const FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_FungibleVault";
+const FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME: &str = "transfer_out_FungibleVault";
+const FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME: &str = "transfer_in_FungibleVault";
 
 const NON_FUNGIBLE_VAULT_TAKE_EXPORT_NAME: &str = "take_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_PUT_EXPORT_NAME: &str = "put_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_GET_AMOUNT_EXPORT_NAME: &str = "get_amount_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_RECALL_EXPORT_NAME: &str = "recall_NonFungibleVault";
+const NON_FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME: &str =
+    "recall_with_reason_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_FREEZE_EXPORT_NAME: &str = "freeze_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_UNFREEZE_EXPORT_NAME: &str = "unfreeze_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_EXPORT_NAME: &str = "unlock_fungibles_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_EXPORT_NAME: &str =
     "unlock_non_fungibles_NonFungibleVault";
 const NON_FUNGIBLE_VAULT_BURN_EXPORT_NAME: &str = "burn_NonFungibleVault";
+const NON_FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME: &str = "transfer_out_NonFungibleVault";
+const NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_EXPORT_NAME: &str =
+    "transfer_non_fungibles_out_NonFungibleVault";
+const NON_FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME: &str = "transfer_in_NonFungibleVault";
 
 const FUNGIBLE_BUCKET_TAKE_EXPORT_NAME: &str = "take_FungibleBucket";
 const FUNGIBLE_BUCKET_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleBucket";
@@ -154,6 +169,14 @@ impl ResourceNativePackage {
                     .add_child_type_and_descendents::<FungibleResourceManagerTotalSupplySubstate>(),
                 TRACK_TOTAL_SUPPLY_FEATURE,
             ));
+            fields.push(FieldSchema::static_field(
+                aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerReserveTrackingSubstate>(
+                    ),
+            ));
+
+            let mut collections = Vec::new();
+            collections.push(BlueprintCollectionSchema::Index(BlueprintIndexSchema {}));
 
             let mut functions = BTreeMap::new();
             functions.insert(
@@ -317,6 +340,36 @@ impl ResourceNativePackage {
                     export: FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleResourceManagerEnableReserveTrackingInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleResourceManagerEnableReserveTrackingOutput>(),
+                    ),
+                    export: FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleResourceManagerProofOfReservesInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleResourceManagerProofOfReservesOutput>(),
+                    ),
+                    export: FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_EXPORT_NAME.to_string(),
+                },
+            );
 
             let event_schema = event_schema! {
                 aggregator,
@@ -344,7 +397,7 @@ impl ResourceNativePackage {
                     schema,
                     state: BlueprintStateSchemaInit {
                         fields,
-                        collections: vec![],
+                        collections,
                     },
                     events: event_schema,
                     functions: BlueprintFunctionsSchemaInit {
@@ -380,6 +433,8 @@ impl ResourceNativePackage {
                             RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                             RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                             RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
+                            FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_IDENT => [OWNER_ROLE];
+                            FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_IDENT => MethodAccessibility::Public;
                         }
                     }),
                 },
@@ -507,6 +562,19 @@ impl ResourceNativePackage {
                 },
             );
 
+            functions.insert(
+                NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleResourceManagerMintFromBlobsInput>(
+                        )),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleResourceManagerMintFromBlobsOutput>(
+                        )),
+                    export: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_EXPORT_NAME.to_string(),
+                },
+            );
             functions.insert(
                 NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -671,6 +739,7 @@ impl ResourceNativePackage {
                     VAULT_RECALL_FEATURE.to_string(),
                     MINT_FEATURE.to_string(),
                     BURN_FEATURE.to_string(),
+                    TIME_BOXED_FEATURE.to_string(),
                 ),
                 dependencies: btreeset!(),
                 schema: BlueprintSchemaInit {
@@ -709,6 +778,7 @@ impl ResourceNativePackage {
                         },
                         methods {
                             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
+                            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT => [MINTER_ROLE];
                             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT => [MINTER_ROLE];
                             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT => [MINTER_ROLE];
                             RESOURCE_MANAGER_BURN_IDENT => [BURNER_ROLE];
@@ -826,6 +896,22 @@ impl ResourceNativePackage {
                     export: FUNGIBLE_VAULT_RECALL_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                VAULT_RECALL_WITH_REASON_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo {
+                        receiver: Receiver::SelfRefMut,
+                        ref_types: RefTypes::DIRECT_ACCESS,
+                    }),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultRecallWithReasonInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultRecallWithReasonOutput>(),
+                    ),
+                    export: FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME.to_string(),
+                },
+            );
             functions.insert(
                 VAULT_FREEZE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -909,6 +995,35 @@ impl ResourceNativePackage {
                     export: FUNGIBLE_VAULT_BURN_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                FUNGIBLE_VAULT_TRANSFER_OUT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleVaultTransferOutInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleVaultTransferOutOutput>(),
+                    ),
+                    export: FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                FUNGIBLE_VAULT_TRANSFER_IN_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<FungibleVaultTransferInInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<FungibleVaultTransferInOutput>(),
+                    ),
+                    export: FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME.to_string(),
+                },
+            );
 
             let event_schema = event_schema! {
                 aggregator,
@@ -916,7 +1031,8 @@ impl ResourceNativePackage {
                     LockFeeEvent,
                     WithdrawResourceEvent,
                     DepositResourceEvent,
-                    RecallResourceEvent
+                    RecallResourceEvent,
+                    RecallAuditEvent
                 ]
             };
 
@@ -957,10 +1073,13 @@ impl ResourceNativePackage {
                             VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                             FUNGIBLE_VAULT_LOCK_FEE_IDENT => [WITHDRAWER_ROLE];
                             VAULT_RECALL_IDENT => [RECALLER_ROLE];
+                            VAULT_RECALL_WITH_REASON_IDENT => [RECALLER_ROLE];
                             VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                             VAULT_BURN_IDENT => [BURNER_ROLE];
                             FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
                             FUNGIBLE_VAULT_UNLOCK_FUNGIBLE_AMOUNT_IDENT => MethodAccessibility::OwnPackageOnly;
+                            FUNGIBLE_VAULT_TRANSFER_OUT_IDENT => [WITHDRAWER_ROLE];
+                            FUNGIBLE_VAULT_TRANSFER_IN_IDENT => [DEPOSITOR_ROLE];
                         },
                     }),
                 },
@@ -1040,6 +1159,22 @@ impl ResourceNativePackage {
                     export: NON_FUNGIBLE_VAULT_RECALL_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                VAULT_RECALL_WITH_REASON_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo {
+                        receiver: Receiver::SelfRefMut,
+                        ref_types: RefTypes::DIRECT_ACCESS,
+                    }),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultRecallWithReasonInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<VaultRecallWithReasonOutput>(),
+                    ),
+                    export: NON_FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME.to_string(),
+                },
+            );
             functions.insert(
                 VAULT_FREEZE_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -1088,6 +1223,22 @@ impl ResourceNativePackage {
                     export: NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo {
+                        receiver: Receiver::SelfRefMut,
+                        ref_types: RefTypes::DIRECT_ACCESS,
+                    }),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultRecallNonFungiblesWithReasonInput>(
+                        )),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultRecallNonFungiblesWithReasonOutput>(
+                        )),
+                    export: NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 VAULT_PUT_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -1184,6 +1335,47 @@ impl ResourceNativePackage {
                     export: NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                NON_FUNGIBLE_VAULT_TRANSFER_OUT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<NonFungibleVaultTransferOutInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<NonFungibleVaultTransferOutOutput>(),
+                    ),
+                    export: NON_FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultTransferNonFungiblesOutInput>()),
+                    output: TypeRef::Static(aggregator
+                        .add_child_type_and_descendents::<NonFungibleVaultTransferNonFungiblesOutOutput>()),
+                    export: NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<NonFungibleVaultTransferInInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<NonFungibleVaultTransferInOutput>(),
+                    ),
+                    export: NON_FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME.to_string(),
+                },
+            );
 
             let event_schema = event_schema! {
                 aggregator,
@@ -1191,7 +1383,8 @@ impl ResourceNativePackage {
                     LockFeeEvent,
                     WithdrawResourceEvent,
                     DepositResourceEvent,
-                    RecallResourceEvent
+                    RecallResourceEvent,
+                    RecallAuditEvent
                 ]
             };
 
@@ -1232,15 +1425,20 @@ impl ResourceNativePackage {
                             VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                             NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT => [WITHDRAWER_ROLE];
                             VAULT_RECALL_IDENT => [RECALLER_ROLE];
+                            VAULT_RECALL_WITH_REASON_IDENT => [RECALLER_ROLE];
                             VAULT_FREEZE_IDENT => [FREEZER_ROLE];
                             VAULT_UNFREEZE_IDENT => [FREEZER_ROLE];
                             NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT => [RECALLER_ROLE];
+                            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT => [RECALLER_ROLE];
                             VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                             VAULT_BURN_IDENT => [BURNER_ROLE];
                             NON_FUNGIBLE_VAULT_BURN_NON_FUNGIBLES_IDENT => [BURNER_ROLE];
 
                             NON_FUNGIBLE_VAULT_LOCK_NON_FUNGIBLES_IDENT => MethodAccessibility::OwnPackageOnly;
                             NON_FUNGIBLE_VAULT_UNLOCK_NON_FUNGIBLES_IDENT => MethodAccessibility::OwnPackageOnly;
+                            NON_FUNGIBLE_VAULT_TRANSFER_OUT_IDENT => [WITHDRAWER_ROLE];
+                            NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_IDENT => [WITHDRAWER_ROLE];
+                            NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT => [DEPOSITOR_ROLE];
                         },
                     }),
                 },
@@ -1959,6 +2157,49 @@ impl ResourceNativePackage {
                     export: WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                WORKTOP_ASSERT_CONTAINS_EXACTLY_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertContainsExactlyInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertContainsExactlyOutput>(),
+                    ),
+                    export: WORKTOP_ASSERT_CONTAINS_EXACTLY_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                WORKTOP_ASSERT_RESOURCES_ONLY_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertResourcesOnlyInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertResourcesOnlyOutput>(),
+                    ),
+                    export: WORKTOP_ASSERT_RESOURCES_ONLY_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                WORKTOP_CONTENTS_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<WorktopContentsInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<WorktopContentsOutput>(),
+                    ),
+                    export: WORKTOP_CONTENTS_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 WORKTOP_DRAIN_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -2120,6 +2361,46 @@ impl ResourceNativePackage {
                 },
             );
 
+            functions.insert(
+                AUTH_ZONE_PUSH_SCOPE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePushScopeInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePushScopeOutput>(),
+                    ),
+                    export: AUTH_ZONE_PUSH_SCOPE_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                AUTH_ZONE_POP_SCOPE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePopScopeInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePopScopeOutput>(),
+                    ),
+                    export: AUTH_ZONE_POP_SCOPE_EXPORT_NAME.to_string(),
+                },
+            );
+            functions.insert(
+                AUTH_ZONE_SCOPE_DEPTH_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZoneScopeDepthInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZoneScopeDepthOutput>(),
+                    ),
+                    export: AUTH_ZONE_SCOPE_DEPTH_EXPORT_NAME.to_string(),
+                },
+            );
+
             let schema = generate_full_schema(aggregator);
             let auth_zone_blueprint = BlueprintStateSchemaInit {
                 fields,
@@ -2279,6 +2560,23 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_EXPORT_NAME => {
+                let _input: FungibleResourceManagerEnableReserveTrackingInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::enable_reserve_tracking(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_EXPORT_NAME => {
+                let input: FungibleResourceManagerProofOfReservesInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    FungibleResourceManagerBlueprint::proof_of_reserves(input.top_holders, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME => {
                 let input: NonFungibleResourceManagerCreateInput =
                     input.as_typed().map_err(|e| {
@@ -2341,6 +2639,17 @@ impl ResourceNativePackage {
                     NonFungibleResourceManagerBlueprint::mint_non_fungible(input.entries, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerMintFromBlobsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::mint_non_fungible_from_blobs(
+                    input.entries,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME => {
                 let input: NonFungibleResourceManagerMintRuidInput =
                     input.as_typed().map_err(|e| {
@@ -2492,6 +2801,14 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::recall(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME => {
+                let input: VaultRecallWithReasonInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    FungibleVaultBlueprint::recall_with_reason(input.amount, input.reason, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_VAULT_FREEZE_EXPORT_NAME => {
                 let input: VaultFreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -2546,6 +2863,20 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::unlock_amount(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME => {
+                let input: FungibleVaultTransferOutInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::transfer_out(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME => {
+                let input: FungibleVaultTransferInInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::transfer_in(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_VAULT_BURN_EXPORT_NAME => {
                 let input: VaultBurnInput = input.as_typed().map_err(|e| {
                     RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
@@ -2590,6 +2921,14 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::recall(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_RECALL_WITH_REASON_EXPORT_NAME => {
+                let input: VaultRecallWithReasonInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    NonFungibleVaultBlueprint::recall_with_reason(input.amount, input.reason, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_VAULT_FREEZE_EXPORT_NAME => {
                 let input: VaultFreezeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -2615,6 +2954,18 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT => {
+                let input: NonFungibleVaultRecallNonFungiblesWithReasonInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::recall_non_fungibles_with_reason(
+                    input.non_fungible_local_ids,
+                    input.reason,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_VAULT_PUT_EXPORT_NAME => {
                 let input: VaultPutInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -2682,6 +3033,32 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_TRANSFER_OUT_EXPORT_NAME => {
+                let input: NonFungibleVaultTransferOutInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleVaultBlueprint::transfer_out(input.amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_EXPORT_NAME => {
+                let input: NonFungibleVaultTransferNonFungiblesOutInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::transfer_non_fungibles_out(
+                    &input.non_fungible_local_ids,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_VAULT_TRANSFER_IN_EXPORT_NAME => {
+                let input: NonFungibleVaultTransferInInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    NonFungibleVaultBlueprint::transfer_in(input.non_fungible_local_ids, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
             FUNGIBLE_PROOF_CLONE_EXPORT_NAME => {
                 let _input: ProofCloneInput = input.as_typed().map_err(|e| {
@@ -2927,6 +3304,13 @@ impl ResourceNativePackage {
             WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT => {
                 WorktopBlueprint::assert_contains_non_fungibles(input, api)
             }
+            WORKTOP_ASSERT_CONTAINS_EXACTLY_IDENT => {
+                WorktopBlueprint::assert_contains_exactly(input, api)
+            }
+            WORKTOP_ASSERT_RESOURCES_ONLY_IDENT => {
+                WorktopBlueprint::assert_resources_only(input, api)
+            }
+            WORKTOP_CONTENTS_IDENT => WorktopBlueprint::contents(input, api),
             WORKTOP_DRAIN_IDENT => WorktopBlueprint::drain(input, api),
             AUTH_ZONE_POP_EXPORT_NAME => {
                 let _input: AuthZonePopInput = input.as_typed().map_err(|e| {
@@ -3009,6 +3393,33 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&proofs))
             }
+            AUTH_ZONE_PUSH_SCOPE_EXPORT_NAME => {
+                let input: AuthZonePushScopeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                AuthZoneBlueprint::push_scope(input.proofs, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&()))
+            }
+            AUTH_ZONE_POP_SCOPE_EXPORT_NAME => {
+                let _input: AuthZonePopScopeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                AuthZoneBlueprint::pop_scope(api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&()))
+            }
+            AUTH_ZONE_SCOPE_DEPTH_EXPORT_NAME => {
+                let _input: AuthZoneScopeDepthInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let depth = AuthZoneBlueprint::scope_depth(api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&depth))
+            }
             AUTH_ZONE_DROP_EXPORT_NAME => AuthZoneBlueprint::drop(input, api),
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
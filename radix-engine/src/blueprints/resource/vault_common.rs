@@ -1,5 +1,8 @@
 use crate::blueprints::resource::*;
+use crate::errors::{ApplicationError, RuntimeError};
 use crate::types::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::types::*;
 
@@ -12,6 +15,7 @@ pub enum VaultError {
     NotFreezable,
     NotRecallable,
     VaultIsFrozen,
+    RecallReasonTooLong { length: usize, max: usize },
 
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance,
@@ -25,4 +29,55 @@ impl VaultUtil {
             && (blueprint.blueprint_name.eq(NON_FUNGIBLE_VAULT_BLUEPRINT)
                 || blueprint.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT))
     }
+
+    pub fn is_recall_method(ident: &str) -> bool {
+        match ident {
+            VAULT_RECALL_IDENT
+            | VAULT_RECALL_WITH_REASON_IDENT
+            | NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT
+            | NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT => true,
+            _ => false,
+        }
+    }
+
+    pub fn check_recall_reason(reason: &Option<String>) -> Result<(), RuntimeError> {
+        match reason {
+            Some(reason) if reason.len() > MAX_RECALL_REASON_LENGTH => {
+                Err(RuntimeError::ApplicationError(
+                    ApplicationError::VaultError(VaultError::RecallReasonTooLong {
+                        length: reason.len(),
+                        max: MAX_RECALL_REASON_LENGTH,
+                    }),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Emits the audit event of a recall from the current vault actor, attributing it to the
+    /// caller of the vault.
+    pub fn emit_recall_audit_event<Y>(
+        recalled: RecalledResource,
+        reason: Option<String>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let vault_id = InternalAddress::new_or_panic(api.actor_get_node_id()?.0);
+        let resource_address =
+            ResourceAddress::new_or_panic(api.actor_get_info()?.get_outer_object().into());
+        let recaller = api.actor_get_global_caller()?;
+
+        Runtime::emit_event(
+            api,
+            RecallAuditEvent {
+                vault_id,
+                resource_address,
+                recalled,
+                recaller,
+                reason,
+            },
+        )
+    }
 }
@@ -330,6 +330,94 @@ impl WorktopBlueprint {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    pub(crate) fn assert_contains_exactly<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        let input: WorktopAssertContainsExactlyInput = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let worktop_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            WorktopField::Worktop.into(),
+            LockFlags::read_only(),
+        )?;
+        let worktop: WorktopSubstate = api.field_lock_read_typed(worktop_handle)?;
+        let amount = if let Some(bucket) = worktop.resources.get(&input.resource_address).cloned() {
+            Bucket(bucket).amount(api)?
+        } else {
+            Decimal::zero()
+        };
+        if amount != input.amount {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::WorktopError(WorktopError::AssertionFailed),
+            ));
+        }
+        api.field_lock_release(worktop_handle)?;
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    /// Asserts that the worktop holds no resources other than the given ones.
+    pub(crate) fn assert_resources_only<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        let input: WorktopAssertResourcesOnlyInput = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let worktop_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            WorktopField::Worktop.into(),
+            LockFlags::read_only(),
+        )?;
+        let worktop: WorktopSubstate = api.field_lock_read_typed(worktop_handle)?;
+        for (resource_address, bucket) in worktop.resources {
+            if !input.resource_addresses.contains(&resource_address)
+                && !Bucket(bucket).amount(api)?.is_zero()
+            {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::WorktopError(WorktopError::AssertionFailed),
+                ));
+            }
+        }
+        api.field_lock_release(worktop_handle)?;
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    /// Returns the amount of each resource on the worktop, without taking any of it.
+    pub(crate) fn contents<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        let _input: WorktopContentsInput = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let worktop_handle = api.actor_open_field(
+            OBJECT_HANDLE_SELF,
+            WorktopField::Worktop.into(),
+            LockFlags::read_only(),
+        )?;
+        let worktop: WorktopSubstate = api.field_lock_read_typed(worktop_handle)?;
+        let mut contents = WorktopContentsOutput::new();
+        for (resource_address, bucket) in worktop.resources {
+            contents.insert(resource_address, Bucket(bucket).amount(api)?);
+        }
+        api.field_lock_release(worktop_handle)?;
+        Ok(IndexedScryptoValue::from_typed(&contents))
+    }
+
     pub(crate) fn drain<Y>(
         input: &IndexedScryptoValue,
         api: &mut Y,
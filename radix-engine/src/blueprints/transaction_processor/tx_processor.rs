@@ -13,6 +13,7 @@ use native_sdk::resource::{NativeBucket, NativeProof, Worktop};
 use native_sdk::runtime::LocalAuthZone;
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::package::BlueprintVersion;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::blueprints::transaction_processor::*;
@@ -184,6 +185,17 @@ impl TransactionProcessorBlueprint {
                     )?;
                     InstructionOutput::None
                 }
+                InstructionV1::AssertWorktopContainsExactly {
+                    amount,
+                    resource_address,
+                } => {
+                    worktop.assert_contains_exactly(resource_address, amount, api)?;
+                    InstructionOutput::None
+                }
+                InstructionV1::AssertWorktopResourcesOnly { resource_addresses } => {
+                    worktop.assert_resources_only(resource_addresses.into_iter().collect(), api)?;
+                    InstructionOutput::None
+                }
                 InstructionV1::PopFromAuthZone {} => {
                     let proof = LocalAuthZone::pop(api)?;
                     processor.create_manifest_proof(proof)?;
@@ -399,6 +411,19 @@ impl TransactionProcessorBlueprint {
 
                     InstructionOutput::None
                 }
+                InstructionV1::DropAllBucketsInto { account_address } => {
+                    let account_address = processor.resolve_global_address(account_address)?;
+                    let buckets = worktop.drain(api)?;
+                    let rtn = api.call_method(
+                        account_address.as_node_id(),
+                        ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+                        scrypto_encode(&AccountTryDepositBatchOrAbortInput { buckets }).unwrap(),
+                    )?;
+
+                    let result = IndexedScryptoValue::from_vec(rtn).unwrap();
+                    processor.handle_call_return_data(&result, &worktop, api)?;
+                    InstructionOutput::CallReturn(result.into())
+                }
             };
             outputs.push(result);
         }
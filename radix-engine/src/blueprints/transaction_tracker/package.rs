@@ -39,6 +39,8 @@ pub struct TransactionTrackerNativePackage;
 pub const PARTITION_RANGE_START: u8 = MAIN_BASE_PARTITION.0 + 1;
 pub const PARTITION_RANGE_END: u8 = u8::MAX;
 pub const EPOCHS_PER_PARTITION: u64 = 100;
+/// The number of unexpired idempotency keys the transaction tracker can remember at once.
+pub const MAX_TRACKED_IDEMPOTENCY_KEYS: u32 = 1024;
 
 impl TransactionTrackerNativePackage {
     pub fn definition() -> PackageDefinition {
@@ -61,6 +63,9 @@ impl TransactionTrackerNativePackage {
         fields.push(FieldSchema::static_field(
             aggregator.add_child_type_and_descendents::<TransactionTrackerSubstate>(),
         ));
+        fields.push(FieldSchema::static_field(
+            aggregator.add_child_type_and_descendents::<IdempotencyKeysSubstate>(),
+        ));
 
         let mut functions = BTreeMap::new();
         functions.insert(
@@ -199,6 +204,75 @@ impl TransactionTrackerSubstate {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct IdempotencyKeyEntry {
+    pub idempotency_key: Hash,
+    /// The end (exclusive) of the epoch range of the transaction which committed the key.
+    pub expiry_epoch: Epoch,
+}
+
+/// A fixed capacity buffer of the idempotency keys of committed transactions.
+///
+/// A new key only ever takes the slot of an expired key, so every key is remembered until its
+/// expiry epoch. Once all slots hold unexpired keys, keyed transactions are rejected until one
+/// of them expires.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct IdempotencyKeysSubstate {
+    pub entries: Vec<IdempotencyKeyEntry>,
+    pub capacity: u32,
+}
+
+impl IdempotencyKeysSubstate {
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            entries: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Returns the expiry epoch of the key, if it has been committed and hasn't yet expired.
+    pub fn find_unexpired(&self, idempotency_key: &Hash, current_epoch: Epoch) -> Option<Epoch> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.idempotency_key.eq(idempotency_key) && current_epoch < entry.expiry_epoch
+            })
+            .map(|entry| entry.expiry_epoch)
+    }
+
+    /// Returns the index of the slot the next key would be written to: an unused slot while the
+    /// buffer is filling up, and afterwards the first slot holding an expired key.
+    fn free_slot(&self, current_epoch: Epoch) -> Option<usize> {
+        if self.entries.len() < self.capacity as usize {
+            return Some(self.entries.len());
+        }
+        self.entries
+            .iter()
+            .position(|entry| current_epoch >= entry.expiry_epoch)
+    }
+
+    /// Whether all slots hold keys which haven't yet expired.
+    pub fn is_full(&self, current_epoch: Epoch) -> bool {
+        self.free_slot(current_epoch).is_none()
+    }
+
+    /// Records the key, which must not be called when the buffer [`Self::is_full`].
+    pub fn insert(&mut self, idempotency_key: Hash, expiry_epoch: Epoch, current_epoch: Epoch) {
+        let entry = IdempotencyKeyEntry {
+            idempotency_key,
+            expiry_epoch,
+        };
+        let index = self
+            .free_slot(current_epoch)
+            .expect("Idempotency keys should have been checked for a free slot");
+        if index == self.entries.len() {
+            self.entries.push(entry);
+        } else {
+            self.entries[index] = entry;
+        }
+    }
+}
+
 pub struct TransactionTrackerBlueprint;
 
 impl TransactionTrackerBlueprint {
@@ -212,14 +286,18 @@ impl TransactionTrackerBlueprint {
         let current_epoch = Runtime::current_epoch(api)?;
         let intent_store = api.new_simple_object(
             TRANSACTION_TRACKER_BLUEPRINT,
-            vec![scrypto_encode(&TransactionTrackerSubstate {
-                start_epoch: current_epoch.number(),
-                start_partition: PARTITION_RANGE_START,
-                partition_range_start_inclusive: PARTITION_RANGE_START,
-                partition_range_end_inclusive: PARTITION_RANGE_END,
-                epochs_per_partition: EPOCHS_PER_PARTITION,
-            })
-            .unwrap()],
+            vec![
+                scrypto_encode(&TransactionTrackerSubstate {
+                    start_epoch: current_epoch.number(),
+                    start_partition: PARTITION_RANGE_START,
+                    partition_range_start_inclusive: PARTITION_RANGE_START,
+                    partition_range_end_inclusive: PARTITION_RANGE_END,
+                    epochs_per_partition: EPOCHS_PER_PARTITION,
+                })
+                .unwrap(),
+                scrypto_encode(&IdempotencyKeysSubstate::new(MAX_TRACKED_IDEMPOTENCY_KEYS))
+                    .unwrap(),
+            ],
         )?;
         let access_rules = AccessRules::create(OwnerRole::None, btreemap!(), api)?.0;
         let metadata = Metadata::create(api)?;
@@ -292,4 +370,37 @@ mod tests {
         assert_eq!(store.start_epoch, 256 + EPOCHS_PER_PARTITION);
         assert_eq!(store.start_partition, 71);
     }
+
+    #[test]
+    fn test_idempotency_keys_buffer() {
+        let mut keys = IdempotencyKeysSubstate::new(2);
+        let key = |i: u8| Hash([i; Hash::LENGTH]);
+
+        keys.insert(key(1), Epoch::of(20), Epoch::of(1));
+        keys.insert(key(2), Epoch::of(10), Epoch::of(1));
+        assert_eq!(
+            keys.find_unexpired(&key(1), Epoch::of(9)),
+            Some(Epoch::of(20))
+        );
+        assert_eq!(keys.find_unexpired(&key(2), Epoch::of(10)), None);
+        assert_eq!(keys.find_unexpired(&key(3), Epoch::of(9)), None);
+
+        // No slot is free while all keys are unexpired
+        assert!(keys.is_full(Epoch::of(9)));
+
+        // The first expired key is overwritten, even if it isn't the oldest
+        assert!(!keys.is_full(Epoch::of(10)));
+        keys.insert(key(3), Epoch::of(30), Epoch::of(10));
+        assert_eq!(
+            keys.find_unexpired(&key(1), Epoch::of(10)),
+            Some(Epoch::of(20))
+        );
+        assert_eq!(keys.find_unexpired(&key(2), Epoch::of(9)), None);
+        assert_eq!(
+            keys.find_unexpired(&key(3), Epoch::of(10)),
+            Some(Epoch::of(30))
+        );
+        assert_eq!(keys.entries.len(), 2);
+        assert!(keys.is_full(Epoch::of(10)));
+    }
 }
@@ -1,7 +1,9 @@
 use crate::blueprints::access_controller::AccessControllerError;
 use crate::blueprints::account::AccountError;
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
+use crate::blueprints::hook_registry::HookRegistryError;
 use crate::blueprints::package::PackageError;
+use crate::blueprints::payment_stream::PaymentStreamError;
 use crate::blueprints::pool::multi_resource_pool::MultiResourcePoolError;
 use crate::blueprints::pool::one_resource_pool::OneResourcePoolError;
 use crate::blueprints::pool::two_resource_pool::TwoResourcePoolError;
@@ -17,18 +19,24 @@ use crate::kernel::call_frame::{
     CreateFrameError, CreateNodeError, DropNodeError, ListNodeModuleError, MoveModuleError,
     OpenSubstateError, PassMessageError, ReadSubstateError, WriteSubstateError,
 };
+use crate::system::big_int::BigIntError;
 use crate::system::node_modules::access_rules::AccessRulesError;
 use crate::system::node_modules::metadata::MetadataPanicError;
 use crate::system::node_modules::royalty::ComponentRoyaltyError;
 use crate::system::system_modules::auth::AuthError;
-use crate::system::system_modules::costing::CostingError;
+use crate::system::system_modules::costing::{
+    CostingError, ExecutionDeadline, ExecutionDeadlineMetrics,
+};
+use crate::system::system_modules::invocation_filter::InvocationFilterError;
 use crate::system::system_modules::limits::TransactionLimitsError;
 use crate::system::system_modules::node_move::NodeMoveError;
 use crate::transaction::AbortReason;
 use crate::types::*;
 use crate::vm::wasm::WasmRuntimeError;
+use radix_engine_interface::api::blueprint_error_name;
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::blueprints::package::CanonicalBlueprintId;
+use sbor::representations::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum IdAllocationError {
@@ -52,8 +60,23 @@ pub enum RejectionError {
         valid_until: Epoch,
         current_epoch: Epoch,
     },
+    TransactionExpired {
+        expires_at: Instant,
+        current_time_milli: i64,
+    },
     IntentHashPreviouslyCommitted,
     IntentHashPreviouslyCancelled,
+    IdempotencyKeyPreviouslyCommitted {
+        idempotency_key: Hash,
+        expiry_epoch: Epoch,
+    },
+    IdempotencyKeysExhausted {
+        capacity: u32,
+    },
+    ExecutionDeadlineExceeded {
+        deadline: ExecutionDeadline,
+        metrics: ExecutionDeadlineMetrics,
+    },
 }
 
 impl fmt::Display for RejectionError {
@@ -62,6 +85,18 @@ impl fmt::Display for RejectionError {
     }
 }
 
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for RejectionError {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        write_with_addresses(f, self, context)
+    }
+}
+
 /// Represents an error when executing a transaction.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum RuntimeError {
@@ -215,6 +250,7 @@ pub enum SystemError {
     MutatingImmutableSubstate,
     NotAKeyValueStore,
     CannotStoreOwnedInIterable,
+    CannotStoreOwnedInTransientEntry,
     InvalidSubstateWrite(String),
     InvalidKeyValueStoreOwnership,
     InvalidKeyValueKey(String),
@@ -240,6 +276,35 @@ pub enum SystemError {
     TransactionRuntimeModuleNotEnabled,
     PayloadValidationAgainstSchemaError(PayloadValidationAgainstSchemaError),
     EventError(EventError),
+    StaticCallError(StaticCallError),
+    VaultTransferError(VaultTransferError),
+    BigIntError(BigIntError),
+}
+
+/// Restrictions placed on everything which runs within a static call.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum StaticCallError {
+    /// Substates can only be read, and no entities can be globalized.
+    WriteNotAllowed,
+    /// Buckets, proofs and other owned nodes can't be passed into or returned from a static call.
+    OwnedNodeMoveNotAllowed,
+    /// Only static method calls can be made from within a static call.
+    NonStaticCallNotAllowed,
+}
+
+/// Errors of a direct vault-to-vault transfer.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum VaultTransferError {
+    /// Both ends of a transfer must be vaults.
+    NotAVault(NodeId),
+    /// Transfers of specific non-fungibles are only possible between non-fungible vaults.
+    NotANonFungibleVault(NodeId),
+    /// Both vaults must hold the same resource.
+    ResourceMismatch,
+    /// A vault can't transfer to itself.
+    SameVault,
+    /// The two halves of a transfer can only be invoked by the system.
+    MethodNotCallable(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -295,6 +360,7 @@ pub enum SystemModuleError {
     CostingError(CostingError),
     TransactionLimitsError(TransactionLimitsError),
     EventError(Box<EventError>),
+    InvocationFilterError(InvocationFilterError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -306,10 +372,20 @@ pub enum PayloadValidationAgainstSchemaError {
     KeyValueStoreValueDoesNotExist,
     EventDoesNotExist(String),
     PayloadValidationError(String),
+    InvalidFnOutput(InvalidFnOutputError),
     InstanceSchemaDoesNotExist,
     SchemaNotFound,
 }
 
+/// The value returned by a blueprint function doesn't match the output type in its schema.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct InvalidFnOutputError {
+    pub fn_ident: String,
+    pub expected_type: String,
+    /// Every mismatching part of the output, as `<value path>: <validation error>`.
+    pub mismatches: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct InvalidModuleType {
     pub expected_blueprint: BlueprintId,
@@ -405,6 +481,25 @@ impl<E: SelfError> From<InvokeError<E>> for RuntimeError {
     }
 }
 
+/// A typed error a blueprint failed the transaction with, and the function which failed.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct BlueprintError {
+    pub fn_identifier: FnIdentifier,
+    /// The name of the type of the error, as described by its schema.
+    pub error_name: String,
+    pub error: ScryptoValue,
+}
+
+impl BlueprintError {
+    /// Decodes the error as an `E`, if it's of a type with the same name and its value fits.
+    pub fn error_as<E: ScryptoDecode + ScryptoDescribe>(&self) -> Option<E> {
+        if self.error_name != blueprint_error_name::<E>() {
+            return None;
+        }
+        scrypto_decode(&scrypto_encode(&self.error).ok()?).ok()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum ApplicationError {
     //===================
@@ -418,6 +513,8 @@ pub enum ApplicationError {
 
     Panic(String),
 
+    BlueprintError(Box<BlueprintError>),
+
     //===================
     // Node module errors
     //===================
@@ -463,6 +560,10 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    HookRegistryError(HookRegistryError),
+
+    PaymentStreamError(PaymentStreamError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
@@ -531,6 +632,152 @@ impl fmt::Display for RuntimeError {
     }
 }
 
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for RuntimeError {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        write_with_addresses(f, self, context)
+    }
+}
+
+/// Writes a value from its SBOR encoding, annotated with its schema, with every node id and
+/// address in it rendered as bech32 using the encoder of the given context. Without an encoder,
+/// or if the value can't be encoded, the `Debug` output is written instead.
+pub fn write_with_addresses<F: fmt::Write, T: ScryptoEncode + ScryptoDescribe + fmt::Debug>(
+    f: &mut F,
+    value: &T,
+    context: &AddressDisplayContext,
+) -> fmt::Result {
+    let encoder = match context.encoder {
+        Some(encoder) => encoder,
+        None => return write!(f, "{:?}", value),
+    };
+    let mut scrypto_value = match scrypto_encode(value)
+        .ok()
+        .and_then(|bytes| scrypto_decode::<ScryptoValue>(&bytes).ok())
+    {
+        Some(scrypto_value) => scrypto_value,
+        None => return write!(f, "{:?}", value),
+    };
+
+    let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+    let type_index = aggregator.add_child_type_and_descendents::<T>();
+    let node_id_type_index = aggregator.add_child_type_and_descendents::<NodeId>();
+    let mut schema = generate_full_schema::<ScryptoCustomSchema>(aggregator);
+
+    // Node ids are encoded as plain byte arrays, so they're turned into references, which are
+    // displayed as addresses
+    node_ids_to_references(&schema, node_id_type_index, type_index, &mut scrypto_value);
+    if let LocalTypeIndex::SchemaLocalIndex(index) = node_id_type_index {
+        schema.type_kinds[index] = TypeKind::Custom(ScryptoCustomTypeKind::Reference);
+        schema.type_metadata[index] = TypeMetadata::unnamed();
+        schema.type_validations[index] = TypeValidation::None;
+    }
+
+    let payload = scrypto_encode(&scrypto_value).map_err(|_| fmt::Error)?;
+    write!(
+        f,
+        "{}",
+        ScryptoRawPayload::new_from_valid_owned(payload).to_string(
+            ValueDisplayParameters::Annotated {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: encoder.into(),
+                schema: &schema,
+                type_index,
+            }
+        )
+    )
+}
+
+fn node_ids_to_references(
+    schema: &Schema<ScryptoCustomSchema>,
+    node_id_type_index: LocalTypeIndex,
+    type_index: LocalTypeIndex,
+    value: &mut ScryptoValue,
+) {
+    let reference_value_kind = ValueKind::Custom(ScryptoCustomValueKind::Reference);
+    if type_index == node_id_type_index {
+        if let ScryptoValue::Array { elements, .. } = value {
+            let bytes: Vec<u8> = elements
+                .iter()
+                .filter_map(|element| match element {
+                    ScryptoValue::U8 { value } => Some(*value),
+                    _ => None,
+                })
+                .collect();
+            if let Ok(reference) = Reference::try_from(bytes.as_slice()) {
+                *value = ScryptoValue::Custom {
+                    value: ScryptoCustomValue::Reference(reference),
+                };
+            }
+        }
+        return;
+    }
+
+    match (schema.resolve_type_kind(type_index), value) {
+        (
+            Some(TypeKind::Array { element_type }),
+            ScryptoValue::Array {
+                element_value_kind,
+                elements,
+            },
+        ) => {
+            for element in elements {
+                node_ids_to_references(schema, node_id_type_index, *element_type, element);
+            }
+            if *element_type == node_id_type_index {
+                *element_value_kind = reference_value_kind;
+            }
+        }
+        (Some(TypeKind::Tuple { field_types }), ScryptoValue::Tuple { fields }) => {
+            for (field_type, field) in field_types.iter().zip(fields) {
+                node_ids_to_references(schema, node_id_type_index, *field_type, field);
+            }
+        }
+        (
+            Some(TypeKind::Enum { variants }),
+            ScryptoValue::Enum {
+                discriminator,
+                fields,
+            },
+        ) => {
+            if let Some(field_types) = variants.get(discriminator) {
+                for (field_type, field) in field_types.iter().zip(fields) {
+                    node_ids_to_references(schema, node_id_type_index, *field_type, field);
+                }
+            }
+        }
+        (
+            Some(TypeKind::Map {
+                key_type,
+                value_type,
+            }),
+            ScryptoValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            },
+        ) => {
+            for (key, value) in entries {
+                node_ids_to_references(schema, node_id_type_index, *key_type, key);
+                node_ids_to_references(schema, node_id_type_index, *value_type, value);
+            }
+            if *key_type == node_id_type_index {
+                *key_value_kind = reference_value_kind;
+            }
+            if *value_type == node_id_type_index {
+                *value_value_kind = reference_value_kind;
+            }
+        }
+        _ => {}
+    }
+}
+
 impl From<OpenSubstateError> for CallFrameError {
     fn from(value: OpenSubstateError) -> Self {
         Self::OpenSubstateError(value)
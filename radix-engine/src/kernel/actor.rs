@@ -20,6 +20,8 @@ pub struct MethodActor {
     pub ident: String,
     pub instance_context: Option<InstanceContext>,
     pub is_direct_access: bool,
+    /// Whether the method was invoked through a static (read-only) call.
+    pub is_static: bool,
 }
 
 impl MethodActor {
@@ -88,6 +90,15 @@ impl Actor {
         }
     }
 
+    pub fn is_static(&self) -> bool {
+        match self {
+            Actor::Method(MethodActor { is_static, .. }) => *is_static,
+            Actor::Function { .. } => false,
+            Actor::VirtualLazyLoad { .. } => false,
+            Actor::Root { .. } => false,
+        }
+    }
+
     pub fn is_barrier(&self) -> bool {
         match self {
             Actor::Method(MethodActor {
@@ -263,6 +274,7 @@ impl Actor {
         module_object_info: ObjectInfo,
         instance_context: Option<InstanceContext>,
         is_direct_access: bool,
+        is_static: bool,
     ) -> Self {
         Self::Method(MethodActor {
             global_address,
@@ -272,6 +284,7 @@ impl Actor {
             module_object_info,
             instance_context,
             is_direct_access,
+            is_static,
         })
     }
 
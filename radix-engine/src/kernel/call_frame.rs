@@ -1108,6 +1108,14 @@ impl<L: Clone> CallFrame<L> {
         self.owned_root_nodes.keys().cloned().collect()
     }
 
+    /// Returns the total encoded size of all heap nodes owned by this frame.
+    pub fn owned_heap_size(&self, heap: &Heap) -> usize {
+        self.owned_root_nodes
+            .keys()
+            .map(|node_id| heap.node_size(node_id))
+            .sum()
+    }
+
     pub fn move_node_to_store<S: SubstateStore>(
         heap: &mut Heap,
         store: &mut S,
@@ -47,6 +47,27 @@ impl Heap {
         self.nodes.contains_key(node_id)
     }
 
+    /// Returns the total encoded size of the given node, including all its owned child nodes
+    /// which are still in this heap.
+    pub fn node_size(&self, node_id: &NodeId) -> usize {
+        match self.nodes.get(node_id) {
+            Some(node) => node
+                .substates
+                .values()
+                .flat_map(|partition| partition.values())
+                .map(|value| {
+                    value.len()
+                        + value
+                            .owned_nodes()
+                            .iter()
+                            .map(|child| self.node_size(child))
+                            .sum::<usize>()
+                })
+                .sum(),
+            None => 0,
+        }
+    }
+
     pub fn list_modules(&self, node_id: &NodeId) -> Option<BTreeSet<PartitionNumber>> {
         self.nodes
             .get(node_id)
@@ -342,7 +342,11 @@ where
     fn kernel_allocate_node_id(&mut self, entity_type: EntityType) -> Result<NodeId, RuntimeError> {
         M::on_allocate_node_id(entity_type, self)?;
 
-        self.id_allocator.allocate_node_id(entity_type)
+        let node_id = self.id_allocator.allocate_node_id(entity_type)?;
+
+        M::after_allocate_node_id(&node_id, self)?;
+
+        Ok(node_id)
     }
 
     #[trace_resources(log=node_id.entity_type())]
@@ -416,6 +420,10 @@ where
         self.current_frame.depth()
     }
 
+    fn kernel_get_current_frame_heap_size(&self) -> usize {
+        self.current_frame.owned_heap_size(&self.heap)
+    }
+
     fn kernel_get_system_state(&mut self) -> SystemState<'_, M> {
         let caller = match self.prev_frame_stack.last() {
             Some(call_frame) => call_frame.actor(),
@@ -701,11 +709,14 @@ where
         M::on_read_substate(lock_handle, value_size, &store_access, self)?;
 
         // Double read due to borrow chacker of self.
-        Ok(self
+        let value = self
             .current_frame
             .read_substate(&mut self.heap, self.store, lock_handle)
             .unwrap()
-            .0)
+            .0;
+        self.callback.on_read_substate_value(lock_handle, value);
+
+        Ok(value)
     }
 
     #[trace_resources]
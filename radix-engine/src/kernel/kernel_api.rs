@@ -197,6 +197,9 @@ pub trait KernelInternalApi<M: KernelCallbackObject> {
     /// Gets the number of call frames that are currently in the call frame stack
     fn kernel_get_current_depth(&self) -> usize;
 
+    /// Gets the total encoded size of the heap nodes owned by the current call frame
+    fn kernel_get_current_frame_heap_size(&self) -> usize;
+
     // TODO: Cleanup
     fn kernel_get_node_visibility(&self, node_id: &NodeId) -> NodeVisibility;
 
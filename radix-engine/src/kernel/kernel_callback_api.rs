@@ -173,4 +173,12 @@ pub trait KernelCallbackObject: Sized {
     fn on_allocate_node_id<Y>(entity_type: EntityType, api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: KernelApi<Self>;
+
+    fn after_allocate_node_id<Y>(node_id: &NodeId, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>;
+
+    /// Observes the value of a substate read. Unlike the other callbacks, this has no access to
+    /// the kernel, as the value is borrowed from it.
+    fn on_read_substate_value(&mut self, lock_handle: LockHandle, value: &IndexedScryptoValue);
 }
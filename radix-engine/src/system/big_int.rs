@@ -0,0 +1,203 @@
+use crate::types::*;
+use radix_engine_interface::api::{BigIntOperation, BigIntType};
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum BigIntError {
+    InvalidOperandsLength { expected: usize, actual: usize },
+    Overflow,
+    DivisionByZero,
+    NegativeExponent,
+    NonPositiveModulus,
+}
+
+macro_rules! evaluate_with {
+    ($t:ident, $wide:ident, $operation:expr, $operands:expr) => {{
+        let operands: Vec<$t> = $operands
+            .chunks($t::BYTES as usize)
+            .map(|chunk| $t::from_le_bytes(chunk))
+            .collect();
+        let result = match $operation {
+            BigIntOperation::Add => operands[0].0.checked_add(operands[1].0).map($t),
+            BigIntOperation::Sub => operands[0].0.checked_sub(operands[1].0).map($t),
+            BigIntOperation::Mul => operands[0].0.checked_mul(operands[1].0).map($t),
+            BigIntOperation::Div => {
+                if operands[1] == $t::ZERO {
+                    return Err(BigIntError::DivisionByZero);
+                }
+                operands[0].0.checked_div(operands[1].0).map($t)
+            }
+            BigIntOperation::ModPow => {
+                let (base, mut exponent, modulus) = (operands[0], operands[1], operands[2]);
+                if exponent < $t::ZERO {
+                    return Err(BigIntError::NegativeExponent);
+                }
+                if modulus <= $t::ZERO {
+                    return Err(BigIntError::NonPositiveModulus);
+                }
+
+                // Intermediate products of two values below the modulus always fit the wider type
+                let two = $t::ONE + $t::ONE;
+                let modulus = $wide::from(modulus);
+                let mut base = $wide::from(base) % modulus;
+                if base < $wide::ZERO {
+                    base = base + modulus;
+                }
+                let mut result = $wide::ONE % modulus;
+                while exponent > $t::ZERO {
+                    if exponent % two != $t::ZERO {
+                        result = result * base % modulus;
+                    }
+                    base = base * base % modulus;
+                    exponent = exponent / two;
+                }
+                $t::try_from(result).ok()
+            }
+        };
+        result
+            .map(|result| result.to_vec())
+            .ok_or(BigIntError::Overflow)
+    }};
+}
+
+/// Evaluates a big integer operation on behalf of a blueprint, on operands encoded as described
+/// by [`ClientBigIntApi::big_int_op`](radix_engine_interface::api::ClientBigIntApi::big_int_op).
+pub fn evaluate_big_int_operation(
+    int_type: BigIntType,
+    operation: BigIntOperation,
+    operands: &[u8],
+) -> Result<Vec<u8>, BigIntError> {
+    let expected = int_type.byte_len() * operation.operand_count();
+    if operands.len() != expected {
+        return Err(BigIntError::InvalidOperandsLength {
+            expected,
+            actual: operands.len(),
+        });
+    }
+
+    match int_type {
+        BigIntType::I256 => evaluate_with!(BnumI256, BnumI512, operation, operands),
+        BigIntType::I384 => evaluate_with!(BnumI384, BnumI768, operation, operands),
+        BigIntType::U256 => evaluate_with!(BnumU256, BnumU512, operation, operands),
+        BigIntType::U384 => evaluate_with!(BnumU384, BnumU768, operation, operands),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn u256_op(operation: BigIntOperation, operands: &[BnumU256]) -> Result<BnumU256, BigIntError> {
+        let operands: Vec<u8> = operands.iter().flat_map(|x| x.to_vec()).collect();
+        evaluate_big_int_operation(BigIntType::U256, operation, &operands)
+            .map(|result| BnumU256::from_le_bytes(&result))
+    }
+
+    fn i256_op(operation: BigIntOperation, operands: &[BnumI256]) -> Result<BnumI256, BigIntError> {
+        let operands: Vec<u8> = operands.iter().flat_map(|x| x.to_vec()).collect();
+        evaluate_big_int_operation(BigIntType::I256, operation, &operands)
+            .map(|result| BnumI256::from_le_bytes(&result))
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = BnumU256::from(1_000_000u64);
+        let b = BnumU256::from(7u64);
+        assert_eq!(u256_op(BigIntOperation::Add, &[a, b]), Ok(a + b));
+        assert_eq!(u256_op(BigIntOperation::Sub, &[a, b]), Ok(a - b));
+        assert_eq!(u256_op(BigIntOperation::Mul, &[a, b]), Ok(a * b));
+        assert_eq!(u256_op(BigIntOperation::Div, &[a, b]), Ok(a / b));
+        assert_eq!(
+            i256_op(
+                BigIntOperation::Div,
+                &[BnumI256::from(-7i64), BnumI256::from(2i64)]
+            ),
+            Ok(BnumI256::from(-3i64))
+        );
+    }
+
+    #[test]
+    fn test_overflow_and_division_by_zero() {
+        assert_eq!(
+            u256_op(BigIntOperation::Add, &[BnumU256::MAX, BnumU256::ONE]),
+            Err(BigIntError::Overflow)
+        );
+        assert_eq!(
+            u256_op(BigIntOperation::Sub, &[BnumU256::ZERO, BnumU256::ONE]),
+            Err(BigIntError::Overflow)
+        );
+        assert_eq!(
+            i256_op(
+                BigIntOperation::Div,
+                &[BnumI256::MIN, BnumI256::from(-1i64)]
+            ),
+            Err(BigIntError::Overflow)
+        );
+        assert_eq!(
+            u256_op(BigIntOperation::Div, &[BnumU256::ONE, BnumU256::ZERO]),
+            Err(BigIntError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(
+            u256_op(
+                BigIntOperation::ModPow,
+                &[
+                    BnumU256::from(4u64),
+                    BnumU256::from(13u64),
+                    BnumU256::from(497u64)
+                ]
+            ),
+            Ok(BnumU256::from(445u64))
+        );
+        // (MAX - 1)^2 = 1 mod MAX
+        assert_eq!(
+            u256_op(
+                BigIntOperation::ModPow,
+                &[
+                    BnumU256::MAX - BnumU256::ONE,
+                    BnumU256::from(2u64),
+                    BnumU256::MAX
+                ]
+            ),
+            Ok(BnumU256::ONE)
+        );
+        assert_eq!(
+            i256_op(
+                BigIntOperation::ModPow,
+                &[
+                    BnumI256::from(-2i64),
+                    BnumI256::from(3i64),
+                    BnumI256::from(5i64)
+                ]
+            ),
+            Ok(BnumI256::from(2i64))
+        );
+        assert_eq!(
+            i256_op(
+                BigIntOperation::ModPow,
+                &[BnumI256::ONE, BnumI256::from(-1i64), BnumI256::from(5i64)]
+            ),
+            Err(BigIntError::NegativeExponent)
+        );
+        assert_eq!(
+            u256_op(
+                BigIntOperation::ModPow,
+                &[BnumU256::ONE, BnumU256::ONE, BnumU256::ZERO]
+            ),
+            Err(BigIntError::NonPositiveModulus)
+        );
+    }
+
+    #[test]
+    fn test_invalid_operands_length() {
+        assert_eq!(
+            evaluate_big_int_operation(BigIntType::U384, BigIntOperation::Add, &[0u8; 64]),
+            Err(BigIntError::InvalidOperandsLength {
+                expected: 96,
+                actual: 64
+            })
+        );
+    }
+}
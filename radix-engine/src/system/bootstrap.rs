@@ -1,10 +1,12 @@
 use crate::blueprints::access_controller::*;
 use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
+use crate::blueprints::hook_registry::HookRegistryNativePackage;
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
 use crate::blueprints::package::{
     create_bootstrap_package_partitions, PackageNativePackage, PackageOwnerBadgeData,
 };
+use crate::blueprints::payment_stream::PaymentStreamNativePackage;
 use crate::blueprints::pool::PoolNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
@@ -479,6 +481,7 @@ pub fn create_system_bootstrap_flash(
                 definition,
                 VmType::Native,
                 native_code_id.to_be_bytes().to_vec(),
+                None,
             )
             .expect("Invalid Package Package definition");
 
@@ -895,6 +898,50 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Hook Registry Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(HOOK_REGISTRY_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: HookRegistryNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Hook Registry Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of hook registry components.".to_owned(), locked;
+                },
+                native_package_code_id: HOOK_REGISTRY_CODE_ID,
+            }),
+        });
+    }
+
+    // Payment Stream Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(PAYMENT_STREAM_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: PaymentStreamNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Payment Stream Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of payment stream components.".to_owned(), locked;
+                },
+                native_package_code_id: PAYMENT_STREAM_CODE_ID,
+            }),
+        });
+    }
+
     // ECDSA Secp256k1
     {
         pre_allocated_addresses.push((
@@ -24,6 +24,17 @@ pub enum IDAllocation {
     },
 }
 
+/// The lifecycle of a global address reserved within a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum GlobalAddressReservationState {
+    /// The address is reserved, and waiting to be globalized.
+    Reserved,
+    /// An object has been globalized at the address.
+    Globalized,
+    /// The reservation was dropped unused, freeing the address.
+    Released,
+}
+
 impl IDAllocation {
     pub fn is_global(&self) -> bool {
         match self {
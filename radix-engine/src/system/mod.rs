@@ -1,3 +1,4 @@
+pub mod big_int;
 pub mod bootstrap;
 pub mod id_allocation;
 pub mod module;
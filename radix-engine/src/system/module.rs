@@ -92,6 +92,14 @@ pub trait SystemModule<M: KernelCallbackObject> {
         Ok(())
     }
 
+    #[inline(always)]
+    fn after_allocate_node_id<Y: KernelApi<M>>(
+        _api: &mut Y,
+        _node_id: &NodeId,
+    ) -> Result<(), RuntimeError> {
+        Ok(())
+    }
+
     #[inline(always)]
     fn before_create_node<Y: KernelApi<M>>(
         _api: &mut Y,
@@ -1,16 +1,17 @@
-use super::id_allocation::IDAllocation;
+use super::id_allocation::{GlobalAddressReservationState, IDAllocation};
 use super::payload_validation::*;
 use super::system_modules::auth::Authorization;
 use super::system_modules::costing::CostingEntry;
 use crate::errors::{
     ApplicationError, CannotGlobalizeError, CreateObjectError, InvalidDropNodeAccess,
-    InvalidModuleSet, InvalidModuleType, PayloadValidationAgainstSchemaError, RuntimeError,
-    SystemError, SystemModuleError,
+    InvalidFnOutputError, InvalidModuleSet, InvalidModuleType, PayloadValidationAgainstSchemaError,
+    RuntimeError, SystemError, SystemModuleError,
 };
-use crate::errors::{EventError, SystemUpstreamError};
+use crate::errors::{EventError, StaticCallError, SystemUpstreamError, VaultTransferError};
 use crate::kernel::actor::{Actor, InstanceContext, MethodActor};
 use crate::kernel::call_frame::{NodeVisibility, Visibility};
 use crate::kernel::kernel_api::*;
+use crate::system::big_int::evaluate_big_int_operation;
 use crate::system::node_init::type_info_partition;
 use crate::system::node_modules::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::system::system_callback::{
@@ -146,6 +147,242 @@ where
         }
     }
 
+    fn is_in_static_call(&mut self) -> bool {
+        self.api.kernel_get_system_state().current.is_static()
+    }
+
+    fn assert_not_in_static_call(&mut self, error: StaticCallError) -> Result<(), RuntimeError> {
+        if self.is_in_static_call() {
+            return Err(RuntimeError::SystemError(SystemError::StaticCallError(
+                error,
+            )));
+        }
+        Ok(())
+    }
+
+    /// The two halves of a vault-to-vault transfer bypass the bucket hop, so they can't be
+    /// invoked directly, only through `transfer_between_vaults` and
+    /// `transfer_non_fungibles_between_vaults`.
+    fn assert_not_vault_transfer_method(
+        &mut self,
+        receiver: &NodeId,
+        method_name: &str,
+    ) -> Result<(), RuntimeError> {
+        let is_transfer_method = if receiver.is_internal_fungible_vault() {
+            method_name.eq(FUNGIBLE_VAULT_TRANSFER_OUT_IDENT)
+                || method_name.eq(FUNGIBLE_VAULT_TRANSFER_IN_IDENT)
+        } else if receiver.is_internal_non_fungible_vault() {
+            method_name.eq(NON_FUNGIBLE_VAULT_TRANSFER_OUT_IDENT)
+                || method_name.eq(NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_IDENT)
+                || method_name.eq(NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT)
+        } else {
+            false
+        };
+        if is_transfer_method {
+            return Err(RuntimeError::SystemError(SystemError::VaultTransferError(
+                VaultTransferError::MethodNotCallable(method_name.to_string()),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that a vault-to-vault transfer is between two distinct vaults of the same
+    /// resource, and returns the blueprint of the two vaults.
+    fn assert_valid_vault_transfer(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+    ) -> Result<String, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::NonStaticCallNotAllowed)?;
+
+        if from_vault.eq(to_vault) {
+            return Err(RuntimeError::SystemError(SystemError::VaultTransferError(
+                VaultTransferError::SameVault,
+            )));
+        }
+
+        let mut resource_addresses = Vec::new();
+        let mut blueprint_name = String::new();
+        for vault in [from_vault, to_vault] {
+            let info = self.get_object_info(vault)?;
+            if !info.blueprint_id.package_address.eq(&RESOURCE_PACKAGE)
+                || !(info
+                    .blueprint_id
+                    .blueprint_name
+                    .eq(FUNGIBLE_VAULT_BLUEPRINT)
+                    || info
+                        .blueprint_id
+                        .blueprint_name
+                        .eq(NON_FUNGIBLE_VAULT_BLUEPRINT))
+            {
+                return Err(RuntimeError::SystemError(SystemError::VaultTransferError(
+                    VaultTransferError::NotAVault(vault.clone()),
+                )));
+            }
+            resource_addresses.push(info.get_outer_object());
+            blueprint_name = info.blueprint_id.blueprint_name;
+        }
+        // The resource decides the vault blueprint, so both vaults are also of the same blueprint
+        if !resource_addresses[0].eq(&resource_addresses[1]) {
+            return Err(RuntimeError::SystemError(SystemError::VaultTransferError(
+                VaultTransferError::ResourceMismatch,
+            )));
+        }
+
+        Ok(blueprint_name)
+    }
+
+    /// The second half of a transfer out of a non-fungible vault.
+    fn transfer_non_fungibles_in(
+        &mut self,
+        to_vault: &NodeId,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    ) -> Result<(), RuntimeError> {
+        self.call_method_internal(
+            to_vault,
+            false,
+            ObjectModuleId::Main,
+            NON_FUNGIBLE_VAULT_TRANSFER_IN_IDENT,
+            scrypto_encode(&NonFungibleVaultTransferInInput {
+                non_fungible_local_ids,
+            })
+            .unwrap(),
+            false,
+        )?;
+
+        Ok(())
+    }
+
+    /// Within a static call, substates may only be opened for reading, and those reads ignore
+    /// any write lock held further up the call stack, so that the caller can be queried back.
+    fn apply_static_call_lock_flags(
+        &mut self,
+        flags: LockFlags,
+    ) -> Result<LockFlags, RuntimeError> {
+        if flags.contains(LockFlags::UNLOCKED_READ) {
+            return Err(RuntimeError::SystemError(SystemError::InvalidLockFlags));
+        }
+
+        if !self.is_in_static_call() {
+            return Ok(flags);
+        }
+
+        if flags.contains(LockFlags::MUTABLE) || flags.contains(LockFlags::FORCE_WRITE) {
+            return Err(RuntimeError::SystemError(SystemError::StaticCallError(
+                StaticCallError::WriteNotAllowed,
+            )));
+        }
+
+        Ok(flags | LockFlags::UNLOCKED_READ)
+    }
+
+    fn call_method_internal(
+        &mut self,
+        receiver: &NodeId,
+        direct_access: bool,
+        object_module_id: ObjectModuleId,
+        method_name: &str,
+        args: Vec<u8>,
+        is_static: bool,
+    ) -> Result<IndexedScryptoValue, RuntimeError> {
+        let node_object_info = self.get_object_info(receiver)?;
+
+        let (module_object_info, global_address) = match object_module_id {
+            ObjectModuleId::Main => {
+                let global_address = if node_object_info.global {
+                    Some(GlobalAddress::new_or_panic(receiver.clone().into()))
+                } else {
+                    // FIXME: Have a correct implementation of tracking global address
+                    // See if we have a parent
+                    // Cleanup, this is a rather crude way of trying to figure out
+                    // whether the node reference is a child of the current parent
+                    // this should be cleaned up once call_frame is refactored
+                    let node_visibility = self.api.kernel_get_node_visibility(receiver);
+                    if node_visibility.0.iter().any(|v| v.is_normal())
+                        && !node_visibility
+                            .0
+                            .iter()
+                            .any(|v| matches!(v, Visibility::FrameOwned))
+                    {
+                        match self.api.kernel_get_system_state().current {
+                            Actor::Method(MethodActor { global_address, .. }) => {
+                                global_address.clone()
+                            }
+                            _ => None,
+                        }
+                    } else {
+                        None
+                    }
+                };
+
+                (node_object_info.clone(), global_address)
+            }
+            // FIXME: verify whether we need to check the modules or not
+            ObjectModuleId::Metadata | ObjectModuleId::Royalty | ObjectModuleId::AccessRules => (
+                ObjectInfo {
+                    global: node_object_info.global,
+
+                    blueprint_id: object_module_id.static_blueprint().unwrap(),
+                    version: BlueprintVersion::default(),
+
+                    blueprint_info: ObjectBlueprintInfo::default(),
+                    features: btreeset!(),
+                    instance_schema: None,
+                },
+                None,
+            ),
+        };
+
+        let identifier =
+            MethodIdentifier(receiver.clone(), object_module_id, method_name.to_string());
+
+        // TODO: Can we load this lazily when needed?
+        let instance_context = if module_object_info.global {
+            match global_address {
+                None => None,
+                Some(address) => Some(InstanceContext {
+                    outer_object: address,
+                    outer_blueprint: module_object_info.blueprint_id.blueprint_name.clone(),
+                }),
+            }
+        } else {
+            match &module_object_info.blueprint_info {
+                ObjectBlueprintInfo::Inner { outer_object } => {
+                    // TODO: do this recursively until global?
+                    let outer_info = self.get_object_info(outer_object.as_node_id())?;
+                    Some(InstanceContext {
+                        outer_object: outer_object.clone(),
+                        outer_blueprint: outer_info.blueprint_id.blueprint_name.clone(),
+                    })
+                }
+                ObjectBlueprintInfo::Outer { .. } => None,
+            }
+        };
+
+        let args = IndexedScryptoValue::from_vec(args).map_err(|e| {
+            RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
+        })?;
+        if is_static && !args.owned_nodes().is_empty() {
+            return Err(RuntimeError::SystemError(SystemError::StaticCallError(
+                StaticCallError::OwnedNodeMoveNotAllowed,
+            )));
+        }
+
+        let invocation = KernelInvocation {
+            actor: Actor::method(
+                global_address,
+                identifier,
+                module_object_info,
+                instance_context,
+                direct_access,
+                is_static,
+            ),
+            args,
+        };
+
+        self.api.kernel_invoke(Box::new(invocation))
+    }
+
     fn validate_payload<'s>(
         &mut self,
         payload: &[u8],
@@ -242,6 +479,191 @@ where
         Ok(())
     }
 
+    /// The hash of the schema a payload at the given type pointer is validated against.
+    fn schema_hash_at_type_pointer(
+        instance_schema: &Option<InstanceSchema>,
+        type_pointer: &TypePointer,
+    ) -> Option<Hash> {
+        match type_pointer {
+            TypePointer::Package(schema_hash, _) => Some(*schema_hash),
+            TypePointer::Instance(_) => instance_schema
+                .as_ref()
+                .map(|instance_schema| hash(scrypto_encode(&instance_schema.schema).unwrap())),
+        }
+    }
+
+    /// The hash of the schema each field and key-value entry of the initial state of an object
+    /// was validated against.
+    fn initial_state_schema_hashes(
+        blueprint_interface: &BlueprintInterface,
+        instance_schema: &Option<InstanceSchema>,
+        user_substates: &BTreeMap<PartitionOffset, BTreeMap<SubstateKey, IndexedScryptoValue>>,
+    ) -> Vec<(PartitionOffset, SubstateKey, Hash)> {
+        let mut schema_hashes = Vec::new();
+        for (offset, substates) in user_substates {
+            for substate_key in substates.keys() {
+                let type_pointer = match substate_key {
+                    SubstateKey::Field(field_index) => {
+                        blueprint_interface.get_field_type_pointer(*field_index)
+                    }
+                    SubstateKey::Map(..) => blueprint_interface
+                        .state
+                        .collections
+                        .iter()
+                        .position(|(collection_offset, _)| collection_offset == offset)
+                        .and_then(|collection_index| {
+                            blueprint_interface.get_kv_value_type_pointer(collection_index as u8)
+                        })
+                        .map(|(type_pointer, _)| type_pointer),
+                    SubstateKey::Sorted(..) => None,
+                };
+                if let Some(schema_hash) = type_pointer.and_then(|type_pointer| {
+                    Self::schema_hash_at_type_pointer(instance_schema, &type_pointer)
+                }) {
+                    schema_hashes.push((offset.clone(), substate_key.clone(), schema_hash));
+                }
+            }
+        }
+        schema_hashes
+    }
+
+    /// Validates the output of a blueprint function, reporting the expected type and every
+    /// mismatching part of the output on failure.
+    pub fn validate_fn_output(
+        &mut self,
+        blueprint_id: &BlueprintId,
+        fn_ident: &str,
+        type_pointer: TypePointer,
+        payload: &[u8],
+    ) -> Result<(), RuntimeError> {
+        let (hash, index) = match type_pointer {
+            TypePointer::Package(hash, index) => (hash, index),
+            TypePointer::Instance(..) => {
+                return self.validate_payload_at_type_pointer(
+                    blueprint_id,
+                    &None,
+                    type_pointer,
+                    payload,
+                );
+            }
+        };
+
+        let schema = self.get_schema(blueprint_id.package_address, &hash)?;
+        let schema_origin = SchemaOrigin::Blueprint(blueprint_id.clone());
+        let error_message =
+            match self.validate_payload(payload, &schema, index, schema_origin.clone()) {
+                Ok(()) => return Ok(()),
+                Err(err) => err.error_message(&schema),
+            };
+
+        let mut mismatches = Vec::new();
+        if let Ok(value) = scrypto_decode::<ScryptoValue>(payload) {
+            self.collect_output_mismatches(
+                &schema,
+                index,
+                &value,
+                "$".to_string(),
+                &schema_origin,
+                &mut mismatches,
+            );
+        }
+        if mismatches.is_empty() {
+            mismatches.push(format!("$: {}", error_message));
+        }
+
+        Err(RuntimeError::SystemError(
+            SystemError::PayloadValidationAgainstSchemaError(
+                PayloadValidationAgainstSchemaError::InvalidFnOutput(InvalidFnOutputError {
+                    fn_ident: fn_ident.to_string(),
+                    expected_type: schema
+                        .resolve_type_name_from_metadata(index)
+                        .unwrap_or("<anonymous>")
+                        .to_string(),
+                    mismatches,
+                }),
+            ),
+        ))
+    }
+
+    /// Descends into the tuples and enum variants of a mismatching value, so that every invalid
+    /// field is reported rather than only the first one found by the payload validator.
+    fn collect_output_mismatches(
+        &mut self,
+        schema: &ScryptoSchema,
+        type_index: LocalTypeIndex,
+        value: &ScryptoValue,
+        path: String,
+        schema_origin: &SchemaOrigin,
+        mismatches: &mut Vec<String>,
+    ) {
+        let error_message = match self.validate_payload(
+            &scrypto_encode(value).unwrap(),
+            schema,
+            type_index,
+            schema_origin.clone(),
+        ) {
+            Ok(()) => return,
+            Err(err) => err.error_message(schema),
+        };
+
+        let (field_types, field_names, fields) = match (schema.resolve_type_kind(type_index), value)
+        {
+            (Some(TypeKind::Tuple { field_types }), Value::Tuple { fields })
+                if field_types.len() == fields.len() =>
+            {
+                let field_names = schema
+                    .resolve_matching_tuple_metadata(type_index, fields.len())
+                    .field_names;
+                (field_types, field_names, fields)
+            }
+            (
+                Some(TypeKind::Enum { variants }),
+                Value::Enum {
+                    discriminator,
+                    fields,
+                },
+            ) if variants
+                .get(discriminator)
+                .map_or(false, |field_types| field_types.len() == fields.len()) =>
+            {
+                let field_names = schema
+                    .resolve_matching_enum_metadata(type_index, *discriminator, fields.len())
+                    .field_names;
+                (variants.get(discriminator).unwrap(), field_names, fields)
+            }
+            _ => {
+                mismatches.push(format!("{}: {}", path, error_message));
+                return;
+            }
+        };
+
+        let field_types = field_types.clone();
+        let field_names: Vec<String> = (0..fields.len())
+            .map(|i| {
+                field_names
+                    .and_then(|names| names.get(i))
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| i.to_string())
+            })
+            .collect();
+        let mismatches_before = mismatches.len();
+        for ((field_type, field), field_name) in
+            field_types.into_iter().zip(fields).zip(field_names)
+        {
+            self.collect_output_mismatches(
+                schema,
+                field_type,
+                field,
+                format!("{}.{}", path, field_name),
+                schema_origin,
+                mismatches,
+            );
+        }
+        if mismatches.len() == mismatches_before {
+            mismatches.push(format!("{}: {}", path, error_message));
+        }
+    }
+
     fn validate_instance_schema_and_state(
         &mut self,
         blueprint_id: &BlueprintId,
@@ -580,6 +1002,15 @@ where
             ),
         )?;
 
+        self.api
+            .kernel_get_system_state()
+            .system
+            .modules
+            .set_global_address_reservation_state(
+                global_address,
+                GlobalAddressReservationState::Reserved,
+            );
+
         Ok(GlobalAddressReservation(Own(global_address_reservation)))
     }
 
@@ -707,6 +1138,11 @@ where
             fields,
             kv_entries,
         )?;
+        let state_schema_hashes = Self::initial_state_schema_hashes(
+            &blueprint_interface,
+            &instance_schema,
+            &user_substates,
+        );
 
         let node_id = self.api.kernel_allocate_node_id(
             IDAllocation::Object {
@@ -740,6 +1176,14 @@ where
 
         self.api.kernel_create_node(node_id, node_substates)?;
 
+        let modules = &mut self.api.kernel_get_system().modules;
+        for (offset, substate_key, schema_hash) in state_schema_hashes {
+            let partition_num = MAIN_BASE_PARTITION
+                .at_offset(offset)
+                .expect("Module number overflow");
+            modules.add_state_write_schema_hash(node_id, partition_num, substate_key, schema_hash);
+        }
+
         Ok(node_id.into())
     }
 
@@ -978,6 +1422,19 @@ where
             }
         };
 
+        self.api
+            .kernel_get_system_state()
+            .system
+            .modules
+            .set_global_address_reservation_state(
+                global_address,
+                GlobalAddressReservationState::Globalized,
+            );
+        self.api
+            .kernel_get_system()
+            .modules
+            .add_new_global_entity()?;
+
         // Check blueprint id
         let reserved_blueprint_id = {
             let lock_handle = self.kernel_open_substate(
@@ -1169,6 +1626,13 @@ where
             }
         }
 
+        if global_address.as_node_id().is_global_component() {
+            self.api
+                .kernel_get_system()
+                .modules
+                .apply_instantiation_deposit(global_address)?;
+        }
+
         Ok(global_address)
     }
 
@@ -1224,29 +1688,47 @@ where
         lock_handle: FieldLockHandle,
         buffer: Vec<u8>,
     ) -> Result<(), RuntimeError> {
-        let LockInfo { data, .. } = self.api.kernel_get_lock_info(lock_handle)?;
+        let LockInfo {
+            node_id,
+            partition_num,
+            substate_key,
+            data,
+            ..
+        } = self.api.kernel_get_lock_info(lock_handle)?;
 
-        match data {
+        let schema_hash = match data {
             SystemLockData::Field(FieldLockData::Write {
                 blueprint_id,
                 type_pointer,
             }) => {
+                // TODO: Change to Some, once support for generic fields is implemented
+                let instance_schema = None;
+                let schema_hash =
+                    Self::schema_hash_at_type_pointer(&instance_schema, &type_pointer);
                 self.validate_payload_at_type_pointer(
                     &blueprint_id,
-                    &None, // TODO: Change to Some, once support for generic fields is implemented
+                    &instance_schema,
                     type_pointer,
                     &buffer,
                 )?;
+                schema_hash
             }
             _ => {
                 return Err(RuntimeError::SystemError(SystemError::NotAFieldWriteLock));
             }
-        }
+        };
 
         let substate =
             IndexedScryptoValue::from_vec(buffer).expect("Should be valid due to payload check");
         self.api.kernel_write_substate(lock_handle, substate)?;
 
+        if let Some(schema_hash) = schema_hash {
+            self.api
+                .kernel_get_system()
+                .modules
+                .add_state_write_schema_hash(node_id, partition_num, substate_key, schema_hash);
+        }
+
         Ok(())
     }
 
@@ -1314,9 +1796,9 @@ where
         let global_address_reservation =
             self.prepare_global_address(blueprint_id, global_address)?;
 
-        // NOTE: Because allocated global address is represented as an owned object and nobody is allowed
-        // to drop it except the system during globalization, we don't track the lifecycle of
-        // allocated addresses.
+        // NOTE: Allocated global address is represented as an owned object which nobody is allowed
+        // to drop except the system, either during globalization or when releasing a reservation
+        // left unused at the end of a call frame (see `auto_drop`).
 
         Ok((global_address_reservation, global_address))
     }
@@ -1342,6 +1824,8 @@ where
         modules: BTreeMap<ObjectModuleId, NodeId>,
         address_reservation: Option<GlobalAddressReservation>,
     ) -> Result<GlobalAddress, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         // TODO: optimize by skipping address allocation
         let (global_address_reservation, global_address) =
             if let Some(reservation) = address_reservation {
@@ -1366,6 +1850,8 @@ where
         inner_object_blueprint: &str,
         inner_object_fields: Vec<Vec<u8>>,
     ) -> Result<(GlobalAddress, NodeId), RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_blueprint = self.resolve_blueprint_from_modules(&modules)?;
 
         let global_address = self.globalize_with_address_internal(modules, address_reservation)?;
@@ -1397,96 +1883,131 @@ where
         method_name: &str,
         args: Vec<u8>,
     ) -> Result<Vec<u8>, RuntimeError> {
-        let node_object_info = self.get_object_info(receiver)?;
+        self.assert_not_in_static_call(StaticCallError::NonStaticCallNotAllowed)?;
+        self.assert_not_vault_transfer_method(receiver, method_name)?;
+
+        self.call_method_internal(
+            receiver,
+            direct_access,
+            object_module_id,
+            method_name,
+            args,
+            false,
+        )
+        .map(|v| v.into())
+    }
 
-        let (module_object_info, global_address) = match object_module_id {
-            ObjectModuleId::Main => {
-                let global_address = if node_object_info.global {
-                    Some(GlobalAddress::new_or_panic(receiver.clone().into()))
-                } else {
-                    // FIXME: Have a correct implementation of tracking global address
-                    // See if we have a parent
-                    // Cleanup, this is a rather crude way of trying to figure out
-                    // whether the node reference is a child of the current parent
-                    // this should be cleaned up once call_frame is refactored
-                    let node_visibility = self.api.kernel_get_node_visibility(receiver);
-                    if node_visibility.0.iter().any(|v| v.is_normal())
-                        && !node_visibility
-                            .0
-                            .iter()
-                            .any(|v| matches!(v, Visibility::FrameOwned))
-                    {
-                        match self.api.kernel_get_system_state().current {
-                            Actor::Method(MethodActor { global_address, .. }) => {
-                                global_address.clone()
-                            }
-                            _ => None,
-                        }
-                    } else {
-                        None
-                    }
-                };
+    // Costing through kernel
+    #[trace_resources]
+    fn call_static_method(
+        &mut self,
+        receiver: &NodeId,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.assert_not_vault_transfer_method(receiver, method_name)?;
+
+        let output = self.call_method_internal(
+            receiver,
+            false,
+            ObjectModuleId::Main,
+            method_name,
+            args,
+            true,
+        )?;
 
-                (node_object_info.clone(), global_address)
-            }
-            // FIXME: verify whether we need to check the modules or not
-            ObjectModuleId::Metadata | ObjectModuleId::Royalty | ObjectModuleId::AccessRules => (
-                ObjectInfo {
-                    global: node_object_info.global,
+        if !output.owned_nodes().is_empty() {
+            return Err(RuntimeError::SystemError(SystemError::StaticCallError(
+                StaticCallError::OwnedNodeMoveNotAllowed,
+            )));
+        }
 
-                    blueprint_id: object_module_id.static_blueprint().unwrap(),
-                    version: BlueprintVersion::default(),
+        Ok(output.into())
+    }
 
-                    blueprint_info: ObjectBlueprintInfo::default(),
-                    features: btreeset!(),
-                    instance_schema: None,
-                },
-                None,
-            ),
-        };
+    // Costing through kernel
+    #[trace_resources]
+    fn transfer_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        amount: Decimal,
+    ) -> Result<(), RuntimeError> {
+        let blueprint_name = self.assert_valid_vault_transfer(from_vault, to_vault)?;
+
+        // Each half is a regular method call, so the withdraw and deposit roles and the freeze
+        // flags of the two vaults are checked as they would be for a take and a put.
+        if blueprint_name.eq(NON_FUNGIBLE_VAULT_BLUEPRINT) {
+            let taken: BTreeSet<NonFungibleLocalId> = self
+                .call_method_internal(
+                    from_vault,
+                    false,
+                    ObjectModuleId::Main,
+                    NON_FUNGIBLE_VAULT_TRANSFER_OUT_IDENT,
+                    scrypto_encode(&NonFungibleVaultTransferOutInput { amount }).unwrap(),
+                    false,
+                )?
+                .as_typed()
+                .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
+
+            return self.transfer_non_fungibles_in(to_vault, taken);
+        }
 
-        let identifier =
-            MethodIdentifier(receiver.clone(), object_module_id, method_name.to_string());
+        let taken: Decimal = self
+            .call_method_internal(
+                from_vault,
+                false,
+                ObjectModuleId::Main,
+                FUNGIBLE_VAULT_TRANSFER_OUT_IDENT,
+                scrypto_encode(&FungibleVaultTransferOutInput { amount }).unwrap(),
+                false,
+            )?
+            .as_typed()
+            .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
 
-        // TODO: Can we load this lazily when needed?
-        let instance_context = if module_object_info.global {
-            match global_address {
-                None => None,
-                Some(address) => Some(InstanceContext {
-                    outer_object: address,
-                    outer_blueprint: module_object_info.blueprint_id.blueprint_name.clone(),
-                }),
-            }
-        } else {
-            match &module_object_info.blueprint_info {
-                ObjectBlueprintInfo::Inner { outer_object } => {
-                    // TODO: do this recursively until global?
-                    let outer_info = self.get_object_info(outer_object.as_node_id())?;
-                    Some(InstanceContext {
-                        outer_object: outer_object.clone(),
-                        outer_blueprint: outer_info.blueprint_id.blueprint_name.clone(),
-                    })
-                }
-                ObjectBlueprintInfo::Outer { .. } => None,
-            }
-        };
+        self.call_method_internal(
+            to_vault,
+            false,
+            ObjectModuleId::Main,
+            FUNGIBLE_VAULT_TRANSFER_IN_IDENT,
+            scrypto_encode(&FungibleVaultTransferInInput { amount: taken }).unwrap(),
+            false,
+        )?;
 
-        let invocation = KernelInvocation {
-            actor: Actor::method(
-                global_address,
-                identifier,
-                module_object_info,
-                instance_context,
-                direct_access,
-            ),
-            args: IndexedScryptoValue::from_vec(args).map_err(|e| {
-                RuntimeError::SystemUpstreamError(SystemUpstreamError::InputDecodeError(e))
-            })?,
-        };
+        Ok(())
+    }
 
-        self.api
-            .kernel_invoke(Box::new(invocation))
-            .map(|v| v.into())
+    // Costing through kernel
+    #[trace_resources]
+    fn transfer_non_fungibles_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    ) -> Result<(), RuntimeError> {
+        let blueprint_name = self.assert_valid_vault_transfer(from_vault, to_vault)?;
+        if !blueprint_name.eq(NON_FUNGIBLE_VAULT_BLUEPRINT) {
+            return Err(RuntimeError::SystemError(SystemError::VaultTransferError(
+                VaultTransferError::NotANonFungibleVault(from_vault.clone()),
+            )));
+        }
+
+        let taken: BTreeSet<NonFungibleLocalId> = self
+            .call_method_internal(
+                from_vault,
+                false,
+                ObjectModuleId::Main,
+                NON_FUNGIBLE_VAULT_TRANSFER_NON_FUNGIBLES_OUT_IDENT,
+                scrypto_encode(&NonFungibleVaultTransferNonFungiblesOutInput {
+                    non_fungible_local_ids,
+                })
+                .unwrap(),
+                false,
+            )?
+            .as_typed()
+            .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
+
+        self.transfer_non_fungibles_in(to_vault, taken)
     }
 
     // Costing through kernel
@@ -1645,15 +2166,23 @@ where
         handle: KeyValueEntryHandle,
         buffer: Vec<u8>,
     ) -> Result<(), RuntimeError> {
-        let LockInfo { data, .. } = self.api.kernel_get_lock_info(handle)?;
+        let LockInfo {
+            node_id,
+            partition_num,
+            substate_key,
+            data,
+            ..
+        } = self.api.kernel_get_lock_info(handle)?;
 
-        let can_own = match data {
+        let (can_own, schema_hash) = match data {
             SystemLockData::KeyValueEntry(KeyValueEntryLockData::BlueprintWrite {
                 blueprint_id,
                 instance_schema,
                 type_pointer: schema_pointer,
                 can_own,
             }) => {
+                let schema_hash =
+                    Self::schema_hash_at_type_pointer(&instance_schema, &schema_pointer);
                 self.validate_payload_at_type_pointer(
                     &blueprint_id,
                     &instance_schema,
@@ -1661,7 +2190,7 @@ where
                     &buffer,
                 )?;
 
-                can_own
+                (can_own, schema_hash)
             }
             SystemLockData::KeyValueEntry(KeyValueEntryLockData::Write {
                 schema,
@@ -1675,7 +2204,18 @@ where
                         ))
                     })?;
 
-                can_own
+                (can_own, Some(hash(scrypto_encode(&schema).unwrap())))
+            }
+            SystemLockData::KeyValueEntry(KeyValueEntryLockData::TransientWrite) => {
+                let substate = IndexedScryptoValue::from_slice(&buffer)
+                    .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
+                if !substate.owned_nodes().is_empty() {
+                    return Err(RuntimeError::SystemError(
+                        SystemError::CannotStoreOwnedInTransientEntry,
+                    ));
+                }
+
+                (false, None)
             }
             _ => {
                 return Err(RuntimeError::SystemError(
@@ -1702,6 +2242,13 @@ where
 
         self.api.kernel_write_substate(handle, indexed)?;
 
+        if let Some(schema_hash) = schema_hash {
+            self.api
+                .kernel_get_system()
+                .modules
+                .add_state_write_schema_hash(node_id, partition_num, substate_key, schema_hash);
+        }
+
         Ok(())
     }
 
@@ -1775,6 +2322,7 @@ where
         if flags.contains(LockFlags::UNMODIFIED_BASE) || flags.contains(LockFlags::FORCE_WRITE) {
             return Err(RuntimeError::SystemError(SystemError::InvalidLockFlags));
         }
+        let flags = self.apply_static_call_lock_flags(flags)?;
 
         let info = match type_info {
             TypeInfoSubstate::KeyValueStore(info) => info,
@@ -1855,6 +2403,8 @@ where
         key: Vec<u8>,
         buffer: Vec<u8>,
     ) -> Result<(), RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num) = self.get_actor_index(actor_object_type, collection_index)?;
@@ -1879,6 +2429,8 @@ where
         collection_index: CollectionIndex,
         key: Vec<u8>,
     ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num) = self.get_actor_index(actor_object_type, collection_index)?;
@@ -1919,6 +2471,8 @@ where
         collection_index: CollectionIndex,
         count: u32,
     ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num) = self.get_actor_index(actor_object_type, collection_index)?;
@@ -1948,6 +2502,8 @@ where
         sorted_key: SortedKey,
         buffer: Vec<u8>,
     ) -> Result<(), RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num) =
@@ -1978,6 +2534,8 @@ where
         collection_index: CollectionIndex,
         sorted_key: &SortedKey,
     ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::WriteNotAllowed)?;
+
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num) =
@@ -2032,6 +2590,8 @@ where
         function_name: &str,
         args: Vec<u8>,
     ) -> Result<Vec<u8>, RuntimeError> {
+        self.assert_not_in_static_call(StaticCallError::NonStaticCallNotAllowed)?;
+
         let identifier = FunctionIdentifier::new(
             BlueprintId::new(&package_address, blueprint_name),
             function_name.to_string(),
@@ -2205,6 +2765,7 @@ where
         field_index: u8,
         flags: LockFlags,
     ) -> Result<LockHandle, RuntimeError> {
+        let flags = self.apply_static_call_lock_flags(flags)?;
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num, schema_pointer, object_info) =
@@ -2243,6 +2804,64 @@ where
         )
     }
 
+    // Costing through kernel
+    #[trace_resources]
+    fn actor_open_transient_entry(
+        &mut self,
+        object_handle: ObjectHandle,
+        key: &Vec<u8>,
+        flags: LockFlags,
+    ) -> Result<KeyValueEntryHandle, RuntimeError> {
+        if flags.contains(LockFlags::UNMODIFIED_BASE) || flags.contains(LockFlags::FORCE_WRITE) {
+            return Err(RuntimeError::SystemError(SystemError::InvalidLockFlags));
+        }
+        let flags = self.apply_static_call_lock_flags(flags)?;
+
+        let actor_object_type: ActorObjectType = object_handle.try_into()?;
+        let node_id = match actor_object_type {
+            ActorObjectType::SELF => self.actor_get_node_id()?,
+            ActorObjectType::OuterObject => match self.actor_get_info()?.blueprint_info {
+                ObjectBlueprintInfo::Inner { outer_object } => outer_object.into_node_id(),
+                ObjectBlueprintInfo::Outer { .. } => {
+                    return Err(RuntimeError::SystemError(
+                        SystemError::OuterObjectDoesNotExist,
+                    ));
+                }
+            },
+        };
+
+        IndexedScryptoValue::from_slice(key)
+            .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
+
+        let lock_data = if flags.contains(LockFlags::MUTABLE) {
+            KeyValueEntryLockData::TransientWrite
+        } else {
+            KeyValueEntryLockData::Read
+        };
+
+        self.api.kernel_open_substate_with_default(
+            &node_id,
+            TRANSIENT_PARTITION,
+            &SubstateKey::Map(key.to_vec()),
+            flags,
+            Some(|| {
+                let kv_entry = KeyValueEntrySubstate::<()>::default();
+                IndexedScryptoValue::from_typed(&kv_entry)
+            }),
+            SystemLockData::KeyValueEntry(lock_data),
+        )
+    }
+
+    // Costing through kernel
+    fn actor_remove_transient_entry(
+        &mut self,
+        object_handle: ObjectHandle,
+        key: &Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        let handle = self.actor_open_transient_entry(object_handle, key, LockFlags::MUTABLE)?;
+        self.key_value_entry_remove_and_close_substate(handle)
+    }
+
     #[trace_resources]
     fn actor_get_info(&mut self) -> Result<ObjectInfo, RuntimeError> {
         self.api
@@ -2291,6 +2910,16 @@ where
         }
     }
 
+    #[trace_resources]
+    fn actor_get_global_caller(&mut self) -> Result<Option<GlobalCaller>, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(CostingEntry::QueryActor)?;
+
+        Ok(self.api.kernel_get_system_state().caller.as_global_caller())
+    }
+
     #[trace_resources]
     fn actor_get_blueprint(&mut self) -> Result<BlueprintId, RuntimeError> {
         self.api
@@ -2367,6 +2996,7 @@ where
         key: &Vec<u8>,
         flags: LockFlags,
     ) -> Result<KeyValueEntryHandle, RuntimeError> {
+        let flags = self.apply_static_call_lock_flags(flags)?;
         let actor_object_type: ActorObjectType = object_handle.try_into()?;
 
         let (node_id, partition_num, kv_schema, object_info) =
@@ -2470,6 +3100,17 @@ where
         )?;
         match auth_result {
             AuthorizationCheckResult::Authorized => Ok(()),
+            AuthorizationCheckResult::Failed(..)
+                if self
+                    .api
+                    .kernel_get_system()
+                    .modules
+                    .auth
+                    .params
+                    .assume_all_access_rule_checks_pass =>
+            {
+                Ok(())
+            }
             AuthorizationCheckResult::Failed(..) => Err(RuntimeError::SystemError(
                 SystemError::AssertAccessRuleFailed,
             )),
@@ -2500,15 +3141,15 @@ where
 {
     #[trace_resources]
     fn emit_event(&mut self, event_name: String, event_data: Vec<u8>) -> Result<(), RuntimeError> {
-        self.api
-            .kernel_get_system()
-            .modules
-            .apply_execution_cost(CostingEntry::EmitEvent {
-                size: event_data.len(),
-            })?;
+        let modules = &mut self.api.kernel_get_system().modules;
+        let count = modules.number_of_events();
+        modules.apply_execution_cost(CostingEntry::EmitEvent {
+            size: event_data.len(),
+            count,
+        })?;
 
         // Locking the package info substate associated with the emitter's package
-        let type_pointer = {
+        let (type_pointer, schema_hash) = {
             let actor = self.api.kernel_get_system_state().current;
 
             // Getting the package address and blueprint name associated with the actor
@@ -2549,7 +3190,10 @@ where
                 &[(&event_data, type_pointer.clone())],
             )?;
 
-            type_pointer
+            let schema_hash = Self::schema_hash_at_type_pointer(&instance_schema, &type_pointer)
+                .expect("Checked by payload-schema validation");
+
+            (type_pointer, schema_hash)
         };
 
         // Construct the event type identifier based on the current actor
@@ -2578,27 +3222,31 @@ where
         }?;
 
         // Adding the event to the event store
-        self.api
-            .kernel_get_system()
-            .modules
-            .add_event(event_type_identifier, event_data)?;
+        let actor = actor.fn_identifier();
+        self.api.kernel_get_system().modules.add_event(
+            event_type_identifier,
+            event_data,
+            schema_hash,
+            actor,
+        )?;
 
         Ok(())
     }
 
     #[trace_resources]
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
-        self.api
-            .kernel_get_system()
-            .modules
-            .apply_execution_cost(CostingEntry::EmitLog {
-                size: message.len(),
-            })?;
+        let modules = &mut self.api.kernel_get_system().modules;
+        let count = modules.number_of_logs();
+        modules.apply_execution_cost(CostingEntry::EmitLog {
+            size: message.len(),
+            count,
+        })?;
 
+        let actor = self.actor_get_fn_identifier()?;
         self.api
             .kernel_get_system()
             .modules
-            .add_log(level, message)?;
+            .add_log(level, message, actor)?;
 
         Ok(())
     }
@@ -2621,6 +3269,31 @@ where
         )))
     }
 
+    fn fail(&mut self, error_name: String, error: Vec<u8>) -> Result<(), RuntimeError> {
+        let size = error_name.len() + error.len();
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(CostingEntry::Panic { size })?;
+
+        self.api
+            .kernel_get_system()
+            .modules
+            .check_panic_size(size)?;
+
+        let error = scrypto_decode::<ScryptoValue>(&error)
+            .map_err(|e| RuntimeError::SystemError(SystemError::InvalidScryptoValue(e)))?;
+        let fn_identifier = self.actor_get_fn_identifier()?;
+
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::BlueprintError(Box::new(BlueprintError {
+                fn_identifier,
+                error_name,
+                error,
+            })),
+        ))
+    }
+
     #[trace_resources]
     fn get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
         self.api
@@ -2654,6 +3327,31 @@ where
     }
 }
 
+impl<'a, Y, V> ClientBigIntApi<RuntimeError> for SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    #[trace_resources]
+    fn big_int_op(
+        &mut self,
+        int_type: BigIntType,
+        operation: BigIntOperation,
+        operands: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(CostingEntry::BigIntOp {
+                int_type,
+                operation,
+            })?;
+
+        evaluate_big_int_operation(int_type, operation, &operands)
+            .map_err(|e| RuntimeError::SystemError(SystemError::BigIntError(e)))
+    }
+}
+
 impl<'a, Y, V> ClientApi<RuntimeError> for SystemService<'a, Y, V>
 where
     Y: KernelApi<SystemConfig<V>>,
@@ -2813,6 +3511,10 @@ where
         self.api.kernel_get_current_depth()
     }
 
+    fn kernel_get_current_frame_heap_size(&self) -> usize {
+        self.api.kernel_get_current_frame_heap_size()
+    }
+
     fn kernel_get_node_visibility(&self, node_id: &NodeId) -> NodeVisibility {
         self.api.kernel_get_node_visibility(node_id)
     }
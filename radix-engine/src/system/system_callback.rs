@@ -7,6 +7,7 @@ use crate::kernel::call_frame::Message;
 use crate::kernel::kernel_api::KernelSubstateApi;
 use crate::kernel::kernel_api::{KernelApi, KernelInvocation};
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
+use crate::system::id_allocation::GlobalAddressReservationState;
 use crate::system::module::SystemModule;
 use crate::system::system::KeyValueEntrySubstate;
 use crate::system::system::SystemService;
@@ -58,6 +59,9 @@ pub enum KeyValueEntryLockData {
         type_pointer: TypePointer,
         can_own: bool,
     },
+    /// Write to an entry of an object's transient store, which is neither typed nor allowed to
+    /// own nodes (as it is discarded at the end of the transaction).
+    TransientWrite,
 }
 
 #[derive(Clone)]
@@ -277,6 +281,17 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
         SystemModuleMixer::on_allocate_node_id(api, entity_type)
     }
 
+    fn after_allocate_node_id<Y>(node_id: &NodeId, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: KernelApi<Self>,
+    {
+        SystemModuleMixer::after_allocate_node_id(api, node_id)
+    }
+
+    fn on_read_substate_value(&mut self, lock_handle: LockHandle, value: &IndexedScryptoValue) {
+        self.modules.on_read_substate_value(lock_handle, value)
+    }
+
     fn after_move_modules<Y>(
         src_node_id: &NodeId,
         dest_node_id: &NodeId,
@@ -407,10 +422,11 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                         .get_function_output_type_pointer(ident.as_str())
                         .expect("Schema verification should enforce that this exists.");
 
-                    system.validate_payload_against_blueprint_schema(
+                    system.validate_fn_output(
                         &blueprint_id,
-                        &None,
-                        &[(output.as_vec_ref(), output_type_pointer)],
+                        ident.as_str(),
+                        output_type_pointer,
+                        output.as_slice(),
                     )?;
                 }
                 FnIdent::System(..) => {
@@ -467,6 +483,22 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                         }
                     }
                 }
+                TypeInfoSubstate::GlobalAddressReservation(global_address) => {
+                    // Release the unused reservation along with the address phantom, so that
+                    // a leaked reservation doesn't leave the address unusable.
+                    api.kernel_drop_node(&node_id)?;
+                    api.kernel_remove_substate(
+                        global_address.as_node_id(),
+                        TYPE_INFO_FIELD_PARTITION,
+                        &TypeInfoField::TypeInfo.into(),
+                    )?;
+                    api.kernel_get_system()
+                        .modules
+                        .set_global_address_reservation_state(
+                            global_address,
+                            GlobalAddressReservationState::Released,
+                        );
+                }
                 _ => {}
             }
         }
@@ -30,6 +30,8 @@ pub enum AuthError {
     Unauthorized(Box<Unauthorized>),
     InnerBlueprintDoesNotExist(String),
     InvalidOuterObjectMapping,
+    /// The component has been paused by its owner and the method is not exempt.
+    ComponentPaused(FnIdentifier),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -89,6 +91,12 @@ impl AuthModule {
         Y: KernelApi<SystemConfig<V>>,
     {
         if let Some(auth_zone_id) = api.kernel_get_system().modules.auth.last_auth_zone() {
+            let assume_all_access_rule_checks_pass = api
+                .kernel_get_system()
+                .modules
+                .auth
+                .params
+                .assume_all_access_rule_checks_pass;
             let mut system = SystemService::new(api);
 
             // Step 1: Resolve method to permission
@@ -123,13 +131,20 @@ impl AuthModule {
             };
 
             // Step 2: Check permission
-            Self::check_permission(
+            // The check is run even if it's assumed to pass, so that it's still charged for
+            let result = Self::check_permission(
                 &auth_zone_id,
                 acting_location,
                 permission,
                 callee.fn_identifier(),
                 &mut system,
-            )?;
+            );
+            match result {
+                Err(RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                    AuthError::Unauthorized(..),
+                ))) if assume_all_access_rule_checks_pass => {}
+                result => result?,
+            }
         } else {
             // Bypass auth check for ROOT frame
         }
@@ -213,6 +228,17 @@ impl AuthModule {
             );
         }
 
+        // Pausing applies to every main module method of a global component, whatever auth
+        // template its blueprint uses
+        if callee.module_id == ObjectModuleId::Main
+            && callee.node_id.is_global_component()
+            && AccessRulesNativePackage::is_paused(&callee.node_id, callee.ident.as_str(), api)?
+        {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::AuthError(AuthError::ComponentPaused(callee.fn_identifier())),
+            ));
+        }
+
         let auth_template = PackageAuthNativeBlueprint::get_bp_auth_template(
             callee
                 .module_object_info
@@ -8,7 +8,8 @@ use crate::system::system_modules::auth::{
     AuthorityListAuthorizationResult, AuthorizationCheckResult,
 };
 use crate::types::*;
-use native_sdk::resource::{NativeNonFungibleProof, NativeProof};
+use native_sdk::resource::{NativeNonFungibleProof, NativeProof, ResourceManager};
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::{ClientApi, ClientObjectApi, LockFlags, ObjectModuleId};
 use radix_engine_interface::blueprints::resource::*;
 use sbor::rust::ops::Fn;
@@ -36,14 +37,74 @@ impl Authorization {
                     proof_resource_address == non_fungible_global_id.resource_address()
                         && proof
                             .non_fungible_local_ids(api)?
-                            .contains(non_fungible_global_id.local_id()),
+                            .contains(non_fungible_global_id.local_id())
+                        && Self::is_usable_badge(
+                            &proof_resource_address,
+                            non_fungible_global_id.local_id(),
+                            api,
+                        )?,
                 )
             }
             ResourceOrNonFungible::Resource(resource_address) => {
                 let proof_resource_address = proof.resource_address(api)?;
-                Ok(proof_resource_address == *resource_address)
+                if proof_resource_address != *resource_address {
+                    return Ok(false);
+                }
+                if !Self::is_time_boxed(resource_address, api)? {
+                    return Ok(true);
+                }
+                Ok(Self::usable_amount(proof, resource_address, api)?.is_positive())
+            }
+        }
+    }
+
+    fn is_time_boxed<Y: ClientObjectApi<RuntimeError>>(
+        resource_address: &ResourceAddress,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError> {
+        if resource_address.is_fungible() {
+            return Ok(false);
+        }
+        Ok(api
+            .get_object_info(resource_address.as_node_id())?
+            .features
+            .contains(TIME_BOXED_FEATURE))
+    }
+
+    /// Whether the badge can be used for auth, which time-boxed badges only can during their
+    /// validity window.
+    fn is_usable_badge<Y: ClientObjectApi<RuntimeError>>(
+        resource_address: &ResourceAddress,
+        local_id: &NonFungibleLocalId,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError> {
+        if !Self::is_time_boxed(resource_address, api)? {
+            return Ok(true);
+        }
+        let data: ScryptoValue =
+            ResourceManager(*resource_address).get_non_fungible_data(local_id.clone(), api)?;
+        let current_epoch = Runtime::current_epoch(api)?;
+        Ok(ValidityWindow::from_non_fungible_data(&data)
+            .map_or(false, |window| window.contains(current_epoch)))
+    }
+
+    /// The amount of the proof which can be used for auth, excluding any time-boxed badges
+    /// outside of their validity window.
+    fn usable_amount<Y: ClientObjectApi<RuntimeError>>(
+        proof: &Proof,
+        resource_address: &ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError> {
+        if !Self::is_time_boxed(resource_address, api)? {
+            return proof.amount(api);
+        }
+        let mut amount = Decimal::zero();
+        for local_id in proof.non_fungible_local_ids(api)? {
+            if Self::is_usable_badge(resource_address, &local_id, api)? {
+                amount += Decimal::one();
             }
         }
+        Ok(amount)
     }
 
     fn auth_zone_stack_matches<P, Y>(
@@ -142,7 +203,7 @@ impl Authorization {
                 // TODO: revisit this and decide if we need to check the composite max amount rather than just each proof individually
                 for p in auth_zone.proofs() {
                     if Self::proof_matches(&ResourceOrNonFungible::Resource(*resource), p, api)?
-                        && p.amount(api)? >= amount
+                        && Self::usable_amount(p, resource, api)? >= amount
                     {
                         return Ok(true);
                     }
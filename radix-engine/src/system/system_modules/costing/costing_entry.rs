@@ -2,6 +2,7 @@ use super::FeeTable;
 use crate::kernel::actor::Actor;
 use crate::track::interface::{StoreAccessInfo, StoreCommit};
 use crate::types::*;
+use radix_engine_interface::api::{BigIntOperation, BigIntType};
 use radix_engine_interface::*;
 
 #[derive(Debug, IntoStaticStr)]
@@ -100,11 +101,19 @@ pub enum CostingEntry<'a> {
     AssertAccessRule,
     QueryTransactionHash,
     GenerateRuid,
+    BigIntOp {
+        int_type: BigIntType,
+        operation: BigIntOperation,
+    },
     EmitEvent {
         size: usize,
+        /// The number of events already emitted by the transaction
+        count: usize,
     },
     EmitLog {
         size: usize,
+        /// The number of logs already emitted by the transaction
+        count: usize,
     },
     Panic {
         size: usize,
@@ -184,8 +193,12 @@ impl<'a> CostingEntry<'a> {
             CostingEntry::AssertAccessRule => ft.assert_access_rule_cost(),
             CostingEntry::QueryTransactionHash => ft.query_transaction_hash_cost(),
             CostingEntry::GenerateRuid => ft.generate_ruid_cost(),
-            CostingEntry::EmitEvent { size } => ft.emit_event_cost(*size),
-            CostingEntry::EmitLog { size } => ft.emit_log_cost(*size),
+            CostingEntry::BigIntOp {
+                int_type,
+                operation,
+            } => ft.big_int_op_cost(*int_type, *operation),
+            CostingEntry::EmitEvent { size, count } => ft.emit_event_cost(*size, *count),
+            CostingEntry::EmitLog { size, count } => ft.emit_log_cost(*size, *count),
             CostingEntry::Panic { size } => ft.panic_cost(*size),
             CostingEntry::RoyaltyModule { direct_charge } => *direct_charge,
             CostingEntry::AuthModule { direct_charge } => *direct_charge,
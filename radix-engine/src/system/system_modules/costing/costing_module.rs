@@ -14,6 +14,7 @@ use crate::{
     errors::{CanBeAbortion, RuntimeError, SystemModuleError},
     transaction::AbortReason,
 };
+use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::package::BlueprintVersionKey;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::{types::NodeId, *};
@@ -21,16 +22,57 @@ use radix_engine_interface::{types::NodeId, *};
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum CostingError {
     FeeReserveError(FeeReserveError),
+    ExecutionDeadlineExceeded {
+        deadline: ExecutionDeadline,
+        metrics: ExecutionDeadlineMetrics,
+    },
+    /// The rent balance of the component doesn't cover the rent it owes, so only its modules
+    /// can be called until it's topped up.
+    ComponentDormant {
+        component_address: ComponentAddress,
+        rent_owed: Decimal,
+        rent_balance: Decimal,
+    },
+    /// The package and component royalties of a call add up to more than allowed per call,
+    /// which may happen with USD royalties when the USD price changes.
+    RoyaltyExceedsPerCallMaximum {
+        max: Decimal,
+        actual: Decimal,
+    },
 }
 
 impl CanBeAbortion for CostingError {
     fn abortion(&self) -> Option<&AbortReason> {
         match self {
             Self::FeeReserveError(err) => err.abortion(),
+            Self::ExecutionDeadlineExceeded { .. }
+            | Self::ComponentDormant { .. }
+            | Self::RoyaltyExceedsPerCallMaximum { .. } => None,
         }
     }
 }
 
+/// A bound on the execution of a transaction, checked whenever execution cost is applied.
+///
+/// Unlike the cost unit limit, exceeding a deadline rejects the transaction, regardless of
+/// whether the fee loan has been repaid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub enum ExecutionDeadline {
+    /// Execution may consume at most this many cost units.
+    CostUnits(u32),
+    /// Execution may take at most this many milliseconds of wall-clock time.
+    /// This is only enforced with the `std` feature.
+    WallClockMillis(u64),
+}
+
+/// How far execution got before a deadline was exceeded.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ExecutionDeadlineMetrics {
+    pub cost_units_consumed: u32,
+    /// The wall-clock time since execution started, if measurable.
+    pub elapsed_millis: Option<u64>,
+}
+
 #[derive(Debug, Clone)]
 pub struct CostingModule {
     pub fee_reserve: SystemLoanFeeReserve,
@@ -40,8 +82,16 @@ pub struct CostingModule {
     pub num_of_signatures: usize,
     /// The maximum allowed method royalty in XRD allowed to be set by package and component owners
     pub max_per_function_royalty_in_xrd: Decimal,
+    /// The maximum of package and component royalties in XRD charged for a single call
+    pub max_per_call_royalty_in_xrd: Decimal,
     pub enable_cost_breakdown: bool,
     pub costing_traces: IndexMap<String, u32>,
+    /// The index of the instruction being executed, if any
+    pub instruction_index: Option<usize>,
+    pub instruction_costing_traces: IndexMap<usize, InstructionCostBreakdown>,
+    pub execution_deadline: Option<ExecutionDeadline>,
+    #[cfg(feature = "std")]
+    pub execution_start: std::time::Instant,
 }
 
 impl CostingModule {
@@ -69,9 +119,96 @@ impl CostingModule {
                 .entry(key)
                 .or_default()
                 .add_assign(cost_units);
+            self.trace_instruction_cost(&costing_entry, cost_units);
         }
 
-        Ok(())
+        self.check_execution_deadline()
+    }
+
+    pub fn update_instruction_index(&mut self, new_index: usize) {
+        self.instruction_index = Some(new_index);
+    }
+
+    fn trace_instruction_cost(&mut self, costing_entry: &CostingEntry, cost_units: u32) {
+        // Transaction-wide costs aren't attributed to the instruction being executed
+        if matches!(
+            costing_entry,
+            CostingEntry::TxBaseCost
+                | CostingEntry::TxPayloadCost { .. }
+                | CostingEntry::TxSignatureVerification { .. }
+                | CostingEntry::Commit { .. }
+        ) {
+            return;
+        }
+        let breakdown = match self.instruction_index {
+            Some(instruction_index) => self
+                .instruction_costing_traces
+                .entry(instruction_index)
+                .or_default(),
+            None => return,
+        };
+        let category = match costing_entry {
+            CostingEntry::RunWasmCode { .. } | CostingEntry::PrepareWasmCode { .. } => {
+                &mut breakdown.wasm_execution_cost_units
+            }
+            CostingEntry::OpenSubstate { .. }
+            | CostingEntry::ReadSubstate { .. }
+            | CostingEntry::CloseSubstate { .. }
+            | CostingEntry::ScanSortedSubstates { .. }
+            | CostingEntry::ScanSubstates { .. } => &mut breakdown.substate_read_cost_units,
+            CostingEntry::WriteSubstate { .. }
+            | CostingEntry::SetSubstate { .. }
+            | CostingEntry::RemoveSubstate { .. }
+            | CostingEntry::TakeSubstate { .. } => &mut breakdown.substate_write_cost_units,
+            _ => &mut breakdown.other_execution_cost_units,
+        };
+        category.add_assign(cost_units);
+    }
+
+    fn trace_instruction_royalty(&mut self, royalty_cost_xrd: Decimal) {
+        if !self.enable_cost_breakdown {
+            return;
+        }
+        if let Some(instruction_index) = self.instruction_index {
+            self.instruction_costing_traces
+                .entry(instruction_index)
+                .or_default()
+                .royalty_cost_xrd += royalty_cost_xrd;
+        }
+    }
+
+    fn check_execution_deadline(&self) -> Result<(), RuntimeError> {
+        let deadline = match self.execution_deadline {
+            Some(deadline) => deadline,
+            None => return Ok(()),
+        };
+
+        #[cfg(feature = "std")]
+        let elapsed_millis = Some(self.execution_start.elapsed().as_millis() as u64);
+        #[cfg(not(feature = "std"))]
+        let elapsed_millis: Option<u64> = None;
+
+        let cost_units_consumed = self.fee_reserve.execution_cost_units_consumed();
+        let exceeded = match deadline {
+            ExecutionDeadline::CostUnits(limit) => cost_units_consumed > limit,
+            ExecutionDeadline::WallClockMillis(limit) => {
+                elapsed_millis.map_or(false, |elapsed| elapsed > limit)
+            }
+        };
+
+        if exceeded {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::CostingError(CostingError::ExecutionDeadlineExceeded {
+                    deadline,
+                    metrics: ExecutionDeadlineMetrics {
+                        cost_units_consumed,
+                        elapsed_millis,
+                    },
+                }),
+            ))
+        } else {
+            Ok(())
+        }
     }
 
     pub fn apply_deferred_execution_cost(
@@ -112,6 +249,19 @@ impl CostingModule {
         Ok(())
     }
 
+    pub fn apply_instantiation_deposit(
+        &mut self,
+        global_address: GlobalAddress,
+    ) -> Result<(), RuntimeError> {
+        self.fee_reserve
+            .consume_instantiation_deposit(global_address)
+            .map_err(|e| {
+                RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                    CostingError::FeeReserveError(e),
+                ))
+            })
+    }
+
     pub fn credit_cost_units(
         &mut self,
         vault_id: NodeId,
@@ -128,17 +278,51 @@ impl CostingModule {
     }
 }
 
+/// Whether calling the given function or method is exempt from package and component royalties.
+///
+/// System methods (those of native packages, including the account fee locking methods, and of
+/// object modules) are never charged royalties, so that paying fees and refunding deposits can't
+/// be griefed.
+pub fn is_royalty_exempt(blueprint: &BlueprintId, is_main_module: bool, is_method: bool) -> bool {
+    if is_method && !is_main_module {
+        return true;
+    }
+    NATIVE_PACKAGES.contains(&blueprint.package_address)
+}
+
 pub fn apply_royalty_cost<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
     api: &mut Y,
     royalty_amount: RoyaltyAmount,
     recipient: RoyaltyRecipient,
     recipient_vault_id: NodeId,
+) -> Result<(), RuntimeError> {
+    let costing = &mut api.kernel_get_system().modules.costing;
+    let royalty_cost_before = costing.fee_reserve.royalty_cost_committed();
+    costing
+        .fee_reserve
+        .consume_royalty(royalty_amount, recipient, recipient_vault_id)
+        .map_err(|e| {
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::FeeReserveError(e),
+            ))
+        })?;
+    let royalty_cost = costing.fee_reserve.royalty_cost_committed() - royalty_cost_before;
+    costing.trace_instruction_royalty(royalty_cost);
+
+    Ok(())
+}
+
+pub fn apply_rent_cost<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+    api: &mut Y,
+    component_address: ComponentAddress,
+    rent_vault_id: NodeId,
+    amount: Decimal,
 ) -> Result<(), RuntimeError> {
     api.kernel_get_system()
         .modules
         .costing
         .fee_reserve
-        .consume_royalty(royalty_amount, recipient, recipient_vault_id)
+        .consume_rent(component_address, rent_vault_id, amount)
         .map_err(|e| {
             RuntimeError::SystemModuleError(SystemModuleError::CostingError(
                 CostingError::FeeReserveError(e),
@@ -201,28 +385,52 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for CostingModule {
         _args: &IndexedScryptoValue,
     ) -> Result<(), RuntimeError> {
         // Identify the function, and optional component address
-        let (blueprint, ident, optional_component) = {
+        let (blueprint, ident, optional_component, is_main_module) = {
             let blueprint = callee.blueprint_id();
-            let (maybe_component, ident) = match &callee {
-                Actor::Method(MethodActor { node_id, ident, .. }) => {
+            let (maybe_component, ident, is_main_module) = match &callee {
+                Actor::Method(MethodActor {
+                    node_id,
+                    ident,
+                    module_id,
+                    ..
+                }) => {
+                    let is_main_module = *module_id == ObjectModuleId::Main;
                     if node_id.is_global_component() {
                         (
                             Some(ComponentAddress::new_or_panic(node_id.clone().into())),
                             ident,
+                            is_main_module,
                         )
                     } else {
-                        (None, ident)
+                        (None, ident, is_main_module)
                     }
                 }
-                Actor::Function { ident, .. } => (None, ident),
+                Actor::Function { ident, .. } => (None, ident, false),
                 Actor::VirtualLazyLoad { .. } | Actor::Root => {
                     return Ok(());
                 }
             };
 
-            (blueprint, ident, maybe_component)
+            (blueprint, ident, maybe_component, is_main_module)
         };
 
+        //===========================
+        // Apply component rent
+        //===========================
+        if let Some(component_address) = optional_component.filter(|_| is_main_module) {
+            ComponentRoyaltyBlueprint::charge_component_rent(component_address.as_node_id(), api)?;
+        }
+
+        if is_royalty_exempt(&blueprint, is_main_module, optional_component.is_some()) {
+            return Ok(());
+        }
+        let royalty_before_call = api
+            .kernel_get_system()
+            .modules
+            .costing
+            .fee_reserve
+            .royalty_cost_committed();
+
         //===========================
         // Apply package royalty
         //===========================
@@ -245,6 +453,20 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for CostingModule {
             )?;
         }
 
+        //===========================
+        // Check royalty cap
+        //===========================
+        let costing = &api.kernel_get_system().modules.costing;
+        let royalty_of_call = costing.fee_reserve.royalty_cost_committed() - royalty_before_call;
+        if royalty_of_call > costing.max_per_call_royalty_in_xrd {
+            return Err(RuntimeError::SystemModuleError(
+                SystemModuleError::CostingError(CostingError::RoyaltyExceedsPerCallMaximum {
+                    max: costing.max_per_call_royalty_in_xrd,
+                    actual: royalty_of_call,
+                }),
+            ));
+        }
+
         Ok(())
     }
 
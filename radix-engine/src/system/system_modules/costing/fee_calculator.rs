@@ -0,0 +1,121 @@
+use super::{FeeSummary, RoyaltyRecipient};
+use crate::types::*;
+use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
+
+/// The tip charged per cost unit, in XRD.
+pub fn tip_price(cost_unit_price: Decimal, tip_percentage: u16) -> Decimal {
+    cost_unit_price * u32::from(tip_percentage) / dec!(100)
+}
+
+/// Splits the fee required by a transaction across its fee locks, in the order the engine
+/// charges them: from the latest lock backwards, skipping contingent locks unless the transaction
+/// succeeded.
+///
+/// Returns the amount charged to each lock, in the order of `locked_fees`, and the part of the
+/// fee the locks couldn't cover.
+pub fn charge_locked_fees(
+    locked_fees: &[(NodeId, LiquidFungibleResource, bool)],
+    required: Decimal,
+    is_success: bool,
+) -> (Vec<Decimal>, Decimal) {
+    let mut charges = vec![Decimal::ZERO; locked_fees.len()];
+    let mut required = required;
+    for (index, (_, locked, contingent)) in locked_fees.iter().enumerate().rev() {
+        let amount = if *contingent && !is_success {
+            Decimal::zero()
+        } else {
+            Decimal::min(locked.amount(), required)
+        };
+        charges[index] = amount;
+        required -= amount;
+    }
+    (charges, required)
+}
+
+/// The outcome of fee settlement for the fee payers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeSettlement {
+    /// The XRD taken from each vault which locked fees.
+    pub payments: IndexMap<NodeId, Decimal>,
+    /// The locked XRD returned to each vault which locked fees.
+    pub refunds: IndexMap<NodeId, Decimal>,
+    /// The part of the fee not covered by the locked fees, which would fail the transaction.
+    pub shortfall: Decimal,
+}
+
+/// Recomputes the fee settlement of a transaction for hypothetical changes to its tip or
+/// royalties, with the same math the engine settles fees with.
+///
+/// The execution and state expansion costs are assumed to stay the same, which holds as long as
+/// the changes don't alter what the transaction does.
+#[derive(Debug, Clone)]
+pub struct FeeCalculator {
+    fee_summary: FeeSummary,
+    is_success: bool,
+}
+
+impl FeeCalculator {
+    pub fn new(fee_summary: &FeeSummary, is_success: bool) -> Self {
+        Self {
+            fee_summary: fee_summary.clone(),
+            is_success,
+        }
+    }
+
+    pub fn with_tip_percentage(mut self, tip_percentage: u16) -> Self {
+        self.fee_summary.tip_percentage = tip_percentage;
+        self.fee_summary.total_tipping_cost_xrd =
+            tip_price(self.fee_summary.cost_unit_price, tip_percentage)
+                * self.fee_summary.execution_cost_sum;
+        self
+    }
+
+    /// Sets the royalty charged for a recipient, paid into the given vault of the recipient.
+    /// An amount of zero removes the royalty.
+    pub fn with_royalty(
+        mut self,
+        recipient: RoyaltyRecipient,
+        vault_id: NodeId,
+        amount: Decimal,
+    ) -> Self {
+        if let Some((_, previous)) = self.fee_summary.royalty_cost_breakdown.remove(&recipient) {
+            self.fee_summary.total_royalty_cost_xrd -= previous;
+        }
+        if amount.is_positive() {
+            self.fee_summary
+                .royalty_cost_breakdown
+                .insert(recipient, (vault_id, amount));
+            self.fee_summary.total_royalty_cost_xrd += amount;
+        }
+        self
+    }
+
+    /// The fee summary, with the hypothetical changes applied.
+    pub fn fee_summary(&self) -> &FeeSummary {
+        &self.fee_summary
+    }
+
+    /// The total XRD to be taken from the fee locks.
+    pub fn total_fee(&self) -> Decimal {
+        self.fee_summary.total_cost() + self.fee_summary.total_instantiation_deposit_xrd
+    }
+
+    pub fn settle(&self) -> FeeSettlement {
+        let locked_fees = &self.fee_summary.locked_fees;
+        let (charges, shortfall) =
+            charge_locked_fees(locked_fees, self.total_fee(), self.is_success);
+
+        let mut payments: IndexMap<NodeId, Decimal> = index_map_new();
+        let mut refunds: IndexMap<NodeId, Decimal> = index_map_new();
+        for ((vault_id, locked, _), amount) in locked_fees.iter().zip(charges).rev() {
+            *payments.entry(*vault_id).or_default() += amount;
+            *refunds.entry(*vault_id).or_default() += locked.amount() - amount;
+        }
+
+        FeeSettlement {
+            payments,
+            refunds,
+            shortfall,
+        }
+    }
+}
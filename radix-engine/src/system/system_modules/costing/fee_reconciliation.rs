@@ -0,0 +1,322 @@
+use super::{FeeSummary, RoyaltyRecipient};
+use crate::types::*;
+
+/// A line of a fee statement: an amount of XRD moved by fee settlement, signed from the point
+/// of view of the fee payers. Amounts taken from payers are negative, and amounts returned to
+/// payers or credited to a cost are positive.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum FeeStatementLine {
+    /// XRD locked from a vault, for all the locks of the vault.
+    Locked {
+        vault_id: NodeId,
+        amount: Decimal,
+    },
+    /// Locked XRD returned to a vault, because it wasn't needed.
+    Refunded {
+        vault_id: NodeId,
+        amount: Decimal,
+    },
+    ExecutionCost {
+        amount: Decimal,
+    },
+    Tip {
+        amount: Decimal,
+    },
+    StateExpansionCost {
+        amount: Decimal,
+    },
+    Royalty {
+        recipient: RoyaltyRecipient,
+        vault_id: NodeId,
+        amount: Decimal,
+    },
+    InstantiationDeposit {
+        global_address: GlobalAddress,
+        amount: Decimal,
+    },
+}
+
+impl FeeStatementLine {
+    pub fn amount(&self) -> Decimal {
+        match self {
+            FeeStatementLine::Locked { amount, .. } => -*amount,
+            FeeStatementLine::Refunded { amount, .. }
+            | FeeStatementLine::ExecutionCost { amount }
+            | FeeStatementLine::Tip { amount }
+            | FeeStatementLine::StateExpansionCost { amount }
+            | FeeStatementLine::Royalty { amount, .. }
+            | FeeStatementLine::InstantiationDeposit { amount, .. } => *amount,
+        }
+    }
+}
+
+/// An inconsistency found when reconciling the fees of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum FeeReconciliationError {
+    /// The statement lines don't sum to zero.
+    Unbalanced {
+        balance: Decimal,
+    },
+    /// A vault was charged more than it locked.
+    OverchargedVault {
+        vault_id: NodeId,
+        locked: Decimal,
+        paid: Decimal,
+    },
+    /// A vault was charged without having locked any fee.
+    UnlockedVaultCharged {
+        vault_id: NodeId,
+        paid: Decimal,
+    },
+    /// The breakdown of a cost doesn't add up to its total in the fee summary.
+    BreakdownMismatch {
+        cost: String,
+        total: Decimal,
+        breakdown: Decimal,
+    },
+    NegativeAmount {
+        line: FeeStatementLine,
+    },
+    UnrepaidLoan {
+        bad_debt: Decimal,
+    },
+}
+
+/// The fees of a committed transaction as a balanced statement: what each payer locked and got
+/// refunded, against what each cost was charged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct FeeReconciliation {
+    pub lines: Vec<FeeStatementLine>,
+}
+
+impl FeeReconciliation {
+    /// Builds the statement of a finalized fee summary, including its fee payments.
+    pub fn from_fee_summary(fee_summary: &FeeSummary) -> Self {
+        let mut locked: IndexMap<NodeId, Decimal> = index_map_new();
+        for (vault_id, resource, _contingent) in &fee_summary.locked_fees {
+            *locked.entry(*vault_id).or_default() += resource.amount();
+        }
+
+        let mut lines = Vec::new();
+        for (vault_id, amount) in &locked {
+            lines.push(FeeStatementLine::Locked {
+                vault_id: *vault_id,
+                amount: *amount,
+            });
+        }
+        for (vault_id, amount) in &locked {
+            let paid = fee_summary
+                .fee_payments
+                .get(vault_id)
+                .cloned()
+                .unwrap_or_default();
+            lines.push(FeeStatementLine::Refunded {
+                vault_id: *vault_id,
+                amount: *amount - paid,
+            });
+        }
+        lines.push(FeeStatementLine::ExecutionCost {
+            amount: fee_summary.total_execution_cost_xrd,
+        });
+        lines.push(FeeStatementLine::Tip {
+            amount: fee_summary.total_tipping_cost_xrd,
+        });
+        lines.push(FeeStatementLine::StateExpansionCost {
+            amount: fee_summary.total_state_expansion_cost_xrd,
+        });
+        for (recipient, (vault_id, amount)) in &fee_summary.royalty_cost_breakdown {
+            lines.push(FeeStatementLine::Royalty {
+                recipient: recipient.clone(),
+                vault_id: *vault_id,
+                amount: *amount,
+            });
+        }
+        for (global_address, amount) in &fee_summary.instantiation_deposits {
+            lines.push(FeeStatementLine::InstantiationDeposit {
+                global_address: *global_address,
+                amount: *amount,
+            });
+        }
+
+        Self { lines }
+    }
+
+    /// The sum of all lines, which is zero for a consistent statement.
+    pub fn balance(&self) -> Decimal {
+        self.lines
+            .iter()
+            .fold(Decimal::ZERO, |balance, line| balance + line.amount())
+    }
+
+    pub fn total_locked(&self) -> Decimal {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                FeeStatementLine::Locked { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .fold(Decimal::ZERO, |total, amount| total + amount)
+    }
+
+    pub fn total_refunded(&self) -> Decimal {
+        self.lines
+            .iter()
+            .filter_map(|line| match line {
+                FeeStatementLine::Refunded { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .fold(Decimal::ZERO, |total, amount| total + amount)
+    }
+
+    /// Checks the statement against the fee summary it was built from, returning all the
+    /// inconsistencies found.
+    pub fn check(&self, fee_summary: &FeeSummary) -> Result<(), Vec<FeeReconciliationError>> {
+        let mut errors = Vec::new();
+
+        let balance = self.balance();
+        if !balance.is_zero() {
+            errors.push(FeeReconciliationError::Unbalanced { balance });
+        }
+        if !fee_summary.total_bad_debt_xrd.is_zero() {
+            errors.push(FeeReconciliationError::UnrepaidLoan {
+                bad_debt: fee_summary.total_bad_debt_xrd,
+            });
+        }
+        for line in &self.lines {
+            if line.amount().is_negative() != matches!(line, FeeStatementLine::Locked { .. })
+                && !line.amount().is_zero()
+            {
+                errors.push(FeeReconciliationError::NegativeAmount { line: line.clone() });
+            }
+        }
+
+        for (vault_id, paid) in &fee_summary.fee_payments {
+            let locked = self.lines.iter().find_map(|line| match line {
+                FeeStatementLine::Locked {
+                    vault_id: locked_vault_id,
+                    amount,
+                } if locked_vault_id.eq(vault_id) => Some(*amount),
+                _ => None,
+            });
+            match locked {
+                Some(locked) if *paid > locked => {
+                    errors.push(FeeReconciliationError::OverchargedVault {
+                        vault_id: *vault_id,
+                        locked,
+                        paid: *paid,
+                    })
+                }
+                Some(_) => {}
+                None => errors.push(FeeReconciliationError::UnlockedVaultCharged {
+                    vault_id: *vault_id,
+                    paid: *paid,
+                }),
+            }
+        }
+
+        let royalties = fee_summary
+            .royalty_cost_breakdown
+            .values()
+            .fold(Decimal::ZERO, |total, (_, amount)| total + *amount);
+        if royalties != fee_summary.total_royalty_cost_xrd {
+            errors.push(FeeReconciliationError::BreakdownMismatch {
+                cost: "royalty".to_string(),
+                total: fee_summary.total_royalty_cost_xrd,
+                breakdown: royalties,
+            });
+        }
+        let deposits = fee_summary
+            .instantiation_deposits
+            .values()
+            .fold(Decimal::ZERO, |total, amount| total + *amount);
+        if deposits != fee_summary.total_instantiation_deposit_xrd {
+            errors.push(FeeReconciliationError::BreakdownMismatch {
+                cost: "instantiation deposit".to_string(),
+                total: fee_summary.total_instantiation_deposit_xrd,
+                breakdown: deposits,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl FeeSummary {
+    /// Reconciles the locked fees, costs and refunds of this summary into a single statement.
+    ///
+    /// Only meaningful once fees have been settled, i.e. on the fee summary of a commit.
+    pub fn reconcile(&self) -> FeeReconciliation {
+        FeeReconciliation::from_fee_summary(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
+
+    fn vault(n: u8) -> NodeId {
+        NodeId([n; NodeId::LENGTH])
+    }
+
+    fn settled_fee_summary() -> FeeSummary {
+        FeeSummary {
+            total_execution_cost_xrd: dec!(3),
+            total_tipping_cost_xrd: dec!("0.5"),
+            total_state_expansion_cost_xrd: dec!(1),
+            total_royalty_cost_xrd: dec!(2),
+            locked_fees: vec![
+                (vault(1), LiquidFungibleResource::new(dec!(5)), false),
+                (vault(2), LiquidFungibleResource::new(dec!(10)), false),
+                (vault(1), LiquidFungibleResource::new(dec!(1)), true),
+            ],
+            royalty_cost_breakdown: btreemap!(
+                RoyaltyRecipient::Package(FAUCET_PACKAGE) => (vault(3), dec!(2))
+            ),
+            fee_payments: indexmap!(vault(2) => dec!("6.5")),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settled_fees_are_balanced() {
+        let fee_summary = settled_fee_summary();
+
+        let reconciliation = fee_summary.reconcile();
+
+        assert_eq!(reconciliation.balance(), Decimal::ZERO);
+        assert_eq!(reconciliation.total_locked(), dec!(16));
+        assert_eq!(reconciliation.total_refunded(), dec!("9.5"));
+        assert_eq!(reconciliation.check(&fee_summary), Ok(()));
+    }
+
+    #[test]
+    fn test_anomalies_are_detected() {
+        let mut fee_summary = settled_fee_summary();
+        fee_summary.total_execution_cost_xrd = dec!(4);
+        fee_summary.fee_payments.insert(vault(4), dec!(1));
+        fee_summary.total_royalty_cost_xrd = dec!(3);
+
+        let reconciliation = fee_summary.reconcile();
+
+        assert_eq!(
+            reconciliation.check(&fee_summary),
+            Err(vec![
+                FeeReconciliationError::Unbalanced { balance: dec!(1) },
+                FeeReconciliationError::UnlockedVaultCharged {
+                    vault_id: vault(4),
+                    paid: dec!(1)
+                },
+                FeeReconciliationError::BreakdownMismatch {
+                    cost: "royalty".to_string(),
+                    total: dec!(3),
+                    breakdown: dec!(2)
+                },
+            ])
+        );
+    }
+}
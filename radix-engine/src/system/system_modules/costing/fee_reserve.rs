@@ -23,6 +23,10 @@ pub enum FeeReserveError {
         new: u32,
     },
     LoanRepaymentFailed,
+    FeeCeilingExceeded {
+        max_fee: Decimal,
+        required: Decimal,
+    },
     Abort(AbortReason),
 }
 
@@ -56,6 +60,11 @@ pub trait ExecutionFeeReserve {
 
     fn consume_execution(&mut self, cost_units: u32) -> Result<(), FeeReserveError>;
 
+    fn consume_instantiation_deposit(
+        &mut self,
+        global_address: GlobalAddress,
+    ) -> Result<(), FeeReserveError>;
+
     fn lock_fee(
         &mut self,
         vault_id: NodeId,
@@ -93,6 +102,10 @@ pub struct SystemLoanFeeReserve {
     /// Whether to abort the transaction run when the loan is repaid.
     /// This is used when test-executing pending transactions.
     abort_when_loan_repaid: bool,
+    /// The maximum amount of XRD that can be charged in total, if any
+    max_fee: Option<u128>,
+    /// The XRD deposit required to globalize a component
+    instantiation_deposit: u128,
 
     /// (Cache) The effective execution price, with tips considered
     effective_price: u128,
@@ -113,6 +126,14 @@ pub struct SystemLoanFeeReserve {
     /// State expansion costs
     state_expansion_committed: u128,
 
+    /// Instantiation deposits
+    instantiation_deposits_committed: IndexMap<GlobalAddress, u128>,
+    instantiation_deposits_committed_sum: u128,
+
+    /// Component rents, paid from the rent vault of each component rather than the locked fees
+    rent_committed: IndexMap<ComponentAddress, (NodeId, u128)>,
+    rent_committed_sum: u128,
+
     /// Payments made during the execution of a transaction.
     locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
 }
@@ -128,6 +149,16 @@ fn checked_assign_add(value: &mut u32, summand: u32) -> Result<(), FeeReserveErr
     Ok(())
 }
 
+#[inline]
+fn checked_add_u128(a: u128, b: u128) -> Result<u128, FeeReserveError> {
+    a.checked_add(b).ok_or(FeeReserveError::Overflow)
+}
+
+#[inline]
+fn checked_mul_u128(a: u128, b: u128) -> Result<u128, FeeReserveError> {
+    a.checked_mul(b).ok_or(FeeReserveError::Overflow)
+}
+
 fn transmute_u128_as_decimal(a: u128) -> Decimal {
     Decimal(a.into())
 }
@@ -160,6 +191,8 @@ impl SystemLoanFeeReserve {
             cost_unit_limit,
             system_loan,
             abort_when_loan_repaid,
+            max_fee: None,
+            instantiation_deposit: 0,
 
             effective_price,
 
@@ -175,6 +208,11 @@ impl SystemLoanFeeReserve {
 
             state_expansion_committed: 0,
 
+            instantiation_deposits_committed: index_map_new(),
+            instantiation_deposits_committed_sum: 0,
+            rent_committed: index_map_new(),
+            rent_committed_sum: 0,
+
             locked_fees: Vec::new(),
         }
     }
@@ -184,16 +222,43 @@ impl SystemLoanFeeReserve {
         self
     }
 
+    pub fn with_fee_ceiling(mut self, max_fee_in_xrd: Option<Decimal>) -> Self {
+        // Negative ceilings are rejected by transaction validation; clamp anything out of range.
+        self.max_fee = max_fee_in_xrd.map(|x| {
+            transmute_decimal_as_u128(x).unwrap_or(if x.is_negative() { 0 } else { u128::MAX })
+        });
+        self
+    }
+
+    pub fn with_instantiation_deposit(mut self, xrd_amount: Option<Decimal>) -> Self {
+        self.instantiation_deposit = xrd_amount
+            .map(|amount| transmute_decimal_as_u128(amount).unwrap())
+            .unwrap_or_default();
+        self
+    }
+
+    pub fn max_fee(&self) -> Option<Decimal> {
+        self.max_fee.map(transmute_u128_as_decimal)
+    }
+
     pub fn cost_unit_limit(&self) -> u32 {
         self.cost_unit_limit
     }
 
+    pub fn execution_cost_units_consumed(&self) -> u32 {
+        self.execution_committed_sum
+    }
+
+    pub fn royalty_cost_committed(&self) -> Decimal {
+        transmute_u128_as_decimal(self.royalty_committed_sum)
+    }
+
     pub fn cost_unit_price(&self) -> Decimal {
         transmute_u128_as_decimal(self.cost_unit_price)
     }
 
     pub fn tip_price(&self) -> Decimal {
-        self.cost_unit_price() * self.tip_percentage() / dec!(100)
+        tip_price(self.cost_unit_price(), self.tip_percentage)
     }
 
     pub fn usd_price(&self) -> Decimal {
@@ -219,10 +284,34 @@ impl SystemLoanFeeReserve {
         Ok(())
     }
 
+    /// The total amount of XRD charged so far, across execution, tipping, royalty, state expansion
+    /// and instantiation deposits.
+    fn total_charged(&self) -> Result<u128, FeeReserveError> {
+        let execution =
+            checked_mul_u128(self.effective_price, self.execution_committed_sum as u128)?;
+        let royalty_and_execution = checked_add_u128(execution, self.royalty_committed_sum)?;
+        let costs = checked_add_u128(royalty_and_execution, self.state_expansion_committed)?;
+        checked_add_u128(costs, self.instantiation_deposits_committed_sum)
+    }
+
+    fn check_fee_ceiling(&self, amount: u128) -> Result<(), FeeReserveError> {
+        if let Some(max_fee) = self.max_fee {
+            let required = checked_add_u128(self.total_charged()?, amount)?;
+            if required > max_fee {
+                return Err(FeeReserveError::FeeCeilingExceeded {
+                    max_fee: transmute_u128_as_decimal(max_fee),
+                    required: transmute_u128_as_decimal(required),
+                });
+            }
+        }
+        Ok(())
+    }
+
     fn consume_execution_internal(&mut self, cost_units: u32) -> Result<(), FeeReserveError> {
         self.check_cost_unit_limit(cost_units)?;
 
-        let amount = self.effective_price * cost_units as u128;
+        let amount = checked_mul_u128(self.effective_price, cost_units as u128)?;
+        self.check_fee_ceiling(amount)?;
         if self.xrd_balance < amount {
             return Err(FeeReserveError::InsufficientBalance {
                 required: transmute_u128_as_decimal(amount),
@@ -230,7 +319,7 @@ impl SystemLoanFeeReserve {
             });
         } else {
             self.xrd_balance -= amount;
-            self.execution_committed_sum += cost_units;
+            checked_assign_add(&mut self.execution_committed_sum, cost_units)?;
             Ok(())
         }
     }
@@ -251,19 +340,21 @@ impl SystemLoanFeeReserve {
             }
             RoyaltyAmount::Free => 0u128,
         };
+        self.check_fee_ceiling(amount)?;
         if self.xrd_balance < amount {
             return Err(FeeReserveError::InsufficientBalance {
                 required: transmute_u128_as_decimal(amount),
                 remaining: transmute_u128_as_decimal(self.xrd_balance),
             });
         } else {
-            self.xrd_balance -= amount;
-            self.royalty_committed
+            let royalty_committed_sum = checked_add_u128(self.royalty_committed_sum, amount)?;
+            let recipient_royalty = self
+                .royalty_committed
                 .entry(recipient)
-                .or_insert((recipient_vault_id, 0))
-                .1
-                .add_assign(amount);
-            self.royalty_committed_sum += amount;
+                .or_insert((recipient_vault_id, 0));
+            recipient_royalty.1 = checked_add_u128(recipient_royalty.1, amount)?;
+            self.royalty_committed_sum = royalty_committed_sum;
+            self.xrd_balance -= amount;
             Ok(())
         }
     }
@@ -298,6 +389,57 @@ impl SystemLoanFeeReserve {
         self.royalty_committed_sum = 0;
     }
 
+    pub fn revert_instantiation_deposits(&mut self) {
+        self.xrd_balance += self.instantiation_deposits_committed_sum;
+        self.instantiation_deposits_committed.clear();
+        self.instantiation_deposits_committed_sum = 0;
+    }
+
+    pub fn revert_rent(&mut self) {
+        self.rent_committed.clear();
+        self.rent_committed_sum = 0;
+    }
+
+    /// Records rent charged to a component, which is taken from its rent vault on finalization.
+    ///
+    /// Rent doesn't draw from the XRD balance, nor count towards the max fee, as it isn't paid by
+    /// the fee payers.
+    pub fn consume_rent(
+        &mut self,
+        component_address: ComponentAddress,
+        rent_vault_id: NodeId,
+        amount: Decimal,
+    ) -> Result<(), FeeReserveError> {
+        let amount = transmute_decimal_as_u128(amount)?;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        self.rent_committed_sum = checked_add_u128(self.rent_committed_sum, amount)?;
+        let rent = self
+            .rent_committed
+            .entry(component_address)
+            .or_insert((rent_vault_id, 0));
+        rent.1 = checked_add_u128(rent.1, amount)?;
+        Ok(())
+    }
+
+    pub fn rent_payments(&self) -> IndexMap<ComponentAddress, (NodeId, Decimal)> {
+        self.rent_committed
+            .iter()
+            .map(|(address, (vault_id, amount))| {
+                (*address, (*vault_id, transmute_u128_as_decimal(*amount)))
+            })
+            .collect()
+    }
+
+    pub fn instantiation_deposits(&self) -> IndexMap<GlobalAddress, Decimal> {
+        self.instantiation_deposits_committed
+            .iter()
+            .map(|(address, amount)| (*address, transmute_u128_as_decimal(*amount)))
+            .collect()
+    }
+
     pub fn royalty_cost(&self) -> BTreeMap<RoyaltyRecipient, (NodeId, Decimal)> {
         self.royalty_committed
             .clone()
@@ -359,7 +501,8 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
             }
             StoreCommit::Delete { .. } => 0, // TODO: refund?
         };
-        let amount = self.state_expansion_price.saturating_mul(delta as u128);
+        let amount = checked_mul_u128(self.state_expansion_price, delta as u128)?;
+        self.check_fee_ceiling(amount)?;
 
         if self.xrd_balance < amount {
             return Err(FeeReserveError::InsufficientBalance {
@@ -367,8 +510,9 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
                 remaining: transmute_u128_as_decimal(self.xrd_balance),
             });
         } else {
+            self.state_expansion_committed =
+                checked_add_u128(self.state_expansion_committed, amount)?;
             self.xrd_balance -= amount;
-            self.state_expansion_committed += amount;
             Ok(())
         }
     }
@@ -387,6 +531,31 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
         Ok(())
     }
 
+    fn consume_instantiation_deposit(
+        &mut self,
+        global_address: GlobalAddress,
+    ) -> Result<(), FeeReserveError> {
+        let amount = self.instantiation_deposit;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        self.check_fee_ceiling(amount)?;
+        if self.xrd_balance < amount {
+            return Err(FeeReserveError::InsufficientBalance {
+                required: transmute_u128_as_decimal(amount),
+                remaining: transmute_u128_as_decimal(self.xrd_balance),
+            });
+        }
+
+        self.instantiation_deposits_committed_sum =
+            checked_add_u128(self.instantiation_deposits_committed_sum, amount)?;
+        self.instantiation_deposits_committed
+            .insert(global_address, amount);
+        self.xrd_balance -= amount;
+        Ok(())
+    }
+
     fn lock_fee(
         &mut self,
         vault_id: NodeId,
@@ -395,8 +564,8 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
     ) -> Result<LiquidFungibleResource, FeeReserveError> {
         // Update balance
         if !contingent {
-            // Assumption: no overflow due to limited XRD supply
-            self.xrd_balance += transmute_decimal_as_u128(fee.amount())?;
+            self.xrd_balance =
+                checked_add_u128(self.xrd_balance, transmute_decimal_as_u128(fee.amount())?)?;
         }
 
         // Move resource
@@ -410,6 +579,8 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
 impl FinalizingFeeReserve for SystemLoanFeeReserve {
     fn finalize(self) -> FeeSummary {
         let royalty_cost_breakdown = self.royalty_cost();
+        let instantiation_deposits = self.instantiation_deposits();
+        let rent_payments = self.rent_payments();
         let fee_summary = FeeSummary {
             cost_unit_limit: self.cost_unit_limit,
             cost_unit_price: transmute_u128_as_decimal(self.cost_unit_price),
@@ -420,15 +591,28 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             total_state_expansion_cost_xrd: transmute_u128_as_decimal(
                 self.state_expansion_committed,
             ),
+            total_instantiation_deposit_xrd: transmute_u128_as_decimal(
+                self.instantiation_deposits_committed_sum,
+            ),
+            total_rent_xrd: transmute_u128_as_decimal(self.rent_committed_sum),
             total_bad_debt_xrd: transmute_u128_as_decimal(self.xrd_owed),
             locked_fees: self.locked_fees,
             execution_cost_breakdown: BTreeMap::new(),
             execution_cost_sum: self.execution_committed_sum,
             royalty_cost_breakdown,
+            instantiation_deposits,
+            rent_payments,
             fee_payments: index_map_new(),
+            instruction_cost_breakdown: index_map_new(),
         };
 
         // Sanity check
+        if let Some(max_fee) = self.max_fee {
+            assert!(
+                fee_summary.total_cost() + fee_summary.total_instantiation_deposit_xrd
+                    <= transmute_u128_as_decimal(max_fee)
+            );
+        }
         assert_eq!(
             fee_summary.total_execution_cost_xrd
                 + fee_summary.total_tipping_cost_xrd
@@ -621,4 +805,50 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn test_fee_ceiling() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(dec!(1), dec!(1), dec!(0), 0, 1000, 5, false)
+                .with_fee_ceiling(Some(dec!(10)));
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .unwrap();
+        fee_reserve.consume_execution(6).unwrap();
+        assert_eq!(
+            fee_reserve.consume_royalty(
+                RoyaltyAmount::Xrd(5.into()),
+                RoyaltyRecipient::Package(PACKAGE_PACKAGE),
+                TEST_VAULT_ID
+            ),
+            Err(FeeReserveError::FeeCeilingExceeded {
+                max_fee: dec!("10"),
+                required: dec!("11"),
+            }),
+        );
+        fee_reserve.consume_execution(4).unwrap();
+        assert_eq!(
+            fee_reserve.consume_execution(1),
+            Err(FeeReserveError::FeeCeilingExceeded {
+                max_fee: dec!("10"),
+                required: dec!("11"),
+            }),
+        );
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.total_cost(), dec!("10"));
+    }
+
+    #[test]
+    fn test_lock_fee_overflow() {
+        let mut fee_reserve =
+            SystemLoanFeeReserve::new(dec!(1), dec!(1), dec!(0), 0, 1000, 5, false);
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, xrd(dec!("200000000000000000000")), false)
+            .unwrap();
+        assert_eq!(
+            fee_reserve.lock_fee(TEST_VAULT_ID_2, xrd(dec!("200000000000000000000")), false),
+            Err(FeeReserveError::Overflow),
+        );
+    }
 }
@@ -19,6 +19,11 @@ pub struct FeeSummary {
     pub total_state_expansion_cost_xrd: Decimal,
     /// The total cost for royalty
     pub total_royalty_cost_xrd: Decimal,
+    /// The total XRD deposited for globalizing components, which is held rather than distributed
+    pub total_instantiation_deposit_xrd: Decimal,
+    /// The total rent charged to components, which is paid from their rent vaults and distributed
+    /// along with execution and state expansion costs
+    pub total_rent_xrd: Decimal,
     /// The (non-negative) amount of bad debt due to transaction unable to repay loan.
     pub total_bad_debt_xrd: Decimal,
     /// The vaults locked for XRD payment
@@ -29,8 +34,50 @@ pub struct FeeSummary {
     pub execution_cost_sum: u32,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: BTreeMap<RoyaltyRecipient, (NodeId, Decimal)>,
+    /// The instantiation deposit paid for each globalized component
+    pub instantiation_deposits: IndexMap<GlobalAddress, Decimal>,
+    /// The rent charged to each component, and the rent vault it's paid from
+    pub rent_payments: IndexMap<ComponentAddress, (NodeId, Decimal)>,
     /// The actual fee payments
     pub fee_payments: IndexMap<NodeId, Decimal>,
+    /// The cost breakdown of each instruction, by instruction index.
+    ///
+    /// Transaction-wide costs, e.g. for the payload, signatures and commit, aren't attributed to
+    /// any instruction.
+    pub instruction_cost_breakdown: IndexMap<usize, InstructionCostBreakdown>,
+}
+
+/// The costs of a single instruction of a transaction, by category.
+#[derive(Default, Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct InstructionCostBreakdown {
+    /// The cost units consumed by preparing and running WASM code
+    pub wasm_execution_cost_units: u32,
+    /// The cost units consumed by opening, reading and scanning substates
+    pub substate_read_cost_units: u32,
+    /// The cost units consumed by writing, setting, removing and taking substates
+    pub substate_write_cost_units: u32,
+    /// The cost units consumed by anything else, e.g. invocations and native code
+    pub other_execution_cost_units: u32,
+    /// The total cost for execution in XRD, excluding tips
+    pub execution_cost_xrd: Decimal,
+    /// The total cost for tipping in XRD
+    pub tipping_cost_xrd: Decimal,
+    /// The total cost for royalty in XRD
+    pub royalty_cost_xrd: Decimal,
+}
+
+impl InstructionCostBreakdown {
+    /// The total number of cost units consumed (excluding royalties).
+    pub fn execution_cost_units(&self) -> u32 {
+        self.wasm_execution_cost_units
+            + self.substate_read_cost_units
+            + self.substate_write_cost_units
+            + self.other_execution_cost_units
+    }
+
+    pub fn total_cost(&self) -> Decimal {
+        self.execution_cost_xrd + self.tipping_cost_xrd + self.royalty_cost_xrd
+    }
 }
 
 impl FeeSummary {
@@ -39,7 +86,7 @@ impl FeeSummary {
     }
 
     pub fn fees_to_distribute(&self) -> Decimal {
-        self.total_execution_cost_xrd + self.total_state_expansion_cost_xrd
+        self.total_execution_cost_xrd + self.total_state_expansion_cost_xrd + self.total_rent_xrd
     }
 
     pub fn tips_to_distribute(&self) -> Decimal {
@@ -53,6 +100,30 @@ impl FeeSummary {
             + self.total_royalty_cost_xrd
     }
 
+    /// The royalty charged for the given recipient, in XRD.
+    pub fn royalty_cost_of(&self, recipient: &RoyaltyRecipient) -> Decimal {
+        self.royalty_cost_breakdown
+            .get(recipient)
+            .map(|(_, amount)| *amount)
+            .unwrap_or_default()
+    }
+
+    pub fn package_royalty_cost(&self, package_address: PackageAddress) -> Decimal {
+        self.royalty_cost_of(&RoyaltyRecipient::Package(package_address))
+    }
+
+    pub fn component_royalty_cost(&self, component_address: ComponentAddress) -> Decimal {
+        self.royalty_cost_of(&RoyaltyRecipient::Component(component_address))
+    }
+
+    /// The index and cost breakdown of the instruction which cost the most.
+    pub fn most_costly_instruction(&self) -> Option<(usize, &InstructionCostBreakdown)> {
+        self.instruction_cost_breakdown
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cost().cmp(&b.total_cost()))
+            .map(|(index, breakdown)| (*index, breakdown))
+    }
+
     //===================
     // For testing only
     //===================
@@ -5,6 +5,7 @@ use crate::{
     types::*,
 };
 use lazy_static::lazy_static;
+use radix_engine_interface::api::{BigIntOperation, BigIntType};
 
 lazy_static! {
     pub static ref NATIVE_FUNCTION_BASE_COSTS: IndexMap<PackageAddress, IndexMap<&'static str, u32>> = {
@@ -66,6 +67,14 @@ impl FeeTable {
         mul(cast(size), 2)
     }
 
+    fn emission_count_cost(count: usize) -> u32 {
+        // Rationality:
+        // Every event or log adds an entry to the receipt. The first 32 are free of this charge so
+        // that regular transactions are unaffected, after which each one costs 100 more than the
+        // previous one, making a receipt bloated with many small entries quadratically expensive.
+        mul(cast(count.saturating_sub(32)), 100)
+    }
+
     fn data_processing_cost(size: usize) -> u32 {
         // FIXME: add payload against schema validation costs
 
@@ -345,13 +354,31 @@ impl FeeTable {
     }
 
     #[inline]
-    pub fn emit_event_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size) + Self::transient_data_cost(size)
+    pub fn big_int_op_cost(&self, int_type: BigIntType, operation: BigIntOperation) -> u32 {
+        // Schoolbook arithmetic over 64-bit words, with a modular exponentiation costing a
+        // multiplication per bit of the exponent.
+        let words = int_type.byte_len() as u32 / 8;
+        let bits = int_type.byte_len() as u32 * 8;
+        let word_operations = match operation {
+            BigIntOperation::Add | BigIntOperation::Sub => words,
+            BigIntOperation::Mul | BigIntOperation::Div => words * words,
+            BigIntOperation::ModPow => 2 * bits * (2 * words) * (2 * words),
+        };
+        500 + 10 * word_operations
     }
 
     #[inline]
-    pub fn emit_log_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size) + Self::transient_data_cost(size)
+    pub fn emit_event_cost(&self, size: usize, count: usize) -> u32 {
+        500 + Self::data_processing_cost(size)
+            + Self::transient_data_cost(size)
+            + Self::emission_count_cost(count)
+    }
+
+    #[inline]
+    pub fn emit_log_cost(&self, size: usize, count: usize) -> u32 {
+        500 + Self::data_processing_cost(size)
+            + Self::transient_data_cost(size)
+            + Self::emission_count_cost(count)
     }
 
     #[inline]
@@ -1,11 +1,15 @@
 mod costing_entry;
 mod costing_module;
+mod fee_calculator;
+mod fee_reconciliation;
 mod fee_reserve;
 mod fee_summary;
 mod fee_table;
 
 pub use costing_entry::*;
 pub use costing_module::*;
+pub use fee_calculator::*;
+pub use fee_reconciliation::*;
 pub use fee_reserve::*;
 pub use fee_summary::*;
 pub use fee_table::*;
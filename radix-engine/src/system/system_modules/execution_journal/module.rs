@@ -0,0 +1,139 @@
+use crate::errors::RuntimeError;
+use crate::kernel::kernel_api::KernelApi;
+use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::track::interface::StoreAccessInfo;
+use crate::types::*;
+use radix_engine_interface::api::field_lock_api::LockFlags;
+
+/// A decision point of the kernel, which must be identical on every node executing the same
+/// transaction against the same state.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum JournalEntry {
+    /// A lock acquired on a substate, with the bits of its [`LockFlags`].
+    OpenSubstate {
+        node_id: NodeId,
+        partition_num: PartitionNumber,
+        substate_key: SubstateKey,
+        flags: u32,
+        lock_handle: LockHandle,
+    },
+    ReadSubstate {
+        lock_handle: LockHandle,
+        value_hash: Hash,
+    },
+    AllocateNodeId {
+        node_id: NodeId,
+    },
+}
+
+/// The kernel decision points of a transaction execution, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct ExecutionJournal {
+    pub entries: Vec<JournalEntry>,
+}
+
+/// The first entry at which two journals differ, `None` on the side which ended earlier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalDivergence {
+    pub index: usize,
+    pub left: Option<JournalEntry>,
+    pub right: Option<JournalEntry>,
+}
+
+impl ExecutionJournal {
+    /// Encodes the journal into its compact binary form, for shipping between nodes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        scrypto_encode(self).expect("Execution journal should be encodable")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(bytes)
+    }
+
+    /// Finds where the execution recorded by this journal diverged from the other, if it did.
+    pub fn compare(&self, other: &ExecutionJournal) -> Option<JournalDivergence> {
+        let len = self.entries.len().max(other.entries.len());
+        (0..len)
+            .find(|index| self.entries.get(*index) != other.entries.get(*index))
+            .map(|index| JournalDivergence {
+                index,
+                left: self.entries.get(index).cloned(),
+                right: other.entries.get(index).cloned(),
+            })
+    }
+}
+
+/// Records the kernel decision points of an execution into an [`ExecutionJournal`], so that the
+/// journals of nodes which disagree on the outcome of a transaction can be compared.
+///
+/// Substate values are journaled by hash. This is for debugging only, hence disabled by default.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionJournalModule {
+    journal: ExecutionJournal,
+    /// The substate being opened, until its lock handle is known.
+    opening: Option<(NodeId, PartitionNumber, SubstateKey, LockFlags)>,
+}
+
+impl ExecutionJournalModule {
+    /// Called by the kernel with every value read, which unlike the other hooks have no access
+    /// to the kernel while the value is borrowed.
+    pub fn on_read_substate_value(&mut self, lock_handle: LockHandle, value: &IndexedScryptoValue) {
+        self.journal.entries.push(JournalEntry::ReadSubstate {
+            lock_handle,
+            value_hash: hash(value.as_slice()),
+        });
+    }
+
+    pub fn finalize(self) -> ExecutionJournal {
+        self.journal
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionJournalModule {
+    fn after_allocate_node_id<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .execution_journal
+            .journal
+            .entries
+            .push(JournalEntry::AllocateNodeId { node_id: *node_id });
+        Ok(())
+    }
+
+    fn before_open_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+        partition_num: &PartitionNumber,
+        substate_key: &SubstateKey,
+        flags: &LockFlags,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system().modules.execution_journal.opening =
+            Some((*node_id, *partition_num, substate_key.clone(), *flags));
+        Ok(())
+    }
+
+    fn after_open_substate<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        lock_handle: LockHandle,
+        _node_id: &NodeId,
+        _store_access: &StoreAccessInfo,
+        _size: usize,
+    ) -> Result<(), RuntimeError> {
+        let module = &mut api.kernel_get_system().modules.execution_journal;
+        if let Some((node_id, partition_num, substate_key, flags)) = module.opening.take() {
+            module.journal.entries.push(JournalEntry::OpenSubstate {
+                node_id,
+                partition_num,
+                substate_key,
+                flags: flags.bits(),
+                lock_handle,
+            });
+        }
+        Ok(())
+    }
+}
@@ -32,8 +32,9 @@ pub struct ExecutionTraceModule {
     /// (e.g. open_substate call inside drop_node).
     current_kernel_call_depth: usize,
 
-    /// A stack of traced kernel call inputs, their origin, and the instruction index.
-    traced_kernel_call_inputs_stack: Vec<(ResourceSummary, TraceOrigin, usize)>,
+    /// A stack of traced kernel call inputs, their origin, the instruction index and the
+    /// execution cost units consumed when the call started.
+    traced_kernel_call_inputs_stack: Vec<(ResourceSummary, TraceOrigin, usize, u32)>,
 
     /// A mapping of complete KernelCallTrace stacks (\w both inputs and outputs), indexed by depth.
     kernel_call_traces_stacks: IndexMap<usize, Vec<ExecutionTrace>>,
@@ -56,6 +57,15 @@ pub struct ResourceChange {
     pub amount: Decimal,
 }
 
+/// A recall of resources from a vault, by an actor which doesn't necessarily own it.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct ResourceRecall {
+    pub vault_id: NodeId,
+    /// The node whose method recalled the resources, if the recall was made from a method
+    pub recaller: Option<NodeId>,
+    pub resource: ResourceSpecifier,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum WorktopChange {
     Take(ResourceSpecifier),
@@ -91,6 +101,7 @@ pub enum VaultOp {
     Put(ResourceAddress, Decimal), // TODO: add non-fungible support
     Take(ResourceAddress, Decimal),
     LockFee(Decimal, bool),
+    Recall(ResourceSpecifier),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
@@ -186,6 +197,10 @@ pub struct ExecutionTrace {
     pub instruction_index: usize,
     pub input: ResourceSummary,
     pub output: ResourceSummary,
+    /// Execution cost units consumed by the transaction when the kernel call started
+    pub cost_units_start: u32,
+    /// Execution cost units consumed by the transaction when the kernel call finished
+    pub cost_units_end: u32,
     pub children: Vec<ExecutionTrace>,
 }
 
@@ -240,6 +255,47 @@ impl ExecutionTrace {
             child.worktop_changes(worktop_changes_aggregator)
         }
     }
+
+    pub fn chrome_trace_events(&self, events: &mut Vec<String>) {
+        let (category, name) = match &self.origin {
+            TraceOrigin::ScryptoFunction(fn_identifier) => (
+                "function",
+                format!("{}::{}", fn_identifier.blueprint_name, fn_identifier.ident),
+            ),
+            TraceOrigin::ScryptoMethod(fn_identifier) => (
+                "method",
+                format!("{}::{}", fn_identifier.blueprint_name, fn_identifier.ident),
+            ),
+            TraceOrigin::CreateNode => ("create_node", "CreateNode".to_string()),
+            TraceOrigin::DropNode => ("drop_node", "DropNode".to_string()),
+        };
+        events.push(format!(
+            "{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":0,\"tid\":0,\"args\":{{\"instruction_index\":{},\"kernel_call_depth\":{}}}}}",
+            escape_json_string(&name),
+            category,
+            self.cost_units_start,
+            self.cost_units_end.saturating_sub(self.cost_units_start),
+            self.instruction_index,
+            self.kernel_call_depth,
+        ));
+
+        for child in self.children.iter() {
+            child.chrome_trace_events(events)
+        }
+    }
+}
+
+fn escape_json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 impl ResourceSummary {
@@ -293,11 +349,12 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         _node_id: &NodeId,
         _node_substates: &NodeSubstates,
     ) -> Result<(), RuntimeError> {
-        api.kernel_get_system_state()
-            .system
-            .modules
+        let system_state = api.kernel_get_system_state();
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules
             .execution_trace
-            .handle_before_create_node();
+            .handle_before_create_node(cost_units);
         Ok(())
     }
 
@@ -310,11 +367,14 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         let current_depth = api.kernel_get_current_depth();
         let resource_summary = ResourceSummary::from_node_id(api, node_id);
         let system_state = api.kernel_get_system_state();
-        system_state
-            .system
-            .modules
-            .execution_trace
-            .handle_after_create_node(system_state.current, current_depth, resource_summary);
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules.execution_trace.handle_after_create_node(
+            system_state.current,
+            current_depth,
+            resource_summary,
+            cost_units,
+        );
         Ok(())
     }
 
@@ -323,11 +383,12 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         node_id: &NodeId,
     ) -> Result<(), RuntimeError> {
         let resource_summary = ResourceSummary::from_node_id(api, node_id);
-        api.kernel_get_system_state()
-            .system
-            .modules
+        let system_state = api.kernel_get_system_state();
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules
             .execution_trace
-            .handle_before_drop_node(resource_summary);
+            .handle_before_drop_node(resource_summary, cost_units);
         Ok(())
     }
 
@@ -337,11 +398,13 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
     ) -> Result<(), RuntimeError> {
         let current_depth = api.kernel_get_current_depth();
         let system_state = api.kernel_get_system_state();
-        system_state
-            .system
-            .modules
-            .execution_trace
-            .handle_after_drop_node(system_state.current, current_depth);
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules.execution_trace.handle_after_drop_node(
+            system_state.current,
+            current_depth,
+            cost_units,
+        );
         Ok(())
     }
 
@@ -353,11 +416,15 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
     ) -> Result<(), RuntimeError> {
         let resource_summary = ResourceSummary::from_message(api, update);
         let system_state = api.kernel_get_system_state();
-        system_state
-            .system
-            .modules
-            .execution_trace
-            .handle_before_push_frame(system_state.current, callee, resource_summary, args);
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules.execution_trace.handle_before_push_frame(
+            system_state.current,
+            callee,
+            resource_summary,
+            args,
+            cost_units,
+        );
         Ok(())
     }
 
@@ -372,16 +439,15 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
 
         let caller = TraceActor::from_actor(system_state.caller);
 
-        system_state
-            .system
-            .modules
-            .execution_trace
-            .handle_on_execution_finish(
-                system_state.current,
-                current_depth,
-                &caller,
-                resource_summary,
-            );
+        let modules = &mut system_state.system.modules;
+        let cost_units = modules.costing.fee_reserve.execution_cost_units_consumed();
+        modules.execution_trace.handle_on_execution_finish(
+            system_state.current,
+            current_depth,
+            &caller,
+            resource_summary,
+            cost_units,
+        );
 
         Ok(())
     }
@@ -399,7 +465,7 @@ impl ExecutionTraceModule {
         }
     }
 
-    fn handle_before_create_node(&mut self) {
+    fn handle_before_create_node(&mut self, cost_units: u32) {
         if self.current_kernel_call_depth <= self.max_kernel_call_depth_traced {
             let instruction_index = self.instruction_index();
 
@@ -407,6 +473,7 @@ impl ExecutionTraceModule {
                 ResourceSummary::default(),
                 TraceOrigin::CreateNode,
                 instruction_index,
+                cost_units,
             );
             self.traced_kernel_call_inputs_stack.push(traced_input);
         }
@@ -419,6 +486,7 @@ impl ExecutionTraceModule {
         current_actor: &Actor,
         current_depth: usize,
         resource_summary: ResourceSummary,
+        cost_units: u32,
     ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth -= 1;
@@ -429,21 +497,31 @@ impl ExecutionTraceModule {
         }
 
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth)
+        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth, cost_units)
     }
 
-    fn handle_before_drop_node(&mut self, resource_summary: ResourceSummary) {
+    fn handle_before_drop_node(&mut self, resource_summary: ResourceSummary, cost_units: u32) {
         if self.current_kernel_call_depth <= self.max_kernel_call_depth_traced {
             let instruction_index = self.instruction_index();
 
-            let traced_input = (resource_summary, TraceOrigin::DropNode, instruction_index);
+            let traced_input = (
+                resource_summary,
+                TraceOrigin::DropNode,
+                instruction_index,
+                cost_units,
+            );
             self.traced_kernel_call_inputs_stack.push(traced_input);
         }
 
         self.current_kernel_call_depth += 1;
     }
 
-    fn handle_after_drop_node(&mut self, current_actor: &Actor, current_depth: usize) {
+    fn handle_after_drop_node(
+        &mut self,
+        current_actor: &Actor,
+        current_depth: usize,
+        cost_units: u32,
+    ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth -= 1;
 
@@ -455,7 +533,7 @@ impl ExecutionTraceModule {
         let traced_output = ResourceSummary::default();
 
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(traced_output, current_actor, current_depth)
+        self.finalize_kernel_call_trace(traced_output, current_actor, current_depth, cost_units)
     }
 
     fn handle_before_push_frame(
@@ -464,6 +542,7 @@ impl ExecutionTraceModule {
         callee: &Actor,
         resource_summary: ResourceSummary,
         args: &IndexedScryptoValue,
+        cost_units: u32,
     ) {
         if self.current_kernel_call_depth <= self.max_kernel_call_depth_traced {
             let origin = match &callee {
@@ -494,6 +573,7 @@ impl ExecutionTraceModule {
                 resource_summary.clone(),
                 origin,
                 instruction_index,
+                cost_units,
             ));
         }
 
@@ -530,6 +610,7 @@ impl ExecutionTraceModule {
         current_depth: usize,
         caller: &TraceActor,
         resource_summary: ResourceSummary,
+        cost_units: u32,
     ) {
         match current_actor {
             Actor::Method(MethodActor {
@@ -542,6 +623,16 @@ impl ExecutionTraceModule {
             {
                 self.handle_vault_take_output(&resource_summary, &caller, node_id)
             }
+            Actor::Method(MethodActor {
+                node_id,
+                module_object_info: object_info,
+                ident,
+                ..
+            }) if VaultUtil::is_vault_blueprint(&object_info.blueprint_id)
+                && VaultUtil::is_recall_method(ident) =>
+            {
+                self.handle_vault_recall_output(&resource_summary, &caller, node_id)
+            }
             Actor::VirtualLazyLoad { .. } => return,
             _ => {}
         }
@@ -555,7 +646,7 @@ impl ExecutionTraceModule {
         }
 
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth)
+        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth, cost_units)
     }
 
     fn finalize_kernel_call_trace(
@@ -563,13 +654,14 @@ impl ExecutionTraceModule {
         traced_output: ResourceSummary,
         current_actor: TraceActor,
         current_depth: usize,
+        cost_units_end: u32,
     ) {
         let child_traces = self
             .kernel_call_traces_stacks
             .remove(&(self.current_kernel_call_depth + 1))
             .unwrap_or(vec![]);
 
-        let (traced_input, origin, instruction_index) = self
+        let (traced_input, origin, instruction_index, cost_units_start) = self
             .traced_kernel_call_inputs_stack
             .pop()
             .expect("kernel call input stack underflow");
@@ -588,6 +680,8 @@ impl ExecutionTraceModule {
                 instruction_index,
                 input: traced_input,
                 output: traced_output,
+                cost_units_start,
+                cost_units_end,
                 children: child_traces,
             };
 
@@ -610,12 +704,14 @@ impl ExecutionTraceModule {
         }
 
         let fee_locks = calculate_fee_locks(&self.vault_ops);
+        let recalls = calculate_recalls(&self.vault_ops, is_success);
         let resource_changes = calculate_resource_changes(self.vault_ops, fee_payments, is_success);
 
         TransactionExecutionTrace {
             execution_traces,
             resource_changes,
             fee_locks,
+            recalls,
         }
     }
 
@@ -671,6 +767,22 @@ impl ExecutionTraceModule {
             ));
         }
     }
+
+    fn handle_vault_recall_output<'s>(
+        &mut self,
+        resource_summary: &ResourceSummary,
+        actor: &TraceActor,
+        vault_id: &NodeId,
+    ) {
+        for (_, resource) in &resource_summary.buckets {
+            self.vault_ops.push((
+                actor.clone(),
+                vault_id.clone(),
+                VaultOp::Recall(resource.into()),
+                self.instruction_index(),
+            ));
+        }
+    }
 }
 
 pub fn calculate_resource_changes(
@@ -720,6 +832,8 @@ pub fn calculate_resource_changes(
                         .or_insert((XRD, Decimal::zero()))
                         .1 -= fee_payments.get(&vault_id).cloned().unwrap_or_default();
                 }
+                // Recalls are reported separately, as the recaller isn't the owner of the vault
+                VaultOp::Recall(..) => {}
             }
         }
     }
@@ -748,6 +862,35 @@ pub fn calculate_resource_changes(
     resource_changes
 }
 
+pub fn calculate_recalls(
+    vault_ops: &Vec<(TraceActor, NodeId, VaultOp, usize)>,
+    is_commit_success: bool,
+) -> IndexMap<usize, Vec<ResourceRecall>> {
+    let mut recalls = index_map_new::<usize, Vec<ResourceRecall>>();
+    if !is_commit_success {
+        return recalls;
+    }
+
+    for (actor, vault_id, vault_op, instruction_index) in vault_ops {
+        if let VaultOp::Recall(resource) = vault_op {
+            let recaller = match actor {
+                TraceActor::Method(node_id) => Some(*node_id),
+                TraceActor::NonMethod => None,
+            };
+            recalls
+                .entry(*instruction_index)
+                .or_default()
+                .push(ResourceRecall {
+                    vault_id: *vault_id,
+                    recaller,
+                    resource: resource.clone(),
+                });
+        }
+    }
+
+    recalls
+}
+
 pub fn calculate_fee_locks(vault_ops: &Vec<(TraceActor, NodeId, VaultOp, usize)>) -> FeeLocks {
     let mut fee_locks = FeeLocks {
         lock: Decimal::ZERO,
@@ -0,0 +1,122 @@
+use crate::blueprints::consensus_manager::InvocationFilterSubstate;
+use crate::errors::{RuntimeError, SystemModuleError};
+use crate::kernel::actor::{Actor, MethodActor};
+use crate::kernel::kernel_api::{KernelApi, KernelInvocation};
+use crate::system::module::SystemModule;
+use crate::system::system_callback::{SystemConfig, SystemLockData};
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::types::*;
+use radix_engine_interface::api::field_lock_api::LockFlags;
+use radix_engine_interface::blueprints::consensus_manager::InvocationFilter;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum InvocationFilterError {
+    DenyListed(GlobalAddress),
+    NotAllowListed(GlobalAddress),
+}
+
+/// Rejects invocations of packages and components according to the [`InvocationFilter`]
+/// maintained by governance on the consensus manager.
+///
+/// Intended for permissioned deployments of the engine, hence disabled by default.
+/// The filter is loaded once per transaction, on the first invocation.
+#[derive(Debug, Clone, Default)]
+pub struct InvocationFilterModule {
+    filter: Option<InvocationFilter>,
+}
+
+impl InvocationFilterModule {
+    fn load_filter<V: SystemCallbackObject, Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+    ) -> Result<InvocationFilter, RuntimeError> {
+        if let Some(filter) = &api.kernel_get_system().modules.invocation_filter.filter {
+            return Ok(filter.clone());
+        }
+
+        let handle = api.kernel_open_substate(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::InvocationFilter.into(),
+            LockFlags::read_only(),
+            SystemLockData::default(),
+        )?;
+        let substate: InvocationFilterSubstate =
+            api.kernel_read_substate(handle)?.as_typed().unwrap();
+        api.kernel_close_substate(handle)?;
+
+        api.kernel_get_system().modules.invocation_filter.filter = Some(substate.filter.clone());
+        Ok(substate.filter)
+    }
+
+    /// Returns the package and, for methods, the global component being invoked.
+    fn targets(actor: &Actor) -> Option<(PackageAddress, Option<GlobalAddress>)> {
+        match actor {
+            Actor::Root => None,
+            Actor::Method(MethodActor {
+                global_address,
+                node_id,
+                module_object_info,
+                ..
+            }) => {
+                let component = if node_id.is_global() {
+                    Some(GlobalAddress::new_or_panic(node_id.clone().into()))
+                } else {
+                    *global_address
+                };
+                Some((module_object_info.blueprint_id.package_address, component))
+            }
+            Actor::Function { blueprint_id, .. } | Actor::VirtualLazyLoad { blueprint_id, .. } => {
+                Some((blueprint_id.package_address, None))
+            }
+        }
+    }
+
+    pub fn check(
+        filter: &InvocationFilter,
+        package_address: PackageAddress,
+        component: Option<GlobalAddress>,
+    ) -> Result<(), InvocationFilterError> {
+        let package = GlobalAddress::from(package_address);
+        match filter {
+            InvocationFilter::None => Ok(()),
+            InvocationFilter::DenyList(denied) => {
+                if let Some(component) = component.filter(|c| denied.contains(c)) {
+                    return Err(InvocationFilterError::DenyListed(component));
+                }
+                if denied.contains(&package) {
+                    return Err(InvocationFilterError::DenyListed(package));
+                }
+                Ok(())
+            }
+            InvocationFilter::AllowList(allowed) => {
+                let is_native = ALWAYS_VISIBLE_GLOBAL_NODES.contains(package_address.as_node_id());
+                if is_native
+                    || allowed.contains(&package)
+                    || component.map_or(false, |c| allowed.contains(&c))
+                {
+                    Ok(())
+                } else {
+                    Err(InvocationFilterError::NotAllowListed(
+                        component.unwrap_or(package),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for InvocationFilterModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation,
+    ) -> Result<(), RuntimeError> {
+        let Some((package_address, component)) = Self::targets(&invocation.actor) else {
+            return Ok(());
+        };
+
+        let filter = Self::load_filter(api)?;
+        Self::check(&filter, package_address, component).map_err(|e| {
+            RuntimeError::SystemModuleError(SystemModuleError::InvocationFilterError(e))
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::kernel::actor::Actor;
 use crate::kernel::kernel_api::KernelInvocation;
 use crate::system::module::SystemModule;
 use crate::system::system_callback::SystemConfig;
@@ -17,18 +18,27 @@ pub enum TransactionLimitsError {
     PanicMessageSizeTooLarge { actual: usize, max: usize },
     TooManyLogs,
     TooManyEvents,
+    TotalLogSizeTooLarge { actual: usize, max: usize },
+    TotalEventSizeTooLarge { actual: usize, max: usize },
+    MaxHeapSizePerFrameExceeded { actual: usize, max: usize },
+    TooManyNewGlobalEntities { max: usize },
 }
 
 pub struct TransactionLimitsConfig {
     pub max_number_of_substates_in_track: usize,
     pub max_number_of_substates_in_heap: usize, // FIXME: enforce this limits in heap!
+    pub max_number_of_new_global_entities: usize,
     pub max_substate_size: usize,
     pub max_invoke_payload_size: usize,
     pub max_event_size: usize,
     pub max_log_size: usize,
+    pub max_total_event_size: usize,
+    pub max_total_log_size: usize,
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
+    pub max_heap_size_per_frame: Option<usize>,
+    pub enable_heap_size_tracking: bool,
 }
 
 /// Tracks and verifies transaction limits during transactino execution,
@@ -39,6 +49,11 @@ pub struct LimitsModule {
     config: TransactionLimitsConfig,
     number_of_substates_in_track: usize,
     _number_of_substates_in_heap: usize,
+    number_of_new_global_entities: usize,
+    total_event_size: usize,
+    total_log_size: usize,
+    /// Peak heap size owned by a single call frame, per blueprint of the frame actor.
+    heap_size_peaks: IndexMap<BlueprintId, usize>,
 }
 
 impl LimitsModule {
@@ -47,9 +62,17 @@ impl LimitsModule {
             config: limits_config,
             number_of_substates_in_track: 0,
             _number_of_substates_in_heap: 0,
+            number_of_new_global_entities: 0,
+            total_event_size: 0,
+            total_log_size: 0,
+            heap_size_peaks: index_map_new(),
         }
     }
 
+    pub fn finalize(self) -> IndexMap<BlueprintId, usize> {
+        self.heap_size_peaks
+    }
+
     pub fn config(&self) -> &TransactionLimitsConfig {
         &self.config
     }
@@ -77,6 +100,99 @@ impl LimitsModule {
             Ok(())
         }
     }
+
+    /// Counts an entity globalized by the transaction and checks the count against the cap.
+    pub fn process_new_global_entity(&mut self) -> Result<(), RuntimeError> {
+        self.number_of_new_global_entities += 1;
+        if self.number_of_new_global_entities > self.config.max_number_of_new_global_entities {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TooManyNewGlobalEntities {
+                        max: self.config.max_number_of_new_global_entities,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds the size of an emitted event to the transaction total and checks it against the cap.
+    pub fn process_event_size(&mut self, size: usize) -> Result<(), RuntimeError> {
+        self.total_event_size += size;
+        if self.total_event_size > self.config.max_total_event_size {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TotalEventSizeTooLarge {
+                        actual: self.total_event_size,
+                        max: self.config.max_total_event_size,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds the size of an emitted log to the transaction total and checks it against the cap.
+    pub fn process_log_size(&mut self, size: usize) -> Result<(), RuntimeError> {
+        self.total_log_size += size;
+        if self.total_log_size > self.config.max_total_log_size {
+            Err(RuntimeError::SystemModuleError(
+                SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::TotalLogSizeTooLarge {
+                        actual: self.total_log_size,
+                        max: self.config.max_total_log_size,
+                    },
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Samples the heap size of the current call frame, records it against the frame actor
+    /// and checks it against the per-frame cap.
+    pub fn process_heap_size<V: SystemCallbackObject, Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+    ) -> Result<(), RuntimeError> {
+        let config = &api.kernel_get_system().modules.limits.config;
+        if !config.enable_heap_size_tracking && config.max_heap_size_per_frame.is_none() {
+            return Ok(());
+        }
+
+        let heap_size = api.kernel_get_current_frame_heap_size();
+        let state = api.kernel_get_system_state();
+        let limits = &mut state.system.modules.limits;
+
+        if limits.config.enable_heap_size_tracking {
+            match state.current {
+                Actor::Root => {}
+                actor => {
+                    let peak = limits
+                        .heap_size_peaks
+                        .entry(actor.blueprint_id().clone())
+                        .or_default();
+                    *peak = (*peak).max(heap_size);
+                }
+            }
+        }
+
+        if let Some(max) = limits.config.max_heap_size_per_frame {
+            if heap_size > max {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::TransactionLimitsError(
+                        TransactionLimitsError::MaxHeapSizePerFrameExceeded {
+                            actual: heap_size,
+                            max,
+                        },
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
@@ -108,6 +224,17 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         Ok(())
     }
 
+    fn on_execution_start<Y: KernelApi<SystemConfig<V>>>(api: &mut Y) -> Result<(), RuntimeError> {
+        LimitsModule::process_heap_size(api)
+    }
+
+    fn after_pop_frame<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _dropped_actor: &Actor,
+    ) -> Result<(), RuntimeError> {
+        LimitsModule::process_heap_size(api)
+    }
+
     fn before_create_node<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
         _node_id: &NodeId,
@@ -139,7 +266,9 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         api.kernel_get_system()
             .modules
             .limits
-            .process_store_access(store_access)
+            .process_store_access(store_access)?;
+
+        LimitsModule::process_heap_size(api)
     }
 
     #[inline(always)]
@@ -199,7 +328,9 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for LimitsModule {
         api.kernel_get_system()
             .modules
             .limits
-            .process_store_access(store_access)
+            .process_store_access(store_access)?;
+
+        LimitsModule::process_heap_size(api)
     }
 
     fn on_close_substate<Y: KernelApi<SystemConfig<V>>>(
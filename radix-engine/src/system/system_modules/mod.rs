@@ -1,6 +1,8 @@
 pub mod auth;
 pub mod costing;
+pub mod execution_journal;
 pub mod execution_trace;
+pub mod invocation_filter;
 pub mod kernel_trace;
 pub mod limits;
 pub mod node_move;
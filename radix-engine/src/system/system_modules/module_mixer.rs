@@ -5,6 +5,7 @@ use crate::kernel::actor::Actor;
 use crate::kernel::call_frame::Message;
 use crate::kernel::kernel_api::KernelApi;
 use crate::kernel::kernel_api::KernelInvocation;
+use crate::system::id_allocation::GlobalAddressReservationState;
 use crate::system::module::SystemModule;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
@@ -12,7 +13,9 @@ use crate::system::system_modules::auth::AuthModule;
 use crate::system::system_modules::costing::CostingModule;
 use crate::system::system_modules::costing::FeeTable;
 use crate::system::system_modules::costing::SystemLoanFeeReserve;
+use crate::system::system_modules::execution_journal::ExecutionJournalModule;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::invocation_filter::InvocationFilterModule;
 use crate::system::system_modules::kernel_trace::KernelTraceModule;
 use crate::system::system_modules::limits::{LimitsModule, TransactionLimitsConfig};
 use crate::system::system_modules::node_move::NodeMoveModule;
@@ -45,6 +48,12 @@ bitflags! {
 
         // Execution trace, for preview only
         const EXECUTION_TRACE = 0x01 << 6;
+
+        // Invocation allow/deny list, for permissioned networks only
+        const INVOCATION_FILTER = 0x01 << 7;
+
+        // Execution journal, for debugging nondeterminism only
+        const EXECUTION_JOURNAL = 0x01 << 8;
     }
 }
 
@@ -88,6 +97,8 @@ pub struct SystemModuleMixer {
     pub(super) node_move: NodeMoveModule,
     pub(super) transaction_runtime: TransactionRuntimeModule,
     pub(super) execution_trace: ExecutionTraceModule,
+    pub(super) invocation_filter: InvocationFilterModule,
+    pub(super) execution_journal: ExecutionJournalModule,
 }
 
 // Macro generates default modules dispatches call based on passed function name and arguments.
@@ -117,6 +128,12 @@ macro_rules! internal_call_dispatch {
             if modules.contains(EnabledModules::EXECUTION_TRACE) {
                 ExecutionTraceModule::[< $fn >]($($param, )*)?;
             }
+            if modules.contains(EnabledModules::INVOCATION_FILTER) {
+                InvocationFilterModule::[< $fn >]($($param, )*)?;
+            }
+            if modules.contains(EnabledModules::EXECUTION_JOURNAL) {
+                ExecutionJournalModule::[< $fn >]($($param, )*)?;
+            }
             Ok(())
         }
     }};
@@ -143,8 +160,14 @@ impl SystemModuleMixer {
                 payload_len,
                 num_of_signatures,
                 max_per_function_royalty_in_xrd: execution_config.max_per_function_royalty_in_xrd,
+                max_per_call_royalty_in_xrd: execution_config.max_per_call_royalty_in_xrd,
                 enable_cost_breakdown: execution_config.enable_cost_breakdown,
                 costing_traces: index_map_new(),
+                instruction_index: None,
+                instruction_costing_traces: index_map_new(),
+                execution_deadline: execution_config.execution_deadline,
+                #[cfg(feature = "std")]
+                execution_start: std::time::Instant::now(),
             },
             node_move: NodeMoveModule {},
             auth: AuthModule {
@@ -154,21 +177,36 @@ impl SystemModuleMixer {
             limits: LimitsModule::new(TransactionLimitsConfig {
                 max_number_of_substates_in_track: execution_config.max_number_of_substates_in_track,
                 max_number_of_substates_in_heap: execution_config.max_number_of_substates_in_heap,
+                max_number_of_new_global_entities: execution_config
+                    .max_number_of_new_global_entities,
                 max_substate_size: execution_config.max_substate_size,
                 max_invoke_payload_size: execution_config.max_invoke_input_size,
                 max_number_of_logs: execution_config.max_number_of_logs,
                 max_number_of_events: execution_config.max_number_of_events,
                 max_event_size: execution_config.max_event_size,
                 max_log_size: execution_config.max_log_size,
+                max_total_event_size: execution_config.max_total_event_size,
+                max_total_log_size: execution_config.max_total_log_size,
                 max_panic_message_size: execution_config.max_panic_message_size,
+                max_heap_size_per_frame: execution_config.max_heap_size_per_frame,
+                enable_heap_size_tracking: execution_config.enable_heap_size_tracking,
             }),
             execution_trace: ExecutionTraceModule::new(execution_config.max_execution_trace_depth),
+            invocation_filter: InvocationFilterModule::default(),
+            execution_journal: ExecutionJournalModule::default(),
             transaction_runtime: TransactionRuntimeModule {
                 tx_hash,
                 next_id: 0,
                 logs: Vec::new(),
                 events: Vec::new(),
                 replacements: index_map_new(),
+                instruction_index: 0,
+                next_emission_index: 0,
+                log_origins: Vec::new(),
+                event_origins: Vec::new(),
+                event_schema_hashes: Vec::new(),
+                state_write_schema_hashes: index_map_new(),
+                global_address_reservations: index_map_new(),
             },
         }
     }
@@ -179,8 +217,27 @@ impl SystemModuleMixer {
         CostingModule,
         TransactionRuntimeModule,
         ExecutionTraceModule,
+        LimitsModule,
+        ExecutionJournalModule,
     ) {
-        (self.costing, self.transaction_runtime, self.execution_trace)
+        (
+            self.costing,
+            self.transaction_runtime,
+            self.execution_trace,
+            self.limits,
+            self.execution_journal,
+        )
+    }
+
+    /// Journals a substate value read, if the execution journal is enabled.
+    pub fn on_read_substate_value(&mut self, lock_handle: LockHandle, value: &IndexedScryptoValue) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::EXECUTION_JOURNAL)
+        {
+            self.execution_journal
+                .on_read_substate_value(lock_handle, value);
+        }
     }
 }
 
@@ -194,6 +251,16 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
     fn on_init<Y: KernelApi<SystemConfig<V>>>(api: &mut Y) -> Result<(), RuntimeError> {
         let modules: EnabledModules = api.kernel_get_system().modules.enabled_modules;
 
+        // Enable execution journal
+        if modules.contains(EnabledModules::EXECUTION_JOURNAL) {
+            ExecutionJournalModule::on_init(api)?;
+        }
+
+        // Enable invocation filter
+        if modules.contains(EnabledModules::INVOCATION_FILTER) {
+            InvocationFilterModule::on_init(api)?;
+        }
+
         // Enable execution trace
         if modules.contains(EnabledModules::EXECUTION_TRACE) {
             ExecutionTraceModule::on_init(api)?;
@@ -292,6 +359,14 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
         internal_call_dispatch!(api, on_allocate_node_id(api, entity_type))
     }
 
+    #[trace_resources]
+    fn after_allocate_node_id<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        node_id: &NodeId,
+    ) -> Result<(), RuntimeError> {
+        internal_call_dispatch!(api, after_allocate_node_id(api, node_id))
+    }
+
     #[trace_resources]
     fn before_create_node<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
@@ -437,7 +512,20 @@ impl SystemModuleMixer {
     // - Kernel uses the `SystemModule<SystemConfig<V>>` trait above;
     // - System uses methods defined below (TODO: add a trait?)
 
-    pub fn add_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+    pub fn number_of_logs(&self) -> usize {
+        self.transaction_runtime.logs.len()
+    }
+
+    pub fn number_of_events(&self) -> usize {
+        self.transaction_runtime.events.len()
+    }
+
+    pub fn add_log(
+        &mut self,
+        level: Level,
+        message: String,
+        actor: FnIdentifier,
+    ) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
             if self.transaction_runtime.logs.len() >= self.limits.config().max_number_of_logs {
                 return Err(RuntimeError::SystemModuleError(
@@ -454,13 +542,14 @@ impl SystemModuleMixer {
                     ),
                 ));
             }
+            self.limits.process_log_size(message.len())?;
         }
 
         if self
             .enabled_modules
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
-            self.transaction_runtime.add_log(level, message);
+            self.transaction_runtime.add_log(level, message, actor);
         }
 
         Ok(())
@@ -470,6 +559,8 @@ impl SystemModuleMixer {
         &mut self,
         identifier: EventTypeIdentifier,
         data: Vec<u8>,
+        schema_hash: Hash,
+        actor: FnIdentifier,
     ) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
             if self.transaction_runtime.events.len() >= self.limits.config().max_number_of_events {
@@ -489,25 +580,32 @@ impl SystemModuleMixer {
                     ),
                 ));
             }
+            self.limits.process_event_size(data.len())?;
         }
 
         if self
             .enabled_modules
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
-            self.transaction_runtime.add_event(identifier, data)
+            self.transaction_runtime
+                .add_event(identifier, data, schema_hash, actor)
         }
 
         Ok(())
     }
 
     pub fn set_panic_message(&mut self, message: String) -> Result<(), RuntimeError> {
+        self.check_panic_size(message.len())
+    }
+
+    /// Checks the size of a panic message, or of a typed error a blueprint fails with.
+    pub fn check_panic_size(&mut self, size: usize) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
-            if message.len() > self.limits.config().max_panic_message_size {
+            if size > self.limits.config().max_panic_message_size {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(
                         TransactionLimitsError::PanicMessageSizeTooLarge {
-                            actual: message.len(),
+                            actual: size,
                             max: self.limits.config().max_panic_message_size,
                         },
                     ),
@@ -518,6 +616,34 @@ impl SystemModuleMixer {
         Ok(())
     }
 
+    pub fn add_new_global_entity(&mut self) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            self.limits.process_new_global_entity()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn add_state_write_schema_hash(
+        &mut self,
+        node_id: NodeId,
+        partition_num: PartitionNumber,
+        substate_key: SubstateKey,
+        schema_hash: Hash,
+    ) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.add_state_write_schema_hash(
+                node_id,
+                partition_num,
+                substate_key,
+                schema_hash,
+            )
+        }
+    }
+
     pub fn add_replacement(
         &mut self,
         old: (NodeId, ObjectModuleId),
@@ -531,6 +657,30 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn set_global_address_reservation_state(
+        &mut self,
+        global_address: GlobalAddress,
+        state: GlobalAddressReservationState,
+    ) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime
+                .set_global_address_reservation_state(global_address, state)
+        }
+    }
+
+    pub fn global_address_reservation_state(
+        &self,
+        global_address: &GlobalAddress,
+    ) -> Option<GlobalAddressReservationState> {
+        self.transaction_runtime
+            .global_address_reservations
+            .get(global_address)
+            .cloned()
+    }
+
     pub fn auth_zone_id(&mut self) -> Option<NodeId> {
         if self.enabled_modules.contains(EnabledModules::AUTH) {
             self.auth.last_auth_zone()
@@ -578,12 +728,21 @@ impl SystemModuleMixer {
     }
 
     pub fn update_instruction_index(&mut self, new_index: usize) {
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing.update_instruction_index(new_index)
+        }
         if self
             .enabled_modules
             .contains(EnabledModules::EXECUTION_TRACE)
         {
             self.execution_trace.update_instruction_index(new_index)
         }
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.update_instruction_index(new_index)
+        }
     }
 
     pub fn apply_execution_cost(
@@ -608,6 +767,17 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn apply_instantiation_deposit(
+        &mut self,
+        global_address: GlobalAddress,
+    ) -> Result<(), RuntimeError> {
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing.apply_instantiation_deposit(global_address)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn credit_cost_units(
         &mut self,
         vault_id: NodeId,
@@ -1,9 +1,29 @@
 use crate::kernel::kernel_callback_api::KernelCallbackObject;
+use crate::system::id_allocation::GlobalAddressReservationState;
 use crate::system::module::SystemModule;
 use crate::types::*;
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::crypto::Hash;
 
+/// Where an event or log was emitted from.
+///
+/// Events and logs of a transaction are totally ordered by the index of the manifest instruction
+/// during which they were emitted, and then by their emission order within that instruction,
+/// which is shared between events and logs.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct EmissionOrigin {
+    pub instruction_index: usize,
+    pub emission_index: u32,
+    /// The function or method which emitted the entry.
+    pub actor: FnIdentifier,
+}
+
+impl EmissionOrigin {
+    pub fn sort_key(&self) -> (usize, u32) {
+        (self.instruction_index, self.emission_index)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionRuntimeModule {
     pub tx_hash: Hash,
@@ -11,6 +31,15 @@ pub struct TransactionRuntimeModule {
     pub logs: Vec<(Level, String)>,
     pub events: Vec<(EventTypeIdentifier, Vec<u8>)>,
     pub replacements: IndexMap<(NodeId, ObjectModuleId), (NodeId, ObjectModuleId)>,
+    pub instruction_index: usize,
+    pub next_emission_index: u32,
+    pub log_origins: Vec<EmissionOrigin>,
+    pub event_origins: Vec<EmissionOrigin>,
+    /// The hash of the schema each event was validated against, index-aligned with the events.
+    pub event_schema_hashes: Vec<Hash>,
+    /// The hash of the schema each substate was last validated against when written.
+    pub state_write_schema_hashes: IndexMap<(NodeId, PartitionNumber, SubstateKey), Hash>,
+    pub global_address_reservations: IndexMap<GlobalAddress, GlobalAddressReservationState>,
 }
 
 impl TransactionRuntimeModule {
@@ -28,12 +57,49 @@ impl TransactionRuntimeModule {
         hash(bytes).0
     }
 
-    pub fn add_log(&mut self, level: Level, message: String) {
-        self.logs.push((level, message))
+    pub fn update_instruction_index(&mut self, new_index: usize) {
+        self.instruction_index = new_index;
+        self.next_emission_index = 0;
+    }
+
+    fn next_origin(&mut self, actor: FnIdentifier) -> EmissionOrigin {
+        let origin = EmissionOrigin {
+            instruction_index: self.instruction_index,
+            emission_index: self.next_emission_index,
+            actor,
+        };
+        self.next_emission_index += 1;
+        origin
+    }
+
+    pub fn add_log(&mut self, level: Level, message: String, actor: FnIdentifier) {
+        let origin = self.next_origin(actor);
+        self.logs.push((level, message));
+        self.log_origins.push(origin);
+    }
+
+    pub fn add_event(
+        &mut self,
+        identifier: EventTypeIdentifier,
+        data: Vec<u8>,
+        schema_hash: Hash,
+        actor: FnIdentifier,
+    ) {
+        let origin = self.next_origin(actor);
+        self.events.push((identifier, data));
+        self.event_origins.push(origin);
+        self.event_schema_hashes.push(schema_hash);
     }
 
-    pub fn add_event(&mut self, identifier: EventTypeIdentifier, data: Vec<u8>) {
-        self.events.push((identifier, data))
+    pub fn add_state_write_schema_hash(
+        &mut self,
+        node_id: NodeId,
+        partition_num: PartitionNumber,
+        substate_key: SubstateKey,
+        schema_hash: Hash,
+    ) {
+        self.state_write_schema_hashes
+            .insert((node_id, partition_num, substate_key), schema_hash);
     }
 
     pub fn add_replacement(
@@ -44,20 +110,55 @@ impl TransactionRuntimeModule {
         self.replacements.insert(old, new);
     }
 
+    pub fn set_global_address_reservation_state(
+        &mut self,
+        global_address: GlobalAddress,
+        state: GlobalAddressReservationState,
+    ) {
+        self.global_address_reservations
+            .insert(global_address, state);
+    }
+
     pub fn clear(&mut self) {
         self.events.clear();
+        self.event_origins.clear();
+        self.event_schema_hashes.clear();
+        self.state_write_schema_hashes.clear();
         self.replacements.clear();
     }
 
-    pub fn finalize(
-        self,
-        is_success: bool,
-    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<(Level, String)>) {
+    /// Sorts the entries by their origins, keeping the relative order of entries with equal keys.
+    fn sort_by_origin<T>(
+        entries: Vec<T>,
+        origins: Vec<EmissionOrigin>,
+    ) -> (Vec<T>, Vec<EmissionOrigin>) {
+        let mut entries: Vec<(T, EmissionOrigin)> = entries.into_iter().zip(origins).collect();
+        entries.sort_by_key(|(_, origin)| origin.sort_key());
+        entries.into_iter().unzip()
+    }
+
+    pub fn finalize(self, is_success: bool) -> TransactionRuntimeOutput {
+        let (logs, log_origins) = Self::sort_by_origin(self.logs, self.log_origins);
         if !is_success {
-            return (Vec::new(), self.logs);
+            return TransactionRuntimeOutput {
+                events: Vec::new(),
+                event_origins: Vec::new(),
+                event_schema_hashes: Vec::new(),
+                state_write_schema_hashes: index_map_new(),
+                logs,
+                log_origins,
+                global_address_reservations: self.global_address_reservations,
+            };
         }
 
-        let mut events = self.events;
+        let (events, event_origins) = Self::sort_by_origin(
+            self.events
+                .into_iter()
+                .zip(self.event_schema_hashes)
+                .collect(),
+            self.event_origins,
+        );
+        let (mut events, event_schema_hashes): (Vec<_>, Vec<_>) = events.into_iter().unzip();
         for (event_identifier, _) in events.iter_mut() {
             // Apply replacements
             let (node_id, module_id) = match event_identifier {
@@ -74,10 +175,54 @@ impl TransactionRuntimeModule {
             }
         }
 
-        (events, self.logs)
+        // Substates written before their object was globalized were moved to the global node
+        let mut state_write_schema_hashes = index_map_new();
+        for ((node_id, partition_num, substate_key), schema_hash) in self.state_write_schema_hashes
+        {
+            let (node_id, partition_num) = match self
+                .replacements
+                .get(&(node_id, ObjectModuleId::Main))
+                .and_then(|(new_node_id, new_module_id)| {
+                    let offset = partition_num.0.checked_sub(MAIN_BASE_PARTITION.0)?;
+                    let partition_num = new_module_id
+                        .base_partition_num()
+                        .at_offset(PartitionOffset(offset))?;
+                    Some((*new_node_id, partition_num))
+                }) {
+                Some(replacement) => replacement,
+                None => (node_id, partition_num),
+            };
+            state_write_schema_hashes.insert((node_id, partition_num, substate_key), schema_hash);
+        }
+
+        TransactionRuntimeOutput {
+            events,
+            event_origins,
+            event_schema_hashes,
+            state_write_schema_hashes,
+            logs,
+            log_origins,
+            global_address_reservations: self.global_address_reservations,
+        }
     }
 }
 
+/// The events and logs of a transaction in their total order, along with where they were
+/// emitted from.
+pub struct TransactionRuntimeOutput {
+    pub events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    pub event_origins: Vec<EmissionOrigin>,
+    /// The hash of the schema each event was validated against, index-aligned with the events.
+    pub event_schema_hashes: Vec<Hash>,
+    /// The hash of the schema each substate was last validated against when written, by the
+    /// location it was written to after any globalization.
+    pub state_write_schema_hashes: IndexMap<(NodeId, PartitionNumber, SubstateKey), Hash>,
+    pub logs: Vec<(Level, String)>,
+    pub log_origins: Vec<EmissionOrigin>,
+    /// The final state of each global address reserved by the transaction.
+    pub global_address_reservations: IndexMap<GlobalAddress, GlobalAddressReservationState>,
+}
+
 impl<K: KernelCallbackObject> SystemModule<K> for TransactionRuntimeModule {}
 
 #[cfg(test)]
@@ -95,6 +240,13 @@ mod tests {
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instruction_index: 0,
+            next_emission_index: 0,
+            log_origins: Vec::new(),
+            event_origins: Vec::new(),
+            event_schema_hashes: Vec::new(),
+            state_write_schema_hashes: index_map_new(),
+            global_address_reservations: index_map_new(),
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -107,6 +259,13 @@ mod tests {
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instruction_index: 0,
+            next_emission_index: 0,
+            log_origins: Vec::new(),
+            event_origins: Vec::new(),
+            event_schema_hashes: Vec::new(),
+            state_write_schema_hashes: index_map_new(),
+            global_address_reservations: index_map_new(),
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -119,6 +278,13 @@ mod tests {
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instruction_index: 0,
+            next_emission_index: 0,
+            log_origins: Vec::new(),
+            event_origins: Vec::new(),
+            event_schema_hashes: Vec::new(),
+            state_write_schema_hashes: index_map_new(),
+            global_address_reservations: index_map_new(),
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
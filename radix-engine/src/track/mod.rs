@@ -1,8 +1,10 @@
 pub mod interface;
+pub mod prefetch;
 pub mod track;
 pub mod utils;
 
 #[cfg(test)]
 mod test;
 
+pub use prefetch::*;
 pub use track::*;
@@ -0,0 +1,213 @@
+use crate::types::*;
+use radix_engine_store_interface::interface::*;
+
+/// The number of substates read ahead from a high latency database which serializes reads.
+pub const DEFAULT_PREFETCH_LIMIT_FOR_SERIAL_READS: usize = 64;
+/// The number of substates read ahead from a high latency database which serves reads in
+/// parallel, and so hides some of the latency of lookups by itself.
+pub const DEFAULT_PREFETCH_LIMIT_FOR_PARALLEL_READS: usize = 16;
+
+/// How many substates of a partition Track reads ahead, with a single iteration over the
+/// partition, once it looks one of them up in the database.
+///
+/// Prefetching only changes how the database is read: the substates are charged for once they
+/// are looked up, as if they were read from the database then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchPolicy {
+    /// Prefetches from high latency databases only, based on their characteristics.
+    Auto,
+    Disabled,
+    /// Prefetches up to the given number of substates of each partition.
+    UpTo(usize),
+}
+
+impl Default for PrefetchPolicy {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl PrefetchPolicy {
+    pub fn prefetch_limit(&self, characteristics: &DatabaseCharacteristics) -> usize {
+        match self {
+            Self::Auto => match characteristics.read_latency {
+                ReadLatency::Low => 0,
+                ReadLatency::High if characteristics.supports_parallel_reads => {
+                    DEFAULT_PREFETCH_LIMIT_FOR_PARALLEL_READS
+                }
+                ReadLatency::High => DEFAULT_PREFETCH_LIMIT_FOR_SERIAL_READS,
+            },
+            Self::Disabled => 0,
+            Self::UpTo(limit) => *limit,
+        }
+    }
+}
+
+struct PrefetchedPartition {
+    substates: IndexMap<DbSortKey, DbSubstateValue>,
+    /// Whether all the substates of the partition were prefetched.
+    is_complete: bool,
+}
+
+/// The substates a track has prefetched from the database.
+pub struct PrefetchedSubstates {
+    limit: usize,
+    partitions: IndexMap<DbPartitionKey, PrefetchedPartition>,
+}
+
+impl PrefetchedSubstates {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            partitions: index_map_new(),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Looks a substate up, prefetching its partition on the first lookup of the partition.
+    pub fn get_substate<S: SubstateDatabase>(
+        &mut self,
+        substate_db: &S,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        if self.limit == 0 {
+            return substate_db.get_substate(partition_key, sort_key);
+        }
+
+        let limit = self.limit;
+        let partition = self
+            .partitions
+            .entry(partition_key.clone())
+            .or_insert_with(|| {
+                let mut entries = substate_db.list_entries(partition_key);
+                let mut substates = index_map_new();
+                let is_complete = loop {
+                    if substates.len() == limit {
+                        break false;
+                    }
+                    match entries.next() {
+                        Some((sort_key, value)) => {
+                            substates.insert(sort_key, value);
+                        }
+                        None => break true,
+                    }
+                };
+                PrefetchedPartition {
+                    substates,
+                    is_complete,
+                }
+            });
+
+        match partition.substates.get(sort_key) {
+            Some(value) => Some(value.clone()),
+            None if partition.is_complete => None,
+            None => substate_db.get_substate(partition_key, sort_key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::cell::Cell;
+
+    #[derive(Default)]
+    struct CountingDatabase {
+        substates: BTreeMap<(DbPartitionKey, DbSortKey), DbSubstateValue>,
+        lookups: Cell<usize>,
+        iterations: Cell<usize>,
+    }
+
+    impl CountingDatabase {
+        fn with_partition(substate_count: u8) -> Self {
+            let mut database = Self::default();
+            for n in 0..substate_count {
+                database
+                    .substates
+                    .insert((DbPartitionKey(vec![0]), DbSortKey(vec![n])), vec![n]);
+            }
+            database
+        }
+    }
+
+    impl SubstateDatabase for CountingDatabase {
+        fn get_substate(
+            &self,
+            partition_key: &DbPartitionKey,
+            sort_key: &DbSortKey,
+        ) -> Option<DbSubstateValue> {
+            self.lookups.set(self.lookups.get() + 1);
+            self.substates
+                .get(&(partition_key.clone(), sort_key.clone()))
+                .cloned()
+        }
+
+        fn list_entries(
+            &self,
+            partition_key: &DbPartitionKey,
+        ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+            self.iterations.set(self.iterations.get() + 1);
+            let partition_key = partition_key.clone();
+            Box::new(
+                self.substates
+                    .iter()
+                    .filter(move |((key, _), _)| *key == partition_key)
+                    .map(|((_, sort_key), value)| (sort_key.clone(), value.clone())),
+            )
+        }
+    }
+
+    fn get(
+        prefetched: &mut PrefetchedSubstates,
+        database: &CountingDatabase,
+        n: u8,
+    ) -> Option<DbSubstateValue> {
+        prefetched.get_substate(database, &DbPartitionKey(vec![0]), &DbSortKey(vec![n]))
+    }
+
+    #[test]
+    fn test_auto_policy_prefetches_from_high_latency_databases_only() {
+        let low_latency = DatabaseCharacteristics::default();
+        let high_latency = DatabaseCharacteristics {
+            read_latency: ReadLatency::High,
+            supports_parallel_reads: false,
+        };
+
+        assert_eq!(PrefetchPolicy::Auto.prefetch_limit(&low_latency), 0);
+        assert_eq!(
+            PrefetchPolicy::Auto.prefetch_limit(&high_latency),
+            DEFAULT_PREFETCH_LIMIT_FOR_SERIAL_READS
+        );
+        assert_eq!(PrefetchPolicy::Disabled.prefetch_limit(&high_latency), 0);
+    }
+
+    #[test]
+    fn test_complete_partition_is_read_with_a_single_iteration() {
+        let database = CountingDatabase::with_partition(3);
+        let mut prefetched = PrefetchedSubstates::new(10);
+
+        assert_eq!(get(&mut prefetched, &database, 0), Some(vec![0]));
+        assert_eq!(get(&mut prefetched, &database, 2), Some(vec![2]));
+        assert_eq!(get(&mut prefetched, &database, 5), None);
+
+        assert_eq!(database.iterations.get(), 1);
+        assert_eq!(database.lookups.get(), 0);
+    }
+
+    #[test]
+    fn test_substates_past_the_limit_are_looked_up() {
+        let database = CountingDatabase::with_partition(5);
+        let mut prefetched = PrefetchedSubstates::new(2);
+
+        assert_eq!(get(&mut prefetched, &database, 1), Some(vec![1]));
+        assert_eq!(get(&mut prefetched, &database, 4), Some(vec![4]));
+        assert_eq!(get(&mut prefetched, &database, 9), None);
+
+        assert_eq!(database.iterations.get(), 1);
+        assert_eq!(database.lookups.get(), 2);
+    }
+}
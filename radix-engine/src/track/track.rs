@@ -2,6 +2,7 @@ use crate::track::interface::{
     AcquireLockError, NodeSubstates, SetSubstateError, StoreAccess, StoreAccessInfo, SubstateStore,
     TakeSubstateError,
 };
+use crate::track::prefetch::{PrefetchPolicy, PrefetchedSubstates};
 use crate::track::utils::OverlayingIterator;
 use crate::types::*;
 use radix_engine_interface::api::field_lock_api::LockFlags;
@@ -306,6 +307,23 @@ impl TrackedNode {
             tracked_partition.revert_writes();
         }
     }
+
+    /// Whether the node was created in this transaction and then had all of its substates
+    /// removed, eg the phantom of a released global address reservation.
+    pub fn is_new_and_discarded(&self) -> bool {
+        self.is_new
+            && self.tracked_partitions.values().all(|tracked_partition| {
+                tracked_partition
+                    .substates
+                    .values()
+                    .all(|tracked| tracked.substate_value.get().is_none())
+            })
+    }
+}
+
+/// Whether substates of the given partition are discarded at the end of the transaction.
+pub fn is_transient_partition(partition_num: PartitionNumber) -> bool {
+    partition_num == TRANSIENT_PARTITION
 }
 
 pub fn to_state_updates<M: DatabaseKeyMapper>(
@@ -380,9 +398,32 @@ impl<'a> Iterator for TrackedIter<'a> {
 }
 
 /// Transaction-wide states and side effects
+/// Counts of the substate reads served by a [`Track`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrackReadMetrics {
+    /// The substates read from the database, by lookup or by scanning a partition.
+    pub db_reads: usize,
+    pub db_read_bytes: usize,
+    /// The lookups of substates already in the track, which didn't go to the database.
+    pub track_hits: usize,
+}
+
+impl TrackReadMetrics {
+    fn record_db_scan(&mut self, store_access: &StoreAccessInfo) {
+        for access in store_access {
+            if let StoreAccess::ReadFromDb(size) = access {
+                self.db_reads += 1;
+                self.db_read_bytes += size;
+            }
+        }
+    }
+}
+
 pub struct Track<'s, S: SubstateDatabase, M: DatabaseKeyMapper> {
     /// Substate database, use `get_substate_from_db` and `list_entries_from_db` for access
     substate_db: &'s S,
+    /// Substates read ahead from the database, before they are looked up
+    prefetched_substates: PrefetchedSubstates,
 
     tracked_nodes: IndexMap<NodeId, TrackedNode>,
     force_write_tracked_nodes: IndexMap<NodeId, TrackedNode>,
@@ -391,6 +432,7 @@ pub struct Track<'s, S: SubstateDatabase, M: DatabaseKeyMapper> {
 
     locks: IndexMap<u32, (NodeId, PartitionNumber, SubstateKey, LockFlags)>,
     next_lock_id: u32,
+    read_metrics: TrackReadMetrics,
     phantom_data: PhantomData<M>,
 }
 
@@ -398,23 +440,39 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
     pub fn new(substate_db: &'s S) -> Self {
         Self {
             substate_db,
+            prefetched_substates: PrefetchedSubstates::new(
+                PrefetchPolicy::Auto.prefetch_limit(&substate_db.characteristics()),
+            ),
             force_write_tracked_nodes: index_map_new(),
             tracked_nodes: index_map_new(),
             deleted_partitions: index_set_new(),
             locks: index_map_new(),
             next_lock_id: 0,
+            read_metrics: TrackReadMetrics::default(),
             phantom_data: PhantomData::default(),
         }
     }
 
+    pub fn with_prefetch_policy(mut self, prefetch_policy: PrefetchPolicy) -> Self {
+        self.prefetched_substates = PrefetchedSubstates::new(
+            prefetch_policy.prefetch_limit(&self.substate_db.characteristics()),
+        );
+        self
+    }
+
+    pub fn read_metrics(&self) -> &TrackReadMetrics {
+        &self.read_metrics
+    }
+
     fn get_substate_from_db(
         substate_db: &'s S,
+        prefetched_substates: &mut PrefetchedSubstates,
         partition_key: &DbPartitionKey,
         sort_key: &DbSortKey,
         store_access: &mut StoreAccessInfo,
     ) -> Option<IndexedScryptoValue> {
-        let result = substate_db
-            .get_substate(partition_key, sort_key)
+        let result = prefetched_substates
+            .get_substate(substate_db, partition_key, sort_key)
             .map(|e| IndexedScryptoValue::from_vec(e).expect("Failed to decode substate"));
         if let Some(x) = &result {
             store_access.push(StoreAccess::ReadFromDb(x.len()));
@@ -507,13 +565,20 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
 
     /// Finalizes changes captured by this substate store.
     ///
+    /// Transient substates are discarded at this point, so they never reach the database.
+    ///
     ///  Returns the state changes and dependencies.
     pub fn finalize(
-        self,
+        mut self,
     ) -> (
         IndexMap<NodeId, TrackedNode>,
         IndexSet<(NodeId, PartitionNumber)>,
     ) {
+        for (_, tracked_node) in &mut self.tracked_nodes {
+            tracked_node
+                .tracked_partitions
+                .retain(|partition_num, _| !is_transient_partition(*partition_num));
+        }
         (self.tracked_nodes, self.deleted_partitions)
     }
 
@@ -554,13 +619,24 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
 
         match entry {
             Entry::Vacant(e) => {
-                let db_partition_key = M::to_db_partition_key(node_id, partition_num);
-                let value = Self::get_substate_from_db(
-                    self.substate_db,
-                    &db_partition_key,
-                    &db_sort_key,
-                    store_access,
-                );
+                // Transient substates are never persisted, so there is no point reading them
+                let value = if is_transient_partition(partition_num) {
+                    None
+                } else {
+                    let db_partition_key = M::to_db_partition_key(node_id, partition_num);
+                    Self::get_substate_from_db(
+                        self.substate_db,
+                        &mut self.prefetched_substates,
+                        &db_partition_key,
+                        &db_sort_key,
+                        store_access,
+                    )
+                };
+                if !is_transient_partition(partition_num) {
+                    self.read_metrics.db_reads += 1;
+                    self.read_metrics.db_read_bytes +=
+                        value.as_ref().map(|value| value.len()).unwrap_or(0);
+                }
                 if let Some(value) = value {
                     store_access.push(StoreAccess::ReadFromDb(value.len()));
                     store_access.push(StoreAccess::NewEntryInTrack);
@@ -595,6 +671,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> Track<'s, S, M> {
                 }
             }
             Entry::Occupied(mut entry) => {
+                self.read_metrics.track_hits += 1;
                 let read_only_non_existent = matches!(
                     entry.get().substate_value,
                     TrackedSubstateValue::ReadOnly(ReadOnly::NonExistent)
@@ -817,6 +894,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         tracked_partition.range_read = u32::max(tracked_partition.range_read, num_iterations);
 
         drop(tracked_iter);
+        self.read_metrics.record_db_scan(&store_access);
         (items, store_access)
     }
 
@@ -913,6 +991,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         }
 
         drop(tracked_iter);
+        self.read_metrics.record_db_scan(&store_access);
         (items, store_access)
     }
 
@@ -973,6 +1052,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         // Use the statistics (gathered by the `.inspect()`s above) to update the track's metadata and to return costing info
         tracked_partition.range_read = u32::max(tracked_partition.range_read, db_values_count);
 
+        self.read_metrics.record_db_scan(&store_access);
         return (items, store_access);
     }
 
@@ -1032,9 +1112,11 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
         };
 
         // Check read/write permission
-        substate.lock_state.try_lock(flags).map_err(|_| {
-            AcquireLockError::SubstateLocked(*node_id, partition_num, substate_key.clone())
-        })?;
+        if !flags.contains(LockFlags::UNLOCKED_READ) {
+            substate.lock_state.try_lock(flags).map_err(|_| {
+                AcquireLockError::SubstateLocked(*node_id, partition_num, substate_key.clone())
+            })?;
+        }
 
         let handle = self.new_lock_handle(node_id, partition_num, substate_key, flags);
 
@@ -1058,7 +1140,9 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
             .get_runtime_substate_mut()
             .expect("Could not have created lock on non-existent substate");
 
-        substate.lock_state.unlock();
+        if !flags.contains(LockFlags::UNLOCKED_READ) {
+            substate.lock_state.unlock();
+        }
 
         if flags.contains(LockFlags::FORCE_WRITE) {
             let db_sort_key = M::to_db_sort_key(&substate_key);
@@ -1179,6 +1263,9 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper> SubstateStore for Track<'s,
 
         for (node_id, node) in &self.tracked_nodes {
             for (partition_number, partition) in &node.tracked_partitions {
+                if is_transient_partition(*partition_number) {
+                    continue;
+                }
                 for (sort_key, substate) in &partition.substates {
                     match &substate.substate_value {
                         TrackedSubstateValue::New(v) => {
@@ -0,0 +1,165 @@
+use crate::transaction::*;
+use crate::types::*;
+use crate::vm::wasm::*;
+use crate::vm::ScryptoVm;
+use radix_engine_store_interface::interface::*;
+use transaction::model::*;
+
+pub const DEFAULT_EXECUTION_CACHE_CAPACITY: usize = 1000;
+
+#[derive(Debug, Clone)]
+struct CachedExecution {
+    state_version: u64,
+    fingerprint: Hash,
+    receipt: TransactionReceipt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionCacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Caches transaction receipts by intent hash and the state version they were executed against.
+///
+/// Nodes execute the same transaction when it's proposed and again when it's committed. As long as
+/// the state version is unchanged the execution is deterministic, so the second execution can reuse
+/// the receipt (including its state updates) of the first.
+///
+/// The state version is owned by the caller, and must change whenever the substate database does.
+/// The same cache must not be shared across different fee reserve or execution configs.
+#[derive(Debug, Clone)]
+pub struct ExecutionResultCache {
+    capacity: usize,
+    entries: IndexMap<Hash, CachedExecution>,
+    metrics: ExecutionCacheMetrics,
+}
+
+impl Default for ExecutionResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_EXECUTION_CACHE_CAPACITY)
+    }
+}
+
+impl ExecutionResultCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: index_map_new(),
+            metrics: ExecutionCacheMetrics::default(),
+        }
+    }
+
+    /// Returns the cached receipt if the transaction was executed against the same state version.
+    pub fn get(
+        &mut self,
+        executable: &Executable,
+        state_version: u64,
+    ) -> Option<TransactionReceipt> {
+        let intent_hash = executable.intent_hash().to_hash();
+        let receipt = self
+            .entries
+            .get(&intent_hash)
+            .filter(|cached| {
+                cached.state_version == state_version
+                    && cached.fingerprint == Self::fingerprint(executable)
+            })
+            .map(|cached| cached.receipt.clone());
+
+        if receipt.is_some() {
+            self.metrics.hits += 1;
+        } else {
+            self.metrics.misses += 1;
+        }
+        receipt
+    }
+
+    /// Caches a receipt, replacing any receipt of the same intent at a different state version.
+    pub fn insert(
+        &mut self,
+        executable: &Executable,
+        state_version: u64,
+        receipt: TransactionReceipt,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let intent_hash = executable.intent_hash().to_hash();
+        self.entries.shift_remove(&intent_hash);
+        while self.entries.len() >= self.capacity {
+            self.entries.shift_remove_index(0);
+        }
+        self.entries.insert(
+            intent_hash,
+            CachedExecution {
+                state_version,
+                fingerprint: Self::fingerprint(executable),
+                receipt,
+            },
+        );
+    }
+
+    /// Drops all receipts computed against state versions before `state_version`, which can no
+    /// longer be hit once the ledger has moved on.
+    pub fn evict_before(&mut self, state_version: u64) {
+        self.entries
+            .retain(|_, cached| cached.state_version >= state_version);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn metrics(&self) -> ExecutionCacheMetrics {
+        self.metrics
+    }
+
+    /// The intent hash doesn't cover signatures or the test-only fee payment overrides, so the
+    /// remaining inputs of the executable are hashed to guard against a false hit.
+    fn fingerprint(executable: &Executable) -> Hash {
+        let mut bytes = executable.encoded_instructions().to_vec();
+        bytes.extend(scrypto_encode(executable.auth_zone_params()).unwrap());
+        bytes.extend(scrypto_encode(executable.fee_payment()).unwrap());
+        bytes.extend(scrypto_encode(executable.pre_allocated_addresses()).unwrap());
+        for blob_hash in executable.blobs().keys() {
+            bytes.extend(blob_hash.0);
+        }
+        bytes.extend((executable.payload_size() as u64).to_le_bytes());
+        hash(bytes)
+    }
+}
+
+/// Executes a transaction, reusing the receipt of an earlier execution of the same transaction
+/// against the same state version if there is one.
+pub fn execute_transaction_with_cache<S: SubstateDatabase, W: WasmEngine>(
+    substate_db: &S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+    cache: &mut ExecutionResultCache,
+    state_version: u64,
+) -> TransactionReceipt {
+    if let Some(receipt) = cache.get(transaction, state_version) {
+        return receipt;
+    }
+
+    let receipt = execute_transaction(
+        substate_db,
+        scrypto_interpreter,
+        fee_reserve_config,
+        execution_config,
+        transaction,
+    );
+    cache.insert(transaction, state_version, receipt.clone());
+    receipt
+}
@@ -0,0 +1,134 @@
+use crate::track::TrackReadMetrics;
+use crate::transaction::*;
+use crate::types::*;
+use core::time::Duration;
+use radix_engine_store_interface::interface::DatabaseUpdate;
+
+/// The outcome of an executed transaction, as reported to a [`MetricsSink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransactionResultKind {
+    CommitSuccess,
+    CommitFailure,
+    Reject,
+    Abort,
+}
+
+impl TransactionResultKind {
+    pub fn of(result: &TransactionResult) -> Self {
+        match result {
+            TransactionResult::Commit(commit) => match commit.outcome {
+                TransactionOutcome::Success(_) => Self::CommitSuccess,
+                TransactionOutcome::Failure(_) => Self::CommitFailure,
+            },
+            TransactionResult::Reject(_) => Self::Reject,
+            TransactionResult::Abort(_) => Self::Abort,
+        }
+    }
+}
+
+/// The substate IO of an executed transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubstateIoMetrics {
+    pub reads: TrackReadMetrics,
+    /// The substates set or deleted by the committed state updates.
+    pub writes: usize,
+    pub write_bytes: usize,
+}
+
+impl SubstateIoMetrics {
+    pub fn new(reads: TrackReadMetrics, result: &TransactionResult) -> Self {
+        let mut writes = 0;
+        let mut write_bytes = 0;
+        if let TransactionResult::Commit(commit) = result {
+            for partition_updates in commit.state_updates.database_updates.values() {
+                for update in partition_updates.values() {
+                    writes += 1;
+                    if let DatabaseUpdate::Set(value) = update {
+                        write_bytes += value.len();
+                    }
+                }
+            }
+        }
+        Self {
+            reads,
+            writes,
+            write_bytes,
+        }
+    }
+}
+
+/// Receives the metrics of every transaction executed with
+/// [`execute_transaction_with_metrics`], so that embedders can export them without parsing
+/// receipts.
+///
+/// All callbacks default to doing nothing. For each transaction, they are called in the order
+/// they're declared in.
+pub trait MetricsSink {
+    /// The wall-clock time of the execution. Only reported with the `std` feature.
+    fn on_duration(&mut self, _duration: Duration) {}
+
+    /// The execution cost units consumed by a committed transaction.
+    fn on_execution_cost_units(&mut self, _cost_units: u32) {}
+
+    fn on_substate_io(&mut self, _metrics: &SubstateIoMetrics) {}
+
+    fn on_transaction_executed(&mut self, _result: TransactionResultKind) {}
+}
+
+/// A [`MetricsSink`] which discards all metrics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpMetricsSink;
+
+impl MetricsSink for NoOpMetricsSink {}
+
+/// A [`MetricsSink`] which sums up the metrics of all transactions, for scraping at intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AggregatingMetricsSink {
+    pub transactions: BTreeMap<TransactionResultKind, u64>,
+    pub total_duration: Duration,
+    pub max_duration: Duration,
+    pub total_execution_cost_units: u64,
+    pub substate_io: SubstateIoMetrics,
+}
+
+impl AggregatingMetricsSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn transaction_count(&self) -> u64 {
+        self.transactions.values().sum()
+    }
+
+    pub fn transaction_count_of(&self, result: TransactionResultKind) -> u64 {
+        self.transactions.get(&result).cloned().unwrap_or(0)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl MetricsSink for AggregatingMetricsSink {
+    fn on_duration(&mut self, duration: Duration) {
+        self.total_duration += duration;
+        self.max_duration = self.max_duration.max(duration);
+    }
+
+    fn on_execution_cost_units(&mut self, cost_units: u32) {
+        self.total_execution_cost_units += u64::from(cost_units);
+    }
+
+    fn on_substate_io(&mut self, metrics: &SubstateIoMetrics) {
+        let io = &mut self.substate_io;
+        io.reads.db_reads += metrics.reads.db_reads;
+        io.reads.db_read_bytes += metrics.reads.db_read_bytes;
+        io.reads.track_hits += metrics.reads.track_hits;
+        io.writes += metrics.writes;
+        io.write_bytes += metrics.write_bytes;
+    }
+
+    fn on_transaction_executed(&mut self, result: TransactionResultKind) {
+        *self.transactions.entry(result).or_default() += 1;
+    }
+}
@@ -1,9 +1,15 @@
+mod execution_cache;
+mod metrics_sink;
 mod preview_executor;
 mod state_update_summary;
 mod transaction_executor;
 mod transaction_receipt;
+mod transaction_review;
 
+pub use execution_cache::*;
+pub use metrics_sink::*;
 pub use preview_executor::*;
 pub use state_update_summary::*;
 pub use transaction_executor::*;
 pub use transaction_receipt::*;
+pub use transaction_review::*;
@@ -37,7 +37,7 @@ impl StateUpdateSummary {
         let mut new_vaults = index_set_new();
 
         for (node_id, tracked) in updates {
-            if tracked.is_new {
+            if tracked.is_new && !tracked.is_new_and_discarded() {
                 if node_id.is_global_package() {
                     new_packages.insert(PackageAddress::new_or_panic(node_id.0));
                 }
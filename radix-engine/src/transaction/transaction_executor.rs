@@ -1,21 +1,28 @@
-use crate::blueprints::consensus_manager::{ConsensusManagerSubstate, ValidatorRewardsSubstate};
+use crate::blueprints::consensus_manager::{
+    ConsensusManagerSubstate, InstantiationDepositsSubstate, ProposerMilliTimestampSubstate,
+    ValidatorRewardsSubstate,
+};
 use crate::blueprints::transaction_processor::TransactionProcessorError;
-use crate::blueprints::transaction_tracker::{TransactionStatus, TransactionTrackerSubstate};
+use crate::blueprints::transaction_tracker::{
+    IdempotencyKeysSubstate, TransactionStatus, TransactionTrackerSubstate,
+};
 use crate::errors::*;
 use crate::kernel::id_allocator::IdAllocator;
 use crate::kernel::kernel::KernelBoot;
 use crate::system::system::{KeyValueEntrySubstate, SubstateMutability};
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_modules::costing::*;
+use crate::system::system_modules::execution_journal::ExecutionJournalModule;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::limits::LimitsModule;
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
 use crate::track::interface::SubstateStore;
-use crate::track::{to_state_updates, Track};
+use crate::track::{to_state_updates, PrefetchPolicy, Track};
 use crate::transaction::*;
 use crate::types::*;
 use crate::vm::wasm::*;
-use crate::vm::{ScryptoVm, Vm};
+use crate::vm::{PackageStubs, ScryptoVm, Vm};
 use radix_engine_constants::*;
 use radix_engine_interface::api::LockFlags;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
@@ -29,6 +36,8 @@ pub struct FeeReserveConfig {
     pub usd_price: Decimal,
     pub state_expansion_price: Decimal,
     pub system_loan: u32,
+    /// The XRD deposit required to globalize a component, held until the component is destroyed
+    pub instantiation_deposit: Option<Decimal>,
 }
 
 impl Default for FeeReserveConfig {
@@ -38,6 +47,7 @@ impl Default for FeeReserveConfig {
             usd_price: DEFAULT_USD_PRICE_IN_XRD.try_into().unwrap(),
             state_expansion_price: DEFAULT_STATE_EXPANSION_PRICE_IN_XRD.try_into().unwrap(),
             system_loan: DEFAULT_SYSTEM_LOAN,
+            instantiation_deposit: None,
         }
     }
 }
@@ -51,15 +61,23 @@ pub struct ExecutionConfig {
     pub abort_when_loan_repaid: bool,
     pub max_number_of_substates_in_track: usize,
     pub max_number_of_substates_in_heap: usize,
+    pub max_number_of_new_global_entities: usize,
     pub max_substate_size: usize,
     pub max_invoke_input_size: usize,
     pub enable_cost_breakdown: bool,
     pub max_event_size: usize,
     pub max_log_size: usize,
+    pub max_total_event_size: usize,
+    pub max_total_log_size: usize,
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
     pub max_per_function_royalty_in_xrd: Decimal,
+    pub max_per_call_royalty_in_xrd: Decimal,
+    pub max_heap_size_per_frame: Option<usize>,
+    pub enable_heap_size_tracking: bool,
+    pub execution_deadline: Option<ExecutionDeadline>,
+    pub prefetch_policy: PrefetchPolicy,
 }
 
 impl ExecutionConfig {
@@ -74,11 +92,14 @@ impl ExecutionConfig {
             abort_when_loan_repaid: false,
             max_number_of_substates_in_track: DEFAULT_MAX_NUMBER_OF_SUBSTATES_IN_TRACK,
             max_number_of_substates_in_heap: DEFAULT_MAX_NUMBER_OF_SUBSTATES_IN_HEAP,
+            max_number_of_new_global_entities: DEFAULT_MAX_NUMBER_OF_NEW_GLOBAL_ENTITIES,
             max_substate_size: DEFAULT_MAX_SUBSTATE_SIZE,
             max_invoke_input_size: DEFAULT_MAX_INVOKE_INPUT_SIZE,
             enable_cost_breakdown: false,
             max_event_size: DEFAULT_MAX_EVENT_SIZE,
             max_log_size: DEFAULT_MAX_LOG_SIZE,
+            max_total_event_size: DEFAULT_MAX_TOTAL_EVENT_SIZE,
+            max_total_log_size: DEFAULT_MAX_TOTAL_LOG_SIZE,
             max_panic_message_size: DEFAULT_MAX_PANIC_MESSAGE_SIZE,
             max_number_of_logs: DEFAULT_MAX_NUMBER_OF_LOGS,
             max_number_of_events: DEFAULT_MAX_NUMBER_OF_EVENTS,
@@ -86,6 +107,12 @@ impl ExecutionConfig {
                 DEFAULT_MAX_PER_FUNCTION_ROYALTY_IN_XRD,
             )
             .unwrap(),
+            max_per_call_royalty_in_xrd: Decimal::try_from(DEFAULT_MAX_PER_CALL_ROYALTY_IN_XRD)
+                .unwrap(),
+            max_heap_size_per_frame: None,
+            enable_heap_size_tracking: false,
+            execution_deadline: None,
+            prefetch_policy: PrefetchPolicy::Auto,
         }
     }
 
@@ -94,6 +121,7 @@ impl ExecutionConfig {
             enabled_modules: EnabledModules::for_genesis_transaction(),
             max_number_of_substates_in_track: 50_000,
             max_number_of_substates_in_heap: 50_000,
+            max_number_of_new_global_entities: 50_000,
             max_number_of_events: 1_000_000,
             ..Self::default()
         }
@@ -117,6 +145,7 @@ impl ExecutionConfig {
         Self {
             enabled_modules: EnabledModules::for_test_transaction(),
             enable_cost_breakdown: true,
+            enable_heap_size_tracking: true,
             ..Self::default()
         }
     }
@@ -124,6 +153,7 @@ impl ExecutionConfig {
     pub fn for_preview() -> Self {
         Self {
             enabled_modules: EnabledModules::for_preview(),
+            enable_heap_size_tracking: true,
             ..Self::default()
         }
     }
@@ -137,11 +167,63 @@ impl ExecutionConfig {
         self
     }
 
+    /// Records the kernel decision points of the execution into the receipt, to debug
+    /// nondeterminism across nodes.
+    pub fn with_execution_journal(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules
+                .insert(EnabledModules::EXECUTION_JOURNAL);
+        } else {
+            self.enabled_modules
+                .remove(EnabledModules::EXECUTION_JOURNAL);
+        }
+        self
+    }
+
+    pub fn with_invocation_filter(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules
+                .insert(EnabledModules::INVOCATION_FILTER);
+        } else {
+            self.enabled_modules
+                .remove(EnabledModules::INVOCATION_FILTER);
+        }
+        self
+    }
+
+    pub fn with_max_heap_size_per_frame(mut self, max_heap_size_per_frame: usize) -> Self {
+        self.max_heap_size_per_frame = Some(max_heap_size_per_frame);
+        self
+    }
+
+    /// Fails the transaction once it globalizes more than the given number of entities.
+    pub fn with_max_number_of_new_global_entities(
+        mut self,
+        max_number_of_new_global_entities: usize,
+    ) -> Self {
+        self.max_number_of_new_global_entities = max_number_of_new_global_entities;
+        self
+    }
+
     pub fn with_cost_unit_limit(mut self, cost_unit_limit: u32) -> Self {
         self.cost_unit_limit = cost_unit_limit;
         self
     }
 
+    /// Rejects the transaction if execution runs past the given deadline, e.g. to bound the
+    /// latency of previews.
+    pub fn with_execution_deadline(mut self, execution_deadline: ExecutionDeadline) -> Self {
+        self.execution_deadline = Some(execution_deadline);
+        self
+    }
+
+    /// Overrides how many substates are read ahead from the database, which by default
+    /// depends on its advertised characteristics.
+    pub fn with_prefetch_policy(mut self, prefetch_policy: PrefetchPolicy) -> Self {
+        self.prefetch_policy = prefetch_policy;
+        self
+    }
+
     pub fn up_to_loan_repayment(mut self, enabled: bool) -> Self {
         self.abort_when_loan_repaid = enabled;
         self
@@ -151,27 +233,40 @@ impl ExecutionConfig {
 /// An executor that runs transactions.
 /// This is no longer public -- it can be removed / merged into the exposed functions in a future small PR
 /// But I'm not doing it in this PR to avoid merge conflicts in the body of execute_with_fee_reserve
-struct TransactionExecutor<'s, 'w, S, W>
+struct TransactionExecutor<'s, 'w, 'm, S, W>
 where
     S: SubstateDatabase,
     W: WasmEngine,
 {
     substate_db: &'s S,
     scrypto_vm: &'w ScryptoVm<W>,
+    package_stubs: Option<&'w dyn PackageStubs>,
+    metrics_sink: &'m mut dyn MetricsSink,
 }
 
-impl<'s, 'w, S, W> TransactionExecutor<'s, 'w, S, W>
+impl<'s, 'w, 'm, S, W> TransactionExecutor<'s, 'w, 'm, S, W>
 where
     S: SubstateDatabase,
     W: WasmEngine,
 {
-    pub fn new(substate_db: &'s S, scrypto_vm: &'w ScryptoVm<W>) -> Self {
+    pub fn new(
+        substate_db: &'s S,
+        scrypto_vm: &'w ScryptoVm<W>,
+        metrics_sink: &'m mut dyn MetricsSink,
+    ) -> Self {
         Self {
             substate_db,
             scrypto_vm,
+            package_stubs: None,
+            metrics_sink,
         }
     }
 
+    pub fn with_package_stubs(mut self, package_stubs: &'w dyn PackageStubs) -> Self {
+        self.package_stubs = Some(package_stubs);
+        self
+    }
+
     pub fn execute(
         &mut self,
         transaction: &Executable,
@@ -187,7 +282,9 @@ where
             fee_reserve_config.system_loan,
             execution_config.abort_when_loan_repaid,
         )
-        .with_free_credit(transaction.fee_payment().free_credit_in_xrd);
+        .with_free_credit(transaction.fee_payment().free_credit_in_xrd)
+        .with_fee_ceiling(transaction.fee_payment().max_fee_in_xrd)
+        .with_instantiation_deposit(fee_reserve_config.instantiation_deposit);
 
         self.execute_with_fee_reserve(transaction, execution_config, fee_reserve, FeeTable::new())
     }
@@ -208,13 +305,17 @@ where
             Self::print_executable(&executable);
         }
 
+        #[cfg(feature = "std")]
+        let execution_start = std::time::Instant::now();
+
         // Start hardware resource usage tracker
         #[cfg(all(target_os = "linux", feature = "std", feature = "cpu_ram_metrics"))]
         let mut resources_tracker =
             crate::kernel::resources_tracker::ResourcesTracker::start_measurement();
 
         // Create a track
-        let mut track = Track::<_, SpreadPrefixKeyMapper>::new(self.substate_db);
+        let mut track = Track::<_, SpreadPrefixKeyMapper>::new(self.substate_db)
+            .with_prefetch_policy(execution_config.prefetch_policy);
 
         // Perform runtime validation.
         // TODO: the following assumptions can be removed with better interface.
@@ -240,13 +341,39 @@ where
         } else {
             Ok(())
         };
+        let validation_result = validation_result.and_then(|_| {
+            match (
+                executable.expires_at_timestamp(),
+                Self::read_current_time_milli(&mut track),
+            ) {
+                (Some(expires_at), Some(current_time_milli)) => {
+                    Self::validate_expiry(*expires_at, current_time_milli)
+                }
+                _ => Ok(()),
+            }
+        });
+        let validation_result =
+            validation_result.and_then(|_| match (executable.idempotency_key(), current_epoch) {
+                (Some(idempotency_key), Some(current_epoch)) => {
+                    Self::validate_idempotency_key(&mut track, idempotency_key, current_epoch)
+                }
+                _ => Ok(()),
+            });
 
         // Run manifest
+        let mut execution_journal = None;
+        let track_read_metrics;
         let result = match validation_result {
             Ok(()) => {
                 let (
                     interpretation_result,
-                    (mut costing_module, runtime_module, execution_trace_module),
+                    (
+                        mut costing_module,
+                        runtime_module,
+                        execution_trace_module,
+                        limits_module,
+                        execution_journal_module,
+                    ),
                 ) = self.interpret_manifest(
                     &mut track,
                     executable,
@@ -255,6 +382,13 @@ where
                     fee_table,
                 );
 
+                if execution_config
+                    .enabled_modules
+                    .contains(EnabledModules::EXECUTION_JOURNAL)
+                {
+                    execution_journal = Some(execution_journal_module.finalize());
+                }
+
                 #[cfg(not(feature = "alloc"))]
                 if execution_config
                     .enabled_modules
@@ -275,6 +409,8 @@ where
                         // Commit/revert
                         if !is_success {
                             costing_module.fee_reserve.revert_royalty();
+                            costing_module.fee_reserve.revert_instantiation_deposits();
+                            costing_module.fee_reserve.revert_rent();
                             track.revert_non_force_write_changes();
                         }
 
@@ -286,7 +422,28 @@ where
                             .into_iter()
                             .map(|(k, v)| (k.to_string(), v))
                             .collect();
+                        let tip_per_cost_unit =
+                            tip_price(fee_summary.cost_unit_price, fee_summary.tip_percentage);
+                        fee_summary.instruction_cost_breakdown = costing_module
+                            .instruction_costing_traces
+                            .into_iter()
+                            .map(|(instruction_index, mut breakdown)| {
+                                let cost_units = breakdown.execution_cost_units();
+                                breakdown.execution_cost_xrd =
+                                    fee_summary.cost_unit_price * cost_units;
+                                breakdown.tipping_cost_xrd = tip_per_cost_unit * cost_units;
+                                // Royalties are reverted along with the transaction
+                                if !is_success {
+                                    breakdown.royalty_cost_xrd = Decimal::ZERO;
+                                }
+                                (instruction_index, breakdown)
+                            })
+                            .collect();
                         fee_summary.fee_payments = fee_payments.clone();
+                        #[cfg(debug_assertions)]
+                        if let Err(errors) = fee_summary.reconcile().check(&fee_summary) {
+                            panic!("Fee reconciliation failed: {:?}", errors);
+                        }
 
                         // Update intent hash status
                         if let Some(next_epoch) = Self::read_epoch(&mut track) {
@@ -296,13 +453,29 @@ where
                                 executable.intent_hash(),
                                 is_success,
                             );
+                            if let (Some(idempotency_key), Some(range)) =
+                                (executable.idempotency_key(), executable.epoch_range())
+                            {
+                                Self::record_idempotency_key(
+                                    &mut track,
+                                    *idempotency_key,
+                                    range.end_epoch_exclusive,
+                                    next_epoch,
+                                );
+                            }
                         }
 
                         // Finalize everything
-                        let (application_events, application_logs) =
-                            runtime_module.finalize(is_success);
+                        let mut runtime_output = runtime_module.finalize(is_success);
                         let execution_trace =
                             execution_trace_module.finalize(&fee_payments, is_success);
+                        let execution_metrics = ExecutionMetrics {
+                            heap_size_peaks: limits_module.finalize(),
+                            global_address_reservations: core::mem::take(
+                                &mut runtime_output.global_address_reservations,
+                            ),
+                        };
+                        track_read_metrics = track.read_metrics().clone();
                         let (tracked_nodes, deleted_partitions) = track.finalize();
                         let state_update_summary =
                             StateUpdateSummary::new(self.substate_db, &tracked_nodes);
@@ -310,6 +483,19 @@ where
                             tracked_nodes,
                             deleted_partitions,
                         );
+                        let state_write_schema_hashes = runtime_output
+                            .state_write_schema_hashes
+                            .into_iter()
+                            .filter(|((node_id, partition_num, substate_key), _)| {
+                                matches!(
+                                    state_updates
+                                        .system_updates
+                                        .get(&(*node_id, *partition_num))
+                                        .and_then(|updates| updates.get(substate_key)),
+                                    Some(DatabaseUpdate::Set(..))
+                                )
+                            })
+                            .collect();
 
                         TransactionResult::Commit(CommitResult {
                             state_updates,
@@ -319,20 +505,30 @@ where
                                 Err(e) => TransactionOutcome::Failure(e),
                             },
                             fee_summary,
-                            application_events,
-                            application_logs,
+                            application_events: runtime_output.events,
+                            application_logs: runtime_output.logs,
+                            application_event_origins: runtime_output.event_origins,
+                            application_log_origins: runtime_output.log_origins,
+                            application_event_schema_hashes: runtime_output.event_schema_hashes,
+                            state_write_schema_hashes,
                             execution_trace,
+                            execution_metrics,
                         })
                     }
                     TransactionResultType::Reject(error) => {
+                        track_read_metrics = track.read_metrics().clone();
                         TransactionResult::Reject(RejectResult { error })
                     }
                     TransactionResultType::Abort(error) => {
+                        track_read_metrics = track.read_metrics().clone();
                         TransactionResult::Abort(AbortResult { reason: error })
                     }
                 }
             }
-            Err(error) => TransactionResult::Reject(RejectResult { error }),
+            Err(error) => {
+                track_read_metrics = track.read_metrics().clone();
+                TransactionResult::Reject(RejectResult { error })
+            }
         };
 
         // Stop hardware resource usage tracker
@@ -347,6 +543,7 @@ where
         let receipt = TransactionReceipt {
             transaction_result: result,
             resources_usage,
+            execution_journal,
         };
 
         // Dump summary
@@ -358,6 +555,20 @@ where
             Self::print_execution_summary(&receipt);
         }
 
+        // Report metrics
+        #[cfg(feature = "std")]
+        self.metrics_sink.on_duration(execution_start.elapsed());
+        if let TransactionResult::Commit(commit) = &receipt.transaction_result {
+            self.metrics_sink
+                .on_execution_cost_units(commit.fee_summary.execution_cost_sum);
+        }
+        self.metrics_sink.on_substate_io(&SubstateIoMetrics::new(
+            track_read_metrics,
+            &receipt.transaction_result,
+        ));
+        self.metrics_sink
+            .on_transaction_executed(TransactionResultKind::of(&receipt.transaction_result));
+
         receipt
     }
 
@@ -380,6 +591,36 @@ where
         Some(substate.epoch)
     }
 
+    fn read_current_time_milli(track: &mut Track<S, SpreadPrefixKeyMapper>) -> Option<i64> {
+        let handle = match track.acquire_lock(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::CurrentTime.into(),
+            LockFlags::read_only(),
+        ) {
+            Ok(x) => x.0,
+            Err(_) => {
+                return None;
+            }
+        };
+        let substate: ProposerMilliTimestampSubstate =
+            track.read_substate(handle).0.as_typed().unwrap();
+        track.close_substate(handle);
+        Some(substate.epoch_milli)
+    }
+
+    fn validate_expiry(expires_at: Instant, current_time_milli: i64) -> Result<(), RejectionError> {
+        let expires_at_milli = expires_at.seconds_since_unix_epoch.saturating_mul(1000);
+        if current_time_milli >= expires_at_milli {
+            return Err(RejectionError::TransactionExpired {
+                expires_at,
+                current_time_milli,
+            });
+        }
+
+        Ok(())
+    }
+
     fn validate_epoch_range(
         current_epoch: Epoch,
         start_epoch_inclusive: Epoch,
@@ -457,6 +698,38 @@ where
         Ok(())
     }
 
+    fn validate_idempotency_key(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        idempotency_key: &Hash,
+        current_epoch: Epoch,
+    ) -> Result<(), RejectionError> {
+        let handle = track
+            .acquire_lock(
+                TRANSACTION_TRACKER.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &TransactionTrackerField::IdempotencyKeys.into(),
+                LockFlags::read_only(),
+            )
+            .unwrap()
+            .0;
+        let substate: IdempotencyKeysSubstate = track.read_substate(handle).0.as_typed().unwrap();
+        track.close_substate(handle);
+
+        if let Some(expiry_epoch) = substate.find_unexpired(idempotency_key, current_epoch) {
+            return Err(RejectionError::IdempotencyKeyPreviouslyCommitted {
+                idempotency_key: *idempotency_key,
+                expiry_epoch,
+            });
+        }
+        if substate.is_full(current_epoch) {
+            return Err(RejectionError::IdempotencyKeysExhausted {
+                capacity: substate.capacity,
+            });
+        }
+
+        Ok(())
+    }
+
     fn interpret_manifest(
         &self,
         track: &mut Track<S, SpreadPrefixKeyMapper>,
@@ -470,6 +743,8 @@ where
             CostingModule,
             TransactionRuntimeModule,
             ExecutionTraceModule,
+            LimitsModule,
+            ExecutionJournalModule,
         ),
     ) {
         let mut id_allocator = IdAllocator::new(executable.intent_hash().to_hash());
@@ -479,6 +754,7 @@ where
             schema_cache: NonIterMap::new(),
             callback_obj: Vm {
                 scrypto_vm: self.scrypto_vm,
+                package_stubs: self.package_stubs,
             },
             modules: SystemModuleMixer::new(
                 execution_config.enabled_modules,
@@ -576,6 +852,14 @@ where
                 }
                 _ => {}
             },
+            Err(RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::ExecutionDeadlineExceeded { deadline, metrics },
+            ))) => {
+                return TransactionResultType::Reject(RejectionError::ExecutionDeadlineExceeded {
+                    deadline: *deadline,
+                    metrics: metrics.clone(),
+                })
+            }
             Err(err) => {
                 if let Some(abort_reason) = err.abortion() {
                     return TransactionResultType::Abort(abort_reason.clone());
@@ -602,47 +886,26 @@ where
         fee_reserve: SystemLoanFeeReserve,
         is_success: bool,
     ) -> (FeeSummary, IndexMap<NodeId, Decimal>) {
-        // Distribute royalty
-        for (_, (recipient_vault_id, amount)) in fee_reserve.royalty_cost() {
-            let node_id = recipient_vault_id;
-            let substate_key = FungibleVaultField::LiquidFungible.into();
-            let (handle, _store_access) = track
-                .acquire_lock(
-                    &node_id,
-                    MAIN_BASE_PARTITION,
-                    &substate_key,
-                    LockFlags::MUTABLE,
-                )
-                .unwrap();
-            let (substate_value, _store_access) = track.read_substate(handle);
-            let mut substate: LiquidFungibleResource = substate_value.as_typed().unwrap();
-            substate.put(LiquidFungibleResource::new(amount));
-            track.update_substate(handle, IndexedScryptoValue::from_typed(&substate));
-            track.close_substate(handle);
-        }
+        // Royalties are only settled once the fees they're paid from are collected, as their
+        // recipient vaults may have been created by the transaction itself
+        let royalty_cost = fee_reserve.royalty_cost();
 
         // Take fee payments
         let fee_summary = fee_reserve.finalize();
         let mut fee_payments: IndexMap<NodeId, Decimal> = index_map_new();
-        let mut required = fee_summary.total_execution_cost_xrd
+        let required = fee_summary.total_execution_cost_xrd
             + fee_summary.total_tipping_cost_xrd
             + fee_summary.total_state_expansion_cost_xrd
-            + fee_summary.total_royalty_cost_xrd;
+            + fee_summary.total_royalty_cost_xrd
+            + fee_summary.total_instantiation_deposit_xrd;
+        let (charges, required) =
+            charge_locked_fees(&fee_summary.locked_fees, required, is_success);
         let mut collected_fees = LiquidFungibleResource::new(Decimal::ZERO);
-        for (vault_id, mut locked, contingent) in fee_summary.locked_fees.iter().cloned().rev() {
-            let amount = if contingent {
-                if is_success {
-                    Decimal::min(locked.amount(), required)
-                } else {
-                    Decimal::zero()
-                }
-            } else {
-                Decimal::min(locked.amount(), required)
-            };
-
+        for ((vault_id, mut locked, _), amount) in
+            fee_summary.locked_fees.iter().cloned().zip(charges).rev()
+        {
             // Take fees
             collected_fees.put(locked.take_by_amount(amount).unwrap());
-            required -= amount;
 
             // Refund overpayment
             let (handle, _store_access) = track
@@ -663,6 +926,40 @@ where
             *fee_payments.entry(vault_id).or_default() += amount;
         }
 
+        // Take rent payments
+        for (_, (rent_vault_id, amount)) in &fee_summary.rent_payments {
+            let (handle, _store_access) = track
+                .acquire_lock(
+                    rent_vault_id,
+                    MAIN_BASE_PARTITION,
+                    &FungibleVaultField::LiquidFungible.into(),
+                    LockFlags::MUTABLE,
+                )
+                .unwrap();
+            let (substate_value, _store_access) = track.read_substate(handle);
+            let mut substate: LiquidFungibleResource = substate_value.as_typed().unwrap();
+            collected_fees.put(substate.take_by_amount(*amount).unwrap());
+            track.update_substate(handle, IndexedScryptoValue::from_typed(&substate));
+            track.close_substate(handle);
+        }
+
+        // Distribute royalty
+        for (_, (recipient_vault_id, amount)) in royalty_cost {
+            let (handle, _store_access) = track
+                .acquire_lock(
+                    &recipient_vault_id,
+                    MAIN_BASE_PARTITION,
+                    &FungibleVaultField::LiquidFungible.into(),
+                    LockFlags::MUTABLE,
+                )
+                .unwrap();
+            let (substate_value, _store_access) = track.read_substate(handle);
+            let mut substate: LiquidFungibleResource = substate_value.as_typed().unwrap();
+            substate.put(collected_fees.take_by_amount(amount).unwrap());
+            track.update_substate(handle, IndexedScryptoValue::from_typed(&substate));
+            track.close_substate(handle);
+        }
+
         let tips_to_distribute = fee_summary.tips_to_distribute();
         let fees_to_distribute = fee_summary.fees_to_distribute();
 
@@ -671,9 +968,49 @@ where
         assert_eq!(fee_summary.total_bad_debt_xrd, Decimal::ZERO);
         assert_eq!(
             tips_to_distribute + fees_to_distribute,
-            collected_fees.amount() - fee_summary.total_royalty_cost_xrd /* royalty already distributed */
+            collected_fees.amount() - fee_summary.total_instantiation_deposit_xrd
         );
 
+        // Hold instantiation deposits
+        if !fee_summary.instantiation_deposits.is_empty() {
+            let handle = track
+                .acquire_lock(
+                    CONSENSUS_MANAGER.as_node_id(),
+                    MAIN_BASE_PARTITION,
+                    &ConsensusManagerField::InstantiationDeposits.into(),
+                    LockFlags::MUTABLE,
+                )
+                .unwrap()
+                .0;
+            let mut substate: InstantiationDepositsSubstate =
+                track.read_substate(handle).0.as_typed().unwrap();
+            for (global_address, amount) in &fee_summary.instantiation_deposits {
+                *substate.deposits.entry(*global_address).or_default() += *amount;
+            }
+            let deposit_vault_id = substate.deposit_vault.0 .0;
+            track.update_substate(handle, IndexedScryptoValue::from_typed(&substate));
+            track.close_substate(handle);
+
+            let handle = track
+                .acquire_lock(
+                    &deposit_vault_id,
+                    MAIN_BASE_PARTITION,
+                    &FungibleVaultField::LiquidFungible.into(),
+                    LockFlags::MUTABLE,
+                )
+                .unwrap()
+                .0;
+            let mut vault_balance: LiquidFungibleResource =
+                track.read_substate(handle).0.as_typed().unwrap();
+            vault_balance.put(
+                collected_fees
+                    .take_by_amount(fee_summary.total_instantiation_deposit_xrd)
+                    .unwrap(),
+            );
+            track.update_substate(handle, IndexedScryptoValue::from_typed(&vault_balance));
+            track.close_substate(handle);
+        }
+
         if !tips_to_distribute.is_zero() || !fees_to_distribute.is_zero() {
             // Fetch current leader
             // TODO: maybe we should move current leader into validator rewards?
@@ -831,6 +1168,28 @@ where
         track.close_substate(handle);
     }
 
+    fn record_idempotency_key(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        idempotency_key: Hash,
+        expiry_epoch: Epoch,
+        current_epoch: Epoch,
+    ) {
+        let handle = track
+            .acquire_lock(
+                TRANSACTION_TRACKER.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &TransactionTrackerField::IdempotencyKeys.into(),
+                LockFlags::MUTABLE,
+            )
+            .unwrap()
+            .0;
+        let mut substate: IdempotencyKeysSubstate =
+            track.read_substate(handle).0.as_typed().unwrap();
+        substate.insert(idempotency_key, expiry_epoch, current_epoch);
+        track.update_substate(handle, IndexedScryptoValue::from_typed(&substate));
+        track.close_substate(handle);
+    }
+
     #[cfg(not(feature = "alloc"))]
     fn print_executable(executable: &Executable) {
         println!("{:-^100}", "Executable");
@@ -856,6 +1215,16 @@ where
                     println!("{:<75}: {:>15}", k, v.to_string());
                 }
 
+                println!("{:-^100}", "Instruction Costs");
+                for (instruction_index, breakdown) in &commit.fee_summary.instruction_cost_breakdown
+                {
+                    println!(
+                        "{:<30}: {:>15}",
+                        format!("Instruction {}", instruction_index),
+                        breakdown.total_cost().to_string()
+                    );
+                }
+
                 println!("{:-^100}", "Cost Totals");
                 println!(
                     "{:<30}: {:>15}",
@@ -948,13 +1317,47 @@ pub fn execute_transaction<S: SubstateDatabase, W: WasmEngine>(
     execution_config: &ExecutionConfig,
     transaction: &Executable,
 ) -> TransactionReceipt {
-    TransactionExecutor::new(substate_db, scrypto_interpreter).execute(
+    execute_transaction_with_metrics(
+        substate_db,
+        scrypto_interpreter,
+        fee_reserve_config,
+        execution_config,
+        transaction,
+        &mut NoOpMetricsSink,
+    )
+}
+
+/// Executes a transaction, reporting its metrics to the given sink.
+pub fn execute_transaction_with_metrics<S: SubstateDatabase, W: WasmEngine>(
+    substate_db: &S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+    metrics_sink: &mut dyn MetricsSink,
+) -> TransactionReceipt {
+    TransactionExecutor::new(substate_db, scrypto_interpreter, metrics_sink).execute(
         transaction,
         fee_reserve_config,
         execution_config,
     )
 }
 
+/// Executes a transaction, with the code of the packages stubbed by `package_stubs` replaced by
+/// their stubs.
+pub fn execute_transaction_with_package_stubs<S: SubstateDatabase, W: WasmEngine>(
+    substate_db: &S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    package_stubs: &dyn PackageStubs,
+    fee_reserve_config: &FeeReserveConfig,
+    execution_config: &ExecutionConfig,
+    transaction: &Executable,
+) -> TransactionReceipt {
+    TransactionExecutor::new(substate_db, scrypto_interpreter, &mut NoOpMetricsSink)
+        .with_package_stubs(package_stubs)
+        .execute(transaction, fee_reserve_config, execution_config)
+}
+
 enum TransactionResultType {
     Commit(Result<Vec<InstructionOutput>, RuntimeError>),
     Reject(RejectionError),
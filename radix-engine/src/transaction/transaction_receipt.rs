@@ -1,17 +1,20 @@
 use super::{BalanceChange, StateUpdateSummary};
 use crate::blueprints::consensus_manager::EpochChangeEvent;
 use crate::errors::*;
-use crate::system::system_modules::costing::FeeSummary;
+use crate::system::id_allocation::GlobalAddressReservationState;
+use crate::system::system_modules::costing::{FeeReconciliation, FeeSummary};
+use crate::system::system_modules::execution_journal::ExecutionJournal;
 use crate::system::system_modules::execution_trace::{
-    ExecutionTrace, ResourceChange, WorktopChange,
+    ExecutionTrace, ResourceChange, ResourceRecall, WorktopChange,
 };
+use crate::system::system_modules::transaction_runtime::EmissionOrigin;
 use crate::track::StateUpdates;
 use crate::types::*;
 use colored::*;
 use radix_engine_interface::address::AddressDisplayContext;
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
-use radix_engine_interface::data::scrypto::ScryptoDecode;
+use radix_engine_interface::data::scrypto::{ScryptoDecode, ScryptoDescribe};
 use radix_engine_interface::types::*;
 use sbor::representations::*;
 use utils::ContextualDisplay;
@@ -28,6 +31,8 @@ pub struct TransactionExecutionTrace {
     pub execution_traces: Vec<ExecutionTrace>,
     pub resource_changes: IndexMap<usize, Vec<ResourceChange>>,
     pub fee_locks: FeeLocks,
+    /// Resources recalled from vaults, by instruction index
+    pub recalls: IndexMap<usize, Vec<ResourceRecall>>,
 }
 
 impl TransactionExecutionTrace {
@@ -38,6 +43,17 @@ impl TransactionExecutionTrace {
         }
         aggregator
     }
+
+    /// Renders the execution traces in the Chrome trace event format, which can be loaded into
+    /// `chrome://tracing` or Perfetto. Each traced kernel call becomes a complete event, with
+    /// its timestamp and duration measured in execution cost units rather than microseconds.
+    pub fn to_chrome_trace_json(&self) -> String {
+        let mut events = Vec::new();
+        for trace in &self.execution_traces {
+            trace.chrome_trace_events(&mut events)
+        }
+        format!("{{\"traceEvents\":[{}]}}", events.join(","))
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, Default)]
@@ -71,9 +87,30 @@ pub struct CommitResult {
     pub fee_summary: FeeSummary,
     pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
     pub application_logs: Vec<(Level, String)>,
+    /// Where each of `application_events` was emitted from, index-aligned with the events.
+    pub application_event_origins: Vec<EmissionOrigin>,
+    /// Where each of `application_logs` was emitted from, index-aligned with the logs.
+    pub application_log_origins: Vec<EmissionOrigin>,
+    /// The hash of the schema each of `application_events` was validated against, index-aligned
+    /// with the events, so that it can be decoded even after its package has been upgraded.
+    pub application_event_schema_hashes: Vec<Hash>,
+    /// The hash of the schema each field and key-value entry set by the transaction was last
+    /// validated against.
+    pub state_write_schema_hashes: IndexMap<(NodeId, PartitionNumber, SubstateKey), Hash>,
     /// Optional, only when `EnabledModule::ExecutionTrace` is ON.
     /// Mainly for transaction preview.
     pub execution_trace: TransactionExecutionTrace,
+    pub execution_metrics: ExecutionMetrics,
+}
+
+#[derive(Debug, Clone, ScryptoSbor, Default)]
+pub struct ExecutionMetrics {
+    /// The peak encoded size of heap nodes owned by a single call frame, per blueprint.
+    /// Optional, only when heap size tracking is enabled in the `ExecutionConfig`.
+    pub heap_size_peaks: IndexMap<BlueprintId, usize>,
+    /// The final state of each global address reserved by the transaction, for debugging.
+    /// Optional, only when the transaction runtime module is enabled.
+    pub global_address_reservations: IndexMap<GlobalAddress, GlobalAddressReservationState>,
 }
 
 impl CommitResult {
@@ -85,10 +122,20 @@ impl CommitResult {
             fee_summary: Default::default(),
             application_events: Default::default(),
             application_logs: Default::default(),
+            application_event_origins: Default::default(),
+            application_log_origins: Default::default(),
+            application_event_schema_hashes: Default::default(),
+            state_write_schema_hashes: Default::default(),
             execution_trace: Default::default(),
+            execution_metrics: Default::default(),
         }
     }
 
+    /// The fees of this transaction as a balanced statement of locked fees, costs and refunds.
+    pub fn fee_reconciliation(&self) -> FeeReconciliation {
+        self.fee_summary.reconcile()
+    }
+
     pub fn next_epoch(&self) -> Option<EpochChangeEvent> {
         // Note: Node should use a well-known index id
         for (ref event_type_id, ref event_data) in self.application_events.iter() {
@@ -150,6 +197,32 @@ impl CommitResult {
             TransactionOutcome::Failure(_) => panic!("Transaction failed"),
         }
     }
+
+    /// Like [`Self::output`], but panics with the expected type and the actual value
+    /// if the output of the `nth` instruction doesn't decode as `T`.
+    pub fn expect_output<T: ScryptoDecode>(&self, nth: usize) -> T {
+        match &self.outcome {
+            TransactionOutcome::Success(o) => match o.get(nth) {
+                Some(InstructionOutput::CallReturn(value)) => match scrypto_decode::<T>(value) {
+                    Ok(output) => output,
+                    Err(err) => panic!(
+                        "Expected output [{}] to be {} but was {:?}: {:?}",
+                        nth,
+                        core::any::type_name::<T>(),
+                        scrypto_decode::<ScryptoValue>(value),
+                        err
+                    ),
+                },
+                Some(InstructionOutput::None) => panic!(
+                    "Expected output [{}] to be {} but was none",
+                    nth,
+                    core::any::type_name::<T>()
+                ),
+                None => panic!("No output for [{}]", nth),
+            },
+            TransactionOutcome::Failure(err) => panic!("Transaction failed: {:?}", err),
+        }
+    }
 }
 
 /// Captures whether a transaction's commit outcome is Success or Failure
@@ -210,6 +283,8 @@ pub struct TransactionReceipt {
     pub transaction_result: TransactionResult,
     /// Optional, only when compile-time feature flag `resources_usage` is ON.
     pub resources_usage: ResourcesUsage,
+    /// Optional, only when the execution journal is enabled in the `ExecutionConfig`.
+    pub execution_journal: Option<ExecutionJournal>,
 }
 
 impl TransactionReceipt {
@@ -218,6 +293,7 @@ impl TransactionReceipt {
         Self {
             transaction_result: TransactionResult::Commit(commit_result),
             resources_usage: Default::default(),
+            execution_journal: None,
         }
     }
 
@@ -271,10 +347,25 @@ impl TransactionReceipt {
         self.expect_commit(true)
     }
 
+    /// Returns the output of the instruction at the given index of a successfully committed
+    /// transaction, decoded as `T`.
+    pub fn output_of<T: ScryptoDecode>(&self, instruction_index: usize) -> T {
+        self.expect_commit_success()
+            .expect_output(instruction_index)
+    }
+
     pub fn expect_commit_failure(&self) -> &CommitResult {
         self.expect_commit(false)
     }
 
+    pub fn expect_commit_ignore_outcome(&self) -> &CommitResult {
+        match &self.transaction_result {
+            TransactionResult::Commit(c) => c,
+            TransactionResult::Reject(_) => panic!("Transaction was rejected"),
+            TransactionResult::Abort(_) => panic!("Transaction was aborted"),
+        }
+    }
+
     pub fn expect_rejection(&self) -> &RejectionError {
         match &self.transaction_result {
             TransactionResult::Commit(..) => panic!("Expected rejection but was commit"),
@@ -344,6 +435,28 @@ impl TransactionReceipt {
         }
     }
 
+    /// Asserts that the transaction failed with a typed blueprint error of type `E` which
+    /// matches `f`, and returns the error along with the function which failed.
+    pub fn expect_blueprint_error<E, F>(&self, f: F) -> (&FnIdentifier, E)
+    where
+        E: ScryptoDecode + ScryptoDescribe,
+        F: Fn(&E) -> bool,
+    {
+        if let RuntimeError::ApplicationError(ApplicationError::BlueprintError(blueprint_error)) =
+            self.expect_failure()
+        {
+            if let Some(error) = blueprint_error.error_as::<E>() {
+                if f(&error) {
+                    return (&blueprint_error.fn_identifier, error);
+                }
+            }
+        }
+        panic!(
+            "Expected specific blueprint error but was different error:\n{:?}",
+            self
+        );
+    }
+
     pub fn expect_auth_failure(&self) {
         self.expect_specific_failure(|e| {
             matches!(
@@ -361,6 +474,58 @@ impl TransactionReceipt {
             )
         })
     }
+
+    /// Asserts that the transaction was committed with a total cost, in XRD, within the given
+    /// inclusive bounds.
+    ///
+    /// Prefer loose bounds: the assertion is meant to catch order-of-magnitude regressions,
+    /// not to pin the exact cost of a transaction.
+    pub fn expect_fee_between(&self, min: Decimal, max: Decimal) -> &FeeSummary {
+        let fee_summary = &self.expect_commit_ignore_outcome().fee_summary;
+        let total_cost = fee_summary.total_cost();
+        if total_cost < min || total_cost > max {
+            panic!(
+                "Expected total cost between {} and {} XRD but was {}:\n{:?}",
+                min, max, total_cost, fee_summary
+            );
+        }
+        fee_summary
+    }
+
+    /// Asserts that the transaction was committed having consumed a number of execution cost
+    /// units within the given inclusive bounds.
+    pub fn expect_execution_cost_units_between(&self, min: u32, max: u32) -> &FeeSummary {
+        let fee_summary = &self.expect_commit_ignore_outcome().fee_summary;
+        let cost_units = fee_summary.execution_cost_sum;
+        if cost_units < min || cost_units > max {
+            panic!(
+                "Expected execution cost units between {} and {} but was {}:\n{:?}",
+                min, max, cost_units, fee_summary.execution_cost_breakdown
+            );
+        }
+        fee_summary
+    }
+
+    /// Asserts a property of the execution cost breakdown, keyed by cost entry as in
+    /// [`FeeSummary::execution_cost_breakdown`].
+    ///
+    /// The breakdown is only recorded when `enable_cost_breakdown` is set in the execution config.
+    pub fn expect_execution_cost_breakdown<F>(&self, f: F) -> &FeeSummary
+    where
+        F: Fn(&BTreeMap<String, u32>) -> bool,
+    {
+        let fee_summary = &self.expect_commit_ignore_outcome().fee_summary;
+        if fee_summary.execution_cost_breakdown.is_empty() {
+            panic!("Execution cost breakdown was not recorded");
+        }
+        if !f(&fee_summary.execution_cost_breakdown) {
+            panic!(
+                "Unexpected execution cost breakdown:\n{:?}",
+                fee_summary.execution_cost_breakdown
+            );
+        }
+        fee_summary
+    }
 }
 
 macro_rules! prefix {
@@ -388,6 +553,8 @@ pub struct TransactionReceiptDisplayContext<'a> {
     pub encoder: Option<&'a AddressBech32Encoder>,
     pub schema_lookup_callback:
         Option<Box<dyn Fn(&EventTypeIdentifier) -> Option<(LocalTypeIndex, ScryptoSchema)> + 'a>>,
+    /// Used to render fungible amounts with the number of decimal places of their resource.
+    pub divisibility_lookup_callback: Option<Box<dyn Fn(&ResourceAddress) -> Option<u8> + 'a>>,
 }
 
 impl<'a> TransactionReceiptDisplayContext<'a> {
@@ -413,6 +580,38 @@ impl<'a> TransactionReceiptDisplayContext<'a> {
             None => None,
         }
     }
+
+    pub fn lookup_divisibility(&self, resource_address: &ResourceAddress) -> Option<u8> {
+        match self.divisibility_lookup_callback {
+            Some(ref callback) => {
+                let callback = callback.as_ref();
+                callback(resource_address)
+            }
+            None => None,
+        }
+    }
+
+    pub fn format_balance_change(
+        &self,
+        resource_address: &ResourceAddress,
+        balance_change: &BalanceChange,
+    ) -> String {
+        match balance_change {
+            BalanceChange::Fungible(amount) => match self.lookup_divisibility(resource_address) {
+                Some(divisibility) => amount.to_string_with_decimal_places(divisibility),
+                None => amount.to_string(),
+            },
+            BalanceChange::NonFungible { added, removed } => {
+                let format_ids = |ids: &BTreeSet<NonFungibleLocalId>| {
+                    ids.iter()
+                        .map(|id| id.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                format!("+{{{}}}, -{{{}}}", format_ids(added), format_ids(removed))
+            }
+        }
+    }
 }
 
 impl<'a> From<&'a AddressBech32Encoder> for TransactionReceiptDisplayContext<'a> {
@@ -420,6 +619,7 @@ impl<'a> From<&'a AddressBech32Encoder> for TransactionReceiptDisplayContext<'a>
         Self {
             encoder: Some(encoder),
             schema_lookup_callback: None,
+            divisibility_lookup_callback: None,
         }
     }
 }
@@ -429,6 +629,7 @@ impl<'a> From<Option<&'a AddressBech32Encoder>> for TransactionReceiptDisplayCon
         Self {
             encoder,
             schema_lookup_callback: None,
+            divisibility_lookup_callback: None,
         }
     }
 }
@@ -440,6 +641,7 @@ impl<'a> TransactionReceiptDisplayContextBuilder<'a> {
         Self(TransactionReceiptDisplayContext {
             encoder: None,
             schema_lookup_callback: None,
+            divisibility_lookup_callback: None,
         })
     }
 
@@ -456,6 +658,14 @@ impl<'a> TransactionReceiptDisplayContextBuilder<'a> {
         self
     }
 
+    pub fn divisibility_lookup_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ResourceAddress) -> Option<u8> + 'a,
+    {
+        self.0.divisibility_lookup_callback = Some(Box::new(callback));
+        self
+    }
+
     pub fn build(self) -> TransactionReceiptDisplayContext<'a> {
         self.0
     }
@@ -480,9 +690,11 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
             match result {
                 TransactionResult::Commit(c) => match &c.outcome {
                     TransactionOutcome::Success(_) => "COMMITTED SUCCESS".green(),
-                    TransactionOutcome::Failure(e) => format!("COMMITTED FAILURE: {}", e).red(),
+                    TransactionOutcome::Failure(e) =>
+                        format!("COMMITTED FAILURE: {}", e.display(address_display_context)).red(),
                 },
-                TransactionResult::Reject(r) => format!("REJECTED: {}", r.error).red(),
+                TransactionResult::Reject(r) =>
+                    format!("REJECTED: {}", r.error.display(address_display_context)).red(),
                 TransactionResult::Abort(a) => format!("ABORTED: {}", a.reason).bright_red(),
             },
         )?;
@@ -498,6 +710,24 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 c.fee_summary.total_royalty_cost_xrd,
             )?;
 
+            if !c.fee_summary.total_instantiation_deposit_xrd.is_zero() {
+                write!(
+                    f,
+                    "\n{} {} XRD",
+                    "Instantiation Deposits:".bold().green(),
+                    c.fee_summary.total_instantiation_deposit_xrd,
+                )?;
+            }
+
+            if !c.fee_summary.total_rent_xrd.is_zero() {
+                write!(
+                    f,
+                    "\n{} {} XRD",
+                    "Component Rent:".bold().green(),
+                    c.fee_summary.total_rent_xrd,
+                )?;
+            }
+
             write!(
                 f,
                 "\n{} {} limit, {} consumed, {} XRD per cost unit, {}% tip",
@@ -598,12 +828,7 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                     prefix!(i, balance_changes),
                     address.display(address_display_context),
                     resource.display(address_display_context),
-                    match delta {
-                        BalanceChange::Fungible(d) => format!("{}", d),
-                        BalanceChange::NonFungible { added, removed } => {
-                            format!("+{:?}, -{:?}", added, removed)
-                        }
-                    }
+                    context.format_balance_change(resource, delta)
                 )?;
             }
 
@@ -628,12 +853,7 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                     prefix!(i, direct_vault_updates),
                     hex::encode(object_id),
                     resource.display(address_display_context),
-                    match delta {
-                        BalanceChange::Fungible(d) => format!("{}", d),
-                        BalanceChange::NonFungible { added, removed } => {
-                            format!("+{:?}, -{:?}", added, removed)
-                        }
-                    }
+                    context.format_balance_change(resource, delta)
                 )?;
             }
 
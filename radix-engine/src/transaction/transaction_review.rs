@@ -0,0 +1,472 @@
+use crate::transaction::*;
+use crate::types::*;
+use crate::vm::wasm::WasmEngine;
+use crate::vm::ScryptoVm;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::interface::*;
+use transaction::manifest::{
+    ResourceFlowAmount, ResourceFlowEdge, ResourceFlowGraph, ResourceFlowNode,
+};
+use transaction::model::*;
+
+pub const DEFAULT_FEE_CEILING_MARGIN_PERCENTAGE: u16 = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionReviewConfig {
+    /// The margin added on top of the previewed fee to obtain the fee ceiling, as a percentage.
+    pub fee_ceiling_margin_percentage: u16,
+    pub with_kernel_trace: bool,
+}
+
+impl Default for TransactionReviewConfig {
+    fn default() -> Self {
+        Self {
+            fee_ceiling_margin_percentage: DEFAULT_FEE_CEILING_MARGIN_PERCENTAGE,
+            with_kernel_trace: false,
+        }
+    }
+}
+
+/// A finding of the static review of a manifest, which a wallet may want to surface to its user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewLint {
+    /// No instruction locks a fee, so the transaction can only succeed if fees are locked by a
+    /// blueprint called by the manifest.
+    NoFeeLock,
+    /// Resource of a statically unknown amount is deposited into an account, without a worktop
+    /// assertion guaranteeing any of it.
+    UnguardedDeposit {
+        instruction_index: usize,
+        account: GlobalAddress,
+        resource_address: Option<ResourceAddress>,
+    },
+    ResourceBurned {
+        instruction_index: usize,
+        resource_address: Option<ResourceAddress>,
+    },
+}
+
+/// What can be told about a manifest without executing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestSummary {
+    pub instruction_count: usize,
+    pub accounts_withdrawn_from: IndexSet<GlobalAddress>,
+    pub accounts_deposited_into: IndexSet<GlobalAddress>,
+    /// The global entities called by the manifest, excluding addresses allocated by the manifest.
+    pub entities_called: IndexSet<GlobalAddress>,
+    pub resource_flows: ResourceFlowGraph,
+    pub lints: Vec<ReviewLint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeEstimate {
+    pub execution_cost_units: u32,
+    /// The total fee of the preview, including tips and royalties.
+    pub estimated_fee_xrd: Decimal,
+    /// The estimated fee plus the configured margin, which is a sensible amount to lock.
+    pub fee_ceiling_xrd: Decimal,
+}
+
+/// The result of reviewing a transaction before it's signed.
+#[derive(Debug, Clone)]
+pub struct TransactionReview {
+    pub summary: ManifestSummary,
+    pub preview_receipt: TransactionReceipt,
+    /// The balance changes of accounts which hold regardless of the execution, derived from the
+    /// withdrawals of known amounts and from worktop assertions. Fee payments are not included.
+    pub guaranteed_balance_changes:
+        IndexMap<GlobalAddress, IndexMap<ResourceAddress, BalanceChange>>,
+    /// The balance changes of the preview, including fee payments.
+    pub predicted_balance_changes:
+        IndexMap<GlobalAddress, IndexMap<ResourceAddress, BalanceChange>>,
+    /// `None` if the preview was rejected.
+    pub fee_estimate: Option<FeeEstimate>,
+    /// The entities called by the manifest, or whose state the preview touched or created.
+    pub entities_touched: IndexSet<GlobalAddress>,
+}
+
+/// Reviews a transaction in one go: summarizes and lints its manifest, previews it, and
+/// estimates its balance changes and fee.
+pub fn review_transaction<S: SubstateDatabase, W: WasmEngine>(
+    substate_db: &S,
+    scrypto_interpreter: &ScryptoVm<W>,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV1,
+    config: &TransactionReviewConfig,
+) -> Result<TransactionReview, PreviewError> {
+    let instructions = preview_intent.intent.instructions.0.clone();
+    let (summary, guaranteed_balance_changes) = summarize_manifest(&instructions);
+
+    let preview_receipt = execute_preview(
+        substate_db,
+        scrypto_interpreter,
+        network,
+        preview_intent,
+        config.with_kernel_trace,
+    )?;
+
+    let mut predicted_balance_changes = index_map_new();
+    let mut fee_estimate = None;
+    let mut entities_touched = summary.entities_called.clone();
+    if let TransactionResult::Commit(commit) = &preview_receipt.transaction_result {
+        let state_update_summary = &commit.state_update_summary;
+        predicted_balance_changes = state_update_summary.balance_changes.clone();
+        entities_touched.extend(predicted_balance_changes.keys().cloned());
+        entities_touched.extend(
+            state_update_summary
+                .new_packages
+                .iter()
+                .map(|address| GlobalAddress::from(*address)),
+        );
+        entities_touched.extend(
+            state_update_summary
+                .new_components
+                .iter()
+                .map(|address| GlobalAddress::from(*address)),
+        );
+        entities_touched.extend(
+            state_update_summary
+                .new_resources
+                .iter()
+                .map(|address| GlobalAddress::from(*address)),
+        );
+
+        let estimated_fee_xrd = commit.fee_summary.total_cost();
+        fee_estimate = Some(FeeEstimate {
+            execution_cost_units: commit.fee_summary.execution_cost_sum,
+            estimated_fee_xrd,
+            fee_ceiling_xrd: estimated_fee_xrd
+                + estimated_fee_xrd * Decimal::from(config.fee_ceiling_margin_percentage)
+                    / Decimal::from(100),
+        });
+    }
+
+    Ok(TransactionReview {
+        summary,
+        preview_receipt,
+        guaranteed_balance_changes,
+        predicted_balance_changes,
+        fee_estimate,
+        entities_touched,
+    })
+}
+
+/// The least resource known to be held by the worktop or a bucket.
+#[derive(Debug, Clone)]
+enum KnownAmount {
+    Fungible(Decimal),
+    NonFungibles(BTreeSet<NonFungibleLocalId>),
+}
+
+impl KnownAmount {
+    fn from_flow(amount: &ResourceFlowAmount) -> Option<Self> {
+        match amount {
+            ResourceFlowAmount::Amount(amount) => Some(KnownAmount::Fungible(*amount)),
+            ResourceFlowAmount::NonFungibles(ids) => {
+                Some(KnownAmount::NonFungibles(ids.iter().cloned().collect()))
+            }
+            ResourceFlowAmount::All | ResourceFlowAmount::Unknown => None,
+        }
+    }
+
+    fn add(&mut self, other: KnownAmount) {
+        match (self, other) {
+            (KnownAmount::Fungible(x), KnownAmount::Fungible(y)) => *x = *x + y,
+            (KnownAmount::NonFungibles(x), KnownAmount::NonFungibles(y)) => x.extend(y),
+            (this, other) => *this = other,
+        }
+    }
+
+    /// Raises the known amount to at least the asserted amount.
+    fn assert_at_least(&mut self, other: KnownAmount) {
+        match (self, other) {
+            (KnownAmount::Fungible(x), KnownAmount::Fungible(y)) => {
+                if y > *x {
+                    *x = y;
+                }
+            }
+            (KnownAmount::NonFungibles(x), KnownAmount::NonFungibles(y)) => x.extend(y),
+            (this, other) => *this = other,
+        }
+    }
+
+    fn remove(&mut self, other: &KnownAmount) {
+        match (self, other) {
+            (KnownAmount::Fungible(x), KnownAmount::Fungible(y)) => {
+                *x = if *y > *x { Decimal::ZERO } else { *x - *y };
+            }
+            (KnownAmount::NonFungibles(x), KnownAmount::NonFungibles(y)) => {
+                x.retain(|id| !y.contains(id));
+            }
+            (this, _) => *this = KnownAmount::Fungible(Decimal::ZERO),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            KnownAmount::Fungible(x) => x.is_zero(),
+            KnownAmount::NonFungibles(x) => x.is_empty(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct GuaranteedChanges {
+    changes: IndexMap<GlobalAddress, IndexMap<ResourceAddress, BalanceChange>>,
+}
+
+impl GuaranteedChanges {
+    fn record(
+        &mut self,
+        account: GlobalAddress,
+        resource_address: ResourceAddress,
+        amount: &KnownAmount,
+        is_deposit: bool,
+    ) {
+        let changes = self.changes.entry(account).or_insert_with(index_map_new);
+        match amount {
+            KnownAmount::Fungible(amount) => {
+                let change = changes
+                    .entry(resource_address)
+                    .or_insert(BalanceChange::Fungible(Decimal::ZERO));
+                if let BalanceChange::Fungible(x) = change {
+                    *x = if is_deposit {
+                        *x + *amount
+                    } else {
+                        *x - *amount
+                    };
+                }
+            }
+            KnownAmount::NonFungibles(ids) => {
+                let change =
+                    changes
+                        .entry(resource_address)
+                        .or_insert(BalanceChange::NonFungible {
+                            added: BTreeSet::new(),
+                            removed: BTreeSet::new(),
+                        });
+                if let BalanceChange::NonFungible { added, removed } = change {
+                    for id in ids {
+                        let (to, from) = if is_deposit {
+                            (&mut *added, &mut *removed)
+                        } else {
+                            (&mut *removed, &mut *added)
+                        };
+                        if !from.remove(id) {
+                            to.insert(id.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Summarizes and lints the given instructions, and derives the balance changes of accounts which
+/// are guaranteed by them.
+pub fn summarize_manifest(
+    instructions: &[InstructionV1],
+) -> (
+    ManifestSummary,
+    IndexMap<GlobalAddress, IndexMap<ResourceAddress, BalanceChange>>,
+) {
+    let resource_flows = ResourceFlowGraph::from_instructions(instructions);
+
+    let mut lints = Vec::new();
+    let mut entities_called = index_set_new();
+    let mut assertions: Vec<(usize, ResourceAddress, KnownAmount)> = Vec::new();
+    let mut locks_fee = false;
+    for (instruction_index, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } => {
+                if let DynamicGlobalAddress::Static(address) = address {
+                    entities_called.insert(*address);
+                }
+                if matches!(
+                    method_name.as_str(),
+                    ACCOUNT_LOCK_FEE_IDENT
+                        | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+                        | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+                        | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT
+                ) {
+                    locks_fee = true;
+                }
+            }
+            InstructionV1::CallFunction {
+                package_address: DynamicPackageAddress::Static(address),
+                ..
+            } => {
+                entities_called.insert((*address).into());
+            }
+            InstructionV1::CallRoyaltyMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            }
+            | InstructionV1::CallMetadataMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            }
+            | InstructionV1::CallAccessRulesMethod {
+                address: DynamicGlobalAddress::Static(address),
+                ..
+            } => {
+                entities_called.insert(*address);
+            }
+            InstructionV1::AssertWorktopContains {
+                resource_address,
+                amount,
+            }
+            | InstructionV1::AssertWorktopContainsExactly {
+                resource_address,
+                amount,
+            } => assertions.push((
+                instruction_index,
+                *resource_address,
+                KnownAmount::Fungible(*amount),
+            )),
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids,
+            } => assertions.push((
+                instruction_index,
+                *resource_address,
+                KnownAmount::NonFungibles(ids.iter().cloned().collect()),
+            )),
+            _ => {}
+        }
+    }
+    if !locks_fee {
+        lints.push(ReviewLint::NoFeeLock);
+    }
+
+    // Tracks the least resource held by the worktop and each bucket, through the flows in
+    // instruction order, to tell what any deposit is guaranteed to contain.
+    let mut guaranteed = GuaranteedChanges::default();
+    let mut worktop: IndexMap<ResourceAddress, KnownAmount> = index_map_new();
+    let mut buckets: IndexMap<ManifestBucket, KnownAmount> = index_map_new();
+    let mut worktop_has_unknown = false;
+    let mut asserted_since_unknown = false;
+    let mut accounts_withdrawn_from = index_set_new();
+    let mut accounts_deposited_into = index_set_new();
+    let mut assertions = assertions.into_iter().peekable();
+    for edge in &resource_flows.edges {
+        while let Some((_, resource_address, amount)) =
+            assertions.next_if(|(index, _, _)| *index < edge.instruction_index)
+        {
+            asserted_since_unknown = true;
+            match worktop.get_mut(&resource_address) {
+                Some(known) => known.assert_at_least(amount),
+                None => {
+                    worktop.insert(resource_address, amount);
+                }
+            }
+        }
+
+        let ResourceFlowEdge {
+            from,
+            to,
+            instruction_index,
+            resource_address,
+            amount,
+        } = edge;
+        let known = match (from, resource_address) {
+            (ResourceFlowNode::Worktop, Some(resource_address)) => {
+                let known = match amount {
+                    ResourceFlowAmount::All => worktop.swap_remove(resource_address),
+                    _ => KnownAmount::from_flow(amount),
+                };
+                if let (Some(known), Some(remaining)) = (&known, worktop.get_mut(resource_address))
+                {
+                    remaining.remove(known);
+                }
+                known
+            }
+            (ResourceFlowNode::Worktop, None) => {
+                // The entire worktop
+                let all: Vec<_> = worktop.drain(..).collect();
+                if let ResourceFlowNode::Account(account) = to {
+                    accounts_deposited_into.insert(*account);
+                    if worktop_has_unknown && !asserted_since_unknown {
+                        lints.push(ReviewLint::UnguardedDeposit {
+                            instruction_index: *instruction_index,
+                            account: *account,
+                            resource_address: None,
+                        });
+                    }
+                    for (resource_address, known) in &all {
+                        guaranteed.record(*account, *resource_address, known, true);
+                    }
+                }
+                worktop_has_unknown = false;
+                continue;
+            }
+            (ResourceFlowNode::Bucket(bucket_id), _) => buckets
+                .swap_remove(bucket_id)
+                .or_else(|| KnownAmount::from_flow(amount)),
+            (ResourceFlowNode::Account(account), _) => {
+                accounts_withdrawn_from.insert(*account);
+                let known = KnownAmount::from_flow(amount);
+                if let (Some(resource_address), Some(known)) = (resource_address, &known) {
+                    guaranteed.record(*account, *resource_address, known, false);
+                }
+                known
+            }
+            _ => KnownAmount::from_flow(amount),
+        };
+
+        match to {
+            ResourceFlowNode::Worktop => match (resource_address, known) {
+                (Some(resource_address), Some(known)) => match worktop.get_mut(resource_address) {
+                    Some(existing) => existing.add(known),
+                    None => {
+                        worktop.insert(*resource_address, known);
+                    }
+                },
+                _ => {
+                    worktop_has_unknown = true;
+                    asserted_since_unknown = false;
+                }
+            },
+            ResourceFlowNode::Bucket(bucket_id) => {
+                if let Some(known) = known {
+                    buckets.insert(*bucket_id, known);
+                }
+            }
+            ResourceFlowNode::Account(account) => {
+                accounts_deposited_into.insert(*account);
+                match (resource_address, known) {
+                    (Some(resource_address), Some(known)) if !known.is_zero() => {
+                        guaranteed.record(*account, *resource_address, &known, true)
+                    }
+                    _ => lints.push(ReviewLint::UnguardedDeposit {
+                        instruction_index: *instruction_index,
+                        account: *account,
+                        resource_address: *resource_address,
+                    }),
+                }
+            }
+            ResourceFlowNode::Burned => lints.push(ReviewLint::ResourceBurned {
+                instruction_index: *instruction_index,
+                resource_address: *resource_address,
+            }),
+            ResourceFlowNode::Component(_) | ResourceFlowNode::Vault(_) => {}
+        }
+    }
+
+    (
+        ManifestSummary {
+            instruction_count: instructions.len(),
+            accounts_withdrawn_from,
+            accounts_deposited_into,
+            entities_called,
+            resource_flows,
+            lints,
+        },
+        guaranteed.changes,
+    )
+}
@@ -170,6 +170,22 @@ fn get_arguments_schema<'s>(
         Invocation::Function(package_address @ POOL_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(&POOL_PACKAGE_DEFINITION, package_address, blueprint).map(Some)?
         }
+        Invocation::Function(package_address @ HOOK_REGISTRY_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(
+                &HOOK_REGISTRY_PACKAGE_DEFINITION,
+                package_address,
+                blueprint,
+            )
+            .map(Some)?
+        }
+        Invocation::Function(package_address @ PAYMENT_STREAM_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(
+                &PAYMENT_STREAM_PACKAGE_DEFINITION,
+                package_address,
+                blueprint,
+            )
+            .map(Some)?
+        }
         Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
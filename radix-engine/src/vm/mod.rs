@@ -1,4 +1,5 @@
 mod native_vm;
+mod package_stubs;
 mod scrypto_vm;
 mod vm;
 
@@ -8,5 +9,6 @@ pub mod wasm;
 pub mod wasm_runtime;
 
 pub use native_vm::*;
+pub use package_stubs::*;
 pub use scrypto_vm::*;
 pub use vm::*;
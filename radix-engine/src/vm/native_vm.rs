@@ -1,8 +1,10 @@
 use crate::blueprints::access_controller::AccessControllerNativePackage;
 use crate::blueprints::account::AccountNativePackage;
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
+use crate::blueprints::hook_registry::HookRegistryNativePackage;
 use crate::blueprints::identity::IdentityNativePackage;
 use crate::blueprints::package::PackageNativePackage;
+use crate::blueprints::payment_stream::PaymentStreamNativePackage;
 use crate::blueprints::pool::PoolNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
@@ -14,10 +16,12 @@ use crate::system::node_modules::metadata::MetadataNativePackage;
 use crate::system::node_modules::royalty::RoyaltyNativePackage;
 use crate::system::system_callback::SystemLockData;
 use crate::types::*;
+use crate::vm::wasm::WasmInstrumentationCache;
 use crate::vm::VmInvoke;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::*;
 use resources_tracker_macro::trace_resources;
+use sbor::rust::sync::Arc;
 
 pub struct NativeVm;
 
@@ -25,6 +29,7 @@ impl NativeVm {
     pub fn create_instance(
         package_address: &PackageAddress,
         code: &[u8],
+        wasm_instrumentation_cache: Option<Arc<dyn WasmInstrumentationCache>>,
     ) -> Result<NativeVmInstance, RuntimeError> {
         let code: [u8; 8] = match code.clone().try_into() {
             Ok(code) => code,
@@ -40,6 +45,7 @@ impl NativeVm {
         let instance = NativeVmInstance {
             package_address: *package_address,
             native_package_code_id,
+            wasm_instrumentation_cache,
         };
 
         Ok(instance)
@@ -51,6 +57,8 @@ pub struct NativeVmInstance {
     #[allow(dead_code)]
     package_address: PackageAddress,
     native_package_code_id: u64,
+    /// Used by the package blueprint when publishing WASM packages
+    wasm_instrumentation_cache: Option<Arc<dyn WasmInstrumentationCache>>,
 }
 
 impl VmInvoke for NativeVmInstance {
@@ -71,7 +79,12 @@ impl VmInvoke for NativeVmInstance {
         })?;
 
         match self.native_package_code_id {
-            PACKAGE_CODE_ID => PackageNativePackage::invoke_export(export_name, input, api),
+            PACKAGE_CODE_ID => PackageNativePackage::invoke_export(
+                export_name,
+                input,
+                self.wasm_instrumentation_cache.as_deref(),
+                api,
+            ),
             RESOURCE_CODE_ID => ResourceNativePackage::invoke_export(export_name, input, api),
             CONSENSUS_MANAGER_CODE_ID => {
                 ConsensusManagerNativePackage::invoke_export(export_name, input, api)
@@ -93,6 +106,12 @@ impl VmInvoke for NativeVmInstance {
             TRANSACTION_TRACKER_CODE_ID => {
                 TransactionTrackerNativePackage::invoke_export(export_name, input, api)
             }
+            HOOK_REGISTRY_CODE_ID => {
+                HookRegistryNativePackage::invoke_export(export_name, input, api)
+            }
+            PAYMENT_STREAM_CODE_ID => {
+                PaymentStreamNativePackage::invoke_export(export_name, input, api)
+            }
             _ => {
                 return Err(RuntimeError::VmError(VmError::Native(
                     NativeRuntimeError::InvalidCodeId,
@@ -0,0 +1,27 @@
+use crate::types::*;
+
+/// A call into a stubbed package, as seen by the stub.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StubbedCall {
+    pub package_address: PackageAddress,
+    pub blueprint_name: String,
+    pub function_name: String,
+    /// The component called, or `None` for a function call
+    pub receiver: Option<NodeId>,
+    pub input: ScryptoValue,
+}
+
+/// Replaces the code of some packages with Rust implementations, for testing.
+///
+/// A stubbed package is still published and resolved as usual, so the input and output of each
+/// call are validated against its schema, and auth and royalties are applied. Only the execution
+/// of its code is replaced.
+///
+/// A stub doesn't have access to the system API, so it can neither read nor write substates, nor
+/// take ownership of the buckets and proofs passed to it.
+pub trait PackageStubs {
+    fn is_stubbed(&self, package_address: &PackageAddress) -> bool;
+
+    /// Returns the output of the call, or a message with which the call panics.
+    fn invoke(&self, call: StubbedCall) -> Result<IndexedScryptoValue, String>;
+}
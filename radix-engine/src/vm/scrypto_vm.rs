@@ -5,10 +5,13 @@ use crate::vm::wasm::*;
 use crate::vm::wasm_runtime::ScryptoRuntime;
 use radix_engine_interface::api::ClientApi;
 use resources_tracker_macro::trace_resources;
+use sbor::rust::sync::Arc;
 
 pub struct ScryptoVm<W: WasmEngine> {
     pub wasm_engine: W,
     pub wasm_validator_config: WasmValidatorConfigV1,
+    /// Optional, reuses the instrumentation of package code published before
+    pub wasm_instrumentation_cache: Option<Arc<dyn WasmInstrumentationCache>>,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
@@ -16,6 +19,7 @@ impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
         Self {
             wasm_engine: W::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            wasm_instrumentation_cache: None,
         }
     }
 }
@@ -32,6 +36,15 @@ impl<W: WasmEngine> ScryptoVm<W> {
             package_address: *package_address,
         }
     }
+
+    /// Loads the modules of a validated artifact into the engine, so that the first calls into
+    /// these packages after a restart don't pay for preparing them.
+    pub fn preload_artifact(&self, artifact: &PrecompiledWasmArtifact) {
+        for module in &artifact.modules {
+            self.wasm_engine
+                .preload(module.code_hash, &module.instrumented_code);
+        }
+    }
 }
 
 pub struct ScryptoVmInstance<I: WasmInstance> {
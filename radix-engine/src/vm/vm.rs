@@ -1,18 +1,53 @@
 use crate::blueprints::package::{PackageError, VmType};
 use crate::errors::{ApplicationError, RuntimeError};
+use crate::kernel::actor::Actor;
 use crate::kernel::kernel_api::{KernelInternalApi, KernelNodeApi, KernelSubstateApi};
 use crate::system::system::KeyValueEntrySubstate;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::types::*;
-use crate::vm::wasm::{WasmEngine, WasmValidator};
-use crate::vm::{NativeVm, ScryptoVm};
+use crate::vm::wasm::{WasmEngine, WasmInstrumentationCache, WasmValidator};
+use crate::vm::{NativeVm, PackageStubs, ScryptoVm, StubbedCall};
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::*;
 
 pub struct Vm<'g, W: WasmEngine> {
     pub scrypto_vm: &'g ScryptoVm<W>,
+    /// Rust implementations which replace the code of some packages, for testing
+    pub package_stubs: Option<&'g dyn PackageStubs>,
+}
+
+impl<'g, W: WasmEngine + 'g> Vm<'g, W> {
+    fn stubbed_call<Y>(
+        address: &PackageAddress,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Option<StubbedCall>
+    where
+        Y: KernelInternalApi<SystemConfig<Self>>,
+    {
+        let (blueprint_id, function_name, receiver) = match api.kernel_get_system_state().current {
+            Actor::Method(actor) => (
+                actor.module_object_info.blueprint_id.clone(),
+                actor.ident.clone(),
+                Some(actor.node_id),
+            ),
+            Actor::Function {
+                blueprint_id,
+                ident,
+            } => (blueprint_id.clone(), ident.clone(), None),
+            Actor::VirtualLazyLoad { .. } | Actor::Root => return None,
+        };
+
+        Some(StubbedCall {
+            package_address: *address,
+            blueprint_name: blueprint_id.blueprint_name,
+            function_name,
+            receiver,
+            input: input.to_scrypto_value(),
+        })
+    }
 }
 
 impl<'g, W: WasmEngine + 'g> SystemCallbackObject for Vm<'g, W> {
@@ -29,6 +64,16 @@ impl<'g, W: WasmEngine + 'g> SystemCallbackObject for Vm<'g, W> {
             + KernelSubstateApi<SystemLockData>,
         W: WasmEngine,
     {
+        if let Some(package_stubs) = api.kernel_get_system().callback_obj.package_stubs {
+            if package_stubs.is_stubbed(address) {
+                if let Some(call) = Self::stubbed_call(address, input, api) {
+                    return package_stubs.invoke(call).map_err(|message| {
+                        RuntimeError::ApplicationError(ApplicationError::Panic(message))
+                    });
+                }
+            }
+        }
+
         let vm_type = {
             let handle = api.kernel_open_substate_with_default(
                 address.as_node_id(),
@@ -76,7 +121,19 @@ impl<'g, W: WasmEngine + 'g> SystemCallbackObject for Vm<'g, W> {
                         .expect(&format!("Original code not found: {:?}", export))
                 };
 
-                let mut vm_instance = { NativeVm::create_instance(address, &original_code.code)? };
+                let wasm_instrumentation_cache = api
+                    .kernel_get_system()
+                    .callback_obj
+                    .scrypto_vm
+                    .wasm_instrumentation_cache
+                    .clone();
+                let mut vm_instance = {
+                    NativeVm::create_instance(
+                        address,
+                        &original_code.code,
+                        wasm_instrumentation_cache,
+                    )?
+                };
                 let output = { vm_instance.invoke(export.export_name.as_str(), input, api)? };
 
                 output
@@ -146,19 +203,27 @@ impl VmPackageValidation {
         definition: &PackageDefinition,
         vm_type: VmType,
         code: &[u8],
+        wasm_instrumentation_cache: Option<&dyn WasmInstrumentationCache>,
     ) -> Result<Option<Vec<u8>>, RuntimeError> {
         match vm_type {
             VmType::Native => Ok(None),
             VmType::ScryptoV1 => {
                 // Validate WASM
-                let instrumented_code = WasmValidator::default()
-                    .validate(&code, definition.blueprints.values())
-                    .map_err(|e| {
-                        RuntimeError::ApplicationError(ApplicationError::PackageError(
-                            PackageError::InvalidWasm(e),
-                        ))
-                    })?
-                    .0;
+                let wasm_validator = WasmValidator::default();
+                let instrumented_code = match wasm_instrumentation_cache {
+                    Some(cache) => wasm_validator.validate_with_cache(
+                        &code,
+                        definition.blueprints.values(),
+                        cache,
+                    ),
+                    None => wasm_validator.validate(&code, definition.blueprints.values()),
+                }
+                .map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::PackageError(
+                        PackageError::InvalidWasm(e),
+                    ))
+                })?
+                .0;
 
                 for BlueprintDefinitionInit {
                     blueprint_type,
@@ -19,9 +19,12 @@ pub const ALLOCATE_GLOBAL_ADDRESS_FUNCTION_NAME: &str = "allocate_global_address
 pub const NEW_OBJECT_FUNCTION_NAME: &str = "new_object";
 pub const GLOBALIZE_FUNCTION_NAME: &str = "globalize";
 pub const CALL_METHOD_FUNCTION_NAME: &str = "call_method";
+pub const CALL_STATIC_METHOD_FUNCTION_NAME: &str = "call_static_method";
 pub const CALL_FUNCTION_FUNCTION_NAME: &str = "call_function";
 pub const GET_OBJECT_INFO_FUNCTION_NAME: &str = "get_object_info";
 pub const DROP_OBJECT_FUNCTION_NAME: &str = "drop_object";
+pub const VAULT_TRANSFER_FUNCTION_NAME: &str = "vault_transfer";
+pub const VAULT_TRANSFER_NON_FUNGIBLES_FUNCTION_NAME: &str = "vault_transfer_non_fungibles";
 
 //=================
 // Key Value Store
@@ -50,6 +53,8 @@ pub const FIELD_LOCK_RELEASE_FUNCTION_NAME: &str = "field_lock_release";
 //=================
 pub const ACTOR_OPEN_FIELD_FUNCTION_NAME: &str = "actor_open_field";
 pub const ACTOR_CALL_MODULE_METHOD_FUNCTION_NAME: &str = "actor_call_module_method";
+pub const ACTOR_OPEN_TRANSIENT_ENTRY_FUNCTION_NAME: &str = "actor_open_transient_entry";
+pub const ACTOR_REMOVE_TRANSIENT_ENTRY_FUNCTION_NAME: &str = "actor_remove_transient_entry";
 pub const GET_GLOBAL_ADDRESS_FUNCTION_NAME: &str = "get_global_address";
 pub const GET_BLUEPRINT_FUNCTION_NAME: &str = "get_blueprint";
 pub const GET_AUTH_ZONE_FUNCTION_NAME: &str = "get_auth_zone";
@@ -63,7 +68,9 @@ pub const EMIT_EVENT_FUNCTION_NAME: &str = "emit_event";
 pub const EMIT_LOG_FUNCTION_NAME: &str = "emit_log";
 pub const GET_TRANSACTION_HASH_FUNCTION_NAME: &str = "get_transaction_hash";
 pub const GENERATE_RUID_FUNCTION_NAME: &str = "generate_ruid";
+pub const BIG_INT_OP_FUNCTION_NAME: &str = "big_int_op";
 pub const PANIC_FUNCTION_NAME: &str = "panic";
+pub const FAIL_FUNCTION_NAME: &str = "fail";
 
 pub const MODULE_ENV_NAME: &str = "env";
 pub const EXPORT_MEMORY: &str = "memory";
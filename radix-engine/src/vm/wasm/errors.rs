@@ -136,6 +136,16 @@ pub enum WasmRuntimeError {
     /// Invalid log level
     InvalidLogLevel(DecodeError),
 
+    /// Invalid decimal amount
+    InvalidAmount(DecodeError),
+
+    /// Invalid non-fungible local ids
+    InvalidNonFungibleLocalIds(DecodeError),
+
+    InvalidBigIntType(u32),
+
+    InvalidBigIntOperation(u32),
+
     /// Costing error (no-op runtime only!)
     FeeReserveError(FeeReserveError),
 }
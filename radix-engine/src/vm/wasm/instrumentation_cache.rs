@@ -0,0 +1,114 @@
+use crate::types::*;
+use crate::vm::wasm::*;
+use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
+
+/// A content-addressed store of validated and instrumented package code, which lets engine
+/// instances skip instrumenting code that has been published before, e.g. in an earlier process.
+///
+/// Entries are keyed by [`WasmValidator::instrumentation_cache_key`], so an entry is never reused
+/// after the code, its blueprints, the validation limits or the metering rules change. Cached code
+/// is trusted as is, so the backing store must not be writable by anyone else.
+pub trait WasmInstrumentationCache: Send + Sync {
+    fn get(&self, key: &Hash) -> Option<Vec<u8>>;
+
+    fn put(&self, key: &Hash, value: &[u8]);
+}
+
+/// A [`WasmInstrumentationCache`] backed by a directory, with one file per entry.
+///
+/// Failing to read or write an entry is treated as a cache miss, so that a missing or read-only
+/// directory never fails a transaction.
+#[cfg(not(feature = "alloc"))]
+#[derive(Debug, Clone)]
+pub struct DirectoryWasmInstrumentationCache {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl DirectoryWasmInstrumentationCache {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn entry_path(&self, key: &Hash) -> std::path::PathBuf {
+        self.path.join(format!("{}.bin", key))
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl WasmInstrumentationCache for DirectoryWasmInstrumentationCache {
+    fn get(&self, key: &Hash) -> Option<Vec<u8>> {
+        std::fs::read(self.entry_path(key)).ok()
+    }
+
+    fn put(&self, key: &Hash, value: &[u8]) {
+        // Write to a temporary file first, so that concurrent readers never see a partial entry
+        let entry_path = self.entry_path(key);
+        let temporary_path = self
+            .path
+            .join(format!("{}.{}.tmp", key, std::process::id()));
+        let _ = std::fs::create_dir_all(&self.path)
+            .and_then(|_| std::fs::write(&temporary_path, value))
+            .and_then(|_| std::fs::rename(&temporary_path, &entry_path));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+struct CachedInstrumentation {
+    instrumented_code: Vec<u8>,
+    exports: Vec<String>,
+}
+
+impl WasmValidator {
+    /// The key under which the result of validating the given code against the given blueprints
+    /// is cached.
+    pub fn instrumentation_cache_key<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        code: &[u8],
+        blueprints: I,
+    ) -> Hash {
+        let mut bytes = scrypto_encode(&(
+            hash(code),
+            self.max_memory_size_in_pages,
+            self.max_initial_table_size,
+            self.max_number_of_br_table_targets,
+            self.max_number_of_functions,
+            self.max_number_of_globals,
+            self.instrumenter_config.version(),
+            self.instrumenter_config.max_stack_size(),
+        ))
+        .unwrap();
+        for blueprint in blueprints {
+            bytes.extend(scrypto_encode(blueprint).unwrap());
+        }
+        hash(bytes)
+    }
+
+    /// Like [`Self::validate`], but reuses the result of an earlier validation of the same code
+    /// from the given cache. Only successful validations are cached.
+    pub fn validate_with_cache<'a, I: Iterator<Item = &'a BlueprintDefinitionInit> + Clone>(
+        &self,
+        code: &[u8],
+        blueprints: I,
+        cache: &dyn WasmInstrumentationCache,
+    ) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
+        let key = self.instrumentation_cache_key(code, blueprints.clone());
+        if let Some(cached) = cache
+            .get(&key)
+            .and_then(|bytes| scrypto_decode::<CachedInstrumentation>(&bytes).ok())
+        {
+            return Ok((cached.instrumented_code, cached.exports));
+        }
+
+        let (instrumented_code, exports) = self.validate(code, blueprints)?;
+        cache.put(
+            &key,
+            &scrypto_encode(&CachedInstrumentation {
+                instrumented_code: instrumented_code.clone(),
+                exports: exports.clone(),
+            })
+            .unwrap(),
+        );
+        Ok((instrumented_code, exports))
+    }
+}
@@ -1,7 +1,9 @@
 mod constants;
 mod errors;
+mod instrumentation_cache;
 mod prepare;
 mod traits;
+mod wasm_artifact;
 mod wasm_validator;
 mod wasm_validator_config;
 #[cfg(feature = "wasmer")]
@@ -14,8 +16,10 @@ pub use self::wasmer::*;
 pub use self::wasmi::*;
 pub use constants::*;
 pub use errors::*;
+pub use instrumentation_cache::*;
 pub use prepare::*;
 pub use traits::*;
+pub use wasm_artifact::*;
 pub use wasm_validator::*;
 pub use wasm_validator_config::*;
 pub use weights::*;
@@ -31,6 +31,13 @@ pub trait WasmRuntime {
         args: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn call_static_method(
+        &mut self,
+        receiver: Vec<u8>,
+        ident: Vec<u8>,
+        args: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn call_function(
         &mut self,
         package_address: Vec<u8>,
@@ -93,6 +100,20 @@ pub trait WasmRuntime {
 
     fn drop_object(&mut self, node_id: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn vault_transfer(
+        &mut self,
+        from_vault: Vec<u8>,
+        to_vault: Vec<u8>,
+        amount: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    fn vault_transfer_non_fungibles(
+        &mut self,
+        from_vault: Vec<u8>,
+        to_vault: Vec<u8>,
+        non_fungible_local_ids: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn actor_open_field(
         &mut self,
         object_handle: u32,
@@ -100,6 +121,19 @@ pub trait WasmRuntime {
         flags: u32,
     ) -> Result<LockHandle, InvokeError<WasmRuntimeError>>;
 
+    fn actor_open_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: Vec<u8>,
+        flags: u32,
+    ) -> Result<LockHandle, InvokeError<WasmRuntimeError>>;
+
+    fn actor_remove_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn field_lock_read(
         &mut self,
         handle: LockHandle,
@@ -151,9 +185,22 @@ pub trait WasmRuntime {
 
     fn panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    fn fail(
+        &mut self,
+        error_name: Vec<u8>,
+        error: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn generate_ruid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn big_int_op(
+        &mut self,
+        int_type: u32,
+        operation: u32,
+        operands: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 }
 
 /// Represents an instantiated, invokable Scrypto module.
@@ -184,4 +231,11 @@ pub trait WasmEngine {
     ///
     /// The code must have been validated and instrumented!!!
     fn instantiate(&self, code_hash: Hash, instrumented_code: &[u8]) -> Self::WasmInstance;
+
+    /// Prepares a Scrypto module ahead of its first instantiation, warming up any module cache.
+    ///
+    /// The code must have been validated and instrumented!!!
+    fn preload(&self, code_hash: Hash, instrumented_code: &[u8]) {
+        self.instantiate(code_hash, instrumented_code);
+    }
 }
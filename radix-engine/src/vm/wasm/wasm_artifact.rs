@@ -0,0 +1,236 @@
+use crate::system::system::KeyValueEntrySubstate;
+use crate::types::*;
+use crate::vm::wasm::*;
+use radix_engine_interface::blueprints::package::{
+    PackageInstrumentedCodeSubstate, PACKAGE_INSTRUMENTED_CODE_PARTITION_OFFSET,
+};
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::SubstateDatabase;
+use sbor::rust::iter;
+
+/// The version of the precompiled WASM artifact format.
+pub const PRECOMPILED_WASM_ARTIFACT_VERSION: u8 = 1;
+
+/// A module which has been validated and instrumented ahead of time.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PrecompiledWasmModule {
+    /// The hash of the original code, which packages refer to their code by
+    pub code_hash: Hash,
+    pub instrumented_code_hash: Hash,
+    pub instrumented_code: Vec<u8>,
+}
+
+/// A deterministic bundle of instrumented WASM modules, which nodes can ship alongside a state
+/// snapshot to skip instrumenting and parsing package code after a restart.
+///
+/// The same set of modules always encodes to the same bytes: modules are sorted by code hash and
+/// only appear once.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PrecompiledWasmArtifact {
+    pub version: u8,
+    /// The version of the instrumentation rules the modules were instrumented with
+    pub instrumenter_version: u8,
+    pub modules: Vec<PrecompiledWasmModule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrecompiledWasmArtifactError {
+    DecodeError(DecodeError),
+    UnsupportedVersion {
+        expected: u8,
+        actual: u8,
+    },
+    InstrumenterVersionMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    ModulesNotSorted,
+    InvalidCode {
+        code_hash: Hash,
+        error: PrepareError,
+    },
+    InstrumentedCodeHashMismatch {
+        code_hash: Hash,
+    },
+    UnknownCodeHash {
+        code_hash: Hash,
+    },
+    ExpectedInstrumentedCodeMismatch {
+        code_hash: Hash,
+    },
+}
+
+impl PrecompiledWasmArtifact {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        scrypto_encode(self).unwrap()
+    }
+
+    /// Decodes an artifact and checks it against the instrumented code hashes of the packages it
+    /// may be used for, keyed by code hash.
+    ///
+    /// Every module must be well-formed and match the instrumented code of a known package, so
+    /// that a loaded artifact can never change which code gets run.
+    pub fn load(
+        bytes: &[u8],
+        expected_instrumented_code_hashes: &IndexMap<Hash, Hash>,
+    ) -> Result<Self, PrecompiledWasmArtifactError> {
+        let artifact: Self =
+            scrypto_decode(bytes).map_err(PrecompiledWasmArtifactError::DecodeError)?;
+        artifact.validate(expected_instrumented_code_hashes)?;
+        Ok(artifact)
+    }
+
+    pub fn validate(
+        &self,
+        expected_instrumented_code_hashes: &IndexMap<Hash, Hash>,
+    ) -> Result<(), PrecompiledWasmArtifactError> {
+        if self.version != PRECOMPILED_WASM_ARTIFACT_VERSION {
+            return Err(PrecompiledWasmArtifactError::UnsupportedVersion {
+                expected: PRECOMPILED_WASM_ARTIFACT_VERSION,
+                actual: self.version,
+            });
+        }
+        let instrumenter_version = WasmValidatorConfigV1::new().version();
+        if self.instrumenter_version != instrumenter_version {
+            return Err(PrecompiledWasmArtifactError::InstrumenterVersionMismatch {
+                expected: instrumenter_version,
+                actual: self.instrumenter_version,
+            });
+        }
+        if self
+            .modules
+            .windows(2)
+            .any(|pair| pair[0].code_hash >= pair[1].code_hash)
+        {
+            return Err(PrecompiledWasmArtifactError::ModulesNotSorted);
+        }
+
+        for module in &self.modules {
+            let code_hash = module.code_hash;
+            if hash(&module.instrumented_code) != module.instrumented_code_hash {
+                return Err(PrecompiledWasmArtifactError::InstrumentedCodeHashMismatch {
+                    code_hash,
+                });
+            }
+            match expected_instrumented_code_hashes.get(&code_hash) {
+                Some(expected) if expected.eq(&module.instrumented_code_hash) => {}
+                Some(_) => {
+                    return Err(
+                        PrecompiledWasmArtifactError::ExpectedInstrumentedCodeMismatch {
+                            code_hash,
+                        },
+                    )
+                }
+                None => return Err(PrecompiledWasmArtifactError::UnknownCodeHash { code_hash }),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds [`PrecompiledWasmArtifact`]s, from original package code or from the instrumented code
+/// of packages already in a substate database.
+pub struct WasmPrecompiler {
+    validator: WasmValidator,
+    modules: BTreeMap<Hash, PrecompiledWasmModule>,
+}
+
+impl Default for WasmPrecompiler {
+    fn default() -> Self {
+        Self::new(WasmValidator::default())
+    }
+}
+
+impl WasmPrecompiler {
+    pub fn new(validator: WasmValidator) -> Self {
+        Self {
+            validator,
+            modules: BTreeMap::new(),
+        }
+    }
+
+    /// Validates and instruments original package code, exactly as when it's published,
+    /// returning its code hash.
+    pub fn add_code(&mut self, code: &[u8]) -> Result<Hash, PrecompiledWasmArtifactError> {
+        let code_hash = hash(code);
+        // Exports are checked against the blueprints at publishing, which doesn't affect the
+        // instrumented code
+        let (instrumented_code, _) = self
+            .validator
+            .validate(code, iter::empty())
+            .map_err(|error| PrecompiledWasmArtifactError::InvalidCode { code_hash, error })?;
+        self.add_instrumented_code(code_hash, instrumented_code);
+        Ok(code_hash)
+    }
+
+    pub fn add_instrumented_code(&mut self, code_hash: Hash, instrumented_code: Vec<u8>) {
+        self.modules.insert(
+            code_hash,
+            PrecompiledWasmModule {
+                code_hash,
+                instrumented_code_hash: hash(&instrumented_code),
+                instrumented_code,
+            },
+        );
+    }
+
+    /// Adds the instrumented code of a package in the database, returning the code hashes added.
+    pub fn add_package<S: SubstateDatabase>(
+        &mut self,
+        substate_db: &S,
+        package_address: &PackageAddress,
+    ) -> Vec<Hash> {
+        let mut code_hashes = Vec::new();
+        for (code_hash, code) in read_instrumented_code(substate_db, package_address) {
+            self.add_instrumented_code(code_hash, code);
+            code_hashes.push(code_hash);
+        }
+        code_hashes
+    }
+
+    pub fn build(self) -> PrecompiledWasmArtifact {
+        PrecompiledWasmArtifact {
+            version: PRECOMPILED_WASM_ARTIFACT_VERSION,
+            instrumenter_version: self.validator.instrumenter_config.version(),
+            modules: self.modules.into_values().collect(),
+        }
+    }
+}
+
+/// Returns the hashes of the instrumented code of the given packages, keyed by code hash, for
+/// validating an artifact against a substate database.
+pub fn read_instrumented_code_hashes<'a, S: SubstateDatabase>(
+    substate_db: &S,
+    package_addresses: impl IntoIterator<Item = &'a PackageAddress>,
+) -> IndexMap<Hash, Hash> {
+    let mut hashes = index_map_new();
+    for package_address in package_addresses {
+        for (code_hash, code) in read_instrumented_code(substate_db, package_address) {
+            hashes.insert(code_hash, hash(&code));
+        }
+    }
+    hashes
+}
+
+fn read_instrumented_code<S: SubstateDatabase>(
+    substate_db: &S,
+    package_address: &PackageAddress,
+) -> Vec<(Hash, Vec<u8>)> {
+    let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
+        package_address.as_node_id(),
+        MAIN_BASE_PARTITION
+            .at_offset(PACKAGE_INSTRUMENTED_CODE_PARTITION_OFFSET)
+            .unwrap(),
+    );
+    substate_db
+        .list_entries(&partition_key)
+        .filter_map(|(sort_key, value)| {
+            let code_hash: Hash =
+                scrypto_decode(&SpreadPrefixKeyMapper::map_from_db_sort_key(&sort_key)).unwrap();
+            let entry: KeyValueEntrySubstate<PackageInstrumentedCodeSubstate> =
+                scrypto_decode(&value).unwrap();
+            entry.value.map(|code| (code_hash, code.code))
+        })
+        .collect()
+}
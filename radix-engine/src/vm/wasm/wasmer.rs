@@ -221,6 +221,28 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn call_static_method(
+            env: &WasmerInstanceEnv,
+            receiver_ptr: u32,
+            receiver_len: u32,
+            ident_ptr: u32,
+            ident_len: u32,
+            args_ptr: u32,
+            args_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let receiver = read_memory(&instance, receiver_ptr, receiver_len)?;
+            let ident = read_memory(&instance, ident_ptr, ident_len)?;
+            let args = read_memory(&instance, args_ptr, args_len)?;
+
+            let buffer = runtime
+                .call_static_method(receiver, ident, args)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         pub fn call_function(
             env: &WasmerInstanceEnv,
             package_address_ptr: u32,
@@ -465,6 +487,54 @@ impl WasmerModule {
             Ok(())
         }
 
+        pub fn vault_transfer(
+            env: &WasmerInstanceEnv,
+            from_vault_ptr: u32,
+            from_vault_len: u32,
+            to_vault_ptr: u32,
+            to_vault_len: u32,
+            amount_ptr: u32,
+            amount_len: u32,
+        ) -> Result<(), RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let from_vault = read_memory(&instance, from_vault_ptr, from_vault_len)?;
+            let to_vault = read_memory(&instance, to_vault_ptr, to_vault_len)?;
+            let amount = read_memory(&instance, amount_ptr, amount_len)?;
+
+            runtime
+                .vault_transfer(from_vault, to_vault, amount)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(())
+        }
+
+        pub fn vault_transfer_non_fungibles(
+            env: &WasmerInstanceEnv,
+            from_vault_ptr: u32,
+            from_vault_len: u32,
+            to_vault_ptr: u32,
+            to_vault_len: u32,
+            non_fungible_local_ids_ptr: u32,
+            non_fungible_local_ids_len: u32,
+        ) -> Result<(), RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let from_vault = read_memory(&instance, from_vault_ptr, from_vault_len)?;
+            let to_vault = read_memory(&instance, to_vault_ptr, to_vault_len)?;
+            let non_fungible_local_ids = read_memory(
+                &instance,
+                non_fungible_local_ids_ptr,
+                non_fungible_local_ids_len,
+            )?;
+
+            runtime
+                .vault_transfer_non_fungibles(from_vault, to_vault, non_fungible_local_ids)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(())
+        }
+
         pub fn actor_open_field(
             env: &WasmerInstanceEnv,
             object_handle: u32,
@@ -480,6 +550,44 @@ impl WasmerModule {
             Ok(handle)
         }
 
+        pub fn actor_open_transient_entry(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            key_ptr: u32,
+            key_len: u32,
+            flags: u32,
+        ) -> Result<u32, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let handle = runtime
+                .actor_open_transient_entry(
+                    object_handle,
+                    read_memory(&instance, key_ptr, key_len)?,
+                    flags,
+                )
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(handle)
+        }
+
+        pub fn actor_remove_transient_entry(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let buffer = runtime
+                .actor_remove_transient_entry(
+                    object_handle,
+                    read_memory(&instance, key_ptr, key_len)?,
+                )
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         pub fn field_lock_read(env: &WasmerInstanceEnv, handle: u32) -> Result<u64, RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -626,6 +734,21 @@ impl WasmerModule {
             runtime.panic(message)
         }
 
+        fn fail(
+            env: &WasmerInstanceEnv,
+            error_name_ptr: u32,
+            error_name_len: u32,
+            error_ptr: u32,
+            error_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let error_name = read_memory(&instance, error_name_ptr, error_name_len)?;
+            let error = read_memory(&instance, error_ptr, error_len)?;
+
+            runtime.fail(error_name, error)
+        }
+
         pub fn get_transaction_hash(env: &WasmerInstanceEnv) -> Result<u64, RuntimeError> {
             let (_instance, runtime) = grab_runtime!(env);
 
@@ -646,6 +769,23 @@ impl WasmerModule {
             Ok(buffer.0)
         }
 
+        pub fn big_int_op(
+            env: &WasmerInstanceEnv,
+            int_type: u32,
+            operation: u32,
+            operands_ptr: u32,
+            operands_len: u32,
+        ) -> Result<u64, RuntimeError> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let operands = read_memory(&instance, operands_ptr, operands_len)?;
+            let buffer = runtime
+                .big_int_op(int_type, operation, operands)
+                .map_err(|e| RuntimeError::user(Box::new(e)))?;
+
+            Ok(buffer.0)
+        }
+
         // native functions ends
 
         // env
@@ -659,6 +799,7 @@ impl WasmerModule {
             MODULE_ENV_NAME => {
                 CONSUME_BUFFER_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_buffer),
                 CALL_METHOD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), call_method),
+                CALL_STATIC_METHOD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), call_static_method),
                 CALL_FUNCTION_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), call_function),
                 NEW_OBJECT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), new_object),
                 ALLOCATE_GLOBAL_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), allocate_global_address),
@@ -669,8 +810,12 @@ impl WasmerModule {
                 GLOBALIZE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), globalize_object),
                 GET_OBJECT_INFO_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_type_info),
                 DROP_OBJECT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), drop_object),
+                VAULT_TRANSFER_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), vault_transfer),
+                VAULT_TRANSFER_NON_FUNGIBLES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), vault_transfer_non_fungibles),
                 ACTOR_OPEN_FIELD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_open_field),
                 ACTOR_CALL_MODULE_METHOD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_call_module_method),
+                ACTOR_OPEN_TRANSIENT_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_open_transient_entry),
+                ACTOR_REMOVE_TRANSIENT_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_remove_transient_entry),
                 KEY_VALUE_STORE_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_new),
                 KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_open_entry),
                 KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_remove_entry),
@@ -689,8 +834,10 @@ impl WasmerModule {
                 EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_event),
                 EMIT_LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), emit_log),
                 PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), panic),
+                FAIL_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), fail),
                 GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), get_transaction_hash),
                 GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), generate_ruid),
+                BIG_INT_OP_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), big_int_op),
             }
         };
 
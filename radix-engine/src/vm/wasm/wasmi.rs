@@ -149,6 +149,26 @@ fn call_method(
         .map(|buffer| buffer.0)
 }
 
+fn call_static_method(
+    mut caller: Caller<'_, HostState>,
+    receiver_ptr: u32,
+    receiver_len: u32,
+    ident_ptr: u32,
+    ident_len: u32,
+    args_ptr: u32,
+    args_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let receiver = read_memory(caller.as_context_mut(), memory, receiver_ptr, receiver_len)?;
+    let ident = read_memory(caller.as_context_mut(), memory, ident_ptr, ident_len)?;
+    let args = read_memory(caller.as_context_mut(), memory, args_ptr, args_len)?;
+
+    runtime
+        .call_static_method(receiver, ident, args)
+        .map(|buffer| buffer.0)
+}
+
 fn call_function(
     mut caller: Caller<'_, HostState>,
     package_address_ptr: u32,
@@ -313,6 +333,57 @@ fn drop_object(
     runtime.drop_object(node_id)
 }
 
+fn vault_transfer(
+    mut caller: Caller<'_, HostState>,
+    from_vault_ptr: u32,
+    from_vault_len: u32,
+    to_vault_ptr: u32,
+    to_vault_len: u32,
+    amount_ptr: u32,
+    amount_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let from_vault = read_memory(
+        caller.as_context_mut(),
+        memory,
+        from_vault_ptr,
+        from_vault_len,
+    )?;
+    let to_vault = read_memory(caller.as_context_mut(), memory, to_vault_ptr, to_vault_len)?;
+    let amount = read_memory(caller.as_context_mut(), memory, amount_ptr, amount_len)?;
+
+    runtime.vault_transfer(from_vault, to_vault, amount)
+}
+
+fn vault_transfer_non_fungibles(
+    mut caller: Caller<'_, HostState>,
+    from_vault_ptr: u32,
+    from_vault_len: u32,
+    to_vault_ptr: u32,
+    to_vault_len: u32,
+    non_fungible_local_ids_ptr: u32,
+    non_fungible_local_ids_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let from_vault = read_memory(
+        caller.as_context_mut(),
+        memory,
+        from_vault_ptr,
+        from_vault_len,
+    )?;
+    let to_vault = read_memory(caller.as_context_mut(), memory, to_vault_ptr, to_vault_len)?;
+    let non_fungible_local_ids = read_memory(
+        caller.as_context_mut(),
+        memory,
+        non_fungible_local_ids_ptr,
+        non_fungible_local_ids_len,
+    )?;
+
+    runtime.vault_transfer_non_fungibles(from_vault, to_vault, non_fungible_local_ids)
+}
+
 fn lock_key_value_store_entry(
     mut caller: Caller<'_, HostState>,
     node_id_ptr: u32,
@@ -382,6 +453,33 @@ fn lock_field(
     runtime.actor_open_field(object_handle, field as u8, flags)
 }
 
+fn actor_open_transient_entry(
+    mut caller: Caller<'_, HostState>,
+    object_handle: u32,
+    key_ptr: u32,
+    key_len: u32,
+    flags: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime.actor_open_transient_entry(object_handle, key, flags)
+}
+
+fn actor_remove_transient_entry(
+    mut caller: Caller<'_, HostState>,
+    object_handle: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime
+        .actor_remove_transient_entry(object_handle, key)
+        .map(|buffer| buffer.0)
+}
+
 fn field_lock_read(
     caller: Caller<'_, HostState>,
     handle: u32,
@@ -496,6 +594,22 @@ fn generate_ruid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmR
     runtime.generate_ruid().map(|buffer| buffer.0)
 }
 
+fn big_int_op(
+    mut caller: Caller<'_, HostState>,
+    int_type: u32,
+    operation: u32,
+    operands_ptr: u32,
+    operands_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let operands = read_memory(caller.as_context_mut(), memory, operands_ptr, operands_len)?;
+
+    runtime
+        .big_int_op(int_type, operation, operands)
+        .map(|buffer| buffer.0)
+}
+
 fn emit_log(
     mut caller: Caller<'_, HostState>,
     level_ptr: u32,
@@ -522,6 +636,26 @@ fn panic(
 
     runtime.panic(message)
 }
+
+fn fail(
+    mut caller: Caller<'_, HostState>,
+    error_name_ptr: u32,
+    error_name_len: u32,
+    error_ptr: u32,
+    error_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let error_name = read_memory(
+        caller.as_context_mut(),
+        memory,
+        error_name_ptr,
+        error_name_len,
+    )?;
+    let error = read_memory(caller.as_context_mut(), memory, error_ptr, error_len)?;
+
+    runtime.fail(error_name, error)
+}
 // native functions ends
 
 macro_rules! linker_define {
@@ -623,6 +757,29 @@ impl WasmiModule {
             },
         );
 
+        let host_call_static_method = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             receiver_ptr: u32,
+             receiver_len: u32,
+             ident_ptr: u32,
+             ident_len: u32,
+             args_ptr: u32,
+             args_len: u32|
+             -> Result<u64, Trap> {
+                call_static_method(
+                    caller,
+                    receiver_ptr,
+                    receiver_len,
+                    ident_ptr,
+                    ident_len,
+                    args_ptr,
+                    args_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_call_function = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -751,6 +908,52 @@ impl WasmiModule {
             },
         );
 
+        let host_vault_transfer = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             from_vault_ptr: u32,
+             from_vault_len: u32,
+             to_vault_ptr: u32,
+             to_vault_len: u32,
+             amount_ptr: u32,
+             amount_len: u32|
+             -> Result<(), Trap> {
+                vault_transfer(
+                    caller,
+                    from_vault_ptr,
+                    from_vault_len,
+                    to_vault_ptr,
+                    to_vault_len,
+                    amount_ptr,
+                    amount_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_vault_transfer_non_fungibles = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             from_vault_ptr: u32,
+             from_vault_len: u32,
+             to_vault_ptr: u32,
+             to_vault_len: u32,
+             non_fungible_local_ids_ptr: u32,
+             non_fungible_local_ids_len: u32|
+             -> Result<(), Trap> {
+                vault_transfer_non_fungibles(
+                    caller,
+                    from_vault_ptr,
+                    from_vault_len,
+                    to_vault_ptr,
+                    to_vault_len,
+                    non_fungible_local_ids_ptr,
+                    non_fungible_local_ids_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_lock_key_value_store_entry = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -821,6 +1024,31 @@ impl WasmiModule {
             },
         );
 
+        let host_actor_open_transient_entry = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             key_ptr: u32,
+             key_len: u32,
+             flags: u32|
+             -> Result<u32, Trap> {
+                actor_open_transient_entry(caller, object_handle, key_ptr, key_len, flags)
+                    .map_err(|e| e.into())
+            },
+        );
+
+        let host_actor_remove_transient_entry = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Trap> {
+                actor_remove_transient_entry(caller, object_handle, key_ptr, key_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let host_field_lock_read = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>, handle: u32| -> Result<u64, Trap> {
@@ -930,6 +1158,19 @@ impl WasmiModule {
             },
         );
 
+        let host_fail = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             error_name_ptr: u32,
+             error_name_len: u32,
+             error_ptr: u32,
+             error_len: u32|
+             -> Result<(), Trap> {
+                fail(caller, error_name_ptr, error_name_len, error_ptr, error_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let host_get_transaction_hash = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -944,10 +1185,28 @@ impl WasmiModule {
             },
         );
 
+        let host_big_int_op = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             int_type: u32,
+             operation: u32,
+             operands_ptr: u32,
+             operands_len: u32|
+             -> Result<u64, Trap> {
+                big_int_op(caller, int_type, operation, operands_ptr, operands_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let mut linker = <Linker<HostState>>::new();
 
         linker_define!(linker, CONSUME_BUFFER_FUNCTION_NAME, host_consume_buffer);
         linker_define!(linker, CALL_METHOD_FUNCTION_NAME, host_call_method);
+        linker_define!(
+            linker,
+            CALL_STATIC_METHOD_FUNCTION_NAME,
+            host_call_static_method
+        );
         linker_define!(linker, CALL_FUNCTION_FUNCTION_NAME, host_call_function);
         linker_define!(linker, NEW_OBJECT_FUNCTION_NAME, host_new_component);
 
@@ -963,12 +1222,28 @@ impl WasmiModule {
         linker_define!(linker, GLOBALIZE_FUNCTION_NAME, host_globalize_object);
         linker_define!(linker, GET_OBJECT_INFO_FUNCTION_NAME, host_get_object_info);
         linker_define!(linker, DROP_OBJECT_FUNCTION_NAME, host_drop_node);
+        linker_define!(linker, VAULT_TRANSFER_FUNCTION_NAME, host_vault_transfer);
+        linker_define!(
+            linker,
+            VAULT_TRANSFER_NON_FUNGIBLES_FUNCTION_NAME,
+            host_vault_transfer_non_fungibles
+        );
         linker_define!(linker, ACTOR_OPEN_FIELD_FUNCTION_NAME, host_lock_field);
         linker_define!(
             linker,
             ACTOR_CALL_MODULE_METHOD_FUNCTION_NAME,
             host_actor_call_module_method
         );
+        linker_define!(
+            linker,
+            ACTOR_OPEN_TRANSIENT_ENTRY_FUNCTION_NAME,
+            host_actor_open_transient_entry
+        );
+        linker_define!(
+            linker,
+            ACTOR_REMOVE_TRANSIENT_ENTRY_FUNCTION_NAME,
+            host_actor_remove_transient_entry
+        );
 
         linker_define!(
             linker,
@@ -1033,12 +1308,14 @@ impl WasmiModule {
         linker_define!(linker, EMIT_EVENT_FUNCTION_NAME, host_emit_event);
         linker_define!(linker, EMIT_LOG_FUNCTION_NAME, host_emit_log);
         linker_define!(linker, PANIC_FUNCTION_NAME, host_panic);
+        linker_define!(linker, FAIL_FUNCTION_NAME, host_fail);
         linker_define!(
             linker,
             GET_TRANSACTION_HASH_FUNCTION_NAME,
             host_get_transaction_hash
         );
         linker_define!(linker, GENERATE_RUID_FUNCTION_NAME, host_generate_ruid);
+        linker_define!(linker, BIG_INT_OP_FUNCTION_NAME, host_big_int_op);
 
         let global_value = Global::new(store.as_context_mut(), Value::I32(-1), Mutability::Var);
         linker_define!(linker, "test_global_mutable_value", global_value);
@@ -4,7 +4,7 @@ use crate::types::*;
 use crate::vm::wasm::*;
 use radix_engine_interface::api::field_lock_api::LockFlags;
 use radix_engine_interface::api::object_api::ObjectModuleId;
-use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::api::{BigIntOperation, BigIntType, ClientApi, ClientBigIntApi};
 use radix_engine_interface::blueprints::resource::AccessRule;
 use radix_engine_interface::schema::KeyValueStoreSchema;
 use radix_engine_interface::types::ClientCostingEntry;
@@ -129,6 +129,25 @@ where
         self.allocate_buffer(return_data)
     }
 
+    fn call_static_method(
+        &mut self,
+        receiver: Vec<u8>,
+        ident: Vec<u8>,
+        args: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let receiver = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(receiver.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let ident = String::from_utf8(ident).map_err(|_| WasmRuntimeError::InvalidString)?;
+
+        let return_data = self
+            .api
+            .call_static_method(&receiver, ident.as_str(), args)?;
+
+        self.allocate_buffer(return_data)
+    }
+
     fn call_function(
         &mut self,
         package_address: Vec<u8>,
@@ -212,6 +231,55 @@ where
         Ok(())
     }
 
+    fn vault_transfer(
+        &mut self,
+        from_vault: Vec<u8>,
+        to_vault: Vec<u8>,
+        amount: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let from_vault = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(from_vault.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let to_vault = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(to_vault.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let amount = scrypto_decode::<Decimal>(&amount).map_err(WasmRuntimeError::InvalidAmount)?;
+
+        self.api
+            .transfer_between_vaults(&from_vault, &to_vault, amount)?;
+
+        Ok(())
+    }
+
+    fn vault_transfer_non_fungibles(
+        &mut self,
+        from_vault: Vec<u8>,
+        to_vault: Vec<u8>,
+        non_fungible_local_ids: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let from_vault = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(from_vault.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let to_vault = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(to_vault.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let non_fungible_local_ids =
+            scrypto_decode::<BTreeSet<NonFungibleLocalId>>(&non_fungible_local_ids)
+                .map_err(WasmRuntimeError::InvalidNonFungibleLocalIds)?;
+
+        self.api.transfer_non_fungibles_between_vaults(
+            &from_vault,
+            &to_vault,
+            non_fungible_local_ids,
+        )?;
+
+        Ok(())
+    }
+
     fn key_value_store_new(
         &mut self,
         schema: Vec<u8>,
@@ -293,6 +361,29 @@ where
         Ok(handle)
     }
 
+    fn actor_open_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: Vec<u8>,
+        flags: u32,
+    ) -> Result<LockHandle, InvokeError<WasmRuntimeError>> {
+        let flags = LockFlags::from_bits(flags).ok_or(WasmRuntimeError::InvalidLockFlags)?;
+        let handle = self
+            .api
+            .actor_open_transient_entry(object_handle, &key, flags)?;
+
+        Ok(handle)
+    }
+
+    fn actor_remove_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let rtn = self.api.actor_remove_transient_entry(object_handle, &key)?;
+        self.allocate_buffer(rtn)
+    }
+
     fn field_lock_read(
         &mut self,
         handle: LockHandle,
@@ -426,6 +517,18 @@ where
         Ok(())
     }
 
+    fn fail(
+        &mut self,
+        error_name: Vec<u8>,
+        error: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.fail(
+            String::from_utf8(error_name).map_err(|_| WasmRuntimeError::InvalidString)?,
+            error,
+        )?;
+        Ok(())
+    }
+
     fn get_transaction_hash(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let hash = self.api.get_transaction_hash()?;
 
@@ -438,6 +541,21 @@ where
         self.allocate_buffer(scrypto_encode(&ruid).expect("Failed to encode RUID"))
     }
 
+    fn big_int_op(
+        &mut self,
+        int_type: u32,
+        operation: u32,
+        operands: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let int_type = BigIntType::try_from(int_type)
+            .map_err(|_| WasmRuntimeError::InvalidBigIntType(int_type))?;
+        let operation = BigIntOperation::try_from(operation)
+            .map_err(|_| WasmRuntimeError::InvalidBigIntOperation(operation))?;
+        let result = self.api.big_int_op(int_type, operation, operands)?;
+
+        self.allocate_buffer(result)
+    }
+
     fn cost_unit_limit(&mut self) -> Result<u32, InvokeError<WasmRuntimeError>> {
         let cost_unit_limit = self.api.cost_unit_limit()?;
 
@@ -203,7 +203,16 @@ impl<X: CustomValueKind, D: Decoder<X>, T: Decode<X, D> + Categorize<X> + Ord> D
         let len = decoder.read_size()?;
         let mut result = BTreeSet::new();
         for _ in 0..len {
-            if !result.insert(decoder.decode_deeper_body_with_value_kind(element_value_kind)?) {
+            let element: T = decoder.decode_deeper_body_with_value_kind(element_value_kind)?;
+            if decoder.is_strict()
+                && result
+                    .iter()
+                    .next_back()
+                    .map_or(false, |last| &element < last)
+            {
+                return Err(DecodeError::NonCanonicalKeyOrder);
+            }
+            if !result.insert(element) {
                 return Err(DecodeError::DuplicateKey);
             }
         }
@@ -271,9 +280,13 @@ impl<
         let len = decoder.read_size()?;
         let mut result = BTreeMap::new();
         for _ in 0..len {
+            let key: K = decoder.decode_deeper_body_with_value_kind(key_value_kind)?;
+            if decoder.is_strict() && result.keys().next_back().map_or(false, |last| &key < last) {
+                return Err(DecodeError::NonCanonicalKeyOrder);
+            }
             if result
                 .insert(
-                    decoder.decode_deeper_body_with_value_kind(key_value_kind)?,
+                    key,
                     decoder.decode_deeper_body_with_value_kind(value_value_kind)?,
                 )
                 .is_some()
@@ -7,17 +7,34 @@ use crate::*;
 pub enum DecodeError {
     ExtraTrailingBytes(usize),
 
-    BufferUnderflow { required: usize, remaining: usize },
-
-    UnexpectedPayloadPrefix { expected: u8, actual: u8 },
-
-    UnexpectedValueKind { expected: u8, actual: u8 },
-
-    UnexpectedCustomValueKind { actual: u8 },
-
-    UnexpectedSize { expected: usize, actual: usize },
-
-    UnexpectedDiscriminator { expected: u8, actual: u8 },
+    BufferUnderflow {
+        required: usize,
+        remaining: usize,
+    },
+
+    UnexpectedPayloadPrefix {
+        expected: u8,
+        actual: u8,
+    },
+
+    UnexpectedValueKind {
+        expected: u8,
+        actual: u8,
+    },
+
+    UnexpectedCustomValueKind {
+        actual: u8,
+    },
+
+    UnexpectedSize {
+        expected: usize,
+        actual: usize,
+    },
+
+    UnexpectedDiscriminator {
+        expected: u8,
+        actual: u8,
+    },
 
     UnknownValueKind(u8),
 
@@ -34,6 +51,10 @@ pub enum DecodeError {
     DuplicateKey,
 
     InvalidCustomValue, // TODO: generify custom error codes
+
+    /// The keys of a sorted collection weren't in canonical (ascending) order, which only strict
+    /// decoders reject.
+    NonCanonicalKeyOrder,
 }
 
 pub trait Decoder<X: CustomValueKind>: Sized {
@@ -207,6 +228,14 @@ pub trait Decoder<X: CustomValueKind>: Sized {
     }
 
     fn peek_byte(&self) -> Result<u8, DecodeError>;
+
+    /// Whether the decoder rejects non-canonical encodings which would otherwise decode to the
+    /// same value, such as sorted collections whose keys aren't encoded in ascending order.
+    ///
+    /// Redundant size encodings are rejected by all decoders.
+    fn is_strict(&self) -> bool {
+        false
+    }
 }
 
 pub trait BorrowingDecoder<'de, X: CustomValueKind>: Decoder<X> {
@@ -219,6 +248,7 @@ pub struct VecDecoder<'de, X: CustomValueKind> {
     offset: usize,
     stack_depth: usize,
     max_depth: usize,
+    strict: bool,
     phantom: PhantomData<X>,
 }
 
@@ -229,10 +259,20 @@ impl<'de, X: CustomValueKind> VecDecoder<'de, X> {
             offset: 0,
             stack_depth: 0,
             max_depth,
+            strict: false,
             phantom: PhantomData,
         }
     }
 
+    /// Creates a decoder which only accepts the canonical encoding of each value, so that no two
+    /// distinct payloads decode to the same value. See [`Decoder::is_strict`].
+    pub fn new_strict(input: &'de [u8], max_depth: usize) -> Self {
+        Self {
+            strict: true,
+            ..Self::new(input, max_depth)
+        }
+    }
+
     pub fn get_input_slice(&self) -> &'de [u8] {
         &self.input
     }
@@ -326,6 +366,11 @@ impl<'de, X: CustomValueKind> Decoder<X> for VecDecoder<'de, X> {
         let result = self.input[self.offset];
         Ok(result)
     }
+
+    #[inline]
+    fn is_strict(&self) -> bool {
+        self.strict
+    }
 }
 
 impl<'de, X: CustomValueKind> BorrowingDecoder<'de, X> for VecDecoder<'de, X> {
@@ -530,6 +575,64 @@ mod tests {
         );
     }
 
+    fn strict_basic_decode<T: BasicDecode>(buf: &[u8]) -> Result<T, DecodeError> {
+        BasicDecoder::new_strict(buf, BASIC_SBOR_V1_MAX_DEPTH)
+            .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+    }
+
+    #[test]
+    pub fn test_strict_decode_rejects_unsorted_keys() {
+        let unsorted_set = basic_encode(&vec![2u16, 1u16]).unwrap();
+        let sorted_set = basic_encode(&vec![1u16, 2u16]).unwrap();
+        // Sorted sets can be decoded from both orderings by default
+        assert_eq!(
+            basic_decode::<BTreeSet<u16>>(&unsorted_set),
+            basic_decode::<BTreeSet<u16>>(&sorted_set)
+        );
+        assert_eq!(
+            strict_basic_decode::<BTreeSet<u16>>(&unsorted_set),
+            Err(DecodeError::NonCanonicalKeyOrder)
+        );
+        assert_eq!(
+            strict_basic_decode::<BTreeSet<u16>>(&sorted_set),
+            Ok([1u16, 2u16].into_iter().collect())
+        );
+        // Unordered and insertion-ordered sets aren't affected
+        assert!(strict_basic_decode::<IndexSet<u16>>(&unsorted_set).is_ok());
+        assert!(strict_basic_decode::<HashSet<u16>>(&unsorted_set).is_ok());
+
+        let mut map = index_map_new();
+        map.insert(2u16, "b".to_string());
+        map.insert(1u16, "a".to_string());
+        let unsorted_map = basic_encode(&map).unwrap();
+        assert!(basic_decode::<BTreeMap<u16, String>>(&unsorted_map).is_ok());
+        assert_eq!(
+            strict_basic_decode::<BTreeMap<u16, String>>(&unsorted_map),
+            Err(DecodeError::NonCanonicalKeyOrder)
+        );
+        let sorted_map = basic_encode(&map.into_iter().collect::<BTreeMap<_, _>>()).unwrap();
+        assert!(strict_basic_decode::<BTreeMap<u16, String>>(&sorted_map).is_ok());
+
+        // Duplicates are still reported as such
+        let duplicates = basic_encode(&vec![1u16, 1u16]).unwrap();
+        assert_eq!(
+            strict_basic_decode::<BTreeSet<u16>>(&duplicates),
+            Err(DecodeError::DuplicateKey)
+        );
+    }
+
+    #[test]
+    pub fn test_redundant_size_encodings_are_rejected() {
+        // 1 encoded in two bytes
+        let mut dec = BasicDecoder::new_strict(&[0x81, 0x00], 256);
+        assert_eq!(dec.read_size(), Err(DecodeError::InvalidSize));
+        let mut dec = BasicDecoder::new(&[0x81, 0x00], 256);
+        assert_eq!(dec.read_size(), Err(DecodeError::InvalidSize));
+        // 0 encoded in two bytes
+        let mut dec = BasicDecoder::new(&[0x80, 0x00], 256);
+        assert_eq!(dec.read_size(), Err(DecodeError::InvalidSize));
+    }
+
     #[derive(sbor::Categorize, sbor::Encode, sbor::Decode, PartialEq, Eq, Debug)]
     struct NFA {
         a: [u8; 32],
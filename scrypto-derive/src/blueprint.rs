@@ -331,6 +331,36 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         const_statements
     };
 
+    // The exported state method is generated before the schema, so that it's exposed like any
+    // other method of the blueprint
+    let exportable_state = blueprint
+        .attributes
+        .iter()
+        .any(|attribute| attribute.path.is_ident("exportable_state"));
+    let exported_state_struct = if exportable_state {
+        let (exported_state_struct, export_state_method) =
+            generate_exported_state(bp_ident, bp_fields)?;
+        bp_items.push(export_state_method);
+        exported_state_struct
+    } else {
+        quote! {}
+    };
+
+    // Likewise for the component config methods
+    let component_config_field = blueprint
+        .attributes
+        .iter()
+        .find(|attribute| attribute.path.is_ident("component_config"))
+        .map(|attribute| attribute.parse_args::<Ident>())
+        .transpose()?;
+    let component_config_event_struct = if let Some(config_field) = &component_config_field {
+        let (event_struct, config_methods) = generate_component_config(bp_fields, config_field)?;
+        bp_items.extend(config_methods);
+        event_struct
+    } else {
+        quote! {}
+    };
+
     let generated_schema_info = generate_schema(bp_ident, bp_items, &mut dependency_exprs)?;
     let fn_idents = generated_schema_info.fn_idents;
     let method_idents = generated_schema_info.method_idents;
@@ -355,6 +385,66 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
             quote! {
                 #auth_macro
             }
+        } else if exportable_state || component_config_field.is_some() {
+            let export_state_statements = if exportable_state {
+                quote! {
+                    methods.insert(
+                        MethodKey::new("export_state"),
+                        method_accessibility!(restrict_to: [OWNER]),
+                    );
+                }
+            } else {
+                quote! {}
+            };
+            let (role_statements, config_statements) = if component_config_field.is_some() {
+                (
+                    quote! {
+                        pub struct MethodRoles<T> {
+                            config_admin: T,
+                        }
+
+                        impl<T> MethodRoles<T> {
+                            fn list(self) -> Vec<(&'static str, T)> {
+                                vec![("config_admin", self.config_admin)]
+                            }
+                        }
+
+                        const ROLE_STRINGS: MethodRoles<&str> = MethodRoles {
+                            config_admin: "config_admin",
+                        };
+                    },
+                    quote! {
+                        methods.insert(
+                            MethodKey::new("set_config"),
+                            method_accessibility!(restrict_to: [config_admin]),
+                        );
+                        internal_add_role!(roles, config_admin => updatable_by: [OWNER]);
+                    },
+                )
+            } else {
+                (quote! {}, quote! {})
+            };
+            quote! {
+                #role_statements
+
+                fn method_auth_template() -> scrypto::blueprints::package::MethodAuthTemplate {
+                    let mut methods: BTreeMap<MethodKey, MethodAccessibility> = BTreeMap::new();
+                    for method in Methods::<MethodAccessibility>::methods() {
+                        methods.insert(MethodKey::new(method), MethodAccessibility::Public);
+                    }
+                    #[allow(unused_mut)]
+                    let mut roles: BTreeMap<RoleKey, RoleList> = BTreeMap::new();
+                    #export_state_statements
+                    #config_statements
+
+                    scrypto::blueprints::package::MethodAuthTemplate::StaticRoles(
+                        scrypto::blueprints::package::StaticRoles {
+                            methods,
+                            roles: scrypto::blueprints::package::RoleSpecification::Normal(roles),
+                        },
+                    )
+                }
+            }
         } else {
             quote! {
                 fn method_auth_template() -> scrypto::blueprints::package::MethodAuthTemplate {
@@ -524,6 +614,15 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     }
                 }
             }
+            if component_config_field.is_some() {
+                let path: Path = parse_quote! { ConfigUpdatedEvent };
+                if let Some(..) = paths.insert("ConfigUpdatedEvent".to_owned(), path.clone()) {
+                    return Err(Error::new(
+                        path.span(),
+                        "`ConfigUpdatedEvent` is generated by `component_config` and can't be registered",
+                    ));
+                }
+            }
             (
                 paths.keys().into_iter().cloned().collect::<Vec<_>>(),
                 paths.values().into_iter().cloned().collect::<Vec<_>>(),
@@ -679,6 +778,10 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
             #output_original_code
 
+            #exported_state_struct
+
+            #component_config_event_struct
+
             #methods_struct
 
             #functions_struct
@@ -700,6 +803,130 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     Ok(output)
 }
 
+/// Generates the `<Blueprint>ExportedState` struct and the `export_state` method returning it.
+///
+/// The exported state is a copy of the component state, except that vaults are summarized by
+/// their resource and amount, and key value stores by their node id, as owned objects can't be
+/// returned by a method.
+fn generate_exported_state(
+    bp_ident: &Ident,
+    bp_fields: &Fields,
+) -> Result<(TokenStream, ImplItem)> {
+    let exported_state_ident = format_ident!("{}ExportedState", bp_ident);
+
+    let named_fields = match bp_fields {
+        Fields::Named(named_fields) => named_fields,
+        Fields::Unit => {
+            return Ok((
+                quote! {
+                    #[derive(::scrypto::prelude::ScryptoSbor)]
+                    pub struct #exported_state_ident {}
+                },
+                parse_quote! {
+                    pub fn export_state(&self) -> #exported_state_ident {
+                        #exported_state_ident {}
+                    }
+                },
+            ))
+        }
+        Fields::Unnamed(_) => {
+            return Err(Error::new(
+                bp_fields.span(),
+                "`exportable_state` requires a struct with named fields",
+            ))
+        }
+    };
+
+    let mut field_idents = Vec::new();
+    let mut field_types = Vec::<Type>::new();
+    let mut field_exprs = Vec::<Expr>::new();
+    for field in &named_fields.named {
+        let ident = field.ident.clone().unwrap();
+        let ty = &field.ty;
+        let type_ident = match ty {
+            Type::Path(p) => p.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        };
+        match type_ident.as_deref() {
+            Some("Vault") | Some("FungibleVault") | Some("NonFungibleVault") => {
+                field_types.push(parse_quote! { ::scrypto::prelude::VaultSummary });
+                field_exprs
+                    .push(parse_quote! { ::scrypto::prelude::VaultSummary::of(&self.#ident) });
+            }
+            Some("KeyValueStore") => {
+                field_types.push(parse_quote! { ::scrypto::prelude::NodeId });
+                field_exprs.push(parse_quote! { self.#ident.id.0 });
+            }
+            _ => {
+                field_types.push(ty.clone());
+                field_exprs.push(parse_quote! { self.#ident.clone() });
+            }
+        }
+        field_idents.push(ident);
+    }
+
+    let exported_state_struct = quote! {
+        #[derive(::scrypto::prelude::ScryptoSbor)]
+        pub struct #exported_state_ident {
+            #(pub #field_idents: #field_types,)*
+        }
+    };
+    let export_state_method = parse_quote! {
+        pub fn export_state(&self) -> #exported_state_ident {
+            #exported_state_ident {
+                #(#field_idents: #field_exprs,)*
+            }
+        }
+    };
+
+    Ok((exported_state_struct, export_state_method))
+}
+
+/// Generates the `get_config` and `set_config` methods exposing a field of the component state as
+/// its configuration, and the `ConfigUpdatedEvent` emitted on every update.
+fn generate_component_config(
+    bp_fields: &Fields,
+    config_field: &Ident,
+) -> Result<(TokenStream, Vec<ImplItem>)> {
+    let config_type = match bp_fields {
+        Fields::Named(named_fields) => named_fields
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(config_field))
+            .map(|field| field.ty.clone()),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::new(
+            config_field.span(),
+            format!(
+                "`component_config` field `{}` not found in the component state",
+                config_field
+            ),
+        )
+    })?;
+
+    let event_struct = quote! {
+        #[derive(::scrypto::prelude::ScryptoSbor, ::scrypto::prelude::ScryptoEvent)]
+        pub struct ConfigUpdatedEvent {
+            pub config: #config_type,
+        }
+    };
+    let get_config = parse_quote! {
+        pub fn get_config(&self) -> #config_type {
+            self.#config_field.clone()
+        }
+    };
+    let set_config = parse_quote! {
+        pub fn set_config(&mut self, config: #config_type) {
+            self.#config_field = config.clone();
+            ::scrypto::prelude::Runtime::emit_event(ConfigUpdatedEvent { config });
+        }
+    };
+
+    Ok((event_struct, vec![get_config, set_config]))
+}
+
 fn generate_methods_struct(method_idents: Vec<Ident>) -> TokenStream {
     let method_names: Vec<String> = method_idents.iter().map(|i| i.to_string()).collect();
 
@@ -1276,6 +1503,24 @@ mod tests {
         assert!(matches!(handle_blueprint(input), Err(_)));
     }
 
+    #[test]
+    fn test_component_config_with_unknown_field_should_fail() {
+        let input = TokenStream::from_str(
+            "#[component_config(settings)] mod test { struct Test { fee: u32 } impl Test { } }",
+        )
+        .unwrap();
+        assert!(handle_blueprint(input).is_err());
+    }
+
+    #[test]
+    fn test_exportable_state_with_unnamed_fields_should_fail() {
+        let input = TokenStream::from_str(
+            "#[exportable_state] mod test { struct Test(u32); impl Test { } }",
+        )
+        .unwrap();
+        assert!(matches!(handle_blueprint(input), Err(_)));
+    }
+
     #[test]
     fn test_blueprint() {
         let input = TokenStream::from_str(
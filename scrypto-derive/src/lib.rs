@@ -40,6 +40,25 @@ use proc_macro::TokenStream;
 ///     }
 /// }
 /// ```
+///
+/// # Exportable state
+/// With `#[exportable_state]` on the module, an `export_state` method is generated, returning a
+/// `<Blueprint>ExportedState` copy of the component state where vaults are replaced by a
+/// `VaultSummary` and key value stores by their node id. Other fields must implement `Clone`,
+/// and owned objects nested in them aren't supported.
+///
+/// The method is restricted to the owner of the component, and all other methods are public. If
+/// `enable_method_auth!` is used instead, `export_state` must be listed there like any method.
+///
+/// # Component config
+/// With `#[component_config(<field>)]` on the module, the given field of the component state is
+/// exposed as the component configuration, through a public `get_config` method and a
+/// `set_config` method emitting a `ConfigUpdatedEvent` with the new value. The field type must
+/// implement `Clone`.
+///
+/// `set_config` is restricted to the `config_admin` role, which falls back to the owner role
+/// unless set when globalizing, and all other methods are public. If `enable_method_auth!` is
+/// used instead, both methods must be listed there like any method.
 #[proc_macro_attribute]
 pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
     blueprint::handle_blueprint(proc_macro2::TokenStream::from(input))
@@ -65,3 +65,28 @@ mod empty_with_use_super {
 
     impl EmptyWithUseSuper {}
 }
+
+#[blueprint]
+#[exportable_state]
+mod exportable {
+    struct Exportable {
+        count: u32,
+        tokens: Vault,
+        fungible_tokens: FungibleVault,
+        records: KeyValueStore<u32, String>,
+    }
+
+    impl Exportable {
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn exported_count(&self) -> u32 {
+            let state: ExportableExportedState = self.export_state();
+            let _: VaultSummary = state.tokens;
+            let _: VaultSummary = state.fungible_tokens;
+            let _: NodeId = state.records;
+            state.count
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::Compile;
+use radix_engine::types::*;
+use sbor::representations::*;
+use std::fs;
+use std::path::Path;
+
+/// Set this environment variable to overwrite ABI snapshots with the current ABI, rather than
+/// asserting against them.
+pub const UPDATE_ABI_SNAPSHOTS_ENV_VAR: &str = "UPDATE_ABI_SNAPSHOTS";
+
+const DIFF_CONTEXT_LINES: usize = 3;
+const MAX_DIFF_CELLS: usize = 16_000_000;
+
+/// Asserts that the ABI of the blueprint crate in the current package matches a checked-in
+/// JSON snapshot, at a path relative to the crate root.
+///
+/// ```ignore
+/// #[test]
+/// fn abi_is_unchanged() {
+///     assert_abi_snapshot!("abi/expected.json");
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_abi_snapshot {
+    ($snapshot_path: expr) => {
+        $crate::assert_abi_snapshot_matches(
+            env!("CARGO_MANIFEST_DIR"),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($snapshot_path),
+        )
+    };
+}
+
+/// Compiles the package and compares its ABI against the snapshot at `snapshot_path`, panicking
+/// with a line diff on mismatch.
+///
+/// A missing snapshot is written and accepted, as is any snapshot when the
+/// [`UPDATE_ABI_SNAPSHOTS_ENV_VAR`] environment variable is set.
+pub fn assert_abi_snapshot_matches<P: AsRef<Path>, S: AsRef<Path>>(
+    package_dir: P,
+    snapshot_path: S,
+) {
+    let (_, definition) = Compile::compile(package_dir);
+    let actual = package_definition_to_json(&definition);
+    let snapshot_path = snapshot_path.as_ref();
+
+    if !snapshot_path.exists() || std::env::var_os(UPDATE_ABI_SNAPSHOTS_ENV_VAR).is_some() {
+        if let Some(parent) = snapshot_path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create ABI snapshot directory");
+        }
+        fs::write(snapshot_path, &actual).expect("Failed to write ABI snapshot");
+        return;
+    }
+
+    let expected = fs::read_to_string(snapshot_path).expect("Failed to read ABI snapshot");
+    if expected != actual {
+        panic!(
+            "ABI snapshot mismatch for {:?} (set {} to update it):\n{}",
+            snapshot_path,
+            UPDATE_ABI_SNAPSHOTS_ENV_VAR,
+            diff_lines(&expected, &actual)
+        );
+    }
+}
+
+/// Renders a package definition as pretty-printed JSON, annotated with the type and field names
+/// of its schema.
+pub fn package_definition_to_json(definition: &PackageDefinition) -> String {
+    let (type_index, schema) =
+        generate_full_schema_from_single_type::<PackageDefinition, ScryptoCustomSchema>();
+    let payload = scrypto_encode(definition).unwrap();
+    let address_bech32_encoder = AddressBech32Encoder::for_simulator();
+
+    let mut json = serde_json::to_string_pretty(
+        &ScryptoRawPayload::new_from_valid_slice(&payload).serializable(
+            SerializationParameters::WithSchema {
+                mode: SerializationMode::Programmatic,
+                custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                    &address_bech32_encoder,
+                )),
+                schema: &schema,
+                type_index,
+            },
+        ),
+    )
+    .unwrap();
+    json.push('\n');
+    json
+}
+
+/// A unified line diff of `expected` against `actual`, showing the changed lines with a few
+/// lines of context.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let prefix = expected
+        .iter()
+        .zip(actual.iter())
+        .take_while(|(e, a)| e == a)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(e, a)| e == a)
+        .count();
+    let expected_changed = &expected[prefix..expected.len() - suffix];
+    let actual_changed = &actual[prefix..actual.len() - suffix];
+
+    let mut output = String::new();
+    let context_start = prefix.saturating_sub(DIFF_CONTEXT_LINES);
+    for line in &expected[context_start..prefix] {
+        output.push_str(&format!("  {}\n", line));
+    }
+    for (tag, line) in diff_changed_lines(expected_changed, actual_changed) {
+        output.push_str(&format!("{} {}\n", tag, line));
+    }
+    let context_end = (expected.len() - suffix + DIFF_CONTEXT_LINES).min(expected.len());
+    for line in &expected[expected.len() - suffix..context_end] {
+        output.push_str(&format!("  {}\n", line));
+    }
+    output
+}
+
+/// Diffs the lines using their longest common subsequence, or reports all lines as replaced if
+/// they are too many to diff.
+fn diff_changed_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<(char, &'a str)> {
+    if expected.len() * actual.len() > MAX_DIFF_CELLS {
+        return expected
+            .iter()
+            .map(|line| ('-', *line))
+            .chain(actual.iter().map(|line| ('+', *line)))
+            .collect();
+    }
+
+    // lcs[i][j] is the length of the longest common subsequence of expected[i..] and actual[j..]
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push((' ', expected[i]));
+            i += 1;
+            j += 1;
+        } else if j < actual.len() && (i == expected.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            lines.push(('+', actual[j]));
+            j += 1;
+        } else {
+            lines.push(('-', expected[i]));
+            i += 1;
+        }
+    }
+    lines
+}
@@ -0,0 +1,218 @@
+use crate::TestRunner;
+use radix_engine::transaction::{TransactionReceipt, TransactionResult, TransactionResultKind};
+use radix_engine::types::*;
+use radix_engine_store_interface::interface::DatabaseUpdate;
+use transaction::prelude::*;
+
+/// A view of a transaction receipt which doesn't depend on the engine version's types, so that
+/// receipts of different engine builds can be compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparableReceipt {
+    pub result: TransactionResultKind,
+    /// The debug rendering of the rejection, abortion or failure reason, if any
+    pub error: Option<String>,
+    pub execution_cost_units: u32,
+    /// The debug rendering of each event type identifier, with the event data
+    pub events: Vec<(String, Vec<u8>)>,
+    /// The raw value of each substate set (`Some`) or deleted (`None`), by partition and sort key
+    pub state_updates: BTreeMap<(Vec<u8>, Vec<u8>), Option<Vec<u8>>>,
+}
+
+impl ComparableReceipt {
+    pub fn from_receipt(receipt: &TransactionReceipt) -> Self {
+        let result = TransactionResultKind::of(&receipt.transaction_result);
+        match &receipt.transaction_result {
+            TransactionResult::Commit(commit) => {
+                let mut state_updates = BTreeMap::new();
+                for (partition_key, partition_updates) in &commit.state_updates.database_updates {
+                    for (sort_key, update) in partition_updates {
+                        let value = match update {
+                            DatabaseUpdate::Set(value) => Some(value.clone()),
+                            DatabaseUpdate::Delete => None,
+                        };
+                        state_updates.insert((partition_key.0.clone(), sort_key.0.clone()), value);
+                    }
+                }
+                Self {
+                    result,
+                    error: match &commit.outcome {
+                        TransactionOutcome::Success(_) => None,
+                        TransactionOutcome::Failure(error) => Some(format!("{:?}", error)),
+                    },
+                    execution_cost_units: commit.fee_summary.execution_cost_sum,
+                    events: commit
+                        .application_events
+                        .iter()
+                        .map(|(event_type, data)| (format!("{:?}", event_type), data.clone()))
+                        .collect(),
+                    state_updates,
+                }
+            }
+            TransactionResult::Reject(reject) => Self {
+                result,
+                error: Some(format!("{:?}", reject.error)),
+                execution_cost_units: 0,
+                events: Vec::new(),
+                state_updates: BTreeMap::new(),
+            },
+            TransactionResult::Abort(abort) => Self {
+                result,
+                error: Some(format!("{:?}", abort.reason)),
+                execution_cost_units: 0,
+                events: Vec::new(),
+                state_updates: BTreeMap::new(),
+            },
+        }
+    }
+}
+
+/// An engine build which executes and commits transactions of type `T`.
+///
+/// To compare against a previous release, depend on it under a renamed crate (or behind a
+/// feature), and implement this trait for a wrapper of its test runner which converts the
+/// receipts of that release into [`ComparableReceipt`]s.
+pub trait DifferentialEngine<T> {
+    fn execute_and_commit(&mut self, transaction: &T) -> ComparableReceipt;
+}
+
+/// A manifest with the initial proofs it's executed with.
+pub type DifferentialManifest = (TransactionManifestV1, Vec<NonFungibleGlobalId>);
+
+impl DifferentialEngine<DifferentialManifest> for TestRunner {
+    fn execute_and_commit(&mut self, transaction: &DifferentialManifest) -> ComparableReceipt {
+        let (manifest, initial_proofs) = transaction;
+        let receipt = self.execute_manifest(manifest.clone(), initial_proofs.clone());
+        ComparableReceipt::from_receipt(&receipt)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReceiptDivergence {
+    Result {
+        a: TransactionResultKind,
+        b: TransactionResultKind,
+    },
+    Error {
+        a: Option<String>,
+        b: Option<String>,
+    },
+    ExecutionCostUnits {
+        a: u32,
+        b: u32,
+    },
+    Events {
+        a: Vec<(String, Vec<u8>)>,
+        b: Vec<(String, Vec<u8>)>,
+    },
+    /// A substate which was updated differently, where `None` means it wasn't updated at all
+    StateUpdate {
+        partition_key: Vec<u8>,
+        sort_key: Vec<u8>,
+        a: Option<Option<Vec<u8>>>,
+        b: Option<Option<Vec<u8>>>,
+    },
+}
+
+impl ReceiptDivergence {
+    pub fn between(a: &ComparableReceipt, b: &ComparableReceipt) -> Vec<Self> {
+        let mut divergences = Vec::new();
+        if a.result != b.result {
+            divergences.push(Self::Result {
+                a: a.result,
+                b: b.result,
+            });
+        }
+        if a.error != b.error {
+            divergences.push(Self::Error {
+                a: a.error.clone(),
+                b: b.error.clone(),
+            });
+        }
+        if a.execution_cost_units != b.execution_cost_units {
+            divergences.push(Self::ExecutionCostUnits {
+                a: a.execution_cost_units,
+                b: b.execution_cost_units,
+            });
+        }
+        if a.events != b.events {
+            divergences.push(Self::Events {
+                a: a.events.clone(),
+                b: b.events.clone(),
+            });
+        }
+        let keys: BTreeSet<&(Vec<u8>, Vec<u8>)> = a
+            .state_updates
+            .keys()
+            .chain(b.state_updates.keys())
+            .collect();
+        for key in keys {
+            let update_a = a.state_updates.get(key);
+            let update_b = b.state_updates.get(key);
+            if update_a != update_b {
+                divergences.push(Self::StateUpdate {
+                    partition_key: key.0.clone(),
+                    sort_key: key.1.clone(),
+                    a: update_a.cloned(),
+                    b: update_b.cloned(),
+                });
+            }
+        }
+        divergences
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DifferentialReport {
+    pub transaction_count: usize,
+    /// The divergences of each diverging transaction, by its index in the stream
+    pub divergences: BTreeMap<usize, Vec<ReceiptDivergence>>,
+}
+
+impl DifferentialReport {
+    pub fn is_compatible(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// Executes the same transaction stream against two engine builds, which should start from the
+/// same state, and reports where their receipts diverge.
+///
+/// Both engines commit every transaction, so once a transaction diverges in its state updates,
+/// later divergences may be a consequence of it.
+pub struct DifferentialHarness<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> DifferentialHarness<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    pub fn execute<T>(&mut self, transaction: &T) -> Vec<ReceiptDivergence>
+    where
+        A: DifferentialEngine<T>,
+        B: DifferentialEngine<T>,
+    {
+        let receipt_a = self.a.execute_and_commit(transaction);
+        let receipt_b = self.b.execute_and_commit(transaction);
+        ReceiptDivergence::between(&receipt_a, &receipt_b)
+    }
+
+    pub fn run<T, I>(&mut self, transactions: I) -> DifferentialReport
+    where
+        A: DifferentialEngine<T>,
+        B: DifferentialEngine<T>,
+        I: IntoIterator<Item = T>,
+    {
+        let mut report = DifferentialReport::default();
+        for (index, transaction) in transactions.into_iter().enumerate() {
+            let divergences = self.execute(&transaction);
+            if !divergences.is_empty() {
+                report.divergences.insert(index, divergences);
+            }
+            report.transaction_count += 1;
+        }
+        report
+    }
+}
@@ -1,9 +1,17 @@
+mod abi_snapshot;
 #[cfg(feature = "rocksdb")]
 mod basic_rocksdb_test_runner;
+mod differential;
+mod package_stubs;
 mod test_runner;
+mod test_scaffold;
 mod utils;
 
 pub use crate::utils::*;
+pub use abi_snapshot::*;
 #[cfg(feature = "rocksdb")]
 pub use basic_rocksdb_test_runner::*;
+pub use differential::*;
+pub use package_stubs::*;
 pub use test_runner::*;
+pub use test_scaffold::*;
@@ -0,0 +1,54 @@
+use radix_engine::types::*;
+use radix_engine::vm::{PackageStubs, StubbedCall};
+use std::cell::RefCell;
+
+/// A Rust implementation of a package, which returns the output of each call made to it, or a
+/// message with which the call panics.
+pub type PackageStub = Box<dyn Fn(&StubbedCall) -> Result<IndexedScryptoValue, String>>;
+
+/// The package stubs of a test runner, which records every call made to them.
+#[derive(Default)]
+pub struct TestPackageStubs {
+    stubs: BTreeMap<PackageAddress, PackageStub>,
+    calls: RefCell<Vec<StubbedCall>>,
+}
+
+impl TestPackageStubs {
+    pub fn is_empty(&self) -> bool {
+        self.stubs.is_empty()
+    }
+
+    pub fn insert(&mut self, package_address: PackageAddress, stub: PackageStub) {
+        self.stubs.insert(package_address, stub);
+    }
+
+    pub fn remove(&mut self, package_address: &PackageAddress) {
+        self.stubs.remove(package_address);
+    }
+
+    /// The calls made to the stubs so far, in the order they were made, including those of
+    /// transactions which were rejected or failed.
+    pub fn calls(&self) -> Vec<StubbedCall> {
+        self.calls.borrow().clone()
+    }
+
+    pub fn clear_calls(&mut self) {
+        self.calls.borrow_mut().clear();
+    }
+}
+
+impl PackageStubs for TestPackageStubs {
+    fn is_stubbed(&self, package_address: &PackageAddress) -> bool {
+        self.stubs.contains_key(package_address)
+    }
+
+    fn invoke(&self, call: StubbedCall) -> Result<IndexedScryptoValue, String> {
+        let stub = self
+            .stubs
+            .get(&call.package_address)
+            .expect("Package is not stubbed");
+        let output = stub(&call);
+        self.calls.borrow_mut().push(call);
+        output
+    }
+}
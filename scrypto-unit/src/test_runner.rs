@@ -2,41 +2,67 @@ use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
+use crate::TestPackageStubs;
 use radix_engine::blueprints::consensus_manager::*;
 use radix_engine::errors::*;
 use radix_engine::system::bootstrap::*;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
 use radix_engine::system::system::KeyValueEntrySubstate;
+use radix_engine::system::system_modules::costing::RoyaltyRecipient;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction, CommitResult, ExecutionConfig, FeeReserveConfig,
-    PreviewError, TransactionReceipt, TransactionResult,
+    execute_preview, execute_transaction, execute_transaction_with_package_stubs,
+    review_transaction, CommitResult, ExecutionConfig, FeeReserveConfig, PreviewError,
+    TransactionReceipt, TransactionReceiptDisplayContextBuilder, TransactionResult,
+    TransactionReview, TransactionReviewConfig,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
-use radix_engine::vm::ScryptoVm;
+use radix_engine::vm::wasm::{
+    DefaultWasmEngine, DirectoryWasmInstrumentationCache, WasmValidatorConfigV1,
+};
+use radix_engine::vm::{ScryptoVm, StubbedCall};
 use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
 use radix_engine_interface::api::node_modules::auth::*;
-use radix_engine_interface::api::node_modules::royalty::ComponentRoyaltySubstate;
+use radix_engine_interface::api::node_modules::royalty::{
+    ComponentRent, ComponentRentSubstate, ComponentRoyaltySubstate,
+};
 use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::access_controller::{
+    AccessControllerCreateProofInput, AccessControllerInitiateRecoveryAsRecoveryInput,
+    AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput,
+    AccessControllerTimedConfirmRecoveryInput, Role, RuleSet, ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+    ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+    ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+    ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+    ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+};
+use radix_engine_interface::blueprints::account::{AccountSecurifyInput, ACCOUNT_SECURIFY_IDENT};
 use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerConfig, ConsensusManagerGetCurrentEpochInput,
     ConsensusManagerGetCurrentTimeInput, ConsensusManagerNextRoundInput, EpochChangeCondition,
-    LeaderProposalHistory, TimePrecision, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
-    CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT, CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
+    EpochStartRecord, LeaderProposalHistory, TimePrecision,
+    CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT, CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+    CONSENSUS_MANAGER_NEXT_ROUND_IDENT,
 };
 use radix_engine_interface::blueprints::package::{
     BlueprintDefinitionInit, PackageDefinition, PackagePublishWasmAdvancedManifestInput,
     PackageRoyaltyAccumulatorSubstate, TypePointer, PACKAGE_BLUEPRINT,
     PACKAGE_PUBLISH_WASM_ADVANCED_IDENT, PACKAGE_SCHEMAS_PARTITION_OFFSET,
 };
+use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_interface::constants::CONSENSUS_MANAGER;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_interface::time::Instant;
 use radix_engine_interface::{dec, freeze_roles, rule};
-use radix_engine_queries::query::{ResourceAccounter, StateTreeTraverser, VaultFinder};
+use radix_engine_queries::query::{
+    AuthRequirements, AuthRequirementsAnalyzer, EntityFootprint, OwnershipGraph, ResourceAccounter,
+    StateQuery, StateQueryEngine, StateQueryResult, StateTreeTraverser, VaultFinder,
+    DEFAULT_LARGEST_SUBSTATES,
+};
 use radix_engine_queries::typed_substate_layout::{
     BlueprintDefinition, BlueprintVersionKey, PACKAGE_BLUEPRINTS_PARTITION_OFFSET,
 };
@@ -48,15 +74,21 @@ use radix_engine_store_interface::{
     },
     interface::{CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates},
 };
+use radix_engine_stores::cached_db::{CacheMetrics, CacheWriteMode, CachedSubstateStore};
 use radix_engine_stores::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
 use radix_engine_stores::hash_tree::{put_at_next_version, SubstateHashChange};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use rand::RngCore;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use sbor::representations::{DisplayMode, PrintMode, ValueDisplayParameters};
 use scrypto::prelude::*;
 use transaction::prelude::*;
 use transaction::signing::secp256k1::Secp256k1PrivateKey;
 use transaction::validation::{
     NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
 };
+use utils::ContextualDisplay;
 
 pub struct Compile;
 
@@ -234,10 +266,156 @@ impl CustomGenesis {
     }
 }
 
+/// The environment variable consulted by [`TestRunnerBuilder::with_random_seed`], allowing a
+/// failing randomized test to be replayed with the seed it printed.
+pub const TEST_RUNNER_SEED_ENV_VAR: &str = "TEST_RUNNER_SEED";
+
+/// A seeded source of randomness, backing the key and nonce choices of a [`TestRunner`].
+#[derive(Clone)]
+struct TestRunnerRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl TestRunnerRng {
+    fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    fn next_private_key(&mut self) -> u64 {
+        // Note that 0 is not a valid private key
+        loop {
+            let key = self.rng.next_u64();
+            if key != 0 {
+                return key;
+            }
+        }
+    }
+
+    fn next_transaction_nonce(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+}
+
+/// Protocol parameters which deviate from the defaults.
+///
+/// Like a protocol update, a change applies to every transaction executed from then on, taking
+/// precedence over the configuration a transaction is otherwise executed with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProtocolParameters {
+    pub cost_unit_price: Option<Decimal>,
+    pub usd_price: Option<Decimal>,
+    pub cost_unit_limit: Option<u32>,
+    pub max_call_depth: Option<usize>,
+    pub max_substate_size: Option<usize>,
+    pub max_invoke_input_size: Option<usize>,
+    pub max_number_of_events: Option<usize>,
+    pub max_number_of_logs: Option<usize>,
+    pub max_per_function_royalty_in_xrd: Option<Decimal>,
+    pub max_per_call_royalty_in_xrd: Option<Decimal>,
+}
+
+impl ProtocolParameters {
+    pub fn with_cost_unit_price(mut self, cost_unit_price: Decimal) -> Self {
+        self.cost_unit_price = Some(cost_unit_price);
+        self
+    }
+
+    pub fn with_usd_price(mut self, usd_price: Decimal) -> Self {
+        self.usd_price = Some(usd_price);
+        self
+    }
+
+    pub fn with_cost_unit_limit(mut self, cost_unit_limit: u32) -> Self {
+        self.cost_unit_limit = Some(cost_unit_limit);
+        self
+    }
+
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = Some(max_call_depth);
+        self
+    }
+
+    pub fn with_max_substate_size(mut self, max_substate_size: usize) -> Self {
+        self.max_substate_size = Some(max_substate_size);
+        self
+    }
+
+    pub fn with_max_invoke_input_size(mut self, max_invoke_input_size: usize) -> Self {
+        self.max_invoke_input_size = Some(max_invoke_input_size);
+        self
+    }
+
+    pub fn with_max_number_of_events(mut self, max_number_of_events: usize) -> Self {
+        self.max_number_of_events = Some(max_number_of_events);
+        self
+    }
+
+    pub fn with_max_number_of_logs(mut self, max_number_of_logs: usize) -> Self {
+        self.max_number_of_logs = Some(max_number_of_logs);
+        self
+    }
+
+    pub fn with_max_per_function_royalty_in_xrd(mut self, max_royalty: Decimal) -> Self {
+        self.max_per_function_royalty_in_xrd = Some(max_royalty);
+        self
+    }
+
+    pub fn with_max_per_call_royalty_in_xrd(mut self, max_royalty: Decimal) -> Self {
+        self.max_per_call_royalty_in_xrd = Some(max_royalty);
+        self
+    }
+
+    fn apply(
+        &self,
+        fee_reserve_config: &mut FeeReserveConfig,
+        execution_config: &mut ExecutionConfig,
+    ) {
+        if let Some(cost_unit_price) = self.cost_unit_price {
+            fee_reserve_config.cost_unit_price = cost_unit_price;
+        }
+        if let Some(usd_price) = self.usd_price {
+            fee_reserve_config.usd_price = usd_price;
+        }
+        if let Some(cost_unit_limit) = self.cost_unit_limit {
+            execution_config.cost_unit_limit = cost_unit_limit;
+        }
+        if let Some(max_call_depth) = self.max_call_depth {
+            execution_config.max_call_depth = max_call_depth;
+        }
+        if let Some(max_substate_size) = self.max_substate_size {
+            execution_config.max_substate_size = max_substate_size;
+        }
+        if let Some(max_invoke_input_size) = self.max_invoke_input_size {
+            execution_config.max_invoke_input_size = max_invoke_input_size;
+        }
+        if let Some(max_number_of_events) = self.max_number_of_events {
+            execution_config.max_number_of_events = max_number_of_events;
+        }
+        if let Some(max_number_of_logs) = self.max_number_of_logs {
+            execution_config.max_number_of_logs = max_number_of_logs;
+        }
+        if let Some(max_royalty) = self.max_per_function_royalty_in_xrd {
+            execution_config.max_per_function_royalty_in_xrd = max_royalty;
+        }
+        if let Some(max_royalty) = self.max_per_call_royalty_in_xrd {
+            execution_config.max_per_call_royalty_in_xrd = max_royalty;
+        }
+    }
+}
+
 pub struct TestRunnerBuilder {
     custom_genesis: Option<CustomGenesis>,
     trace: bool,
     state_hashing: bool,
+    invocation_filter: bool,
+    seed: Option<u64>,
+    substate_cache_size: Option<usize>,
+    protocol_parameters: ProtocolParameters,
+    wasm_instrumentation_cache_dir: Option<PathBuf>,
 }
 
 impl TestRunnerBuilder {
@@ -251,15 +429,66 @@ impl TestRunnerBuilder {
         self
     }
 
+    /// Enables the invocation filter system module for all transactions executed by the runner.
+    pub fn with_invocation_filter(mut self) -> Self {
+        self.invocation_filter = true;
+        self
+    }
+
+    /// Caches up to the given number of substates across the transactions executed by the runner.
+    pub fn with_substate_cache(mut self, capacity: usize) -> Self {
+        self.substate_cache_size = Some(capacity);
+        self
+    }
+
+    /// Reuses the instrumentation of published packages across test runners and test runs, by
+    /// caching it in the given directory.
+    pub fn with_wasm_instrumentation_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.wasm_instrumentation_cache_dir = Some(dir.into());
+        self
+    }
+
     pub fn with_custom_genesis(mut self, genesis: CustomGenesis) -> Self {
         self.custom_genesis = Some(genesis);
         self
     }
 
+    /// Executes all transactions after genesis with the given protocol parameters.
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.protocol_parameters = protocol_parameters;
+        self
+    }
+
+    /// Makes all key pairs and transaction nonces chosen by the test runner derive from the given
+    /// seed, so that a randomized test is fully reproducible. The seed is printed if the test
+    /// panics.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Like [`Self::with_seed`], but uses the seed from the [`TEST_RUNNER_SEED_ENV_VAR`]
+    /// environment variable if set, or a freshly generated one otherwise.
+    pub fn with_random_seed(self) -> Self {
+        let seed = match std::env::var(TEST_RUNNER_SEED_ENV_VAR) {
+            Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+                panic!(
+                    "{} must be an unsigned 64-bit integer, got {:?}",
+                    TEST_RUNNER_SEED_ENV_VAR, value
+                )
+            }),
+            Err(_) => rand::random(),
+        };
+        self.with_seed(seed)
+    }
+
     pub fn build_and_get_epoch(self) -> (TestRunner, ActiveValidatorSet) {
         let scrypto_interpreter = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            wasm_instrumentation_cache: self
+                .wasm_instrumentation_cache_dir
+                .map(|dir| Arc::new(DirectoryWasmInstrumentationCache::new(dir)) as Arc<_>),
         };
         let mut substate_db = InMemorySubstateDatabase::standard();
 
@@ -288,13 +517,21 @@ impl TestRunnerBuilder {
 
         let runner = TestRunner {
             scrypto_interpreter,
-            substate_db,
+            substate_db: CachedSubstateStore::new(
+                substate_db,
+                self.substate_cache_size.unwrap_or(0),
+                CacheWriteMode::WriteThrough,
+            ),
             state_hash_support: Some(self.state_hashing)
                 .filter(|x| *x)
                 .map(|_| StateHashSupport::new()),
             next_private_key,
             next_transaction_nonce,
+            rng: self.seed.map(TestRunnerRng::new),
             trace: self.trace,
+            invocation_filter: self.invocation_filter,
+            package_stubs: TestPackageStubs::default(),
+            protocol_parameters: self.protocol_parameters,
         };
 
         let next_epoch = wrap_up_receipt
@@ -311,11 +548,29 @@ impl TestRunnerBuilder {
 
 pub struct TestRunner {
     scrypto_interpreter: ScryptoVm<DefaultWasmEngine>,
-    substate_db: InMemorySubstateDatabase,
+    /// The ledger, behind a cache which only holds substates if the runner was built with one
+    substate_db: CachedSubstateStore<InMemorySubstateDatabase>,
     next_private_key: u64,
     next_transaction_nonce: u32,
+    rng: Option<TestRunnerRng>,
     trace: bool,
+    invocation_filter: bool,
+    package_stubs: TestPackageStubs,
     state_hash_support: Option<StateHashSupport>,
+    protocol_parameters: ProtocolParameters,
+}
+
+impl Drop for TestRunner {
+    fn drop(&mut self) {
+        if let Some(rng) = &self.rng {
+            if std::thread::panicking() {
+                eprintln!(
+                    "TestRunner was seeded with {} - rerun with {}={} to reproduce",
+                    rng.seed, TEST_RUNNER_SEED_ENV_VAR, rng.seed
+                );
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -323,7 +578,44 @@ pub struct TestRunnerSnapshot {
     substate_db: InMemorySubstateDatabase,
     next_private_key: u64,
     next_transaction_nonce: u32,
+    rng: Option<TestRunnerRng>,
     state_hash_support: Option<StateHashSupport>,
+    protocol_parameters: ProtocolParameters,
+}
+
+/// A virtual account whose owner badge is held by an access controller, with a distinct key
+/// for each of the access controller roles.
+#[derive(Debug, Clone)]
+pub struct SecurifiedAccount {
+    pub account: ComponentAddress,
+    pub access_controller: ComponentAddress,
+    pub primary_key: Secp256k1PublicKey,
+    pub recovery_key: Secp256k1PublicKey,
+    pub confirmation_key: Secp256k1PublicKey,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+}
+
+impl SecurifiedAccount {
+    pub fn key(&self, role: Role) -> &Secp256k1PublicKey {
+        match role {
+            Role::Primary => &self.primary_key,
+            Role::Recovery => &self.recovery_key,
+            Role::Confirmation => &self.confirmation_key,
+        }
+    }
+
+    /// The rule set of the access controller with the primary role replaced by the given key.
+    pub fn rule_set_with_primary_key(&self, primary_key: &Secp256k1PublicKey) -> RuleSet {
+        RuleSet {
+            primary_role: rule!(require(NonFungibleGlobalId::from_public_key(primary_key))),
+            recovery_role: rule!(require(NonFungibleGlobalId::from_public_key(
+                &self.recovery_key
+            ))),
+            confirmation_role: rule!(require(NonFungibleGlobalId::from_public_key(
+                &self.confirmation_key
+            ))),
+        }
+    }
 }
 
 impl TestRunner {
@@ -335,23 +627,95 @@ impl TestRunner {
             #[cfg(feature = "resource_tracker")]
             trace: false,
             state_hashing: false,
+            invocation_filter: false,
+            seed: None,
+            substate_cache_size: None,
+            protocol_parameters: ProtocolParameters::default(),
+            wasm_instrumentation_cache_dir: None,
         }
     }
 
     pub fn create_snapshot(&self) -> TestRunnerSnapshot {
         TestRunnerSnapshot {
-            substate_db: self.substate_db.clone(),
+            substate_db: self.substate_db.inner().clone(),
             next_private_key: self.next_private_key,
             next_transaction_nonce: self.next_transaction_nonce,
+            rng: self.rng.clone(),
             state_hash_support: self.state_hash_support.clone(),
+            protocol_parameters: self.protocol_parameters.clone(),
         }
     }
 
     pub fn restore_snapshot(&mut self, snapshot: TestRunnerSnapshot) {
-        self.substate_db = snapshot.substate_db;
+        self.substate_db = CachedSubstateStore::new(
+            snapshot.substate_db,
+            self.substate_db.capacity(),
+            CacheWriteMode::WriteThrough,
+        );
         self.next_private_key = snapshot.next_private_key;
         self.next_transaction_nonce = snapshot.next_transaction_nonce;
+        self.rng = snapshot.rng;
         self.state_hash_support = snapshot.state_hash_support;
+        self.protocol_parameters = snapshot.protocol_parameters;
+    }
+
+    /// The hits and misses of the substate cache, if the runner was built with one.
+    pub fn substate_cache_metrics(&self) -> Option<CacheMetrics> {
+        Some(self.substate_db.metrics()).filter(|_| self.substate_db.capacity() > 0)
+    }
+
+    /// Replaces the code of the given package with a Rust stub for all transactions executed from
+    /// now on, so that integrations with a third-party package can be tested without deploying
+    /// its dependencies. The package must be published, as calls to it are still resolved and
+    /// validated against its schema.
+    pub fn stub_package<F>(&mut self, package_address: PackageAddress, stub: F)
+    where
+        F: Fn(&StubbedCall) -> Result<IndexedScryptoValue, String> + 'static,
+    {
+        self.package_stubs.insert(package_address, Box::new(stub));
+    }
+
+    pub fn unstub_package(&mut self, package_address: &PackageAddress) {
+        self.package_stubs.remove(package_address);
+    }
+
+    /// The calls made to stubbed packages so far, in the order they were made.
+    pub fn stubbed_calls(&self) -> Vec<StubbedCall> {
+        self.package_stubs.calls()
+    }
+
+    pub fn clear_stubbed_calls(&mut self) {
+        self.package_stubs.clear_calls();
+    }
+
+    pub fn protocol_parameters(&self) -> &ProtocolParameters {
+        &self.protocol_parameters
+    }
+
+    /// Changes the protocol parameters for all transactions executed from now on, as a protocol
+    /// update would.
+    pub fn set_protocol_parameters(&mut self, protocol_parameters: ProtocolParameters) {
+        self.protocol_parameters = protocol_parameters;
+    }
+
+    /// Changes the condition for ending an epoch, from the current epoch on, as a protocol update
+    /// of the consensus manager configuration would.
+    pub fn set_epoch_change_condition(&mut self, epoch_change_condition: EpochChangeCondition) {
+        let mut substate = self
+            .substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, ConsensusManagerConfigSubstate>(
+                &CONSENSUS_MANAGER.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &ConsensusManagerField::Config.into(),
+            )
+            .unwrap();
+        substate.config.epoch_change_condition = epoch_change_condition;
+        self.substate_db.put_mapped::<SpreadPrefixKeyMapper, _>(
+            &CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &ConsensusManagerField::Config.into(),
+            &substate,
+        );
     }
 
     pub fn faucet_component(&self) -> GlobalAddress {
@@ -359,19 +723,30 @@ impl TestRunner {
     }
 
     pub fn substate_db(&self) -> &InMemorySubstateDatabase {
-        &self.substate_db
+        self.substate_db.inner()
     }
 
     pub fn substate_db_mut(&mut self) -> &mut InMemorySubstateDatabase {
-        &mut self.substate_db
+        self.substate_db.inner_mut()
+    }
+
+    /// The seed backing the test runner's key and nonce choices, if it was built with one.
+    pub fn seed(&self) -> Option<u64> {
+        self.rng.as_ref().map(|rng| rng.seed)
     }
 
     pub fn next_private_key(&mut self) -> u64 {
+        if let Some(rng) = &mut self.rng {
+            return rng.next_private_key();
+        }
         self.next_private_key += 1;
         self.next_private_key - 1
     }
 
     pub fn next_transaction_nonce(&mut self) -> u32 {
+        if let Some(rng) = &mut self.rng {
+            return rng.next_transaction_nonce();
+        }
         self.next_transaction_nonce += 1;
         self.next_transaction_nonce - 1
     }
@@ -449,6 +824,20 @@ impl TestRunner {
             .unwrap()
     }
 
+    pub fn inspect_component_rent(
+        &mut self,
+        component_address: ComponentAddress,
+    ) -> Option<ComponentRent> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, ComponentRentSubstate>(
+                component_address.as_node_id(),
+                ROYALTY_FIELDS_PARTITION,
+                &RoyaltyField::Rent.into(),
+            )
+            .unwrap()
+            .rent
+    }
+
     pub fn inspect_package_royalty(&mut self, package_address: PackageAddress) -> Option<Decimal> {
         let output = self
             .substate_db
@@ -467,6 +856,18 @@ impl TestRunner {
             .map(|r| r.amount())
     }
 
+    /// The royalty accumulated by the given recipient, and not yet claimed.
+    pub fn inspect_royalty(&mut self, recipient: &RoyaltyRecipient) -> Decimal {
+        match recipient {
+            RoyaltyRecipient::Package(package_address) => self
+                .inspect_package_royalty(*package_address)
+                .unwrap_or_default(),
+            RoyaltyRecipient::Component(component_address) => {
+                self.inspect_component_royalty(*component_address)
+            }
+        }
+    }
+
     pub fn account_balance(
         &mut self,
         account_address: ComponentAddress,
@@ -493,6 +894,59 @@ impl TestRunner {
         node_ids
     }
 
+    /// Returns the ownership graph of all global entities in the current ledger state.
+    pub fn ownership_graph(&self) -> OwnershipGraph {
+        OwnershipGraph::from_all_global_entities(&self.substate_db)
+    }
+
+    /// Returns the ownership graph of the given global entity.
+    pub fn ownership_graph_of<A: Into<GlobalAddress>>(&self, address: A) -> OwnershipGraph {
+        OwnershipGraph::from_roots(&self.substate_db, [*address.into().as_node_id()])
+    }
+
+    /// Returns the storage footprint of the given global entity and the nodes it owns.
+    pub fn entity_footprint<A: Into<GlobalAddress>>(&self, address: A) -> EntityFootprint {
+        let address = address.into();
+        EntityFootprint::measure(
+            &self.substate_db,
+            address.as_node_id(),
+            DEFAULT_LARGEST_SUBSTATES,
+        )
+        .unwrap_or_else(|| panic!("Entity {:?} doesn't exist", address))
+    }
+
+    /// Asserts that the storage footprint of the given global entity is within the given limits.
+    pub fn assert_footprint_at_most<A: Into<GlobalAddress>>(
+        &self,
+        address: A,
+        max_substate_count: usize,
+        max_total_bytes: usize,
+    ) {
+        let footprint = self.entity_footprint(address);
+        if footprint.substate_count > max_substate_count || footprint.total_bytes > max_total_bytes
+        {
+            panic!(
+                "Footprint of {:?} is {} substates and {} bytes, exceeding {} substates and {} bytes. Largest substates: {:?}",
+                footprint.node_id,
+                footprint.substate_count,
+                footprint.total_bytes,
+                max_substate_count,
+                max_total_bytes,
+                footprint.largest_substates
+            );
+        }
+    }
+
+    /// Executes a structured query over the current ledger state.
+    pub fn query_state(&self, query: &StateQuery) -> StateQueryResult {
+        StateQueryEngine::new(&self.substate_db).execute(query)
+    }
+
+    /// Works out the access rules a manifest will be checked against on the current state.
+    pub fn auth_requirements(&self, manifest: &TransactionManifestV1) -> AuthRequirements {
+        AuthRequirementsAnalyzer::new(&self.substate_db).analyze(&manifest.instructions)
+    }
+
     pub fn find_all_components(&self) -> Vec<ComponentAddress> {
         self.find_all_nodes()
             .iter()
@@ -571,6 +1025,102 @@ impl TestRunner {
         definitions
     }
 
+    /// Returns the schema of the output of an instruction, if it calls a function or a method
+    /// of a global object whose blueprint definition is known.
+    pub fn instruction_output_schema(
+        &self,
+        instruction: &InstructionV1,
+    ) -> Option<(LocalTypeIndex, ScryptoSchema)> {
+        let (package_address, blueprint_name, function_name) = match instruction {
+            InstructionV1::CallFunction {
+                package_address: DynamicPackageAddress::Static(package_address),
+                blueprint_name,
+                function_name,
+                ..
+            } => (*package_address, blueprint_name.clone(), function_name),
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(address),
+                method_name,
+                ..
+            } => {
+                let type_info = self
+                    .substate_db()
+                    .get_mapped::<SpreadPrefixKeyMapper, TypeInfoSubstate>(
+                        address.as_node_id(),
+                        TYPE_INFO_FIELD_PARTITION,
+                        &TypeInfoField::TypeInfo.into(),
+                    )?;
+                match type_info {
+                    TypeInfoSubstate::Object(ObjectInfo { blueprint_id, .. }) => (
+                        blueprint_id.package_address,
+                        blueprint_id.blueprint_name,
+                        method_name,
+                    ),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        let (_, definition) = self
+            .get_package_blueprint_definitions(&package_address)
+            .into_iter()
+            .find(|(key, _)| key.blueprint == blueprint_name)?;
+        match definition.interface.functions.get(function_name)?.output {
+            TypePointer::Package(schema_hash, local_type_index) => {
+                let schema = self
+                    .get_package_scrypto_schemas(&package_address)
+                    .swap_remove(&schema_hash)?;
+                Some((local_type_index, schema))
+            }
+            TypePointer::Instance(..) => None,
+        }
+    }
+
+    /// Renders the outputs of a transaction executed from the given manifest, annotated with
+    /// the types from the schemas of the functions called, where known.
+    ///
+    /// Instructions which don't return a value are rendered as `None`.
+    pub fn decoded_outputs(
+        &self,
+        manifest: &TransactionManifestV1,
+        receipt: &TransactionReceipt,
+    ) -> Vec<Option<String>> {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let outputs = receipt.expect_commit_success().outcome.expect_success();
+        manifest
+            .instructions
+            .iter()
+            .zip(outputs)
+            .map(|(instruction, output)| {
+                let value = match output {
+                    InstructionOutput::CallReturn(value) => value,
+                    InstructionOutput::None => return None,
+                };
+                let payload = ScryptoRawPayload::new_from_valid_slice(value);
+                let custom_context =
+                    ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder));
+                let rendered = match self.instruction_output_schema(instruction) {
+                    Some((type_index, schema)) => {
+                        payload.to_string(ValueDisplayParameters::Annotated {
+                            display_mode: DisplayMode::RustLike,
+                            print_mode: PrintMode::SingleLine,
+                            custom_context,
+                            schema: &schema,
+                            type_index,
+                        })
+                    }
+                    None => payload.to_string(ValueDisplayParameters::Schemaless {
+                        display_mode: DisplayMode::RustLike,
+                        print_mode: PrintMode::SingleLine,
+                        custom_context,
+                    }),
+                };
+                Some(rendered)
+            })
+            .collect()
+    }
+
     pub fn get_component_vaults(
         &mut self,
         component_address: ComponentAddress,
@@ -761,6 +1311,177 @@ impl TestRunner {
         component_address
     }
 
+    /// Creates a virtual account, securifies it and moves its owner badge into a new access
+    /// controller, whose primary role is held by the original account key.
+    pub fn new_securified_account(
+        &mut self,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    ) -> SecurifiedAccount {
+        let (primary_key, _, account) = self.new_virtual_account();
+        let (recovery_key, _) = self.new_key_pair();
+        let (confirmation_key, _) = self.new_key_pair();
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(account, ACCOUNT_SECURIFY_IDENT, AccountSecurifyInput {})
+            .take_all_from_worktop(ACCOUNT_OWNER_BADGE, "owner_badge")
+            .create_access_controller(
+                "owner_badge",
+                rule!(require(NonFungibleGlobalId::from_public_key(&primary_key))),
+                rule!(require(NonFungibleGlobalId::from_public_key(&recovery_key))),
+                rule!(require(NonFungibleGlobalId::from_public_key(
+                    &confirmation_key
+                ))),
+                timed_recovery_delay_in_minutes,
+            )
+            .build();
+        let receipt = self.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&primary_key)],
+        );
+        let access_controller = receipt.expect_commit_success().new_component_addresses()[0];
+
+        SecurifiedAccount {
+            account,
+            access_controller,
+            primary_key,
+            recovery_key,
+            confirmation_key,
+            timed_recovery_delay_in_minutes,
+        }
+    }
+
+    /// Locks fee from the account using the owner badge proof created by the access controller's
+    /// primary role, signed with the given key.
+    /// This succeeds only while the key holds the primary role and the role is not locked.
+    pub fn lock_fee_from_securified_account(
+        &mut self,
+        securified_account: &SecurifiedAccount,
+        signer: &Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                securified_account.access_controller,
+                ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+                AccessControllerCreateProofInput {},
+            )
+            .lock_fee(securified_account.account, 10)
+            .build();
+        self.execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(signer)])
+    }
+
+    /// Proposes replacing the primary role of a securified account with the given key.
+    pub fn initiate_securified_account_recovery(
+        &mut self,
+        securified_account: &SecurifiedAccount,
+        proposer: Role,
+        new_primary_key: &Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let method_name = match proposer {
+            Role::Primary => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
+            Role::Recovery => ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            Role::Confirmation => panic!("Confirmation role can't initiate recovery"),
+        };
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                securified_account.access_controller,
+                method_name,
+                AccessControllerInitiateRecoveryAsRecoveryInput {
+                    rule_set: securified_account.rule_set_with_primary_key(new_primary_key),
+                    timed_recovery_delay_in_minutes: securified_account
+                        .timed_recovery_delay_in_minutes,
+                },
+            )
+            .build();
+        self.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                securified_account.key(proposer),
+            )],
+        )
+    }
+
+    /// Moves the ledger clock forward by the timed recovery delay of the securified account.
+    pub fn wait_for_timed_recovery(&mut self, securified_account: &SecurifiedAccount) {
+        let delay_in_minutes = securified_account
+            .timed_recovery_delay_in_minutes
+            .expect("Timed recovery is disabled for this account");
+        let round = Round::of(self.get_consensus_manager_state().round.number() + 1);
+        let timestamp_ms =
+            self.get_current_proposer_timestamp_ms() + i64::from(delay_in_minutes) * 60 * 1000;
+        self.advance_to_round_at_timestamp(round, timestamp_ms)
+            .expect_commit_success();
+    }
+
+    /// Confirms a recovery proposed by the recovery role once the timed recovery delay has passed.
+    /// On success, the primary key of the securified account is updated.
+    pub fn timed_confirm_securified_account_recovery(
+        &mut self,
+        securified_account: &mut SecurifiedAccount,
+        new_primary_key: &Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                securified_account.access_controller,
+                ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+                AccessControllerTimedConfirmRecoveryInput {
+                    rule_set: securified_account.rule_set_with_primary_key(new_primary_key),
+                    timed_recovery_delay_in_minutes: securified_account
+                        .timed_recovery_delay_in_minutes,
+                },
+            )
+            .build();
+        let receipt = self.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                &securified_account.recovery_key,
+            )],
+        );
+        if receipt.is_commit_success() {
+            securified_account.primary_key = *new_primary_key;
+        }
+        receipt
+    }
+
+    /// Immediately confirms a recovery proposal with a second role.
+    /// On success, the primary key of the securified account is updated.
+    pub fn quick_confirm_securified_account_recovery(
+        &mut self,
+        securified_account: &mut SecurifiedAccount,
+        proposer: Role,
+        as_role: Role,
+        new_primary_key: &Secp256k1PublicKey,
+    ) -> TransactionReceipt {
+        let method_name = match proposer {
+            Role::Primary => ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            Role::Recovery => ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+            Role::Confirmation => panic!("Confirmation role can't propose recovery"),
+        };
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                securified_account.access_controller,
+                method_name,
+                AccessControllerQuickConfirmRecoveryRoleRecoveryProposalInput {
+                    rule_set: securified_account.rule_set_with_primary_key(new_primary_key),
+                    timed_recovery_delay_in_minutes: securified_account
+                        .timed_recovery_delay_in_minutes,
+                },
+            )
+            .build();
+        let receipt = self.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(
+                securified_account.key(as_role),
+            )],
+        );
+        if receipt.is_commit_success() {
+            securified_account.primary_key = *new_primary_key;
+        }
+        receipt
+    }
+
     pub fn new_validator_with_pub_key(
         &mut self,
         pub_key: Secp256k1PublicKey,
@@ -926,6 +1647,23 @@ impl TestRunner {
         )
     }
 
+    /// Replays a transaction as returned by the Core API or the Gateway API, for debugging.
+    ///
+    /// The transaction is fully validated against the given network, but executed against the
+    /// state of this test runner, which may need its epoch moving into the transaction's range.
+    pub fn execute_gateway_transaction(
+        &mut self,
+        network: &NetworkDefinition,
+        transaction: &GatewayTransactionJson,
+    ) -> TransactionReceipt {
+        let raw_transaction = transaction
+            .to_notarized_transaction(network)
+            .expect("Expected gateway transaction to be consistent")
+            .to_raw()
+            .unwrap();
+        self.execute_raw_transaction(network, &raw_transaction)
+    }
+
     pub fn execute_manifest<T>(
         &mut self,
         manifest: TransactionManifestV1,
@@ -971,16 +1709,34 @@ impl TestRunner {
         fee_reserve_config: FeeReserveConfig,
         mut execution_config: ExecutionConfig,
     ) -> TransactionReceipt {
+        let mut fee_reserve_config = fee_reserve_config;
+        self.protocol_parameters
+            .apply(&mut fee_reserve_config, &mut execution_config);
+
         // Override the kernel trace config
         execution_config = execution_config.with_kernel_trace(self.trace);
+        if self.invocation_filter {
+            execution_config = execution_config.with_invocation_filter(true);
+        }
 
-        let transaction_receipt = execute_transaction(
-            &mut self.substate_db,
-            &self.scrypto_interpreter,
-            &fee_reserve_config,
-            &execution_config,
-            &executable,
-        );
+        let transaction_receipt = if self.package_stubs.is_empty() {
+            execute_transaction(
+                &mut self.substate_db,
+                &self.scrypto_interpreter,
+                &fee_reserve_config,
+                &execution_config,
+                &executable,
+            )
+        } else {
+            execute_transaction_with_package_stubs(
+                &mut self.substate_db,
+                &self.scrypto_interpreter,
+                &self.package_stubs,
+                &fee_reserve_config,
+                &execution_config,
+                &executable,
+            )
+        };
         if let TransactionResult::Commit(commit) = &transaction_receipt.transaction_result {
             self.substate_db
                 .commit(&commit.state_updates.database_updates);
@@ -1012,36 +1768,100 @@ impl TestRunner {
         tip_percentage: u16,
         flags: PreviewFlags,
     ) -> TransactionReceipt {
-        let epoch = self.get_current_epoch();
+        let preview_intent =
+            self.preview_intent(manifest, signer_public_keys, tip_percentage, flags);
         execute_preview(
             &mut self.substate_db,
             &self.scrypto_interpreter,
             &NetworkDefinition::simulator(),
-            PreviewIntentV1 {
-                intent: IntentV1 {
-                    header: TransactionHeaderV1 {
-                        network_id: NetworkDefinition::simulator().id,
-                        start_epoch_inclusive: epoch,
-                        end_epoch_exclusive: epoch.after(10),
-                        nonce: 0,
-                        notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
-                        notary_is_signatory: false,
-                        tip_percentage,
-                    },
-                    instructions: InstructionsV1(manifest.instructions),
-                    blobs: BlobsV1 {
-                        blobs: manifest.blobs.values().map(|x| BlobV1(x.clone())).collect(),
-                    },
-                    message: MessageV1::default(),
-                },
-                signer_public_keys,
-                flags,
-            },
+            preview_intent,
             self.trace,
         )
         .unwrap()
     }
 
+    pub fn review_manifest(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        flags: PreviewFlags,
+    ) -> TransactionReview {
+        let preview_intent = self.preview_intent(manifest, signer_public_keys, 0, flags);
+        review_transaction(
+            &self.substate_db,
+            &self.scrypto_interpreter,
+            &NetworkDefinition::simulator(),
+            preview_intent,
+            &TransactionReviewConfig {
+                with_kernel_trace: self.trace,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    /// Applies an upgrade plan within one transaction, paying the fee from the faucet.
+    pub fn execute_upgrade_plan<T>(
+        &mut self,
+        plan: &UpgradePlan,
+        initial_proofs: T,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .upgrade(plan)
+            .build();
+        self.execute_manifest(manifest, initial_proofs)
+    }
+
+    /// Previews an upgrade plan as a dry run, without committing anything.
+    pub fn preview_upgrade_plan(
+        &mut self,
+        plan: &UpgradePlan,
+        signer_public_keys: Vec<PublicKey>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .upgrade(plan)
+            .build();
+        self.preview_manifest(manifest, signer_public_keys, 0, PreviewFlags::default())
+    }
+
+    fn preview_intent(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        tip_percentage: u16,
+        flags: PreviewFlags,
+    ) -> PreviewIntentV1 {
+        let epoch = self.get_current_epoch();
+        PreviewIntentV1 {
+            intent: IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: NetworkDefinition::simulator().id,
+                    start_epoch_inclusive: epoch,
+                    end_epoch_exclusive: epoch.after(10),
+                    nonce: 0,
+                    notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
+                    notary_is_signatory: false,
+                    tip_percentage,
+                    max_fee_in_xrd: None,
+                    expires_at_timestamp: None,
+                    idempotency_key: None,
+                },
+                instructions: InstructionsV1(manifest.instructions),
+                blobs: BlobsV1 {
+                    blobs: manifest.blobs.values().map(|x| BlobV1(x.clone())).collect(),
+                },
+                message: MessageV1::default(),
+            },
+            signer_public_keys,
+            flags,
+        }
+    }
+
     /// Calls a package blueprint function with the given arguments, paying the fee from the faucet.
     ///
     /// The arguments should be one of:
@@ -1693,6 +2513,114 @@ impl TestRunner {
             .unwrap()
     }
 
+    /// Reads out the bounded history of epoch starts, ordered from the oldest to the current epoch.
+    pub fn get_epoch_history(&mut self) -> Vec<EpochStartRecord> {
+        self.substate_db()
+            .get_mapped::<SpreadPrefixKeyMapper, EpochHistorySubstate>(
+                CONSENSUS_MANAGER.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &ConsensusManagerField::EpochHistory.into(),
+            )
+            .unwrap()
+            .records
+    }
+
+    /// Reads out the instantiation deposits held for globalized components.
+    pub fn get_instantiation_deposits(&mut self) -> InstantiationDepositsSubstate {
+        self.substate_db()
+            .get_mapped::<SpreadPrefixKeyMapper, InstantiationDepositsSubstate>(
+                CONSENSUS_MANAGER.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &ConsensusManagerField::InstantiationDeposits.into(),
+            )
+            .unwrap()
+    }
+
+    /// Advances rounds (keeping the timestamp unchanged) until `epoch_count` epoch changes have
+    /// happened, and returns the receipts of the epoch-changing transactions.
+    ///
+    /// Note: this relies on the configured `max_round_count` to eventually trigger each change.
+    pub fn advance_epochs(&mut self, epoch_count: u64) -> Vec<TransactionReceipt> {
+        let mut receipts = Vec::new();
+        while (receipts.len() as u64) < epoch_count {
+            let next_round = self.get_consensus_manager_state().round.number() + 1;
+            let receipt = self.advance_to_round(Round::of(next_round));
+            if receipt.expect_commit_success().next_epoch().is_some() {
+                receipts.push(receipt);
+            }
+        }
+        receipts
+    }
+
+    /// Returns the amount of XRD currently held in the validator's stake pool.
+    pub fn get_validator_stake_xrd(&mut self, address: ComponentAddress) -> Decimal {
+        let stake_xrd_vault_id = self.get_validator_info(address).stake_xrd_vault_id;
+        self.inspect_vault_balance(stake_xrd_vault_id.0).unwrap()
+    }
+
+    /// Returns the total supply of the validator's stake units.
+    pub fn get_validator_stake_unit_supply(&self, address: ComponentAddress) -> Decimal {
+        let stake_unit_resource = self.get_validator_info(address).stake_unit_resource;
+        self.substate_db()
+            .get_mapped::<SpreadPrefixKeyMapper, Decimal>(
+                stake_unit_resource.as_node_id(),
+                MAIN_BASE_PARTITION,
+                &FungibleResourceManagerField::TotalSupply.into(),
+            )
+            .unwrap()
+    }
+
+    /// Returns the amount of XRD which the given amount of the validator's stake units is
+    /// currently worth (i.e. a proportional share of the stake pool).
+    pub fn get_validator_stake_unit_redemption_value(
+        &mut self,
+        address: ComponentAddress,
+        stake_unit_amount: Decimal,
+    ) -> Decimal {
+        let stake_unit_supply = self.get_validator_stake_unit_supply(address);
+        if stake_unit_supply.is_zero() {
+            return Decimal::zero();
+        }
+        let stake_xrd = self.get_validator_stake_xrd(address);
+        stake_unit_amount * stake_xrd / stake_unit_supply
+    }
+
+    /// Extracts the per-validator emission events from an epoch-changing transaction, in the
+    /// order in which they were emitted (i.e. the order of the concluded validator set).
+    pub fn extract_validator_emissions(
+        &self,
+        result: &CommitResult,
+    ) -> Vec<(ComponentAddress, ValidatorEmissionAppliedEvent)> {
+        self.extract_validator_events(result)
+    }
+
+    /// Extracts the per-validator reward (i.e. fee and tip distribution) events from an
+    /// epoch-changing transaction.
+    pub fn extract_validator_rewards(
+        &self,
+        result: &CommitResult,
+    ) -> Vec<(ComponentAddress, ValidatorRewardAppliedEvent)> {
+        self.extract_validator_events(result)
+    }
+
+    fn extract_validator_events<T: ScryptoEvent>(
+        &self,
+        result: &CommitResult,
+    ) -> Vec<(ComponentAddress, T)> {
+        result
+            .application_events
+            .iter()
+            .filter(|(id, _data)| self.is_event_name_equal::<T>(id))
+            .filter_map(|(id, data)| match &id.0 {
+                Emitter::Method(node_id, ObjectModuleId::Main) => Some((
+                    ComponentAddress::new_or_panic(node_id.0),
+                    scrypto_decode::<T>(data).unwrap(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn get_current_time(&mut self, precision: TimePrecision) -> Instant {
         let receipt = self.execute_system_transaction(
             vec![InstructionV1::CallMethod {
@@ -1770,6 +2698,31 @@ impl TestRunner {
         }
     }
 
+    /// Returns the divisibility of the given resource, or `None` if it's not fungible.
+    pub fn get_resource_divisibility(&self, resource_address: &ResourceAddress) -> Option<u8> {
+        if !resource_address.is_fungible() {
+            return None;
+        }
+        self.substate_db().get_mapped::<SpreadPrefixKeyMapper, u8>(
+            resource_address.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &FungibleResourceManagerField::Divisibility.into(),
+        )
+    }
+
+    /// Renders the receipt with bech32-encoded addresses and fungible balance changes shown
+    /// with the divisibility of their resource.
+    pub fn display_receipt(&self, receipt: &TransactionReceipt) -> String {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let display_context = TransactionReceiptDisplayContextBuilder::new()
+            .encoder(&encoder)
+            .divisibility_lookup_callback(|resource_address: &ResourceAddress| {
+                self.get_resource_divisibility(resource_address)
+            })
+            .build();
+        receipt.display(display_context).to_string()
+    }
+
     pub fn event_name(&self, event_type_identifier: &EventTypeIdentifier) -> String {
         let (local_type_index, schema) = self.event_schema(event_type_identifier);
         schema
@@ -1939,6 +2892,9 @@ pub fn create_notarized_transaction(
             notary_public_key: sk_notary.public_key().into(),
             notary_is_signatory: false,
             tip_percentage: 5,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         })
         .manifest(manifest)
         .sign(&sk1)
@@ -0,0 +1,333 @@
+use crate::Compile;
+use radix_engine::types::*;
+use radix_engine_interface::schema::{BlueprintSchemaInit, FunctionSchemaInit, TypeRef};
+use std::fmt::Write;
+use std::path::Path;
+
+/// Compiles the package and generates an integration test file for it.
+/// See [`generate_integration_tests`].
+pub fn scaffold_integration_tests<P: AsRef<Path>>(package_dir: P) -> String {
+    let (_, definition) = Compile::compile(package_dir.as_ref());
+    generate_integration_tests(&package_dir.as_ref().to_string_lossy(), &definition)
+}
+
+/// Generates a ready-to-edit integration test file with one [`TestRunner`](crate::TestRunner)
+/// test per function and method of the package, where `package_dir` is the path under which the
+/// tests will find the package.
+///
+/// Each test calls the function with placeholder arguments, which are derived from the schema
+/// where possible, and asserts that the transaction commits successfully. Arguments which can't
+/// be derived, and the components which methods are called on, are left as `todo!()`s.
+pub fn generate_integration_tests(package_dir: &str, definition: &PackageDefinition) -> String {
+    let mut output = String::new();
+    output.push_str("use radix_engine::types::*;\n");
+    output.push_str("use scrypto_unit::*;\n");
+    output.push_str("use transaction::prelude::*;\n");
+
+    for (blueprint_name, blueprint) in &definition.blueprints {
+        for (function_name, function) in &blueprint.schema.functions.functions {
+            output.push('\n');
+            write_test(
+                &mut output,
+                package_dir,
+                blueprint_name,
+                &blueprint.schema,
+                function_name,
+                function,
+            );
+        }
+    }
+
+    output
+}
+
+fn write_test(
+    output: &mut String,
+    package_dir: &str,
+    blueprint_name: &str,
+    schema: &BlueprintSchemaInit,
+    function_name: &str,
+    function: &FunctionSchemaInit,
+) {
+    let arguments = match &function.input {
+        TypeRef::Static(type_index) => input_arguments(&schema.schema, *type_index),
+        TypeRef::Generic(_) => Vec::new(),
+    };
+    let needs_lookup = arguments.iter().any(|argument| argument.setup.is_some());
+
+    writeln!(output, "#[test]").unwrap();
+    writeln!(
+        output,
+        "fn test_{}_{}() {{",
+        to_snake_case(blueprint_name),
+        function_name
+    )
+    .unwrap();
+    writeln!(output, "    // Arrange").unwrap();
+    writeln!(
+        output,
+        "    let mut test_runner = TestRunner::builder().build();"
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "    let (public_key, _private_key, account) = test_runner.new_allocated_account();"
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "    let package_address = test_runner.compile_and_publish({:?});",
+        package_dir
+    )
+    .unwrap();
+    if function.receiver.is_some() {
+        writeln!(
+            output,
+            "    let component_address: ComponentAddress = todo!(\"Instantiate a {} component to call the method on\");",
+            blueprint_name
+        )
+        .unwrap();
+    }
+    for argument in &arguments {
+        if argument.setup.is_none() {
+            writeln!(output, "    let {} = {};", argument.name, argument.value).unwrap();
+        }
+    }
+    writeln!(output).unwrap();
+
+    writeln!(output, "    // Act").unwrap();
+    writeln!(output, "    let manifest = ManifestBuilder::new()").unwrap();
+    for argument in &arguments {
+        if let Some(setup) = &argument.setup {
+            writeln!(output, "        {}", setup).unwrap();
+        }
+    }
+    let (target, call) = if function.receiver.is_some() {
+        ("component_address", "call_method")
+    } else {
+        ("package_address", "call_function")
+    };
+    let blueprint_argument = if function.receiver.is_some() {
+        String::new()
+    } else {
+        format!(" {:?},", blueprint_name)
+    };
+    let values: Vec<&str> = arguments
+        .iter()
+        .map(|argument| argument.value.as_str())
+        .collect();
+    if needs_lookup {
+        writeln!(
+            output,
+            "        .{}_with_name_lookup({},{} {:?}, |lookup| ({},))",
+            call,
+            target,
+            blueprint_argument,
+            function_name,
+            values.join(", ")
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            output,
+            "        .{}({},{} {:?}, manifest_args!({}))",
+            call,
+            target,
+            blueprint_argument,
+            function_name,
+            values.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(output, "        .try_deposit_batch_or_abort(account)").unwrap();
+    writeln!(output, "        .build();").unwrap();
+    writeln!(
+        output,
+        "    let receipt = test_runner.execute_manifest_ignoring_fee(\n        manifest,\n        vec![NonFungibleGlobalId::from_public_key(&public_key)],\n    );"
+    )
+    .unwrap();
+    writeln!(output).unwrap();
+
+    writeln!(output, "    // Assert").unwrap();
+    writeln!(output, "    receipt.expect_commit_success();").unwrap();
+    writeln!(output, "}}").unwrap();
+}
+
+/// A placeholder argument of a generated call.
+struct Argument {
+    name: String,
+    /// The expression passed as the argument
+    value: String,
+    /// The manifest builder call which puts the argument into a named bucket or proof
+    setup: Option<String>,
+}
+
+fn input_arguments(schema: &ScryptoSchema, input: LocalTypeIndex) -> Vec<Argument> {
+    let Some(TypeKind::Tuple { field_types }) = schema.resolve_type_kind(input) else {
+        return Vec::new();
+    };
+    let field_names = schema
+        .resolve_type_metadata(input)
+        .and_then(|metadata| metadata.get_field_names());
+
+    field_types
+        .iter()
+        .enumerate()
+        .map(|(i, field_type)| {
+            let name = match field_names {
+                Some(field_names) if field_names.len() == field_types.len() => {
+                    field_names[i].to_string()
+                }
+                _ => format!("arg{}", i),
+            };
+            argument(schema, name, *field_type)
+        })
+        .collect()
+}
+
+fn argument(schema: &ScryptoSchema, name: String, type_index: LocalTypeIndex) -> Argument {
+    let type_name = type_name(schema, type_index);
+    match (schema.resolve_type_kind(type_index), type_name.as_deref()) {
+        (
+            Some(TypeKind::Custom(ScryptoCustomTypeKind::Own)),
+            Some("Bucket" | "FungibleBucket" | "NonFungibleBucket"),
+        ) => Argument {
+            value: format!("lookup.bucket({:?})", name),
+            setup: Some(format!(
+                ".withdraw_from_account(account, XRD, dec!(\"1\"))\n        .take_from_worktop(XRD, dec!(\"1\"), {:?})",
+                name
+            )),
+            name,
+        },
+        (
+            Some(TypeKind::Custom(ScryptoCustomTypeKind::Own)),
+            Some("Proof" | "FungibleProof" | "NonFungibleProof"),
+        ) => Argument {
+            value: format!("lookup.proof({:?})", name),
+            setup: Some(format!(
+                ".create_proof_from_account_of_amount(account, XRD, dec!(\"1\"))\n        .pop_from_auth_zone({:?})",
+                name
+            )),
+            name,
+        },
+        _ => Argument {
+            value: placeholder(schema, type_index),
+            setup: None,
+            name,
+        },
+    }
+}
+
+fn type_name(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Option<String> {
+    schema
+        .resolve_type_metadata(type_index)
+        .and_then(|metadata| metadata.get_name_string())
+}
+
+/// A Rust expression for a value of the type, for the test author to replace.
+fn placeholder(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> String {
+    let todo = || {
+        format!(
+            "todo!(\"Provide a {}\")",
+            type_name(schema, type_index).unwrap_or_else(|| "value".to_string())
+        )
+    };
+    let Some(type_kind) = schema.resolve_type_kind(type_index) else {
+        return todo();
+    };
+
+    match type_kind {
+        TypeKind::Bool => "false".to_string(),
+        TypeKind::I8 => "0i8".to_string(),
+        TypeKind::I16 => "0i16".to_string(),
+        TypeKind::I32 => "0i32".to_string(),
+        TypeKind::I64 => "0i64".to_string(),
+        TypeKind::I128 => "0i128".to_string(),
+        TypeKind::U8 => "0u8".to_string(),
+        TypeKind::U16 => "0u16".to_string(),
+        TypeKind::U32 => "0u32".to_string(),
+        TypeKind::U64 => "0u64".to_string(),
+        TypeKind::U128 => "0u128".to_string(),
+        TypeKind::String => "String::new()".to_string(),
+        TypeKind::Array { element_type } => match rust_type(schema, *element_type) {
+            Some(element_type) => format!("Vec::<{}>::new()", element_type),
+            None => todo(),
+        },
+        TypeKind::Tuple { field_types } if field_types.is_empty() => "()".to_string(),
+        TypeKind::Enum { variants }
+            if type_name(schema, type_index).as_deref() == Some("Option") =>
+        {
+            match variants.get(&1).and_then(|fields| fields.first()) {
+                Some(inner) => match rust_type(schema, *inner) {
+                    Some(inner) => format!("None::<{}>", inner),
+                    None => todo(),
+                },
+                None => todo(),
+            }
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => "dec!(\"1\")".to_string(),
+        TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => "pdec!(\"1\")".to_string(),
+        TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            "NonFungibleLocalId::integer(1)".to_string()
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            match type_name(schema, type_index).as_deref() {
+                Some("ResourceAddress") => "XRD".to_string(),
+                Some("ComponentAddress") => "account".to_string(),
+                Some("PackageAddress") => "package_address".to_string(),
+                Some("GlobalAddress") => "GlobalAddress::from(account)".to_string(),
+                _ => todo(),
+            }
+        }
+        _ => todo(),
+    }
+}
+
+/// The Rust type of simple types, for annotating empty placeholders.
+fn rust_type(schema: &ScryptoSchema, type_index: LocalTypeIndex) -> Option<String> {
+    let type_kind = schema.resolve_type_kind(type_index)?;
+    let rust_type = match type_kind {
+        TypeKind::Bool => "bool".to_string(),
+        TypeKind::I8 => "i8".to_string(),
+        TypeKind::I16 => "i16".to_string(),
+        TypeKind::I32 => "i32".to_string(),
+        TypeKind::I64 => "i64".to_string(),
+        TypeKind::I128 => "i128".to_string(),
+        TypeKind::U8 => "u8".to_string(),
+        TypeKind::U16 => "u16".to_string(),
+        TypeKind::U32 => "u32".to_string(),
+        TypeKind::U64 => "u64".to_string(),
+        TypeKind::U128 => "u128".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Array { element_type } => format!("Vec<{}>", rust_type(schema, *element_type)?),
+        TypeKind::Custom(ScryptoCustomTypeKind::Decimal)
+        | TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal)
+        | TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            type_name(schema, type_index)?
+        }
+        TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            match type_name(schema, type_index)?.as_str() {
+                name @ ("ResourceAddress" | "ComponentAddress" | "PackageAddress"
+                | "GlobalAddress") => name.to_string(),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(rust_type)
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
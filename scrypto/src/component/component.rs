@@ -401,6 +401,10 @@ impl<O: HasStub> HasMetadata for Global<O> {
     fn remove_metadata<K: ToString>(&self, name: K) -> bool {
         self.metadata().remove(name)
     }
+
+    fn lock_metadata<K: ToString>(&self, name: K) {
+        self.metadata().lock(name)
+    }
 }
 
 impl<O: HasStub> HasAccessRules for Global<O> {
@@ -416,6 +420,10 @@ impl<O: HasStub> HasAccessRules for Global<O> {
         self.access_rules().set_role(name, rule);
     }
 
+    fn renounce_role(&self, name: &str) {
+        self.access_rules().renounce_role(name);
+    }
+
     fn get_role(&self, name: &str) -> Option<AccessRule> {
         self.access_rules().get_role(name)
     }
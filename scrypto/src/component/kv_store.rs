@@ -110,6 +110,23 @@ impl<
         env.key_value_entry_release(handle).unwrap();
     }
 
+    /// Locks the entry associated with the given key until the returned guard is dropped.
+    ///
+    /// While the guard is alive, no other invocation in the same transaction (e.g. a nested or
+    /// re-entrant call) is able to open the entry, as the underlying substate stays locked.
+    pub fn lock_entry(&self, key: &K) -> KeyValueEntryLock<'_, V> {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(key).unwrap();
+        let handle = env
+            .key_value_store_open_entry(self.id.as_node_id(), &key_payload, LockFlags::MUTABLE)
+            .unwrap();
+        let raw_bytes = env.key_value_entry_get(handle).unwrap();
+
+        let substate: Option<ScryptoValue> = scrypto_decode(&raw_bytes).unwrap();
+        let value = substate.map(|value| scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap());
+        KeyValueEntryLock::new(handle, value)
+    }
+
     /// Remove an entry from the map and return the original value if it exists
     pub fn remove(&self, key: &K) -> Option<V> {
         let mut env = ScryptoEnv;
@@ -265,3 +282,49 @@ impl<'a, V: ScryptoEncode> DerefMut for KeyValueEntryRefMut<'a, V> {
         &mut self.value
     }
 }
+
+/// An exclusive lock on a key value store entry, released on drop.
+pub struct KeyValueEntryLock<'a, V: ScryptoEncode> {
+    handle: KeyValueEntryHandle,
+    value: Option<V>,
+    updated: bool,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, V: ScryptoEncode> KeyValueEntryLock<'a, V> {
+    pub fn new(lock_handle: KeyValueEntryHandle, value: Option<V>) -> KeyValueEntryLock<'a, V> {
+        KeyValueEntryLock {
+            handle: lock_handle,
+            value,
+            updated: false,
+            phantom: PhantomData::default(),
+        }
+    }
+
+    /// Returns the current value of the locked entry, if any.
+    pub fn get(&self) -> Option<&V> {
+        self.value.as_ref()
+    }
+
+    /// Updates the value of the locked entry. The write happens when the lock is released.
+    pub fn set(&mut self, value: V) {
+        self.value = Some(value);
+        self.updated = true;
+    }
+
+    /// Releases the lock, writing back any update.
+    pub fn unlock(self) {}
+}
+
+impl<'a, V: ScryptoEncode> Drop for KeyValueEntryLock<'a, V> {
+    fn drop(&mut self) {
+        let mut env = ScryptoEnv;
+        if self.updated {
+            if let Some(value) = &self.value {
+                let value = scrypto_encode(value).unwrap();
+                env.key_value_entry_set(self.handle, value).unwrap();
+            }
+        }
+        env.key_value_entry_release(self.handle).unwrap();
+    }
+}
@@ -57,6 +57,19 @@ pub trait ObjectStub: Copy {
         scrypto_decode(&output).unwrap()
     }
 
+    /// Calls a method in read-only mode, e.g. to query a component which is currently calling
+    /// into this one.
+    fn static_call<A: ScryptoEncode, T: ScryptoDecode>(&self, method: &str, args: &A) -> T {
+        let output = ScryptoEnv
+            .call_static_method(
+                self.handle().as_node_id(),
+                method,
+                scrypto_encode(args).unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&output).unwrap()
+    }
+
     fn blueprint(&self) -> BlueprintId {
         ScryptoEnv
             .get_object_info(self.handle().as_node_id())
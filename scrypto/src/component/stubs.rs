@@ -1,6 +1,8 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::hook_registry::*;
+use radix_engine_interface::blueprints::payment_stream::*;
 
 use crate::prelude::*;
 
@@ -13,6 +15,8 @@ use crate::prelude::*;
 //  4. Access Controller
 //  5. Consensus Manager
 //  6. Validator
+//  7. Hook Registry
+//  8. Payment Stream
 // ================================================================================================
 
 extern_blueprint_internal! {
@@ -123,6 +127,7 @@ extern_blueprint_internal! {
         fn create_advanced(owner_role: OwnerRole) -> Global<Account>;
     },
     {
+        fn add_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
         fn burn(&mut self, resource_address: ResourceAddress, amount: Decimal);
         fn burn_non_fungibles(&mut self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>);
         fn change_account_default_deposit_rule(&self, default_deposit_rule: AccountDefaultDepositRule);
@@ -130,12 +135,14 @@ extern_blueprint_internal! {
         fn create_proof(&self, resource_address: ResourceAddress) -> Proof;
         fn create_proof_of_amount(&self, resource_address: ResourceAddress, amount: Decimal) -> Proof;
         fn create_proof_of_non_fungibles(&self, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Proof;
+        fn create_proofs(&self, proofs: Vec<AccountProofSpecifier>) -> Vec<Proof>;
         fn deposit(&mut self, bucket: Bucket);
         fn deposit_batch(&mut self, buckets: Vec<Bucket>);
         fn lock_contingent_fee(&mut self, amount: Decimal);
         fn lock_fee(&mut self, amount: Decimal);
         fn lock_fee_and_withdraw(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, amount: Decimal) -> Bucket;
         fn lock_fee_and_withdraw_non_fungibles(&mut self, amount_to_lock: Decimal, resource_address: ResourceAddress, ids: Vec<NonFungibleLocalId>) -> Bucket;
+        fn remove_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
         fn securify(&mut self) -> Bucket;
         fn try_deposit_batch_or_abort(&mut self, buckets: Vec<Bucket>);
         fn try_deposit_batch_or_refund(&mut self, buckets: Vec<Bucket>) -> Vec<Bucket>;
@@ -239,3 +246,40 @@ extern_blueprint_internal! {
         fn update_key(&mut self, key: Secp256k1PublicKey);
     }
 }
+
+extern_blueprint_internal! {
+    HOOK_REGISTRY_PACKAGE,
+    HookRegistry,
+    "HookRegistry",
+    "OwnedHookRegistry",
+    "GlobalHookRegistry",
+    HookRegistryFunctions
+    {
+        fn instantiate(owner_role: OwnerRole, hook_invoker_rule: AccessRule) -> Global<HookRegistry>;
+    },
+    {
+        /// Registers a method of the calling component, which must be the hook's receiver.
+        fn register_hook(&mut self, event_name: String, hook: RegisteredHook);
+        fn unregister_hook(&mut self, event_name: String, hook: RegisteredHook);
+        fn invoke_hooks(&self, event_name: String, payload: Vec<u8>) -> Vec<RegisteredHook>;
+        fn get_hooks(&self, event_name: String) -> Vec<RegisteredHook>;
+    }
+}
+
+extern_blueprint_internal! {
+    PAYMENT_STREAM_PACKAGE,
+    PaymentStream,
+    "PaymentStream",
+    "OwnedPaymentStream",
+    "GlobalPaymentStream",
+    PaymentStreamFunctions
+    {
+        fn instantiate(bucket: Bucket, rate_per_epoch: Decimal, sender_rule: AccessRule, recipient_rule: AccessRule) -> Global<PaymentStream>;
+    },
+    {
+        fn withdraw(&mut self) -> Bucket;
+        fn cancel(&mut self) -> Bucket;
+        fn get_withdrawable_amount(&self) -> Decimal;
+        fn get_info(&self) -> PaymentStreamInfo;
+    }
+}
@@ -8,12 +8,14 @@ use radix_engine_interface::api::key_value_store_api::ClientKeyValueStoreApi;
 use radix_engine_interface::api::object_api::ObjectModuleId;
 use radix_engine_interface::api::system_modules::auth_api::ClientAuthApi;
 use radix_engine_interface::api::{
-    ClientActorApi, ClientCostingApi, ClientFieldLockApi, ClientObjectApi, ObjectHandle,
+    BigIntOperation, BigIntType, ClientActorApi, ClientBigIntApi, ClientCostingApi,
+    ClientFieldLockApi, ClientObjectApi, ObjectHandle,
 };
 use radix_engine_interface::api::{ClientBlueprintApi, ClientTransactionRuntimeApi};
 use radix_engine_interface::api::{KVEntry, LockFlags};
-use radix_engine_interface::blueprints::resource::AccessRule;
+use radix_engine_interface::blueprints::resource::{AccessRule, GlobalCaller};
 use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::data::scrypto::model::NonFungibleLocalId;
 use radix_engine_interface::data::scrypto::*;
 use radix_engine_interface::types::{BlueprintId, GlobalAddress};
 use radix_engine_interface::types::{Level, LockHandle, NodeId};
@@ -170,6 +172,26 @@ impl ClientObjectApi<ClientApiError> for ScryptoEnv {
         Ok(return_data)
     }
 
+    fn call_static_method(
+        &mut self,
+        receiver: &NodeId,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<u8>, ClientApiError> {
+        let return_data = copy_buffer(unsafe {
+            call_static_method(
+                receiver.as_ref().as_ptr(),
+                receiver.as_ref().len(),
+                method_name.as_ptr(),
+                method_name.len(),
+                args.as_ptr(),
+                args.len(),
+            )
+        });
+
+        Ok(return_data)
+    }
+
     fn get_object_info(&mut self, node_id: &NodeId) -> Result<ObjectInfo, ClientApiError> {
         let bytes = copy_buffer(unsafe {
             get_object_info(node_id.as_ref().as_ptr(), node_id.as_ref().len())
@@ -193,6 +215,50 @@ impl ClientObjectApi<ClientApiError> for ScryptoEnv {
         Ok(Vec::new())
     }
 
+    fn transfer_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        amount: Decimal,
+    ) -> Result<(), ClientApiError> {
+        let amount = scrypto_encode(&amount).unwrap();
+
+        unsafe {
+            vault_transfer(
+                from_vault.as_ref().as_ptr(),
+                from_vault.as_ref().len(),
+                to_vault.as_ref().as_ptr(),
+                to_vault.as_ref().len(),
+                amount.as_ptr(),
+                amount.len(),
+            )
+        };
+
+        Ok(())
+    }
+
+    fn transfer_non_fungibles_between_vaults(
+        &mut self,
+        from_vault: &NodeId,
+        to_vault: &NodeId,
+        non_fungible_local_ids: BTreeSet<NonFungibleLocalId>,
+    ) -> Result<(), ClientApiError> {
+        let non_fungible_local_ids = scrypto_encode(&non_fungible_local_ids).unwrap();
+
+        unsafe {
+            vault_transfer_non_fungibles(
+                from_vault.as_ref().as_ptr(),
+                from_vault.as_ref().len(),
+                to_vault.as_ref().as_ptr(),
+                to_vault.as_ref().len(),
+                non_fungible_local_ids.as_ptr(),
+                non_fungible_local_ids.len(),
+            )
+        };
+
+        Ok(())
+    }
+
     fn allocate_virtual_global_address(
         &mut self,
         _blueprint_id: BlueprintId,
@@ -366,6 +432,31 @@ impl ClientActorApi<ClientApiError> for ScryptoEnv {
         Ok(handle)
     }
 
+    fn actor_open_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: &Vec<u8>,
+        flags: LockFlags,
+    ) -> Result<KeyValueEntryHandle, ClientApiError> {
+        let handle = unsafe {
+            actor_open_transient_entry(object_handle, key.as_ptr(), key.len(), flags.bits())
+        };
+
+        Ok(handle)
+    }
+
+    fn actor_remove_transient_entry(
+        &mut self,
+        object_handle: u32,
+        key: &Vec<u8>,
+    ) -> Result<Vec<u8>, ClientApiError> {
+        let removed = copy_buffer(unsafe {
+            actor_remove_transient_entry(object_handle, key.as_ptr(), key.len())
+        });
+
+        Ok(removed)
+    }
+
     fn actor_is_feature_enabled(
         &mut self,
         _: ObjectHandle,
@@ -390,6 +481,10 @@ impl ClientActorApi<ClientApiError> for ScryptoEnv {
         scrypto_decode(&global_address).map_err(ClientApiError::DecodeError)
     }
 
+    fn actor_get_global_caller(&mut self) -> Result<Option<GlobalCaller>, ClientApiError> {
+        unimplemented!("Not available for Scrypto")
+    }
+
     fn actor_get_blueprint(&mut self) -> Result<BlueprintId, ClientApiError> {
         let actor = copy_buffer(unsafe { get_blueprint() });
 
@@ -475,6 +570,38 @@ impl ClientTransactionRuntimeApi<ClientApiError> for ScryptoEnv {
         };
         Ok(())
     }
+
+    fn fail(&mut self, error_name: String, error: Vec<u8>) -> Result<(), ClientApiError> {
+        unsafe {
+            fail(
+                error_name.as_ptr(),
+                error_name.len(),
+                error.as_ptr(),
+                error.len(),
+            );
+        };
+        Ok(())
+    }
+}
+
+impl ClientBigIntApi<ClientApiError> for ScryptoEnv {
+    fn big_int_op(
+        &mut self,
+        int_type: BigIntType,
+        operation: BigIntOperation,
+        operands: Vec<u8>,
+    ) -> Result<Vec<u8>, ClientApiError> {
+        let result = copy_buffer(unsafe {
+            big_int_op(
+                int_type as u32,
+                operation as u32,
+                operands.as_ptr(),
+                operands.len(),
+            )
+        });
+
+        Ok(result)
+    }
 }
 
 #[macro_export]
@@ -113,6 +113,16 @@ extern "C" {
         args_len: usize,
     ) -> Buffer;
 
+    /// Invokes a method on a component in read-only mode.
+    pub fn call_static_method(
+        receiver_ptr: *const u8,
+        receive_len: usize,
+        ident_ptr: *const u8,
+        ident_len: usize,
+        args_ptr: *const u8,
+        args_len: usize,
+    ) -> Buffer;
+
     /// Invokes a function on a blueprint.
     pub fn call_function(
         package_address_ptr: *const u8,
@@ -128,6 +138,26 @@ extern "C" {
     /// Destroys a node.
     pub fn drop_object(node_id_ptr: *const u8, node_id_len: usize);
 
+    /// Moves an amount between two vaults of the same resource.
+    pub fn vault_transfer(
+        from_vault_ptr: *const u8,
+        from_vault_len: usize,
+        to_vault_ptr: *const u8,
+        to_vault_len: usize,
+        amount_ptr: *const u8,
+        amount_len: usize,
+    );
+
+    /// Moves the given non-fungibles between two non-fungible vaults of the same resource.
+    pub fn vault_transfer_non_fungibles(
+        from_vault_ptr: *const u8,
+        from_vault_len: usize,
+        to_vault_ptr: *const u8,
+        to_vault_len: usize,
+        non_fungible_local_ids_ptr: *const u8,
+        non_fungible_local_ids_len: usize,
+    );
+
     //===============
     // Actor API
     //===============
@@ -144,6 +174,20 @@ extern "C" {
         _args_len: usize,
     ) -> Buffer;
 
+    // Opens an entry of the transient store of the actor
+    pub fn actor_open_transient_entry(
+        object_handle: u32,
+        key: *const u8,
+        key_len: usize,
+        flags: u32,
+    ) -> u32;
+
+    pub fn actor_remove_transient_entry(
+        object_handle: u32,
+        key: *const u8,
+        key_len: usize,
+    ) -> Buffer;
+
     //===============
     // Field Lock API
     //===============
@@ -187,9 +231,23 @@ extern "C" {
 
     pub fn panic(message_ptr: *const u8, message_len: usize);
 
+    pub fn fail(
+        error_name_ptr: *const u8,
+        error_name_len: usize,
+        error_ptr: *const u8,
+        error_len: usize,
+    );
+
     pub fn get_transaction_hash() -> Buffer;
 
     pub fn generate_ruid() -> Buffer;
+
+    pub fn big_int_op(
+        int_type: u32,
+        operation: u32,
+        operands_ptr: *const u8,
+        operands_len: usize,
+    ) -> Buffer;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -317,6 +375,18 @@ pub unsafe fn call_method(
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn call_static_method(
+    _receiver_ptr: *const u8,
+    _receive_len: usize,
+    _ident_ptr: *const u8,
+    _ident_len: usize,
+    _args_ptr: *const u8,
+    _args_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn call_function(
     _package_address_ptr: *const u8,
@@ -336,11 +406,54 @@ pub unsafe fn drop_object(_node_id_ptr: *const u8, _node_id_len: usize) {
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn vault_transfer(
+    _from_vault_ptr: *const u8,
+    _from_vault_len: usize,
+    _to_vault_ptr: *const u8,
+    _to_vault_len: usize,
+    _amount_ptr: *const u8,
+    _amount_len: usize,
+) {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn vault_transfer_non_fungibles(
+    _from_vault_ptr: *const u8,
+    _from_vault_len: usize,
+    _to_vault_ptr: *const u8,
+    _to_vault_len: usize,
+    _non_fungible_local_ids_ptr: *const u8,
+    _non_fungible_local_ids_len: usize,
+) {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn actor_open_field(_object_handle: u32, _field: u32, _flags: u32) -> u32 {
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn actor_open_transient_entry(
+    _object_handle: u32,
+    _key: *const u8,
+    _key_len: usize,
+    _flags: u32,
+) -> u32 {
+    unreachable!()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn actor_remove_transient_entry(
+    _object_handle: u32,
+    _key: *const u8,
+    _key_len: usize,
+) -> Buffer {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn field_lock_read(_handle: u32) -> Buffer {
     unreachable!()
@@ -416,6 +529,16 @@ pub unsafe fn panic(_message_ptr: *const u8, _message_len: usize) {
     unreachable!()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn fail(
+    _error_name_ptr: *const u8,
+    _error_name_len: usize,
+    _error_ptr: *const u8,
+    _error_len: usize,
+) {
+    unreachable!()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub unsafe fn get_transaction_hash() -> Buffer {
     unreachable!()
@@ -425,3 +548,13 @@ pub unsafe fn get_transaction_hash() -> Buffer {
 pub unsafe fn generate_ruid() -> Buffer {
     unreachable!()
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub unsafe fn big_int_op(
+    _int_type: u32,
+    _operation: u32,
+    _operands_ptr: *const u8,
+    _operands_len: usize,
+) -> Buffer {
+    unreachable!()
+}
@@ -4,9 +4,11 @@ use crate::modules::ModuleHandle;
 use crate::prelude::Attachable;
 use radix_engine_derive::*;
 use radix_engine_interface::api::node_modules::auth::{
-    AccessRulesCreateInput, AccessRulesGetRoleInput, AccessRulesLockOwnerRoleInput,
-    AccessRulesSetOwnerRoleInput, AccessRulesSetRoleInput, ACCESS_RULES_BLUEPRINT,
-    ACCESS_RULES_CREATE_IDENT, ACCESS_RULES_GET_ROLE_IDENT, ACCESS_RULES_LOCK_OWNER_ROLE_IDENT,
+    AccessRulesCreateInput, AccessRulesGetOwnerRoleHistoryInput, AccessRulesGetRoleInput,
+    AccessRulesLockOwnerRoleInput, AccessRulesRenounceRoleInput, AccessRulesSetOwnerRoleInput,
+    AccessRulesSetRoleInput, OwnerRoleChange, ACCESS_RULES_BLUEPRINT, ACCESS_RULES_CREATE_IDENT,
+    ACCESS_RULES_GET_OWNER_ROLE_HISTORY_IDENT, ACCESS_RULES_GET_ROLE_IDENT,
+    ACCESS_RULES_LOCK_OWNER_ROLE_IDENT, ACCESS_RULES_RENOUNCE_ROLE_IDENT,
     ACCESS_RULES_SET_OWNER_ROLE_IDENT, ACCESS_RULES_SET_ROLE_IDENT,
 };
 use radix_engine_interface::api::*;
@@ -23,6 +25,7 @@ pub trait HasAccessRules {
     fn set_owner_role<A: Into<AccessRule>>(&self, rule: A);
     fn lock_owner_role<A: Into<AccessRule>>(&self);
     fn set_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
+    fn renounce_role(&self, name: &str);
     fn get_role(&self, name: &str) -> Option<AccessRule>;
     fn set_metadata_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
     fn set_component_royalties_role<A: Into<AccessRule>>(&self, name: &str, rule: A);
@@ -66,6 +69,14 @@ impl AccessRules {
         );
     }
 
+    /// Returns the most recent owner role changes, oldest first.
+    pub fn get_owner_role_history(&self) -> Vec<OwnerRoleChange> {
+        self.call(
+            ACCESS_RULES_GET_OWNER_ROLE_HISTORY_IDENT,
+            &AccessRulesGetOwnerRoleHistoryInput {},
+        )
+    }
+
     fn internal_set_role<A: Into<AccessRule>>(&self, module: ObjectModuleId, name: &str, rule: A) {
         self.call_ignore_rtn(
             ACCESS_RULES_SET_ROLE_IDENT,
@@ -77,6 +88,16 @@ impl AccessRules {
         );
     }
 
+    fn internal_renounce_role(&self, module: ObjectModuleId, name: &str) {
+        self.call_ignore_rtn(
+            ACCESS_RULES_RENOUNCE_ROLE_IDENT,
+            &AccessRulesRenounceRoleInput {
+                module,
+                role_key: RoleKey::new(name),
+            },
+        );
+    }
+
     fn internal_get_role(&self, module: ObjectModuleId, name: &str) -> Option<AccessRule> {
         self.call(
             ACCESS_RULES_GET_ROLE_IDENT,
@@ -91,6 +112,11 @@ impl AccessRules {
         self.internal_set_role(ObjectModuleId::Main, name, rule);
     }
 
+    /// Permanently denies the role to everyone, after which it can no longer be updated.
+    pub fn renounce_role(&self, name: &str) {
+        self.internal_renounce_role(ObjectModuleId::Main, name);
+    }
+
     pub fn get_role(&self, name: &str) -> Option<AccessRule> {
         self.internal_get_role(ObjectModuleId::Main, name)
     }
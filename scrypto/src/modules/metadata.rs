@@ -18,6 +18,7 @@ pub trait HasMetadata {
     fn set_metadata<K: AsRef<str>, V: MetadataVal>(&self, name: K, value: V);
     fn get_metadata<K: ToString, V: MetadataVal>(&self, name: K) -> Result<V, MetadataError>;
     fn remove_metadata<K: ToString>(&self, name: K) -> bool;
+    fn lock_metadata<K: ToString>(&self, name: K);
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -142,4 +143,14 @@ impl Metadata {
 
         rtn
     }
+
+    /// Locks the entry, so that it can no longer be set or removed.
+    pub fn lock<K: ToString>(&self, name: K) {
+        self.call_ignore_rtn(
+            METADATA_LOCK_IDENT,
+            &MetadataLockInput {
+                key: name.to_string(),
+            },
+        );
+    }
 }
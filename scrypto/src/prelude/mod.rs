@@ -26,6 +26,8 @@ pub use num_traits::{
 pub use radix_engine_interface::api::node_modules::auth::*;
 pub use radix_engine_interface::api::node_modules::metadata::*;
 pub use radix_engine_interface::api::node_modules::*;
+pub use radix_engine_interface::blueprints::hook_registry::{RegisteredHook, MAX_HOOKS_PER_EVENT};
+pub use radix_engine_interface::blueprints::payment_stream::PaymentStreamInfo;
 pub use radix_engine_interface::blueprints::resource::*;
 pub use radix_engine_interface::crypto::*;
 pub use radix_engine_interface::math::*;
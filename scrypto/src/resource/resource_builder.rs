@@ -1,7 +1,7 @@
 use crate::engine::scrypto_env::ScryptoEnv;
 use crate::radix_engine_interface::api::ClientBlueprintApi;
 use crate::runtime::Runtime;
-use radix_engine_interface::api::node_modules::auth::RoleDefinition;
+use radix_engine_interface::api::node_modules::auth::{RoleDefinition, OWNER};
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::blueprints::resource::*;
@@ -71,6 +71,114 @@ impl ResourceBuilder {
     ) -> InProgressResourceBuilder<NonFungibleResourceType<RUIDNonFungibleLocalId, D>> {
         InProgressResourceBuilder::new(owner_role)
     }
+
+    /// Starts a new builder from the badge preset: a fungible resource which is not divisible.
+    ///
+    /// The preset is only a starting point - any builder method called afterwards overrides it.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// let admin_badge = ResourceBuilder::new_badge(OwnerRole::None)
+    ///     .mint_initial_supply(1);
+    /// ```
+    pub fn new_badge(owner_role: OwnerRole) -> InProgressResourceBuilder<FungibleResourceType> {
+        Self::new_fungible(owner_role).divisibility(DIVISIBILITY_NONE)
+    }
+
+    /// Starts a new builder from the soulbound badge preset: a badge which can never be withdrawn
+    /// from the vault it is first deposited into.
+    ///
+    /// The preset is only a starting point - any builder method called afterwards overrides it.
+    pub fn new_soulbound_badge(
+        owner_role: OwnerRole,
+    ) -> InProgressResourceBuilder<FungibleResourceType> {
+        Self::new_badge(owner_role).withdraw_roles(withdraw_roles! {
+            withdrawer => rule!(deny_all);
+            withdrawer_updater => rule!(deny_all);
+        })
+    }
+
+    /// Starts a new builder from the share token preset: a fungible resource which can only be
+    /// minted and burned by the given pool component, with a locked `pool` metadata entry
+    /// pointing at the pool.
+    ///
+    /// The preset is only a starting point - any builder method called afterwards overrides it.
+    /// Note that setting the metadata replaces the preset's `pool` entry.
+    pub fn new_share_token(
+        owner_role: OwnerRole,
+        pool: ComponentAddress,
+    ) -> InProgressResourceBuilder<FungibleResourceType> {
+        Self::new_fungible(owner_role)
+            .divisibility(DIVISIBILITY_MAXIMUM)
+            .mint_roles(mint_roles! {
+                minter => rule!(require(global_caller(pool)));
+                minter_updater => rule!(deny_all);
+            })
+            .burn_roles(burn_roles! {
+                burner => rule!(require(global_caller(pool)));
+                burner_updater => rule!(deny_all);
+            })
+            .metadata(metadata! {
+                init {
+                    "pool" => GlobalAddress::from(pool), locked;
+                }
+            })
+    }
+
+    /// Starts a new builder from the stablecoin preset: a fungible resource whose whole supply,
+    /// of at most `max_supply`, is minted on creation.
+    ///
+    /// Minting is disabled for good, and burning, freezing and recalling fall to the owner,
+    /// without being updatable. The max supply is also published in a locked `max_supply`
+    /// metadata entry.
+    ///
+    /// The preset is only a starting point - any builder method called afterwards overrides it.
+    /// Note that enabling minting lifts the max supply guarantee, and that setting the metadata
+    /// replaces the preset's `max_supply` entry.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// # let issuer_badge = XRD;
+    /// let bucket = ResourceBuilder::new_stablecoin(
+    ///     OwnerRole::Fixed(rule!(require(issuer_badge))),
+    ///     dec!(1000000),
+    /// )
+    /// .mint_initial_supply(1000000);
+    /// ```
+    pub fn new_stablecoin(
+        owner_role: OwnerRole,
+        max_supply: Decimal,
+    ) -> InProgressResourceBuilder<FungibleResourceType> {
+        let mut builder = Self::new_fungible(owner_role)
+            .divisibility(DIVISIBILITY_MAXIMUM)
+            .mint_roles(mint_roles! {
+                minter => rule!(deny_all);
+                minter_updater => rule!(deny_all);
+            })
+            .burn_roles(burn_roles! {
+                burner => OWNER;
+                burner_updater => rule!(deny_all);
+            })
+            .freeze_roles(freeze_roles! {
+                freezer => OWNER;
+                freezer_updater => rule!(deny_all);
+            })
+            .recall_roles(recall_roles! {
+                recaller => OWNER;
+                recaller_updater => rule!(deny_all);
+            })
+            .metadata(metadata! {
+                init {
+                    "max_supply" => max_supply, locked;
+                }
+            });
+        builder.resource_type.max_supply = Some(max_supply);
+        builder
+    }
 }
 
 /// Utility for setting up a new resource, which has building in progress.
@@ -115,6 +223,7 @@ pub trait AnyResourceType: Default {
 
 pub struct FungibleResourceType {
     divisibility: u8,
+    max_supply: Option<Decimal>,
 }
 impl AnyResourceType for FungibleResourceType {
     type ResourceRoles = FungibleResourceRoles;
@@ -123,6 +232,7 @@ impl Default for FungibleResourceType {
     fn default() -> Self {
         Self {
             divisibility: DIVISIBILITY_MAXIMUM,
+            max_supply: None,
         }
     }
 }
@@ -547,7 +657,7 @@ impl InProgressResourceBuilder<FungibleResourceType> {
     /// ```
     pub fn divisibility(mut self, divisibility: u8) -> Self {
         assert!(divisibility <= 18);
-        self.resource_type = FungibleResourceType { divisibility };
+        self.resource_type.divisibility = divisibility;
         self
     }
 }
@@ -563,6 +673,16 @@ impl InProgressResourceBuilder<FungibleResourceType> {
     ///     .mint_initial_supply(5);
     /// ```
     pub fn mint_initial_supply<T: Into<Decimal>>(mut self, amount: T) -> Bucket {
+        let amount = amount.into();
+        if let Some(max_supply) = self.resource_type.max_supply {
+            assert!(
+                amount <= max_supply,
+                "Initial supply {} exceeds the max supply {}",
+                amount,
+                max_supply
+            );
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -579,7 +699,7 @@ impl InProgressResourceBuilder<FungibleResourceType> {
                     divisibility: self.resource_type.divisibility,
                     resource_roles: self.resource_roles,
                     metadata,
-                    initial_supply: amount.into(),
+                    initial_supply: amount,
                     address_reservation: self.address_reservation,
                 })
                 .unwrap(),
@@ -121,6 +121,19 @@ impl ResourceManager {
         self.0.set_role(FREEZER_UPDATER_ROLE, AccessRule::DenyAll);
     }
 
+    /// Permanently prevents any further minting, fixing the supply of the resource.
+    pub fn renounce_minting(&self) {
+        self.0.renounce_role(MINTER_ROLE);
+    }
+
+    pub fn renounce_burning(&self) {
+        self.0.renounce_role(BURNER_ROLE);
+    }
+
+    pub fn renounce_recalling(&self) {
+        self.0.renounce_role(RECALLER_ROLE);
+    }
+
     pub fn set_updatable_metadata(&self, access_rule: AccessRule) {
         self.0.set_metadata_role(METADATA_SETTER_ROLE, access_rule);
     }
@@ -179,6 +192,24 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Starts tracking the vaults holding this fungible resource, which must have a tracked
+    /// total supply of zero
+    pub fn enable_reserve_tracking(&self) {
+        self.call(
+            FUNGIBLE_RESOURCE_MANAGER_ENABLE_RESERVE_TRACKING_IDENT,
+            &FungibleResourceManagerEnableReserveTrackingInput {},
+        )
+    }
+
+    /// Returns the aggregate amount of this fungible resource held in vaults, with a breakdown
+    /// of the `top_holders` largest holdings
+    pub fn proof_of_reserves(&self, top_holders: u32) -> ProofOfReserves {
+        self.call(
+            FUNGIBLE_RESOURCE_MANAGER_PROOF_OF_RESERVES_IDENT,
+            &FungibleResourceManagerProofOfReservesInput { top_holders },
+        )
+    }
+
     pub fn non_fungible_exists(&self, id: &NonFungibleLocalId) -> bool {
         self.call(
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
@@ -60,6 +60,8 @@ pub trait ScryptoFungibleVault {
     fn create_proof_of_amount<A: Into<Decimal>>(&self, amount: A) -> FungibleProof;
 
     fn authorize_with_amount<A: Into<Decimal>, F: FnOnce() -> O, O>(&self, amount: A, f: F) -> O;
+
+    fn transfer_to<A: Into<Decimal>>(&mut self, to: &mut FungibleVault, amount: A);
 }
 
 pub trait ScryptoNonFungibleVault {
@@ -93,6 +95,14 @@ pub trait ScryptoNonFungibleVault {
         non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
         f: F,
     ) -> O;
+
+    fn transfer_to<A: Into<Decimal>>(&mut self, to: &mut NonFungibleVault, amount: A);
+
+    fn transfer_non_fungibles_to(
+        &mut self,
+        to: &mut NonFungibleVault,
+        non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
+    );
 }
 
 //===========
@@ -349,6 +359,14 @@ impl ScryptoFungibleVault for FungibleVault {
         LocalAuthZone::pop().drop();
         output
     }
+
+    /// Moves the specified amount into another vault of the same resource, without going
+    /// through a bucket.
+    fn transfer_to<A: Into<Decimal>>(&mut self, to: &mut FungibleVault, amount: A) {
+        let mut env = ScryptoEnv;
+        env.transfer_between_vaults(self.0 .0.as_node_id(), to.0 .0.as_node_id(), amount.into())
+            .unwrap();
+    }
 }
 
 //====================
@@ -537,4 +555,49 @@ impl ScryptoNonFungibleVault for NonFungibleVault {
         LocalAuthZone::pop().drop();
         output
     }
+
+    /// Moves the specified amount of non-fungibles into another vault of the same resource,
+    /// without going through a bucket.
+    fn transfer_to<A: Into<Decimal>>(&mut self, to: &mut NonFungibleVault, amount: A) {
+        let mut env = ScryptoEnv;
+        env.transfer_between_vaults(self.0 .0.as_node_id(), to.0 .0.as_node_id(), amount.into())
+            .unwrap();
+    }
+
+    /// Moves the specified non-fungibles into another vault of the same resource, without going
+    /// through a bucket.
+    fn transfer_non_fungibles_to(
+        &mut self,
+        to: &mut NonFungibleVault,
+        non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
+    ) {
+        let mut env = ScryptoEnv;
+        env.transfer_non_fungibles_between_vaults(
+            self.0 .0.as_node_id(),
+            to.0 .0.as_node_id(),
+            non_fungible_local_ids.clone(),
+        )
+        .unwrap();
+    }
+}
+
+//===============
+// Vault summary
+//===============
+
+/// The resource and amount of a vault, as reported in place of the vault by the
+/// `export_state` method of `#[exportable_state]` blueprints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct VaultSummary {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl VaultSummary {
+    pub fn of<V: ScryptoVault>(vault: &V) -> Self {
+        Self {
+            resource_address: vault.resource_address(),
+            amount: vault.amount(),
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use crate::engine::scrypto_env::ScryptoEnv;
+use radix_engine_interface::api::{BigIntOperation, BigIntType, ClientBigIntApi};
+use radix_engine_interface::math::*;
+use sbor::rust::fmt;
+use sbor::rust::ops::{Add, Div, Mul, Sub};
+use sbor::rust::prelude::*;
+
+macro_rules! engine_ints {
+    ($($t:ident, $inner:ident, $int_type:expr),*) => {
+        $(
+            /// A fixed-width integer whose arithmetic is evaluated by the engine rather than in
+            /// WASM, which is cheaper for large operands and keeps bigint code out of the package.
+            ///
+            /// Like the Bnum types, operations panic on overflow and division by zero, which
+            /// fails the transaction.
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+            pub struct $t(pub $inner);
+
+            impl $t {
+                pub const ZERO: Self = Self($inner::ZERO);
+                pub const ONE: Self = Self($inner::ONE);
+                pub const MIN: Self = Self($inner::MIN);
+                pub const MAX: Self = Self($inner::MAX);
+
+                /// Returns `self ^ exponent mod modulus`, in `[0, modulus)`.
+                ///
+                /// Panics if the exponent is negative or the modulus is not positive.
+                pub fn mod_pow(&self, exponent: Self, modulus: Self) -> Self {
+                    evaluate(BigIntOperation::ModPow, &[*self, exponent, modulus])
+                }
+            }
+
+            impl EngineInt for $t {
+                const INT_TYPE: BigIntType = $int_type;
+
+                fn to_le_bytes(&self) -> Vec<u8> {
+                    self.0.to_vec()
+                }
+
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    Self($inner::from_le_bytes(bytes))
+                }
+            }
+
+            impl From<$inner> for $t {
+                fn from(value: $inner) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$t> for $inner {
+                fn from(value: $t) -> Self {
+                    value.0
+                }
+            }
+
+            impl Add for $t {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    evaluate(BigIntOperation::Add, &[self, other])
+                }
+            }
+
+            impl Sub for $t {
+                type Output = Self;
+
+                fn sub(self, other: Self) -> Self {
+                    evaluate(BigIntOperation::Sub, &[self, other])
+                }
+            }
+
+            impl Mul for $t {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    evaluate(BigIntOperation::Mul, &[self, other])
+                }
+            }
+
+            impl Div for $t {
+                type Output = Self;
+
+                fn div(self, other: Self) -> Self {
+                    evaluate(BigIntOperation::Div, &[self, other])
+                }
+            }
+
+            impl fmt::Debug for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.0, f)
+                }
+            }
+
+            impl fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+trait EngineInt: Sized {
+    const INT_TYPE: BigIntType;
+
+    fn to_le_bytes(&self) -> Vec<u8>;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+fn evaluate<T: EngineInt>(operation: BigIntOperation, operands: &[T]) -> T {
+    let operands: Vec<u8> = operands.iter().flat_map(|x| x.to_le_bytes()).collect();
+    let result = ScryptoEnv
+        .big_int_op(T::INT_TYPE, operation, operands)
+        .unwrap();
+    T::from_le_bytes(&result)
+}
+
+engine_ints! {
+    EngineI256, BnumI256, BigIntType::I256,
+    EngineI384, BnumI384, BigIntType::I384,
+    EngineU256, BnumU256, BigIntType::U256,
+    EngineU384, BnumU384, BigIntType::U384
+}
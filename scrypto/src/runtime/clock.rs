@@ -1,12 +1,16 @@
 use radix_engine_interface::api::ClientObjectApi;
 use radix_engine_interface::blueprints::consensus_manager::{
-    ConsensusManagerCompareCurrentTimeInput, ConsensusManagerGetCurrentTimeInput, TimePrecision,
+    ConsensusManagerCompareCurrentTimeInput, ConsensusManagerGetCurrentTimeInput,
+    ConsensusManagerGetEpochHistoryInput, EpochStartRecord, TimePrecision,
     CONSENSUS_MANAGER_COMPARE_CURRENT_TIME_IDENT, CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT,
+    CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT,
 };
 use radix_engine_interface::constants::CONSENSUS_MANAGER;
 use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode};
 use radix_engine_interface::time::*;
+use radix_engine_interface::types::Epoch;
 use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
 use scrypto::engine::scrypto_env::ScryptoEnv;
 
 /// The system clock
@@ -80,4 +84,28 @@ impl Clock {
 
         scrypto_decode(&rtn).unwrap()
     }
+
+    /// Returns the start records of the most recent epochs (at most
+    /// [`EPOCH_HISTORY_CAPACITY`](radix_engine_interface::blueprints::consensus_manager::EPOCH_HISTORY_CAPACITY)
+    /// of them), ordered from the oldest to the current epoch.
+    pub fn epoch_history() -> Vec<EpochStartRecord> {
+        let mut env = ScryptoEnv;
+        let rtn = env
+            .call_method(
+                CONSENSUS_MANAGER.as_node_id(),
+                CONSENSUS_MANAGER_GET_EPOCH_HISTORY_IDENT,
+                scrypto_encode(&ConsensusManagerGetEpochHistoryInput).unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
+    /// Returns the start instant (rounded down to seconds) of the given epoch, if it is still
+    /// within the retained epoch history.
+    pub fn epoch_start_time(epoch: Epoch) -> Option<Instant> {
+        Self::epoch_history()
+            .into_iter()
+            .find(|record| record.epoch == epoch)
+            .map(|record| record.start_instant())
+    }
 }
@@ -96,6 +96,46 @@ impl LocalAuthZone {
         scrypto_decode(&rtn).unwrap()
     }
 
+    /// Opens a scope with the given proofs, which are dropped when the scope is popped.
+    ///
+    /// While a scope is open, proofs can't be popped, drained or composed from the auth zone.
+    pub fn push_scope(proofs: Vec<Proof>) {
+        let mut env = ScryptoEnv;
+        let node_id = env.get_auth_zone().unwrap();
+        env.call_method(
+            &node_id,
+            AUTH_ZONE_PUSH_SCOPE_IDENT,
+            scrypto_encode(&AuthZonePushScopeInput { proofs }).unwrap(),
+        )
+        .unwrap();
+    }
+
+    /// Closes the innermost scope, dropping the proofs pushed since it was opened.
+    pub fn pop_scope() {
+        let mut env = ScryptoEnv;
+        let node_id = env.get_auth_zone().unwrap();
+        env.call_method(
+            &node_id,
+            AUTH_ZONE_POP_SCOPE_IDENT,
+            scrypto_encode(&AuthZonePopScopeInput {}).unwrap(),
+        )
+        .unwrap();
+    }
+
+    /// Returns the number of open scopes.
+    pub fn scope_depth() -> u32 {
+        let mut env = ScryptoEnv;
+        let node_id = env.get_auth_zone().unwrap();
+        let rtn = env
+            .call_method(
+                &node_id,
+                AUTH_ZONE_SCOPE_DEPTH_IDENT,
+                scrypto_encode(&AuthZoneScopeDepthInput {}).unwrap(),
+            )
+            .unwrap();
+        scrypto_decode(&rtn).unwrap()
+    }
+
     pub fn clear() {
         let mut env = ScryptoEnv;
         let node_id = env.get_auth_zone().unwrap();
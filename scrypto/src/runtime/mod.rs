@@ -1,12 +1,16 @@
+mod big_int;
 mod clock;
 mod data;
 mod local_auth_zone;
 mod logger;
 mod runtime;
+mod transient;
 
+pub use big_int::*;
 pub use clock::*;
 pub use data::*;
 pub use local_auth_zone::*;
 pub use logger::Logger;
 pub use radix_engine_interface::data::scrypto::model::*;
 pub use runtime::*;
+pub use transient::*;
@@ -1,5 +1,6 @@
 use crate::component::ObjectStubHandle;
 use crate::prelude::{AnyComponent, Global};
+use crate::runtime::LocalAuthZone;
 use radix_engine_common::math::Decimal;
 use radix_engine_common::types::GlobalAddressReservation;
 use radix_engine_interface::api::system_modules::auth_api::ClientAuthApi;
@@ -7,7 +8,7 @@ use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::consensus_manager::{
     ConsensusManagerGetCurrentEpochInput, CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT,
 };
-use radix_engine_interface::blueprints::resource::{AccessRule, NonFungibleGlobalId};
+use radix_engine_interface::blueprints::resource::{AccessRule, NonFungibleGlobalId, Proof};
 use radix_engine_interface::constants::CONSENSUS_MANAGER;
 use radix_engine_interface::crypto::Hash;
 use radix_engine_interface::data::scrypto::{
@@ -82,6 +83,17 @@ impl Runtime {
         env.assert_access_rule(access_rule).unwrap();
     }
 
+    /// Runs `f` with the given proofs in the auth zone, dropping them once `f` returns.
+    ///
+    /// The proofs can't leave the scope: the auth zone refuses to pop, drain or compose proofs
+    /// while a scope is open, and a panic within `f` aborts the transaction.
+    pub fn with_auth_scope<F: FnOnce() -> O, O>(proofs: Vec<Proof>, f: F) -> O {
+        LocalAuthZone::push_scope(proofs);
+        let output = f();
+        LocalAuthZone::pop_scope();
+        output
+    }
+
     pub fn allocate_component_address(
         blueprint_id: BlueprintId,
     ) -> (GlobalAddressReservation, ComponentAddress) {
@@ -112,4 +124,13 @@ impl Runtime {
         ScryptoEnv.panic(message).unwrap();
         loop {}
     }
+
+    /// Fails the transaction with a typed error, which is kept in the receipt along with the
+    /// method which failed, rather than as a panic message.
+    pub fn fail<E: ScryptoEncode + ScryptoDescribe>(error: E) -> ! {
+        ScryptoEnv
+            .fail(blueprint_error_name::<E>(), scrypto_encode(&error).unwrap())
+            .unwrap();
+        loop {}
+    }
 }
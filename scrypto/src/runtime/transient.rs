@@ -0,0 +1,54 @@
+use crate::engine::scrypto_env::ScryptoEnv;
+use radix_engine_interface::api::field_lock_api::LockFlags;
+use radix_engine_interface::api::key_value_entry_api::ClientKeyValueEntryApi;
+use radix_engine_interface::api::{ClientActorApi, OBJECT_HANDLE_SELF};
+use radix_engine_interface::data::scrypto::model::*;
+use radix_engine_interface::data::scrypto::*;
+use sbor::rust::prelude::*;
+
+/// Scratch storage of the current component which only lives until the end of the transaction.
+///
+/// Entries are shared by all calls to the component within a transaction, and are discarded
+/// when the transaction completes. Values must not contain buckets, vaults or other owned objects.
+#[derive(Debug)]
+pub struct TransientState {}
+
+impl TransientState {
+    /// Returns the value associated with the given key, if set earlier in this transaction.
+    pub fn get<K: ScryptoEncode, V: ScryptoDecode>(key: &K) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(key).unwrap();
+        let handle = env
+            .actor_open_transient_entry(OBJECT_HANDLE_SELF, &key_payload, LockFlags::read_only())
+            .unwrap();
+        let raw_bytes = env.key_value_entry_get(handle).unwrap();
+        env.key_value_entry_release(handle).unwrap();
+
+        let substate: Option<ScryptoValue> = scrypto_decode(&raw_bytes).unwrap();
+        substate.map(|value| scrypto_decode(&scrypto_encode(&value).unwrap()).unwrap())
+    }
+
+    /// Associates a value with the given key for the rest of the transaction.
+    pub fn insert<K: ScryptoEncode, V: ScryptoEncode>(key: K, value: V) {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(&key).unwrap();
+        let handle = env
+            .actor_open_transient_entry(OBJECT_HANDLE_SELF, &key_payload, LockFlags::MUTABLE)
+            .unwrap();
+        let value_payload = scrypto_encode(&value).unwrap();
+
+        env.key_value_entry_set(handle, value_payload).unwrap();
+        env.key_value_entry_release(handle).unwrap();
+    }
+
+    /// Removes an entry and returns its value, if any.
+    pub fn remove<K: ScryptoEncode, V: ScryptoDecode>(key: &K) -> Option<V> {
+        let mut env = ScryptoEnv;
+        let key_payload = scrypto_encode(key).unwrap();
+        let rtn = env
+            .actor_remove_transient_entry(OBJECT_HANDLE_SELF, &key_payload)
+            .unwrap();
+
+        scrypto_decode(&rtn).unwrap()
+    }
+}
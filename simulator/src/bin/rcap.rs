@@ -0,0 +1,9 @@
+#[cfg(windows)]
+use colored::*;
+use simulator::rcap;
+
+pub fn main() -> Result<(), rcap::Error> {
+    #[cfg(windows)]
+    control::set_virtual_terminal(true).unwrap();
+    rcap::run()
+}
@@ -0,0 +1,9 @@
+#[cfg(windows)]
+use colored::*;
+use simulator::rtvg;
+
+pub fn main() -> Result<(), rtvg::Error> {
+    #[cfg(windows)]
+    control::set_virtual_terminal(true).unwrap();
+    rtvg::run()
+}
@@ -1,11 +1,15 @@
 /// Provides a file-based ledger.
 pub mod ledger;
+/// Radix client API capability manifest generator CLI.
+pub mod rcap;
 /// Radix Engine Simulator CLI.
 pub mod resim;
 /// Radix transaction manifest compiler CLI.
 pub mod rtmc;
 /// Radix transaction manifest decompiler CLI.
 pub mod rtmd;
+/// Radix test vector generator CLI.
+pub mod rtvg;
 /// Scrypto CLI.
 pub mod scrypto;
 /// Utility functions.
@@ -0,0 +1,730 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The capability manifest of the client API traits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityManifest {
+    pub traits: Vec<TraitCapabilities>,
+}
+
+/// The capabilities exposed by a single `Client*Api` trait.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraitCapabilities {
+    pub name: String,
+    /// Path of the declaring file, relative to the scanned directory
+    pub file: String,
+    pub supertraits: Vec<String>,
+    pub functions: Vec<FunctionCapability>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionCapability {
+    pub name: String,
+    pub generics: Vec<String>,
+    /// Inputs, excluding the receiver
+    pub inputs: Vec<FunctionInput>,
+    pub output: String,
+    /// Whether the trait provides a default implementation
+    pub provided: bool,
+    pub costing_class: CostingClass,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionInput {
+    pub name: String,
+    pub ty: String,
+}
+
+/// The kind of cost a client API function is charged with by the costing module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CostingClass {
+    /// Invokes another function or method, paying for the callee's execution
+    Invoke,
+    /// Creates, allocates, globalizes or drops nodes
+    NodeLifecycle,
+    /// Opens, reads, writes or closes substates
+    SubstateAccess,
+    /// Reads system state, charged with the base system call cost only
+    Query,
+    /// Emits events or logs, charged by size
+    Emission,
+    /// Consumes or credits cost units from the fee reserve
+    FeeAccounting,
+    /// Pure computation, charged by operation
+    Computation,
+    /// Evaluates access rules against the auth zone
+    Auth,
+}
+
+impl CostingClass {
+    /// Classifies a client API function by its name.
+    ///
+    /// The rules are matched in order, with `Query` as the fallback.
+    pub fn classify(name: &str) -> Self {
+        if name.contains("call_") || name.starts_with("transfer_") {
+            CostingClass::Invoke
+        } else if name == "consume_cost_units" || name == "credit_cost_units" {
+            CostingClass::FeeAccounting
+        } else if name.starts_with("emit_") || name == "panic" {
+            CostingClass::Emission
+        } else if name == "assert_access_rule" {
+            CostingClass::Auth
+        } else if name.starts_with("big_int_") {
+            CostingClass::Computation
+        } else if name.starts_with("new_")
+            || name.starts_with("allocate_")
+            || name.starts_with("globalize")
+            || name.starts_with("drop_")
+            || name.ends_with("_new")
+        {
+            CostingClass::NodeLifecycle
+        } else if [
+            "_open_",
+            "_read",
+            "_write",
+            "_release",
+            "_freeze",
+            "_insert",
+            "_remove",
+            "_scan",
+            "_take",
+            "_set",
+            "_entry_get",
+        ]
+        .iter()
+        .any(|pattern| name.contains(pattern))
+        {
+            CostingClass::SubstateAccess
+        } else {
+            CostingClass::Query
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CapabilityError {
+    IoError(PathBuf, std::io::Error),
+    UnbalancedDelimiter { file: String, position: usize },
+}
+
+impl CapabilityManifest {
+    /// Scans all `.rs` files under the given directory for `Client*Api` traits.
+    pub fn from_directory(root: &Path) -> Result<Self, CapabilityError> {
+        let mut files = Vec::new();
+        collect_source_files(root, root, &mut files)?;
+        files.sort();
+
+        let mut traits = Vec::new();
+        for relative_path in files {
+            let path = root.join(&relative_path);
+            let source = std::fs::read_to_string(&path)
+                .map_err(|e| CapabilityError::IoError(path.clone(), e))?;
+            traits.extend(parse_traits(&relative_path, &source)?);
+        }
+        traits.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(Self { traits })
+    }
+
+    /// Lists the changes from `previous` to `self`.
+    pub fn diff(&self, previous: &CapabilityManifest) -> Vec<CapabilityChange> {
+        let mut changes = Vec::new();
+
+        for old in &previous.traits {
+            let new = match self.traits.iter().find(|t| t.name == old.name) {
+                Some(new) => new,
+                None => {
+                    changes.push(CapabilityChange::TraitRemoved {
+                        trait_name: old.name.clone(),
+                    });
+                    continue;
+                }
+            };
+
+            for supertrait in &old.supertraits {
+                if !new.supertraits.contains(supertrait) {
+                    changes.push(CapabilityChange::SupertraitRemoved {
+                        trait_name: old.name.clone(),
+                        supertrait: supertrait.clone(),
+                    });
+                }
+            }
+            for supertrait in &new.supertraits {
+                if !old.supertraits.contains(supertrait) {
+                    changes.push(CapabilityChange::SupertraitAdded {
+                        trait_name: old.name.clone(),
+                        supertrait: supertrait.clone(),
+                    });
+                }
+            }
+
+            for old_function in &old.functions {
+                match new.functions.iter().find(|f| f.name == old_function.name) {
+                    None => changes.push(CapabilityChange::FunctionRemoved {
+                        trait_name: old.name.clone(),
+                        function: old_function.name.clone(),
+                    }),
+                    Some(new_function) => {
+                        if new_function.generics != old_function.generics
+                            || new_function.inputs != old_function.inputs
+                            || new_function.output != old_function.output
+                        {
+                            changes.push(CapabilityChange::SignatureChanged {
+                                trait_name: old.name.clone(),
+                                function: old_function.name.clone(),
+                            });
+                        }
+                        if new_function.costing_class != old_function.costing_class {
+                            changes.push(CapabilityChange::CostingClassChanged {
+                                trait_name: old.name.clone(),
+                                function: old_function.name.clone(),
+                                from: old_function.costing_class,
+                                to: new_function.costing_class,
+                            });
+                        }
+                    }
+                }
+            }
+            for new_function in &new.functions {
+                if !old.functions.iter().any(|f| f.name == new_function.name) {
+                    changes.push(CapabilityChange::FunctionAdded {
+                        trait_name: old.name.clone(),
+                        function: new_function.name.clone(),
+                    });
+                }
+            }
+        }
+
+        for new in &self.traits {
+            if !previous.traits.iter().any(|t| t.name == new.name) {
+                changes.push(CapabilityChange::TraitAdded {
+                    trait_name: new.name.clone(),
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityChange {
+    TraitAdded {
+        trait_name: String,
+    },
+    TraitRemoved {
+        trait_name: String,
+    },
+    SupertraitAdded {
+        trait_name: String,
+        supertrait: String,
+    },
+    SupertraitRemoved {
+        trait_name: String,
+        supertrait: String,
+    },
+    FunctionAdded {
+        trait_name: String,
+        function: String,
+    },
+    FunctionRemoved {
+        trait_name: String,
+        function: String,
+    },
+    SignatureChanged {
+        trait_name: String,
+        function: String,
+    },
+    CostingClassChanged {
+        trait_name: String,
+        function: String,
+        from: CostingClass,
+        to: CostingClass,
+    },
+}
+
+impl CapabilityChange {
+    /// Whether existing callers of the API (including generated bindings) may break.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            CapabilityChange::TraitRemoved { .. }
+            | CapabilityChange::SupertraitRemoved { .. }
+            | CapabilityChange::FunctionRemoved { .. }
+            | CapabilityChange::SignatureChanged { .. } => true,
+            CapabilityChange::TraitAdded { .. }
+            | CapabilityChange::SupertraitAdded { .. }
+            | CapabilityChange::FunctionAdded { .. }
+            | CapabilityChange::CostingClassChanged { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for CapabilityChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CapabilityChange::TraitAdded { trait_name } => write!(f, "Added trait {}", trait_name),
+            CapabilityChange::TraitRemoved { trait_name } => {
+                write!(f, "Removed trait {}", trait_name)
+            }
+            CapabilityChange::SupertraitAdded {
+                trait_name,
+                supertrait,
+            } => write!(f, "Added supertrait {} to {}", supertrait, trait_name),
+            CapabilityChange::SupertraitRemoved {
+                trait_name,
+                supertrait,
+            } => write!(f, "Removed supertrait {} from {}", supertrait, trait_name),
+            CapabilityChange::FunctionAdded {
+                trait_name,
+                function,
+            } => write!(f, "Added function {}::{}", trait_name, function),
+            CapabilityChange::FunctionRemoved {
+                trait_name,
+                function,
+            } => write!(f, "Removed function {}::{}", trait_name, function),
+            CapabilityChange::SignatureChanged {
+                trait_name,
+                function,
+            } => write!(f, "Changed signature of {}::{}", trait_name, function),
+            CapabilityChange::CostingClassChanged {
+                trait_name,
+                function,
+                from,
+                to,
+            } => write!(
+                f,
+                "Changed costing class of {}::{} from {:?} to {:?}",
+                trait_name, function, from, to
+            ),
+        }
+    }
+}
+
+fn collect_source_files(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<String>,
+) -> Result<(), CapabilityError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| CapabilityError::IoError(dir.into(), e))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| CapabilityError::IoError(dir.into(), e))?
+            .path();
+        if path.is_dir() {
+            collect_source_files(root, &path, files)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+            let relative_path = path
+                .strip_prefix(root)
+                .unwrap()
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<String>>()
+                .join("/");
+            files.push(relative_path);
+        }
+    }
+    Ok(())
+}
+
+/// Parses all `pub trait Client*Api` declarations in a source file.
+pub fn parse_traits(file: &str, source: &str) -> Result<Vec<TraitCapabilities>, CapabilityError> {
+    let source = strip_comments(source);
+    let bytes = source.as_bytes();
+    let unbalanced = |position: usize| CapabilityError::UnbalancedDelimiter {
+        file: file.to_string(),
+        position,
+    };
+
+    let trait_regex = Regex::new(r"\bpub\s+trait\s+(Client\w*Api)\b").unwrap();
+    let mut traits = Vec::new();
+    for captures in trait_regex.captures_iter(&source) {
+        let name = captures[1].to_string();
+        let header_start = captures.get(0).unwrap().end();
+        let body_start = header_start
+            + source[header_start..]
+                .find('{')
+                .ok_or_else(|| unbalanced(header_start))?;
+        let body_end = find_matching(bytes, body_start).ok_or_else(|| unbalanced(body_start))?;
+
+        // Skip the trait generics and read the supertraits, if any
+        let mut header = source[header_start..body_start].trim_start();
+        if header.starts_with('<') {
+            let end =
+                find_matching(header.as_bytes(), 0).ok_or_else(|| unbalanced(header_start))?;
+            header = header[end + 1..].trim_start();
+        }
+        let supertraits = match header.strip_prefix(':') {
+            Some(bounds) => split_top_level(bounds, '+')
+                .iter()
+                .map(|s| normalize(s))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let functions = parse_functions(&source[body_start + 1..body_end])
+            .ok_or_else(|| unbalanced(body_start))?;
+
+        traits.push(TraitCapabilities {
+            name,
+            file: file.to_string(),
+            supertraits,
+            functions,
+        });
+    }
+
+    Ok(traits)
+}
+
+fn parse_functions(body: &str) -> Option<Vec<FunctionCapability>> {
+    let bytes = body.as_bytes();
+    let fn_regex = Regex::new(r"\bfn\s+(\w+)").unwrap();
+    let mut functions = Vec::new();
+    let mut position = 0;
+
+    while let Some(captures) = fn_regex.captures(&body[position..]) {
+        let name = captures[1].to_string();
+        let mut cursor = position + captures.get(0).unwrap().end();
+
+        let mut generics = Vec::new();
+        let rest = &body[cursor..];
+        if rest.trim_start().starts_with('<') {
+            let open = cursor + rest.find('<').unwrap();
+            let close = find_matching(bytes, open)?;
+            generics = split_top_level(&body[open + 1..close], ',')
+                .iter()
+                .map(|s| normalize(s))
+                .collect();
+            cursor = close + 1;
+        }
+
+        let open = cursor + body[cursor..].find('(')?;
+        let close = find_matching(bytes, open)?;
+        let inputs = split_top_level(&body[open + 1..close], ',')
+            .iter()
+            .map(|s| normalize(s))
+            .filter(|s| !s.ends_with("self") || s.contains(':'))
+            .map(|s| {
+                let (name, ty) = s.split_once(':').unwrap_or((s.as_str(), ""));
+                FunctionInput {
+                    name: name.trim().trim_start_matches("mut ").to_string(),
+                    ty: ty.trim().to_string(),
+                }
+            })
+            .collect();
+
+        let terminator = find_signature_end(bytes, close + 1)?;
+        let output = normalize(body[close + 1..terminator].trim().trim_start_matches("->"));
+        let provided = bytes[terminator] == b'{';
+        position = if provided {
+            find_matching(bytes, terminator)? + 1
+        } else {
+            terminator + 1
+        };
+
+        functions.push(FunctionCapability {
+            costing_class: CostingClass::classify(&name),
+            name,
+            generics,
+            inputs,
+            output: if output.is_empty() {
+                "()".to_string()
+            } else {
+                output
+            },
+            provided,
+        });
+    }
+
+    Some(functions)
+}
+
+/// Removes line and block comments, leaving string literals untouched.
+fn strip_comments(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut result = String::with_capacity(source.len());
+    let mut i = 0;
+    let mut in_string = false;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if in_string {
+            result.push(c);
+            if c == '\\' {
+                if let Some(n) = next {
+                    result.push(n);
+                }
+                i += 2;
+                continue;
+            }
+            if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+        } else if c == '"' {
+            in_string = true;
+            result.push(c);
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            result.push(' ');
+        } else {
+            result.push(c);
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Finds the delimiter closing the one at `open`, ignoring the `>` of `->` and `=>`.
+fn find_matching(bytes: &[u8], open: usize) -> Option<usize> {
+    let (opening, closing) = match bytes[open] {
+        b'{' => (b'{', b'}'),
+        b'(' => (b'(', b')'),
+        b'[' => (b'[', b']'),
+        b'<' => (b'<', b'>'),
+        _ => return None,
+    };
+    let mut depth = 0usize;
+    for (i, &b) in bytes.iter().enumerate().skip(open) {
+        if b == b'>' && i > 0 && (bytes[i - 1] == b'-' || bytes[i - 1] == b'=') {
+            continue;
+        }
+        if b == opening {
+            depth += 1;
+        } else if b == closing {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the `;` or `{` ending a function signature, skipping array types like `[u8; 32]`.
+fn find_signature_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for (i, &b) in bytes.iter().enumerate().skip(start) {
+        match b {
+            b'(' | b'[' | b'<' => depth += 1,
+            b')' | b']' => depth -= 1,
+            b'>' if bytes[i - 1] != b'-' && bytes[i - 1] != b'=' => depth -= 1,
+            b';' | b'{' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits on `separator` outside of any brackets, dropping empty segments.
+fn split_top_level(s: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    let mut previous = ' ';
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '>' if previous != '-' && previous != '=' => depth -= 1,
+            _ => {}
+        }
+        if c == separator && depth == 0 {
+            parts.push(current.clone());
+            current.clear();
+        } else {
+            current.push(c);
+        }
+        previous = c;
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Collapses whitespace and drops trailing commas, so formatting changes don't show up as diffs.
+fn normalize(s: &str) -> String {
+    let collapsed = Regex::new(r"\s+").unwrap().replace_all(s.trim(), " ");
+    let collapsed = Regex::new(r"([(<\[]) ")
+        .unwrap()
+        .replace_all(&collapsed, "$1");
+    let collapsed = Regex::new(r" ([)>\],])")
+        .unwrap()
+        .replace_all(&collapsed, "$1");
+    Regex::new(r",([)>\]])")
+        .unwrap()
+        .replace_all(&collapsed, "$1")
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+use sbor::rust::fmt::Debug;
+
+/// A test api
+pub trait ClientTestApi<E: Debug> {
+    // A required method
+    fn test_open_entry(
+        &mut self,
+        key: &Vec<u8>,
+        flags: LockFlags,
+    ) -> Result<
+        KeyValueEntryHandle,
+        E,
+    >;
+
+    fn test_call<F: FnOnce() -> u32, T>(&mut self, mut f: F) -> Result<T, E> {
+        let _ = { f() };
+        todo!()
+    }
+
+    fn test_panic(&mut self, message: String);
+
+    fn test_generate_ruid(&mut self) -> Result<[u8; 32], E>;
+}
+
+pub trait NotAClientTrait {
+    fn ignored(&self);
+}
+
+pub trait ClientAllApi<E: Debug>: ClientTestApi<E> + ClientOtherApi<E> {}
+"#;
+
+    #[test]
+    fn test_parse_traits() {
+        let traits = parse_traits("test.rs", SOURCE).unwrap();
+        assert_eq!(traits.len(), 2);
+
+        let test_api = &traits[0];
+        assert_eq!(test_api.name, "ClientTestApi");
+        assert_eq!(test_api.file, "test.rs");
+        assert!(test_api.supertraits.is_empty());
+        assert_eq!(test_api.functions.len(), 4);
+
+        let open = &test_api.functions[0];
+        assert_eq!(open.name, "test_open_entry");
+        assert!(open.generics.is_empty());
+        assert_eq!(
+            open.inputs,
+            vec![
+                FunctionInput {
+                    name: "key".to_string(),
+                    ty: "&Vec<u8>".to_string()
+                },
+                FunctionInput {
+                    name: "flags".to_string(),
+                    ty: "LockFlags".to_string()
+                },
+            ]
+        );
+        assert_eq!(open.output, "Result<KeyValueEntryHandle, E>");
+        assert!(!open.provided);
+        assert_eq!(open.costing_class, CostingClass::SubstateAccess);
+
+        let call = &test_api.functions[1];
+        assert_eq!(call.name, "test_call");
+        assert_eq!(call.generics, vec!["F: FnOnce() -> u32", "T"]);
+        assert_eq!(call.inputs[0].name, "f");
+        assert_eq!(call.inputs[0].ty, "F");
+        assert!(call.provided);
+        assert_eq!(call.costing_class, CostingClass::Invoke);
+
+        let panic = &test_api.functions[2];
+        assert_eq!(panic.output, "()");
+        assert_eq!(panic.costing_class, CostingClass::Emission);
+
+        let generate_ruid = &test_api.functions[3];
+        assert_eq!(generate_ruid.output, "Result<[u8; 32], E>");
+        assert_eq!(generate_ruid.costing_class, CostingClass::Query);
+
+        let all_api = &traits[1];
+        assert_eq!(all_api.name, "ClientAllApi");
+        assert_eq!(
+            all_api.supertraits,
+            vec!["ClientTestApi<E>", "ClientOtherApi<E>"]
+        );
+        assert!(all_api.functions.is_empty());
+    }
+
+    #[test]
+    fn test_diff_detects_breaking_changes() {
+        let previous = CapabilityManifest {
+            traits: parse_traits("test.rs", SOURCE).unwrap(),
+        };
+        let current = CapabilityManifest {
+            traits: parse_traits(
+                "test.rs",
+                &SOURCE
+                    .replace("flags: LockFlags,", "")
+                    .replace("fn test_panic", "fn test_emit_log"),
+            )
+            .unwrap(),
+        };
+
+        let changes = current.diff(&previous);
+
+        assert_eq!(
+            changes,
+            vec![
+                CapabilityChange::SignatureChanged {
+                    trait_name: "ClientTestApi".to_string(),
+                    function: "test_open_entry".to_string(),
+                },
+                CapabilityChange::FunctionRemoved {
+                    trait_name: "ClientTestApi".to_string(),
+                    function: "test_panic".to_string(),
+                },
+                CapabilityChange::FunctionAdded {
+                    trait_name: "ClientTestApi".to_string(),
+                    function: "test_emit_log".to_string(),
+                },
+            ]
+        );
+        assert_eq!(changes.iter().filter(|c| c.is_breaking()).count(), 2);
+        assert!(current.diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_costing_classes() {
+        assert_eq!(CostingClass::classify("call_method"), CostingClass::Invoke);
+        assert_eq!(
+            CostingClass::classify("key_value_store_new"),
+            CostingClass::NodeLifecycle
+        );
+        assert_eq!(
+            CostingClass::classify("key_value_entry_get"),
+            CostingClass::SubstateAccess
+        );
+        assert_eq!(
+            CostingClass::classify("key_value_store_get_info"),
+            CostingClass::Query
+        );
+        assert_eq!(CostingClass::classify("emit_event"), CostingClass::Emission);
+    }
+}
@@ -0,0 +1,67 @@
+mod capability;
+
+pub use capability::*;
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Radix client API capability manifest generator
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rcap")]
+pub struct Args {
+    /// Path to the output file, defaults to stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// Path to a previously generated manifest to compare against
+    #[clap(short, long)]
+    check: Option<PathBuf>,
+
+    /// Directory containing the radix-engine-interface API traits
+    #[clap(required = true)]
+    input: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IoError(std::io::Error),
+    CapabilityError(CapabilityError),
+    JsonError(serde_json::Error),
+    SnapshotMismatch { breaking: usize, total: usize },
+}
+
+pub fn run() -> Result<(), Error> {
+    let args = Args::parse();
+
+    let manifest =
+        CapabilityManifest::from_directory(&args.input).map_err(Error::CapabilityError)?;
+
+    if let Some(snapshot) = args.check {
+        let content = std::fs::read_to_string(&snapshot).map_err(Error::IoError)?;
+        let previous: CapabilityManifest =
+            serde_json::from_str(&content).map_err(Error::JsonError)?;
+        let changes = manifest.diff(&previous);
+        for change in &changes {
+            if change.is_breaking() {
+                println!("BREAKING: {}", change);
+            } else {
+                println!("{}", change);
+            }
+        }
+        if !changes.is_empty() {
+            return Err(Error::SnapshotMismatch {
+                breaking: changes.iter().filter(|c| c.is_breaking()).count(),
+                total: changes.len(),
+            });
+        }
+        return Ok(());
+    }
+
+    let json = serde_json::to_string_pretty(&manifest).map_err(Error::JsonError)? + "\n";
+    match args.output {
+        Some(output) => std::fs::write(output, json).map_err(Error::IoError)?,
+        None => print!("{}", json),
+    }
+
+    Ok(())
+}
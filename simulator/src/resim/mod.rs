@@ -56,6 +56,7 @@ use clap::{Parser, Subcommand};
 use radix_engine::blueprints::consensus_manager::{
     ConsensusManagerSubstate, ProposerMilliTimestampSubstate, ProposerMinuteTimestampSubstate,
 };
+use radix_engine::blueprints::resource::FungibleResourceManagerDivisibilitySubstate;
 use radix_engine::system::bootstrap::Bootstrapper;
 use radix_engine::system::node_modules::type_info::TypeInfoSubstate;
 use radix_engine::system::system::KeyValueEntrySubstate;
@@ -86,7 +87,7 @@ use radix_engine_stores::rocks_db::RocksdbSubstateStore;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
-use transaction::manifest::decompile;
+use transaction::manifest::decompile_manifest;
 use transaction::model::TestTransaction;
 use transaction::model::{BlobV1, BlobsV1, InstructionV1, InstructionsV1};
 use transaction::model::{SystemTransactionV1, TransactionPayload};
@@ -203,6 +204,9 @@ pub fn handle_system_transaction<O: std::io::Write>(
             .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
                 get_event_schema(&substate_db, event_type_identifier)
             })
+            .divisibility_lookup_callback(|resource_address: &ResourceAddress| {
+                get_resource_divisibility(&substate_db, resource_address)
+            })
             .build();
         writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
     }
@@ -228,7 +232,7 @@ pub fn handle_manifest<O: std::io::Write>(
         Some(path) => {
             if !env::var(ENV_DISABLE_MANIFEST_OUTPUT).is_ok() {
                 let manifest_str =
-                    decompile(&manifest.instructions, &network).map_err(Error::DecompileError)?;
+                    decompile_manifest(&manifest, &network).map_err(Error::DecompileError)?;
                 fs::write(path, manifest_str).map_err(Error::IOError)?;
                 for blob in manifest.blobs.values() {
                     let blob_hash = hash(&blob);
@@ -274,6 +278,9 @@ pub fn handle_manifest<O: std::io::Write>(
                     .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
                         get_event_schema(&substate_db, event_type_identifier)
                     })
+                    .divisibility_lookup_callback(|resource_address: &ResourceAddress| {
+                        get_resource_divisibility(&substate_db, resource_address)
+                    })
                     .build();
                 writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
             }
@@ -483,6 +490,20 @@ pub fn get_event_schema<S: SubstateDatabase>(
     }
 }
 
+pub fn get_resource_divisibility<S: SubstateDatabase>(
+    substate_db: &S,
+    resource_address: &ResourceAddress,
+) -> Option<u8> {
+    if !resource_address.is_fungible() {
+        return None;
+    }
+    substate_db.get_mapped::<SpreadPrefixKeyMapper, FungibleResourceManagerDivisibilitySubstate>(
+        resource_address.as_node_id(),
+        MAIN_BASE_PARTITION,
+        &FungibleResourceManagerField::Divisibility.into(),
+    )
+}
+
 pub fn db_upsert_timestamps(
     milli_timestamp: ProposerMilliTimestampSubstate,
     minute_timestamp: ProposerMinuteTimestampSubstate,
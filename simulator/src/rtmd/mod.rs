@@ -5,7 +5,7 @@ use radix_engine_interface::crypto::hash;
 use radix_engine_interface::data::manifest::manifest_decode;
 use std::path::PathBuf;
 use std::str::FromStr;
-use transaction::manifest::decompile;
+use transaction::manifest::decompile_manifest;
 use transaction::prelude::*;
 
 /// Radix transaction manifest decompiler
@@ -51,7 +51,7 @@ pub fn run() -> Result<(), Error> {
     validate_call_arguments_to_native_components(&manifest.instructions)
         .map_err(Error::InstructionSchemaValidationError)?;
 
-    let result = decompile(&manifest.instructions, &network).map_err(Error::DecompileError)?;
+    let result = decompile_manifest(&manifest, &network).map_err(Error::DecompileError)?;
     std::fs::write(&args.output, &result).map_err(Error::IoError)?;
 
     if args.export_blobs {
@@ -0,0 +1,97 @@
+mod vectors;
+
+pub use vectors::*;
+
+use clap::{Parser, Subcommand};
+use radix_engine::types::*;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Radix test vector generator, for cross-implementation conformance of encodings
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None, name = "rtvg")]
+pub struct RtvgCli {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    Generate(Generate),
+    Verify(Verify),
+}
+
+/// Generate the test vectors as JSON
+#[derive(Parser, Debug)]
+pub struct Generate {
+    /// Path to the output file, defaults to stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// Network to Use [Simulator | Adapanet | Nebunet | Mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+}
+
+/// Verify a test vector file against this implementation
+#[derive(Parser, Debug)]
+pub struct Verify {
+    /// Path to the test vector file
+    #[clap(required = true)]
+    input: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IoError(std::io::Error),
+    JsonError(serde_json::Error),
+    ParseNetworkError(ParseNetworkError),
+    TestVectorsError(TestVectorsError),
+    VerificationFailed { failed: usize },
+}
+
+pub fn run() -> Result<(), Error> {
+    let cli = RtvgCli::parse();
+
+    match cli.command {
+        Command::Generate(cmd) => cmd.run(),
+        Command::Verify(cmd) => cmd.run(),
+    }
+}
+
+impl Generate {
+    pub fn run(&self) -> Result<(), Error> {
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+
+        let vectors = TestVectors::generate(&network);
+        let json = serde_json::to_string_pretty(&vectors).map_err(Error::JsonError)? + "\n";
+        match &self.output {
+            Some(output) => std::fs::write(output, json).map_err(Error::IoError)?,
+            None => print!("{}", json),
+        }
+
+        Ok(())
+    }
+}
+
+impl Verify {
+    pub fn run(&self) -> Result<(), Error> {
+        let content = std::fs::read_to_string(&self.input).map_err(Error::IoError)?;
+        let vectors: TestVectors = serde_json::from_str(&content).map_err(Error::JsonError)?;
+
+        let failures = vectors.verify().map_err(Error::TestVectorsError)?;
+        for failure in &failures {
+            println!("FAILED: {}", failure);
+        }
+        if !failures.is_empty() {
+            return Err(Error::VerificationFailed {
+                failed: failures.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
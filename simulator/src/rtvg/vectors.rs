@@ -0,0 +1,818 @@
+use radix_engine::types::*;
+use sbor::representations::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use transaction::manifest::{compile, decompile, BlobProvider};
+use transaction::prelude::*;
+
+/// Bumped whenever the layout of the fixture file changes.
+pub const TEST_VECTORS_VERSION: u32 = 1;
+
+/// A set of encoding test vectors, which other implementations of SBOR, the manifest format,
+/// Bech32m addresses and non-fungible local ids can check themselves against.
+///
+/// All vectors are generated from fixed inputs, so generating twice yields identical output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub version: u32,
+    pub network: String,
+    pub scrypto_sbor: Vec<SborVector>,
+    pub invalid_scrypto_sbor: Vec<InvalidPayloadVector>,
+    pub manifest_sbor: Vec<SborVector>,
+    pub invalid_manifest_sbor: Vec<InvalidPayloadVector>,
+    pub manifests: Vec<ManifestVector>,
+    pub addresses: Vec<AddressVector>,
+    pub non_fungible_local_ids: Vec<NonFungibleLocalIdVector>,
+    pub invalid_non_fungible_local_ids: Vec<InvalidNonFungibleLocalIdVector>,
+}
+
+/// A valid payload, with its value in the programmatic JSON format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SborVector {
+    pub name: String,
+    pub payload: String,
+    pub value: serde_json::Value,
+}
+
+/// A payload which must be rejected by a decoder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidPayloadVector {
+    pub name: String,
+    pub payload: String,
+    pub error: String,
+}
+
+/// A manifest in its canonical (decompiled) text form, with its encoded instructions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestVector {
+    pub name: String,
+    pub manifest: String,
+    pub instructions: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressVector {
+    pub entity_type: String,
+    pub entity_type_id: u8,
+    pub node_id: String,
+    pub address: String,
+}
+
+/// A non-fungible local id in its string form, with its Scrypto SBOR encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonFungibleLocalIdVector {
+    pub id: String,
+    pub payload: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidNonFungibleLocalIdVector {
+    pub id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestVectorsError {
+    UnsupportedVersion(u32),
+    InvalidNetwork(String),
+}
+
+/// A vector which the current implementation doesn't agree with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectorFailure {
+    pub category: &'static str,
+    pub name: String,
+    pub reason: String,
+}
+
+impl fmt::Display for VectorFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}: {}", self.category, self.name, self.reason)
+    }
+}
+
+impl TestVectors {
+    pub fn generate(network: &NetworkDefinition) -> Self {
+        let address_bech32_encoder = AddressBech32Encoder::new(network);
+
+        Self {
+            version: TEST_VECTORS_VERSION,
+            network: network.logical_name.clone(),
+            scrypto_sbor: scrypto_values()
+                .into_iter()
+                .map(|(name, payload)| SborVector {
+                    name: name.to_owned(),
+                    value: scrypto_json(&payload, &address_bech32_encoder),
+                    payload: hex::encode(payload),
+                })
+                .collect(),
+            invalid_scrypto_sbor: invalid_scrypto_payloads()
+                .into_iter()
+                .map(|(name, payload)| InvalidPayloadVector {
+                    name: name.to_owned(),
+                    error: format!(
+                        "{:?}",
+                        scrypto_decode::<ScryptoValue>(&payload).unwrap_err()
+                    ),
+                    payload: hex::encode(payload),
+                })
+                .collect(),
+            manifest_sbor: manifest_values()
+                .into_iter()
+                .map(|(name, payload)| SborVector {
+                    name: name.to_owned(),
+                    value: manifest_json(&payload, &address_bech32_encoder),
+                    payload: hex::encode(payload),
+                })
+                .collect(),
+            invalid_manifest_sbor: invalid_manifest_payloads()
+                .into_iter()
+                .map(|(name, payload)| InvalidPayloadVector {
+                    name: name.to_owned(),
+                    error: format!(
+                        "{:?}",
+                        manifest_decode::<ManifestValue>(&payload).unwrap_err()
+                    ),
+                    payload: hex::encode(payload),
+                })
+                .collect(),
+            manifests: manifests()
+                .into_iter()
+                .map(|(name, manifest)| ManifestVector {
+                    name: name.to_owned(),
+                    manifest: decompile(&manifest.instructions, network)
+                        .expect("Generated manifest must decompile"),
+                    instructions: hex::encode(manifest_encode(&manifest.instructions).unwrap()),
+                })
+                .collect(),
+            addresses: (0..=u8::MAX)
+                .filter_map(EntityType::from_repr)
+                .map(|entity_type| {
+                    let node_id = address_node_id(entity_type);
+                    AddressVector {
+                        entity_type: format!("{:?}", entity_type),
+                        entity_type_id: entity_type as u8,
+                        node_id: hex::encode(node_id),
+                        address: address_bech32_encoder.encode(&node_id).unwrap(),
+                    }
+                })
+                .collect(),
+            non_fungible_local_ids: non_fungible_local_ids()
+                .into_iter()
+                .map(|id| NonFungibleLocalIdVector {
+                    id: id.to_string(),
+                    payload: hex::encode(scrypto_encode(&id).unwrap()),
+                })
+                .collect(),
+            invalid_non_fungible_local_ids: INVALID_NON_FUNGIBLE_LOCAL_IDS
+                .iter()
+                .map(|id| InvalidNonFungibleLocalIdVector {
+                    id: id.to_string(),
+                    error: format!("{:?}", NonFungibleLocalId::from_str(id).unwrap_err()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks every vector against this implementation, in both directions where the encoding
+    /// is invertible, and returns the vectors which don't match.
+    ///
+    /// The vectors may come from another implementation, so nothing is assumed about them
+    /// beyond the fixture layout.
+    pub fn verify(&self) -> Result<Vec<VectorFailure>, TestVectorsError> {
+        if self.version != TEST_VECTORS_VERSION {
+            return Err(TestVectorsError::UnsupportedVersion(self.version));
+        }
+        let network = NetworkDefinition::from_str(&self.network)
+            .map_err(|_| TestVectorsError::InvalidNetwork(self.network.clone()))?;
+        let address_bech32_encoder = AddressBech32Encoder::new(&network);
+        let address_bech32_decoder = AddressBech32Decoder::new(&network);
+
+        let mut failures = Vec::new();
+        let mut check = |category: &'static str, name: &str, result: Result<(), String>| {
+            if let Err(reason) = result {
+                failures.push(VectorFailure {
+                    category,
+                    name: name.to_owned(),
+                    reason,
+                });
+            }
+        };
+
+        for vector in &self.scrypto_sbor {
+            check(
+                "scrypto_sbor",
+                &vector.name,
+                verify_sbor(vector, |payload| {
+                    let value = scrypto_decode::<ScryptoValue>(payload)
+                        .map_err(|e| format!("Decoding failed: {:?}", e))?;
+                    Ok((
+                        scrypto_encode(&value).unwrap(),
+                        scrypto_json(payload, &address_bech32_encoder),
+                    ))
+                }),
+            );
+        }
+        for vector in &self.invalid_scrypto_sbor {
+            check(
+                "invalid_scrypto_sbor",
+                &vector.name,
+                verify_invalid_payload(vector, |payload| {
+                    scrypto_decode::<ScryptoValue>(payload)
+                        .map(|_| ())
+                        .map_err(|e| format!("{:?}", e))
+                }),
+            );
+        }
+        for vector in &self.manifest_sbor {
+            check(
+                "manifest_sbor",
+                &vector.name,
+                verify_sbor(vector, |payload| {
+                    let value = manifest_decode::<ManifestValue>(payload)
+                        .map_err(|e| format!("Decoding failed: {:?}", e))?;
+                    Ok((
+                        manifest_encode(&value).unwrap(),
+                        manifest_json(payload, &address_bech32_encoder),
+                    ))
+                }),
+            );
+        }
+        for vector in &self.invalid_manifest_sbor {
+            check(
+                "invalid_manifest_sbor",
+                &vector.name,
+                verify_invalid_payload(vector, |payload| {
+                    manifest_decode::<ManifestValue>(payload)
+                        .map(|_| ())
+                        .map_err(|e| format!("{:?}", e))
+                }),
+            );
+        }
+        for vector in &self.manifests {
+            check("manifests", &vector.name, verify_manifest(vector, &network));
+        }
+        for vector in &self.addresses {
+            check(
+                "addresses",
+                &vector.entity_type,
+                verify_address(vector, &address_bech32_encoder, &address_bech32_decoder),
+            );
+        }
+        for vector in &self.non_fungible_local_ids {
+            check(
+                "non_fungible_local_ids",
+                &vector.id,
+                verify_non_fungible_local_id(vector),
+            );
+        }
+        for vector in &self.invalid_non_fungible_local_ids {
+            check(
+                "invalid_non_fungible_local_ids",
+                &vector.id,
+                match NonFungibleLocalId::from_str(&vector.id) {
+                    Ok(_) => Err("Parsing succeeded".to_owned()),
+                    Err(e) => expect_eq("error", &vector.error, &format!("{:?}", e)),
+                },
+            );
+        }
+
+        Ok(failures)
+    }
+}
+
+fn verify_sbor<F>(vector: &SborVector, decode: F) -> Result<(), String>
+where
+    F: Fn(&[u8]) -> Result<(Vec<u8>, serde_json::Value), String>,
+{
+    let payload = decode_hex(&vector.payload)?;
+    let (encoded, value) = decode(&payload)?;
+    expect_eq("re-encoded payload", &vector.payload, &hex::encode(encoded))?;
+    expect_eq("value", &vector.value, &value)
+}
+
+fn verify_invalid_payload<F>(vector: &InvalidPayloadVector, decode: F) -> Result<(), String>
+where
+    F: Fn(&[u8]) -> Result<(), String>,
+{
+    let payload = decode_hex(&vector.payload)?;
+    match decode(&payload) {
+        Ok(()) => Err("Decoding succeeded".to_owned()),
+        Err(error) => expect_eq("error", &vector.error, &error),
+    }
+}
+
+fn verify_manifest(vector: &ManifestVector, network: &NetworkDefinition) -> Result<(), String> {
+    let compiled = compile(&vector.manifest, network, BlobProvider::new())
+        .map_err(|e| format!("Compilation failed: {:?}", e))?;
+    expect_eq(
+        "compiled instructions",
+        &vector.instructions,
+        &hex::encode(manifest_encode(&compiled.instructions).unwrap()),
+    )?;
+
+    let instructions = manifest_decode::<Vec<InstructionV1>>(&decode_hex(&vector.instructions)?)
+        .map_err(|e| format!("Decoding failed: {:?}", e))?;
+    let decompiled =
+        decompile(&instructions, network).map_err(|e| format!("Decompilation failed: {:?}", e))?;
+    expect_eq("decompiled manifest", &vector.manifest, &decompiled)
+}
+
+fn verify_address(
+    vector: &AddressVector,
+    address_bech32_encoder: &AddressBech32Encoder,
+    address_bech32_decoder: &AddressBech32Decoder,
+) -> Result<(), String> {
+    let (entity_type, node_id) = address_bech32_decoder
+        .validate_and_decode(&vector.address)
+        .map_err(|e| format!("Decoding failed: {:?}", e))?;
+    expect_eq(
+        "entity type id",
+        &vector.entity_type_id,
+        &(entity_type as u8),
+    )?;
+    expect_eq("node id", &vector.node_id, &hex::encode(node_id))?;
+
+    let encoded = address_bech32_encoder
+        .encode(&decode_hex(&vector.node_id)?)
+        .map_err(|e| format!("Encoding failed: {:?}", e))?;
+    expect_eq("address", &vector.address, &encoded)
+}
+
+fn verify_non_fungible_local_id(vector: &NonFungibleLocalIdVector) -> Result<(), String> {
+    let id =
+        NonFungibleLocalId::from_str(&vector.id).map_err(|e| format!("Parsing failed: {:?}", e))?;
+    expect_eq(
+        "payload",
+        &vector.payload,
+        &hex::encode(scrypto_encode(&id).unwrap()),
+    )?;
+
+    let decoded = scrypto_decode::<NonFungibleLocalId>(&decode_hex(&vector.payload)?)
+        .map_err(|e| format!("Decoding failed: {:?}", e))?;
+    expect_eq("id", &vector.id, &decoded.to_string())
+}
+
+fn expect_eq<T: PartialEq + fmt::Display + ?Sized>(
+    what: &str,
+    expected: &T,
+    actual: &T,
+) -> Result<(), String> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(format!(
+            "Mismatched {}, expected `{}` but got `{}`",
+            what, expected, actual
+        ))
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, String> {
+    hex::decode(s).map_err(|e| format!("Invalid hex: {:?}", e))
+}
+
+fn scrypto_json(
+    payload: &[u8],
+    address_bech32_encoder: &AddressBech32Encoder,
+) -> serde_json::Value {
+    serde_json::to_value(
+        ScryptoRawPayload::new_from_valid_slice(payload).serializable(
+            SerializationParameters::Schemaless {
+                mode: SerializationMode::Programmatic,
+                custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                    address_bech32_encoder,
+                )),
+            },
+        ),
+    )
+    .unwrap()
+}
+
+fn manifest_json(
+    payload: &[u8],
+    address_bech32_encoder: &AddressBech32Encoder,
+) -> serde_json::Value {
+    serde_json::to_value(
+        ManifestRawPayload::new_from_valid_slice(payload).serializable(
+            SerializationParameters::Schemaless {
+                mode: SerializationMode::Programmatic,
+                custom_context: ManifestValueDisplayContext::with_optional_bech32(Some(
+                    address_bech32_encoder,
+                )),
+            },
+        ),
+    )
+    .unwrap()
+}
+
+/// A node id of the given entity type, with a fixed but non-trivial body.
+fn address_node_id(entity_type: EntityType) -> [u8; NodeId::LENGTH] {
+    let mut node_id = [0u8; NodeId::LENGTH];
+    node_id[0] = entity_type as u8;
+    for (i, byte) in node_id.iter_mut().enumerate().skip(1) {
+        *byte = (i as u8).wrapping_mul(37).wrapping_add(entity_type as u8);
+    }
+    node_id
+}
+
+fn test_account() -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(&Secp256k1PublicKey([2u8; 33]))
+}
+
+fn test_vault() -> InternalAddress {
+    InternalAddress::new_or_panic(address_node_id(EntityType::InternalFungibleVault))
+}
+
+fn scrypto_values() -> Vec<(&'static str, Vec<u8>)> {
+    fn encode<T: ScryptoEncode + ?Sized>(value: &T) -> Vec<u8> {
+        scrypto_encode(value).unwrap()
+    }
+
+    let mut map = BTreeMap::new();
+    map.insert(1u8, "one".to_owned());
+    map.insert(2u8, "two".to_owned());
+
+    vec![
+        ("bool_false", encode(&false)),
+        ("bool_true", encode(&true)),
+        ("i8_min", encode(&i8::MIN)),
+        ("i8_max", encode(&i8::MAX)),
+        ("i16_min", encode(&i16::MIN)),
+        ("i16_max", encode(&i16::MAX)),
+        ("i32_min", encode(&i32::MIN)),
+        ("i32_max", encode(&i32::MAX)),
+        ("i64_min", encode(&i64::MIN)),
+        ("i64_max", encode(&i64::MAX)),
+        ("i128_min", encode(&i128::MIN)),
+        ("i128_max", encode(&i128::MAX)),
+        ("u8_zero", encode(&0u8)),
+        ("u8_max", encode(&u8::MAX)),
+        ("u16_max", encode(&u16::MAX)),
+        ("u32_max", encode(&u32::MAX)),
+        ("u64_max", encode(&u64::MAX)),
+        ("u128_max", encode(&u128::MAX)),
+        ("string_empty", encode("")),
+        ("string_ascii", encode("hello")),
+        ("string_unicode", encode("\u{2603} \u{1F980}")),
+        ("string_long", encode(&"x".repeat(200))),
+        ("bytes_empty", encode(&Vec::<u8>::new())),
+        ("bytes", encode(&vec![0u8, 1, 2, 0xfe, 0xff])),
+        ("array_of_strings", encode(&vec!["a", "b"])),
+        ("array_of_arrays", encode(&vec![vec![1u32], vec![]])),
+        ("tuple_empty", encode(&())),
+        ("tuple", encode(&(1u8, "two", -3i64))),
+        ("tuple_nested", encode(&((1u8,), ((),)))),
+        ("enum_none", encode(&Option::<u8>::None)),
+        ("enum_some", encode(&Some(7u16))),
+        (
+            "enum_ok",
+            encode(&Result::<String, u8>::Ok("ok".to_owned())),
+        ),
+        ("enum_err", encode(&Result::<String, u8>::Err(9))),
+        ("map_empty", encode(&BTreeMap::<String, u8>::new())),
+        ("map", encode(&map)),
+        ("reference_resource", encode(&XRD)),
+        ("reference_package", encode(&ACCOUNT_PACKAGE)),
+        ("reference_account", encode(&test_account())),
+        ("reference_internal", encode(&test_vault())),
+        ("own", encode(&Own(*test_vault().as_node_id()))),
+        ("decimal_zero", encode(&Decimal::ZERO)),
+        ("decimal_one", encode(&Decimal::ONE)),
+        ("decimal_fraction", encode(&dec!("-0.000000000000000001"))),
+        ("decimal_min", encode(&Decimal::MIN)),
+        ("decimal_max", encode(&Decimal::MAX)),
+        ("precise_decimal_zero", encode(&PreciseDecimal::ZERO)),
+        ("precise_decimal_one", encode(&PreciseDecimal::ONE)),
+        ("precise_decimal_min", encode(&PreciseDecimal::MIN)),
+        ("precise_decimal_max", encode(&PreciseDecimal::MAX)),
+        (
+            "non_fungible_local_id_string",
+            encode(&NonFungibleLocalId::string("hello_world").unwrap()),
+        ),
+        (
+            "non_fungible_local_id_integer",
+            encode(&NonFungibleLocalId::integer(u64::MAX)),
+        ),
+        (
+            "non_fungible_local_id_bytes",
+            encode(&NonFungibleLocalId::bytes(vec![0xde, 0xad]).unwrap()),
+        ),
+        (
+            "non_fungible_local_id_ruid",
+            encode(&NonFungibleLocalId::ruid([0x11; 32])),
+        ),
+        (
+            "non_fungible_global_id",
+            encode(&NonFungibleGlobalId::new(
+                SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+                NonFungibleLocalId::integer(1),
+            )),
+        ),
+    ]
+}
+
+fn invalid_scrypto_payloads() -> Vec<(&'static str, Vec<u8>)> {
+    let mut too_deep = vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX];
+    for _ in 0..=SCRYPTO_SBOR_V1_MAX_DEPTH {
+        too_deep.extend([VALUE_KIND_TUPLE, 1]);
+    }
+    too_deep.extend([VALUE_KIND_U8, 0]);
+
+    vec![
+        ("empty", vec![]),
+        (
+            "wrong_prefix",
+            vec![MANIFEST_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_BOOL, 1],
+        ),
+        ("missing_value", vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX]),
+        (
+            "invalid_bool",
+            vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_BOOL, 2],
+        ),
+        (
+            "trailing_bytes",
+            vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_BOOL, 1, 0],
+        ),
+        (
+            "unknown_value_kind",
+            vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, 0xff],
+        ),
+        (
+            "truncated_string",
+            vec![
+                SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
+                VALUE_KIND_STRING,
+                5,
+                b'a',
+                b'b',
+            ],
+        ),
+        (
+            "invalid_utf8",
+            vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_STRING, 1, 0xff],
+        ),
+        (
+            "non_canonical_size",
+            vec![
+                SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
+                VALUE_KIND_STRING,
+                0x80,
+                0x00,
+            ],
+        ),
+        ("too_deep", too_deep),
+    ]
+}
+
+fn manifest_values() -> Vec<(&'static str, Vec<u8>)> {
+    fn encode<T: ManifestEncode + ?Sized>(value: &T) -> Vec<u8> {
+        manifest_encode(value).unwrap()
+    }
+
+    vec![
+        ("bool_true", encode(&true)),
+        ("string", encode("hello")),
+        ("tuple", encode(&(1u8, "two", -3i64))),
+        (
+            "address_static",
+            encode(&ManifestAddress::Static(*XRD.as_node_id())),
+        ),
+        ("address_named", encode(&ManifestAddress::Named(1))),
+        ("bucket", encode(&ManifestBucket(1))),
+        ("proof", encode(&ManifestProof(2))),
+        (
+            "expression_entire_worktop",
+            encode(&ManifestExpression::EntireWorktop),
+        ),
+        (
+            "expression_entire_auth_zone",
+            encode(&ManifestExpression::EntireAuthZone),
+        ),
+        ("blob", encode(&ManifestBlobRef([0x42; 32]))),
+        ("decimal", encode(&dec!("1.5"))),
+        ("precise_decimal", encode(&pdec!("-1.5"))),
+        (
+            "non_fungible_local_id",
+            encode(&NonFungibleLocalId::string("hello").unwrap()),
+        ),
+        (
+            "address_reservation",
+            encode(&ManifestAddressReservation(3)),
+        ),
+    ]
+}
+
+fn invalid_manifest_payloads() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        (
+            "wrong_prefix",
+            vec![SCRYPTO_SBOR_V1_PAYLOAD_PREFIX, VALUE_KIND_BOOL, 1],
+        ),
+        (
+            "unknown_custom_value_kind",
+            vec![MANIFEST_SBOR_V1_PAYLOAD_PREFIX, 0x8f],
+        ),
+        (
+            "invalid_expression",
+            vec![
+                MANIFEST_SBOR_V1_PAYLOAD_PREFIX,
+                MANIFEST_VALUE_KIND_EXPRESSION,
+                0xff,
+            ],
+        ),
+    ]
+}
+
+fn manifests() -> Vec<(&'static str, TransactionManifestV1)> {
+    let account = test_account();
+    let ids = btreeset!(
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::string("two").unwrap()
+    );
+
+    vec![
+        ("empty", ManifestBuilder::new().build()),
+        (
+            "lock_fee",
+            ManifestBuilder::new().lock_fee_from_faucet().build(),
+        ),
+        (
+            "worktop",
+            ManifestBuilder::new()
+                .get_free_xrd_from_faucet()
+                .assert_worktop_contains_any(XRD)
+                .assert_worktop_contains(XRD, dec!(10))
+                .assert_worktop_contains_non_fungibles(SECP256K1_SIGNATURE_VIRTUAL_BADGE, &ids)
+                .take_from_worktop(XRD, dec!(10), "amount")
+                .take_all_from_worktop(XRD, "all")
+                .take_non_fungibles_from_worktop(SECP256K1_SIGNATURE_VIRTUAL_BADGE, &ids, "ids")
+                .return_to_worktop("amount")
+                .burn_resource("ids")
+                .deposit(account, "all")
+                .drop_all_buckets_into(account)
+                .build(),
+        ),
+        (
+            "auth_zone",
+            ManifestBuilder::new()
+                .create_proof_from_auth_zone_of_amount(XRD, dec!(1), "amount")
+                .create_proof_from_auth_zone_of_non_fungibles(
+                    SECP256K1_SIGNATURE_VIRTUAL_BADGE,
+                    &ids,
+                    "ids",
+                )
+                .create_proof_from_auth_zone_of_all(XRD, "all")
+                .clone_proof("amount", "clone")
+                .push_to_auth_zone("clone")
+                .pop_from_auth_zone("popped")
+                .drop_proof("popped")
+                .clear_auth_zone()
+                .clear_signature_proofs()
+                .drop_all_proofs()
+                .build(),
+        ),
+        (
+            "bucket_proofs",
+            ManifestBuilder::new()
+                .get_free_xrd_from_faucet()
+                .take_all_from_worktop(XRD, "xrd")
+                .create_proof_from_bucket_of_amount("xrd", dec!(1), "amount")
+                .create_proof_from_bucket_of_all("xrd", "all")
+                .create_proof_from_bucket_of_non_fungibles("xrd", &ids, "ids")
+                .drop_all_proofs()
+                .deposit(account, "xrd")
+                .build(),
+        ),
+        (
+            "call_function",
+            ManifestBuilder::new()
+                .call_function(
+                    ACCOUNT_PACKAGE,
+                    ACCOUNT_BLUEPRINT,
+                    ACCOUNT_CREATE_ADVANCED_IDENT,
+                    manifest_args!(OwnerRole::None),
+                )
+                .build(),
+        ),
+        (
+            "call_method_with_all_value_kinds",
+            ManifestBuilder::new()
+                .get_free_xrd_from_faucet()
+                .take_all_from_worktop(XRD, "xrd")
+                .create_proof_from_auth_zone_of_all(XRD, "proof")
+                .allocate_global_address(
+                    ACCOUNT_PACKAGE,
+                    ACCOUNT_BLUEPRINT,
+                    "reservation",
+                    "address",
+                )
+                .with_name_lookup(|builder, lookup| {
+                    builder.call_method(
+                        account,
+                        "any_method",
+                        manifest_args!(
+                            true,
+                            -1i32,
+                            u64::MAX,
+                            "text",
+                            vec![1u8, 2, 3],
+                            Some(()),
+                            XRD,
+                            lookup.named_address("address"),
+                            lookup.bucket("xrd"),
+                            lookup.proof("proof"),
+                            lookup.address_reservation("reservation"),
+                            ManifestExpression::EntireWorktop,
+                            ManifestExpression::EntireAuthZone,
+                            dec!("1.5"),
+                            pdec!("-1.5"),
+                            NonFungibleLocalId::ruid([0x22; 32])
+                        ),
+                    )
+                })
+                .build(),
+        ),
+        (
+            "module_methods",
+            ManifestBuilder::new()
+                .set_component_royalty(account, "method", RoyaltyAmount::Xrd(dec!(1)))
+                .set_metadata(account, "name", "Test".to_owned())
+                .set_owner_role(account, AccessRule::AllowAll)
+                .recall(test_vault(), dec!(1))
+                .build(),
+        ),
+    ]
+}
+
+fn non_fungible_local_ids() -> Vec<NonFungibleLocalId> {
+    vec![
+        NonFungibleLocalId::string("a").unwrap(),
+        NonFungibleLocalId::string("Hello_World_123").unwrap(),
+        NonFungibleLocalId::string("x".repeat(64)).unwrap(),
+        NonFungibleLocalId::integer(0),
+        NonFungibleLocalId::integer(1),
+        NonFungibleLocalId::integer(u64::MAX),
+        NonFungibleLocalId::bytes(vec![0x00]).unwrap(),
+        NonFungibleLocalId::bytes(vec![0xab; 64]).unwrap(),
+        NonFungibleLocalId::ruid([0x00; 32]),
+        NonFungibleLocalId::ruid([0xff; 32]),
+    ]
+}
+
+const INVALID_NON_FUNGIBLE_LOCAL_IDS: [&str; 10] = [
+    "",
+    "a",
+    "<>",
+    "<hello world>",
+    "#-1#",
+    "#18446744073709551616#",
+    "[]",
+    "[abc]",
+    "{1111111111111111-1111111111111111-1111111111111111}",
+    "(1)",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_vectors_verify_after_json_round_trip() {
+        let vectors = TestVectors::generate(&NetworkDefinition::simulator());
+        let json = serde_json::to_string(&vectors).unwrap();
+        let parsed: TestVectors = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, vectors);
+        assert_eq!(parsed.verify(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let network = NetworkDefinition::simulator();
+
+        assert_eq!(
+            TestVectors::generate(&network),
+            TestVectors::generate(&network)
+        );
+    }
+
+    #[test]
+    fn test_mismatched_vector_is_reported() {
+        let mut vectors = TestVectors::generate(&NetworkDefinition::simulator());
+        let bool_true = vectors
+            .scrypto_sbor
+            .iter_mut()
+            .find(|v| v.name == "bool_true")
+            .unwrap();
+        assert_eq!(bool_true.payload, "5c0101");
+        bool_true.payload = "5c0100".to_owned();
+
+        let failures = vectors.verify().unwrap();
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].category, "scrypto_sbor");
+        assert_eq!(failures[0].name, "bool_true");
+    }
+}
@@ -0,0 +1,13 @@
+#!/bin/bash
+
+set -x
+set -e
+
+cd "$(dirname "$0")/.."
+
+rcap="cargo run --bin rcap $@ --"
+
+# Fails if the client API surface differs from the committed snapshot.
+# To accept an intended change, regenerate the snapshot with:
+#   cargo run --bin rcap -- ../radix-engine-interface/src/api --output ./tests/capability_manifest.json
+$rcap ../radix-engine-interface/src/api --check ./tests/capability_manifest.json
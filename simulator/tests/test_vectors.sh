@@ -0,0 +1,15 @@
+#!/bin/bash
+
+set -x
+set -e
+
+cd "$(dirname "$0")/.."
+
+rtvg="cargo run --bin rtvg $@ --"
+
+# Generates the test vectors twice, checking that generation is deterministic, and verifies them.
+# Other implementations can be checked by running `verify` against vectors they've produced.
+$rtvg generate --output ./target/test_vectors.json
+$rtvg generate --output ./target/test_vectors_2.json
+cmp ./target/test_vectors.json ./target/test_vectors_2.json
+$rtvg verify ./target/test_vectors.json
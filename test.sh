@@ -36,7 +36,9 @@ echo "Testing CLIs..."
 test_cli \
     "./tests/resim.sh \
     ./tests/scrypto.sh \
-    ./tests/manifest.sh"
+    ./tests/manifest.sh \
+    ./tests/capabilities.sh \
+    ./tests/test_vectors.sh"
 
 echo "Running benchmark..."
 test_benchmark  \
@@ -166,6 +166,9 @@ impl ScenarioCore {
                 notary_public_key: self.default_notary.public_key(),
                 notary_is_signatory: false,
                 tip_percentage: 0,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
             })
             .manifest(manifest);
         for signer in signers {
@@ -8,9 +8,11 @@ use radix_engine_interface::api::node_modules::ModuleConfig;
 use radix_engine_interface::api::ObjectModuleId;
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::component_config::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
 use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::payment_stream::*;
 use radix_engine_interface::blueprints::resource::*;
 
 /// A manifest builder for use in tests.
@@ -354,6 +356,30 @@ impl ManifestBuilder {
         })
     }
 
+    /// Asserts that worktop contains exactly the given amount of resource.
+    pub fn assert_worktop_contains_exactly(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+        self.add_instruction(InstructionV1::AssertWorktopContainsExactly {
+            amount,
+            resource_address,
+        })
+    }
+
+    /// Asserts that worktop contains no resources other than the given ones.
+    pub fn assert_worktop_resources_only(
+        self,
+        resource_addresses: impl IntoIterator<Item = ResourceAddress>,
+    ) -> Self {
+        self.add_instruction(InstructionV1::AssertWorktopResourcesOnly {
+            resource_addresses: resource_addresses.into_iter().collect(),
+        })
+    }
+
     /// Pops the most recent proof from auth zone.
     pub fn pop_from_auth_zone(self, new_proof: impl NewManifestProof) -> Self {
         new_proof.register(&self.registrar);
@@ -501,6 +527,15 @@ impl ManifestBuilder {
         self.add_instruction(InstructionV1::DropAllProofs)
     }
 
+    /// Deposits everything left on the worktop into the given account.
+    /// No instruction touching resources may follow this one.
+    pub fn drop_all_buckets_into(self, account_address: impl ResolvableComponentAddress) -> Self {
+        let account_address = account_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::DropAllBucketsInto {
+            account_address: account_address.into(),
+        })
+    }
+
     /// Drops all virtual proofs.
     pub fn clear_signature_proofs(self) -> Self {
         self.add_instruction(InstructionV1::ClearSignatureProofs)
@@ -965,6 +1000,20 @@ impl ManifestBuilder {
         })
     }
 
+    /// Replaces the configuration of a component generated by `#[component_config(<field>)]`.
+    pub fn set_component_config(
+        self,
+        component_address: impl ResolvableComponentAddress,
+        config: impl ManifestEncode,
+    ) -> Self {
+        let address = component_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: COMPONENT_CONFIG_SET_IDENT.to_string(),
+            args: manifest_args!(config).into(),
+        })
+    }
+
     pub fn set_component_royalty(
         self,
         component_address: impl ResolvableComponentAddress,
@@ -1009,6 +1058,42 @@ impl ManifestBuilder {
         })
     }
 
+    /// Opts the component into rent, paid from the bucket of XRD at `rent_per_epoch`.
+    /// Requires the rent setter role, which defaults to the owner role.
+    pub fn enable_component_rent(
+        self,
+        component_address: impl ResolvableComponentAddress,
+        rent_per_epoch: impl ResolvableDecimal,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = component_address.resolve(&self.registrar);
+        let rent_per_epoch = rent_per_epoch.resolve();
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.add_instruction(InstructionV1::CallRoyaltyMethod {
+            address: address.into(),
+            method_name: COMPONENT_ROYALTY_ENABLE_RENT_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ComponentEnableRentManifestInput {
+                rent_per_epoch,
+                bucket,
+            }),
+        })
+    }
+
+    /// Tops up the rent balance of the component with the bucket of XRD.
+    pub fn top_up_component_rent(
+        self,
+        component_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = component_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.add_instruction(InstructionV1::CallRoyaltyMethod {
+            address: address.into(),
+            method_name: COMPONENT_ROYALTY_TOP_UP_RENT_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&ComponentTopUpRentManifestInput { bucket }),
+        })
+    }
+
     pub fn set_owner_role(self, address: impl ResolvableGlobalAddress, rule: AccessRule) -> Self {
         let address = address.resolve(&self.registrar);
         self.add_instruction(InstructionV1::CallAccessRulesMethod {
@@ -1037,6 +1122,21 @@ impl ManifestBuilder {
         })
     }
 
+    /// Sets the role to deny all and locks it, so that it can never be updated again.
+    pub fn renounce_role(
+        self,
+        address: impl ResolvableGlobalAddress,
+        module: ObjectModuleId,
+        role_key: RoleKey,
+    ) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallAccessRulesMethod {
+            address: address.into(),
+            method_name: ACCESS_RULES_RENOUNCE_ROLE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccessRulesRenounceRoleInput { module, role_key }),
+        })
+    }
+
     pub fn lock_owner_role(self, address: impl ResolvableGlobalAddress) -> Self {
         let address = address.resolve(&self.registrar);
         self.add_instruction(InstructionV1::CallAccessRulesMethod {
@@ -1046,6 +1146,30 @@ impl ManifestBuilder {
         })
     }
 
+    /// Pauses the component, so that only the `exempt_methods` of its main module can be called
+    /// until it's unpaused. Requires the owner role.
+    pub fn pause_component(
+        self,
+        address: impl ResolvableGlobalAddress,
+        exempt_methods: BTreeSet<String>,
+    ) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallAccessRulesMethod {
+            address: address.into(),
+            method_name: ACCESS_RULES_PAUSE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccessRulesPauseInput { exempt_methods }),
+        })
+    }
+
+    pub fn unpause_component(self, address: impl ResolvableGlobalAddress) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallAccessRulesMethod {
+            address: address.into(),
+            method_name: ACCESS_RULES_UNPAUSE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccessRulesUnpauseInput {}),
+        })
+    }
+
     pub fn get_role(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1060,6 +1184,15 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn get_owner_role_history(self, address: impl ResolvableGlobalAddress) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallAccessRulesMethod {
+            address: address.into(),
+            method_name: ACCESS_RULES_GET_OWNER_ROLE_HISTORY_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccessRulesGetOwnerRoleHistoryInput {}),
+        })
+    }
+
     pub fn set_metadata(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1084,6 +1217,19 @@ impl ManifestBuilder {
         }
     }
 
+    pub fn remove_metadata(
+        self,
+        address: impl ResolvableGlobalAddress,
+        key: impl Into<String>,
+    ) -> Self {
+        let address = address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMetadataMethod {
+            address: address.into(),
+            method_name: METADATA_REMOVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&MetadataRemoveInput { key: key.into() }),
+        })
+    }
+
     pub fn lock_metadata(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1344,6 +1490,29 @@ impl ManifestBuilder {
         })
     }
 
+    /// Mints non-fungibles whose data is attached to the transaction as blobs, each holding the
+    /// SBOR-encoded data of one non-fungible.
+    pub fn mint_non_fungible_from_blobs<T: IntoIterator<Item = (NonFungibleLocalId, Vec<u8>)>>(
+        mut self,
+        resource_address: impl ResolvableResourceAddress,
+        entries: T,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+
+        let entries = entries
+            .into_iter()
+            .map(|(id, payload)| (id, self.add_blob(payload)))
+            .collect();
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerMintFromBlobsManifestInput { entries }
+            ),
+        })
+    }
+
     pub fn mint_ruid_non_fungible<T: IntoIterator<Item = V>, V: ManifestEncode>(
         self,
         resource_address: impl ResolvableResourceAddress,
@@ -1365,6 +1534,20 @@ impl ManifestBuilder {
         })
     }
 
+    /// Calls a direct access method on a vault, such as a recall or freeze, bypassing its owner.
+    pub fn call_direct_vault_method(
+        self,
+        vault_address: InternalAddress,
+        method_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: method_name.into(),
+            args: arguments.resolve(),
+        })
+    }
+
     pub fn recall(self, vault_address: InternalAddress, amount: impl ResolvableDecimal) -> Self {
         let amount = amount.resolve();
         self.add_instruction(InstructionV1::CallDirectVaultMethod {
@@ -1390,6 +1573,44 @@ impl ManifestBuilder {
         })
     }
 
+    /// Recalls from a vault, recording the given reason in the recall's audit event.
+    pub fn recall_with_reason(
+        self,
+        vault_address: InternalAddress,
+        amount: impl ResolvableDecimal,
+        reason: impl Into<String>,
+    ) -> Self {
+        let amount = amount.resolve();
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: VAULT_RECALL_WITH_REASON_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&VaultRecallWithReasonInput {
+                amount,
+                reason: reason.into(),
+            }),
+        })
+    }
+
+    /// Recalls non-fungibles from a vault, recording the given reason in the recall's audit event.
+    pub fn recall_non_fungibles_with_reason(
+        self,
+        vault_address: InternalAddress,
+        non_fungible_local_ids: &BTreeSet<NonFungibleLocalId>,
+        reason: impl Into<String>,
+    ) -> Self {
+        let args =
+            to_manifest_value_and_unwrap!(&NonFungibleVaultRecallNonFungiblesWithReasonInput {
+                non_fungible_local_ids: non_fungible_local_ids.clone(),
+                reason: reason.into(),
+            });
+
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_address,
+            method_name: NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_WITH_REASON_IDENT.to_string(),
+            args,
+        })
+    }
+
     pub fn freeze_withdraw(self, vault_id: InternalAddress) -> Self {
         self.add_instruction(InstructionV1::CallDirectVaultMethod {
             address: vault_id,
@@ -1450,6 +1671,26 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn freeze_recall(self, vault_id: InternalAddress) -> Self {
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_id,
+            method_name: VAULT_FREEZE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&VaultFreezeInput {
+                to_freeze: VaultFreezeFlags::RECALL,
+            }),
+        })
+    }
+
+    pub fn unfreeze_recall(self, vault_id: InternalAddress) -> Self {
+        self.add_instruction(InstructionV1::CallDirectVaultMethod {
+            address: vault_id,
+            method_name: VAULT_UNFREEZE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&VaultUnfreezeInput {
+                to_unfreeze: VaultFreezeFlags::RECALL,
+            }),
+        })
+    }
+
     /// Creates an account.
     pub fn new_account_advanced(self, owner_role: OwnerRole) -> Self {
         self.add_instruction(InstructionV1::CallFunction {
@@ -1611,7 +1852,7 @@ impl ManifestBuilder {
         })
     }
 
-    /// Withdraws resource from an account.
+    /// Burns resource directly from the vault of an account.
     pub fn burn_in_account(
         self,
         account_address: impl ResolvableComponentAddress,
@@ -1633,7 +1874,31 @@ impl ManifestBuilder {
         })
     }
 
+    /// Burns non-fungibles directly from the vault of an account.
+    pub fn burn_non_fungibles_in_account(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        ids: &BTreeSet<NonFungibleLocalId>,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+
+        let args = to_manifest_value_and_unwrap!(&AccountBurnNonFungiblesInput {
+            resource_address,
+            ids: ids.clone(),
+        });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: ACCOUNT_BURN_NON_FUNGIBLES_IDENT.to_string(),
+            args,
+        })
+    }
+
     /// Creates resource proof from an account.
+    ///
+    /// The proof returned by the account is pushed onto the auth zone.
     pub fn create_proof_from_account_of_amount(
         self,
         account_address: impl ResolvableComponentAddress,
@@ -1656,6 +1921,8 @@ impl ManifestBuilder {
     }
 
     /// Creates resource proof from an account.
+    ///
+    /// The proof returned by the account is pushed onto the auth zone.
     pub fn create_proof_from_account_of_non_fungibles(
         self,
         account_address: impl ResolvableComponentAddress,
@@ -1677,6 +1944,63 @@ impl ManifestBuilder {
         })
     }
 
+    /// Creates a proof of a single non-fungible from an account, pushing it onto the auth zone.
+    pub fn create_proof_from_account_of_non_fungible(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        non_fungible_global_id: &NonFungibleGlobalId,
+    ) -> Self {
+        self.create_proof_from_account_of_non_fungibles(
+            account_address,
+            non_fungible_global_id.resource_address(),
+            &btreeset!(non_fungible_global_id.local_id().clone()),
+        )
+    }
+
+    /// Creates several resource proofs from an account in a single call.
+    ///
+    /// The proofs returned by the account are pushed onto the auth zone, in the given order.
+    pub fn create_proofs_from_account(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        proofs: Vec<AccountProofSpecifier>,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let args = to_manifest_value_and_unwrap!(&AccountCreateProofsInput { proofs });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: ACCOUNT_CREATE_PROOFS_IDENT.to_string(),
+            args,
+        })
+    }
+
+    /// Creates resource proof from an account, and pops it from the auth zone into a named
+    /// proof, eg to pass it as an argument.
+    pub fn create_named_proof_from_account_of_amount(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+        new_proof: impl NewManifestProof,
+    ) -> Self {
+        self.create_proof_from_account_of_amount(account_address, resource_address, amount)
+            .pop_from_auth_zone(new_proof)
+    }
+
+    /// Creates resource proof from an account, and pops it from the auth zone into a named
+    /// proof, eg to pass it as an argument.
+    pub fn create_named_proof_from_account_of_non_fungibles(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        ids: &BTreeSet<NonFungibleLocalId>,
+        new_proof: impl NewManifestProof,
+    ) -> Self {
+        self.create_proof_from_account_of_non_fungibles(account_address, resource_address, ids)
+            .pop_from_auth_zone(new_proof)
+    }
+
     pub fn deposit(
         self,
         account_address: impl ResolvableComponentAddress,
@@ -1788,11 +2112,58 @@ impl ManifestBuilder {
         )
     }
 
+    pub fn create_payment_stream(
+        self,
+        bucket: impl ExistingManifestBucket,
+        rate_per_epoch: impl ResolvableDecimal,
+        sender_rule: AccessRule,
+        recipient_rule: AccessRule,
+    ) -> Self {
+        let bucket = bucket.mark_consumed(&self.registrar);
+        let rate_per_epoch = rate_per_epoch.resolve();
+        self.call_function(
+            PAYMENT_STREAM_PACKAGE,
+            PAYMENT_STREAM_BLUEPRINT,
+            PAYMENT_STREAM_INSTANTIATE_IDENT,
+            PaymentStreamInstantiateManifestInput {
+                bucket,
+                rate_per_epoch,
+                sender_rule,
+                recipient_rule,
+            },
+        )
+    }
+
+    pub fn withdraw_from_payment_stream(
+        self,
+        payment_stream_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = payment_stream_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            PAYMENT_STREAM_WITHDRAW_IDENT,
+            PaymentStreamWithdrawManifestInput {},
+        )
+    }
+
+    pub fn cancel_payment_stream(
+        self,
+        payment_stream_address: impl ResolvableComponentAddress,
+    ) -> Self {
+        let address = payment_stream_address.resolve(&self.registrar);
+        self.call_method(
+            address,
+            PAYMENT_STREAM_CANCEL_IDENT,
+            PaymentStreamCancelManifestInput {},
+        )
+    }
+
     /// Builds a transaction manifest.
     pub fn build(self) -> TransactionManifestV1 {
         let manifest = TransactionManifestV1 {
             instructions: self.instructions,
             blobs: self.blobs,
+            labels: BTreeMap::new(),
         };
         #[cfg(feature = "dump_manifest_to_file")]
         {
@@ -1,7 +1,9 @@
 mod manifest_builder;
 mod manifest_namer;
 mod transaction_builder;
+mod upgrade_plan;
 
 pub use manifest_builder::*;
 pub use manifest_namer::*;
 pub use transaction_builder::*;
+pub use upgrade_plan::*;
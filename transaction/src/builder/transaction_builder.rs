@@ -127,6 +127,9 @@ mod tests {
                 notary_public_key: private_key.public_key().into(),
                 notary_is_signatory: true,
                 tip_percentage: 5,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
             })
             .manifest(ManifestBuilder::new().clear_auth_zone().build())
             .notarize(&private_key)
@@ -0,0 +1,196 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::api::node_modules::metadata::MetadataInit;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::package::*;
+
+/// The manifest name of the address reservation for the package published by an [`UpgradePlan`].
+pub const UPGRADE_PLAN_PACKAGE_RESERVATION_NAME: &str = "upgrade_plan_package_reservation";
+/// The manifest name of the address of the package published by an [`UpgradePlan`].
+pub const UPGRADE_PLAN_PACKAGE_ADDRESS_NAME: &str = "upgrade_plan_package";
+
+/// A declarative description of a coordinated upgrade: publishing a new package, migrating the
+/// state of components through their migration methods, and then updating roles.
+///
+/// The whole plan is applied within one transaction, so if any step fails, none of it is
+/// committed. Previewing the transaction gives a dry run of the upgrade.
+///
+/// Example:
+/// ```
+/// # use transaction::prelude::*;
+/// # use radix_engine_interface::api::node_modules::metadata::MetadataInit;
+/// # use radix_engine_interface::api::ObjectModuleId;
+/// # use radix_engine_interface::blueprints::package::PackageDefinition;
+/// # let component_address = GENESIS_HELPER; // Just some address to get it to compile
+/// # let (code, definition) = (vec![], PackageDefinition::default());
+/// let plan = UpgradePlan::new()
+///     .publish_package(code, definition, MetadataInit::default(), OwnerRole::None)
+///     .migrate_with_new_package_address(component_address, "migrate", manifest_args!(2u32))
+///     .update_role(component_address, ObjectModuleId::Main, "migrator", AccessRule::DenyAll);
+/// let manifest = ManifestBuilder::new()
+///     .lock_fee_from_faucet()
+///     .upgrade(&plan)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UpgradePlan {
+    pub package: Option<PackageUpgrade>,
+    pub migrations: Vec<ComponentMigration>,
+    pub role_updates: Vec<RoleUpdate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageUpgrade {
+    pub code: Vec<u8>,
+    pub definition: PackageDefinition,
+    pub metadata: MetadataInit,
+    pub owner_role: OwnerRole,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentMigration {
+    pub component_address: ComponentAddress,
+    pub method_name: String,
+    /// The arguments of the migration method, which must be a tuple.
+    pub args: ManifestValue,
+    /// Whether the address of the newly published package is passed as the first argument.
+    pub with_new_package_address: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleUpdate {
+    pub address: GlobalAddress,
+    pub module: ObjectModuleId,
+    pub role_key: RoleKey,
+    pub rule: AccessRule,
+}
+
+impl UpgradePlan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish_package(
+        mut self,
+        code: Vec<u8>,
+        definition: PackageDefinition,
+        metadata: impl Into<MetadataInit>,
+        owner_role: OwnerRole,
+    ) -> Self {
+        self.package = Some(PackageUpgrade {
+            code,
+            definition,
+            metadata: metadata.into(),
+            owner_role,
+        });
+        self
+    }
+
+    pub fn migrate(
+        mut self,
+        component_address: ComponentAddress,
+        method_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        self.migrations.push(ComponentMigration {
+            component_address,
+            method_name: method_name.into(),
+            args: arguments.resolve(),
+            with_new_package_address: false,
+        });
+        self
+    }
+
+    /// Adds a migration which is passed the address of the newly published package, followed
+    /// by the given arguments.
+    pub fn migrate_with_new_package_address(
+        mut self,
+        component_address: ComponentAddress,
+        method_name: impl Into<String>,
+        arguments: impl ResolvableArguments,
+    ) -> Self {
+        self.migrations.push(ComponentMigration {
+            component_address,
+            method_name: method_name.into(),
+            args: arguments.resolve(),
+            with_new_package_address: true,
+        });
+        self
+    }
+
+    pub fn update_role(
+        mut self,
+        address: impl Into<GlobalAddress>,
+        module: ObjectModuleId,
+        role_key: impl Into<RoleKey>,
+        rule: AccessRule,
+    ) -> Self {
+        self.role_updates.push(RoleUpdate {
+            address: address.into(),
+            module,
+            role_key: role_key.into(),
+            rule,
+        });
+        self
+    }
+}
+
+impl ManifestBuilder {
+    /// Adds the instructions applying the upgrade plan, in order: the package is published first,
+    /// then the components are migrated, and finally the roles are updated.
+    ///
+    /// Panics if a migration requires the new package address but the plan publishes no package.
+    pub fn upgrade(self, plan: &UpgradePlan) -> Self {
+        let mut builder = self;
+
+        if let Some(package) = &plan.package {
+            builder = builder
+                .allocate_global_address(
+                    PACKAGE_PACKAGE,
+                    PACKAGE_BLUEPRINT,
+                    UPGRADE_PLAN_PACKAGE_RESERVATION_NAME,
+                    UPGRADE_PLAN_PACKAGE_ADDRESS_NAME,
+                )
+                .publish_package_advanced(
+                    Some(UPGRADE_PLAN_PACKAGE_RESERVATION_NAME.to_string()),
+                    package.code.clone(),
+                    package.definition.clone(),
+                    package.metadata.clone(),
+                    package.owner_role.clone(),
+                );
+        }
+
+        for migration in &plan.migrations {
+            let args = if migration.with_new_package_address {
+                assert!(
+                    plan.package.is_some(),
+                    "Migration requires a new package, but the upgrade plan publishes none"
+                );
+                let package_address = builder.named_address(UPGRADE_PLAN_PACKAGE_ADDRESS_NAME);
+                let ManifestValue::Tuple { fields } = &migration.args else {
+                    panic!("Migration arguments must be a tuple");
+                };
+                let mut fields = fields.clone();
+                fields.insert(0, to_manifest_value_and_unwrap!(&package_address));
+                ManifestValue::Tuple { fields }
+            } else {
+                migration.args.clone()
+            };
+            builder = builder.call_method_raw(
+                migration.component_address,
+                migration.method_name.clone(),
+                args,
+            );
+        }
+
+        for role_update in &plan.role_updates {
+            builder = builder.update_role(
+                role_update.address,
+                role_update.module,
+                role_update.role_key.clone(),
+                role_update.rule.clone(),
+            );
+        }
+
+        builder
+    }
+}
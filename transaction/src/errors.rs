@@ -9,6 +9,8 @@ pub enum HeaderValidationError {
     InvalidNetwork,
     InvalidCostUnitLimit,
     InvalidTipPercentage,
+    InvalidMaxFee,
+    InvalidExpiryTimestamp,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -51,6 +53,11 @@ pub enum TransactionValidationError {
     IdValidationError(ManifestIdValidationError),
     CallDataValidationError(CallDataValidationError),
     InvalidMessage(InvalidMessageError),
+    ResourceInstructionAfterDropAllBuckets,
+    ValidationRuleViolation {
+        rule: String,
+        violation: RuleViolation,
+    },
 }
 
 impl From<PrepareError> for TransactionValidationError {
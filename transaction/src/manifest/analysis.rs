@@ -0,0 +1,294 @@
+use crate::internal_prelude::*;
+use crate::manifest::resource_flow::global_node;
+use radix_engine_interface::blueprints::account::*;
+
+/// An amount of resource moved or presented by an instruction of a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceMovement {
+    pub instruction_index: usize,
+    /// The resource, or `None` for several (or statically unknown) resources.
+    pub resource_address: Option<ResourceAddress>,
+    pub amount: ResourceFlowAmount,
+}
+
+/// A fee locked in XRD from an account by an instruction of a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeLock {
+    pub instruction_index: usize,
+    pub amount: Decimal,
+    /// Whether the fee is only paid if the transaction commits successfully.
+    pub contingent: bool,
+}
+
+/// What a manifest does to one of the accounts it calls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountSummary {
+    pub withdrawals: Vec<ResourceMovement>,
+    pub deposits: Vec<ResourceMovement>,
+    /// The proofs created from the resources of the account, e.g. of badges presented for
+    /// auth.
+    pub presented_badges: Vec<ResourceMovement>,
+    pub fee_locks: Vec<FeeLock>,
+}
+
+impl AccountSummary {
+    /// The total of the fees locked from the account.
+    pub fn total_fee_locked(&self) -> Decimal {
+        self.fee_locks
+            .iter()
+            .fold(Decimal::ZERO, |total, fee_lock| total + fee_lock.amount)
+    }
+}
+
+/// A summary of what a manifest does to the accounts it calls, computed statically from its
+/// instructions, without executing it.
+///
+/// Resource deposited into an account which comes from calls to blueprints is generally
+/// unknown, and shows up as a [`ResourceFlowAmount::All`] deposit of the worktop, or as an
+/// [`ResourceFlowAmount::Unknown`] amount.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestSummary {
+    /// The accounts called by the manifest, in the order they are first called.
+    pub accounts: IndexMap<GlobalAddress, AccountSummary>,
+}
+
+impl ManifestSummary {
+    pub fn from_manifest(manifest: &TransactionManifestV1) -> Self {
+        Self::from_instructions(&manifest.instructions)
+    }
+
+    pub fn from_instructions(instructions: &[InstructionV1]) -> Self {
+        let mut summary = Self::default();
+
+        // Account calls are visited in order, so that the accounts are ordered by first call
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            if let InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } = instruction
+            {
+                if let ResourceFlowNode::Account(account) = global_node(address) {
+                    summary.visit_account_call(instruction_index, account, method_name, args);
+                }
+            }
+            if let InstructionV1::DropAllBucketsInto {
+                account_address: DynamicGlobalAddress::Static(account),
+            } = instruction
+            {
+                summary.accounts.entry(*account).or_default();
+            }
+        }
+
+        let graph = ResourceFlowGraph::from_instructions(instructions);
+        for edge in graph.edges {
+            let movement = ResourceMovement {
+                instruction_index: edge.instruction_index,
+                resource_address: edge.resource_address,
+                amount: edge.amount,
+            };
+            if let ResourceFlowNode::Account(account) = edge.from {
+                summary
+                    .accounts
+                    .entry(account)
+                    .or_default()
+                    .withdrawals
+                    .push(movement);
+            } else if let ResourceFlowNode::Account(account) = edge.to {
+                summary
+                    .accounts
+                    .entry(account)
+                    .or_default()
+                    .deposits
+                    .push(movement);
+            }
+        }
+
+        summary
+    }
+
+    pub fn account(&self, address: impl Into<GlobalAddress>) -> Option<&AccountSummary> {
+        self.accounts.get(&address.into())
+    }
+
+    fn visit_account_call(
+        &mut self,
+        instruction_index: usize,
+        account: GlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let account = self.accounts.entry(account).or_default();
+        let encoded = manifest_encode(args).unwrap();
+        let fee_lock = |amount, contingent| FeeLock {
+            instruction_index,
+            amount,
+            contingent,
+        };
+        let badge = |resource_address, amount| ResourceMovement {
+            instruction_index,
+            resource_address: Some(resource_address),
+            amount,
+        };
+
+        match method_name {
+            ACCOUNT_LOCK_FEE_IDENT => {
+                if let Ok(input) = manifest_decode::<AccountLockFeeInput>(&encoded) {
+                    account.fee_locks.push(fee_lock(input.amount, false));
+                }
+            }
+            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                if let Ok(input) = manifest_decode::<AccountLockContingentFeeInput>(&encoded) {
+                    account.fee_locks.push(fee_lock(input.amount, true));
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                if let Ok(input) = manifest_decode::<AccountLockFeeAndWithdrawInput>(&encoded) {
+                    account
+                        .fee_locks
+                        .push(fee_lock(input.amount_to_lock, false));
+                }
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                if let Ok(input) =
+                    manifest_decode::<AccountLockFeeAndWithdrawNonFungiblesInput>(&encoded)
+                {
+                    account
+                        .fee_locks
+                        .push(fee_lock(input.amount_to_lock, false));
+                }
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                if let Ok(input) = manifest_decode::<AccountCreateProofOfAmountInput>(&encoded) {
+                    account.presented_badges.push(badge(
+                        input.resource_address,
+                        ResourceFlowAmount::Amount(input.amount),
+                    ));
+                }
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                if let Ok(input) =
+                    manifest_decode::<AccountCreateProofOfNonFungiblesInput>(&encoded)
+                {
+                    account.presented_badges.push(badge(
+                        input.resource_address,
+                        ResourceFlowAmount::NonFungibles(input.ids.into_iter().collect()),
+                    ));
+                }
+            }
+            ACCOUNT_CREATE_PROOFS_IDENT => {
+                if let Ok(input) = manifest_decode::<AccountCreateProofsInput>(&encoded) {
+                    for proof in input.proofs {
+                        account.presented_badges.push(match proof {
+                            AccountProofSpecifier::Amount(resource_address, amount) => {
+                                badge(resource_address, ResourceFlowAmount::Amount(amount))
+                            }
+                            AccountProofSpecifier::NonFungibles(resource_address, ids) => badge(
+                                resource_address,
+                                ResourceFlowAmount::NonFungibles(ids.into_iter().collect()),
+                            ),
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(key: u64) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(key).unwrap().public_key(),
+        )
+    }
+
+    #[test]
+    fn test_transfer_between_accounts() {
+        let from = account(1);
+        let to = account(2);
+        let badge = XRD;
+        let manifest = ManifestBuilder::new()
+            .lock_fee(from, 10)
+            .create_proof_from_account_of_amount(from, badge, 1)
+            .withdraw_from_account(from, XRD, 100)
+            .take_from_worktop(XRD, 100, "bucket")
+            .try_deposit_or_abort(to, "bucket")
+            .build();
+
+        let summary = ManifestSummary::from_manifest(&manifest);
+
+        assert_eq!(
+            summary.accounts.keys().cloned().collect::<Vec<_>>(),
+            vec![from.into(), to.into()]
+        );
+        assert_eq!(
+            summary.account(from),
+            Some(&AccountSummary {
+                withdrawals: vec![ResourceMovement {
+                    instruction_index: 2,
+                    resource_address: Some(XRD),
+                    amount: ResourceFlowAmount::Amount(dec!(100)),
+                }],
+                deposits: vec![],
+                presented_badges: vec![ResourceMovement {
+                    instruction_index: 1,
+                    resource_address: Some(badge),
+                    amount: ResourceFlowAmount::Amount(dec!(1)),
+                }],
+                fee_locks: vec![FeeLock {
+                    instruction_index: 0,
+                    amount: dec!(10),
+                    contingent: false,
+                }],
+            })
+        );
+        assert_eq!(
+            summary.account(to),
+            Some(&AccountSummary {
+                deposits: vec![ResourceMovement {
+                    instruction_index: 4,
+                    resource_address: Some(XRD),
+                    amount: ResourceFlowAmount::Amount(dec!(100)),
+                }],
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_fee_locks_are_totalled() {
+        let payer = account(1);
+        let manifest = ManifestBuilder::new()
+            .lock_fee(payer, 10)
+            .lock_contingent_fee(payer, 5)
+            .lock_fee_and_withdraw(payer, 2, XRD, 1)
+            .try_deposit_batch_or_abort(payer)
+            .build();
+
+        let summary = ManifestSummary::from_manifest(&manifest);
+
+        let payer = summary.account(payer).unwrap();
+        assert_eq!(payer.total_fee_locked(), dec!(17));
+        assert_eq!(
+            payer
+                .fee_locks
+                .iter()
+                .map(|l| l.contingent)
+                .collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+        assert_eq!(payer.withdrawals.len(), 1);
+        assert_eq!(
+            payer.deposits,
+            vec![ResourceMovement {
+                instruction_index: 3,
+                resource_address: None,
+                amount: ResourceFlowAmount::All,
+            }]
+        );
+    }
+}
@@ -40,6 +40,15 @@ pub enum Instruction {
         resource_address: Value,
     },
 
+    AssertWorktopContainsExactly {
+        resource_address: Value,
+        amount: Value,
+    },
+
+    AssertWorktopResourcesOnly {
+        resource_addresses: Value,
+    },
+
     PopFromAuthZone {
         new_proof: Value,
     },
@@ -139,6 +148,16 @@ pub enum Instruction {
         named_address: Value,
     },
 
+    DropAllBucketsInto {
+        account_address: Value,
+    },
+
+    CallDirectVaultMethod {
+        address: Value,
+        method_name: Value,
+        args: Vec<Value>,
+    },
+
     /* Call direct vault method aliases */
     RecallFromVault {
         vault_id: Value,
@@ -221,6 +240,18 @@ pub enum Instruction {
         address: Value,
         args: Vec<Value>,
     },
+    RenounceRole {
+        address: Value,
+        args: Vec<Value>,
+    },
+    PauseComponent {
+        address: Value,
+        args: Vec<Value>,
+    },
+    UnpauseComponent {
+        address: Value,
+        args: Vec<Value>,
+    },
     LockComponentRoyalty {
         address: Value,
         args: Vec<Value>,
@@ -243,15 +274,36 @@ pub enum Instruction {
         address: Value,
         args: Vec<Value>,
     },
+    MintNonFungibleFromBlobs {
+        address: Value,
+        args: Vec<Value>,
+    },
     MintRuidNonFungible {
         address: Value,
         args: Vec<Value>,
     },
+    SetComponentConfig {
+        address: Value,
+        args: Vec<Value>,
+    },
     CreateValidator {
         args: Vec<Value>,
     },
 }
 
+/// An annotation of an instruction, eg `@label("payment")`, which is carried alongside the
+/// compiled instructions rather than in them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    Label(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedInstruction {
+    pub annotations: Vec<Annotation>,
+    pub instruction: Instruction,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValueKind {
     // ==============
@@ -5,8 +5,8 @@ use radix_engine_common::native_addresses::PACKAGE_PACKAGE;
 use radix_engine_common::prelude::CONSENSUS_MANAGER;
 use radix_engine_interface::address::AddressBech32Encoder;
 use radix_engine_interface::api::node_modules::auth::{
-    ACCESS_RULES_LOCK_OWNER_ROLE_IDENT, ACCESS_RULES_SET_OWNER_ROLE_IDENT,
-    ACCESS_RULES_SET_ROLE_IDENT,
+    ACCESS_RULES_LOCK_OWNER_ROLE_IDENT, ACCESS_RULES_PAUSE_IDENT, ACCESS_RULES_RENOUNCE_ROLE_IDENT,
+    ACCESS_RULES_SET_OWNER_ROLE_IDENT, ACCESS_RULES_SET_ROLE_IDENT, ACCESS_RULES_UNPAUSE_IDENT,
 };
 use radix_engine_interface::api::node_modules::metadata::METADATA_SET_IDENT;
 use radix_engine_interface::api::node_modules::metadata::{
@@ -22,6 +22,7 @@ use radix_engine_interface::blueprints::access_controller::{
 use radix_engine_interface::blueprints::account::{
     ACCOUNT_BLUEPRINT, ACCOUNT_CREATE_ADVANCED_IDENT, ACCOUNT_CREATE_IDENT,
 };
+use radix_engine_interface::blueprints::component_config::COMPONENT_CONFIG_SET_IDENT;
 use radix_engine_interface::blueprints::consensus_manager::CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT;
 use radix_engine_interface::blueprints::identity::{
     IDENTITY_BLUEPRINT, IDENTITY_CREATE_ADVANCED_IDENT, IDENTITY_CREATE_IDENT,
@@ -83,11 +84,24 @@ impl From<RustToManifestValueError> for DecompileError {
     }
 }
 
-#[derive(Default)]
 pub struct DecompilationContext<'a> {
     pub address_bech32_encoder: Option<&'a AddressBech32Encoder>,
     pub id_allocator: ManifestIdAllocator,
     pub object_names: ManifestObjectNames,
+    /// Whether calls to well-known functions and methods are decompiled into their aliases,
+    /// such as `CREATE_ACCOUNT`, rather than into `CALL_FUNCTION` or `CALL_METHOD`.
+    pub use_aliases: bool,
+}
+
+impl<'a> Default for DecompilationContext<'a> {
+    fn default() -> Self {
+        Self {
+            address_bech32_encoder: None,
+            id_allocator: ManifestIdAllocator::default(),
+            object_names: ManifestObjectNames::default(),
+            use_aliases: true,
+        }
+    }
 }
 
 #[derive(Default, Clone)]
@@ -194,17 +208,87 @@ pub fn decompile_with_known_naming(
     instructions: &[InstructionV1],
     network: &NetworkDefinition,
     known_object_names: ManifestObjectNames,
+) -> Result<String, DecompileError> {
+    decompile_with_labels_and_known_naming(
+        instructions,
+        &BTreeMap::new(),
+        network,
+        known_object_names,
+    )
+}
+
+/// Decompiles the instructions, with the calls which have an alias decompiled into it only if
+/// `use_aliases` is set. Either way, the manifest compiles back into the same instructions.
+pub fn decompile_with_aliases(
+    instructions: &[InstructionV1],
+    network: &NetworkDefinition,
+    use_aliases: bool,
+) -> Result<String, DecompileError> {
+    let address_bech32_encoder = AddressBech32Encoder::new(network);
+    let mut context = DecompilationContext::new(&address_bech32_encoder, Default::default());
+    context.use_aliases = use_aliases;
+    decompile_with_context(instructions, &BTreeMap::new(), &mut context)
+}
+
+/// Decompiles a manifest, emitting the labels of its instructions as `@label` annotations.
+pub fn decompile_manifest(
+    manifest: &TransactionManifestV1,
+    network: &NetworkDefinition,
+) -> Result<String, DecompileError> {
+    decompile_with_labels_and_known_naming(
+        &manifest.instructions,
+        &manifest.labels,
+        network,
+        Default::default(),
+    )
+}
+
+pub fn decompile_with_labels_and_known_naming(
+    instructions: &[InstructionV1],
+    labels: &BTreeMap<usize, String>,
+    network: &NetworkDefinition,
+    known_object_names: ManifestObjectNames,
 ) -> Result<String, DecompileError> {
     let address_bech32_encoder = AddressBech32Encoder::new(network);
-    let mut buf = String::new();
     let mut context = DecompilationContext::new(&address_bech32_encoder, known_object_names);
-    for inst in instructions {
-        decompile_instruction(&mut buf, inst, &mut context)?;
+    decompile_with_context(instructions, labels, &mut context)
+}
+
+fn decompile_with_context(
+    instructions: &[InstructionV1],
+    labels: &BTreeMap<usize, String>,
+    context: &mut DecompilationContext,
+) -> Result<String, DecompileError> {
+    let mut buf = String::new();
+    for (index, inst) in instructions.iter().enumerate() {
+        if let Some(label) = labels.get(&index) {
+            decompile_label(&mut buf, label, context)?;
+        }
+        decompile_instruction(&mut buf, inst, context)?;
     }
 
     Ok(buf)
 }
 
+fn decompile_label<F: fmt::Write>(
+    f: &mut F,
+    label: &str,
+    context: &DecompilationContext,
+) -> Result<(), DecompileError> {
+    write!(f, "@label(")?;
+    format_manifest_value(
+        f,
+        &Value::String {
+            value: label.to_string(),
+        },
+        &context.for_value_display(),
+        false,
+        0,
+    )?;
+    write!(f, ")\n")?;
+    Ok(())
+}
+
 pub fn decompile_instruction<F: fmt::Write>(
     f: &mut F,
     instruction: &InstructionV1,
@@ -259,6 +343,17 @@ pub fn decompile_instruction<F: fmt::Write>(
             "ASSERT_WORKTOP_CONTAINS_ANY",
             to_manifest_value(&(resource_address,))?,
         ),
+        InstructionV1::AssertWorktopContainsExactly {
+            amount,
+            resource_address,
+        } => (
+            "ASSERT_WORKTOP_CONTAINS_EXACTLY",
+            to_manifest_value(&(resource_address, amount))?,
+        ),
+        InstructionV1::AssertWorktopResourcesOnly { resource_addresses } => (
+            "ASSERT_WORKTOP_RESOURCES_ONLY",
+            to_manifest_value(&(resource_addresses,))?,
+        ),
         InstructionV1::PopFromAuthZone => {
             let proof = context.new_proof();
             ("POP_FROM_AUTH_ZONE", to_manifest_value(&(proof,))?)
@@ -334,77 +429,82 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match (
+            let alias = match (
                 package_address,
                 blueprint_name.as_str(),
                 function_name.as_str(),
             ) {
+                _ if !context.use_aliases => None,
                 (package_address, PACKAGE_BLUEPRINT, PACKAGE_PUBLISH_WASM_IDENT)
                     if package_address.is_static_global_package_of(&PACKAGE_PACKAGE) =>
                 {
-                    "PUBLISH_PACKAGE"
+                    Some("PUBLISH_PACKAGE")
                 }
                 (package_address, PACKAGE_BLUEPRINT, PACKAGE_PUBLISH_WASM_ADVANCED_IDENT)
                     if package_address.is_static_global_package_of(&PACKAGE_PACKAGE) =>
                 {
-                    "PUBLISH_PACKAGE_ADVANCED"
+                    Some("PUBLISH_PACKAGE_ADVANCED")
                 }
                 (package_address, ACCOUNT_BLUEPRINT, ACCOUNT_CREATE_ADVANCED_IDENT)
                     if package_address.is_static_global_package_of(&ACCOUNT_PACKAGE) =>
                 {
-                    "CREATE_ACCOUNT_ADVANCED"
+                    Some("CREATE_ACCOUNT_ADVANCED")
                 }
                 (package_address, ACCOUNT_BLUEPRINT, ACCOUNT_CREATE_IDENT)
                     if package_address.is_static_global_package_of(&ACCOUNT_PACKAGE) =>
                 {
-                    "CREATE_ACCOUNT"
+                    Some("CREATE_ACCOUNT")
                 }
                 (package_address, IDENTITY_BLUEPRINT, IDENTITY_CREATE_ADVANCED_IDENT)
                     if package_address.is_static_global_package_of(&IDENTITY_PACKAGE) =>
                 {
-                    "CREATE_IDENTITY_ADVANCED"
+                    Some("CREATE_IDENTITY_ADVANCED")
                 }
                 (package_address, IDENTITY_BLUEPRINT, IDENTITY_CREATE_IDENT)
                     if package_address.is_static_global_package_of(&IDENTITY_PACKAGE) =>
                 {
-                    "CREATE_IDENTITY"
+                    Some("CREATE_IDENTITY")
                 }
                 (
                     package_address,
                     ACCESS_CONTROLLER_BLUEPRINT,
                     ACCESS_CONTROLLER_CREATE_GLOBAL_IDENT,
                 ) if package_address.is_static_global_package_of(&ACCESS_CONTROLLER_PACKAGE) => {
-                    "CREATE_ACCESS_CONTROLLER"
+                    Some("CREATE_ACCESS_CONTROLLER")
                 }
                 (
                     package_address,
                     FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
                     FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
                 ) if package_address.is_static_global_package_of(&RESOURCE_PACKAGE) => {
-                    "CREATE_FUNGIBLE_RESOURCE"
+                    Some("CREATE_FUNGIBLE_RESOURCE")
                 }
                 (
                     package_address,
                     FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
                     FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
                 ) if package_address.is_static_global_package_of(&RESOURCE_PACKAGE) => {
-                    "CREATE_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY"
+                    Some("CREATE_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY")
                 }
                 (
                     package_address,
                     NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
                     NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_IDENT,
                 ) if package_address.is_static_global_package_of(&RESOURCE_PACKAGE) => {
-                    "CREATE_NON_FUNGIBLE_RESOURCE"
+                    Some("CREATE_NON_FUNGIBLE_RESOURCE")
                 }
                 (
                     package_address,
                     NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
                     NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
                 ) if package_address.is_static_global_package_of(&RESOURCE_PACKAGE) => {
-                    "CREATE_NON_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY"
+                    Some("CREATE_NON_FUNGIBLE_RESOURCE_WITH_INITIAL_SUPPLY")
                 }
-                _ => {
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(package_address.to_instruction_argument());
                     fields.push(to_manifest_value(blueprint_name)?);
                     fields.push(to_manifest_value(function_name)?);
@@ -427,13 +527,14 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match (address, method_name.as_str()) {
+            let alias = match (address, method_name.as_str()) {
+                _ if !context.use_aliases => None,
                 // Nb - For Main method call, we also check the address type to avoid name clashing.
 
                 /* Package */
                 (address, PACKAGE_CLAIM_ROYALTIES_IDENT) if address.is_static_global_package() => {
                     fields.push(address.to_instruction_argument());
-                    "CLAIM_PACKAGE_ROYALTIES"
+                    Some("CLAIM_PACKAGE_ROYALTIES")
                 }
 
                 /* Resource manager */
@@ -441,30 +542,46 @@ pub fn decompile_instruction<F: fmt::Write>(
                     if address.is_static_global_fungible_resource_manager() =>
                 {
                     fields.push(address.to_instruction_argument());
-                    "MINT_FUNGIBLE"
+                    Some("MINT_FUNGIBLE")
                 }
                 (address, NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT)
                     if address.is_static_global_non_fungible_resource_manager() =>
                 {
                     fields.push(address.to_instruction_argument());
-                    "MINT_NON_FUNGIBLE"
+                    Some("MINT_NON_FUNGIBLE")
+                }
+                (address, NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT)
+                    if address.is_static_global_non_fungible_resource_manager() =>
+                {
+                    fields.push(address.to_instruction_argument());
+                    Some("MINT_NON_FUNGIBLE_FROM_BLOBS")
                 }
                 (address, NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT)
                     if address.is_static_global_non_fungible_resource_manager() =>
                 {
                     fields.push(address.to_instruction_argument());
-                    "MINT_RUID_NON_FUNGIBLE"
+                    Some("MINT_RUID_NON_FUNGIBLE")
+                }
+
+                /* Component config */
+                (address, COMPONENT_CONFIG_SET_IDENT)
+                    if address.is_static_global_generic_component() =>
+                {
+                    fields.push(address.to_instruction_argument());
+                    Some("SET_COMPONENT_CONFIG")
                 }
 
                 /* Validator */
                 (address, CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT)
                     if address == &CONSENSUS_MANAGER.into() =>
                 {
-                    "CREATE_VALIDATOR"
+                    Some("CREATE_VALIDATOR")
                 }
-
-                /* Default */
-                _ => {
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(address.to_instruction_argument());
                     fields.push(to_manifest_value(method_name)?);
                     "CALL_METHOD"
@@ -486,23 +603,26 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match (address, method_name.as_str()) {
+            let alias = match (address, method_name.as_str()) {
+                _ if !context.use_aliases => None,
                 /* Component royalty */
                 (address, COMPONENT_ROYALTY_SET_ROYALTY_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "SET_COMPONENT_ROYALTY"
+                    Some("SET_COMPONENT_ROYALTY")
                 }
                 (address, COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "LOCK_COMPONENT_ROYALTY"
+                    Some("LOCK_COMPONENT_ROYALTY")
                 }
                 (address, COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "CLAIM_COMPONENT_ROYALTIES"
+                    Some("CLAIM_COMPONENT_ROYALTIES")
                 }
-
-                /* Default */
-                _ => {
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(address.to_instruction_argument());
                     fields.push(to_manifest_value(method_name)?);
                     "CALL_ROYALTY_METHOD"
@@ -524,23 +644,26 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match (address, method_name.as_str()) {
+            let alias = match (address, method_name.as_str()) {
+                _ if !context.use_aliases => None,
                 /* Metadata */
                 (address, METADATA_SET_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "SET_METADATA"
+                    Some("SET_METADATA")
                 }
                 (address, METADATA_REMOVE_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "REMOVE_METADATA"
+                    Some("REMOVE_METADATA")
                 }
                 (address, METADATA_LOCK_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "LOCK_METADATA"
+                    Some("LOCK_METADATA")
                 }
-
-                /* Default */
-                _ => {
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(address.to_instruction_argument());
                     fields.push(to_manifest_value(method_name)?);
                     "CALL_METADATA_METHOD"
@@ -562,23 +685,38 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match (address, method_name.as_str()) {
+            let alias = match (address, method_name.as_str()) {
+                _ if !context.use_aliases => None,
                 /* Access rules */
                 (address, ACCESS_RULES_SET_OWNER_ROLE_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "SET_OWNER_ROLE"
+                    Some("SET_OWNER_ROLE")
                 }
                 (address, ACCESS_RULES_LOCK_OWNER_ROLE_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "LOCK_OWNER_ROLE"
+                    Some("LOCK_OWNER_ROLE")
                 }
                 (address, ACCESS_RULES_SET_ROLE_IDENT) => {
                     fields.push(address.to_instruction_argument());
-                    "SET_ROLE"
+                    Some("SET_ROLE")
                 }
-
-                /* Default */
-                _ => {
+                (address, ACCESS_RULES_RENOUNCE_ROLE_IDENT) => {
+                    fields.push(address.to_instruction_argument());
+                    Some("RENOUNCE_ROLE")
+                }
+                (address, ACCESS_RULES_PAUSE_IDENT) => {
+                    fields.push(address.to_instruction_argument());
+                    Some("PAUSE_COMPONENT")
+                }
+                (address, ACCESS_RULES_UNPAUSE_IDENT) => {
+                    fields.push(address.to_instruction_argument());
+                    Some("UNPAUSE_COMPONENT")
+                }
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(address.to_instruction_argument());
                     fields.push(to_manifest_value(method_name)?);
                     "CALL_ACCESS_RULES_METHOD"
@@ -600,25 +738,29 @@ pub fn decompile_instruction<F: fmt::Write>(
             args,
         } => {
             let mut fields = Vec::new();
-            let name = match method_name.as_str() {
+            let alias = match method_name.as_str() {
+                _ if !context.use_aliases => None,
                 VAULT_RECALL_IDENT => {
                     fields.push(to_manifest_value(vault_id)?);
-                    "RECALL_FROM_VAULT"
+                    Some("RECALL_FROM_VAULT")
                 }
                 VAULT_FREEZE_IDENT => {
                     fields.push(to_manifest_value(vault_id)?);
-                    "FREEZE_VAULT"
+                    Some("FREEZE_VAULT")
                 }
                 VAULT_UNFREEZE_IDENT => {
                     fields.push(to_manifest_value(vault_id)?);
-                    "UNFREEZE_VAULT"
+                    Some("UNFREEZE_VAULT")
                 }
                 NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT => {
                     fields.push(to_manifest_value(vault_id)?);
-                    "RECALL_NON_FUNGIBLES_FROM_VAULT"
+                    Some("RECALL_NON_FUNGIBLES_FROM_VAULT")
                 }
-                /* Default */
-                _ => {
+                _ => None,
+            };
+            let name = match alias {
+                Some(alias) => alias,
+                None => {
                     fields.push(to_manifest_value(vault_id)?);
                     fields.push(to_manifest_value(method_name)?);
                     "CALL_DIRECT_VAULT_METHOD"
@@ -652,6 +794,10 @@ pub fn decompile_instruction<F: fmt::Write>(
                 ))?,
             )
         }
+        InstructionV1::DropAllBucketsInto { account_address } => {
+            let fields = vec![account_address.to_instruction_argument()];
+            ("DROP_ALL_BUCKETS_INTO", Value::Tuple { fields })
+        }
     };
 
     write!(f, "{}", display_name)?;
@@ -3,7 +3,7 @@ use radix_engine_interface::network::NetworkDefinition;
 use std::fs::create_dir_all;
 use std::path::{Path, PathBuf};
 
-use super::decompiler::{decompile_with_known_naming, ManifestObjectNames};
+use super::decompiler::{decompile_with_labels_and_known_naming, ManifestObjectNames};
 
 pub fn dump_manifest_to_file_system<P>(
     manifest: &TransactionManifestV1,
@@ -28,8 +28,12 @@ where
     // Decompile the transaction manifest to the manifest string and then write it to the
     // directory
     {
-        let manifest_string =
-            decompile_with_known_naming(&manifest.instructions, network_definition, naming)?;
+        let manifest_string = decompile_with_labels_and_known_naming(
+            &manifest.instructions,
+            &manifest.labels,
+            network_definition,
+            naming,
+        )?;
         let manifest_path = path.join(format!("{}.rtm", name.unwrap_or("transaction")));
         std::fs::write(manifest_path, manifest_string)?;
     }
@@ -10,6 +10,7 @@ pub mod tests {
     use radix_engine_interface::api::node_modules::ModuleConfig;
     use radix_engine_interface::blueprints::resource::RolesInit;
     use radix_engine_interface::blueprints::resource::{NonFungibleResourceRoles, OwnerRole};
+    use radix_engine_interface::constants::ACCOUNT_PACKAGE;
     use radix_engine_interface::{metadata, metadata_init};
     use scrypto_derive::NonFungibleData;
 
@@ -253,6 +254,32 @@ RECALL_FROM_VAULT
         );
     }
 
+    #[test]
+    fn test_call_direct_vault_method() {
+        compile_and_decompile_with_inversion_test(
+            "call_direct_vault_method",
+            apply_address_replacements(include_str!(
+                "../../examples/resources/call_direct_vault_method.rtm"
+            )),
+            &NetworkDefinition::simulator(),
+            vec![],
+            apply_address_replacements(
+                r##"
+RECALL_FROM_VAULT
+    Address("${vault_address}")
+    Decimal("1.2")
+;
+FREEZE_VAULT
+    Address("${vault_address}")
+    Tuple(
+        1u32
+    )
+;
+"##,
+            ),
+        );
+    }
+
     #[test]
     fn test_vault_freeze() {
         compile_and_decompile_with_inversion_test(
@@ -358,6 +385,33 @@ RECALL_NON_FUNGIBLES_FROM_VAULT
         );
     }
 
+    #[test]
+    fn test_non_fungible_local_ids_of_all_kinds() {
+        compile_and_decompile_with_inversion_test(
+            "non_fungible_local_ids_of_all_kinds",
+            apply_address_replacements(
+                r##"RECALL_NON_FUNGIBLES_FROM_VAULT Address("${vault_address}") Array<NonFungibleLocalId>(NonFungibleLocalId("#0#"), NonFungibleLocalId("#18446744073709551615#"), NonFungibleLocalId("<aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9>"), NonFungibleLocalId("[00]"), NonFungibleLocalId("[ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff]"), NonFungibleLocalId("{0000000000000000-1111111111111111-aaaaaaaaaaaaaaaa-ffffffffffffffff}"));"##,
+            ),
+            &NetworkDefinition::simulator(),
+            vec![],
+            apply_address_replacements(
+                r##"
+RECALL_NON_FUNGIBLES_FROM_VAULT
+    Address("${vault_address}")
+    Array<NonFungibleLocalId>(
+        NonFungibleLocalId("#0#"),
+        NonFungibleLocalId("#18446744073709551615#"),
+        NonFungibleLocalId("<aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9aZ_9>"),
+        NonFungibleLocalId("[00]"),
+        NonFungibleLocalId("[ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff]"),
+        NonFungibleLocalId("{0000000000000000-1111111111111111-aaaaaaaaaaaaaaaa-ffffffffffffffff}")
+    )
+;
+"##,
+            ),
+        );
+    }
+
     #[test]
     fn test_call_method() {
         compile_and_decompile_with_inversion_test(
@@ -745,6 +799,43 @@ SET_ROLE
     "hello"
     Enum<0u8>()
 ;
+RENOUNCE_ROLE
+    Address("${resource_address}")
+    Enum<0u8>()
+    "hello"
+;
+PAUSE_COMPONENT
+    Address("${resource_address}")
+    Array<String>(
+        "get_amount"
+    )
+;
+UNPAUSE_COMPONENT
+    Address("${resource_address}")
+;
+"##,
+            ),
+        );
+    }
+
+    #[test]
+    fn test_set_component_config() {
+        compile_and_decompile_with_inversion_test(
+            "set_component_config",
+            apply_address_replacements(include_str!(
+                "../../examples/component_config/set_config.rtm"
+            )),
+            &NetworkDefinition::simulator(),
+            vec![],
+            apply_address_replacements(
+                r##"
+SET_COMPONENT_CONFIG
+    Address("${component_address}")
+    Tuple(
+        Decimal("0.3"),
+        true
+    )
+;
 "##,
             ),
         );
@@ -1358,6 +1449,36 @@ CALL_METHOD
         );
     }
 
+    #[test]
+    fn test_drop_all_buckets_into() {
+        let canonical_manifest = apply_address_replacements(
+            r##"
+CALL_METHOD
+    Address("${account_address}")
+    "lock_fee"
+    Decimal("500")
+;
+CALL_METHOD
+    Address("${account_address}")
+    "withdraw"
+    Address("${fungible_resource_address}")
+    Decimal("123")
+;
+DROP_ALL_BUCKETS_INTO
+    Address("${other_account_address}")
+;
+DROP_ALL_PROOFS;
+"##,
+        );
+        compile_and_decompile_with_inversion_test(
+            "drop_all_buckets_into",
+            &canonical_manifest,
+            &NetworkDefinition::simulator(),
+            vec![],
+            &canonical_manifest,
+        );
+    }
+
     #[test]
     fn test_simple_transfer_nft_by_id() {
         // Note - this test is intended for demonstration for the ledger
@@ -1502,6 +1623,9 @@ CALL_METHOD
                 notary_public_key: sk_notary.public_key().into(),
                 notary_is_signatory: false,
                 tip_percentage: 3,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
             },
             instructions,
             blobs,
@@ -1534,6 +1658,123 @@ CALL_METHOD
         assert_eq!(manifest, inverted_manifest);
     }
 
+    #[test]
+    pub fn labels_are_preserved_through_decompilation_but_not_in_the_intent() {
+        // Arrange
+        let network = NetworkDefinition::simulator();
+        let unlabeled = apply_address_replacements(
+            r##"
+CALL_METHOD
+    Address("${account_address}")
+    "lock_fee"
+    Decimal("500")
+;
+CALL_METHOD
+    Address("${account_address}")
+    "withdraw"
+    Address("${xrd_resource_address}")
+    Decimal("10")
+;
+DROP_ALL_PROOFS;
+"##,
+        );
+        let labeled = apply_address_replacements(
+            r##"
+CALL_METHOD
+    Address("${account_address}")
+    "lock_fee"
+    Decimal("500")
+;
+@label("Payment \"to Bob\"")
+CALL_METHOD
+    Address("${account_address}")
+    "withdraw"
+    Address("${xrd_resource_address}")
+    Decimal("10")
+;
+DROP_ALL_PROOFS;
+"##,
+        );
+
+        // Act
+        let compiled = compile(&labeled, &network, BlobProvider::new()).unwrap();
+        let decompiled = decompile_manifest(&compiled, &network).unwrap();
+        let recompiled = compile(&decompiled, &network, BlobProvider::new()).unwrap();
+
+        // Assert
+        assert_eq!(
+            compiled.labels,
+            BTreeMap::from([(1, "Payment \"to Bob\"".to_string())])
+        );
+        assert_eq!(decompiled.trim(), labeled.trim());
+        assert_eq!(recompiled, compiled);
+        assert_eq!(
+            compiled.for_intent(),
+            compile(&unlabeled, &network, BlobProvider::new())
+                .unwrap()
+                .for_intent()
+        );
+    }
+
+    #[test]
+    pub fn instruction_cannot_have_two_labels() {
+        let result = compile(
+            r#"@label("one") @label("two") DROP_ALL_PROOFS;"#,
+            &NetworkDefinition::simulator(),
+            BlobProvider::new(),
+        );
+
+        assert_eq!(
+            result,
+            Err(CompileError::ParserError(
+                parser::ParserError::DuplicateAnnotation("label".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    pub fn aliases_can_be_left_out_of_decompiled_manifest() {
+        // Arrange
+        let network = NetworkDefinition::simulator();
+        let aliased = apply_address_replacements(
+            r##"
+CREATE_ACCOUNT;
+LOCK_OWNER_ROLE
+    Address("${resource_address}")
+;
+"##,
+        );
+        let account_package_address =
+            ACCOUNT_PACKAGE.to_string(&AddressBech32Encoder::for_simulator());
+        let unaliased = apply_address_replacements(format!(
+            r##"
+CALL_FUNCTION
+    Address("{account_package_address}")
+    "Account"
+    "create"
+;
+CALL_ACCESS_RULES_METHOD
+    Address("${{resource_address}}")
+    "lock_owner_role"
+;
+"##
+        ));
+        let compiled = compile(&aliased, &network, BlobProvider::new()).unwrap();
+
+        // Act
+        let with_aliases = decompile_with_aliases(&compiled.instructions, &network, true).unwrap();
+        let without_aliases =
+            decompile_with_aliases(&compiled.instructions, &network, false).unwrap();
+
+        // Assert
+        assert_eq!(with_aliases.trim(), aliased.trim());
+        assert_eq!(without_aliases.trim(), unaliased.trim());
+        assert_eq!(
+            compile(&without_aliases, &network, BlobProvider::new()).unwrap(),
+            compiled
+        );
+    }
+
     #[derive(ScryptoSbor, NonFungibleData, ManifestSbor)]
     struct EmptyStruct {}
 }
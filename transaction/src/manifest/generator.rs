@@ -11,8 +11,8 @@ use radix_engine_common::types::NodeId;
 use radix_engine_common::types::PackageAddress;
 use radix_engine_interface::address::AddressBech32Decoder;
 use radix_engine_interface::api::node_modules::auth::{
-    ACCESS_RULES_LOCK_OWNER_ROLE_IDENT, ACCESS_RULES_SET_OWNER_ROLE_IDENT,
-    ACCESS_RULES_SET_ROLE_IDENT,
+    ACCESS_RULES_LOCK_OWNER_ROLE_IDENT, ACCESS_RULES_PAUSE_IDENT, ACCESS_RULES_RENOUNCE_ROLE_IDENT,
+    ACCESS_RULES_SET_OWNER_ROLE_IDENT, ACCESS_RULES_SET_ROLE_IDENT, ACCESS_RULES_UNPAUSE_IDENT,
 };
 use radix_engine_interface::api::node_modules::metadata::METADATA_SET_IDENT;
 use radix_engine_interface::api::node_modules::metadata::{
@@ -28,6 +28,7 @@ use radix_engine_interface::blueprints::access_controller::{
 use radix_engine_interface::blueprints::account::{
     ACCOUNT_BLUEPRINT, ACCOUNT_CREATE_ADVANCED_IDENT, ACCOUNT_CREATE_IDENT,
 };
+use radix_engine_interface::blueprints::component_config::COMPONENT_CONFIG_SET_IDENT;
 use radix_engine_interface::blueprints::consensus_manager::CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT;
 use radix_engine_interface::blueprints::identity::{
     IDENTITY_BLUEPRINT, IDENTITY_CREATE_ADVANCED_IDENT, IDENTITY_CREATE_IDENT,
@@ -86,7 +87,13 @@ pub enum GeneratorError {
     InvalidHash(String),
     InvalidNodeId(String),
     InvalidVaultId(String),
-    InvalidNonFungibleLocalId(String),
+    /// The span is within the id string, in Unicode code points with an exclusive end.
+    InvalidNonFungibleLocalId {
+        value: String,
+        error: ParseNonFungibleLocalIdError,
+        start: usize,
+        end: usize,
+    },
     InvalidNonFungibleGlobalId,
     InvalidExpression(String),
     InvalidComponent(String),
@@ -223,7 +230,7 @@ impl NameResolver {
 }
 
 pub fn generate_manifest<B>(
-    instructions: &[ast::Instruction],
+    instructions: &[ast::AnnotatedInstruction],
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: B,
 ) -> Result<TransactionManifestV1, GeneratorError>
@@ -233,10 +240,18 @@ where
     let mut id_validator = ManifestValidator::new();
     let mut name_resolver = NameResolver::new();
     let mut output = Vec::new();
+    let mut labels = BTreeMap::new();
 
-    for instruction in instructions {
+    for (index, instruction) in instructions.iter().enumerate() {
+        for annotation in &instruction.annotations {
+            match annotation {
+                ast::Annotation::Label(label) => {
+                    labels.insert(index, label.clone());
+                }
+            }
+        }
         output.push(generate_instruction(
-            instruction,
+            &instruction.instruction,
             &mut id_validator,
             &mut name_resolver,
             address_bech32_decoder,
@@ -247,6 +262,7 @@ where
     Ok(TransactionManifestV1 {
         instructions: output,
         blobs: blobs.blobs(),
+        labels,
     })
 }
 
@@ -336,6 +352,21 @@ where
                 )?,
             }
         }
+        ast::Instruction::AssertWorktopContainsExactly {
+            resource_address,
+            amount,
+        } => InstructionV1::AssertWorktopContainsExactly {
+            amount: generate_decimal(amount)?,
+            resource_address: generate_resource_address(resource_address, address_bech32_decoder)?,
+        },
+        ast::Instruction::AssertWorktopResourcesOnly { resource_addresses } => {
+            InstructionV1::AssertWorktopResourcesOnly {
+                resource_addresses: generate_resource_addresses(
+                    resource_addresses,
+                    address_bech32_decoder,
+                )?,
+            }
+        }
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
@@ -595,6 +626,33 @@ where
             }
         }
 
+        ast::Instruction::DropAllBucketsInto { account_address } => {
+            InstructionV1::DropAllBucketsInto {
+                account_address: generate_dynamic_global_address(
+                    account_address,
+                    address_bech32_decoder,
+                    resolver,
+                )?,
+            }
+        }
+        ast::Instruction::CallDirectVaultMethod {
+            address,
+            method_name,
+            args,
+        } => {
+            let address = generate_local_address(address, address_bech32_decoder)?;
+            let method_name = generate_string(&method_name)?;
+            let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
+            id_validator
+                .process_call_data(&args)
+                .map_err(GeneratorError::IdValidationError)?;
+            InstructionV1::CallDirectVaultMethod {
+                address,
+                method_name,
+                args,
+            }
+        }
+
         /* direct vault method aliases */
         ast::Instruction::RecallFromVault { vault_id, args } => {
             InstructionV1::CallDirectVaultMethod {
@@ -761,6 +819,33 @@ where
             method_name: ACCESS_RULES_SET_ROLE_IDENT.to_string(),
             args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
         },
+        ast::Instruction::RenounceRole { address, args } => InstructionV1::CallAccessRulesMethod {
+            address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
+            method_name: ACCESS_RULES_RENOUNCE_ROLE_IDENT.to_string(),
+            args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+        },
+        ast::Instruction::PauseComponent { address, args } => {
+            InstructionV1::CallAccessRulesMethod {
+                address: generate_dynamic_global_address(
+                    address,
+                    address_bech32_decoder,
+                    resolver,
+                )?,
+                method_name: ACCESS_RULES_PAUSE_IDENT.to_string(),
+                args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+            }
+        }
+        ast::Instruction::UnpauseComponent { address, args } => {
+            InstructionV1::CallAccessRulesMethod {
+                address: generate_dynamic_global_address(
+                    address,
+                    address_bech32_decoder,
+                    resolver,
+                )?,
+                method_name: ACCESS_RULES_UNPAUSE_IDENT.to_string(),
+                args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+            }
+        }
 
         /* call main method aliases */
         ast::Instruction::MintFungible { address, args } => InstructionV1::CallMethod {
@@ -773,11 +858,21 @@ where
             method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
             args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
         },
+        ast::Instruction::MintNonFungibleFromBlobs { address, args } => InstructionV1::CallMethod {
+            address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT.to_string(),
+            args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+        },
         ast::Instruction::MintRuidNonFungible { address, args } => InstructionV1::CallMethod {
             address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
             method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT.to_string(),
             args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
         },
+        ast::Instruction::SetComponentConfig { address, args } => InstructionV1::CallMethod {
+            address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
+            method_name: COMPONENT_CONFIG_SET_IDENT.to_string(),
+            args: generate_args(args, resolver, address_bech32_decoder, blobs)?,
+        },
         ast::Instruction::ClaimPackageRoyalties { address, args } => InstructionV1::CallMethod {
             address: generate_dynamic_global_address(address, address_bech32_decoder, resolver)?,
             method_name: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
@@ -895,6 +990,28 @@ fn generate_resource_address(
     }
 }
 
+fn generate_resource_addresses(
+    value: &ast::Value,
+    address_bech32_decoder: &AddressBech32Decoder,
+) -> Result<Vec<ResourceAddress>, GeneratorError> {
+    match value {
+        ast::Value::Array(kind, values) => {
+            if kind != &ast::ValueKind::Address {
+                return Err(GeneratorError::InvalidAstType {
+                    expected_type: ast::ValueKind::Address,
+                    actual: kind.clone(),
+                });
+            }
+
+            values
+                .iter()
+                .map(|v| generate_resource_address(v, address_bech32_decoder))
+                .collect()
+        }
+        v => invalid_type!(v, ast::ValueKind::Array),
+    }
+}
+
 fn generate_dynamic_global_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
@@ -1153,11 +1270,18 @@ fn generate_non_fungible_local_id(
     value: &ast::Value,
 ) -> Result<NonFungibleLocalId, GeneratorError> {
     match value {
-        ast::Value::NonFungibleLocalId(inner) => match inner.as_ref() {
-            ast::Value::String(s) => NonFungibleLocalId::from_str(s.as_str())
-                .map_err(|_| GeneratorError::InvalidNonFungibleLocalId(s.clone())),
-            v => invalid_type!(v, ast::ValueKind::String)?,
-        },
+        ast::Value::NonFungibleLocalId(inner) => {
+            match inner.as_ref() {
+                ast::Value::String(s) => NonFungibleLocalId::parse_with_error_span(s.as_str())
+                    .map_err(|(error, span)| GeneratorError::InvalidNonFungibleLocalId {
+                        value: s.clone(),
+                        error,
+                        start: span.start,
+                        end: span.end,
+                    }),
+                v => invalid_type!(v, ast::ValueKind::String)?,
+            }
+        }
         v => invalid_type!(v, ast::ValueKind::NonFungibleLocalId),
     }
 }
@@ -1639,6 +1763,34 @@ mod tests {
             r#"Decimal("invalid_decimal")"#,
             GeneratorError::InvalidDecimal("invalid_decimal".into())
         );
+        generate_value_error!(
+            r##"NonFungibleLocalId("#12x4#")"##,
+            GeneratorError::InvalidNonFungibleLocalId {
+                value: "#12x4#".into(),
+                error: ParseNonFungibleLocalIdError::InvalidInteger,
+                start: 3,
+                end: 4,
+            }
+        );
+        generate_value_error!(
+            r##"NonFungibleLocalId("{1111111111111111-11111111111111111111111111111111111111111111111111}")"##,
+            GeneratorError::InvalidNonFungibleLocalId {
+                value: "{1111111111111111-11111111111111111111111111111111111111111111111111}"
+                    .into(),
+                error: ParseNonFungibleLocalIdError::InvalidRUID,
+                start: 34,
+                end: 35,
+            }
+        );
+        generate_value_error!(
+            r##"NonFungibleLocalId("#")"##,
+            GeneratorError::InvalidNonFungibleLocalId {
+                value: "#".into(),
+                error: ParseNonFungibleLocalIdError::UnknownType,
+                start: 0,
+                end: 1,
+            }
+        );
     }
 
     #[test]
@@ -1678,6 +1830,19 @@ mod tests {
                 resource_address,
             },
         );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_EXACTLY  Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez")  Decimal("0");"#,
+            InstructionV1::AssertWorktopContainsExactly {
+                amount: Decimal::zero(),
+                resource_address,
+            },
+        );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_RESOURCES_ONLY  Array<Address>(Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez"));"#,
+            InstructionV1::AssertWorktopResourcesOnly {
+                resource_addresses: vec![resource_address],
+            },
+        );
         generate_instruction_ok!(
             r#"CALL_FUNCTION  Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk")  "Airdrop"  "new"  500u32  PreciseDecimal("120");"#,
             InstructionV1::CallFunction {
@@ -2026,6 +2191,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mint_non_fungible_from_blobs_instruction() {
+        let address_bech32_decoder = AddressBech32Decoder::new(&NetworkDefinition::simulator());
+        let resource_address = ResourceAddress::try_from_bech32(
+            &address_bech32_decoder,
+            "resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez",
+        )
+        .unwrap();
+
+        generate_instruction_ok!(
+            r##"
+            MINT_NON_FUNGIBLE_FROM_BLOBS
+                Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez")
+                Map<NonFungibleLocalId, Blob>(NonFungibleLocalId("#1#") => Blob("a710f0959d8e139b3c1ca74ac4fcb9a95ada2c82e7f563304c5487e0117095c0"));
+            "##,
+            InstructionV1::CallMethod {
+                address: resource_address.into(),
+                method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_FROM_BLOBS_IDENT.to_string(),
+                args: to_manifest_value_and_unwrap!(
+                    &NonFungibleResourceManagerMintFromBlobsManifestInput {
+                        entries: BTreeMap::from([(
+                            NonFungibleLocalId::integer(1),
+                            ManifestBlobRef(
+                                hex::decode(
+                                    "a710f0959d8e139b3c1ca74ac4fcb9a95ada2c82e7f563304c5487e0117095c0"
+                                )
+                                .unwrap()
+                                .try_into()
+                                .unwrap()
+                            )
+                        )])
+                    }
+                )
+            },
+        );
+    }
+
     #[test]
     fn test_mint_ruid_non_fungible_instruction() {
         let address_bech32_decoder = AddressBech32Decoder::new(&NetworkDefinition::simulator());
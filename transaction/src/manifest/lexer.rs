@@ -48,6 +48,7 @@ pub enum TokenKind {
     Comma,
     Semicolon,
     FatArrow,
+    At,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -154,7 +155,7 @@ impl Lexer {
             '-' | '0'..='9' => self.tokenize_number(),
             '"' => self.tokenize_string(),
             'a'..='z' | 'A'..='Z' => self.tokenize_identifier(),
-            '{' | '}' | '(' | ')' | '<' | '>' | ',' | ';' | '&' | '=' => {
+            '{' | '}' | '(' | ')' | '<' | '>' | ',' | ';' | '&' | '=' | '@' => {
                 self.tokenize_punctuation()
             }
             _ => Err(LexerError::UnexpectedChar(
@@ -341,6 +342,7 @@ impl Lexer {
             '>' => TokenKind::GreaterThan,
             ',' => TokenKind::Comma,
             ';' => TokenKind::Semicolon,
+            '@' => TokenKind::At,
             '=' => match self.advance()? {
                 '>' => TokenKind::FatArrow,
                 _ => return Err(self.unexpected_char()),
@@ -485,6 +487,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_annotation() {
+        lex_ok!(
+            r#"@label("payment") DROP_ALL_PROOFS;"#,
+            vec![
+                TokenKind::At,
+                TokenKind::Ident("label".to_string()),
+                TokenKind::OpenParenthesis,
+                TokenKind::StringLiteral("payment".into()),
+                TokenKind::CloseParenthesis,
+                TokenKind::Ident("DROP_ALL_PROOFS".to_string()),
+                TokenKind::Semicolon,
+            ]
+        );
+    }
+
     #[test]
     fn test_precise_decimal() {
         lex_ok!(
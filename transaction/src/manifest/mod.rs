@@ -1,3 +1,4 @@
+pub mod analysis;
 pub mod ast;
 pub mod blob_provider;
 pub mod compiler;
@@ -9,8 +10,11 @@ pub mod enums;
 pub mod generator;
 pub mod lexer;
 pub mod parser;
+pub mod resource_flow;
 
+pub use analysis::*;
 pub use blob_provider::*;
 pub use compiler::{compile, CompileError};
-pub use decompiler::{decompile, DecompileError};
+pub use decompiler::{decompile, decompile_manifest, decompile_with_aliases, DecompileError};
 pub use enums::*;
+pub use resource_flow::*;
@@ -1,4 +1,4 @@
-use crate::manifest::ast::{Instruction, Value, ValueKind};
+use crate::manifest::ast::{AnnotatedInstruction, Annotation, Instruction, Value, ValueKind};
 use crate::manifest::enums::KNOWN_ENUM_DISCRIMINATORS;
 use crate::manifest::lexer::{Token, TokenKind};
 use radix_engine_interface::data::manifest::MANIFEST_SBOR_V1_MAX_DEPTH;
@@ -16,11 +16,14 @@ pub enum ParserError {
     InvalidHex(String),
     UnknownEnumDiscriminator(String),
     MaxDepthExceeded(usize),
+    UnknownAnnotation(String),
+    DuplicateAnnotation(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TokenType {
     Instruction,
+    Annotation,
     Value,
     ValueKind,
     EnumDiscriminator,
@@ -38,6 +41,8 @@ pub enum InstructionIdent {
     AssertWorktopContains,
     AssertWorktopContainsNonFungibles,
     AssertWorktopContainsAny,
+    AssertWorktopContainsExactly,
+    AssertWorktopResourcesOnly,
 
     PopFromAuthZone,
     PushToAuthZone,
@@ -59,6 +64,8 @@ pub enum InstructionIdent {
     CallAccessRulesMethod,
     DropAllProofs,
     AllocateGlobalAddress,
+    DropAllBucketsInto,
+    CallDirectVaultMethod,
 
     // ==============
     // Call direct vault method aliases
@@ -95,6 +102,9 @@ pub enum InstructionIdent {
     SetOwnerRole,
     LockOwnerRole,
     SetRole,
+    RenounceRole,
+    PauseComponent,
+    UnpauseComponent,
 
     // ==============
     // Call main-method aliases
@@ -102,7 +112,9 @@ pub enum InstructionIdent {
     ClaimPackageRoyalties,
     MintFungible,
     MintNonFungible,
+    MintNonFungibleFromBlobs,
     MintRuidNonFungible,
+    SetComponentConfig,
     CreateValidator,
 }
 
@@ -121,6 +133,8 @@ impl InstructionIdent {
                 InstructionIdent::AssertWorktopContainsNonFungibles
             }
             "ASSERT_WORKTOP_CONTAINS_ANY" => InstructionIdent::AssertWorktopContainsAny,
+            "ASSERT_WORKTOP_CONTAINS_EXACTLY" => InstructionIdent::AssertWorktopContainsExactly,
+            "ASSERT_WORKTOP_RESOURCES_ONLY" => InstructionIdent::AssertWorktopResourcesOnly,
 
             "POP_FROM_AUTH_ZONE" => InstructionIdent::PopFromAuthZone,
             "PUSH_TO_AUTH_ZONE" => InstructionIdent::PushToAuthZone,
@@ -152,6 +166,8 @@ impl InstructionIdent {
 
             "DROP_ALL_PROOFS" => InstructionIdent::DropAllProofs,
             "ALLOCATE_GLOBAL_ADDRESS" => InstructionIdent::AllocateGlobalAddress,
+            "DROP_ALL_BUCKETS_INTO" => InstructionIdent::DropAllBucketsInto,
+            "CALL_DIRECT_VAULT_METHOD" => InstructionIdent::CallDirectVaultMethod,
 
             // ==============
             // Call direct vault method aliases
@@ -192,14 +208,19 @@ impl InstructionIdent {
             "SET_OWNER_ROLE" => InstructionIdent::SetOwnerRole,
             "LOCK_OWNER_ROLE" => InstructionIdent::LockOwnerRole,
             "SET_ROLE" => InstructionIdent::SetRole,
+            "RENOUNCE_ROLE" => InstructionIdent::RenounceRole,
+            "PAUSE_COMPONENT" => InstructionIdent::PauseComponent,
+            "UNPAUSE_COMPONENT" => InstructionIdent::UnpauseComponent,
 
             // ==============
             // Call main-method aliases
             // ==============
             "MINT_FUNGIBLE" => InstructionIdent::MintFungible,
             "MINT_NON_FUNGIBLE" => InstructionIdent::MintNonFungible,
+            "MINT_NON_FUNGIBLE_FROM_BLOBS" => InstructionIdent::MintNonFungibleFromBlobs,
             "MINT_RUID_NON_FUNGIBLE" => InstructionIdent::MintRuidNonFungible,
             "CLAIM_PACKAGE_ROYALTIES" => InstructionIdent::ClaimPackageRoyalties,
+            "SET_COMPONENT_CONFIG" => InstructionIdent::SetComponentConfig,
             "CREATE_VALIDATOR" => InstructionIdent::CreateValidator,
             _ => {
                 return None;
@@ -445,16 +466,65 @@ impl Parser {
         Ok(token)
     }
 
-    pub fn parse_manifest(&mut self) -> Result<Vec<Instruction>, ParserError> {
-        let mut instructions = Vec::<Instruction>::new();
+    pub fn parse_manifest(&mut self) -> Result<Vec<AnnotatedInstruction>, ParserError> {
+        let mut instructions = Vec::<AnnotatedInstruction>::new();
 
         while !self.is_eof() {
-            instructions.push(self.parse_instruction()?);
+            let annotations = self.parse_annotations()?;
+            instructions.push(AnnotatedInstruction {
+                annotations,
+                instruction: self.parse_instruction()?,
+            });
         }
 
         Ok(instructions)
     }
 
+    /// Parses the annotations preceding an instruction, eg `@label("payment")`.
+    pub fn parse_annotations(&mut self) -> Result<Vec<Annotation>, ParserError> {
+        let mut annotations = Vec::<Annotation>::new();
+
+        while self.peek()?.kind == TokenKind::At {
+            self.advance()?;
+            let token = self.advance()?;
+            let annotation = match &token.kind {
+                TokenKind::Ident(name) if name == "label" => {
+                    if annotations
+                        .iter()
+                        .any(|annotation| matches!(annotation, Annotation::Label(..)))
+                    {
+                        return Err(ParserError::DuplicateAnnotation(name.clone()));
+                    }
+                    advance_match!(self, TokenKind::OpenParenthesis);
+                    let token = self.advance()?;
+                    let label = match token.kind {
+                        TokenKind::StringLiteral(label) => label,
+                        _ => {
+                            return Err(ParserError::UnexpectedToken {
+                                expected: TokenType::Value,
+                                actual: token,
+                            })
+                        }
+                    };
+                    advance_match!(self, TokenKind::CloseParenthesis);
+                    Annotation::Label(label)
+                }
+                TokenKind::Ident(name) => {
+                    return Err(ParserError::UnknownAnnotation(name.clone()));
+                }
+                _ => {
+                    return Err(ParserError::UnexpectedToken {
+                        expected: TokenType::Annotation,
+                        actual: token,
+                    });
+                }
+            };
+            annotations.push(annotation);
+        }
+
+        Ok(annotations)
+    }
+
     fn parse_values_till_semicolon(&mut self) -> Result<Vec<Value>, ParserError> {
         let mut values = Vec::new();
         while self.peek()?.kind != TokenKind::Semicolon {
@@ -512,6 +582,17 @@ impl Parser {
             InstructionIdent::AssertWorktopContainsAny => Instruction::AssertWorktopContainsAny {
                 resource_address: self.parse_value()?,
             },
+            InstructionIdent::AssertWorktopContainsExactly => {
+                Instruction::AssertWorktopContainsExactly {
+                    resource_address: self.parse_value()?,
+                    amount: self.parse_value()?,
+                }
+            }
+            InstructionIdent::AssertWorktopResourcesOnly => {
+                Instruction::AssertWorktopResourcesOnly {
+                    resource_addresses: self.parse_value()?,
+                }
+            }
             InstructionIdent::PopFromAuthZone => Instruction::PopFromAuthZone {
                 new_proof: self.parse_value()?,
             },
@@ -605,6 +686,14 @@ impl Parser {
                 address_reservation: self.parse_value()?,
                 named_address: self.parse_value()?,
             },
+            InstructionIdent::DropAllBucketsInto => Instruction::DropAllBucketsInto {
+                account_address: self.parse_value()?,
+            },
+            InstructionIdent::CallDirectVaultMethod => Instruction::CallDirectVaultMethod {
+                address: self.parse_value()?,
+                method_name: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
 
             /* Call direct vault method aliases */
             InstructionIdent::RecallFromVault => Instruction::RecallFromVault {
@@ -702,6 +791,18 @@ impl Parser {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
             },
+            InstructionIdent::RenounceRole => Instruction::RenounceRole {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
+            InstructionIdent::PauseComponent => Instruction::PauseComponent {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
+            InstructionIdent::UnpauseComponent => Instruction::UnpauseComponent {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
 
             /* Call main method aliases */
             InstructionIdent::MintFungible => Instruction::MintFungible {
@@ -712,10 +813,18 @@ impl Parser {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
             },
+            InstructionIdent::MintNonFungibleFromBlobs => Instruction::MintNonFungibleFromBlobs {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
             InstructionIdent::MintRuidNonFungible => Instruction::MintRuidNonFungible {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
             },
+            InstructionIdent::SetComponentConfig => Instruction::SetComponentConfig {
+                address: self.parse_value()?,
+                args: self.parse_values_till_semicolon()?,
+            },
             InstructionIdent::ClaimPackageRoyalties => Instruction::ClaimPackageRoyalties {
                 address: self.parse_value()?,
                 args: self.parse_values_till_semicolon()?,
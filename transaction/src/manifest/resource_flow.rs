@@ -0,0 +1,708 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::address::AddressBech32Encoder;
+use radix_engine_interface::api::node_modules::royalty::COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT;
+use radix_engine_interface::blueprints::account::*;
+use sbor::rust::fmt::Write;
+
+/// A participant of the resource flows of a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceFlowNode {
+    Worktop,
+    Bucket(ManifestBucket),
+    Account(GlobalAddress),
+    /// Any other global entity called by the manifest, including packages and addresses
+    /// allocated by the manifest itself.
+    Component(DynamicGlobalAddress),
+    Vault(InternalAddress),
+    Burned,
+}
+
+/// How much resource flows along an edge, as far as can be told without executing the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceFlowAmount {
+    Amount(Decimal),
+    NonFungibles(Vec<NonFungibleLocalId>),
+    /// All of the resource present at the source.
+    All,
+    /// The amount depends on the execution, e.g. the return value of a blueprint method.
+    Unknown,
+}
+
+/// A flow of resource between two nodes, caused by an instruction of the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceFlowEdge {
+    pub from: ResourceFlowNode,
+    pub to: ResourceFlowNode,
+    pub instruction_index: usize,
+    /// The resource moved, or `None` for flows of several (or statically unknown) resources.
+    pub resource_address: Option<ResourceAddress>,
+    pub amount: ResourceFlowAmount,
+}
+
+/// The graph of resource flows between the worktop, named buckets and the entities called by a
+/// manifest, extracted statically from its instructions.
+///
+/// Resource returned by calls to blueprints is generally unknown, and shows up as an
+/// [`ResourceFlowAmount::Unknown`] flow from the callee to the worktop, except for the account
+/// methods whose outputs are determined by their arguments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceFlowGraph {
+    pub nodes: Vec<ResourceFlowNode>,
+    pub edges: Vec<ResourceFlowEdge>,
+}
+
+impl ResourceFlowGraph {
+    pub fn from_manifest(manifest: &TransactionManifestV1) -> Self {
+        Self::from_instructions(&manifest.instructions)
+    }
+
+    pub fn from_instructions(instructions: &[InstructionV1]) -> Self {
+        let mut extractor = ResourceFlowExtractor {
+            graph: Self::default(),
+            bucket_contents: index_map_new(),
+            id_allocator: ManifestIdAllocator::new(),
+        };
+        extractor.add_node(ResourceFlowNode::Worktop);
+        for (instruction_index, instruction) in instructions.iter().enumerate() {
+            extractor.visit(instruction_index, instruction);
+        }
+        extractor.graph
+    }
+
+    /// Returns the flows into the given node.
+    pub fn flows_into(&self, node: &ResourceFlowNode) -> Vec<&ResourceFlowEdge> {
+        self.edges.iter().filter(|edge| edge.to.eq(node)).collect()
+    }
+
+    /// Returns the flows out of the given node.
+    pub fn flows_out_of(&self, node: &ResourceFlowNode) -> Vec<&ResourceFlowEdge> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.from.eq(node))
+            .collect()
+    }
+
+    /// Renders the graph in the Graphviz DOT format.
+    pub fn to_dot(&self, address_bech32_encoder: &AddressBech32Encoder) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph resource_flow {{").unwrap();
+        for node in &self.nodes {
+            writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\\n{}\"];",
+                node_id(address_bech32_encoder, node),
+                node_kind(node),
+                node_id(address_bech32_encoder, node)
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"#{}: {} of {}\"];",
+                node_id(address_bech32_encoder, &edge.from),
+                node_id(address_bech32_encoder, &edge.to),
+                edge.instruction_index,
+                format_amount(&edge.amount),
+                format_resource(address_bech32_encoder, &edge.resource_address)
+            )
+            .unwrap();
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    /// Renders the graph as a JSON object with `nodes` and `edges` arrays.
+    pub fn to_json(&self, address_bech32_encoder: &AddressBech32Encoder) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                format!(
+                    "{{\"id\":\"{}\",\"kind\":\"{}\"}}",
+                    node_id(address_bech32_encoder, node),
+                    node_kind(node)
+                )
+            })
+            .collect();
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let resource_address = match &edge.resource_address {
+                    Some(_) => format!(
+                        "\"{}\"",
+                        format_resource(address_bech32_encoder, &edge.resource_address)
+                    ),
+                    None => "null".to_string(),
+                };
+                let amount = match &edge.amount {
+                    ResourceFlowAmount::Amount(amount) => {
+                        format!("{{\"kind\":\"amount\",\"value\":\"{}\"}}", amount)
+                    }
+                    ResourceFlowAmount::NonFungibles(ids) => format!(
+                        "{{\"kind\":\"non_fungibles\",\"ids\":[{}]}}",
+                        ids.iter()
+                            .map(|id| format!("\"{}\"", id))
+                            .collect::<Vec<String>>()
+                            .join(",")
+                    ),
+                    ResourceFlowAmount::All => "{\"kind\":\"all\"}".to_string(),
+                    ResourceFlowAmount::Unknown => "{\"kind\":\"unknown\"}".to_string(),
+                };
+                format!(
+                    "{{\"from\":\"{}\",\"to\":\"{}\",\"instruction_index\":{},\"resource_address\":{},\"amount\":{}}}",
+                    node_id(address_bech32_encoder, &edge.from),
+                    node_id(address_bech32_encoder, &edge.to),
+                    edge.instruction_index,
+                    resource_address,
+                    amount
+                )
+            })
+            .collect();
+        format!(
+            "{{\"nodes\":[{}],\"edges\":[{}]}}",
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+}
+
+struct ResourceFlowExtractor {
+    graph: ResourceFlowGraph,
+    /// The resource and amount taken into each bucket which is still alive.
+    bucket_contents: IndexMap<ManifestBucket, (ResourceAddress, ResourceFlowAmount)>,
+    id_allocator: ManifestIdAllocator,
+}
+
+impl ResourceFlowExtractor {
+    fn visit(&mut self, instruction_index: usize, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => self.take(
+                instruction_index,
+                *resource_address,
+                ResourceFlowAmount::All,
+            ),
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => self.take(
+                instruction_index,
+                *resource_address,
+                ResourceFlowAmount::Amount(*amount),
+            ),
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => self.take(
+                instruction_index,
+                *resource_address,
+                ResourceFlowAmount::NonFungibles(ids.clone()),
+            ),
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                self.move_bucket(instruction_index, *bucket_id, ResourceFlowNode::Worktop)
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                self.move_bucket(instruction_index, *bucket_id, ResourceFlowNode::Burned)
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                function_name: _,
+                blueprint_name: _,
+                args,
+            } => {
+                let callee = ResourceFlowNode::Component(match package_address {
+                    DynamicPackageAddress::Static(address) => {
+                        DynamicGlobalAddress::Static((*address).into())
+                    }
+                    DynamicPackageAddress::Named(id) => DynamicGlobalAddress::Named(*id),
+                });
+                self.pass_arguments(instruction_index, &callee, args);
+                self.add_edge(
+                    instruction_index,
+                    callee,
+                    ResourceFlowNode::Worktop,
+                    None,
+                    ResourceFlowAmount::Unknown,
+                );
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => self.call_method(instruction_index, address, method_name, args),
+            InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let callee = global_node(address);
+                self.pass_arguments(instruction_index, &callee, args);
+                if method_name.eq(COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT) {
+                    self.add_edge(
+                        instruction_index,
+                        callee,
+                        ResourceFlowNode::Worktop,
+                        Some(XRD),
+                        ResourceFlowAmount::Unknown,
+                    );
+                }
+            }
+            InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallAccessRulesMethod { address, args, .. } => {
+                self.pass_arguments(instruction_index, &global_node(address), args)
+            }
+            InstructionV1::CallDirectVaultMethod { address, args, .. } => {
+                let callee = ResourceFlowNode::Vault(*address);
+                self.pass_arguments(instruction_index, &callee, args);
+                self.add_edge(
+                    instruction_index,
+                    callee,
+                    ResourceFlowNode::Worktop,
+                    None,
+                    ResourceFlowAmount::Unknown,
+                );
+            }
+            InstructionV1::DropAllBucketsInto { account_address } => self.add_edge(
+                instruction_index,
+                ResourceFlowNode::Worktop,
+                global_node(account_address),
+                None,
+                ResourceFlowAmount::All,
+            ),
+            InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::AssertWorktopContainsExactly { .. }
+            | InstructionV1::AssertWorktopResourcesOnly { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::ClearAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::ClearSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropAllProofs
+            | InstructionV1::AllocateGlobalAddress { .. } => {}
+        }
+    }
+
+    fn call_method(
+        &mut self,
+        instruction_index: usize,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let callee = global_node(address);
+        self.pass_arguments(instruction_index, &callee, args);
+
+        if !matches!(callee, ResourceFlowNode::Account(_)) {
+            self.add_edge(
+                instruction_index,
+                callee,
+                ResourceFlowNode::Worktop,
+                None,
+                ResourceFlowAmount::Unknown,
+            );
+            return;
+        }
+
+        let encoded = manifest_encode(args).unwrap();
+        let withdrawn =
+            match method_name {
+                ACCOUNT_WITHDRAW_IDENT => manifest_decode::<AccountWithdrawInput>(&encoded)
+                    .ok()
+                    .map(|input| {
+                        (
+                            Some(input.resource_address),
+                            ResourceFlowAmount::Amount(input.amount),
+                        )
+                    }),
+                ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                    manifest_decode::<AccountWithdrawNonFungiblesInput>(&encoded)
+                        .ok()
+                        .map(|input| {
+                            (
+                                Some(input.resource_address),
+                                ResourceFlowAmount::NonFungibles(input.ids.into_iter().collect()),
+                            )
+                        })
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                    manifest_decode::<AccountLockFeeAndWithdrawInput>(&encoded)
+                        .ok()
+                        .map(|input| {
+                            (
+                                Some(input.resource_address),
+                                ResourceFlowAmount::Amount(input.amount),
+                            )
+                        })
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                    manifest_decode::<AccountLockFeeAndWithdrawNonFungiblesInput>(&encoded)
+                        .ok()
+                        .map(|input| {
+                            (
+                                Some(input.resource_address),
+                                ResourceFlowAmount::NonFungibles(input.ids.into_iter().collect()),
+                            )
+                        })
+                }
+                // These return nothing, or resource already accounted for by their arguments
+                ACCOUNT_LOCK_FEE_IDENT
+                | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+                | ACCOUNT_DEPOSIT_IDENT
+                | ACCOUNT_DEPOSIT_BATCH_IDENT
+                | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                | ACCOUNT_BURN_IDENT
+                | ACCOUNT_BURN_NON_FUNGIBLES_IDENT
+                | ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                | ACCOUNT_CREATE_PROOFS_IDENT
+                | ACCOUNT_CHANGE_DEFAULT_DEPOSIT_RULE_IDENT
+                | ACCOUNT_CONFIGURE_RESOURCE_DEPOSIT_RULE_IDENT
+                | ACCOUNT_ADD_AUTHORIZED_DEPOSITOR_IDENT
+                | ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR_IDENT
+                | ACCOUNT_SECURIFY_IDENT => return,
+                _ => None,
+            }
+            .unwrap_or((None, ResourceFlowAmount::Unknown));
+
+        self.add_edge(
+            instruction_index,
+            callee,
+            ResourceFlowNode::Worktop,
+            withdrawn.0,
+            withdrawn.1,
+        );
+    }
+
+    fn take(
+        &mut self,
+        instruction_index: usize,
+        resource_address: ResourceAddress,
+        amount: ResourceFlowAmount,
+    ) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.bucket_contents
+            .insert(bucket_id, (resource_address, amount.clone()));
+        self.add_edge(
+            instruction_index,
+            ResourceFlowNode::Worktop,
+            ResourceFlowNode::Bucket(bucket_id),
+            Some(resource_address),
+            amount,
+        );
+    }
+
+    fn move_bucket(
+        &mut self,
+        instruction_index: usize,
+        bucket_id: ManifestBucket,
+        to: ResourceFlowNode,
+    ) {
+        let (resource_address, amount) = match self.bucket_contents.remove(&bucket_id) {
+            Some((resource_address, amount)) => (Some(resource_address), amount),
+            None => (None, ResourceFlowAmount::Unknown),
+        };
+        self.add_edge(
+            instruction_index,
+            ResourceFlowNode::Bucket(bucket_id),
+            to,
+            resource_address,
+            amount,
+        );
+    }
+
+    /// Adds the flows of the buckets and worktop expressions passed to a call.
+    fn pass_arguments(
+        &mut self,
+        instruction_index: usize,
+        callee: &ResourceFlowNode,
+        args: &ManifestValue,
+    ) {
+        let mut buckets = Vec::new();
+        let mut takes_worktop = false;
+        collect_resource_arguments(args, &mut buckets, &mut takes_worktop);
+        for bucket_id in buckets {
+            self.move_bucket(instruction_index, bucket_id, callee.clone());
+        }
+        if takes_worktop {
+            self.add_edge(
+                instruction_index,
+                ResourceFlowNode::Worktop,
+                callee.clone(),
+                None,
+                ResourceFlowAmount::All,
+            );
+        }
+    }
+
+    fn add_edge(
+        &mut self,
+        instruction_index: usize,
+        from: ResourceFlowNode,
+        to: ResourceFlowNode,
+        resource_address: Option<ResourceAddress>,
+        amount: ResourceFlowAmount,
+    ) {
+        self.add_node(from.clone());
+        self.add_node(to.clone());
+        self.graph.edges.push(ResourceFlowEdge {
+            from,
+            to,
+            instruction_index,
+            resource_address,
+            amount,
+        });
+    }
+
+    fn add_node(&mut self, node: ResourceFlowNode) {
+        if !self.graph.nodes.contains(&node) {
+            self.graph.nodes.push(node);
+        }
+    }
+}
+
+fn collect_resource_arguments(
+    value: &ManifestValue,
+    buckets: &mut Vec<ManifestBucket>,
+    takes_worktop: &mut bool,
+) {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(bucket_id),
+        } => buckets.push(*bucket_id),
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+        } => *takes_worktop = true,
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            for field in fields {
+                collect_resource_arguments(field, buckets, takes_worktop);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                collect_resource_arguments(element, buckets, takes_worktop);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                collect_resource_arguments(key, buckets, takes_worktop);
+                collect_resource_arguments(value, buckets, takes_worktop);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn global_node(address: &DynamicGlobalAddress) -> ResourceFlowNode {
+    match address {
+        DynamicGlobalAddress::Static(address) => match address.as_node_id().entity_type() {
+            Some(EntityType::GlobalAccount)
+            | Some(EntityType::GlobalVirtualSecp256k1Account)
+            | Some(EntityType::GlobalVirtualEd25519Account) => ResourceFlowNode::Account(*address),
+            _ => ResourceFlowNode::Component(DynamicGlobalAddress::Static(*address)),
+        },
+        DynamicGlobalAddress::Named(_) => ResourceFlowNode::Component(*address),
+    }
+}
+
+fn node_kind(node: &ResourceFlowNode) -> &'static str {
+    match node {
+        ResourceFlowNode::Worktop => "Worktop",
+        ResourceFlowNode::Bucket(_) => "Bucket",
+        ResourceFlowNode::Account(_) => "Account",
+        ResourceFlowNode::Component(_) => "Component",
+        ResourceFlowNode::Vault(_) => "Vault",
+        ResourceFlowNode::Burned => "Burned",
+    }
+}
+
+fn node_id(address_bech32_encoder: &AddressBech32Encoder, node: &ResourceFlowNode) -> String {
+    match node {
+        ResourceFlowNode::Worktop => "worktop".to_string(),
+        ResourceFlowNode::Bucket(bucket_id) => format!("bucket{}", bucket_id.0),
+        ResourceFlowNode::Account(address)
+        | ResourceFlowNode::Component(DynamicGlobalAddress::Static(address)) => {
+            encode_node_id(address_bech32_encoder, address.as_node_id())
+        }
+        ResourceFlowNode::Component(DynamicGlobalAddress::Named(id)) => {
+            format!("named_address{}", id)
+        }
+        ResourceFlowNode::Vault(address) => {
+            encode_node_id(address_bech32_encoder, address.as_node_id())
+        }
+        ResourceFlowNode::Burned => "burned".to_string(),
+    }
+}
+
+fn format_amount(amount: &ResourceFlowAmount) -> String {
+    match amount {
+        ResourceFlowAmount::Amount(amount) => amount.to_string(),
+        ResourceFlowAmount::NonFungibles(ids) => ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+        ResourceFlowAmount::All => "all".to_string(),
+        ResourceFlowAmount::Unknown => "unknown".to_string(),
+    }
+}
+
+fn format_resource(
+    address_bech32_encoder: &AddressBech32Encoder,
+    resource_address: &Option<ResourceAddress>,
+) -> String {
+    match resource_address {
+        Some(resource_address) => {
+            encode_node_id(address_bech32_encoder, resource_address.as_node_id())
+        }
+        None => "any resource".to_string(),
+    }
+}
+
+fn encode_node_id(address_bech32_encoder: &AddressBech32Encoder, node_id: &NodeId) -> String {
+    address_bech32_encoder
+        .encode(node_id.as_ref())
+        .unwrap_or_else(|_| node_id.to_hex())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account() -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        )
+    }
+
+    #[test]
+    fn test_withdraw_take_and_deposit() {
+        let from = account();
+        let to = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(from, XRD, 10)
+            .take_from_worktop(XRD, 4, "bucket")
+            .try_deposit_or_abort(to, "bucket")
+            .try_deposit_batch_or_abort(from)
+            .build();
+
+        let graph = ResourceFlowGraph::from_manifest(&manifest);
+
+        let bucket = ResourceFlowNode::Bucket(ManifestBucket(0));
+        assert_eq!(
+            graph.edges,
+            vec![
+                ResourceFlowEdge {
+                    from: ResourceFlowNode::Account(from.into()),
+                    to: ResourceFlowNode::Worktop,
+                    instruction_index: 0,
+                    resource_address: Some(XRD),
+                    amount: ResourceFlowAmount::Amount(dec!(10)),
+                },
+                ResourceFlowEdge {
+                    from: ResourceFlowNode::Worktop,
+                    to: bucket.clone(),
+                    instruction_index: 1,
+                    resource_address: Some(XRD),
+                    amount: ResourceFlowAmount::Amount(dec!(4)),
+                },
+                ResourceFlowEdge {
+                    from: bucket.clone(),
+                    to: ResourceFlowNode::Account(to.into()),
+                    instruction_index: 2,
+                    resource_address: Some(XRD),
+                    amount: ResourceFlowAmount::Amount(dec!(4)),
+                },
+                ResourceFlowEdge {
+                    from: ResourceFlowNode::Worktop,
+                    to: ResourceFlowNode::Account(from.into()),
+                    instruction_index: 3,
+                    resource_address: None,
+                    amount: ResourceFlowAmount::All,
+                },
+            ]
+        );
+        assert_eq!(
+            graph.nodes,
+            vec![
+                ResourceFlowNode::Worktop,
+                ResourceFlowNode::Account(from.into()),
+                bucket,
+                ResourceFlowNode::Account(to.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_returns_and_burns() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account(), 10)
+            .get_free_xrd_from_faucet()
+            .take_all_from_worktop(XRD, "bucket")
+            .burn_resource("bucket")
+            .build();
+
+        let graph = ResourceFlowGraph::from_manifest(&manifest);
+
+        // Locking a fee moves no resource
+        assert!(graph
+            .flows_out_of(&ResourceFlowNode::Account(account().into()))
+            .is_empty());
+        assert_eq!(
+            graph.flows_into(&ResourceFlowNode::Worktop),
+            vec![&ResourceFlowEdge {
+                from: ResourceFlowNode::Component(DynamicGlobalAddress::Static(FAUCET.into())),
+                to: ResourceFlowNode::Worktop,
+                instruction_index: 1,
+                resource_address: None,
+                amount: ResourceFlowAmount::Unknown,
+            }]
+        );
+        assert_eq!(
+            graph.flows_into(&ResourceFlowNode::Burned),
+            vec![&ResourceFlowEdge {
+                from: ResourceFlowNode::Bucket(ManifestBucket(0)),
+                to: ResourceFlowNode::Burned,
+                instruction_index: 3,
+                resource_address: Some(XRD),
+                amount: ResourceFlowAmount::All,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_to_dot_and_json() {
+        let manifest = ManifestBuilder::new()
+            .take_from_worktop(XRD, 1, "bucket")
+            .return_to_worktop("bucket")
+            .build();
+        let graph = ResourceFlowGraph::from_manifest(&manifest);
+        let encoder = AddressBech32Encoder::for_simulator();
+        let xrd = encoder.encode(XRD.as_node_id().as_ref()).unwrap();
+
+        assert_eq!(
+            graph.to_dot(&encoder),
+            format!(
+                "digraph resource_flow {{\n    \"worktop\" [label=\"Worktop\\nworktop\"];\n    \"bucket0\" [label=\"Bucket\\nbucket0\"];\n    \"worktop\" -> \"bucket0\" [label=\"#0: 1 of {xrd}\"];\n    \"bucket0\" -> \"worktop\" [label=\"#1: 1 of {xrd}\"];\n}}\n"
+            )
+        );
+        assert_eq!(
+            graph.to_json(&encoder),
+            format!(
+                "{{\"nodes\":[{{\"id\":\"worktop\",\"kind\":\"Worktop\"}},{{\"id\":\"bucket0\",\"kind\":\"Bucket\"}}],\"edges\":[{{\"from\":\"worktop\",\"to\":\"bucket0\",\"instruction_index\":0,\"resource_address\":\"{xrd}\",\"amount\":{{\"kind\":\"amount\",\"value\":\"1\"}}}},{{\"from\":\"bucket0\",\"to\":\"worktop\",\"instruction_index\":1,\"resource_address\":\"{xrd}\",\"amount\":{{\"kind\":\"amount\",\"value\":\"1\"}}}}]}}"
+            )
+        );
+    }
+}
@@ -4,6 +4,9 @@ use crate::internal_prelude::*;
 pub struct AuthZoneParams {
     pub initial_proofs: BTreeSet<NonFungibleGlobalId>,
     pub virtual_resources: BTreeSet<ResourceAddress>,
+    /// Whether failed access rule checks are let through, e.g. to preview a transaction before
+    /// the badges it requires are presented.
+    pub assume_all_access_rule_checks_pass: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -16,6 +19,10 @@ pub struct EpochRange {
 pub struct ExecutionContext {
     pub intent_hash: TransactionIntentHash,
     pub epoch_range: Option<EpochRange>,
+    /// The instant at (or after) which the transaction can no longer be committed.
+    pub expires_at_timestamp: Option<Instant>,
+    /// The key under which the transaction tracker deduplicates commits, if any.
+    pub idempotency_key: Option<Hash>,
     pub pre_allocated_addresses: Vec<PreAllocatedAddress>,
     pub payload_size: usize,
     pub auth_zone_params: AuthZoneParams,
@@ -65,6 +72,8 @@ pub struct FeePayment {
     pub tip_percentage: u16,
     /// Free credit for execution, for preview only!
     pub free_credit_in_xrd: Decimal,
+    /// The hard ceiling of the total fee, if declared by the transaction.
+    pub max_fee_in_xrd: Option<Decimal>,
 }
 
 /// Executable form of transaction, post stateless validation.
@@ -130,6 +139,14 @@ impl<'a> Executable<'a> {
         self.context.epoch_range = None;
     }
 
+    pub fn expires_at_timestamp(&self) -> Option<&Instant> {
+        self.context.expires_at_timestamp.as_ref()
+    }
+
+    pub fn idempotency_key(&self) -> Option<&Hash> {
+        self.context.idempotency_key.as_ref()
+    }
+
     pub fn fee_payment(&self) -> &FeePayment {
         &self.context.fee_payment
     }
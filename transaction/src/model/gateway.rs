@@ -0,0 +1,383 @@
+use crate::internal_prelude::*;
+use sbor::rust::str::FromStr;
+
+/// A notarized transaction in the JSON shape used by the Core API and the Gateway API: the hex
+/// encoded payload, alongside its hashes and a readable view of its header and manifest.
+///
+/// The payload is the only authoritative field. The other fields are optional, but whenever they
+/// are present they are checked against the payload, so that a transaction copied from the
+/// network can't be altered by accident before being replayed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayTransactionJson {
+    /// The hex encoded notarized transaction payload, `raw_hex` in the Gateway API.
+    #[cfg_attr(feature = "serde", serde(alias = "raw_hex"))]
+    pub payload_hex: String,
+    /// The intent hash, either Bech32m encoded or hex encoded.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub intent_hash: Option<String>,
+    /// The notarized transaction hash, either Bech32m encoded or hex encoded.
+    #[cfg_attr(feature = "serde", serde(default, alias = "hash"))]
+    pub notarized_transaction_hash: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub header: Option<GatewayTransactionHeaderJson>,
+    /// The manifest, in the text format of the manifest compiler.
+    #[cfg_attr(feature = "serde", serde(default, alias = "instructions"))]
+    pub manifest_instructions: Option<String>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayTransactionHeaderJson {
+    pub network_id: u8,
+    pub start_epoch_inclusive: u64,
+    pub end_epoch_exclusive: u64,
+    pub nonce: u32,
+    pub notary_public_key: GatewayPublicKeyJson,
+    pub notary_is_signatory: bool,
+    pub tip_percentage: u16,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayPublicKeyJson {
+    /// Either `EcdsaSecp256k1` or `EddsaEd25519`.
+    pub key_type: String,
+    pub key_hex: String,
+}
+
+pub const GATEWAY_SECP256K1_KEY_TYPE: &str = "EcdsaSecp256k1";
+pub const GATEWAY_ED25519_KEY_TYPE: &str = "EddsaEd25519";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GatewayTransactionError {
+    InvalidPayloadHex,
+    PayloadDecodeError(DecodeError),
+    PayloadEncodeError(EncodeError),
+    PrepareError(PrepareError),
+    InvalidHash {
+        field: &'static str,
+        value: String,
+    },
+    HashMismatch {
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+    NetworkMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    HeaderMismatch {
+        field: &'static str,
+    },
+    InvalidPublicKey(GatewayPublicKeyJson),
+    InvalidManifestInstructions(CompileError),
+    ManifestMismatch,
+    DecompileError(String),
+}
+
+impl GatewayTransactionJson {
+    /// Converts a notarized transaction into its JSON representation, with all optional fields
+    /// filled in and hashes Bech32m encoded for the given network.
+    pub fn from_notarized_transaction(
+        transaction: &NotarizedTransactionV1,
+        network: &NetworkDefinition,
+    ) -> Result<Self, GatewayTransactionError> {
+        let payload = transaction
+            .to_payload_bytes()
+            .map_err(GatewayTransactionError::PayloadEncodeError)?;
+        let prepared = transaction
+            .prepare()
+            .map_err(GatewayTransactionError::PrepareError)?;
+        let encoder = TransactionHashBech32Encoder::new(network);
+        let intent = &transaction.signed_intent.intent;
+
+        Ok(Self {
+            payload_hex: hex::encode(payload),
+            intent_hash: Some(prepared.intent_hash().to_string(&encoder)),
+            notarized_transaction_hash: Some(
+                prepared.notarized_transaction_hash().to_string(&encoder),
+            ),
+            header: Some(GatewayTransactionHeaderJson::from_header(&intent.header)),
+            manifest_instructions: Some(
+                decompile(&intent.instructions.0, network)
+                    .map_err(|e| GatewayTransactionError::DecompileError(format!("{:?}", e)))?,
+            ),
+        })
+    }
+
+    /// Decodes the payload into a notarized transaction, after checking every other field
+    /// which is present against it.
+    pub fn to_notarized_transaction(
+        &self,
+        network: &NetworkDefinition,
+    ) -> Result<NotarizedTransactionV1, GatewayTransactionError> {
+        let payload = hex::decode(self.payload_hex.trim())
+            .map_err(|_| GatewayTransactionError::InvalidPayloadHex)?;
+        let transaction = NotarizedTransactionV1::from_payload_bytes(&payload)
+            .map_err(GatewayTransactionError::PayloadDecodeError)?;
+        let prepared = PreparedNotarizedTransactionV1::prepare_from_payload(&payload)?;
+        let intent = &transaction.signed_intent.intent;
+
+        if intent.header.network_id != network.id {
+            return Err(GatewayTransactionError::NetworkMismatch {
+                expected: network.id,
+                actual: intent.header.network_id,
+            });
+        }
+
+        let decoder = TransactionHashBech32Decoder::new(network);
+        let encoder = TransactionHashBech32Encoder::new(network);
+        if let Some(intent_hash) = &self.intent_hash {
+            let expected: IntentHash = parse_hash(&decoder, "intent_hash", intent_hash)?;
+            let actual = prepared.intent_hash();
+            if expected != actual {
+                return Err(GatewayTransactionError::HashMismatch {
+                    field: "intent_hash",
+                    expected: expected.to_string(&encoder),
+                    actual: actual.to_string(&encoder),
+                });
+            }
+        }
+        if let Some(notarized_transaction_hash) = &self.notarized_transaction_hash {
+            let expected: NotarizedTransactionHash = parse_hash(
+                &decoder,
+                "notarized_transaction_hash",
+                notarized_transaction_hash,
+            )?;
+            let actual = prepared.notarized_transaction_hash();
+            if expected != actual {
+                return Err(GatewayTransactionError::HashMismatch {
+                    field: "notarized_transaction_hash",
+                    expected: expected.to_string(&encoder),
+                    actual: actual.to_string(&encoder),
+                });
+            }
+        }
+
+        if let Some(header) = &self.header {
+            header.check_against(&intent.header)?;
+        }
+
+        if let Some(manifest_instructions) = &self.manifest_instructions {
+            let blobs = intent
+                .blobs
+                .blobs
+                .iter()
+                .map(|blob| blob.0.clone())
+                .collect();
+            let manifest = compile(
+                manifest_instructions,
+                network,
+                BlobProvider::new_with_blobs(blobs),
+            )
+            .map_err(GatewayTransactionError::InvalidManifestInstructions)?;
+            if manifest.instructions != intent.instructions.0 {
+                return Err(GatewayTransactionError::ManifestMismatch);
+            }
+        }
+
+        Ok(transaction)
+    }
+}
+
+impl GatewayTransactionHeaderJson {
+    pub fn from_header(header: &TransactionHeaderV1) -> Self {
+        Self {
+            network_id: header.network_id,
+            start_epoch_inclusive: header.start_epoch_inclusive.number(),
+            end_epoch_exclusive: header.end_epoch_exclusive.number(),
+            nonce: header.nonce,
+            notary_public_key: GatewayPublicKeyJson::from_public_key(&header.notary_public_key),
+            notary_is_signatory: header.notary_is_signatory,
+            tip_percentage: header.tip_percentage,
+        }
+    }
+
+    fn check_against(&self, header: &TransactionHeaderV1) -> Result<(), GatewayTransactionError> {
+        let mismatch = |field| Err(GatewayTransactionError::HeaderMismatch { field });
+
+        if self.network_id != header.network_id {
+            return mismatch("network_id");
+        }
+        if self.start_epoch_inclusive != header.start_epoch_inclusive.number() {
+            return mismatch("start_epoch_inclusive");
+        }
+        if self.end_epoch_exclusive != header.end_epoch_exclusive.number() {
+            return mismatch("end_epoch_exclusive");
+        }
+        if self.nonce != header.nonce {
+            return mismatch("nonce");
+        }
+        if self.notary_public_key.to_public_key()? != header.notary_public_key {
+            return mismatch("notary_public_key");
+        }
+        if self.notary_is_signatory != header.notary_is_signatory {
+            return mismatch("notary_is_signatory");
+        }
+        if self.tip_percentage != header.tip_percentage {
+            return mismatch("tip_percentage");
+        }
+        Ok(())
+    }
+}
+
+impl GatewayPublicKeyJson {
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        match public_key {
+            PublicKey::Secp256k1(public_key) => Self {
+                key_type: GATEWAY_SECP256K1_KEY_TYPE.to_string(),
+                key_hex: public_key.to_string(),
+            },
+            PublicKey::Ed25519(public_key) => Self {
+                key_type: GATEWAY_ED25519_KEY_TYPE.to_string(),
+                key_hex: public_key.to_string(),
+            },
+        }
+    }
+
+    pub fn to_public_key(&self) -> Result<PublicKey, GatewayTransactionError> {
+        let public_key = match self.key_type.as_str() {
+            GATEWAY_SECP256K1_KEY_TYPE => Secp256k1PublicKey::from_str(&self.key_hex)
+                .ok()
+                .map(PublicKey::Secp256k1),
+            GATEWAY_ED25519_KEY_TYPE => Ed25519PublicKey::from_str(&self.key_hex)
+                .ok()
+                .map(PublicKey::Ed25519),
+            _ => None,
+        };
+        public_key.ok_or_else(|| GatewayTransactionError::InvalidPublicKey(self.clone()))
+    }
+}
+
+impl From<PrepareError> for GatewayTransactionError {
+    fn from(value: PrepareError) -> Self {
+        Self::PrepareError(value)
+    }
+}
+
+/// Parses a hash which is either Bech32m encoded, as in the Gateway API, or hex encoded, as in
+/// the Core API.
+fn parse_hash<T: HashHasHrp>(
+    decoder: &TransactionHashBech32Decoder,
+    field: &'static str,
+    value: &str,
+) -> Result<T, GatewayTransactionError> {
+    if let Ok(hash) = decoder.validate_and_decode(value) {
+        return Ok(hash);
+    }
+    Hash::from_str(value)
+        .map(T::from)
+        .map_err(|_| GatewayTransactionError::InvalidHash {
+            field,
+            value: value.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_transaction() -> NotarizedTransactionV1 {
+        let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::of(1),
+                end_epoch_exclusive: Epoch::of(10),
+                nonce: 5,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_is_signatory: true,
+                tip_percentage: 5,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
+            })
+            .manifest(
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .drop_all_proofs()
+                    .build(),
+            )
+            .notarize(&sk_notary)
+            .build()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let network = NetworkDefinition::simulator();
+        let transaction = create_transaction();
+
+        let json =
+            GatewayTransactionJson::from_notarized_transaction(&transaction, &network).unwrap();
+
+        assert!(json.intent_hash.as_ref().unwrap().starts_with("txid_sim"));
+        assert_eq!(json.to_notarized_transaction(&network), Ok(transaction));
+    }
+
+    #[test]
+    fn test_hex_encoded_hashes_are_accepted() {
+        let network = NetworkDefinition::simulator();
+        let transaction = create_transaction();
+        let prepared = transaction.prepare().unwrap();
+        let json = GatewayTransactionJson {
+            payload_hex: hex::encode(transaction.to_payload_bytes().unwrap()),
+            intent_hash: Some(prepared.intent_hash().as_hash().to_string()),
+            notarized_transaction_hash: Some(
+                prepared.notarized_transaction_hash().as_hash().to_string(),
+            ),
+            header: None,
+            manifest_instructions: None,
+        };
+
+        assert_eq!(json.to_notarized_transaction(&network), Ok(transaction));
+    }
+
+    #[test]
+    fn test_inconsistent_fields_are_rejected() {
+        let network = NetworkDefinition::simulator();
+        let transaction = create_transaction();
+        let json =
+            GatewayTransactionJson::from_notarized_transaction(&transaction, &network).unwrap();
+
+        let mut invalid_hex = json.clone();
+        invalid_hex.payload_hex.push('z');
+        assert_eq!(
+            invalid_hex.to_notarized_transaction(&network),
+            Err(GatewayTransactionError::InvalidPayloadHex)
+        );
+
+        let mut wrong_hash = json.clone();
+        wrong_hash.intent_hash = Some(Hash([0u8; Hash::LENGTH]).to_string());
+        assert!(matches!(
+            wrong_hash.to_notarized_transaction(&network),
+            Err(GatewayTransactionError::HashMismatch {
+                field: "intent_hash",
+                ..
+            })
+        ));
+
+        let mut wrong_header = json.clone();
+        wrong_header.header.as_mut().unwrap().nonce = 6;
+        assert_eq!(
+            wrong_header.to_notarized_transaction(&network),
+            Err(GatewayTransactionError::HeaderMismatch { field: "nonce" })
+        );
+
+        let mut wrong_manifest = json.clone();
+        wrong_manifest.manifest_instructions = Some("DROP_ALL_PROOFS;".to_string());
+        assert_eq!(
+            wrong_manifest.to_notarized_transaction(&network),
+            Err(GatewayTransactionError::ManifestMismatch)
+        );
+
+        assert_eq!(
+            json.to_notarized_transaction(&NetworkDefinition::mainnet()),
+            Err(GatewayTransactionError::NetworkMismatch {
+                expected: NetworkDefinition::mainnet().id,
+                actual: network.id,
+            })
+        );
+    }
+}
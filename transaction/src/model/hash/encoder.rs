@@ -123,6 +123,9 @@ mod tests {
             notary_is_signatory: true,
             notary_public_key: pk.public_key().into(),
             tip_percentage: 0,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         };
         TransactionBuilder::new()
             .manifest(manifest)
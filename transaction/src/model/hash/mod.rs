@@ -2,10 +2,12 @@ mod decoder;
 mod display;
 mod encoder;
 mod error;
+mod parts;
 mod traits;
 
 pub use decoder::*;
 pub use display::*;
 pub use encoder::*;
 pub use error::*;
+pub use parts::*;
 pub use traits::*;
@@ -0,0 +1,235 @@
+use crate::internal_prelude::*;
+use radix_engine_constants::TRANSACTION_HASHABLE_PAYLOAD_PREFIX;
+
+//=================================================================================
+// Utilities to compute transaction hashes from the hashes of their parts, without
+// building or preparing the full payload. These match the hashes calculated by the
+// `Prepared*` models - see versioned.rs for a demonstration of the hash structure.
+//
+// A payload hash is `hash(TRANSACTION_HASHABLE_PAYLOAD_PREFIX || discriminator || part hashes..)`,
+// which also applies to payload types added in the future (such as subintents used when
+// composing multi-party transactions), given their discriminator and part hashes.
+//=================================================================================
+
+/// Computes the hash of a payload with the given discriminator from the hashes of its parts.
+pub fn payload_hash_from_part_hashes(
+    discriminator: TransactionDiscriminator,
+    part_hashes: &[Hash],
+) -> Hash {
+    part_hashes
+        .iter()
+        .fold(
+            HashAccumulator::new()
+                .update(&[TRANSACTION_HASHABLE_PAYLOAD_PREFIX, discriminator as u8]),
+            |accumulator, part_hash| accumulator.update(part_hash),
+        )
+        .finalize()
+}
+
+/// Computes the hash of a single-valued part of a transaction, such as its header, instructions,
+/// message, intent signatures or notary signature.
+pub fn hash_transaction_part<T: ManifestEncode>(part: &T) -> Hash {
+    // The payload prefix byte is not included in the hash
+    hash(&manifest_encode(part).unwrap()[1..])
+}
+
+/// Computes the hash of the blobs of an intent.
+pub fn hash_blobs<'a, I: IntoIterator<Item = &'a [u8]>>(blobs: I) -> Hash {
+    blobs
+        .into_iter()
+        .fold(HashAccumulator::new(), |accumulator, blob| {
+            accumulator.update(hash(blob))
+        })
+        .finalize()
+}
+
+/// The hashes of the parts of a V1 intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntentPartHashesV1 {
+    pub header_hash: Hash,
+    pub instructions_hash: Hash,
+    pub blobs_hash: Hash,
+    pub message_hash: Hash,
+}
+
+impl IntentPartHashesV1 {
+    pub fn from_parts(
+        header: &TransactionHeaderV1,
+        instructions: &InstructionsV1,
+        blobs: &BlobsV1,
+        message: &MessageV1,
+    ) -> Self {
+        Self {
+            header_hash: hash_transaction_part(header),
+            instructions_hash: hash_transaction_part(&instructions.0),
+            blobs_hash: hash_blobs(blobs.blobs.iter().map(|blob| blob.0.as_slice())),
+            message_hash: hash_transaction_part(message),
+        }
+    }
+
+    pub fn from_intent(intent: &IntentV1) -> Self {
+        Self::from_parts(
+            &intent.header,
+            &intent.instructions,
+            &intent.blobs,
+            &intent.message,
+        )
+    }
+
+    pub fn intent_hash(&self) -> IntentHash {
+        IntentHash::from_hash(payload_hash_from_part_hashes(
+            TransactionDiscriminator::V1Intent,
+            &[
+                self.header_hash,
+                self.instructions_hash,
+                self.blobs_hash,
+                self.message_hash,
+            ],
+        ))
+    }
+}
+
+pub fn signed_intent_hash_from_parts(
+    intent_hash: &IntentHash,
+    intent_signatures_hash: &Hash,
+) -> SignedIntentHash {
+    SignedIntentHash::from_hash(payload_hash_from_part_hashes(
+        TransactionDiscriminator::V1SignedIntent,
+        &[intent_hash.0, *intent_signatures_hash],
+    ))
+}
+
+pub fn signed_intent_hash_from_signatures(
+    intent_hash: &IntentHash,
+    intent_signatures: &IntentSignaturesV1,
+) -> SignedIntentHash {
+    signed_intent_hash_from_parts(intent_hash, &hash_transaction_part(intent_signatures))
+}
+
+pub fn notarized_transaction_hash_from_parts(
+    signed_intent_hash: &SignedIntentHash,
+    notary_signature_hash: &Hash,
+) -> NotarizedTransactionHash {
+    NotarizedTransactionHash::from_hash(payload_hash_from_part_hashes(
+        TransactionDiscriminator::V1Notarized,
+        &[signed_intent_hash.0, *notary_signature_hash],
+    ))
+}
+
+pub fn notarized_transaction_hash_from_signature(
+    signed_intent_hash: &SignedIntentHash,
+    notary_signature: &NotarySignatureV1,
+) -> NotarizedTransactionHash {
+    notarized_transaction_hash_from_parts(
+        signed_intent_hash,
+        &hash_transaction_part(notary_signature),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+    use crate::signing::ed25519::Ed25519PrivateKey;
+    use crate::signing::secp256k1::Secp256k1PrivateKey;
+
+    #[test]
+    fn payload_hash_from_part_hashes_matches_test_vectors() {
+        let part_hashes = [
+            Hash([1u8; Hash::LENGTH]),
+            Hash([2u8; Hash::LENGTH]),
+            Hash([3u8; Hash::LENGTH]),
+            Hash([4u8; Hash::LENGTH]),
+        ];
+
+        assert_eq!(
+            payload_hash_from_part_hashes(TransactionDiscriminator::V1Intent, &part_hashes)
+                .to_string(),
+            "e4c468b9ed4fb2374a9835cb024a103295f7910b75f688aaab021a53aa3d90c8"
+        );
+        assert_eq!(
+            payload_hash_from_part_hashes(
+                TransactionDiscriminator::V1SignedIntent,
+                &part_hashes[..2]
+            )
+            .to_string(),
+            "39a703244ef10ba81739f1050612e9a7ccb80f206d324dd7628863daced5deeb"
+        );
+        assert_eq!(
+            payload_hash_from_part_hashes(TransactionDiscriminator::V1Notarized, &part_hashes[..2])
+                .to_string(),
+            "2288f04efae7b099cd7b1fbc23fac66ef53674fd113939438c3b5ba0bb1775ec"
+        );
+        assert_eq!(
+            hash_blobs([]).to_string(),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn hashes_from_parts_match_prepared_transaction() {
+        let network = NetworkDefinition::simulator();
+        let signer_private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let notary_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+
+        let intent = IntentV1 {
+            header: TransactionHeaderV1 {
+                network_id: network.id,
+                start_epoch_inclusive: Epoch::of(1),
+                end_epoch_exclusive: Epoch::of(5),
+                nonce: 0,
+                notary_public_key: notary_private_key.public_key().into(),
+                notary_is_signatory: false,
+                tip_percentage: 0,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
+            },
+            instructions: InstructionsV1(vec![InstructionV1::ClearAuthZone]),
+            blobs: BlobsV1 {
+                blobs: vec![BlobV1(vec![0, 1, 2, 3]), BlobV1(vec![5, 6])],
+            },
+            message: MessageV1::default(),
+        };
+        let intent_hash = IntentPartHashesV1::from_intent(&intent).intent_hash();
+        assert_eq!(
+            intent_hash,
+            intent.prepare().unwrap().intent_hash(),
+            "Intent hash"
+        );
+
+        let intent_signatures = IntentSignaturesV1 {
+            signatures: vec![IntentSignatureV1(
+                signer_private_key.sign_with_public_key(&intent_hash),
+            )],
+        };
+        let signed_intent_hash =
+            signed_intent_hash_from_signatures(&intent_hash, &intent_signatures);
+        let signed_intent = SignedIntentV1 {
+            intent,
+            intent_signatures,
+        };
+        assert_eq!(
+            signed_intent_hash,
+            signed_intent.prepare().unwrap().signed_intent_hash(),
+            "Signed intent hash"
+        );
+
+        let notary_signature =
+            NotarySignatureV1(notary_private_key.sign(&signed_intent_hash).into());
+        let notarized_transaction_hash =
+            notarized_transaction_hash_from_signature(&signed_intent_hash, &notary_signature);
+        let notarized_transaction = NotarizedTransactionV1 {
+            signed_intent,
+            notary_signature,
+        };
+        assert_eq!(
+            notarized_transaction_hash,
+            notarized_transaction
+                .prepare()
+                .unwrap()
+                .notarized_transaction_hash(),
+            "Notarized transaction hash"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod concepts;
 mod executable;
+mod gateway;
 mod hash;
 mod preparation;
 mod v1;
@@ -7,6 +8,7 @@ mod versioned;
 
 pub use concepts::*;
 pub use executable::*;
+pub use gateway::*;
 pub use hash::*;
 pub use preparation::*;
 pub use v1::*;
@@ -26,7 +26,7 @@ pub trait TransactionPayload:
     }
 
     fn from_payload_bytes(payload_bytes: &[u8]) -> Result<Self, DecodeError> {
-        Ok(manifest_decode::<Self::Versioned>(payload_bytes)?.into_fields())
+        Ok(manifest_decode_strict::<Self::Versioned>(payload_bytes)?.into_fields())
     }
 
     fn prepare(&self) -> Result<Self::Prepared, PrepareError> {
@@ -59,7 +59,7 @@ pub trait TransactionFullChildPreparable: HasSummary + Sized {
 
     /// Only exposed for testing
     fn prepare_as_full_body_child_from_payload(payload: &[u8]) -> Result<Self, PrepareError> {
-        let mut manifest_decoder = ManifestDecoder::new(payload, MANIFEST_SBOR_V1_MAX_DEPTH);
+        let mut manifest_decoder = ManifestDecoder::new_strict(payload, MANIFEST_SBOR_V1_MAX_DEPTH);
         manifest_decoder.read_and_check_payload_prefix(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)?;
         let mut transaction_decoder = TransactionDecoder::new(manifest_decoder);
         let prepared = Self::prepare_as_full_body_child(&mut transaction_decoder)?;
@@ -80,7 +80,7 @@ pub trait TransactionPayloadPreparable: HasSummary + Sized {
 
     /// Prepares from a full payload
     fn prepare_from_payload(payload: &[u8]) -> Result<Self, PrepareError> {
-        let mut manifest_decoder = ManifestDecoder::new(payload, MANIFEST_SBOR_V1_MAX_DEPTH);
+        let mut manifest_decoder = ManifestDecoder::new_strict(payload, MANIFEST_SBOR_V1_MAX_DEPTH);
         manifest_decoder.read_and_check_payload_prefix(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)?;
         let mut transaction_decoder = TransactionDecoder::new(manifest_decoder);
         let prepared = Self::prepare_for_payload(&mut transaction_decoder)?;
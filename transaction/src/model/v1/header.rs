@@ -1,5 +1,10 @@
+use radix_engine_common::math::Decimal;
+use radix_engine_common::time::Instant;
 use radix_engine_common::types::Epoch;
-use radix_engine_common::{crypto::PublicKey, ManifestSbor};
+use radix_engine_common::{
+    crypto::{Hash, PublicKey},
+    ManifestSbor,
+};
 
 use crate::model::SummarizedRawFullBody;
 
@@ -12,6 +17,15 @@ pub struct TransactionHeaderV1 {
     pub notary_public_key: PublicKey,
     pub notary_is_signatory: bool,
     pub tip_percentage: u16,
+    /// The maximum amount of XRD the fee payer(s) can be charged, across execution, tipping,
+    /// state expansion and royalties.
+    pub max_fee_in_xrd: Option<Decimal>,
+    /// If set, the transaction is rejected when executed at or after this instant, as per the
+    /// on-ledger clock. This complements the epoch range with a wall-clock bound.
+    pub expires_at_timestamp: Option<Instant>,
+    /// If set, the transaction is rejected while another committed transaction with the same key
+    /// is still within its epoch range, even if the two intents differ.
+    pub idempotency_key: Option<Hash>,
 }
 
 pub type PreparedTransactionHeaderV1 = SummarizedRawFullBody<TransactionHeaderV1>;
@@ -85,6 +85,14 @@ impl DynamicGlobalAddress {
             Self::Named(_) => false,
         }
     }
+    pub fn is_static_global_generic_component(&self) -> bool {
+        match self {
+            Self::Static(address) => {
+                address.as_node_id().entity_type() == Some(EntityType::GlobalGenericComponent)
+            }
+            Self::Named(_) => false,
+        }
+    }
 }
 
 impl From<GlobalAddress> for DynamicGlobalAddress {
@@ -341,6 +349,19 @@ pub enum InstructionV1 {
         ids: Vec<NonFungibleLocalId>,
     },
 
+    /// Asserts worktop contains resource by exactly the given amount.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_WORKTOP_CONTAINS_EXACTLY_DISCRIMINATOR))]
+    AssertWorktopContainsExactly {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+
+    /// Asserts worktop contains no resources other than the given ones.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_WORKTOP_RESOURCES_ONLY_DISCRIMINATOR))]
+    AssertWorktopResourcesOnly {
+        resource_addresses: Vec<ResourceAddress>,
+    },
+
     //==============
     // Auth zone
     //==============
@@ -467,6 +488,13 @@ pub enum InstructionV1 {
         package_address: PackageAddress,
         blueprint_name: String,
     },
+
+    /// Deposits all resources remaining on the worktop into the given account.
+    /// This must be the last instruction of the manifest which touches resources.
+    #[sbor(discriminator(INSTRUCTION_DROP_ALL_BUCKETS_INTO_DISCRIMINATOR))]
+    DropAllBucketsInto {
+        account_address: DynamicGlobalAddress,
+    },
 }
 
 //===============================================================
@@ -492,6 +520,8 @@ pub const INSTRUCTION_RETURN_TO_WORKTOP_DISCRIMINATOR: u8 = 0x03;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_DISCRIMINATOR: u8 = 0x04;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES_DISCRIMINATOR: u8 = 0x05;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_ANY_DISCRIMINATOR: u8 = 0x06;
+pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_EXACTLY_DISCRIMINATOR: u8 = 0x07;
+pub const INSTRUCTION_ASSERT_WORKTOP_RESOURCES_ONLY_DISCRIMINATOR: u8 = 0x08;
 
 //==============
 // Auth zone
@@ -533,3 +563,4 @@ pub const INSTRUCTION_CALL_DIRECT_VAULT_METHOD_DISCRIMINATOR: u8 = 0x45;
 //==============
 pub const INSTRUCTION_DROP_ALL_PROOFS_DISCRIMINATOR: u8 = 0x50;
 pub const INSTRUCTION_ALLOCATE_GLOBAL_ADDRESS_DISCRIMINATOR: u8 = 0x51;
+pub const INSTRUCTION_DROP_ALL_BUCKETS_INTO_DISCRIMINATOR: u8 = 0x52;
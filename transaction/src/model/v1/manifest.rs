@@ -11,6 +11,9 @@ use crate::internal_prelude::*;
 pub struct TransactionManifestV1 {
     pub instructions: Vec<InstructionV1>,
     pub blobs: BTreeMap<Hash, Vec<u8>>,
+    /// The labels of the instructions, by instruction index, eg from `@label("payment")` in the
+    /// manifest text. They aren't part of the intent, so they don't affect execution.
+    pub labels: BTreeMap<usize, String>,
 }
 
 impl TransactionManifestV1 {
@@ -23,6 +26,7 @@ impl TransactionManifestV1 {
                 .iter()
                 .map(|blob| (hash(&blob.0), blob.0.clone()))
                 .collect(),
+            labels: BTreeMap::new(),
         }
     }
 
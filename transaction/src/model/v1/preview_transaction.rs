@@ -6,6 +6,9 @@ pub struct PreviewFlags {
     pub use_free_credit: bool,
     pub assume_all_signature_proofs: bool,
     pub skip_epoch_check: bool,
+    /// Lets the transaction through failed access rule checks, so that fees can be estimated
+    /// before the badges it requires are presented.
+    pub assume_all_access_rule_checks_pass: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
@@ -41,6 +44,7 @@ impl ValidatedPreviewIntent {
             } else {
                 Decimal::ZERO
             },
+            max_fee_in_xrd: header.max_fee_in_xrd,
         };
         let initial_proofs = AuthAddresses::signer_set(&self.signer_public_keys);
 
@@ -69,6 +73,16 @@ impl ValidatedPreviewIntent {
                         end_epoch_exclusive: intent.header.inner.end_epoch_exclusive,
                     })
                 },
+                expires_at_timestamp: if flags.skip_epoch_check {
+                    None
+                } else {
+                    intent.header.inner.expires_at_timestamp
+                },
+                idempotency_key: if flags.skip_epoch_check {
+                    None
+                } else {
+                    intent.header.inner.idempotency_key
+                },
                 payload_size: self.encoded_instructions.len()
                     + intent
                         .blobs
@@ -79,6 +93,7 @@ impl ValidatedPreviewIntent {
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources,
+                    assume_all_access_rule_checks_pass: flags.assume_all_access_rule_checks_pass,
                 },
                 fee_payment,
                 pre_allocated_addresses: vec![],
@@ -109,14 +109,18 @@ impl PreparedSystemTransactionV1 {
                     intent_hash: self.hash_for_execution.hash,
                 },
                 epoch_range: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
                 payload_size: 0,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources: BTreeSet::new(),
+                    assume_all_access_rule_checks_pass: false,
                 },
                 fee_payment: FeePayment {
                     tip_percentage: 0,
                     free_credit_in_xrd: Decimal::ZERO,
+                    max_fee_in_xrd: None,
                 },
                 pre_allocated_addresses: self.pre_allocated_addresses.inner.clone(),
             },
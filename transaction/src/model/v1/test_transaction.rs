@@ -61,15 +61,19 @@ impl PreparedTestTransaction {
                     intent_hash: self.hash,
                 },
                 epoch_range: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
                 payload_size: self.encoded_instructions.len()
                     + self.blobs.values().map(|x| x.len()).sum::<usize>(),
                 auth_zone_params: AuthZoneParams {
                     initial_proofs,
                     virtual_resources: BTreeSet::new(),
+                    assume_all_access_rule_checks_pass: false,
                 },
                 fee_payment: FeePayment {
                     tip_percentage: DEFAULT_TIP_PERCENTAGE,
                     free_credit_in_xrd: Decimal::ZERO,
+                    max_fee_in_xrd: None,
                 },
                 pre_allocated_addresses: vec![],
             },
@@ -46,14 +46,18 @@ impl ValidatedNotarizedTransactionV1 {
                     start_epoch_inclusive: header.start_epoch_inclusive,
                     end_epoch_exclusive: header.end_epoch_exclusive,
                 }),
+                expires_at_timestamp: header.expires_at_timestamp,
+                idempotency_key: header.idempotency_key,
                 payload_size: summary.effective_length,
                 auth_zone_params: AuthZoneParams {
                     initial_proofs: AuthAddresses::signer_set(&self.signer_keys),
                     virtual_resources: BTreeSet::new(),
+                    assume_all_access_rule_checks_pass: false,
                 },
                 fee_payment: FeePayment {
                     tip_percentage: intent.header.inner.tip_percentage,
                     free_credit_in_xrd: Decimal::ZERO,
+                    max_fee_in_xrd: intent.header.inner.max_fee_in_xrd,
                 },
                 pre_allocated_addresses: vec![],
             },
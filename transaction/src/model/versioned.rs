@@ -95,6 +95,9 @@ mod tests {
             notary_public_key: notary_private_key.public_key().into(),
             notary_is_signatory: false,
             tip_percentage: 0,
+            max_fee_in_xrd: None,
+            expires_at_timestamp: None,
+            idempotency_key: None,
         };
         let expected_header_hash = hash_manifest_encoded_without_prefix_byte(&header_v1);
 
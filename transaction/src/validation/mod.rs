@@ -1,9 +1,13 @@
 mod id_allocator;
 mod id_validator;
 mod signature_validator;
+mod signature_verifier;
 mod transaction_validator;
+mod validation_rules;
 
 pub use id_allocator::*;
 pub use id_validator::*;
 pub use signature_validator::*;
+pub use signature_verifier::*;
 pub use transaction_validator::*;
+pub use validation_rules::*;
@@ -0,0 +1,40 @@
+use crate::internal_prelude::*;
+use crate::validation::*;
+use sbor::rust::fmt::Debug;
+
+/// A signature collected during transaction validation, along with what it should sign.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureToVerify {
+    pub signed_hash: Hash,
+    pub public_key: PublicKey,
+    pub signature: SignatureV1,
+}
+
+/// The backend used to verify transaction signatures.
+///
+/// Verifiers are registered with [`NotarizedTransactionValidator::with_signature_verifier`],
+/// which lets integrators plug in hardware-accelerated or batch verification. Any verifier must
+/// accept exactly the signatures accepted by [`DefaultSignatureVerifier`].
+pub trait SignatureVerifier: Debug + Send + Sync {
+    fn verify(&self, signed_hash: &Hash, public_key: &PublicKey, signature: &SignatureV1) -> bool;
+
+    /// Returns whether all the given signatures are valid.
+    ///
+    /// Used by [`NotarizedTransactionValidator::validate_batch`] to verify the signatures of many
+    /// transactions at once. The default implementation verifies them one by one.
+    fn verify_batch(&self, signatures: &[SignatureToVerify]) -> bool {
+        signatures
+            .iter()
+            .all(|s| self.verify(&s.signed_hash, &s.public_key, &s.signature))
+    }
+}
+
+/// Verifies signatures one at a time with the [`verify`] function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultSignatureVerifier;
+
+impl SignatureVerifier for DefaultSignatureVerifier {
+    fn verify(&self, signed_hash: &Hash, public_key: &PublicKey, signature: &SignatureV1) -> bool {
+        verify(signed_hash, public_key, signature)
+    }
+}
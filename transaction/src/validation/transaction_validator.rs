@@ -1,5 +1,6 @@
 use crate::internal_prelude::*;
 use crate::validation::*;
+use sbor::rust::sync::Arc;
 
 pub trait TransactionValidator<Prepared: TransactionPayloadPreparable> {
     type Validated;
@@ -95,9 +96,11 @@ impl Default for MessageValidationConfig {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct NotarizedTransactionValidator {
     config: ValidationConfig,
+    rules: Vec<Arc<dyn ValidationRule>>,
+    signature_verifier: Arc<dyn SignatureVerifier>,
 }
 
 impl TransactionValidator<PreparedNotarizedTransactionV1> for NotarizedTransactionValidator {
@@ -111,14 +114,84 @@ impl TransactionValidator<PreparedNotarizedTransactionV1> for NotarizedTransacti
         &self,
         transaction: PreparedNotarizedTransactionV1,
     ) -> Result<Self::Validated, TransactionValidationError> {
+        self.validate_with_signers(transaction, |transaction| {
+            self.validate_signatures_v1(transaction)
+        })
+    }
+}
+
+impl NotarizedTransactionValidator {
+    pub fn new(config: ValidationConfig) -> Self {
+        Self {
+            config,
+            rules: Vec::new(),
+            signature_verifier: Arc::new(DefaultSignatureVerifier),
+        }
+    }
+
+    /// Registers a custom rule, checked after the built-in validation of the intent.
+    /// Rules are checked in the order they were registered.
+    pub fn with_rule(mut self, rule: impl ValidationRule + 'static) -> Self {
+        self.rules.push(Arc::new(rule));
+        self
+    }
+
+    /// Replaces the [`DefaultSignatureVerifier`] used to verify intent and notary signatures.
+    pub fn with_signature_verifier(
+        mut self,
+        signature_verifier: impl SignatureVerifier + 'static,
+    ) -> Self {
+        self.signature_verifier = Arc::new(signature_verifier);
+        self
+    }
+
+    /// Validates many transactions, verifying the signatures of all of them with a single
+    /// [`SignatureVerifier::verify_batch`] call.
+    ///
+    /// If the batch contains an invalid signature, each transaction is validated on its own, so
+    /// the results are always the same as calling [`TransactionValidator::validate`] for each.
+    pub fn validate_batch(
+        &self,
+        transactions: Vec<PreparedNotarizedTransactionV1>,
+    ) -> Vec<Result<ValidatedNotarizedTransactionV1, TransactionValidationError>> {
+        let signatures_to_verify: Vec<_> = transactions
+            .iter()
+            .map(|transaction| self.signatures_to_verify_v1(transaction).ok())
+            .collect();
+
+        let all_signatures: Vec<SignatureToVerify> = signatures_to_verify
+            .iter()
+            .flatten()
+            .flat_map(|(_, signatures)| signatures.iter().cloned())
+            .collect();
+        let all_valid = self.signature_verifier.verify_batch(&all_signatures);
+
+        transactions
+            .into_iter()
+            .zip(signatures_to_verify)
+            .map(|(transaction, signatures)| match signatures {
+                Some((signer_keys, _)) if all_valid => {
+                    self.validate_with_signers(transaction, |_| Ok(signer_keys))
+                }
+                _ => self.validate(transaction),
+            })
+            .collect()
+    }
+
+    fn validate_with_signers(
+        &self,
+        transaction: PreparedNotarizedTransactionV1,
+        signers: impl FnOnce(
+            &PreparedNotarizedTransactionV1,
+        ) -> Result<Vec<PublicKey>, SignatureValidationError>,
+    ) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
         self.validate_intent_v1(&transaction.signed_intent.intent)?;
 
         let encoded_instructions =
             manifest_encode(&transaction.signed_intent.intent.instructions.inner.0)?;
 
-        let signer_keys = self
-            .validate_signatures_v1(&transaction)
-            .map_err(TransactionValidationError::SignatureValidationError)?;
+        let signer_keys =
+            signers(&transaction).map_err(TransactionValidationError::SignatureValidationError)?;
 
         Ok(ValidatedNotarizedTransactionV1 {
             prepared: transaction,
@@ -126,12 +199,6 @@ impl TransactionValidator<PreparedNotarizedTransactionV1> for NotarizedTransacti
             signer_keys,
         })
     }
-}
-
-impl NotarizedTransactionValidator {
-    pub fn new(config: ValidationConfig) -> Self {
-        Self { config }
-    }
 
     pub fn validate_preview_intent_v1(
         &self,
@@ -162,15 +229,36 @@ impl NotarizedTransactionValidator {
 
         Self::validate_instructions_v1(&intent.instructions.inner.0)?;
 
+        self.validate_rules_v1(intent)?;
+
         return Ok(());
     }
 
+    pub fn validate_rules_v1(
+        &self,
+        intent: &PreparedIntentV1,
+    ) -> Result<(), TransactionValidationError> {
+        for rule in &self.rules {
+            rule.validate_intent(intent).map_err(|violation| {
+                TransactionValidationError::ValidationRuleViolation {
+                    rule: rule.name().to_string(),
+                    violation,
+                }
+            })?;
+        }
+        Ok(())
+    }
+
     pub fn validate_instructions_v1(
         instructions: &[InstructionV1],
     ) -> Result<(), TransactionValidationError> {
         // semantic analysis
         let mut id_validator = ManifestValidator::new();
+        let mut worktop_dropped = false;
         for inst in instructions {
+            if worktop_dropped && Self::is_resource_instruction(inst) {
+                return Err(TransactionValidationError::ResourceInstructionAfterDropAllBuckets);
+            }
             match inst {
                 InstructionV1::TakeAllFromWorktop { .. } => {
                     let _ = id_validator.new_bucket();
@@ -189,6 +277,8 @@ impl NotarizedTransactionValidator {
                 InstructionV1::AssertWorktopContainsAny { .. } => {}
                 InstructionV1::AssertWorktopContains { .. } => {}
                 InstructionV1::AssertWorktopContainsNonFungibles { .. } => {}
+                InstructionV1::AssertWorktopContainsExactly { .. } => {}
+                InstructionV1::AssertWorktopResourcesOnly { .. } => {}
                 InstructionV1::PopFromAuthZone => {
                     let _ = id_validator
                         .new_proof(ProofKind::AuthZoneProof)
@@ -264,12 +354,52 @@ impl NotarizedTransactionValidator {
                     let _ = id_validator.new_address_reservation();
                     id_validator.new_named_address();
                 }
+                InstructionV1::DropAllBucketsInto { .. } => {
+                    worktop_dropped = true;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Whether the instruction may move resources in or out of the worktop or named buckets.
+    fn is_resource_instruction(instruction: &InstructionV1) -> bool {
+        match instruction {
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::ClearAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::ClearSignatureProofs
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropAllProofs => false,
+            InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::AssertWorktopContainsExactly { .. }
+            | InstructionV1::AssertWorktopResourcesOnly { .. }
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::BurnResource { .. }
+            | InstructionV1::CallFunction { .. }
+            | InstructionV1::CallMethod { .. }
+            | InstructionV1::CallRoyaltyMethod { .. }
+            | InstructionV1::CallMetadataMethod { .. }
+            | InstructionV1::CallAccessRulesMethod { .. }
+            | InstructionV1::CallDirectVaultMethod { .. }
+            | InstructionV1::AllocateGlobalAddress { .. }
+            | InstructionV1::DropAllBucketsInto { .. } => true,
+        }
+    }
+
     pub fn validate_header_v1(
         &self,
         header: &TransactionHeaderV1,
@@ -297,6 +427,20 @@ impl NotarizedTransactionValidator {
             return Err(HeaderValidationError::InvalidTipPercentage);
         }
 
+        // max fee
+        if let Some(max_fee_in_xrd) = header.max_fee_in_xrd {
+            if max_fee_in_xrd.is_negative() {
+                return Err(HeaderValidationError::InvalidMaxFee);
+            }
+        }
+
+        // expiry timestamp
+        if let Some(expires_at_timestamp) = header.expires_at_timestamp {
+            if expires_at_timestamp.seconds_since_unix_epoch <= 0 {
+                return Err(HeaderValidationError::InvalidExpiryTimestamp);
+            }
+        }
+
         Ok(())
     }
 
@@ -323,7 +467,11 @@ impl NotarizedTransactionValidator {
             let public_key = recover(&intent_hash, &intent_signature.0)
                 .ok_or(SignatureValidationError::InvalidIntentSignature)?;
 
-            if !verify(&intent_hash, &public_key, &intent_signature.0.signature()) {
+            if !self.signature_verifier.verify(
+                &intent_hash,
+                &public_key,
+                &intent_signature.0.signature(),
+            ) {
                 return Err(SignatureValidationError::InvalidIntentSignature);
             }
 
@@ -340,7 +488,7 @@ impl NotarizedTransactionValidator {
 
         // verify notary signature
         let signed_intent_hash = transaction.signed_intent_hash().into_hash();
-        if !verify(
+        if !self.signature_verifier.verify(
             &signed_intent_hash,
             &header.notary_public_key,
             &transaction.notary_signature.inner.0,
@@ -351,6 +499,49 @@ impl NotarizedTransactionValidator {
         Ok(signers.into_iter().collect())
     }
 
+    /// Recovers the signers of a transaction and collects its signatures, without verifying them.
+    fn signatures_to_verify_v1(
+        &self,
+        transaction: &PreparedNotarizedTransactionV1,
+    ) -> Result<(Vec<PublicKey>, Vec<SignatureToVerify>), SignatureValidationError> {
+        let intent_signatures = &transaction.signed_intent.intent_signatures.inner.signatures;
+        if intent_signatures.len() > MAX_NUMBER_OF_INTENT_SIGNATURES {
+            return Err(SignatureValidationError::TooManySignatures);
+        }
+
+        let mut signers = index_set_new();
+        let mut signatures = Vec::with_capacity(intent_signatures.len() + 1);
+        let intent_hash = transaction.intent_hash().into_hash();
+        for intent_signature in intent_signatures {
+            let public_key = recover(&intent_hash, &intent_signature.0)
+                .ok_or(SignatureValidationError::InvalidIntentSignature)?;
+
+            if !signers.insert(public_key) {
+                return Err(SignatureValidationError::DuplicateSigner);
+            }
+
+            signatures.push(SignatureToVerify {
+                signed_hash: intent_hash,
+                public_key,
+                signature: intent_signature.0.signature(),
+            });
+        }
+
+        let header = &transaction.signed_intent.intent.header.inner;
+
+        if header.notary_is_signatory {
+            signers.insert(header.notary_public_key);
+        }
+
+        signatures.push(SignatureToVerify {
+            signed_hash: transaction.signed_intent_hash().into_hash(),
+            public_key: header.notary_public_key,
+            signature: transaction.notary_signature.inner.0,
+        });
+
+        Ok((signers.into_iter().collect(), signatures))
+    }
+
     pub fn validate_call_args(
         value: &ManifestValue,
         id_validator: &mut ManifestValidator,
@@ -432,6 +623,7 @@ mod tests {
         builder::ManifestBuilder, builder::TransactionBuilder,
         signing::secp256k1::Secp256k1PrivateKey,
     };
+    use core::sync::atomic::{AtomicUsize, Ordering};
 
     macro_rules! assert_invalid_tx {
         ($result: expr, ($start_epoch: expr, $end_epoch: expr, $nonce: expr, $signers: expr, $notary: expr)) => {{
@@ -488,6 +680,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_drop_all_buckets_into_must_be_last_resource_instruction() {
+        let drop_all_buckets = InstructionV1::DropAllBucketsInto {
+            account_address: FAUCET.into(),
+        };
+
+        assert_eq!(
+            NotarizedTransactionValidator::validate_instructions_v1(&[
+                drop_all_buckets.clone(),
+                InstructionV1::DropAllProofs,
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_instructions_v1(&[
+                drop_all_buckets.clone(),
+                InstructionV1::TakeAllFromWorktop {
+                    resource_address: XRD,
+                },
+            ]),
+            Err(TransactionValidationError::ResourceInstructionAfterDropAllBuckets)
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_instructions_v1(&[
+                drop_all_buckets.clone(),
+                drop_all_buckets,
+            ]),
+            Err(TransactionValidationError::ResourceInstructionAfterDropAllBuckets)
+        );
+    }
+
     #[test]
     fn test_valid_preview() {
         // Build the whole transaction but only really care about the intent
@@ -502,6 +725,7 @@ mod tests {
                 use_free_credit: true,
                 assume_all_signature_proofs: false,
                 skip_epoch_check: false,
+                assume_all_access_rule_checks_pass: false,
             },
         };
 
@@ -697,6 +921,228 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validation_rules() {
+        let validate = |validator: &NotarizedTransactionValidator, manifest| {
+            validator
+                .validate(
+                    create_transaction_with_manifest(manifest)
+                        .prepare()
+                        .unwrap(),
+                )
+                .map(|_| ())
+        };
+        let forbid_burn = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_rule(ForbiddenInstructionsRule::new([
+                INSTRUCTION_BURN_RESOURCE_DISCRIMINATOR,
+            ]));
+        let limit_size = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_rule(MaxManifestSizeRule {
+                max_instructions: 2,
+                max_manifest_bytes: 1024,
+            });
+        let require_faucet = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_rule(RequiredFeePayerRule::new([FAUCET.into()]));
+
+        // Forbidden instructions
+        assert_eq!(
+            validate(
+                &forbid_burn,
+                ManifestBuilder::new()
+                    .take_all_from_worktop(XRD, "xrd")
+                    .burn_resource("xrd")
+                    .build()
+            ),
+            Err(TransactionValidationError::ValidationRuleViolation {
+                rule: "ForbiddenInstructions".to_string(),
+                violation: RuleViolation::of_instruction(1, "instruction 0x24 is forbidden"),
+            })
+        );
+        assert_eq!(
+            validate(
+                &forbid_burn,
+                ManifestBuilder::new().clear_auth_zone().build()
+            ),
+            Ok(())
+        );
+
+        // Manifest size
+        assert_eq!(
+            validate(
+                &limit_size,
+                ManifestBuilder::new()
+                    .clear_auth_zone()
+                    .clear_auth_zone()
+                    .clear_auth_zone()
+                    .build()
+            ),
+            Err(TransactionValidationError::ValidationRuleViolation {
+                rule: "MaxManifestSize".to_string(),
+                violation: RuleViolation::of_intent("3 instructions exceed the limit of 2"),
+            })
+        );
+        assert!(matches!(
+            validate(&limit_size, {
+                let mut builder = ManifestBuilder::new();
+                builder.add_blob(vec![0; 2048]);
+                builder.build()
+            }),
+            Err(TransactionValidationError::ValidationRuleViolation { rule, .. })
+                if rule == "MaxManifestSize"
+        ));
+
+        // Fee payers
+        assert_eq!(
+            validate(
+                &require_faucet,
+                ManifestBuilder::new().lock_fee_from_faucet().build()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate(
+                &require_faucet,
+                ManifestBuilder::new()
+                    .lock_fee_from_faucet()
+                    .lock_fee(CONSENSUS_MANAGER, 10)
+                    .build()
+            ),
+            Err(TransactionValidationError::ValidationRuleViolation {
+                rule: "RequiredFeePayer".to_string(),
+                violation: RuleViolation::of_instruction(
+                    1,
+                    "fee locked from a fee payer which isn't permitted"
+                ),
+            })
+        );
+        assert_eq!(
+            validate(
+                &require_faucet,
+                ManifestBuilder::new().clear_auth_zone().build()
+            ),
+            Err(TransactionValidationError::ValidationRuleViolation {
+                rule: "RequiredFeePayer".to_string(),
+                violation: RuleViolation::of_intent("no fee locked from a permitted fee payer"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_validation_rules_apply_to_preview() {
+        let tx = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1, 2], 2);
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_rule(RequiredFeePayerRule::new([FAUCET.into()]));
+
+        let preview_intent = PreviewIntentV1 {
+            intent: tx.signed_intent.intent,
+            signer_public_keys: Vec::new(),
+            flags: PreviewFlags {
+                use_free_credit: true,
+                assume_all_signature_proofs: false,
+                skip_epoch_check: false,
+                assume_all_access_rule_checks_pass: false,
+            },
+        };
+
+        assert!(matches!(
+            validator.validate_preview_intent_v1(preview_intent),
+            Err(TransactionValidationError::ValidationRuleViolation { .. })
+        ));
+    }
+
+    #[derive(Debug, Default)]
+    struct BatchCounts {
+        batches: AtomicUsize,
+        signatures: AtomicUsize,
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingSignatureVerifier {
+        counts: Arc<BatchCounts>,
+        reject_batches: bool,
+    }
+
+    impl SignatureVerifier for RecordingSignatureVerifier {
+        fn verify(
+            &self,
+            signed_hash: &Hash,
+            public_key: &PublicKey,
+            signature: &SignatureV1,
+        ) -> bool {
+            DefaultSignatureVerifier.verify(signed_hash, public_key, signature)
+        }
+
+        fn verify_batch(&self, signatures: &[SignatureToVerify]) -> bool {
+            self.counts.batches.fetch_add(1, Ordering::SeqCst);
+            self.counts
+                .signatures
+                .fetch_add(signatures.len(), Ordering::SeqCst);
+            !self.reject_batches && DefaultSignatureVerifier.verify_batch(signatures)
+        }
+    }
+
+    #[test]
+    fn test_validate_batch_verifies_all_signatures_at_once() {
+        let counts = Arc::new(BatchCounts::default());
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_signature_verifier(RecordingSignatureVerifier {
+                counts: counts.clone(),
+                reject_batches: false,
+            });
+        let transactions = vec![
+            create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1, 2], 3),
+            create_transaction(Epoch::zero(), Epoch::of(100), 6, vec![4], 5),
+            create_transaction(Epoch::zero(), Epoch::of(100), 7, vec![6, 6], 7),
+        ];
+
+        let results = validator.validate_batch(
+            transactions
+                .iter()
+                .map(|transaction| transaction.prepare().unwrap())
+                .collect(),
+        );
+
+        // The transaction with a duplicate signer is left out of the batch
+        assert_eq!(counts.batches.load(Ordering::SeqCst), 1);
+        assert_eq!(counts.signatures.load(Ordering::SeqCst), 5);
+        assert_eq!(results.len(), 3);
+        for (transaction, result) in transactions.iter().zip(results) {
+            let expected = validator.validate(transaction.prepare().unwrap());
+            assert_eq!(
+                result.map(|validated| validated.signer_keys),
+                expected.map(|validated| validated.signer_keys)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_batch_falls_back_to_individual_verification() {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator())
+            .with_signature_verifier(RecordingSignatureVerifier {
+                counts: Default::default(),
+                reject_batches: true,
+            });
+        let transaction = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1, 2], 3);
+
+        let results = validator.validate_batch(vec![transaction.prepare().unwrap()]);
+
+        assert_eq!(results.len(), 1);
+        let signer_keys = results[0].as_ref().unwrap().signer_keys.clone();
+        assert_eq!(
+            signer_keys,
+            vec![
+                Secp256k1PrivateKey::from_u64(1)
+                    .unwrap()
+                    .public_key()
+                    .into(),
+                Secp256k1PrivateKey::from_u64(2)
+                    .unwrap()
+                    .public_key()
+                    .into(),
+            ]
+        );
+    }
+
     fn validate_default_expecting_message_error(
         transaction: &NotarizedTransactionV1,
     ) -> InvalidMessageError {
@@ -729,6 +1175,9 @@ mod tests {
                 notary_public_key: sk_notary.public_key().into(),
                 notary_is_signatory: false,
                 tip_percentage: 5,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
             })
             .manifest(ManifestBuilder::new().clear_auth_zone().build())
             .message(message);
@@ -738,6 +1187,27 @@ mod tests {
         builder.build()
     }
 
+    fn create_transaction_with_manifest(manifest: TransactionManifestV1) -> NotarizedTransactionV1 {
+        let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+
+        TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::of(1),
+                end_epoch_exclusive: Epoch::of(10),
+                nonce: 0,
+                notary_public_key: sk_notary.public_key().into(),
+                notary_is_signatory: false,
+                tip_percentage: 5,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
+            })
+            .manifest(manifest)
+            .notarize(&sk_notary)
+            .build()
+    }
+
     fn create_transaction(
         start_epoch: Epoch,
         end_epoch: Epoch,
@@ -756,6 +1226,9 @@ mod tests {
                 notary_public_key: sk_notary.public_key().into(),
                 notary_is_signatory: false,
                 tip_percentage: 5,
+                max_fee_in_xrd: None,
+                expires_at_timestamp: None,
+                idempotency_key: None,
             })
             .manifest(ManifestBuilder::new().clear_auth_zone().build());
 
@@ -0,0 +1,173 @@
+use crate::internal_prelude::*;
+use sbor::rust::fmt::Debug;
+
+/// A custom rule of a network's transaction policy, checked during static validation after the
+/// built-in checks have passed.
+///
+/// Rules are registered with [`NotarizedTransactionValidator::with_rule`], which lets permissioned
+/// deployments restrict what transactions they accept without forking the validator.
+pub trait ValidationRule: Debug + Send + Sync {
+    /// The name of the rule, reported in [`TransactionValidationError::ValidationRuleViolation`].
+    fn name(&self) -> &str;
+
+    fn validate_intent(&self, intent: &PreparedIntentV1) -> Result<(), RuleViolation>;
+}
+
+/// The reason an intent doesn't satisfy a [`ValidationRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleViolation {
+    /// The index of the offending instruction, if the violation is specific to one.
+    pub instruction_index: Option<usize>,
+    pub reason: String,
+}
+
+impl RuleViolation {
+    pub fn of_intent(reason: impl Into<String>) -> Self {
+        Self {
+            instruction_index: None,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn of_instruction(instruction_index: usize, reason: impl Into<String>) -> Self {
+        Self {
+            instruction_index: Some(instruction_index),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Rejects manifests containing any of the given instructions, identified by their
+/// `INSTRUCTION_*_DISCRIMINATOR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForbiddenInstructionsRule {
+    pub discriminators: IndexSet<u8>,
+}
+
+impl ForbiddenInstructionsRule {
+    pub fn new(discriminators: impl IntoIterator<Item = u8>) -> Self {
+        Self {
+            discriminators: discriminators.into_iter().collect(),
+        }
+    }
+}
+
+impl ValidationRule for ForbiddenInstructionsRule {
+    fn name(&self) -> &str {
+        "ForbiddenInstructions"
+    }
+
+    fn validate_intent(&self, intent: &PreparedIntentV1) -> Result<(), RuleViolation> {
+        for (index, instruction) in intent.instructions.inner.0.iter().enumerate() {
+            let discriminator = SborEnum::<ManifestCustomValueKind>::get_discriminator(instruction);
+            if self.discriminators.contains(&discriminator) {
+                return Err(RuleViolation::of_instruction(
+                    index,
+                    format!("instruction {:#04x} is forbidden", discriminator),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Limits the number of instructions and the encoded size of the manifest, blobs included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxManifestSizeRule {
+    pub max_instructions: usize,
+    pub max_manifest_bytes: usize,
+}
+
+impl ValidationRule for MaxManifestSizeRule {
+    fn name(&self) -> &str {
+        "MaxManifestSize"
+    }
+
+    fn validate_intent(&self, intent: &PreparedIntentV1) -> Result<(), RuleViolation> {
+        let instruction_count = intent.instructions.inner.0.len();
+        if instruction_count > self.max_instructions {
+            return Err(RuleViolation::of_intent(format!(
+                "{} instructions exceed the limit of {}",
+                instruction_count, self.max_instructions
+            )));
+        }
+
+        let manifest_bytes =
+            intent.instructions.summary.effective_length + intent.blobs.summary.effective_length;
+        if manifest_bytes > self.max_manifest_bytes {
+            return Err(RuleViolation::of_intent(format!(
+                "{} manifest bytes exceed the limit of {}",
+                manifest_bytes, self.max_manifest_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Requires fees to be locked, and only from the given fee payers.
+///
+/// Fee locks are recognised by the account lock fee methods. Fee locks on named addresses are
+/// rejected, as the payer can't be known statically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredFeePayerRule {
+    pub fee_payers: IndexSet<GlobalAddress>,
+}
+
+impl RequiredFeePayerRule {
+    pub fn new(fee_payers: impl IntoIterator<Item = GlobalAddress>) -> Self {
+        Self {
+            fee_payers: fee_payers.into_iter().collect(),
+        }
+    }
+
+    fn is_lock_fee_method(method_name: &str) -> bool {
+        match method_name {
+            ACCOUNT_LOCK_FEE_IDENT
+            | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT
+            | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+            | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => true,
+            _ => false,
+        }
+    }
+}
+
+impl ValidationRule for RequiredFeePayerRule {
+    fn name(&self) -> &str {
+        "RequiredFeePayer"
+    }
+
+    fn validate_intent(&self, intent: &PreparedIntentV1) -> Result<(), RuleViolation> {
+        let mut fee_locked = false;
+        for (index, instruction) in intent.instructions.inner.0.iter().enumerate() {
+            let InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } = instruction
+            else {
+                continue;
+            };
+            if !Self::is_lock_fee_method(method_name) {
+                continue;
+            }
+            match address {
+                DynamicGlobalAddress::Static(address) if self.fee_payers.contains(address) => {
+                    fee_locked = true;
+                }
+                _ => {
+                    return Err(RuleViolation::of_instruction(
+                        index,
+                        "fee locked from a fee payer which isn't permitted",
+                    ));
+                }
+            }
+        }
+
+        if !fee_locked {
+            return Err(RuleViolation::of_intent(
+                "no fee locked from a permitted fee payer",
+            ));
+        }
+        Ok(())
+    }
+}